@@ -0,0 +1,52 @@
+use std::borrow::Cow;
+
+use rolldown::{
+  plugin::{
+    HookTransformArgs, HookTransformOutput, HookTransformReturn, Plugin,
+    SharedTransformPluginContext,
+  },
+  BundlerBuilder, BundlerOptions,
+};
+use rolldown_testing::workspace;
+use sugar_path::SugarPath;
+
+// cargo run --example native_plugin
+
+/// Replaces `__VERSION__` in the sources with the version of the package.
+#[derive(Debug)]
+struct VersionPlugin;
+
+impl Plugin for VersionPlugin {
+  fn name(&self) -> Cow<'static, str> {
+    Cow::Borrowed("version")
+  }
+
+  async fn transform(
+    &self,
+    _ctx: SharedTransformPluginContext,
+    args: &HookTransformArgs<'_>,
+  ) -> HookTransformReturn {
+    if !args.code.contains("__VERSION__") {
+      return Ok(None);
+    }
+    Ok(Some(HookTransformOutput {
+      code: Some(args.code.replace("__VERSION__", "\"1.0.0\"")),
+      ..Default::default()
+    }))
+  }
+}
+
+#[tokio::main]
+async fn main() {
+  let mut bundler = BundlerBuilder::default()
+    .with_options(BundlerOptions {
+      input: Some(vec!["./entry.js".to_string().into()]),
+      cwd: Some(workspace::crate_dir("rolldown").join("./examples/basic").normalize()),
+      ..Default::default()
+    })
+    .with_plugin(VersionPlugin)
+    .build();
+
+  let result = bundler.write().await.unwrap();
+  assert!(result.errors.is_empty(), "failed to bundle: {:?}", result.errors);
+}
@@ -1,27 +1,46 @@
+use std::cell::Cell;
+
 use oxc::{
   ast::{
     ast::{self, Expression, IdentifierReference, MemberExpression},
     visit::walk,
     Visit,
   },
+  semantic::{ScopeFlags, ScopeId},
   span::{GetSpan, Span},
 };
-use rolldown_common::ImportKind;
+use rolldown_common::{EcmaModuleAstUsage, ImportKind, StmtInfoMeta};
 use rolldown_ecmascript::ToSourceString;
 use rolldown_error::BuildDiagnostic;
+use rolldown_plugin_dynamic_import_vars::{has_ignore_comment, to_glob_pattern};
 use rolldown_std_utils::OptionExt;
 
 use crate::utils::call_expression_ext::CallExpressionExt;
 
-use super::{side_effect_detector::SideEffectDetector, AstScanner};
+use super::{
+  side_effect_detector::{collect_no_side_effects_functions, SideEffectDetector},
+  AstScanner,
+};
 
 impl<'me, 'ast> Visit<'ast> for AstScanner<'me> {
   fn visit_program(&mut self, program: &ast::Program<'ast>) {
+    if self.annotations {
+      self.no_side_effects_functions =
+        collect_no_side_effects_functions(program, self.comments, self.source);
+    }
     for (idx, stmt) in program.body.iter().enumerate() {
       self.current_stmt_info.stmt_idx = Some(idx);
-      self.current_stmt_info.side_effect =
-        SideEffectDetector::new(self.scopes, self.source, self.comments)
-          .detect_side_effect_of_stmt(stmt);
+      self.current_stmt_info.side_effect = SideEffectDetector::new(
+        self.scopes,
+        self.source,
+        self.comments,
+        self.manual_pure_functions,
+        self.property_read_side_effects,
+        self.annotations,
+        &self.no_side_effects_functions,
+        self.unknown_global_side_effects,
+      )
+      .detect_side_effect_of_stmt(stmt);
 
       if cfg!(debug_assertions) {
         self.current_stmt_info.debug_label = Some(stmt.to_source_string());
@@ -110,17 +129,94 @@ impl<'me, 'ast> Visit<'ast> for AstScanner<'me> {
         expr.source.span().is_empty(),
       );
       self.result.imports.insert(expr.span, id);
+    } else if matches!(expr.source, ast::Expression::TemplateLiteral(_))
+      && !has_ignore_comment(self.source, expr.span)
+    {
+      // The dynamic import vars plugin has expanded the template literals matching the modules it
+      // could enumerate, so the remaining ones are too dynamic to be bundled.
+      if let Err(err) = to_glob_pattern(&expr.source) {
+        self.result.warnings.push(
+          BuildDiagnostic::unanalyzable_dynamic_import(
+            self.file_path.to_string(),
+            self.source.clone(),
+            expr.source.span(),
+            err.to_string(),
+          )
+          .with_severity_warning(),
+        );
+      }
     }
     walk::walk_import_expression(self, expr);
   }
 
+  fn visit_function(&mut self, it: &ast::Function<'ast>, flags: ScopeFlags) {
+    if it.id.is_some() {
+      self.current_stmt_info.meta.insert(StmtInfoMeta::HAS_NAMED_FN_OR_CLASS);
+    }
+    walk::walk_function(self, it, flags);
+  }
+
+  fn visit_class(&mut self, it: &ast::Class<'ast>) {
+    if it.id.is_some() {
+      self.current_stmt_info.meta.insert(StmtInfoMeta::HAS_NAMED_FN_OR_CLASS);
+    }
+    walk::walk_class(self, it);
+  }
+
+  fn visit_variable_declarator(&mut self, it: &ast::VariableDeclarator<'ast>) {
+    // `const foo = () => {}` names the function `foo`.
+    if matches!(it.id.kind, ast::BindingPatternKind::BindingIdentifier(_))
+      && matches!(
+        it.init,
+        Some(
+          Expression::ArrowFunctionExpression(_)
+            | Expression::FunctionExpression(_)
+            | Expression::ClassExpression(_)
+        )
+      )
+    {
+      self.current_stmt_info.meta.insert(StmtInfoMeta::HAS_NAMED_FN_OR_CLASS);
+    }
+    walk::walk_variable_declarator(self, it);
+  }
+
   fn visit_declaration(&mut self, it: &ast::Declaration<'ast>) {
     if let ast::Declaration::ClassDeclaration(class) = it {
       self.scan_class_declaration(class);
     }
     walk::walk_declaration(self, it);
   }
+
+  fn enter_scope(&mut self, flags: ScopeFlags, _scope_id: &Cell<Option<ScopeId>>) {
+    self.scope_stack.push(flags);
+  }
+
+  fn leave_scope(&mut self) {
+    self.scope_stack.pop();
+  }
+
+  fn visit_await_expression(&mut self, expr: &ast::AwaitExpression<'ast>) {
+    if self.is_top_level() {
+      self.ast_usage.insert(EcmaModuleAstUsage::TopLevelAwait);
+    }
+    walk::walk_await_expression(self, expr);
+  }
+
+  fn visit_for_of_statement(&mut self, stmt: &ast::ForOfStatement<'ast>) {
+    if stmt.r#await && self.is_top_level() {
+      self.ast_usage.insert(EcmaModuleAstUsage::TopLevelAwait);
+    }
+    walk::walk_for_of_statement(self, stmt);
+  }
   fn visit_assignment_expression(&mut self, node: &ast::AssignmentExpression<'ast>) {
+    // Detect `exports.__esModule = ...`
+    if let ast::AssignmentTarget::StaticMemberExpression(member_expr) = &node.left {
+      if member_expr.property.name == "__esModule"
+        && self.is_cjs_exports_object(&member_expr.object)
+      {
+        self.ast_usage.insert(EcmaModuleAstUsage::EsModuleMarker);
+      }
+    }
     match &node.left {
       ast::AssignmentTarget::AssignmentTargetIdentifier(id_ref) => {
         self.try_diagnostic_forbid_const_assign(id_ref);
@@ -171,6 +267,20 @@ impl<'me, 'ast> Visit<'ast> for AstScanner<'me> {
       }
       _ => {}
     }
+    // Detect `Object.defineProperty(exports, '__esModule', ...)`
+    if let Expression::StaticMemberExpression(callee) = &expr.callee {
+      if let (Expression::Identifier(object), [target, ast::Argument::StringLiteral(key), ..]) =
+        (&callee.object, expr.arguments.as_slice())
+      {
+        if object.name == "Object"
+          && callee.property.name == "defineProperty"
+          && key.value == "__esModule"
+          && target.as_expression().is_some_and(|target| self.is_cjs_exports_object(target))
+        {
+          self.ast_usage.insert(EcmaModuleAstUsage::EsModuleMarker);
+        }
+      }
+    }
     if expr.is_global_require_call(self.scopes) {
       if let Some(ast::Argument::StringLiteral(request)) = &expr.arguments.first() {
         let id = self.add_import_record(
@@ -180,14 +290,93 @@ impl<'me, 'ast> Visit<'ast> for AstScanner<'me> {
           request.span().is_empty(),
         );
         self.result.imports.insert(expr.span, id);
+      } else {
+        self.current_stmt_info.meta.insert(StmtInfoMeta::HAS_DYNAMIC_REQUIRE);
       }
     }
 
     walk::walk_call_expression(self, expr);
   }
+
+  fn visit_new_expression(&mut self, expr: &ast::NewExpression<'ast>) {
+    if let Some((url, request)) = self.worker_url(expr) {
+      let id = self.add_import_record(
+        request.value.as_str(),
+        ImportKind::NewUrl,
+        request.span.start,
+        request.span.is_empty(),
+      );
+      self.result.imports.insert(url.span, id);
+    }
+    walk::walk_new_expression(self, expr);
+  }
 }
 
 impl<'me> AstScanner<'me> {
+  fn is_top_level(&self) -> bool {
+    !self.scope_stack.iter().any(|flags| flags.contains(ScopeFlags::Function))
+  }
+
+  /// Returns `new URL('./worker.js', import.meta.url)` and its string argument if `expr` is
+  /// `new Worker(new URL('./worker.js', import.meta.url), { type: 'module' })` or the same with
+  /// `SharedWorker`. Classic workers can't load the emitted ES module chunk, so they are left as is.
+  fn worker_url<'a, 'ast>(
+    &self,
+    expr: &'a ast::NewExpression<'ast>,
+  ) -> Option<(&'a ast::NewExpression<'ast>, &'a ast::StringLiteral<'ast>)> {
+    let is_global = |ident: &IdentifierReference, name: &str| {
+      ident.name == name && ident.reference_id.get().is_some_and(|id| self.scopes.is_unresolved(id))
+    };
+    let Expression::Identifier(callee) = &expr.callee else {
+      return None;
+    };
+    if !is_global(callee, "Worker") && !is_global(callee, "SharedWorker") {
+      return None;
+    }
+    let [ast::Argument::NewExpression(url), ast::Argument::ObjectExpression(options), ..] =
+      expr.arguments.as_slice()
+    else {
+      return None;
+    };
+    let is_module_type = options.properties.iter().any(|prop| match prop {
+      ast::ObjectPropertyKind::ObjectProperty(prop) => {
+        prop.key.static_name().is_some_and(|name| name == "type")
+          && matches!(&prop.value, Expression::StringLiteral(value) if value.value == "module")
+      }
+      ast::ObjectPropertyKind::SpreadProperty(_) => false,
+    });
+    if !is_module_type {
+      return None;
+    }
+    let Expression::Identifier(url_callee) = &url.callee else {
+      return None;
+    };
+    let [ast::Argument::StringLiteral(request), ast::Argument::StaticMemberExpression(base)] =
+      url.arguments.as_slice()
+    else {
+      return None;
+    };
+    let is_import_meta_url = base.property.name == "url"
+      && matches!(&base.object, Expression::MetaProperty(meta)
+        if meta.meta.name == "import" && meta.property.name == "meta");
+    (is_global(url_callee, "URL") && is_import_meta_url).then_some((&**url, &**request))
+  }
+
+  /// Whether `expr` is the global `exports` or `module.exports`.
+  fn is_cjs_exports_object(&mut self, expr: &Expression) -> bool {
+    match expr {
+      Expression::Identifier(id) => {
+        id.name == "exports" && self.resolve_identifier_to_root_symbol(id).is_none()
+      }
+      Expression::StaticMemberExpression(member_expr) => {
+        matches!(&member_expr.object, Expression::Identifier(id) if id.name == "module"
+          && self.resolve_identifier_to_root_symbol(id).is_none())
+          && member_expr.property.name == "exports"
+      }
+      _ => false,
+    }
+  }
+
   /// visit `Class` of declaration
   pub fn scan_class_declaration(&mut self, class: &ast::Class<'_>) {
     let Some(id) = class.id.as_ref() else {
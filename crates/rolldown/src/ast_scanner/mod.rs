@@ -4,12 +4,12 @@ pub mod side_effect_detector;
 use arcstr::ArcStr;
 use oxc::ast::ast;
 use oxc::index::IndexVec;
-use oxc::semantic::{Reference, ReferenceId, SymbolTable};
+use oxc::semantic::{Reference, ReferenceId, ScopeFlags, SymbolTable};
 use oxc::{
   ast::{
     ast::{
       ExportAllDeclaration, ExportDefaultDeclaration, ExportNamedDeclaration, IdentifierReference,
-      ImportDeclaration, ModuleDeclaration, Program,
+      ImportDeclaration, ModuleDeclaration, Program, WithClause,
     },
     Comment, Visit,
   },
@@ -17,15 +17,17 @@ use oxc::{
   span::{CompactStr, GetSpan, Span},
 };
 use rolldown_common::{
-  AstScopes, EcmaModuleAstUsage, ExportsKind, ImportKind, ImportRecordIdx, ImportRecordMeta,
-  LocalExport, MemberExprRef, ModuleDefFormat, ModuleId, ModuleIdx, NamedImport, RawImportRecord,
-  Specifier, StmtInfo, StmtInfos, SymbolRef, SymbolRefDbForModule, SymbolRefFlags,
+  AstScopes, CommonJsOptions, EcmaModuleAstUsage, ExportsKind, ImportAttributes, ImportKind,
+  ImportRecordIdx, ImportRecordMeta, LocalExport, MemberExprRef, ModuleDefFormat, ModuleId,
+  ModuleIdx, NamedImport, PropertyReadSideEffects, RawImportRecord, Specifier, StmtInfo, StmtInfos,
+  SymbolRef, SymbolRefDbForModule, SymbolRefFlags,
 };
 use rolldown_ecmascript::{BindingIdentifierExt, BindingPatternExt};
 use rolldown_error::{BuildDiagnostic, BuildResult, CjsExportSpan};
 use rolldown_rstr::Rstr;
 use rolldown_utils::ecma_script::legitimize_identifier_name;
 use rolldown_utils::path_ext::PathExt;
+use rolldown_utils::pattern_filter::StringOrRegex;
 use rustc_hash::{FxHashMap, FxHashSet};
 use sugar_path::SugarPath;
 
@@ -51,6 +53,7 @@ pub struct ScanResult {
   pub has_star_exports: bool,
 }
 
+#[allow(clippy::struct_excessive_bools)]
 pub struct AstScanner<'me> {
   idx: ModuleIdx,
   source: &'me ArcStr,
@@ -58,6 +61,23 @@ pub struct AstScanner<'me> {
   file_path: &'me ModuleId,
   scopes: &'me AstScopes,
   comments: &'me oxc::allocator::Vec<'me, Comment>,
+  /// Calls declared side-effect free by `treeshake.manualPureFunctions`.
+  manual_pure_functions: &'me [StringOrRegex],
+  /// Whether property reads may have side effects, by `treeshake.propertyReadSideEffects`.
+  property_read_side_effects: PropertyReadSideEffects,
+  /// Whether `/*#__PURE__*/` and `/*#__NO_SIDE_EFFECTS__*/` annotations are respected, by
+  /// `treeshake.annotations`.
+  annotations: bool,
+  /// The top-level functions annotated with `/*#__NO_SIDE_EFFECTS__*/`.
+  no_side_effects_functions: FxHashSet<SymbolId>,
+  /// Whether reading unknown globals may have side effects, by `treeshake.unknownGlobalSideEffects`.
+  unknown_global_side_effects: bool,
+  /// Whether modules using both `import` and `module` or `exports` are CommonJS modules, by
+  /// `commonjs.transformMixedEsModules`.
+  transform_mixed_es_modules: bool,
+  /// Whether the module type from the extension or `package.json` is trusted over the syntax, by
+  /// `commonjs.strictExportsDetection`.
+  strict_exports_detection: bool,
   current_stmt_info: StmtInfo,
   result: ScanResult,
   esm_export_keyword: Option<Span>,
@@ -74,6 +94,8 @@ pub struct AstScanner<'me> {
   /// lhs of AssignmentExpression
   ast_usage: EcmaModuleAstUsage,
   cur_class_decl_and_symbol_referenced_ids: Option<(SymbolId, &'me Vec<ReferenceId>)>,
+  /// Flags of the scopes entered below the root scope, used to tell whether an `await` is top-level.
+  scope_stack: Vec<ScopeFlags>,
 }
 
 impl<'me> AstScanner<'me> {
@@ -87,6 +109,11 @@ impl<'me> AstScanner<'me> {
     source: &'me ArcStr,
     file_path: &'me ModuleId,
     comments: &'me oxc::allocator::Vec<'me, Comment>,
+    manual_pure_functions: &'me [StringOrRegex],
+    property_read_side_effects: PropertyReadSideEffects,
+    annotations: bool,
+    unknown_global_side_effects: bool,
+    commonjs: CommonJsOptions,
   ) -> Self {
     let mut symbol_ref_db = SymbolRefDbForModule::new(symbol_table, idx, scope.root_scope_id());
     // This is used for converting "export default foo;" => "var default_symbol = foo;"
@@ -133,8 +160,16 @@ impl<'me> AstScanner<'me> {
       source,
       file_path,
       comments,
+      manual_pure_functions,
+      property_read_side_effects,
+      annotations,
+      no_side_effects_functions: FxHashSet::default(),
+      unknown_global_side_effects,
+      transform_mixed_es_modules: commonjs.transform_mixed_es_modules(),
+      strict_exports_detection: commonjs.strict_exports_detection(),
       ast_usage: EcmaModuleAstUsage::empty(),
       cur_class_decl_and_symbol_referenced_ids: None,
+      scope_stack: vec![],
     }
   }
 
@@ -168,7 +203,9 @@ impl<'me> AstScanner<'me> {
           .with_severity_warning(),
         );
       }
-    } else if self.ast_usage.intersects(EcmaModuleAstUsage::ModuleOrExports) {
+    } else if self.ast_usage.intersects(EcmaModuleAstUsage::ModuleOrExports)
+      && !self.is_esm_regardless_of_cjs_usage()
+    {
       exports_kind = ExportsKind::CommonJs;
     } else {
       // TODO(hyf0): Should add warnings if the module type doesn't satisfy the exports kind.
@@ -216,6 +253,15 @@ impl<'me> AstScanner<'me> {
     Ok(self.result)
   }
 
+  /// Whether the module is an ES module even if it uses `module` or `exports`, which are left as global
+  /// variables then.
+  fn is_esm_regardless_of_cjs_usage(&self) -> bool {
+    let is_mixed_es_module = self.esm_import_keyword.is_some() && !self.transform_mixed_es_modules;
+    let is_esm_by_module_type =
+      matches!(self.module_type, ModuleDefFormat::EsmMjs | ModuleDefFormat::EsmPackageJson);
+    is_mixed_es_module || (is_esm_by_module_type && self.strict_exports_detection)
+  }
+
   fn set_esm_export_keyword(&mut self, span: Span) {
     self.esm_export_keyword.get_or_insert(span);
   }
@@ -258,6 +304,27 @@ impl<'me> AstScanner<'me> {
     id
   }
 
+  /// Records `with { type: 'json' }` of the import. The deprecated `assert { type: 'json' }` is treated
+  /// the same.
+  fn set_import_attributes(
+    &mut self,
+    record_id: ImportRecordIdx,
+    with_clause: Option<&WithClause>,
+  ) {
+    let Some(with_clause) = with_clause else {
+      return;
+    };
+    if with_clause.with_entries.is_empty() {
+      return;
+    }
+    let attributes = with_clause
+      .with_entries
+      .iter()
+      .map(|entry| (Rstr::new(entry.key.as_atom().as_str()), Rstr::new(entry.value.value.as_str())))
+      .collect();
+    self.result.import_records[record_id].attributes = Some(ImportAttributes::new(attributes));
+  }
+
   fn add_named_import(
     &mut self,
     local: SymbolId,
@@ -407,6 +474,7 @@ impl<'me> AstScanner<'me> {
       decl.source.span().start,
       decl.source.span().is_empty(),
     );
+    self.set_import_attributes(id, decl.with_clause.as_deref());
     if let Some(exported) = &decl.exported {
       // export * as ns from '...'
       self.add_star_re_export(exported.name().as_str(), id, decl.span);
@@ -426,6 +494,7 @@ impl<'me> AstScanner<'me> {
         source.span().start,
         source.span().is_empty(),
       );
+      self.set_import_attributes(record_id, decl.with_clause.as_deref());
       decl.specifiers.iter().for_each(|spec| {
         self.add_re_export(
           spec.exported.name().as_str(),
@@ -514,6 +583,7 @@ impl<'me> AstScanner<'me> {
       decl.source.span().start,
       decl.source.span().is_empty(),
     );
+    self.set_import_attributes(rec_id, decl.with_clause.as_deref());
     self.result.imports.insert(decl.span, rec_id);
     // // `import '...'` or `import {} from '...'`
     if decl.specifiers.as_ref().map_or(true, |s| s.is_empty()) {
@@ -1,8 +1,16 @@
 use daachorse::DoubleArrayAhoCorasick;
-use oxc::span::Span;
+use oxc::{
+  ast::ast::{
+    BindingPatternKind, Comment, Declaration, ExportDefaultDeclarationKind, Expression, Program,
+    Statement, VariableDeclaration,
+  },
+  semantic::SymbolId,
+  span::{GetSpan, Span},
+};
+use rustc_hash::FxHashSet;
 use std::sync::LazyLock;
 
-use super::SideEffectDetector;
+use super::{utils::leading_comment, SideEffectDetector};
 
 static PURE_COMMENTS: LazyLock<DoubleArrayAhoCorasick<usize>> = LazyLock::new(|| {
   let patterns = vec!["@__PURE__", "#__PURE__"];
@@ -10,6 +18,12 @@ static PURE_COMMENTS: LazyLock<DoubleArrayAhoCorasick<usize>> = LazyLock::new(||
   DoubleArrayAhoCorasick::new(patterns).unwrap()
 });
 
+static NO_SIDE_EFFECTS_COMMENTS: LazyLock<DoubleArrayAhoCorasick<usize>> = LazyLock::new(|| {
+  let patterns = vec!["@__NO_SIDE_EFFECTS__", "#__NO_SIDE_EFFECTS__"];
+
+  DoubleArrayAhoCorasick::new(patterns).unwrap()
+});
+
 impl<'a> SideEffectDetector<'a> {
   /// Comments containing @__PURE__ or #__PURE__ mark a specific function call
   /// or constructor invocation as side effect free.
@@ -23,10 +37,98 @@ impl<'a> SideEffectDetector<'a> {
   /// <https://rollupjs.org/configuration-options/#pure>
   /// Derived from https://github.com/oxc-project/oxc/blob/147864cfeb112df526bb83d5b8671b465c005066/crates/oxc_linter/src/utils/tree_shaking.rs#L162-L171
   pub fn is_pure_function_or_constructor_call(&self, span: Span) -> bool {
+    if !self.annotations {
+      return false;
+    }
     let leading_comment = self.leading_comment_for(span);
 
     leading_comment.map_or(false, |(_comment, comment_text)| {
       PURE_COMMENTS.find_iter(comment_text).next().is_some()
     })
   }
+
+  /// Whether `callee` is a function declared side-effect free by `/*#__NO_SIDE_EFFECTS__*/`.
+  pub fn is_no_side_effects_callee(&self, callee: &Expression) -> bool {
+    match callee {
+      Expression::Identifier(ident) => ident
+        .reference_id
+        .get()
+        .and_then(|reference_id| self.scope.symbol_id_for(reference_id))
+        .is_some_and(|symbol_id| self.no_side_effects_functions.contains(&symbol_id)),
+      _ => false,
+    }
+  }
+}
+
+/// Collects the top-level functions annotated with `/*#__NO_SIDE_EFFECTS__*/`, whose calls are side
+/// effect free. The annotation may precede a function declaration, an `export` of it, or a function
+/// expression assigned to a variable, as well as the declaration of such a variable.
+///
+/// <https://github.com/javascript-compiler-hints/compiler-notations-spec/blob/main/no-side-effects-notation-spec.md>
+pub fn collect_no_side_effects_functions(
+  program: &Program,
+  comments: &[Comment],
+  source: &str,
+) -> FxHashSet<SymbolId> {
+  let is_annotated = |span: Span| {
+    leading_comment(comments, source, span).is_some_and(|(_comment, comment_text)| {
+      NO_SIDE_EFFECTS_COMMENTS.find_iter(comment_text).next().is_some()
+    })
+  };
+  let mut functions = FxHashSet::default();
+  for stmt in &program.body {
+    match stmt {
+      Statement::FunctionDeclaration(func) if is_annotated(func.span) => {
+        functions.extend(func.id.as_ref().and_then(|id| id.symbol_id.get()));
+      }
+      Statement::VariableDeclaration(decl) => {
+        add_var_decl_functions(decl, false, &is_annotated, &mut functions);
+      }
+      Statement::ExportNamedDeclaration(export) => {
+        let annotated = is_annotated(export.span);
+        match &export.declaration {
+          Some(Declaration::FunctionDeclaration(func)) if annotated || is_annotated(func.span) => {
+            functions.extend(func.id.as_ref().and_then(|id| id.symbol_id.get()));
+          }
+          Some(Declaration::VariableDeclaration(decl)) => {
+            add_var_decl_functions(decl, annotated, &is_annotated, &mut functions);
+          }
+          _ => {}
+        }
+      }
+      Statement::ExportDefaultDeclaration(export) => match &export.declaration {
+        ExportDefaultDeclarationKind::FunctionDeclaration(func)
+          if is_annotated(export.span) || is_annotated(func.span) =>
+        {
+          functions.extend(func.id.as_ref().and_then(|id| id.symbol_id.get()));
+        }
+        _ => {}
+      },
+      _ => {}
+    }
+  }
+  functions
+}
+
+fn add_var_decl_functions(
+  decl: &VariableDeclaration,
+  annotated: bool,
+  is_annotated: &impl Fn(Span) -> bool,
+  functions: &mut FxHashSet<SymbolId>,
+) {
+  let annotated = annotated || is_annotated(decl.span);
+  for declarator in &decl.declarations {
+    let BindingPatternKind::BindingIdentifier(id) = &declarator.id.kind else {
+      continue;
+    };
+    let is_annotated_function = match &declarator.init {
+      Some(init @ (Expression::FunctionExpression(_) | Expression::ArrowFunctionExpression(_))) => {
+        annotated || is_annotated(init.span())
+      }
+      _ => false,
+    };
+    if is_annotated_function {
+      functions.extend(id.symbol_id.get());
+    }
+  }
 }
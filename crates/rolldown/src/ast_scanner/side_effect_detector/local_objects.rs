@@ -0,0 +1,161 @@
+use oxc::{
+  ast::ast::{
+    Argument, AssignmentTarget, BindingPatternKind, CallExpression, Expression,
+    IdentifierReference, LogicalOperator, MemberExpression,
+  },
+  semantic::SymbolId,
+};
+
+use super::SideEffectDetector;
+
+impl<'a> SideEffectDetector<'a> {
+  /// Returns the side effects of an immediately invoked function expression, or `None` if `expr`
+  /// isn't one. The body of the function may mutate the parameters bound to objects created by the
+  /// call, such as `var E = function (E) { E[E["A"] = 0] = "A"; return E; }(E || {})` emitted for
+  /// TypeScript enums, where `declared` is the symbol of `E`.
+  pub fn detect_side_effect_of_iife(
+    &mut self,
+    expr: &CallExpression,
+    declared: Option<SymbolId>,
+  ) -> Option<bool> {
+    // Awaiting the result of an async function may call a `then` getter of it.
+    let (params, body) = match expr.callee.without_parentheses() {
+      Expression::FunctionExpression(func) if !func.r#async && !func.generator => {
+        (&func.params, func.body.as_ref()?)
+      }
+      Expression::ArrowFunctionExpression(arrow) if !arrow.r#async => (&arrow.params, &arrow.body),
+      _ => return None,
+    };
+    // Default values and destructuring run code before the body.
+    if params.rest.is_some()
+      || params
+        .items
+        .iter()
+        .any(|param| !matches!(param.pattern.kind, BindingPatternKind::BindingIdentifier(_)))
+    {
+      return Some(true);
+    }
+
+    let mut local_params = vec![];
+    for (idx, arg) in expr.arguments.iter().enumerate() {
+      let Some(arg) = arg.as_expression() else {
+        return Some(true);
+      };
+      if self.detect_side_effect_of_expr(arg) {
+        return Some(true);
+      }
+      if let (true, Some(BindingPatternKind::BindingIdentifier(id))) = (
+        self.is_created_object(arg, declared),
+        params.items.get(idx).map(|param| &param.pattern.kind),
+      ) {
+        local_params.extend(id.symbol_id.get());
+      }
+    }
+
+    let local_objects_len = self.local_objects.len();
+    self.local_objects.extend(local_params);
+    let side_effect = body.statements.iter().any(|stmt| self.detect_side_effect_of_stmt(stmt));
+    self.local_objects.truncate(local_objects_len);
+    Some(side_effect)
+  }
+
+  /// Returns the side effects of `Object.freeze`, `Object.defineProperty` and similar calls, or
+  /// `None` if `expr` isn't one. They only mutate their first argument, which must be a local object.
+  pub fn detect_side_effect_of_object_helper_call(
+    &mut self,
+    expr: &CallExpression,
+  ) -> Option<bool> {
+    let Expression::StaticMemberExpression(callee) = &expr.callee else {
+      return None;
+    };
+    let Expression::Identifier(object) = &callee.object else {
+      return None;
+    };
+    if object.name != "Object" || !self.is_unresolved_reference(object) {
+      return None;
+    }
+    // Non-object descriptors make the call throw.
+    let descriptor_idx = match callee.property.name.as_str() {
+      "freeze" | "seal" | "preventExtensions" => None,
+      "defineProperty" => Some(2),
+      "defineProperties" => Some(1),
+      _ => return None,
+    };
+    let Some(target) = expr.arguments.first().and_then(Argument::as_expression) else {
+      return Some(true);
+    };
+    if !self.is_local_object(target) {
+      return Some(true);
+    }
+    Some(expr.arguments.iter().enumerate().skip(1).any(|(idx, arg)| match arg.as_expression() {
+      Some(arg) => {
+        (Some(idx) == descriptor_idx
+          && !matches!(arg.without_parentheses(), Expression::ObjectExpression(_)))
+          || self.detect_side_effect_of_expr(arg)
+      }
+      None => true,
+    }))
+  }
+
+  /// `E[E["A"] = 0] = "A"` only evaluates the computed key if `E` is a local object. Returns `None`
+  /// for other targets.
+  pub fn detect_side_effect_of_local_object_member_target(
+    &mut self,
+    target: &AssignmentTarget,
+  ) -> Option<bool> {
+    let member_expr = target.as_member_expression()?;
+    let Expression::Identifier(object) = member_expr.object() else {
+      return None;
+    };
+    if !self.is_local_object_ref(object) {
+      return None;
+    }
+    Some(match member_expr {
+      MemberExpression::ComputedMemberExpression(expr) => {
+        self.detect_side_effect_of_expr(&expr.expression)
+      }
+      MemberExpression::StaticMemberExpression(_) => false,
+      MemberExpression::PrivateFieldExpression(_) => true,
+    })
+  }
+
+  /// Whether `expr` evaluates to an object that no other code can observe yet.
+  fn is_local_object(&mut self, expr: &Expression) -> bool {
+    match expr.without_parentheses() {
+      Expression::ObjectExpression(_) | Expression::ArrayExpression(_) => {
+        !self.detect_side_effect_of_expr(expr)
+      }
+      Expression::Identifier(ident) => self.is_local_object_ref(ident),
+      Expression::CallExpression(call) => {
+        self.detect_side_effect_of_object_helper_call(call) == Some(false)
+      }
+      _ => false,
+    }
+  }
+
+  fn is_local_object_ref(&self, ident: &IdentifierReference) -> bool {
+    self.symbol_id_of(ident).is_some_and(|symbol_id| self.local_objects.contains(&symbol_id))
+  }
+
+  /// `{}`, or `E || {}` if `E` is `declared`.
+  fn is_created_object(&self, expr: &Expression, declared: Option<SymbolId>) -> bool {
+    match expr.without_parentheses() {
+      Expression::ObjectExpression(_) => true,
+      Expression::LogicalExpression(logical_expr)
+        if logical_expr.operator == LogicalOperator::Or =>
+      {
+        match (logical_expr.left.without_parentheses(), logical_expr.right.without_parentheses()) {
+          (Expression::Identifier(ident), Expression::ObjectExpression(_)) => {
+            declared.is_some() && self.symbol_id_of(ident) == declared
+          }
+          _ => false,
+        }
+      }
+      _ => false,
+    }
+  }
+
+  fn symbol_id_of(&self, ident: &IdentifierReference) -> Option<SymbolId> {
+    self.scope.symbol_id_for(ident.reference_id.get()?)
+  }
+}
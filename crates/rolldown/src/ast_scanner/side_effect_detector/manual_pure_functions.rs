@@ -0,0 +1,35 @@
+use oxc::ast::ast::Expression;
+use rolldown_utils::pattern_filter::StringOrRegex;
+
+use super::SideEffectDetector;
+
+impl<'a> SideEffectDetector<'a> {
+  /// Whether calling `callee` is declared side-effect free by `treeshake.manualPureFunctions`.
+  pub fn is_manual_pure_callee(&self, callee: &Expression) -> bool {
+    if self.manual_pure_functions.is_empty() {
+      return false;
+    }
+    let Some(path) = callee_path(callee) else {
+      return false;
+    };
+    self.manual_pure_functions.iter().any(|pattern| match pattern {
+      StringOrRegex::String(name) => path
+        .strip_prefix(name.as_str())
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('.')),
+      StringOrRegex::Regex(regex) => regex.matches(&path),
+    })
+  }
+}
+
+/// The dotted name of a callee, such as `styled.div`. Callees that call a function, such as
+/// `styled(Button)`, are named after that function.
+fn callee_path(expr: &Expression) -> Option<String> {
+  match expr {
+    Expression::Identifier(ident) => Some(ident.name.to_string()),
+    Expression::StaticMemberExpression(member_expr) => callee_path(&member_expr.object)
+      .map(|object| format!("{object}.{}", member_expr.property.name)),
+    Expression::CallExpression(call_expr) => callee_path(&call_expr.callee),
+    Expression::ParenthesizedExpression(paren_expr) => callee_path(&paren_expr.expression),
+    _ => None,
+  }
+}
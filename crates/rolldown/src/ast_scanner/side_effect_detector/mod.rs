@@ -7,16 +7,23 @@ use oxc::ast::ast::{
   PropertyKey, VariableDeclarationKind,
 };
 use oxc::ast::{match_expression, match_member_expression};
-use rolldown_common::AstScopes;
+use oxc::semantic::SymbolId;
+use rolldown_common::{AstScopes, PropertyReadSideEffects};
 use rolldown_utils::global_reference::{
   is_global_ident_ref, is_side_effect_free_member_expr_of_len_three,
   is_side_effect_free_member_expr_of_len_two,
 };
+use rolldown_utils::pattern_filter::StringOrRegex;
+use rustc_hash::FxHashSet;
 use utils::{can_change_strict_to_loose, is_side_effect_free_unbound_identifier_ref};
 
 use self::utils::{known_primitive_type, PrimitiveType};
 
 mod annotation;
+mod local_objects;
+mod manual_pure_functions;
+
+pub use annotation::collect_no_side_effects_functions;
 mod utils;
 
 /// Detect if a statement "may" have side effect.
@@ -24,15 +31,41 @@ pub struct SideEffectDetector<'a> {
   pub scope: &'a AstScopes,
   pub source: &'a str,
   pub comments: &'a oxc::allocator::Vec<'a, Comment>,
+  pub manual_pure_functions: &'a [StringOrRegex],
+  pub property_read_side_effects: PropertyReadSideEffects,
+  /// Whether `/*#__PURE__*/` and `/*#__NO_SIDE_EFFECTS__*/` annotations are respected.
+  pub annotations: bool,
+  /// The functions annotated with `/*#__NO_SIDE_EFFECTS__*/`.
+  pub no_side_effects_functions: &'a FxHashSet<SymbolId>,
+  /// Whether reading a global variable that isn't known to exist may throw.
+  pub unknown_global_side_effects: bool,
+  /// The parameters of the enclosing IIFEs bound to objects created by the calls.
+  local_objects: Vec<SymbolId>,
 }
 
 impl<'a> SideEffectDetector<'a> {
+  #[allow(clippy::too_many_arguments)]
   pub fn new(
     scope: &'a AstScopes,
     source: &'a str,
     comments: &'a oxc::allocator::Vec<'a, Comment>,
+    manual_pure_functions: &'a [StringOrRegex],
+    property_read_side_effects: PropertyReadSideEffects,
+    annotations: bool,
+    no_side_effects_functions: &'a FxHashSet<SymbolId>,
+    unknown_global_side_effects: bool,
   ) -> Self {
-    Self { scope, source, comments }
+    Self {
+      scope,
+      source,
+      comments,
+      manual_pure_functions,
+      property_read_side_effects,
+      annotations,
+      no_side_effects_functions,
+      unknown_global_side_effects,
+      local_objects: vec![],
+    }
   }
 
   fn is_unresolved_reference(&self, ident_ref: &IdentifierReference) -> bool {
@@ -68,6 +101,13 @@ impl<'a> SideEffectDetector<'a> {
     if !cls.decorators.is_empty() {
       return true;
     }
+    if cls
+      .super_class
+      .as_ref()
+      .is_some_and(|super_class| self.detect_side_effect_of_class_heritage(super_class))
+    {
+      return true;
+    }
     cls.body.body.iter().any(|elm| match elm {
       ClassElement::StaticBlock(static_block) => {
         static_block.body.iter().any(|stmt| self.detect_side_effect_of_stmt(stmt))
@@ -106,15 +146,51 @@ impl<'a> SideEffectDetector<'a> {
     })
   }
 
-  fn detect_side_effect_of_member_expr(&self, expr: &ast::MemberExpression) -> bool {
+  /// Reading a member of a declared binding, e.g. `class A extends ns.Base {}` with a namespace import
+  /// `ns`, is assumed to be side effect free. Other heritage expressions are checked as usual.
+  fn detect_side_effect_of_class_heritage(&mut self, super_class: &Expression) -> bool {
+    if let Some(member_expr) = super_class.as_member_expression() {
+      if let Some((ref_id, _)) = extract_member_expr_chain(member_expr, usize::MAX) {
+        if !self.scope.is_unresolved(ref_id) {
+          return false;
+        }
+      }
+    }
+    self.detect_side_effect_of_expr(super_class)
+  }
+
+  fn detect_side_effect_of_member_expr(&mut self, expr: &ast::MemberExpression) -> bool {
+    match self.property_read_side_effects {
+      PropertyReadSideEffects::Always => return true,
+      // Without getters, reading a property only evaluates the object and the computed key.
+      PropertyReadSideEffects::Boolean(false) => {
+        return match expr {
+          ast::MemberExpression::ComputedMemberExpression(expr) => {
+            self.detect_side_effect_of_expr(&expr.object)
+              || self.detect_side_effect_of_expr(&expr.expression)
+          }
+          ast::MemberExpression::StaticMemberExpression(expr) => {
+            self.detect_side_effect_of_expr(&expr.object)
+          }
+          ast::MemberExpression::PrivateFieldExpression(expr) => {
+            self.detect_side_effect_of_expr(&expr.object)
+          }
+        };
+      }
+      PropertyReadSideEffects::Boolean(true) => {}
+    }
     // MemberExpression is considered having side effect by default, unless it's some builtin global variables.
-    let Some((ref_id, chains)) = extract_member_expr_chain(expr, 3) else {
+    let max_len = if self.unknown_global_side_effects { 3 } else { usize::MAX };
+    let Some((ref_id, chains)) = extract_member_expr_chain(expr, max_len) else {
       return true;
     };
     // If the global variable is override, we considered it has side effect.
     if !self.scope.is_unresolved(ref_id) {
       return true;
     }
+    if !self.unknown_global_side_effects {
+      return false;
+    }
     match chains.len() {
       2 => !is_side_effect_free_member_expr_of_len_two(&chains),
       3 => !is_side_effect_free_member_expr_of_len_three(&chains),
@@ -122,7 +198,10 @@ impl<'a> SideEffectDetector<'a> {
     }
   }
 
-  fn detect_side_effect_of_assignment_target(expr: &AssignmentTarget) -> bool {
+  fn detect_side_effect_of_assignment_target(&mut self, expr: &AssignmentTarget) -> bool {
+    if let Some(side_effect) = self.detect_side_effect_of_local_object_member_target(expr) {
+      return side_effect;
+    }
     let Some(pattern) = expr.as_assignment_target_pattern() else {
       return true;
     };
@@ -139,14 +218,28 @@ impl<'a> SideEffectDetector<'a> {
   }
 
   fn detect_side_effect_of_call_expr(&mut self, expr: &CallExpression) -> bool {
-    let is_pure = self.is_pure_function_or_constructor_call(expr.span);
+    if self.is_manual_pure_callee(&expr.callee) {
+      // `styled(a)(b)` calls the result of `styled(a)`, which may have side effects by its arguments.
+      let callee_side_effect = matches!(expr.callee, Expression::CallExpression(_))
+        && self.detect_side_effect_of_expr(&expr.callee);
+      return callee_side_effect
+        || expr.arguments.iter().any(|arg| match arg {
+          Argument::SpreadElement(_) => true,
+          _ => self.detect_side_effect_of_expr(arg.to_expression()),
+        });
+    }
+    let is_pure = self.is_pure_function_or_constructor_call(expr.span)
+      || (self.annotations && self.is_no_side_effects_callee(&expr.callee));
     if is_pure {
       expr.arguments.iter().any(|arg| match arg {
         Argument::SpreadElement(_) => true,
         _ => self.detect_side_effect_of_expr(arg.to_expression()),
       })
     } else {
-      true
+      self
+        .detect_side_effect_of_object_helper_call(expr)
+        .or_else(|| self.detect_side_effect_of_iife(expr, None))
+        .unwrap_or(true)
     }
   }
 
@@ -305,7 +398,7 @@ impl<'a> SideEffectDetector<'a> {
         self.detect_side_effect_of_expr(&private_in_expr.right)
       }
       Expression::AssignmentExpression(expr) => {
-        Self::detect_side_effect_of_assignment_target(&expr.left)
+        self.detect_side_effect_of_assignment_target(&expr.left)
           || self.detect_side_effect_of_expr(&expr.right)
       }
 
@@ -319,10 +412,17 @@ impl<'a> SideEffectDetector<'a> {
       Expression::Super(_)
       | Expression::AwaitExpression(_)
       | Expression::ImportExpression(_)
-      | Expression::TaggedTemplateExpression(_)
       | Expression::UpdateExpression(_)
       | Expression::YieldExpression(_) => true,
 
+      // Tagged templates are only pure if their tag is listed in `treeshake.manualPureFunctions`.
+      Expression::TaggedTemplateExpression(expr) => {
+        !self.is_manual_pure_callee(&expr.tag)
+          || (matches!(expr.tag, Expression::CallExpression(_))
+            && self.detect_side_effect_of_expr(&expr.tag))
+          || expr.quasi.expressions.iter().any(|expr| self.detect_side_effect_of_expr(expr))
+      }
+
       Expression::JSXElement(_) | Expression::JSXFragment(_) => {
         unreachable!("jsx should be transpiled")
       }
@@ -335,7 +435,8 @@ impl<'a> SideEffectDetector<'a> {
         }
       }),
       Expression::NewExpression(expr) => {
-        let is_pure = self.is_pure_function_or_constructor_call(expr.span);
+        let is_pure = self.is_pure_function_or_constructor_call(expr.span)
+          || self.is_manual_pure_callee(&expr.callee);
         if is_pure {
           expr.arguments.iter().any(|arg| match arg {
             Argument::SpreadElement(_) => true,
@@ -369,8 +470,19 @@ impl<'a> SideEffectDetector<'a> {
           BindingPatternKind::ArrayPattern(_) | BindingPatternKind::ObjectPattern(_)
         );
 
-        is_destructuring
-          || declarator.init.as_ref().is_some_and(|init| self.detect_side_effect_of_expr(init))
+        if is_destructuring {
+          return true;
+        }
+        // `var E = function (E) { ... }(E || {})`
+        if let (BindingPatternKind::BindingIdentifier(id), Some(Expression::CallExpression(call))) =
+          (&declarator.id.kind, declarator.init.as_ref().map(Expression::without_parentheses))
+        {
+          // Otherwise the call is checked as usual, it might still be annotated with `/*#__PURE__*/`.
+          if self.detect_side_effect_of_iife(call, id.symbol_id.get()) == Some(false) {
+            return false;
+          }
+        }
+        declarator.init.as_ref().is_some_and(|init| self.detect_side_effect_of_expr(init))
       }),
     }
   }
@@ -406,7 +518,9 @@ impl<'a> SideEffectDetector<'a> {
 
   #[inline]
   fn detect_side_effect_of_identifier(&self, ident_ref: &IdentifierReference) -> bool {
-    self.is_unresolved_reference(ident_ref) && !is_global_ident_ref(&ident_ref.name)
+    self.unknown_global_side_effects
+      && self.is_unresolved_reference(ident_ref)
+      && !is_global_ident_ref(&ident_ref.name)
   }
 
   #[allow(clippy::too_many_lines)]
@@ -418,12 +532,12 @@ impl<'a> SideEffectDetector<'a> {
       }
       Statement::ExpressionStatement(expr) => self.detect_side_effect_of_expr(&expr.expression),
       oxc::ast::match_module_declaration!(Statement) => match stmt.to_module_declaration() {
-        ast::ModuleDeclaration::ExportAllDeclaration(_) => true,
-        ast::ModuleDeclaration::ImportDeclaration(_) => {
-          // We consider `import ...` has no side effect. However, `import ...` might be rewritten to other statements by the bundler.
-          // In that case, we will mark the statement as having side effect in link stage.
-          false
-        }
+        // We consider `import ...`, `export * from '...'` and `export { ... } from '...'` have no side effect. The
+        // side effects of the imported modules are tracked by the module graph, so a barrel module doesn't pull in its
+        // re-exported modules by itself. However, these statements might be rewritten to other statements by the
+        // bundler. In that case, we will mark the statement as having side effect in link stage.
+        ast::ModuleDeclaration::ExportAllDeclaration(_)
+        | ast::ModuleDeclaration::ImportDeclaration(_) => false,
         ast::ModuleDeclaration::ExportDefaultDeclaration(default_decl) => {
           use oxc::ast::ast::ExportDefaultDeclarationKind;
           match &default_decl.declaration {
@@ -439,17 +553,10 @@ impl<'a> SideEffectDetector<'a> {
             }
           }
         }
-        ast::ModuleDeclaration::ExportNamedDeclaration(named_decl) => {
-          if named_decl.source.is_some() {
-            // `export { ... } from '...'` is considered as side effect.
-            true
-          } else {
-            named_decl
-              .declaration
-              .as_ref()
-              .map_or(false, |decl| self.detect_side_effect_of_decl(decl))
-          }
-        }
+        ast::ModuleDeclaration::ExportNamedDeclaration(named_decl) => named_decl
+          .declaration
+          .as_ref()
+          .map_or(false, |decl| self.detect_side_effect_of_decl(decl)),
         ast::ModuleDeclaration::TSExportAssignment(_)
         | ast::ModuleDeclaration::TSNamespaceExportDeclaration(_) => {
           unreachable!("ts should be transpiled")
@@ -515,12 +622,31 @@ impl<'a> SideEffectDetector<'a> {
 #[cfg(test)]
 mod test {
   use oxc::span::SourceType;
-  use rolldown_common::AstScopes;
+  use rolldown_common::{AstScopes, PropertyReadSideEffects};
   use rolldown_ecmascript::{EcmaAst, EcmaCompiler};
+  use rolldown_utils::{js_regex::HybridRegex, pattern_filter::StringOrRegex};
 
-  use crate::ast_scanner::side_effect_detector::SideEffectDetector;
+  use crate::ast_scanner::side_effect_detector::{
+    collect_no_side_effects_functions, SideEffectDetector,
+  };
 
   fn get_statements_side_effect(code: &str) -> bool {
+    get_statements_side_effect_with_options(
+      code,
+      &[],
+      PropertyReadSideEffects::default(),
+      true,
+      true,
+    )
+  }
+
+  fn get_statements_side_effect_with_options(
+    code: &str,
+    manual_pure_functions: &[StringOrRegex],
+    property_read_side_effects: PropertyReadSideEffects,
+    annotations: bool,
+    unknown_global_side_effects: bool,
+  ) -> bool {
     let source_type = SourceType::tsx();
     let ast = EcmaCompiler::parse("<Noop>", code, source_type).unwrap();
     let ast_scope = {
@@ -533,9 +659,21 @@ mod test {
       )
     };
 
+    let no_side_effects_functions =
+      collect_no_side_effects_functions(ast.program(), ast.comments(), ast.source());
+
     let has_side_effect = ast.program().body.iter().any(|stmt| {
-      SideEffectDetector::new(&ast_scope, ast.source(), ast.comments())
-        .detect_side_effect_of_stmt(stmt)
+      SideEffectDetector::new(
+        &ast_scope,
+        ast.source(),
+        ast.comments(),
+        manual_pure_functions,
+        property_read_side_effects,
+        annotations,
+        &no_side_effects_functions,
+        unknown_global_side_effects,
+      )
+      .detect_side_effect_of_stmt(stmt)
     });
 
     has_side_effect
@@ -837,4 +975,162 @@ mod test {
     assert!(!get_statements_side_effect("const of = { [+1]: 'hi'}"));
     assert!(get_statements_side_effect("const of = { [{}]: 'hi'}"));
   }
+
+  #[test]
+  fn test_manual_pure_functions() {
+    let pure_functions = [
+      StringOrRegex::String("styled".to_string()),
+      StringOrRegex::Regex(HybridRegex::new("^console\\.").unwrap()),
+    ];
+    let detect = |code: &str| {
+      get_statements_side_effect_with_options(
+        code,
+        &pure_functions,
+        PropertyReadSideEffects::default(),
+        true,
+        true,
+      )
+    };
+    assert!(!detect("styled()"));
+    assert!(!detect("styled.div()"));
+    assert!(!detect("styled('button')()"));
+    assert!(!detect("styled.div`color: red`"));
+    assert!(!detect("new styled()"));
+    assert!(!detect("console.log('a')"));
+    // Arguments are still evaluated
+    assert!(detect("styled(a())"));
+    assert!(detect("styled.div(a())"));
+    // Only the names and their properties are covered
+    assert!(detect("styledComponents()"));
+    assert!(detect("console()"));
+    assert!(detect("foo.styled()"));
+  }
+
+  #[test]
+  fn test_property_read_side_effects() {
+    let detect = |code: &str, property_read_side_effects| {
+      get_statements_side_effect_with_options(code, &[], property_read_side_effects, true, true)
+    };
+    let keep = PropertyReadSideEffects::Boolean(true);
+    assert!(detect("let a = {}; let b = a.b", keep));
+    assert!(!detect("let b = Object.assign", keep));
+
+    let drop = PropertyReadSideEffects::Boolean(false);
+    assert!(!detect("let a = {}; let b = a.b.c", drop));
+    assert!(!detect("let a = {}; let b = a?.['b']", drop));
+    // The object and the key are still evaluated
+    assert!(detect("let b = foo.b", drop));
+    assert!(detect("let a = {}; let b = a[foo()]", drop));
+
+    let always = PropertyReadSideEffects::Always;
+    assert!(detect("let b = Object.assign", always));
+  }
+
+  #[test]
+  fn test_annotations() {
+    let detect = |code: &str, annotations: bool| {
+      get_statements_side_effect_with_options(
+        code,
+        &[],
+        PropertyReadSideEffects::default(),
+        annotations,
+        true,
+      )
+    };
+    assert!(!detect("/*#__PURE__*/ foo()", true));
+    assert!(!detect("/*@__PURE__*/ new Foo()", true));
+    assert!(detect("/*#__PURE__*/ foo(bar())", true));
+    assert!(detect("/*#__PURE__*/ foo()", false));
+
+    assert!(!detect("/*#__NO_SIDE_EFFECTS__*/ function foo() {} foo()", true));
+    assert!(!detect("export /*#__NO_SIDE_EFFECTS__*/ function foo() {} foo()", true));
+    assert!(!detect("/*#__NO_SIDE_EFFECTS__*/ export function foo() {} foo()", true));
+    assert!(!detect("export default /*#__NO_SIDE_EFFECTS__*/ function foo() {} foo()", true));
+    assert!(!detect("const foo = /*#__NO_SIDE_EFFECTS__*/ () => {}; foo()", true));
+    assert!(!detect("/*#__NO_SIDE_EFFECTS__*/ const foo = function () {}; foo()", true));
+    assert!(detect("/*#__NO_SIDE_EFFECTS__*/ function foo() {} foo(bar())", true));
+    assert!(detect("/*#__NO_SIDE_EFFECTS__*/ function foo() {} new foo()", true));
+    assert!(detect("function foo() {} foo()", true));
+    assert!(detect("/*#__NO_SIDE_EFFECTS__*/ function foo() {} foo()", false));
+  }
+
+  #[test]
+  fn test_unknown_global_side_effects() {
+    let detect = |code: &str, unknown_global_side_effects: bool| {
+      get_statements_side_effect_with_options(
+        code,
+        &[],
+        PropertyReadSideEffects::default(),
+        true,
+        unknown_global_side_effects,
+      )
+    };
+    assert!(!detect("const matchMedia = window.matchMedia", true));
+    assert!(detect("const $ = jQuery", true));
+    assert!(detect("const element = angular.element", true));
+    assert!(!detect("const $ = jQuery", false));
+    assert!(!detect("const element = angular.element.fn", false));
+    assert!(!detect("const fn = window.$.fn", false));
+    assert!(detect("const element = angular.element()", false));
+    assert!(detect("let a = {}; let b = a.b", false));
+  }
+
+  #[test]
+  fn test_re_exports() {
+    assert!(!get_statements_side_effect("export * from './foo'"));
+    assert!(!get_statements_side_effect("export * as foo from './foo'"));
+    assert!(!get_statements_side_effect("export { foo, bar as baz } from './foo'"));
+  }
+
+  #[test]
+  fn test_class_extends() {
+    assert!(!get_statements_side_effect("class A {}; class B extends A {}"));
+    assert!(!get_statements_side_effect("class A extends class {} { static a = 1 }"));
+    assert!(!get_statements_side_effect("import * as ns from 'ns'; class A extends ns.Base {}"));
+    assert!(get_statements_side_effect("class A extends foo() {}"));
+    assert!(get_statements_side_effect("class A extends foo().Base {}"));
+    assert!(get_statements_side_effect("let B = class extends foo() {}"));
+  }
+
+  #[test]
+  fn test_iife() {
+    // TypeScript enums
+    assert!(!get_statements_side_effect(
+      "var E = function (E) { E[E['A'] = 0] = 'A'; E[E['B'] = 1] = 'B'; return E; }(E || {})"
+    ));
+    assert!(!get_statements_side_effect(
+      "var E = /* @__PURE__ */ ((E) => { E['A'] = 'a'; return E; })(E || {})"
+    ));
+    assert!(!get_statements_side_effect("let E = function (E) { E.A = 0; return E; }({})"));
+    assert!(!get_statements_side_effect("(function () { let a = 1; })()"));
+    assert!(!get_statements_side_effect("(() => {})()"));
+    assert!(get_statements_side_effect("var E = function (E) { E.A = 0; }(foo || {})"));
+    assert!(get_statements_side_effect("let a = {}; (function (E) { E.A = 0; })(a)"));
+    assert!(get_statements_side_effect("(function (E) { E.A = 0; })({}, foo())"));
+    assert!(get_statements_side_effect("(function ({ a }) {})({})"));
+    assert!(get_statements_side_effect("(function (a = foo()) {})()"));
+    assert!(get_statements_side_effect("(function () { foo() })()"));
+    assert!(get_statements_side_effect("let a; (function () { a.b = 1 })()"));
+    assert!(get_statements_side_effect("(async () => ({ get then() { foo() } }))()"));
+    assert!(!get_statements_side_effect("var a = /* @__PURE__ */ (() => foo())()"));
+  }
+
+  #[test]
+  fn test_object_helpers_on_local_objects() {
+    assert!(!get_statements_side_effect("const a = Object.freeze({ a: 1 })"));
+    assert!(!get_statements_side_effect("const a = Object.freeze([1, 2])"));
+    assert!(!get_statements_side_effect("const a = Object.freeze(Object.seal({}))"));
+    assert!(!get_statements_side_effect("const a = Object.defineProperty({}, 'a', { value: 1 })"));
+    assert!(!get_statements_side_effect(
+      "const a = Object.defineProperties({}, { a: { value: 1 } })"
+    ));
+    assert!(!get_statements_side_effect(
+      "var E = function (E) { Object.defineProperty(E, 'A', { value: 0 }); return E; }({})"
+    ));
+    assert!(get_statements_side_effect("Object.defineProperty(exports, 'a', { value: 1 })"));
+    assert!(get_statements_side_effect("let a = {}; Object.freeze(a)"));
+    assert!(get_statements_side_effect("const a = Object.defineProperty({}, 'a', desc)"));
+    assert!(get_statements_side_effect("const a = Object.freeze({ a: foo() })"));
+    assert!(get_statements_side_effect("let Object; const a = Object.freeze({})"));
+  }
 }
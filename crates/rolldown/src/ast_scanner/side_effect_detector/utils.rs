@@ -32,26 +32,39 @@ impl<'a> SideEffectDetector<'a> {
   /// ```
   /// Derived from https://github.com/oxc-project/oxc/blob/147864cfeb112df526bb83d5b8671b465c005066/crates/oxc_linter/src/utils/tree_shaking.rs#L204
   pub fn leading_comment_for(&self, span: Span) -> Option<(&Comment, &str)> {
-    let comment = comments_range(self.comments, ..span.start).next_back()?;
+    leading_comment(self.comments, self.source, span)
+  }
+}
 
-    let comment_text = comment.span.source_text(self.source);
-    // If there are non-whitespace characters between the `comment` and the `span`,
-    // we treat the `comment` not belongs to the `span`.
-    let range_text = Span::new(comment.span.end, span.start).source_text(self.source);
-    let only_whitespace = match comment.kind {
-      CommentKind::Line => range_text.trim().is_empty(),
-      CommentKind::Block => {
-        range_text
-          .strip_prefix("*/") // for multi-line comment
-          .is_some_and(|s| s.trim().is_empty())
-      }
-    };
-    if !only_whitespace {
-      return None;
-    }
+/// See [`SideEffectDetector::leading_comment_for`].
+pub fn leading_comment<'c>(
+  comments: &'c [Comment],
+  source: &'c str,
+  span: Span,
+) -> Option<(&'c Comment, &'c str)> {
+  // `EnsureSpanUniqueness` gives duplicated spans of module declarations made up ones past the source.
+  if span.start as usize > source.len() {
+    return None;
+  }
+  let comment = comments_range(comments, ..span.start).next_back()?;
 
-    Some((comment, comment_text))
+  let comment_text = comment.span.source_text(source);
+  // If there are non-whitespace characters between the `comment` and the `span`,
+  // we treat the `comment` not belongs to the `span`.
+  let range_text = Span::new(comment.span.end, span.start).source_text(source);
+  let only_whitespace = match comment.kind {
+    CommentKind::Line => range_text.trim().is_empty(),
+    CommentKind::Block => {
+      range_text
+        .strip_prefix("*/") // for multi-line comment
+        .is_some_and(|s| s.trim().is_empty())
+    }
+  };
+  if !only_whitespace {
+    return None;
   }
+
+  Some((comment, comment_text))
 }
 
 #[derive(PartialEq, Eq, Copy, Clone)]
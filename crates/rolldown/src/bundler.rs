@@ -5,20 +5,29 @@ use super::stages::{
 use crate::{
   bundler_builder::BundlerBuilder,
   stages::{generate_stage::GenerateStage, scan_stage::ScanStage},
-  types::bundle_output::BundleOutput,
+  types::{
+    build_stats::{BuildStats, ModuleStats, ScanStats},
+    bundle_output::BundleOutput,
+  },
+  utils::{log_level::is_severity_enabled, public_dir::collect_public_files},
   watcher::watcher::{wait_for_change, Watcher},
   BundlerOptions, SharedOptions, SharedResolver,
 };
 use anyhow::Result;
 
 use arcstr::ArcStr;
-use rolldown_common::{NormalizedBundlerOptions, SharedFileEmitter};
-use rolldown_error::{BuildDiagnostic, BuildResult};
+use rolldown_common::{
+  Log, LogLevel, NormalizedBundlerOptions, OnLogAction, Output, SharedFileEmitter,
+};
+use rolldown_error::{
+  BatchedBuildDiagnostic, BuildDiagnostic, BuildResult, DiagnosticOptions, Severity,
+};
 use rolldown_fs::{FileSystem, OsFileSystem};
 use rolldown_plugin::{
   HookBuildEndArgs, HookRenderErrorArgs, SharedPluginDriver, __inner::SharedPluginable,
 };
-use std::sync::Arc;
+use rustc_hash::FxHashSet;
+use std::{path::Path, sync::Arc, time::Instant};
 use tokio::sync::Mutex;
 use tracing_chrome::FlushGuard;
 
@@ -66,11 +75,47 @@ impl Bundler {
         .map_err(|err| anyhow::anyhow!("Failed to write file in {:?}", dest).context(err))?;
     }
 
+    self.copy_public_files(&dir, &mut output)?;
+
     self.plugin_driver.write_bundle(&mut output.assets).await?;
 
     Ok(output)
   }
 
+  /// Copies the files of `public_dir` into the output directory. Files with the same names as the emitted
+  /// ones are reported instead of overwriting them.
+  fn copy_public_files(&self, dir: &Path, output: &mut BundleOutput) -> Result<()> {
+    let Some(public_dir) = &self.options.public_dir else {
+      return Ok(());
+    };
+    let emitted = output.assets.iter().map(Output::filename).collect::<FxHashSet<_>>();
+    let mut conflicts = vec![];
+    for (path, filename) in collect_public_files(&self.fs, &self.options.cwd.join(public_dir))? {
+      if emitted.contains(filename.as_str()) {
+        if is_severity_enabled(self.options.log_level, Severity::Warn) {
+          conflicts.push(BuildDiagnostic::file_name_conflict(filename).with_severity_warning());
+        }
+        continue;
+      }
+      let dest = dir.join(&filename);
+      if let Some(p) = dest.parent() {
+        if !self.fs.exists(p) {
+          self.fs.create_dir_all(p).unwrap();
+        }
+      };
+      let content = self
+        .fs
+        .read(&path)
+        .map_err(|err| anyhow::anyhow!("Failed to read file in {:?}", path).context(err))?;
+      self
+        .fs
+        .write(&dest, &content)
+        .map_err(|err| anyhow::anyhow!("Failed to write file in {:?}", dest).context(err))?;
+    }
+    output.warnings.extend(conflicts);
+    Ok(())
+  }
+
   #[tracing::instrument(level = "debug", skip_all)]
   pub async fn generate(&mut self) -> Result<BundleOutput> {
     self.bundle_up(/* is_write */ false).await
@@ -115,6 +160,19 @@ impl Bundler {
       }
     };
 
+    // Warnings promoted to errors by `on_log` fail the scan like any other error.
+    let scan_stage_output = match scan_stage_output {
+      Ok(mut v) => {
+        self.apply_on_log(&mut v.warnings, &mut v.errors).await?;
+        if v.errors.is_empty() {
+          Ok(v)
+        } else {
+          Err(std::mem::take(&mut v.errors).into())
+        }
+      }
+      Err(errs) => Err(errs),
+    };
+
     let scan_stage_output = match scan_stage_output {
       Ok(v) => v,
       Err(errs) => {
@@ -138,12 +196,26 @@ impl Bundler {
     Ok(Ok(scan_stage_output))
   }
 
-  async fn try_build(&mut self) -> Result<BuildResult<LinkStageOutput>> {
-    let build_info = match self.scan().await? {
+  async fn try_build(&mut self, stats: &mut BuildStats) -> Result<BuildResult<LinkStageOutput>> {
+    let scan_start = Instant::now();
+    let mut build_info = match self.scan().await? {
       Ok(scan_stage_output) => scan_stage_output,
       Err(errors) => return Ok(Err(errors)),
     };
-    Ok(Ok(LinkStage::new(build_info, &self.options).link()))
+    stats.scan =
+      ScanStats { duration: scan_start.elapsed(), module_requests: build_info.module_requests };
+    stats.bytes_read = build_info.bytes_read;
+    // Warnings of the scan stage already went through `on_log` in `scan`.
+    let scan_warnings = std::mem::take(&mut build_info.warnings);
+
+    let link_start = Instant::now();
+    let mut link_stage_output = LinkStage::new(build_info, &self.options).link();
+    stats.link.duration = link_start.elapsed();
+    stats.modules = ModuleStats::from_module_table(&link_stage_output.module_table);
+    let mut link_warnings = std::mem::take(&mut link_stage_output.warnings);
+    self.apply_on_log(&mut link_warnings, &mut link_stage_output.errors).await?;
+    link_stage_output.warnings = scan_warnings.into_iter().chain(link_warnings).collect();
+    Ok(Ok(link_stage_output))
   }
 
   #[allow(clippy::missing_transmute_annotations)]
@@ -154,14 +226,20 @@ impl Bundler {
       ));
     }
 
-    let mut link_stage_output = match self.try_build().await? {
+    // Files emitted by previous builds of the bundler don't belong to this one. Keeping them would also
+    // deduplicate the assets of this build against files that were already added to a previous output.
+    self.file_emitter.clear();
+
+    let mut stats = BuildStats::default();
+    let mut link_stage_output = match self.try_build(&mut stats).await? {
       Ok(v) => v,
       Err(errors) => {
         return Ok(BundleOutput {
           assets: vec![],
           warnings: vec![],
-          errors: errors.into_vec(),
+          errors: errors.aggregate(&self.diagnostic_options()).into_vec(),
           watch_files: vec![],
+          stats,
         })
       }
     };
@@ -176,6 +254,9 @@ impl Bundler {
 
     self.plugin_driver.render_start().await?;
 
+    // Warnings of the scan and link stages already went through `on_log` in `try_build`.
+    let build_warnings = std::mem::take(&mut link_stage_output.warnings);
+    let generate_start = Instant::now();
     let mut output = {
       let bundle_output =
         GenerateStage::new(&mut link_stage_output, &self.options, &self.plugin_driver)
@@ -188,9 +269,23 @@ impl Bundler {
 
       bundle_output?
     };
+    self.apply_on_log(&mut output.warnings, &mut output.errors).await?;
+    output.warnings.splice(0..0, build_warnings);
+    // Repeated diagnostics, e.g. a missing package imported from many files, are reported once.
+    let opts = self.diagnostic_options();
+    output.warnings =
+      BatchedBuildDiagnostic::new(std::mem::take(&mut output.warnings)).aggregate(&opts).into_vec();
+    output.errors =
+      BatchedBuildDiagnostic::new(std::mem::take(&mut output.errors)).aggregate(&opts).into_vec();
+    stats.generate.duration = generate_start.elapsed();
+    #[allow(clippy::cast_possible_truncation)]
+    let chunks_rendered =
+      output.assets.iter().filter(|asset| matches!(asset, Output::Chunk(_))).count() as u32;
+    stats.chunks_rendered = chunks_rendered;
+    output.stats = stats;
 
     // Add additional files from build plugins.
-    self.file_emitter.add_additional_files(&mut output.assets);
+    self.file_emitter.add_additional_files(&mut output.assets).await?;
 
     self.plugin_driver.generate_bundle(&mut output.assets, is_write).await?;
 
@@ -199,15 +294,57 @@ impl Bundler {
         .module_table
         .modules
         .iter()
-        .filter_map(|m| m.as_normal().map(|m| m.id.as_str().into()))
+        // Virtual modules don't exist in the file system.
+        .filter_map(|m| m.as_normal().filter(|m| !m.id.is_virtual()).map(|m| m.id.as_str().into()))
         .collect::<Vec<ArcStr>>();
       files.extend(self.plugin_driver.watch_files.iter().map(|f| f.clone()));
+      // Watching the directory catches the files added to it as well.
+      if let Some(public_dir) = &self.options.public_dir {
+        files.push(self.options.cwd.join(public_dir).to_string_lossy().into());
+      }
       files
     };
 
     Ok(output)
   }
 
+  /// Passes warnings through the `on_log` hook of plugins and the `on_log` option, which may rewrite, drop
+  /// or promote them to `errors`. Warnings below `log_level` are already dropped where they are collected.
+  /// It takes `&mut self` as `Bundler` isn't `Sync`, which would make the futures of `write` and
+  /// `generate` non-`Send`.
+  async fn apply_on_log(
+    &mut self,
+    warnings: &mut Vec<BuildDiagnostic>,
+    errors: &mut Vec<BuildDiagnostic>,
+  ) -> Result<()> {
+    let opts = self.diagnostic_options();
+    let mut kept = Vec::with_capacity(warnings.len());
+    for warning in std::mem::take(warnings) {
+      let level = match warning.severity() {
+        Severity::Error | Severity::Warn => LogLevel::Warn,
+        Severity::Info => LogLevel::Info,
+        Severity::Debug => LogLevel::Debug,
+      };
+      let log = Log { code: warning.code(), message: warning.message(&opts) };
+      let mut action = self.plugin_driver.on_log(level, &log).await?;
+      if let (OnLogAction::Keep, Some(on_log)) = (&action, &self.options.on_log) {
+        action = on_log.call(level, log).await?;
+      }
+      match action {
+        OnLogAction::Keep => kept.push(warning),
+        OnLogAction::Rewrite(message) => kept.push(warning.with_message(message)),
+        OnLogAction::Drop => {}
+        OnLogAction::Error => errors.push(warning.with_severity(Severity::Error)),
+      }
+    }
+    *warnings = kept;
+    Ok(())
+  }
+
+  fn diagnostic_options(&self) -> DiagnosticOptions {
+    DiagnosticOptions { cwd: self.options.cwd.clone() }
+  }
+
   fn normalize_error<T>(
     ret: &Result<T>,
     errors_fn: impl Fn(&T) -> &[BuildDiagnostic],
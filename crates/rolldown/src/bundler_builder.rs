@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use rolldown_common::FileEmitter;
 use rolldown_fs::OsFileSystem;
-use rolldown_plugin::{PluginDriver, __inner::SharedPluginable};
+use rolldown_plugin::{Plugin, PluginDriver, __inner::SharedPluginable};
 use rolldown_resolver::Resolver;
 
 use crate::{
@@ -56,4 +56,12 @@ impl BundlerBuilder {
     self.plugins = plugins;
     self
   }
+
+  /// Registers a native plugin implementing [`Plugin`]. Plugins are called in the order they're registered,
+  /// unless their hooks are ordered with [`rolldown_plugin::PluginHookMeta`].
+  #[must_use]
+  pub fn with_plugin(mut self, plugin: impl Plugin) -> Self {
+    self.plugins.push(Arc::new(plugin));
+    self
+  }
 }
@@ -1,45 +1,44 @@
-use crate::types::generator::{GenerateContext, GenerateOutput, Generator};
+use crate::{
+  css::{
+    create_css_module_sourcemap, render_css_string, render_css_url, split_url_suffix,
+    RUNTIME_URL_MARKER,
+  },
+  types::generator::{GenerateContext, GenerateOutput, Generator},
+  utils::chunk::generate_rendered_chunk,
+};
+
+use std::path::Path;
 
 use anyhow::Result;
-use rolldown_common::{InstantiatedChunk, InstantiationKind};
-use rolldown_error::BuildResult;
+use itertools::Itertools;
+use oxc::span::Span;
+use rolldown_common::{
+  CssMode, CssUrlReference, CssView, InstantiatedChunk, InstantiationKind, NormalModule,
+  RenderedModule,
+};
+use rolldown_error::{BuildDiagnostic, BuildResult};
+use rolldown_plugin::HookRenderCssChunkArgs;
+use rolldown_sourcemap::{
+  collapse_sourcemaps, lines_count, ConcatSource, RawSource, Source, SourceMapSource,
+};
+use rolldown_utils::{indexmap::FxIndexSet, xxhash::xxhash_base64_url};
+use rustc_hash::FxHashSet;
+use sugar_path::SugarPath;
 
 pub struct CssGenerator;
 
 impl Generator for CssGenerator {
-  #[allow(clippy::too_many_lines)]
   async fn instantiate_chunk<'a>(
     ctx: &mut GenerateContext<'a>,
   ) -> Result<BuildResult<GenerateOutput>> {
-    let mut ordered_css_modules = ctx
-      .chunk
-      .modules
-      .iter()
-      .filter_map(|&id| ctx.link_output.module_table.modules[id].as_normal())
-      .filter(|m| m.css_view.is_some())
-      .collect::<Vec<_>>();
-
-    if ordered_css_modules.is_empty() {
+    // Inlined css is rendered into the js chunk by `EcmaGenerator`.
+    if matches!(ctx.options.css_mode, CssMode::Inline) {
       return Ok(Ok(GenerateOutput {
         chunks: vec![],
         warnings: std::mem::take(&mut ctx.warnings),
       }));
     }
 
-    ordered_css_modules.sort_by_key(|m| m.exec_order);
-
-    let mut content = String::new();
-
-    for module in &ordered_css_modules {
-      let css_view = module.css_view.as_ref().unwrap();
-      let mut magic_string = string_wizard::MagicString::new(&css_view.source);
-      for mutation in &css_view.mutations {
-        mutation.apply(&mut magic_string);
-      }
-      content.push_str(&magic_string.to_string());
-      content.push('\n');
-    }
-
     // Here file path is generated by chunk file name template, it maybe including path segments.
     // So here need to read it's parent directory as file_dir.
     let file_path = ctx.options.cwd.as_path().join(&ctx.options.dir).join(
@@ -52,13 +51,77 @@ impl Generator for CssGenerator {
     );
     let file_dir = file_path.parent().expect("chunk file name should have a parent");
 
+    let Some(mut concat_source) = render_chunk_css(ctx, file_dir) else {
+      return Ok(Ok(GenerateOutput {
+        chunks: vec![],
+        warnings: std::mem::take(&mut ctx.warnings),
+      }));
+    };
+
+    let rendered_modules = ctx
+      .chunk
+      .modules
+      .iter()
+      .filter_map(|&id| ctx.link_output.module_table.modules[id].as_normal())
+      .filter(|m| m.css_view.is_some() && !m.id.starts_with('\0'))
+      .map(|m| (m.id.clone(), RenderedModule { code: None, rendered_length: 0 }))
+      .collect();
+    let rendered_chunk = generate_rendered_chunk(
+      ctx.chunk,
+      rendered_modules,
+      ctx.chunk.pre_rendered_chunk.as_ref().expect("Should have pre-rendered chunk"),
+      ctx.chunk_graph,
+    );
+
+    if let Some(hook) = &ctx.options.css_banner {
+      if let Some(banner) = hook.call(&rendered_chunk).await?.filter(|banner| !banner.is_empty()) {
+        concat_source.add_prepend_source(Box::new(RawSource::new(banner)));
+      }
+    }
+    if let Some(hook) = &ctx.options.css_footer {
+      if let Some(footer) = hook.call(&rendered_chunk).await?.filter(|footer| !footer.is_empty()) {
+        concat_source.add_source(Box::new(RawSource::new(footer)));
+      }
+    }
+    // End the stylesheet with a line break.
+    concat_source.add_source(Box::new(RawSource::new(String::new())));
+    let (content, mut map) = concat_source.content_and_sourcemap();
+
+    if let Some(map) = map.as_mut() {
+      let paths =
+        map.get_sources().map(|source| source.as_path().relative(file_dir)).collect::<Vec<_>>();
+      let sources = paths.iter().map(|x| x.to_string_lossy()).collect::<Vec<_>>();
+      map.set_sources(sources.iter().map(std::convert::AsRef::as_ref).collect::<Vec<_>>());
+    }
+
+    let (content, sourcemap_chain) = ctx
+      .plugin_driver
+      .render_css_chunk(HookRenderCssChunkArgs { code: content, chunk: &rendered_chunk })
+      .await?;
+    let map = match map {
+      Some(map) if !sourcemap_chain.is_empty() => {
+        let mut chain = vec![&map];
+        chain.extend(sourcemap_chain.iter());
+        Some(collapse_sourcemaps(chain))
+      }
+      Some(map) => Some(map),
+      None => match sourcemap_chain.len() {
+        0 => None,
+        1 => sourcemap_chain.into_iter().next(),
+        _ => Some(collapse_sourcemaps(sourcemap_chain.iter().collect::<Vec<_>>())),
+      },
+    };
+
+    // The css file shares the hash of the chunk, so its content needs to be part of the chunk hash.
+    let augment_chunk_hash = Some(xxhash_base64_url(content.as_bytes()));
+
     Ok(Ok(GenerateOutput {
       chunks: vec![InstantiatedChunk {
         origin_chunk: ctx.chunk_idx,
         content,
-        map: None,
+        map,
         meta: InstantiationKind::None,
-        augment_chunk_hash: None,
+        augment_chunk_hash,
         file_dir: file_dir.to_path_buf(),
         preliminary_filename: ctx
           .chunk
@@ -70,3 +133,212 @@ impl Generator for CssGenerator {
     }))
   }
 }
+
+/// Concatenates the css modules of the chunk in execution order, with `url()` references rebased against
+/// `file_dir`, or prefixed with `base` if it's set. Returns `None` if the chunk doesn't contain any css.
+/// The content doesn't end with a line break, so the footer could be appended.
+///
+/// In the inline css mode, urls relative to `file_dir` are wrapped in `RUNTIME_URL_MARKER`s instead, so
+/// that `render_css_injection` resolves them against the url of the chunk.
+pub fn render_chunk_css(ctx: &mut GenerateContext<'_>, file_dir: &Path) -> Option<ConcatSource> {
+  let link_output = ctx.link_output;
+  let mut ordered_css_modules = ctx
+    .chunk
+    .modules
+    .iter()
+    .filter_map(|&id| link_output.module_table.modules[id].as_normal())
+    .filter(|m| m.css_view.is_some())
+    .collect::<Vec<_>>();
+
+  if ordered_css_modules.is_empty() {
+    return None;
+  }
+
+  ordered_css_modules.sort_by_key(|m| m.exec_order);
+
+  // `@charset` and `@import` rules are only valid at the top of a stylesheet. Bundled `@import`s are already
+  // inlined by following the execution order, so only rules of external stylesheets need to be hoisted.
+  let mut at_charset_rule: Option<&str> = None;
+  let mut hoisted_at_import_rules = vec![];
+  let mut seen_at_import_rules = FxHashSet::default();
+  // The order of layers is decided by their first declaration, so `@layer` statements of all modules are
+  // merged into a single statement at the top.
+  let mut layer_names: FxIndexSet<&str> = FxIndexSet::default();
+
+  let mut module_sources: Vec<Box<dyn Source + Send>> = vec![];
+
+  for module in &ordered_css_modules {
+    let css_view = module.css_view.as_ref().unwrap();
+    match (at_charset_rule, css_view.at_charset_rule()) {
+      (None, rule) => at_charset_rule = rule,
+      (Some(hoisted), Some(rule)) if !hoisted.eq_ignore_ascii_case(rule) => {
+        ctx.warnings.push(
+          BuildDiagnostic::css_ordering_conflict(
+            module.id.to_string(),
+            css_view.source.clone(),
+            range_to_span(css_view.at_charset_range.unwrap()),
+            format!("`{rule}` differs from `{hoisted}`, which is used for the whole chunk"),
+          )
+          .with_severity_warning(),
+        );
+      }
+      _ => {}
+    }
+    merge_at_layer_statements(ctx, module, css_view, &mut layer_names);
+    for (idx, record) in css_view.import_records.iter_enumerated() {
+      if link_output.module_table.modules[record.resolved_module].is_external() {
+        let rule = css_view.at_import_rule(idx);
+        if seen_at_import_rules.insert(rule) {
+          hoisted_at_import_rules.push(rule);
+        }
+      }
+    }
+    module_sources.push(render_css_module(ctx, module, css_view, file_dir));
+  }
+
+  let mut hoisted_rules = vec![];
+  if let Some(rule) = at_charset_rule {
+    hoisted_rules.push(rule.to_string());
+  }
+  if !layer_names.is_empty() {
+    hoisted_rules.push(format!("@layer {};", layer_names.iter().join(", ")));
+  }
+  hoisted_rules.extend(hoisted_at_import_rules.into_iter().map(ToString::to_string));
+
+  let mut concat_source = ConcatSource::default();
+  if !hoisted_rules.is_empty() {
+    concat_source.add_source(Box::new(RawSource::new(hoisted_rules.join("\n"))));
+  }
+  for source in module_sources {
+    concat_source.add_source(source);
+  }
+  Some(concat_source)
+}
+
+/// Adds the layers of the `@layer` statements of the module to `layer_names`, and warns about statements
+/// that declare layers in a different order than the earlier ones.
+fn merge_at_layer_statements<'m>(
+  ctx: &mut GenerateContext<'_>,
+  module: &NormalModule,
+  css_view: &'m CssView,
+  layer_names: &mut FxIndexSet<&'m str>,
+) {
+  for statement in &css_view.at_layer_statements {
+    let mut previous: Option<(usize, &str)> = None;
+    let mut conflict = None;
+    for name in &statement.names {
+      let (position, _) = layer_names.insert_full(name.as_str());
+      if let Some((previous_position, previous_name)) = previous {
+        if conflict.is_none() && position < previous_position {
+          conflict = Some((previous_name, name.as_str()));
+        }
+      }
+      previous = Some((position, name.as_str()));
+    }
+    if let Some((before, after)) = conflict {
+      ctx.warnings.push(
+        BuildDiagnostic::css_ordering_conflict(
+          module.id.to_string(),
+          css_view.source.clone(),
+          range_to_span(statement.range),
+          format!("`{before}` is declared before `{after}`, unlike an earlier `@layer` statement"),
+        )
+        .with_severity_warning(),
+      );
+    }
+  }
+}
+
+/// Applies the mutations of the module and rewrites its `url()` references to the emitted assets.
+fn render_css_module(
+  ctx: &GenerateContext<'_>,
+  module: &NormalModule,
+  css_view: &CssView,
+  file_dir: &Path,
+) -> Box<dyn Source + Send> {
+  // Inlined css is part of the js chunk, so it has no sourcemap of its own.
+  let enable_sourcemap =
+    ctx.options.sourcemap.is_some() && !matches!(ctx.options.css_mode, CssMode::Inline);
+  let mut magic_string = string_wizard::MagicString::new(&css_view.source);
+  // Replaced source ranges, which are needed to create the sourcemap of the module.
+  let mut edits = vec![];
+  for mutation in &css_view.mutations {
+    mutation.apply(&mut magic_string);
+    if enable_sourcemap {
+      edits
+        .extend(mutation.removed_ranges().iter().map(|&(start, end)| (start, end, String::new())));
+    }
+  }
+  for url_reference in &css_view.url_references {
+    let Some(replacement) = render_css_url_reference(ctx, css_view, url_reference, file_dir) else {
+      continue;
+    };
+    let (start, end) = url_reference.range;
+    if enable_sourcemap {
+      edits.push((start, end, replacement.clone()));
+    }
+    magic_string.update(start, end, replacement);
+  }
+  let code = magic_string.to_string();
+  if enable_sourcemap && !module.is_virtual() {
+    let map = create_css_module_sourcemap(&module.id, &css_view.source, edits);
+    let map = if css_view.sourcemap_chain.is_empty() {
+      map
+    } else {
+      let mut sourcemap_chain = css_view.sourcemap_chain.iter().collect::<Vec<_>>();
+      sourcemap_chain.push(&map);
+      collapse_sourcemaps(sourcemap_chain)
+    };
+    let lines_count = lines_count(&code);
+    Box::new(SourceMapSource::new(code, map, lines_count))
+  } else {
+    Box::new(RawSource::new(code))
+  }
+}
+
+/// Renders the replacement of a `url()` reference to an emitted asset, or `None` if the reference isn't
+/// emitted as an asset.
+fn render_css_url_reference(
+  ctx: &GenerateContext<'_>,
+  css_view: &CssView,
+  url_reference: &CssUrlReference,
+  file_dir: &Path,
+) -> Option<String> {
+  let reference_id = url_reference.asset_reference_id.as_ref()?;
+  let file_name = ctx.plugin_driver.file_emitter().get_file_name(reference_id);
+  let (_, suffix) = split_url_suffix(&url_reference.specifier);
+  let prefixed_url = ctx.options.base.as_ref().and_then(|base| base.prefixed_url(&file_name));
+  let is_prefixed = prefixed_url.is_some();
+  let mut url = prefixed_url.unwrap_or_else(|| {
+    let out_dir = ctx.options.cwd.as_path().join(&ctx.options.dir);
+    let asset_path = out_dir.join(file_name.as_str());
+    let mut url = asset_path.relative(file_dir).to_slash_lossy().into_owned();
+    if !url.starts_with('.') {
+      url.insert_str(0, "./");
+    }
+    url
+  });
+  url.push_str(suffix);
+  let (start, end) = url_reference.range;
+  let is_url_function = css_view.source[start..end].starts_with("url(");
+  let replacement = if matches!(ctx.options.css_mode, CssMode::Inline) && !is_prefixed {
+    // An injected `<style>` element resolves urls against the document rather than the chunk, so
+    // the url is resolved against the chunk at runtime by `render_css_injection`.
+    let url = format!("\"{RUNTIME_URL_MARKER}{url}{RUNTIME_URL_MARKER}\"");
+    if is_url_function {
+      format!("url({url})")
+    } else {
+      url
+    }
+  } else if is_url_function {
+    render_css_url(&url)
+  } else {
+    render_css_string(&url)
+  };
+  Some(replacement)
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn range_to_span((start, end): (usize, usize)) -> Span {
+  Span::new(start as u32, end as u32)
+}
@@ -0,0 +1,293 @@
+use std::path::Path;
+
+use rolldown_common::{CssLocalClasses, CssLocalRule};
+use rolldown_loader_utils::text_to_string_literal;
+use rolldown_utils::{
+  ecma_script::{is_validate_assignee_identifier_name, legitimize_identifier_name},
+  indexmap::FxIndexMap,
+  xxhash::xxhash_base64_url,
+};
+use rustc_hash::FxHashSet;
+
+use super::skip_string;
+
+/// At-rules whose block contains style rules, rather than declarations or keyframes.
+const GROUPING_AT_RULES: [&str; 5] = ["media", "supports", "layer", "container", "document"];
+
+/// Whether the stylesheet is a css module, such as `foo.module.css` or `foo.module.scss` compiled by a
+/// plugin.
+pub fn is_css_module_id(id: &str) -> bool {
+  let path = id.split_once('?').map_or(id, |(path, _)| path);
+  Path::new(path).file_stem().is_some_and(|stem| stem.to_string_lossy().ends_with(".module"))
+}
+
+/// Suffixes the classes of a css module with a hash of `stable_id`, so they don't collide with classes of
+/// other stylesheets. Classes wrapped in `:global(...)` are left as is.
+pub fn scope_css_module(stable_id: &str, source: &str) -> (String, CssLocalClasses) {
+  let hash = &xxhash_base64_url(stable_id.as_bytes())[..6];
+  let mut names = FxIndexMap::default();
+  let mut replacements = vec![];
+  for rule in scan_style_rules(source) {
+    for ((start, end), name) in rule.selectors.into_iter().flatten() {
+      let scoped = names.entry(name).or_insert_with_key(|name| format!("{name}_{hash}"));
+      replacements.push((start, end, scoped.clone()));
+    }
+    replacements
+      .extend(rule.global_wrappers.into_iter().map(|(start, end)| (start, end, String::new())));
+  }
+  replacements.sort_unstable_by_key(|(start, ..)| *start);
+
+  let mut scoped_source = String::with_capacity(source.len());
+  let mut last_end = 0;
+  for (start, end, replacement) in replacements {
+    scoped_source.push_str(&source[last_end..start]);
+    scoped_source.push_str(&replacement);
+    last_end = end;
+  }
+  scoped_source.push_str(&source[last_end..]);
+
+  // The css view is created from the scoped source, so ranges of rules need to point into it.
+  let scoped_names = names.values().map(String::as_str).collect::<FxHashSet<_>>();
+  let rules = scan_style_rules(&scoped_source)
+    .into_iter()
+    .map(|rule| CssLocalRule {
+      range: rule.range,
+      selectors: rule
+        .selectors
+        .into_iter()
+        .map(|classes| {
+          classes
+            .into_iter()
+            .map(|(_, name)| name)
+            .filter(|name| scoped_names.contains(name.as_str()))
+            .collect()
+        })
+        .collect(),
+    })
+    .collect();
+
+  (scoped_source, CssLocalClasses { names, rules })
+}
+
+/// Renders the js side of a css module. Each class is a named export, so unused ones could be tree-shaken,
+/// and the default export maps all of them for `import styles from './foo.module.css'`.
+pub fn render_css_module_exports(local_classes: &CssLocalClasses) -> anyhow::Result<String> {
+  let mut code = String::new();
+  let mut used_bindings = FxHashSet::default();
+  let mut named_exports = vec![];
+  let mut default_props = vec![];
+  for (name, scoped) in &local_classes.names {
+    let mut binding = legitimize_identifier_name(name).into_owned();
+    if !is_validate_assignee_identifier_name(&binding) {
+      binding.insert(0, '_');
+    }
+    let mut deduped = binding.clone();
+    let mut count = 1;
+    while !used_bindings.insert(deduped.clone()) {
+      deduped = format!("{binding}${count}");
+      count += 1;
+    }
+    let binding = deduped;
+    let name_literal = text_to_string_literal(name)?;
+    code.push_str(&format!("const {binding} = {};\n", text_to_string_literal(scoped)?));
+    named_exports.push(if binding == *name {
+      binding.clone()
+    } else {
+      format!("{binding} as {name_literal}")
+    });
+    default_props.push(format!("{name_literal}: {binding}"));
+  }
+  code.push_str(&format!("export {{ {} }};\n", named_exports.join(", ")));
+  code.push_str(&format!("export default {{ {} }};\n", default_props.join(", ")));
+  Ok(code)
+}
+
+struct StyleRule {
+  range: (usize, usize),
+  /// Classes of each selector, along with the source ranges of their names.
+  selectors: Vec<Vec<((usize, usize), String)>>,
+  /// Ranges of `:global(` and its closing `)`.
+  global_wrappers: Vec<(usize, usize)>,
+}
+
+/// Collects style rules at the top level and in grouping at-rules, such as `@media`. Rules nested in other
+/// style rules are left as is.
+fn scan_style_rules(source: &str) -> Vec<StyleRule> {
+  let mut rules = vec![];
+  scan_block_contents(source, 0, source.len(), &mut rules);
+  rules
+}
+
+fn scan_block_contents(source: &str, start: usize, end: usize, rules: &mut Vec<StyleRule>) {
+  let bytes = source.as_bytes();
+  let mut idx = start;
+  while idx < end {
+    match bytes[idx] {
+      byte if byte.is_ascii_whitespace() || byte == b';' || byte == b'}' => idx += 1,
+      b'/' if bytes.get(idx + 1) == Some(&b'*') => idx = skip_comment(source, idx),
+      b'@' => {
+        let name_end = source[idx + 1..end]
+          .find(|c: char| !(c.is_ascii_alphanumeric() || c == '-'))
+          .map_or(end, |offset| idx + 1 + offset);
+        let name = source[idx + 1..name_end].to_ascii_lowercase();
+        let prelude_end = find_prelude_end(source, name_end, end);
+        if prelude_end >= end || bytes[prelude_end] == b';' {
+          idx = prelude_end + 1;
+          continue;
+        }
+        let block_end = find_block_end(source, prelude_end, end);
+        if GROUPING_AT_RULES.contains(&name.as_str()) {
+          scan_block_contents(source, prelude_end + 1, block_end, rules);
+        }
+        idx = block_end + 1;
+      }
+      _ => {
+        let prelude_end = find_prelude_end(source, idx, end);
+        if prelude_end >= end || bytes[prelude_end] == b';' {
+          idx = prelude_end + 1;
+          continue;
+        }
+        let block_end = find_block_end(source, prelude_end, end);
+        let (selectors, global_wrappers) = scan_selectors(source, idx, prelude_end);
+        rules.push(StyleRule {
+          range: (idx, (block_end + 1).min(end)),
+          selectors,
+          global_wrappers,
+        });
+        idx = block_end + 1;
+      }
+    }
+  }
+}
+
+type ScannedSelectors = (Vec<Vec<((usize, usize), String)>>, Vec<(usize, usize)>);
+
+fn scan_selectors(source: &str, start: usize, end: usize) -> ScannedSelectors {
+  let bytes = source.as_bytes();
+  let mut selectors = vec![vec![]];
+  let mut global_wrappers = vec![];
+  // Depth of parentheses, and the depth at which the innermost `:global(` was opened.
+  let mut depth = 0usize;
+  let mut global_depth = None;
+  let mut idx = start;
+  while idx < end {
+    match bytes[idx] {
+      b'/' if bytes.get(idx + 1) == Some(&b'*') => {
+        idx = skip_comment(source, idx);
+        continue;
+      }
+      quote @ (b'"' | b'\'') => {
+        idx = skip_string(bytes, idx, quote);
+        continue;
+      }
+      b'[' => {
+        idx = source[idx..end].find(']').map_or(end, |offset| idx + offset + 1);
+        continue;
+      }
+      b':' if global_depth.is_none() && source[idx..end].starts_with(":global(") => {
+        global_wrappers.push((idx, idx + ":global(".len()));
+        depth += 1;
+        global_depth = Some(depth);
+        idx += ":global(".len();
+        continue;
+      }
+      b'(' => depth += 1,
+      b')' => {
+        if global_depth == Some(depth) {
+          global_wrappers.push((idx, idx + 1));
+          global_depth = None;
+        }
+        depth = depth.saturating_sub(1);
+      }
+      b',' if depth == 0 => selectors.push(vec![]),
+      b'.' if global_depth.is_none() => {
+        let name_start = idx + 1;
+        let name_end = source[name_start..end]
+          .find(|c: char| !(c.is_alphanumeric() || c == '-' || c == '_'))
+          .map_or(end, |offset| name_start + offset);
+        let name = &source[name_start..name_end];
+        if name.starts_with(|c: char| !c.is_ascii_digit()) {
+          selectors.last_mut().unwrap().push(((name_start, name_end), name.to_string()));
+        }
+        idx = name_end.max(idx + 1);
+        continue;
+      }
+      _ => {}
+    }
+    idx += 1;
+  }
+  (selectors, global_wrappers)
+}
+
+/// Finds the `{` or `;` that ends the prelude of a rule, or `end` if there is none.
+fn find_prelude_end(source: &str, start: usize, end: usize) -> usize {
+  let bytes = source.as_bytes();
+  let mut idx = start;
+  while idx < end {
+    match bytes[idx] {
+      b'{' | b';' => return idx,
+      b'/' if bytes.get(idx + 1) == Some(&b'*') => idx = skip_comment(source, idx),
+      quote @ (b'"' | b'\'') => idx = skip_string(bytes, idx, quote),
+      _ => idx += 1,
+    }
+  }
+  end
+}
+
+/// Finds the `}` that closes the block opened at `open`.
+fn find_block_end(source: &str, open: usize, end: usize) -> usize {
+  let bytes = source.as_bytes();
+  let mut depth = 0usize;
+  let mut idx = open;
+  while idx < end {
+    match bytes[idx] {
+      b'{' => depth += 1,
+      b'}' => {
+        depth -= 1;
+        if depth == 0 {
+          return idx;
+        }
+      }
+      b'/' if bytes.get(idx + 1) == Some(&b'*') => {
+        idx = skip_comment(source, idx);
+        continue;
+      }
+      quote @ (b'"' | b'\'') => {
+        idx = skip_string(bytes, idx, quote);
+        continue;
+      }
+      _ => {}
+    }
+    idx += 1;
+  }
+  end
+}
+
+fn skip_comment(source: &str, start: usize) -> usize {
+  source[start + 2..].find("*/").map_or(source.len(), |offset| start + 2 + offset + 2)
+}
+
+#[test]
+fn scope_css_module_test() {
+  let (scoped, local_classes) = scope_css_module(
+    "a.module.css",
+    ".title, .foo-bar > :global(.icon) {}\n@media print {\n  .title {}\n}\n@keyframes fade {}\n",
+  );
+  let hash = &xxhash_base64_url(b"a.module.css")[..6];
+  assert_eq!(
+    scoped,
+    format!(
+      ".title_{hash}, .foo-bar_{hash} > .icon {{}}\n@media print {{\n  .title_{hash} {{}}\n}}\n@keyframes fade {{}}\n"
+    )
+  );
+  assert_eq!(local_classes.names.keys().collect::<Vec<_>>(), ["title", "foo-bar"]);
+  assert_eq!(local_classes.rules.len(), 2);
+  assert_eq!(
+    local_classes.rules[0].selectors,
+    [vec![format!("title_{hash}")], vec![format!("foo-bar_{hash}")]]
+  );
+  assert_eq!(
+    &scoped[local_classes.rules[1].range.0..local_classes.rules[1].range.1],
+    format!(".title_{hash} {{}}")
+  );
+}
@@ -1,4 +1,7 @@
 pub mod css_generator;
+pub mod local_classes;
+
+use std::path::Path;
 
 use arcstr::ArcStr;
 
@@ -7,8 +10,16 @@ use oxc::{
   semantic::SymbolId,
 };
 use rolldown_common::{
-  CssRenderer, CssView, ImportKind, ImportRecordIdx, ModuleIdx, RawImportRecord, SymbolRef,
+  CssLayerStatement, CssRenderer, CssUrlReference, CssView, EmittedAsset, FileEmitter,
+  GeneratedCodeOptions, ImportKind, ImportRecordIdx, ModuleIdx, OutputFormat, RawImportRecord,
+  SymbolRef,
 };
+use rolldown_fs::FileSystem;
+use rolldown_loader_utils::text_to_string_literal;
+use rolldown_resolver::Resolver;
+use rolldown_sourcemap::{SourceMap, SourceMapBuilder};
+
+use crate::utils::chunk::chunk_url::chunk_url_expr;
 
 pub fn create_css_view(
   _id: &str,
@@ -18,9 +29,23 @@ pub fn create_css_view(
     css_module_lexer::collect_dependencies(source, css_module_lexer::Mode::Css);
 
   let mut dependencies: IndexVec<ImportRecordIdx, RawImportRecord> = IndexVec::default();
+  let mut at_import_ranges: IndexVec<ImportRecordIdx, (usize, usize)> = IndexVec::default();
+  let mut url_references = vec![];
 
   let mut css_renderer = CssRenderer::default();
 
+  // `@charset` is only valid as the very first rule of a stylesheet, so there is no need to search for it elsewhere.
+  let at_charset_range = find_at_charset_range(source);
+  if let Some((start, end)) = at_charset_range {
+    css_renderer.at_import_ranges.push((start, skip_line_break(source, end)));
+  }
+
+  let at_layer_statements = find_at_layer_statements(source);
+  for statement in &at_layer_statements {
+    let (start, end) = statement.range;
+    css_renderer.at_import_ranges.push((start, skip_line_break(source, end)));
+  }
+
   for lexed_dep in lexed_deps {
     match lexed_dep {
       css_module_lexer::Dependency::Import { request, range, .. } => {
@@ -30,17 +55,18 @@ pub fn create_css_view(
           SymbolRef::from((ModuleIdx::from_raw(0), SymbolId::from_usize(0))),
           range.start,
         ));
-        let mut range_end = range.end as usize;
-        if source.is_char_boundary(range_end) {
-          if source[range_end..].starts_with("\r\n") {
-            range_end += 2;
-          }
-          if source[range_end..].starts_with('\n') {
-            range_end += 1;
-          }
-        }
+        at_import_ranges.push((range.start as usize, range.end as usize));
+        let range_end = skip_line_break(source, range.end as usize);
         css_renderer.at_import_ranges.push((range.start as usize, range_end));
       }
+      css_module_lexer::Dependency::Url { request, range, .. } => {
+        url_references.push(CssUrlReference {
+          range: (range.start as usize, range.end as usize),
+          specifier: request.to_string(),
+          asset: None,
+          asset_reference_id: None,
+        });
+      }
       _ => {}
     }
   }
@@ -49,8 +75,333 @@ pub fn create_css_view(
     CssView {
       source: source.clone(),
       import_records: IndexVec::default(),
+      at_import_ranges,
+      at_charset_range,
+      at_layer_statements,
+      url_references,
+      local_classes: None,
       mutations: vec![Box::new(css_renderer)],
+      sourcemap_chain: vec![],
     },
     dependencies,
   )
 }
+
+/// Resolves local files referenced by `url()` and `image-set()` and reads them, so that they could be
+/// emitted as assets and `CssGenerator` could rebase the references against the emitted stylesheet.
+pub fn resolve_url_reference_assets<F: FileSystem + Default + Clone>(
+  css_view: &mut CssView,
+  importer: &str,
+  resolver: &Resolver<F>,
+  fs: &F,
+) -> anyhow::Result<()> {
+  for url_reference in &mut css_view.url_references {
+    if !is_local_url(&url_reference.specifier) {
+      continue;
+    }
+    let (path, _suffix) = split_url_suffix(&url_reference.specifier);
+    // `url(foo.png)` is relative to the stylesheet, rather than a package.
+    let specifier = if path.starts_with('.') { path.to_string() } else { format!("./{path}") };
+    // Files that can't be resolved are left untouched, they might be served from a public directory at runtime.
+    let Ok(resolved) =
+      resolver.resolve(Some(Path::new(importer)), &specifier, ImportKind::AtImport, false)?
+    else {
+      continue;
+    };
+    let path = Path::new(resolved.path.as_str());
+    let content = fs.read(path)?;
+    url_reference.asset = Some(EmittedAsset {
+      name: path.file_name().map(|name| name.to_string_lossy().into_owned()),
+      original_file_name: Some(resolved.path.to_string()),
+      file_name: None,
+      source: content.into(),
+    });
+  }
+  Ok(())
+}
+
+/// Emits the assets referenced by an included stylesheet.
+pub fn emit_url_reference_assets(css_view: &mut CssView, file_emitter: &FileEmitter) {
+  for url_reference in &mut css_view.url_references {
+    if let Some(asset) = url_reference.asset.take() {
+      url_reference.asset_reference_id = Some(file_emitter.emit_file(asset));
+    }
+  }
+}
+
+/// Wraps the urls of assets referenced by inlined css that are resolved against the url of the chunk at
+/// runtime. Css can't contain a NUL character, since it's replaced by U+FFFD when the stylesheet is parsed.
+pub const RUNTIME_URL_MARKER: char = '\0';
+
+/// Renders a snippet that inserts `css` into the document as a `<style>` element once it's evaluated. Urls
+/// wrapped in `RUNTIME_URL_MARKER`s are resolved against the url of the chunk, in the way `format` can
+/// access it.
+pub fn render_css_injection(
+  css: &str,
+  format: &OutputFormat,
+  generated_code: &GeneratedCodeOptions,
+) -> anyhow::Result<String> {
+  let mut text_parts = vec![];
+  let mut url_exprs = vec![];
+  for (idx, part) in css.split(RUNTIME_URL_MARKER).enumerate() {
+    if idx % 2 == 0 {
+      text_parts.push(part);
+    } else {
+      let url = text_to_string_literal(part)?;
+      url_exprs.push(format!("new URL({url}, {}).href", chunk_url_expr(format)));
+    }
+  }
+  let css = if generated_code.template_string() {
+    let mut css = String::from("`");
+    for (idx, text) in text_parts.iter().enumerate() {
+      css.push_str(&text.replace('\\', "\\\\").replace('`', "\\`").replace("${", "\\${"));
+      if let Some(url_expr) = url_exprs.get(idx) {
+        css.push_str(&format!("${{{url_expr}}}"));
+      }
+    }
+    css.push('`');
+    css
+  } else {
+    let mut parts = vec![];
+    for (idx, text) in text_parts.iter().enumerate() {
+      parts.push(text_to_string_literal(text)?);
+      if let Some(url_expr) = url_exprs.get(idx) {
+        parts.push(url_expr.clone());
+      }
+    }
+    parts.join(" + ")
+  };
+  let function_start = if generated_code.arrow_functions() { "() =>" } else { "function()" };
+  let binding_keyword = generated_code.binding_keyword();
+  Ok(format!(
+    r#"({function_start} {{
+  if (typeof document === "undefined") return;
+  {binding_keyword} style = document.createElement("style");
+  style.textContent = {css};
+  document.head.appendChild(style);
+}})();
+"#
+  ))
+}
+
+/// Renders a side-effect import of the stylesheet emitted for the chunk, so importing the js chunk still
+/// loads its css when modules are preserved.
+pub fn render_css_import(format: &OutputFormat, specifier: &str) -> anyhow::Result<Option<String>> {
+  let specifier = text_to_string_literal(specifier)?;
+  Ok(match format {
+    OutputFormat::Esm => Some(format!("import {specifier};\n")),
+    OutputFormat::Cjs => Some(format!("require({specifier});\n")),
+    OutputFormat::App | OutputFormat::Iife | OutputFormat::Umd | OutputFormat::System => None,
+  })
+}
+
+/// Renders `url` as a `url()` function. It's quoted if it contains characters that would end an
+/// unquoted url, such as spaces and parentheses.
+pub fn render_css_url(url: &str) -> String {
+  let needs_quotes = url.chars().any(|c| {
+    c.is_ascii_whitespace() || c.is_ascii_control() || matches!(c, '(' | ')' | '"' | '\'' | '\\')
+  });
+  if needs_quotes {
+    format!("url({})", render_css_string(url))
+  } else {
+    format!("url({url})")
+  }
+}
+
+/// Renders `value` as a double-quoted css string.
+pub fn render_css_string(value: &str) -> String {
+  let mut rendered = String::with_capacity(value.len() + 2);
+  rendered.push('"');
+  for c in value.chars() {
+    match c {
+      '"' | '\\' => {
+        rendered.push('\\');
+        rendered.push(c);
+      }
+      '\n' => rendered.push_str("\\a "),
+      _ => rendered.push(c),
+    }
+  }
+  rendered.push('"');
+  rendered
+}
+
+/// Splits `./font.woff?v=1#iefix` into `./font.woff` and `?v=1#iefix`.
+pub fn split_url_suffix(specifier: &str) -> (&str, &str) {
+  specifier.find(['?', '#']).map_or((specifier, ""), |idx| specifier.split_at(idx))
+}
+
+/// Creates the sourcemap of a css module, which is rendered by replacing the ranges of `edits` in `source`.
+/// Each line of the unchanged text and each replacement starts a mapping, so rules and declarations can be
+/// located in the original stylesheet.
+pub fn create_css_module_sourcemap(
+  id: &str,
+  source: &str,
+  mut edits: Vec<(usize, usize, String)>,
+) -> SourceMap {
+  edits.sort_by_key(|(start, _, _)| *start);
+  // An empty edit at the end maps the text after the last edit.
+  edits.push((source.len(), source.len(), String::new()));
+  let mut builder = SourceMapBuilder::default();
+  let source_id = builder.set_source_and_content(id, source);
+  let mut src_position = (0, 0);
+  let mut dst_position = (0, 0);
+  let mut offset = 0;
+  for (start, end, replacement) in &edits {
+    // Skip edits nested in an earlier one, such as a url inside a removed rule.
+    if *start < offset {
+      continue;
+    }
+    for line in source[offset..*start].split_inclusive('\n') {
+      builder.add_token(
+        dst_position.0,
+        dst_position.1,
+        src_position.0,
+        src_position.1,
+        Some(source_id),
+        None,
+      );
+      advance_position(&mut src_position, line);
+      advance_position(&mut dst_position, line);
+    }
+    if !replacement.is_empty() {
+      builder.add_token(
+        dst_position.0,
+        dst_position.1,
+        src_position.0,
+        src_position.1,
+        Some(source_id),
+        None,
+      );
+      advance_position(&mut dst_position, replacement);
+    }
+    advance_position(&mut src_position, &source[*start..*end]);
+    offset = *end;
+  }
+  builder.into_sourcemap()
+}
+
+/// Moves the `(line, column)` position past `text`. Columns are counted in utf-16 code units, like js.
+#[allow(clippy::cast_possible_truncation)]
+fn advance_position(position: &mut (u32, u32), text: &str) {
+  for c in text.chars() {
+    if c == '\n' {
+      *position = (position.0 + 1, 0);
+    } else {
+      position.1 += c.len_utf16() as u32;
+    }
+  }
+}
+
+fn is_local_url(specifier: &str) -> bool {
+  // - `#id` refers to an element in the document, such as a svg filter.
+  // - `/foo.png` and `//cdn.com/foo.png` are resolved against the server.
+  // - `data:...`, `https://...` and others with a scheme don't refer to a file.
+  !(specifier.is_empty()
+    || specifier.starts_with('#')
+    || specifier.starts_with('/')
+    || specifier.contains(':'))
+}
+
+fn find_at_charset_range(source: &str) -> Option<(usize, usize)> {
+  const AT_CHARSET_PREFIX: &str = "@charset \"";
+  if !source.starts_with(AT_CHARSET_PREFIX) {
+    return None;
+  }
+  source[AT_CHARSET_PREFIX.len()..]
+    .find("\";")
+    .map(|offset| (0, AT_CHARSET_PREFIX.len() + offset + "\";".len()))
+}
+
+/// Finds `@layer a, b;` statements at the top level of the stylesheet. `@layer` blocks and statements
+/// nested in other rules are left as is.
+fn find_at_layer_statements(source: &str) -> Vec<CssLayerStatement> {
+  const AT_LAYER: &str = "@layer";
+  let bytes = source.as_bytes();
+  let mut statements = vec![];
+  let mut depth = 0usize;
+  let mut idx = 0;
+  while idx < bytes.len() {
+    match bytes[idx] {
+      b'/' if bytes.get(idx + 1) == Some(&b'*') => {
+        idx = source[idx + 2..].find("*/").map_or(bytes.len(), |offset| idx + 2 + offset + 2);
+        continue;
+      }
+      quote @ (b'"' | b'\'') => {
+        idx = skip_string(bytes, idx, quote);
+        continue;
+      }
+      b'{' => depth += 1,
+      b'}' => depth = depth.saturating_sub(1),
+      b'@'
+        if depth == 0
+          && source[idx..].starts_with(AT_LAYER)
+          && bytes.get(idx + AT_LAYER.len()).is_some_and(u8::is_ascii_whitespace) =>
+      {
+        let Some(offset) = source[idx..].find([';', '{']) else {
+          break;
+        };
+        let end = idx + offset;
+        if bytes[end] == b';' {
+          let names = source[idx + AT_LAYER.len()..end]
+            .split(',')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(ToString::to_string)
+            .collect();
+          statements.push(CssLayerStatement { range: (idx, end + 1), names });
+          idx = end + 1;
+        } else {
+          // A `@layer name { ... }` block, its `{` is counted as usual.
+          idx = end;
+        }
+        continue;
+      }
+      _ => {}
+    }
+    idx += 1;
+  }
+  statements
+}
+
+fn skip_string(bytes: &[u8], start: usize, quote: u8) -> usize {
+  let mut idx = start + 1;
+  while idx < bytes.len() {
+    match bytes[idx] {
+      b'\\' => idx += 2,
+      byte if byte == quote => return idx + 1,
+      _ => idx += 1,
+    }
+  }
+  bytes.len()
+}
+
+fn skip_line_break(source: &str, mut end: usize) -> usize {
+  if source.is_char_boundary(end) {
+    if source[end..].starts_with("\r\n") {
+      end += 2;
+    }
+    if source[end..].starts_with('\n') {
+      end += 1;
+    }
+  }
+  end
+}
+
+#[test]
+fn test_create_css_module_sourcemap() {
+  let source = "a {}\n@import 'x';\nb { background: url(x.png) }\n";
+  let map = create_css_module_sourcemap(
+    "foo.css",
+    source,
+    vec![(34, 44, "url(./assets/x.png)".to_string()), (5, 18, String::new())],
+  );
+  let tokens = map
+    .get_tokens()
+    .map(|token| {
+      (token.get_dst_line(), token.get_dst_col(), token.get_src_line(), token.get_src_col())
+    })
+    .collect::<Vec<_>>();
+  assert_eq!(tokens, vec![(0, 0, 0, 0), (1, 0, 2, 0), (1, 16, 2, 16), (1, 35, 2, 26)]);
+  assert_eq!(map.get_sources().collect::<Vec<_>>(), vec!["foo.css"]);
+}
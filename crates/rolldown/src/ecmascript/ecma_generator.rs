@@ -1,24 +1,34 @@
 use crate::{
+  css::{css_generator::render_chunk_css, render_css_import, render_css_injection},
   types::generator::{GenerateContext, GenerateOutput, Generator},
-  utils::{chunk::generate_rendered_chunk, render_ecma_module::render_ecma_module},
+  utils::{
+    chunk::{
+      chunk_url::{render_document_current_script, DOCUMENT_CURRENT_SCRIPT},
+      generate_rendered_chunk,
+    },
+    render_ecma_module::render_ecma_module,
+  },
 };
 
 use anyhow::Result;
 use rolldown_common::{
-  EcmaAssetMeta, InstantiatedChunk, InstantiationKind, ModuleId, ModuleIdx, OutputFormat,
+  CssMode, EcmaAssetMeta, InstantiatedChunk, InstantiationKind, ModuleId, ModuleIdx, OutputFormat,
   RenderedModule,
 };
 use rolldown_error::BuildResult;
 use rolldown_plugin::HookAddonArgs;
-use rolldown_sourcemap::Source;
+use rolldown_sourcemap::{RawSource, Source};
 #[cfg(not(target_family = "wasm"))]
 use rolldown_utils::rayon::IndexedParallelIterator;
 use rolldown_utils::rayon::{IntoParallelRefIterator, ParallelIterator};
+use rolldown_utils::virtual_module::is_virtual_module_id;
 use rustc_hash::FxHashMap;
+use std::path::Path;
 use sugar_path::SugarPath;
 
 use super::format::{
-  app::render_app, cjs::render_cjs, esm::render_esm, iife::render_iife, umd::render_umd,
+  app::render_app, cjs::render_cjs, esm::render_esm, iife::render_iife, system::render_system,
+  umd::render_umd,
 };
 
 pub type RenderedModuleSources = Vec<(ModuleIdx, ModuleId, Option<Vec<Box<dyn Source + Send>>>)>;
@@ -48,13 +58,28 @@ impl Generator for EcmaGenerator {
       })
       .collect::<Vec<_>>();
 
-    rendered_module_sources.iter().for_each(|(_, module_id, _)| {
+    rendered_module_sources.iter().for_each(|(_, module_id, sources)| {
       // FIXME: NAPI-RS used CStr under the hood, so it can't handle null byte in the string.
       if !module_id.starts_with('\0') {
-        rendered_modules.insert(module_id.clone(), RenderedModule { code: None });
+        let rendered_length = sources
+          .as_ref()
+          .map_or(0, |sources| sources.iter().map(|source| source.content().len()).sum());
+        rendered_modules.insert(module_id.clone(), RenderedModule { code: None, rendered_length });
       }
     });
 
+    // Here file path is generated by chunk file name template, it maybe including path segments.
+    // So here need to read it's parent directory as file_dir.
+    let file_path = ctx.options.cwd.as_path().join(&ctx.options.dir).join(
+      ctx
+        .chunk
+        .preliminary_filename
+        .as_deref()
+        .expect("chunk file name should be generated before rendering")
+        .as_str(),
+    );
+    let file_dir = file_path.parent().expect("chunk file name should have a parent");
+
     let rendered_chunk = generate_rendered_chunk(
       ctx.chunk,
       rendered_modules,
@@ -84,6 +109,14 @@ impl Generator for EcmaGenerator {
         .await?
     };
 
+    let intro = join_intro(intro, render_css_intro(ctx, file_dir)?);
+    // The script is captured before any module of the chunk is evaluated, while it's still running.
+    let intro = if uses_document_current_script(ctx, &rendered_module_sources, intro.as_deref()) {
+      join_intro(Some(render_document_current_script()), intro)
+    } else {
+      intro
+    };
+
     let outro = {
       let injection = match ctx.options.outro.as_ref() {
         Some(hook) => hook.call(&rendered_chunk).await?,
@@ -106,7 +139,7 @@ impl Generator for EcmaGenerator {
         .await?
     };
 
-    let concat_source = match ctx.options.format {
+    let mut concat_source = match ctx.options.format {
       OutputFormat::Esm => render_esm(ctx, rendered_module_sources, banner, footer, intro, outro),
       OutputFormat::Cjs => {
         match render_cjs(ctx, rendered_module_sources, banner, footer, intro, outro) {
@@ -127,25 +160,26 @@ impl Generator for EcmaGenerator {
           Err(errors) => return Ok(Err(errors)),
         }
       }
+      OutputFormat::System => {
+        render_system(ctx, rendered_module_sources, banner, footer, intro, outro)
+      }
     };
 
+    concat_source.set_skip_empty_sources(ctx.options.compact);
     let (content, mut map) = concat_source.content_and_sourcemap();
 
-    // Here file path is generated by chunk file name template, it maybe including path segments.
-    // So here need to read it's parent directory as file_dir.
-    let file_path = ctx.options.cwd.as_path().join(&ctx.options.dir).join(
-      ctx
-        .chunk
-        .preliminary_filename
-        .as_deref()
-        .expect("chunk file name should be generated before rendering")
-        .as_str(),
-    );
-    let file_dir = file_path.parent().expect("chunk file name should have a parent");
-
     if let Some(map) = map.as_mut() {
-      let paths =
-        map.get_sources().map(|source| source.as_path().relative(file_dir)).collect::<Vec<_>>();
+      // Virtual modules aren't in the file system, so there's no relative path to them.
+      let paths = map
+        .get_sources()
+        .map(|source| {
+          if is_virtual_module_id(source) {
+            source.into()
+          } else {
+            source.as_path().relative(file_dir)
+          }
+        })
+        .collect::<Vec<_>>();
       // Here not normalize the windows path, the rollup `sourcemap_path_transform` ctx.options need to original path.
       let sources = paths.iter().map(|x| x.to_string_lossy()).collect::<Vec<_>>();
       map.set_sources(sources.iter().map(std::convert::AsRef::as_ref).collect::<Vec<_>>());
@@ -169,3 +203,60 @@ impl Generator for EcmaGenerator {
     }))
   }
 }
+
+fn chunk_has_css(ctx: &GenerateContext<'_>) -> bool {
+  ctx.chunk.modules.iter().any(|&module_idx| {
+    ctx.link_output.module_table.modules[module_idx]
+      .as_normal()
+      .is_some_and(|module| module.css_view.is_some())
+  })
+}
+
+/// The inlined css is inserted before any module of the chunk is evaluated, so styles are in place once
+/// the chunk is loaded. Preserved modules keep importing their own stylesheets, which are emitted next to
+/// them.
+fn render_css_intro(ctx: &mut GenerateContext<'_>, file_dir: &Path) -> Result<Option<String>> {
+  match ctx.options.css_mode {
+    CssMode::Inline => match render_chunk_css(ctx, file_dir) {
+      Some(mut css) => {
+        css.add_source(Box::new(RawSource::new(String::new())));
+        let (css, _) = css.content_and_sourcemap();
+        Ok(Some(render_css_injection(&css, &ctx.options.format, &ctx.options.generated_code)?))
+      }
+      None => Ok(None),
+    },
+    CssMode::Extract if ctx.options.preserve_modules && chunk_has_css(ctx) => {
+      let css_filename = ctx
+        .chunk
+        .css_absolute_preliminary_filename
+        .as_ref()
+        .expect("css file name should be generated before rendering");
+      let mut specifier = css_filename.relative(file_dir).to_slash_lossy().into_owned();
+      if !specifier.starts_with('.') {
+        specifier.insert_str(0, "./");
+      }
+      render_css_import(&ctx.options.format, &specifier)
+    }
+    CssMode::Extract => Ok(None),
+  }
+}
+
+fn join_intro(intro: Option<String>, injection: Option<String>) -> Option<String> {
+  match (intro, injection) {
+    (Some(intro), Some(injection)) => Some(format!("{intro}\n{injection}")),
+    (intro, injection) => intro.or(injection),
+  }
+}
+
+fn uses_document_current_script(
+  ctx: &GenerateContext<'_>,
+  rendered_module_sources: &RenderedModuleSources,
+  intro: Option<&str>,
+) -> bool {
+  matches!(ctx.options.format, OutputFormat::Iife | OutputFormat::Umd)
+    && (intro.is_some_and(|intro| intro.contains(DOCUMENT_CURRENT_SCRIPT))
+      || rendered_module_sources
+        .iter()
+        .flat_map(|(_, _, sources)| sources.iter().flatten())
+        .any(|source| source.content().contains(DOCUMENT_CURRENT_SCRIPT)))
+}
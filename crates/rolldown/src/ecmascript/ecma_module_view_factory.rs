@@ -5,23 +5,28 @@ use oxc::{
 };
 use rolldown_common::{
   side_effects::{DeterminedSideEffects, HookSideEffects},
-  AstScopes, EcmaView, EcmaViewMeta, ImportRecordIdx, ModuleDefFormat, ModuleId, ModuleIdx,
-  ModuleType, RawImportRecord, SymbolRef, SymbolRefDbForModule, TreeshakeOptions,
+  AstScopes, CommonJsOptions, ConstEnums, EcmaView, EcmaViewMeta, ImportRecordIdx, ModuleDefFormat,
+  ModuleId, ModuleIdx, ModuleType, PropertyReadSideEffects, RawImportRecord, SymbolRef,
+  SymbolRefDbForModule, TreeshakeOptions,
 };
 use rolldown_ecmascript::EcmaAst;
 use rolldown_error::BuildResult;
-use rolldown_utils::{ecma_script::legitimize_identifier_name, path_ext::PathExt};
+use rolldown_utils::{
+  ecma_script::legitimize_identifier_name, path_ext::PathExt, pattern_filter::StringOrRegex,
+};
 use sugar_path::SugarPath;
 
 use crate::{
   ast_scanner::{AstScanner, ScanResult},
   types::module_factory::{CreateModuleContext, CreateModuleViewArgs},
   utils::{
+    collect_constants::collect_constants,
     make_ast_symbol_and_scope::make_ast_scopes_and_symbols,
     parse_to_ecma_ast::{parse_to_ecma_ast, ParseToEcmaAstResult},
   },
 };
 
+#[allow(clippy::too_many_arguments)]
 fn scan_ast(
   module_idx: ModuleIdx,
   id: &ArcStr,
@@ -29,6 +34,11 @@ fn scan_ast(
   symbols: SymbolTable,
   scopes: ScopeTree,
   module_def_format: ModuleDefFormat,
+  manual_pure_functions: &[StringOrRegex],
+  property_read_side_effects: PropertyReadSideEffects,
+  annotations: bool,
+  unknown_global_side_effects: bool,
+  commonjs: CommonJsOptions,
 ) -> BuildResult<(AstScopes, ScanResult, SymbolRef)> {
   let (symbol_table, ast_scopes) = make_ast_scopes_and_symbols(symbols, scopes);
   let module_id = ModuleId::new(ArcStr::clone(id));
@@ -44,6 +54,11 @@ fn scan_ast(
     ast.source(),
     &module_id,
     ast.comments(),
+    manual_pure_functions,
+    property_read_side_effects,
+    annotations,
+    unknown_global_side_effects,
+    commonjs,
   );
   let namespace_object_ref = scanner.namespace_object_ref;
   let scan_result = scanner.scan(ast.program())?;
@@ -75,11 +90,23 @@ pub async fn create_ecma_view<'any>(
     ctx.replace_global_define_config.as_ref(),
   )?;
 
-  let ParseToEcmaAstResult { mut ast, symbol_table, scope_tree, has_lazy_export, warning } =
-    parse_result;
+  let ParseToEcmaAstResult {
+    mut ast,
+    symbol_table,
+    scope_tree,
+    has_lazy_export,
+    warning,
+    const_enums,
+  } = parse_result;
 
   ctx.warnings.extend(warning);
 
+  // The transformer turns each `const enum` into a variable of the same name.
+  let const_enums: ConstEnums = const_enums
+    .into_iter()
+    .filter_map(|(name, members)| Some((scope_tree.get_root_binding(&name)?, members)))
+    .collect();
+
   let (scope, scan_result, namespace_object_ref) = scan_ast(
     ctx.module_index,
     &ctx.resolved_id.id,
@@ -87,6 +114,11 @@ pub async fn create_ecma_view<'any>(
     symbol_table,
     scope_tree,
     ctx.resolved_id.module_def_format,
+    ctx.options.treeshake.manual_pure_functions(),
+    ctx.options.treeshake.property_read_side_effects(),
+    ctx.options.treeshake.annotations(),
+    ctx.options.treeshake.unknown_global_side_effects(),
+    ctx.options.commonjs,
   )?;
 
   let ScanResult {
@@ -106,7 +138,11 @@ pub async fn create_ecma_view<'any>(
     has_star_exports,
   } = scan_result;
   if !errors.is_empty() {
-    return Err(errors.into());
+    if ctx.options.bail || ctx.options.error_recovery {
+      return Err(errors.into());
+    }
+    // The module is parsed, so its dependencies are still loaded and their errors reported at once.
+    ctx.errors.extend(errors);
   }
   ctx.warnings.extend(scan_warnings);
 
@@ -151,7 +187,7 @@ pub async fn create_ecma_view<'any>(
       TreeshakeOptions::Boolean(false) => DeterminedSideEffects::NoTreeshake,
       TreeshakeOptions::Boolean(true) => unreachable!(),
       TreeshakeOptions::Option(ref opt) => {
-        if opt.module_side_effects.resolve(&stable_id) {
+        if opt.module_side_effects.resolve(&ctx.resolved_id.id, &stable_id, false).await? {
           lazy_check_side_effects()
         } else {
           DeterminedSideEffects::UserDefined(false)
@@ -160,6 +196,8 @@ pub async fn create_ecma_view<'any>(
     },
   };
 
+  let constants = collect_constants(ast.program());
+
   // TODO: Should we check if there are `check_side_effects_for` returns false but there are side effects in the module?
   let view = EcmaView {
     source: ast.source().clone(),
@@ -182,6 +220,8 @@ pub async fn create_ecma_view<'any>(
     side_effects,
     ast_usage,
     self_referenced_class_decl_symbol_ids,
+    const_enums,
+    constants,
     meta: {
       let mut meta = EcmaViewMeta::default();
       meta.set_included(false);
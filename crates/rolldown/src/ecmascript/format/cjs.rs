@@ -1,3 +1,4 @@
+use super::utils::{render_external_interop, render_named_imports};
 use crate::utils::chunk::determine_export_mode::determine_export_mode;
 use crate::utils::chunk::namespace_marker::render_namespace_markers;
 use crate::utils::chunk::render_chunk_exports::get_export_items;
@@ -6,15 +7,16 @@ use crate::{
   types::generator::GenerateContext,
   utils::chunk::{
     collect_render_chunk_imports::{
-      collect_render_chunk_imports, RenderImportDeclarationSpecifier,
+      collect_render_chunk_imports, RenderImportDeclarationSpecifier, RenderImportStmt,
     },
     determine_use_strict::determine_use_strict,
     render_chunk_exports::render_chunk_exports,
   },
 };
-use rolldown_common::{ChunkKind, ExportsKind, Module, OutputExports, WrapKind};
+use rolldown_common::{ChunkKind, ExportsKind, Module, ModuleIdx, OutputExports, WrapKind};
 use rolldown_error::BuildResult;
 use rolldown_sourcemap::{ConcatSource, RawSource};
+use rolldown_std_utils::OptionExt;
 
 pub fn render_cjs(
   ctx: &mut GenerateContext<'_>,
@@ -55,22 +57,9 @@ pub fn render_cjs(
             concat_source.add_source(Box::new(RawSource::new(marker.into())));
           }
         }
-        let meta = &ctx.link_output.metas[entry_id];
-        meta.require_bindings_for_star_exports.iter().for_each(|(importee_idx, binding_ref)| {
-          let importee = &ctx.link_output.module_table.modules[*importee_idx];
-          let binding_ref_name =
-            ctx.link_output.symbol_db.canonical_name_for(*binding_ref, &ctx.chunk.canonical_names);
-            let import_stmt =
-"Object.keys($NAME).forEach(function (k) {
-  if (k !== 'default' && !Object.prototype.hasOwnProperty.call(exports, k)) Object.defineProperty(exports, k, {
-    enumerable: true,
-    get: function () { return $NAME[k]; }
-  });
-});".replace("$NAME", binding_ref_name);
-
-          concat_source.add_source(Box::new(RawSource::new(format!("var {} = require(\"{}\");", binding_ref_name,&importee.stable_id()))));
-          concat_source.add_source(Box::new(RawSource::new(import_stmt)));
-        });
+        for source in render_star_exports_of_requires(ctx, entry_id) {
+          concat_source.add_source(Box::new(RawSource::new(source)));
+        }
         Some(export_mode)
       } else {
         // There is no need for a non-ESM export kind for determining the export mode.
@@ -100,7 +89,7 @@ pub fn render_cjs(
     _ => {}
   }
 
-  concat_source.add_source(Box::new(RawSource::new(render_cjs_chunk_imports(ctx))));
+  concat_source.add_source(Box::new(RawSource::new(render_cjs_chunk_imports(ctx)?)));
 
   // chunk content
   module_sources_peekable.for_each(|(_, _, module_render_output)| {
@@ -111,26 +100,8 @@ pub fn render_cjs(
     }
   });
 
-  if let ChunkKind::EntryPoint { module: entry_id, .. } = ctx.chunk.kind {
-    let entry_meta = &ctx.link_output.metas[entry_id];
-    match entry_meta.wrap_kind {
-      WrapKind::Esm => {
-        // init_xxx()
-        let wrapper_ref = entry_meta.wrapper_ref.as_ref().unwrap();
-        let wrapper_ref_name =
-          ctx.link_output.symbol_db.canonical_name_for(*wrapper_ref, &ctx.chunk.canonical_names);
-        concat_source.add_source(Box::new(RawSource::new(format!("{wrapper_ref_name}();",))));
-      }
-      WrapKind::Cjs => {
-        // "export default require_xxx();"
-        let wrapper_ref = entry_meta.wrapper_ref.as_ref().unwrap();
-        let wrapper_ref_name =
-          ctx.link_output.symbol_db.canonical_name_for(*wrapper_ref, &ctx.chunk.canonical_names);
-        concat_source
-          .add_source(Box::new(RawSource::new(format!("export default {wrapper_ref_name}();\n"))));
-      }
-      WrapKind::None => {}
-    }
+  if let Some(wrapper_call) = render_entry_wrapper_call(ctx) {
+    concat_source.add_source(Box::new(RawSource::new(wrapper_call)));
   }
 
   let export_mode = export_mode.unwrap_or(OutputExports::Auto);
@@ -150,11 +121,83 @@ pub fn render_cjs(
   Ok(concat_source)
 }
 
-fn render_cjs_chunk_imports(ctx: &GenerateContext<'_>) -> String {
+/// Renders the `require` calls of the modules whose exports are re-exported by `export * from` in the entry
+/// module, and the statements copying their exports to `exports`.
+fn render_star_exports_of_requires(ctx: &GenerateContext<'_>, entry_id: ModuleIdx) -> Vec<String> {
+  let meta = &ctx.link_output.metas[entry_id];
+  let mut sources = vec![];
+  meta.require_bindings_for_star_exports.iter().for_each(|(importee_idx, binding_ref)| {
+    let importee = &ctx.link_output.module_table.modules[*importee_idx];
+    let binding_ref_name =
+      ctx.link_output.symbol_db.canonical_name_for(*binding_ref, &ctx.chunk.canonical_names);
+    // Without `output.externalLiveBindings`, the exports of the external module are copied only once.
+    let import_stmt = if ctx.options.external_live_bindings {
+"Object.keys($NAME).forEach($CALLBACK {
+  if (k !== 'default' && !Object.prototype.hasOwnProperty.call(exports, k)) Object.defineProperty(exports, k, {
+    enumerable: true,
+    get: $GETTER
+  });
+});"
+    } else if ctx.options.ses_compatible {
+      // `exports[k] = ...` throws for names like `toString` once `Object.prototype` is frozen.
+"Object.keys($NAME).forEach($CALLBACK {
+  if (k !== 'default' && !Object.prototype.hasOwnProperty.call(exports, k)) Object.defineProperty(exports, k, {
+    enumerable: true,
+    value: $NAME[k]
+  });
+});"
+    } else {
+"Object.keys($NAME).forEach($CALLBACK {
+  if (k !== 'default' && !Object.prototype.hasOwnProperty.call(exports, k)) exports[k] = $NAME[k];
+});"
+    };
+    let (callback, getter) = if ctx.options.generated_code.arrow_functions() {
+      ("(k) =>", "() => $NAME[k]")
+    } else {
+      ("function (k)", "function () { return $NAME[k]; }")
+    };
+    let import_stmt = import_stmt
+      .replace("$CALLBACK", callback)
+      .replace("$GETTER", getter)
+      .replace("$NAME", binding_ref_name);
+
+    sources.push(format!("var {} = require(\"{}\");", binding_ref_name, &importee.stable_id()));
+    sources.push(import_stmt);
+  });
+  sources
+}
+
+/// Renders the call of the wrapper of the entry module, if it's wrapped.
+fn render_entry_wrapper_call(ctx: &GenerateContext<'_>) -> Option<String> {
+  let ChunkKind::EntryPoint { module: entry_id, .. } = ctx.chunk.kind else {
+    return None;
+  };
+  let entry_meta = &ctx.link_output.metas[entry_id];
+  match entry_meta.wrap_kind {
+    WrapKind::Esm => {
+      // init_xxx()
+      let wrapper_ref = entry_meta.wrapper_ref.as_ref().unwrap();
+      let wrapper_ref_name =
+        ctx.link_output.symbol_db.canonical_name_for(*wrapper_ref, &ctx.chunk.canonical_names);
+      Some(format!("{wrapper_ref_name}();"))
+    }
+    WrapKind::Cjs => {
+      // "module.exports = require_xxx();"
+      let wrapper_ref = entry_meta.wrapper_ref.as_ref().unwrap();
+      let wrapper_ref_name =
+        ctx.link_output.symbol_db.canonical_name_for(*wrapper_ref, &ctx.chunk.canonical_names);
+      Some(format!("module.exports = {wrapper_ref_name}();\n"))
+    }
+    WrapKind::None => None,
+  }
+}
+
+fn render_cjs_chunk_imports(ctx: &GenerateContext<'_>) -> BuildResult<String> {
   let render_import_stmts =
     collect_render_chunk_imports(ctx.chunk, ctx.link_output, ctx.chunk_graph);
 
   let mut s = String::new();
+  let binding_keyword = ctx.options.generated_code.binding_keyword();
 
   // render imports from other chunks
   ctx.chunk.imports_from_other_chunks.iter().for_each(|(exporter_id, items)| {
@@ -164,65 +207,43 @@ fn render_cjs_chunk_imports(ctx: &GenerateContext<'_>) -> String {
       s.push_str(&require_path_str);
     } else {
       s.push_str(&format!(
-        "const {} = {require_path_str}",
+        "{binding_keyword} {} = {require_path_str}",
         ctx.chunk.require_binding_names_for_other_chunks[exporter_id],
       ));
     }
   });
 
-  render_import_stmts.iter().for_each(|stmt| {
-    if !stmt.is_external() {
-      return;
-    }
-    let require_path_str = format!("require(\"{}\")", &stmt.path());
-    match &stmt.specifiers() {
+  for stmt in &render_import_stmts {
+    let RenderImportStmt::ExternalRenderImportStmt(stmt) = stmt else {
+      continue;
+    };
+    let require_path_str = format!("require(\"{}\")", &stmt.path);
+    match &stmt.specifiers {
       RenderImportDeclarationSpecifier::ImportSpecifier(specifiers) => {
         if specifiers.is_empty() {
           s.push_str(&format!("{require_path_str};\n"));
         } else {
-          let specifiers = specifiers
-            .iter()
-            .map(|specifier| {
-              if let Some(alias) = &specifier.alias {
-                format!("{}: {alias}", specifier.imported)
-              } else {
-                specifier.imported.to_string()
-              }
-            })
-            .collect::<Vec<_>>();
-          s.push_str(&format!(
-            "const {{ {} }} = {};\n",
-            specifiers.join(", "),
-            if stmt.is_external() {
-              let to_esm_fn_name = &ctx.chunk.canonical_names[&ctx
-                .link_output
-                .symbol_db
-                .canonical_ref_for(ctx.link_output.runtime.resolve_symbol("__toESM"))];
-
-              format!("{to_esm_fn_name}({require_path_str})")
-            } else {
-              require_path_str
-            }
+          let binding_name = if ctx.options.generated_code.destructuring() {
+            ""
+          } else {
+            ctx.chunk.canonical_name_by_token.get(&stmt.binding_name_token).unpack().as_str()
+          };
+          s.push_str(&render_named_imports(
+            ctx,
+            specifiers,
+            binding_name,
+            &render_external_interop(ctx, stmt, require_path_str)?,
           ));
         }
       }
       RenderImportDeclarationSpecifier::ImportStarSpecifier(alias) => {
         s.push_str(&format!(
-          "const {alias} = {};\n",
-          if stmt.is_external() {
-            let to_esm_fn_name = &ctx.chunk.canonical_names[&ctx
-              .link_output
-              .symbol_db
-              .canonical_ref_for(ctx.link_output.runtime.resolve_symbol("__toESM"))];
-
-            format!("{to_esm_fn_name}({require_path_str})")
-          } else {
-            require_path_str
-          }
+          "{binding_keyword} {alias} = {};\n",
+          render_external_interop(ctx, stmt, require_path_str)?
         ));
       }
     }
-  });
+  }
 
-  s
+  Ok(s)
 }
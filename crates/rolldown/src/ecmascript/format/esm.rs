@@ -1,6 +1,6 @@
 use arcstr::ArcStr;
 use itertools::Itertools;
-use rolldown_common::{ChunkKind, ExportsKind, Module, WrapKind};
+use rolldown_common::{ChunkKind, ExportsKind, ImportAttributes, Module, WrapKind};
 use rolldown_sourcemap::{ConcatSource, RawSource};
 
 use crate::{
@@ -42,13 +42,16 @@ pub fn render_esm(
           .filter_map(|importee| {
             let importee = &ctx.link_output.module_table.modules[importee];
             match importee {
-              Module::External(ext) => Some(&ext.name),
+              Module::External(ext) => Some((
+                ext.import_path_for(ctx.chunk),
+                render_with_clause(ctx, ext.attributes.as_ref()),
+              )),
               Module::Normal(_) => None,
             }
           })
           .dedup()
-          .for_each(|ext_name| {
-            let import_stmt = format!("export * from \"{}\"\n", &ext_name);
+          .for_each(|(ext_name, with_clause)| {
+            let import_stmt = format!("export * from \"{ext_name}\"{with_clause}\n");
             concat_source.add_source(Box::new(RawSource::new(import_stmt)));
           });
       }
@@ -110,10 +113,11 @@ fn render_esm_chunk_imports(ctx: &GenerateContext<'_>) -> String {
   let mut s = String::new();
   render_import_stmts.iter().for_each(|stmt| {
     let path = stmt.path();
+    let with_clause = render_with_clause(ctx, stmt.attributes());
     match &stmt.specifiers() {
       RenderImportDeclarationSpecifier::ImportSpecifier(specifiers) => {
         if specifiers.is_empty() {
-          s.push_str(&format!("import \"{path}\";\n",));
+          s.push_str(&format!("import \"{path}\"{with_clause};\n",));
         } else {
           let mut default_alias = vec![];
           let specifiers = specifiers
@@ -130,11 +134,11 @@ fn render_esm_chunk_imports(ctx: &GenerateContext<'_>) -> String {
               }
             })
             .collect::<Vec<_>>();
-          s.push_str(&create_import_declaration(specifiers, &default_alias, path));
+          s.push_str(&create_import_declaration(specifiers, &default_alias, path, &with_clause));
         }
       }
       RenderImportDeclarationSpecifier::ImportStarSpecifier(alias) => {
-        s.push_str(&format!("import * as {alias} from \"{path}\";\n",));
+        s.push_str(&format!("import * as {alias} from \"{path}\"{with_clause};\n",));
       }
     }
   });
@@ -145,6 +149,7 @@ fn create_import_declaration(
   mut specifiers: Vec<String>,
   default_alias: &[String],
   path: &ArcStr,
+  with_clause: &str,
 ) -> String {
   let mut ret = String::new();
   let first_default_alias = match &default_alias {
@@ -161,9 +166,17 @@ fn create_import_declaration(
       ret.push_str(first_default_alias);
       ret.push_str(", ");
     }
-    ret.push_str(&format!("{{ {} }} from \"{path}\";\n", specifiers.join(", ")));
+    ret.push_str(&format!("{{ {} }} from \"{path}\"{with_clause};\n", specifiers.join(", ")));
   } else if let Some(first_default_alias) = first_default_alias {
-    ret.push_str(&format!("import {first_default_alias} from \"{path}\";\n"));
+    ret.push_str(&format!("import {first_default_alias} from \"{path}\"{with_clause};\n"));
   }
   ret
 }
+
+/// Import attributes of external modules are kept unless `output.externalImportAttributes` is `false`.
+fn render_with_clause(ctx: &GenerateContext<'_>, attributes: Option<&ImportAttributes>) -> String {
+  match attributes {
+    Some(attributes) if ctx.options.external_import_attributes => attributes.to_with_clause(),
+    _ => String::new(),
+  }
+}
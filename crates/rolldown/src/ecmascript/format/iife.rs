@@ -38,7 +38,7 @@ use arcstr::ArcStr;
 use rolldown_common::{ChunkKind, OutputExports};
 use rolldown_error::{BuildDiagnostic, BuildResult};
 use rolldown_sourcemap::{ConcatSource, RawSource};
-use rolldown_utils::ecma_script::legitimize_identifier_name;
+use rolldown_utils::ecma_script::{is_validate_identifier_name, legitimize_identifier_name};
 
 use super::utils::{render_chunk_external_imports, render_factory_parameters};
 
@@ -77,7 +77,7 @@ pub fn render_iife(
   let named_exports = matches!(&export_mode, OutputExports::Named);
 
   // It is similar to CJS.
-  let (import_code, externals) = render_chunk_external_imports(ctx);
+  let (import_code, externals) = render_chunk_external_imports(ctx)?;
 
   // Generate the identifier for the IIFE wrapper function.
   // You can refer to the function for more details.
@@ -130,11 +130,28 @@ pub fn render_iife(
     }
   }
 
+  // The runtime module should be placed before the external imports if they are wrapped with `__toESM(...)`.
+  let mut module_sources_peekable = module_sources.into_iter().peekable();
+  if externals.iter().any(|external| external.interop.needs_to_esm(&ctx.options.format)) {
+    match module_sources_peekable.peek() {
+      Some((id, _, _)) if *id == ctx.link_output.runtime.id() => {
+        if let (_, _module_id, Some(emitted_sources)) =
+          module_sources_peekable.next().expect("Must have module")
+        {
+          for source in emitted_sources {
+            concat_source.add_source(source);
+          }
+        }
+      }
+      _ => {}
+    }
+  }
+
   concat_source.add_source(Box::new(RawSource::new(import_code)));
 
   // chunk content
   // TODO indent chunk content for iife format
-  module_sources.into_iter().for_each(|(_, _, module_render_output)| {
+  module_sources_peekable.for_each(|(_, _, module_render_output)| {
     if let Some(emitted_sources) = module_render_output {
       for source in emitted_sources {
         concat_source.add_source(source);
@@ -180,7 +197,12 @@ fn render_iife_factory_arguments(
   let globals = &ctx.options.globals;
   externals.iter().for_each(|external| {
     if let Some(global) = globals.get(external.path.as_str()) {
-      factory_arguments.push(legitimize_identifier_name(global).to_string());
+      // Globals such as `window.React` are passed as they are.
+      if global.split('.').all(is_validate_identifier_name) {
+        factory_arguments.push(global.to_string());
+      } else {
+        factory_arguments.push(legitimize_identifier_name(global).to_string());
+      }
     } else {
       let target = legitimize_identifier_name(external.path.as_str()).to_string();
       ctx.warnings.push(
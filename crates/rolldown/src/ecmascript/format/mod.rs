@@ -2,5 +2,6 @@ pub mod app;
 pub mod cjs;
 pub mod esm;
 pub mod iife;
+pub mod system;
 pub mod umd;
 pub mod utils;
@@ -0,0 +1,287 @@
+//! This is the render function for SystemJS format.
+//! It wraps the chunk content in `System.register`.
+//!
+//! 1. Render the banner if it exists.
+//! 2. Start `System.register` with the dependencies of the chunk, and declare the imported bindings.
+//! 3. Render the setters, which assign the imported bindings whenever the exports of a dependency change,
+//!    so live bindings are kept across chunks.
+//! 4. Start the `execute` function, which is async if any module of the chunk uses top-level await.
+//! 5. Render the intro, the chunk content and the exports, which are passed to `exports(...)`.
+//!    Reassignments of exported bindings are wrapped with `exports(...)` by the finalizer.
+//! 6. Render the outro, close the wrapper and render the footer.
+
+use arcstr::ArcStr;
+use itertools::Itertools;
+use rolldown_common::{ChunkKind, EcmaModuleAstUsage, ExportsKind, Module, StmtInfoIdx, WrapKind};
+use rolldown_rstr::Rstr;
+use rolldown_sourcemap::{ConcatSource, RawSource};
+use rolldown_utils::{
+  ecma_script::{property_access_str, property_key_str},
+  indexmap::FxIndexMap,
+};
+use rustc_hash::FxHashMap;
+
+use crate::{
+  ecmascript::ecma_generator::RenderedModuleSources,
+  types::generator::GenerateContext,
+  utils::chunk::{
+    collect_render_chunk_imports::{
+      collect_render_chunk_imports, RenderImportDeclarationSpecifier,
+    },
+    determine_use_strict::determine_use_strict,
+    render_chunk_exports::{get_export_items, render_chunk_exports},
+  },
+};
+
+pub fn render_system(
+  ctx: &mut GenerateContext<'_>,
+  module_sources: RenderedModuleSources,
+  banner: Option<String>,
+  footer: Option<String>,
+  intro: Option<String>,
+  outro: Option<String>,
+) -> ConcatSource {
+  let mut concat_source = ConcatSource::default();
+
+  if let Some(banner) = banner {
+    concat_source.add_source(Box::new(RawSource::new(banner)));
+  }
+
+  let SystemDependencies { setters, bindings } = collect_system_dependencies(ctx);
+
+  // system wrapper start
+  let name = ctx.options.name.as_ref().map(|name| format!("\"{name}\", ")).unwrap_or_default();
+  let dependencies = setters.keys().map(|path| format!("\"{path}\"")).join(", ");
+  concat_source.add_source(Box::new(RawSource::new(format!(
+    "System.register({name}[{dependencies}], (function(exports, module) {{"
+  ))));
+
+  if determine_use_strict(ctx) {
+    concat_source.add_source(Box::new(RawSource::new("\"use strict\";".to_string())));
+  }
+
+  if !bindings.is_empty() {
+    concat_source.add_source(Box::new(RawSource::new(format!("var {};", bindings.join(", ")))));
+  }
+
+  let rendered_setters = setters
+    .values()
+    .map(|stmts| {
+      if stmts.is_empty() {
+        // Dependencies only imported for side effects don't need a setter.
+        "null".to_string()
+      } else {
+        format!(
+          "function(module) {{\n{}\n}}",
+          stmts.iter().map(|stmt| format!("  {stmt}")).join("\n")
+        )
+      }
+    })
+    .join(", ");
+  let has_top_level_await = ctx.chunk.modules.iter().any(|module_idx| {
+    ctx.link_output.module_table.modules[*module_idx]
+      .as_normal()
+      .is_some_and(|module| module.ast_usage.contains(EcmaModuleAstUsage::TopLevelAwait))
+  });
+  concat_source.add_source(Box::new(RawSource::new(format!(
+    "return {{\nsetters: [{rendered_setters}],\nexecute: ({}function() {{",
+    if has_top_level_await { "async " } else { "" }
+  ))));
+
+  if let Some(intro) = intro {
+    concat_source.add_source(Box::new(RawSource::new(intro)));
+  }
+
+  // chunk content
+  module_sources.into_iter().for_each(|(_, _, module_render_output)| {
+    if let Some(emitted_sources) = module_render_output {
+      for source in emitted_sources {
+        concat_source.add_source(source);
+      }
+    }
+  });
+
+  if let ChunkKind::EntryPoint { module: entry_id, .. } = ctx.chunk.kind {
+    let entry_meta = &ctx.link_output.metas[entry_id];
+    match entry_meta.wrap_kind {
+      WrapKind::Esm => {
+        // init_xxx()
+        let wrapper_ref = entry_meta.wrapper_ref.as_ref().unwrap();
+        let wrapper_ref_name =
+          ctx.link_output.symbol_db.canonical_name_for(*wrapper_ref, &ctx.chunk.canonical_names);
+        concat_source.add_source(Box::new(RawSource::new(format!("{wrapper_ref_name}();",))));
+      }
+      WrapKind::Cjs => {
+        // exports("default", require_xxx());
+        let wrapper_ref = entry_meta.wrapper_ref.as_ref().unwrap();
+        let wrapper_ref_name =
+          ctx.link_output.symbol_db.canonical_name_for(*wrapper_ref, &ctx.chunk.canonical_names);
+        concat_source.add_source(Box::new(RawSource::new(format!(
+          "exports(\"default\", {wrapper_ref_name}());"
+        ))));
+      }
+      WrapKind::None => {}
+    }
+  }
+
+  if let Some(exports) = render_chunk_exports(ctx, None) {
+    concat_source.add_source(Box::new(RawSource::new(exports)));
+  }
+
+  if let Some(outro) = outro {
+    concat_source.add_source(Box::new(RawSource::new(outro)));
+  }
+
+  // system wrapper end
+  concat_source.add_source(Box::new(RawSource::new("})\n};\n}));".to_string())));
+
+  if let Some(footer) = footer {
+    concat_source.add_source(Box::new(RawSource::new(footer)));
+  }
+
+  concat_source
+}
+
+struct SystemDependencies {
+  /// Statements of the setter of each dependency, keyed by the import path.
+  setters: FxIndexMap<ArcStr, Vec<String>>,
+  /// Bindings assigned by the setters, declared in the scope of the `System.register` callback.
+  bindings: Vec<String>,
+}
+
+fn collect_system_dependencies(ctx: &GenerateContext<'_>) -> SystemDependencies {
+  let mut dependencies = SystemDependencies { setters: FxIndexMap::default(), bindings: vec![] };
+  dependencies.add_chunk_imports(ctx);
+  dependencies.add_external_namespaces(ctx);
+  dependencies.add_entry_star_exports(ctx);
+  dependencies
+}
+
+impl SystemDependencies {
+  fn add_chunk_imports(&mut self, ctx: &GenerateContext<'_>) {
+    let generated_code = &ctx.options.generated_code;
+
+    // Imported bindings that are exported again need to be passed to `exports(...)` whenever they change.
+    let mut re_exported_names: FxHashMap<&str, Vec<Rstr>> = FxHashMap::default();
+    for (exported_name, export_ref) in get_export_items(ctx.chunk, ctx.link_output) {
+      let canonical_ref = ctx.link_output.symbol_db.canonical_ref_for(export_ref);
+      if ctx.link_output.symbol_db.get(canonical_ref).namespace_alias.is_none() {
+        let local = ctx.chunk.canonical_names[&canonical_ref].as_str();
+        re_exported_names.entry(local).or_default().push(exported_name);
+      }
+    }
+
+    collect_render_chunk_imports(ctx.chunk, ctx.link_output, ctx.chunk_graph).iter().for_each(
+      |stmt| {
+        let stmts = self.setters.entry(stmt.path().clone()).or_default();
+        let mut re_exports = vec![];
+        let mut add_binding = |local: &str, value: String| {
+          stmts.push(format!("{local} = {value};"));
+          self.bindings.push(local.to_string());
+          if let Some(exported_names) = re_exported_names.get(local) {
+            re_exports.extend(exported_names.iter().map(|exported_name| {
+              if generated_code.object_shorthand() && exported_name.as_str() == local {
+                local.to_string()
+              } else {
+                let key = property_key_str(exported_name, generated_code.reserved_names_as_props());
+                format!("{key}: {local}")
+              }
+            }));
+          }
+        };
+        match stmt.specifiers() {
+          RenderImportDeclarationSpecifier::ImportSpecifier(specifiers) => {
+            specifiers.iter().for_each(|specifier| {
+              let local = specifier.alias.as_ref().unwrap_or(&specifier.imported);
+              add_binding(local, property_access_str("module", &specifier.imported));
+            });
+          }
+          RenderImportDeclarationSpecifier::ImportStarSpecifier(alias) => {
+            add_binding(alias, "module".to_string());
+          }
+        }
+        if !re_exports.is_empty() {
+          stmts.push(format!("exports({{ {} }});", re_exports.join(", ")));
+        }
+      },
+    );
+  }
+
+  /// Namespaces of external modules that are re-exported by `export * from 'external'` in a module namespace object.
+  fn add_external_namespaces(&mut self, ctx: &GenerateContext<'_>) {
+    ctx
+      .chunk
+      .modules
+      .iter()
+      .filter_map(|module_idx| ctx.link_output.module_table.modules[*module_idx].as_normal())
+      .filter(|module| {
+        matches!(module.exports_kind, ExportsKind::Esm)
+          && module.stmt_infos[StmtInfoIdx::new(0)].is_included
+      })
+      .for_each(|module| {
+        ctx.link_output.metas[module.idx].star_exports_from_external_modules.iter().for_each(
+          |rec_idx| {
+            let rec = &module.import_records[*rec_idx];
+            let Module::External(importee) =
+              &ctx.link_output.module_table.modules[rec.resolved_module]
+            else {
+              return;
+            };
+            let namespace_name = ctx
+              .link_output
+              .symbol_db
+              .canonical_name_for(rec.namespace_ref, &ctx.chunk.canonical_names);
+            self
+              .setters
+              .entry(importee.import_path_for(ctx.chunk))
+              .or_default()
+              .push(format!("{namespace_name} = module;"));
+            self.bindings.push(namespace_name.to_string());
+          },
+        );
+      });
+  }
+
+  /// `export * from 'external'` in the entry module re-exports everything except `default` and its own exports.
+  fn add_entry_star_exports(&mut self, ctx: &GenerateContext<'_>) {
+    let ChunkKind::EntryPoint { module: entry_id, .. } = ctx.chunk.kind else {
+      return;
+    };
+    let Module::Normal(entry_module) = &ctx.link_output.module_table.modules[entry_id] else {
+      return;
+    };
+    if !matches!(entry_module.exports_kind, ExportsKind::Esm) {
+      return;
+    }
+    let external_names = entry_module
+      .star_export_module_ids()
+      .filter_map(|importee| match &ctx.link_output.module_table.modules[importee] {
+        Module::External(ext) => Some(ext.import_path_for(ctx.chunk)),
+        Module::Normal(_) => None,
+      })
+      .dedup()
+      .collect::<Vec<_>>();
+    if external_names.is_empty() {
+      return;
+    }
+    let excludes = std::iter::once("default".to_string())
+      .chain(
+        get_export_items(ctx.chunk, ctx.link_output)
+          .into_iter()
+          .map(|(exported_name, _)| exported_name.to_string())
+          .filter(|name| name != "default"),
+      )
+      .map(|name| format!("\"{name}\": 1"))
+      .join(", ");
+    self.bindings.push(format!("_starExcludes = {{ __proto__: null, {excludes} }}"));
+    for name in external_names {
+      self.setters.entry(name).or_default().extend([
+        "var setter = { __proto__: null };".to_string(),
+        "for (var name in module) {".to_string(),
+        "  if (!_starExcludes[name]) setter[name] = module[name];".to_string(),
+        "}".to_string(),
+        "exports(setter);".to_string(),
+      ]);
+    }
+  }
+}
@@ -56,13 +56,18 @@ pub fn render_umd(
   let named_exports = matches!(&export_mode, OutputExports::Named);
 
   // It is similar to CJS.
-  let (import_code, externals) = render_chunk_external_imports(ctx);
+  let (import_code, externals) = render_chunk_external_imports(ctx)?;
 
   // The function argument and the external imports are passed as arguments to the wrapper function.
   let need_global = has_exports || named_exports || !externals.is_empty();
   let wrapper_parameters = if need_global { "global, factory" } else { "factory" };
   let amd_dependencies = render_amd_dependencies(&externals, has_exports && named_exports);
-  let global_argument = if need_global { "this, " } else { "" };
+  // The top-level `this` is only the global object in sloppy mode scripts.
+  let global_argument = match (need_global, ctx.options.ses_compatible) {
+    (false, _) => "",
+    (true, true) => "globalThis, ",
+    (true, false) => "this, ",
+  };
   let factory_parameters = render_factory_parameters(ctx, &externals, has_exports && named_exports);
   let cjs_intro = if need_global {
     let cjs_export = if has_exports && !named_exports { "module.exports = " } else { "" };
@@ -102,11 +107,28 @@ pub fn render_umd(
     }
   }
 
+  // The runtime module should be placed before the external imports if they are wrapped with `__toESM(...)`.
+  let mut module_sources_peekable = module_sources.into_iter().peekable();
+  if externals.iter().any(|external| external.interop.needs_to_esm(&ctx.options.format)) {
+    match module_sources_peekable.peek() {
+      Some((id, _, _)) if *id == ctx.link_output.runtime.id() => {
+        if let (_, _module_id, Some(emitted_sources)) =
+          module_sources_peekable.next().expect("Must have module")
+        {
+          for source in emitted_sources {
+            concat_source.add_source(source);
+          }
+        }
+      }
+      _ => {}
+    }
+  }
+
   concat_source.add_source(Box::new(RawSource::new(import_code)));
 
   // chunk content
   // TODO indent chunk content
-  module_sources.into_iter().for_each(|(_, _, module_render_output)| {
+  module_sources_peekable.for_each(|(_, _, module_render_output)| {
     if let Some(emitted_sources) = module_render_output {
       for source in emitted_sources {
         concat_source.add_source(source);
@@ -188,6 +210,9 @@ fn render_iife_export(
     }
   });
   let deps = dependencies.join(",");
+  if has_exports && ctx.options.no_conflict {
+    return Ok(render_no_conflict_export(ctx, &deps, named_exports));
+  }
   if has_exports {
     if named_exports {
       Ok(format!(
@@ -202,3 +227,47 @@ fn render_iife_export(
     Ok(format!("factory({deps})"))
   }
 }
+
+/// Renders the global export with `output.noConflict`, which remembers the previous value of the global and
+/// adds a `noConflict()` method to restore it.
+///
+/// ```js
+/// (function() {
+///   var current = global.name;
+///   var exports = global.name = {};
+///   factory(exports);
+///   exports.noConflict = function() { global.name = current; return exports; };
+/// })()
+/// ```
+fn render_no_conflict_export(ctx: &GenerateContext<'_>, deps: &str, named_exports: bool) -> String {
+  let (stmt, namespace) = generate_namespace_definition(
+    ctx.options.name.as_ref().expect("should have name"),
+    "global",
+    ";\n    ",
+  );
+  let generated_code = &ctx.options.generated_code;
+  let binding_keyword = generated_code.binding_keyword();
+  let function_start = if generated_code.arrow_functions() { "() =>" } else { "function()" };
+  let exports_value = if named_exports {
+    if ctx.options.extend {
+      format!("{namespace} || {{}}")
+    } else {
+      "{}".to_string()
+    }
+  } else {
+    format!("factory({deps})")
+  };
+  let factory_call = if named_exports {
+    let deps = if deps.is_empty() { String::new() } else { format!(", {deps}") };
+    format!("\n    factory(exports{deps});")
+  } else {
+    String::new()
+  };
+  format!(
+    "({function_start} {{
+    {stmt}{binding_keyword} current = {namespace};
+    {binding_keyword} exports = {namespace} = {exports_value};{factory_call}
+    exports.noConflict = {function_start} {{ {namespace} = current; return exports; }};
+  }})()"
+  )
+}
@@ -1,11 +1,15 @@
-use itertools::Itertools;
+use rolldown_common::InteropMode;
+use rolldown_error::{BuildDiagnostic, BuildResult};
 use rolldown_std_utils::OptionExt;
+use rolldown_utils::ecma_script::{
+  property_access_str, property_access_str_without_reserved_names, property_key_str,
+};
 
 use crate::{
   types::generator::GenerateContext,
   utils::chunk::collect_render_chunk_imports::{
     collect_render_chunk_imports, ExternalRenderImportStmt, RenderImportDeclarationSpecifier,
-    RenderImportStmt,
+    RenderImportSpecifier, RenderImportStmt,
   },
 };
 
@@ -24,50 +28,132 @@ pub fn render_factory_parameters(
   parameters.join(", ")
 }
 
+/// Renders the specifiers of an import as a destructuring pattern, such as `a, default: b`.
+pub fn render_destructured_specifiers(
+  ctx: &GenerateContext<'_>,
+  specifiers: &[RenderImportSpecifier],
+) -> String {
+  let reserved_names_as_props = ctx.options.generated_code.reserved_names_as_props();
+  specifiers
+    .iter()
+    .map(|specifier| {
+      if let Some(alias) = &specifier.alias {
+        format!("{}: {alias}", property_key_str(&specifier.imported, reserved_names_as_props))
+      } else {
+        specifier.imported.to_string()
+      }
+    })
+    .collect::<Vec<_>>()
+    .join(", ")
+}
+
+/// Renders the declarations of the named imports of an external module. They are destructured from
+/// `value`, such as `const { a, default: b } = require("ext");`. If `generatedCode` disables destructuring,
+/// `value` is bound to `binding_name` and each import is assigned by a member access instead.
+pub fn render_named_imports(
+  ctx: &GenerateContext<'_>,
+  specifiers: &[RenderImportSpecifier],
+  binding_name: &str,
+  value: &str,
+) -> String {
+  let binding_keyword = ctx.options.generated_code.binding_keyword();
+  if ctx.options.generated_code.destructuring() {
+    return format!(
+      "{binding_keyword} {{ {} }} = {value};\n",
+      render_destructured_specifiers(ctx, specifiers)
+    );
+  }
+  let reserved_names_as_props = ctx.options.generated_code.reserved_names_as_props();
+  let mut code = if binding_name == value {
+    String::new()
+  } else {
+    format!("{binding_keyword} {binding_name} = {value};\n")
+  };
+  for specifier in specifiers {
+    let local = specifier.alias.as_deref().unwrap_or(specifier.imported.as_str());
+    let member = if reserved_names_as_props {
+      property_access_str(binding_name, &specifier.imported)
+    } else {
+      property_access_str_without_reserved_names(binding_name, &specifier.imported)
+    };
+    code.push_str(&format!("{binding_keyword} {local} = {member};\n"));
+  }
+  code
+}
+
+/// Applies `output.interop` to the value of an external module, which is `require(...)` in the `cjs` format and
+/// the global variable in the `iife` and `umd` format.
+pub fn render_external_interop(
+  ctx: &GenerateContext<'_>,
+  stmt: &ExternalRenderImportStmt,
+  value: String,
+) -> BuildResult<String> {
+  if matches!(stmt.interop, InteropMode::DefaultOnly) {
+    if let RenderImportDeclarationSpecifier::ImportSpecifier(specifiers) = &stmt.specifiers {
+      let errors = specifiers
+        .iter()
+        .filter(|specifier| specifier.imported != "default")
+        .map(|specifier| {
+          BuildDiagnostic::unexpected_named_import(stmt.path.clone(), specifier.imported.clone())
+        })
+        .collect::<Vec<_>>();
+      if !errors.is_empty() {
+        return Err(errors.into());
+      }
+    }
+  }
+  if !stmt.interop.needs_to_esm(&ctx.options.format) {
+    return Ok(value);
+  }
+  let to_esm_fn_name = &ctx.chunk.canonical_names[&ctx
+    .link_output
+    .symbol_db
+    .canonical_ref_for(ctx.link_output.runtime.resolve_symbol("__toESM"))];
+  Ok(match stmt.interop {
+    InteropMode::Auto => format!("{to_esm_fn_name}({value})"),
+    // `isNodeMode` makes `__toESM` always use the module as the default export.
+    _ => format!("{to_esm_fn_name}({value}, 1)"),
+  })
+}
+
 pub fn render_chunk_external_imports(
   ctx: &GenerateContext<'_>,
-) -> (String, Vec<ExternalRenderImportStmt>) {
+) -> BuildResult<(String, Vec<ExternalRenderImportStmt>)> {
   let render_import_stmts =
     collect_render_chunk_imports(ctx.chunk, ctx.link_output, ctx.chunk_graph);
 
   let mut import_code = String::new();
-  let externals = render_import_stmts
-    .into_iter()
-    .filter_map(|stmt| {
-      if let RenderImportStmt::ExternalRenderImportStmt(external_stmt) = stmt {
-        let symbol_name =
-          ctx.chunk.canonical_name_by_token.get(&external_stmt.binding_name_token).unpack();
-        match &external_stmt.specifiers {
-          RenderImportDeclarationSpecifier::ImportSpecifier(specifiers) => {
-            // Empty specifiers can be ignored in IIFE.
-            if specifiers.is_empty() {
-              None
-            } else {
-              let specifiers = specifiers
-                .iter()
-                .map(|specifier| {
-                  if let Some(alias) = &specifier.alias {
-                    format!("{}: {alias}", specifier.imported)
-                  } else {
-                    specifier.imported.to_string()
-                  }
-                })
-                .collect::<Vec<_>>();
-              import_code
-                .push_str(&format!("const {{ {} }} = {symbol_name};\n", specifiers.join(", ")));
-              Some(external_stmt)
-            }
-          }
-          RenderImportDeclarationSpecifier::ImportStarSpecifier(alias) => {
-            import_code.push_str(&format!("const {alias} = {symbol_name};\n"));
-            Some(external_stmt)
-          }
+  let mut externals = vec![];
+  let binding_keyword = ctx.options.generated_code.binding_keyword();
+  for stmt in render_import_stmts {
+    let RenderImportStmt::ExternalRenderImportStmt(external_stmt) = stmt else {
+      continue;
+    };
+    let symbol_name =
+      ctx.chunk.canonical_name_by_token.get(&external_stmt.binding_name_token).unpack();
+    match &external_stmt.specifiers {
+      RenderImportDeclarationSpecifier::ImportSpecifier(specifiers) => {
+        // Empty specifiers can be ignored in IIFE.
+        if specifiers.is_empty() {
+          continue;
         }
-      } else {
-        None
+        // The factory parameter is reused as the binding of the module if `__toESM` isn't needed.
+        import_code.push_str(&render_named_imports(
+          ctx,
+          specifiers,
+          symbol_name,
+          &render_external_interop(ctx, &external_stmt, symbol_name.to_string())?,
+        ));
       }
-    })
-    .collect_vec();
+      RenderImportDeclarationSpecifier::ImportStarSpecifier(alias) => {
+        import_code.push_str(&format!(
+          "{binding_keyword} {alias} = {};\n",
+          render_external_interop(ctx, &external_stmt, symbol_name.to_string())?
+        ));
+      }
+    }
+    externals.push(external_stmt);
+  }
 
-  (import_code, externals)
+  Ok((import_code, externals))
 }
@@ -81,9 +81,12 @@ pub fn generate_identifier(
     return Ok((String::new(), String::new()));
   };
 
+  // The top-level `this` is only the global object in sloppy mode scripts.
+  let global = if ctx.options.ses_compatible { "globalThis" } else { "this" };
+
   // It is same as Rollup.
   if name.contains('.') {
-    let (stmts, namespace) = generate_namespace_definition(name, "this", ";\n");
+    let (stmts, namespace) = generate_namespace_definition(name, global, ";\n");
     // Extend the object if the `extend` option is enabled.
     let final_expr = if ctx.options.extend && matches!(export_mode, OutputExports::Named) {
       format!("{namespace} = {namespace} || {{}}")
@@ -99,7 +102,7 @@ pub fn generate_identifier(
     let final_expr = if matches!(export_mode, OutputExports::Named) {
       // In named exports, the `extend` option will make the assignment disappear and
       // the modification will be done extending the existed object (the `name` option).
-      format!("this{property} = this{property} || {{}}")
+      format!("{global}{property} = {global}{property} || {{}}")
     } else {
       // If there isn't a name in default export, we shouldn't assign the function to `this[""]`.
       // If there is, we should assign the function to `this["name"]`,
@@ -107,7 +110,7 @@ pub fn generate_identifier(
       if name.is_empty() {
         String::new()
       } else {
-        format!("this{property}")
+        format!("{global}{property}")
       }
     };
 
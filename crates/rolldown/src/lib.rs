@@ -22,7 +22,12 @@ pub(crate) type SharedResolver = Arc<Resolver<OsFileSystem>>;
 pub(crate) type SharedOptions = SharedNormalizedBundlerOptions;
 
 pub use crate::{
-  bundler::Bundler, bundler_builder::BundlerBuilder, types::bundle_output::BundleOutput,
+  bundler::Bundler,
+  bundler_builder::BundlerBuilder,
+  types::{
+    build_stats::{BuildStats, ModuleRequestStats, ModuleStats, ScanStats, StageStats},
+    bundle_output::BundleOutput,
+  },
   watcher::watcher::Watcher,
 };
 
@@ -0,0 +1,78 @@
+use oxc::{
+  ast::{ast::Expression, NONE},
+  parser::Parser,
+  span::{SourceType, SPAN},
+};
+use rolldown_utils::path_ext::PathExt;
+use sugar_path::SugarPath;
+
+use crate::utils::chunk::chunk_url::chunk_url_expr;
+
+use super::ScopeHoistingFinalizer;
+
+const FILE_URL_PREFIX: &str = "ROLLUP_FILE_URL_";
+
+impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
+  /// Returns the url of the file emitted by a plugin for `import.meta.ROLLUP_FILE_URL_<referenceId>`, which is
+  /// `new URL('./file', <url of the chunk>).href`, or the file name prefixed with `output.base` if it's a fixed
+  /// url. Unknown reference ids are left as is.
+  pub fn file_url_expr(&self, expr: &Expression<'ast>) -> Option<Expression<'ast>> {
+    let Expression::StaticMemberExpression(member_expr) = expr else {
+      return None;
+    };
+    let Expression::MetaProperty(meta) = &member_expr.object else {
+      return None;
+    };
+    if meta.meta.name != "import" || meta.property.name != "meta" {
+      return None;
+    }
+    let reference_id = member_expr.property.name.strip_prefix(FILE_URL_PREFIX)?;
+    let file_path = self.ctx.emitted_file_paths.get(reference_id)?;
+
+    let builder = &self.snippet.builder;
+    if let Some(url) = self.prefixed_url_for_file(file_path) {
+      return Some(builder.expression_string_literal(SPAN, url.as_str()));
+    }
+
+    let importer_chunk_id = self.ctx.chunk_graph.module_to_chunk[self.ctx.module.idx]
+      .expect("Normal module should belong to a chunk");
+    let import_path =
+      self.ctx.chunk_graph.chunk_table[importer_chunk_id].import_path_for_file(file_path);
+    // Codegen looks up comments before the end of `new` expressions, which an empty span would underflow.
+    let new_url = builder.expression_new(
+      member_expr.span,
+      builder.expression_identifier_reference(SPAN, "URL"),
+      builder.vec_from_iter([
+        builder.argument_expression(builder.expression_string_literal(SPAN, import_path.as_str())),
+        builder.argument_expression(self.chunk_url_expr()),
+      ]),
+      NONE,
+    );
+    Some(self.static_member_expr(new_url, "href"))
+  }
+
+  /// Returns the url of the file at the absolute path `file_path` in the output directory if `output.base` is a
+  /// fixed url, which makes it known at compile time.
+  pub fn prefixed_url_for_file(&self, file_path: &str) -> Option<String> {
+    let base = self.ctx.options.base.as_ref()?;
+    let out_dir = self.ctx.options.cwd.join(&self.ctx.options.dir);
+    base.prefixed_url(&file_path.relative(out_dir).expect_to_slash())
+  }
+
+  /// The url of the chunk being rendered, in the way `output.format` can access it.
+  fn chunk_url_expr(&self) -> Expression<'ast> {
+    Parser::new(self.alloc, chunk_url_expr(&self.ctx.options.format), SourceType::mjs())
+      .parse_expression()
+      .expect("the url of the chunk should be a valid expression")
+  }
+
+  fn static_member_expr(&self, object: Expression<'ast>, property: &str) -> Expression<'ast> {
+    let builder = &self.snippet.builder;
+    builder.expression_member(builder.member_expression_static(
+      SPAN,
+      object,
+      self.snippet.id_name(property, SPAN),
+      false,
+    ))
+  }
+}
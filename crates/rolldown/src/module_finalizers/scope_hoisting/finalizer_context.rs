@@ -1,3 +1,4 @@
+use arcstr::ArcStr;
 use rolldown_common::{IndexModules, ModuleIdx, NormalModule, SymbolRef, SymbolRefDb};
 
 use rolldown_rstr::Rstr;
@@ -21,4 +22,8 @@ pub struct ScopeHoistingFinalizerContext<'me> {
   pub runtime: &'me RuntimeModuleBrief,
   pub chunk_graph: &'me ChunkGraph,
   pub options: &'me SharedOptions,
+  /// Exported names of the reassignable bindings of the chunk, only collected for system format.
+  pub reassignable_exports: Option<&'me FxHashMap<SymbolRef, Vec<Rstr>>>,
+  /// Absolute paths of the files emitted by plugins, by their reference ids.
+  pub emitted_file_paths: &'me FxHashMap<ArcStr, String>,
 }
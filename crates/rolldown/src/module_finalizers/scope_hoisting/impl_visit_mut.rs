@@ -9,7 +9,9 @@ use oxc::{
   },
   span::{GetSpan, Span, SPAN},
 };
-use rolldown_common::{ExportsKind, Module, ModuleType, StmtInfoIdx, SymbolRef, WrapKind};
+use rolldown_common::{
+  ExportsKind, Module, ModuleType, OutputFormat, StmtInfoIdx, SymbolRef, WrapKind,
+};
 use rolldown_ecmascript::{AllocatorExt, ExpressionExt, StatementExt, TakeIn};
 
 use crate::utils::call_expression_ext::CallExpressionExt;
@@ -111,7 +113,8 @@ impl<'me, 'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'me, 'ast> {
                   rolldown_common::OutputFormat::Esm
                   | rolldown_common::OutputFormat::Iife
                   | rolldown_common::OutputFormat::Umd
-                  | rolldown_common::OutputFormat::Cjs => {
+                  | rolldown_common::OutputFormat::Cjs
+                  | rolldown_common::OutputFormat::System => {
                     // Just remove the statement
                     return;
                   }
@@ -245,6 +248,7 @@ impl<'me, 'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'me, 'ast> {
             old_body,
             self.ctx.module.ast_usage,
             self.ctx.options.profiler_names,
+            self.ctx.options.generated_code.arrow_function_helpers(),
             &self.ctx.module.stable_id,
           ));
         }
@@ -317,6 +321,7 @@ impl<'me, 'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'me, 'ast> {
             esm_ref_name,
             stmts_inside_closure,
             self.ctx.options.profiler_names,
+            self.ctx.options.generated_code.arrow_function_helpers(),
             &self.ctx.module.stable_id,
           ));
         }
@@ -362,6 +367,10 @@ impl<'me, 'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'me, 'ast> {
 
   #[allow(clippy::collapsible_else_if, clippy::too_many_lines)]
   fn visit_expression(&mut self, expr: &mut ast::Expression<'ast>) {
+    // Resolve it before the assignment target gets renamed
+    let reassigned_export_names = self.reassigned_export_names(expr);
+    let kept_name = self.kept_name_of_expr(expr);
+
     if let Some(call_expr) = expr.as_call_expression_mut() {
       if call_expr.is_global_require_call(self.scope) && !call_expr.span.is_empty() {
         //  `require` calls that can't be recognized by rolldown are ignored in scanning, so they were not stored in `NomralModule#imports`.
@@ -370,69 +379,81 @@ impl<'me, 'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'me, 'ast> {
           let rec = &self.ctx.module.import_records[rec_id];
           match &self.ctx.modules[rec.resolved_module] {
             Module::Normal(importee) => {
-              match importee.module_type {
-                ModuleType::Json => {
-                  // Nodejs treats json files as an esm module with a default export and rolldown follows this behavior.
-                  // And to make sure the runtime behavior is correct, we need to rewrite `require('xxx.json')` to `require('xxx.json').default` to align with the runtime behavior of nodejs.
-
-                  // Rewrite `require(...)` to `require_xxx(...)` or `(init_xxx(), __toCommonJS(xxx_exports).default)`
-                  let importee_linking_info = &self.ctx.linking_infos[importee.idx];
-                  let wrap_ref_name =
-                    self.canonical_name_for(importee_linking_info.wrapper_ref.unwrap());
-                  if matches!(importee.exports_kind, ExportsKind::CommonJs) {
-                    *expr = self.snippet.call_expr_expr(wrap_ref_name);
-                  } else {
-                    let ns_name = self.canonical_name_for(importee.namespace_object_ref);
-                    let to_commonjs_ref_name = self.canonical_name_for_runtime("__toCommonJS");
-                    *expr = self.snippet.seq2_in_paren_expr(
-                      self.snippet.call_expr_expr(wrap_ref_name),
-                      ast::Expression::StaticMemberExpression(
-                        ast::StaticMemberExpression {
-                          object: self
-                            .snippet
-                            .call_expr_with_arg_expr(to_commonjs_ref_name, ns_name),
-                          property: self.snippet.id_name("default", SPAN),
-                          ..TakeIn::dummy(self.alloc)
-                        }
-                        .into_in(self.alloc),
-                      ),
+              // Rewrite `require(...)` to `require_xxx(...)` or `(init_xxx(), __toCommonJS(xxx_exports))`
+              let importee_linking_info = &self.ctx.linking_infos[importee.idx];
+              let wrap_ref_name =
+                self.canonical_name_for(importee_linking_info.wrapper_ref.unwrap());
+              if matches!(importee.exports_kind, ExportsKind::CommonJs) {
+                *expr = self.snippet.call_expr_expr(wrap_ref_name);
+              } else {
+                let ns_name = self.canonical_name_for(importee.namespace_object_ref);
+                let to_commonjs_ref_name = self.canonical_name_for_runtime("__toCommonJS");
+                let to_commonjs_expr =
+                  self.snippet.call_expr_with_arg_expr(to_commonjs_ref_name, ns_name);
+                // Nodejs treats json files as an esm module with a default export and rolldown follows this behavior.
+                // And to make sure the runtime behavior is correct, we need to rewrite `require('xxx.json')` to `require('xxx.json').default` to align with the runtime behavior of nodejs.
+                // Other esm modules return their default export according to `commonjs.requireReturnsDefault`.
+                let exports = &importee_linking_info.sorted_and_non_ambiguous_resolved_exports;
+                let has_default_export = exports.iter().any(|name| name.as_str() == "default");
+                let returns_default =
+                  matches!(importee.module_type, ModuleType::Json)
+                    || self.ctx.options.commonjs.require_returns_default().returns_default(
+                      has_default_export,
+                      has_default_export && exports.len() == 1,
                     );
-                  }
-                }
-                _ => {
-                  // Rewrite `require(...)` to `require_xxx(...)` or `(init_xxx(), __toCommonJS(xxx_exports))`
-                  let importee_linking_info = &self.ctx.linking_infos[importee.idx];
-                  let wrap_ref_name =
-                    self.canonical_name_for(importee_linking_info.wrapper_ref.unwrap());
-                  if matches!(importee.exports_kind, ExportsKind::CommonJs) {
-                    *expr = self.snippet.call_expr_expr(wrap_ref_name);
+                *expr = self.snippet.seq2_in_paren_expr(
+                  self.snippet.call_expr_expr(wrap_ref_name),
+                  if returns_default {
+                    ast::Expression::StaticMemberExpression(
+                      ast::StaticMemberExpression {
+                        object: to_commonjs_expr,
+                        property: self.snippet.id_name("default", SPAN),
+                        ..TakeIn::dummy(self.alloc)
+                      }
+                      .into_in(self.alloc),
+                    )
                   } else {
-                    let ns_name = self.canonical_name_for(importee.namespace_object_ref);
-                    let to_commonjs_ref_name = self.canonical_name_for_runtime("__toCommonJS");
-                    *expr = self.snippet.seq2_in_paren_expr(
-                      self.snippet.call_expr_expr(wrap_ref_name),
-                      self.snippet.call_expr_with_arg_expr(to_commonjs_ref_name, ns_name),
-                    );
-                  }
-                }
+                    to_commonjs_expr
+                  },
+                );
               }
             }
             Module::External(importee) => {
               let request_path =
                 call_expr.arguments.get_mut(0).expect("require should have an argument");
 
+              let importer_chunk_id = self.ctx.chunk_graph.module_to_chunk[self.ctx.module.idx]
+                .expect("Normal module should belong to a chunk");
+              let import_path =
+                importee.import_path_for(&self.ctx.chunk_graph.chunk_table[importer_chunk_id]);
               // Rewrite `require('xxx')` to `require('fs')`, if there is an alias that maps 'xxx' to 'fs'
               *request_path = ast::Argument::StringLiteral(
-                self.snippet.alloc_string_literal(&importee.name, request_path.span()),
+                self.snippet.alloc_string_literal(&import_path, request_path.span()),
               );
             }
           }
+        } else if !self.ctx.options.commonjs.ignore_dynamic_requires() {
+          // The required module can't be bundled, so the call throws at runtime.
+          let dynamic_require_ref_name = self.canonical_name_for_runtime("__dynamicRequire");
+          call_expr.callee =
+            self.snippet.id_ref_expr(dynamic_require_ref_name, call_expr.callee.span());
         }
       }
     }
 
     self.try_rewrite_identifier_reference_expr(expr, false);
 
+    // inline `E.A` to the value of the member `A` of `const enum E`
+    if let Some(inlined_expr) = self.inlined_const_enum_member(expr) {
+      *expr = inlined_expr;
+      return;
+    }
+
+    if let Some(file_url) = self.file_url_expr(expr) {
+      *expr = file_url;
+      return;
+    }
+
     // rewrite `foo_exports.bar` to `bar` directly
     match expr {
       Expression::StaticMemberExpression(ref inner_expr) => {
@@ -512,6 +533,96 @@ impl<'me, 'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'me, 'ast> {
     }
 
     walk_mut::walk_expression(self, expr);
+
+    // `(function foo() {})` => `__name(function e() {}, "foo")`
+    if let Some(name) = kept_name {
+      *expr = self.keep_name_call_expr(expr.take_in(self.alloc), name);
+    }
+
+    if let Some(exported_names) = reassigned_export_names {
+      self.wrap_with_exports_call(expr, exported_names);
+    }
+
+    // rewrite `import('./foo.js')` to `module.import('./foo.js')`
+    if matches!(self.ctx.options.format, OutputFormat::System) {
+      if let Expression::ImportExpression(import_expr) = expr {
+        let span = import_expr.span;
+        let source = import_expr.source.take_in(self.alloc);
+        *expr = self.snippet.builder.expression_call(
+          span,
+          self.snippet.literal_prop_access_member_expr_expr("module", "import"),
+          NONE,
+          self.snippet.builder.vec1(ast::Argument::from(source)),
+          false,
+        );
+      }
+    }
+
+    // rewrite `import('./foo.js')` to `Promise.resolve().then(function() { return require('./foo.js'); })`
+    if matches!(self.ctx.options.format, OutputFormat::Cjs)
+      && !self.ctx.options.dynamic_import_in_cjs
+    {
+      if let Expression::ImportExpression(import_expr) = expr {
+        let span = import_expr.span;
+        // Only external modules need `__toESM(...)`, chunks are required as they are.
+        let interop = self.ctx.module.imports.get(&span).and_then(|rec_id| {
+          let rec = &self.ctx.module.import_records[*rec_id];
+          match &self.ctx.modules[rec.resolved_module] {
+            Module::External(importee) => importee.interop.to_esm_interop(&self.ctx.options.format),
+            Module::Normal(_) => None,
+          }
+        });
+        let source = import_expr.source.take_in(self.alloc);
+        let require_expr = self.snippet.builder.expression_call(
+          SPAN,
+          self.snippet.id_ref_expr("require", SPAN),
+          NONE,
+          self.snippet.builder.vec1(ast::Argument::from(source)),
+          false,
+        );
+        let require_expr = if interop.is_some() {
+          self.snippet.to_esm_call_with_interop(
+            self.canonical_name_for_runtime("__toESM"),
+            require_expr,
+            interop,
+          )
+        } else {
+          require_expr
+        };
+        *expr = self.snippet.promise_resolve_then_call_expr(
+          span,
+          self.snippet.builder.vec1(self.snippet.return_stmt(require_expr)),
+        );
+      }
+    }
+  }
+
+  fn visit_statements(&mut self, stmts: &mut allocator::Vec<'ast, ast::Statement<'ast>>) {
+    if !self.keeps_names() {
+      walk_mut::walk_statements(self, stmts);
+      return;
+    }
+    // Resolve them before the declarations get renamed
+    let kept_names = stmts.iter().map(|stmt| self.kept_name_of_stmt(stmt)).collect::<Vec<_>>();
+    walk_mut::walk_statements(self, stmts);
+
+    // `function foo() {}` => `function e() {} __name(e, "foo");`
+    let old_stmts = stmts.take_in(self.alloc);
+    for (stmt, kept_name) in old_stmts.into_iter().zip(kept_names) {
+      let keep_name_stmt = kept_name.and_then(|name| self.keep_name_stmt(&stmt, name));
+      stmts.push(stmt);
+      stmts.extend(keep_name_stmt);
+    }
+  }
+
+  fn visit_variable_declarator(&mut self, decl: &mut ast::VariableDeclarator<'ast>) {
+    let kept_name = self.kept_name_of_declarator(decl);
+    walk_mut::walk_variable_declarator(self, decl);
+
+    // `const foo = () => {}` => `const e = __name(() => {}, "foo")`
+    if let (Some(name), Some(init)) = (kept_name, &mut decl.init) {
+      *init = self.keep_name_call_expr(init.take_in(self.alloc), name);
+    }
   }
 
   fn visit_object_property(&mut self, prop: &mut ast::ObjectProperty<'ast>) {
@@ -598,8 +709,12 @@ impl<'me, 'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'me, 'ast> {
             str.value = self.snippet.atom(&import_path);
           }
           Module::External(importee) => {
-            if str.value != importee.name {
-              str.value = self.snippet.atom(&importee.name);
+            let importer_chunk_id = self.ctx.chunk_graph.module_to_chunk[self.ctx.module.idx]
+              .expect("Normal module should belong to a chunk");
+            let import_path =
+              importee.import_path_for(&self.ctx.chunk_graph.chunk_table[importer_chunk_id]);
+            if str.value != import_path {
+              str.value = self.snippet.atom(&import_path);
             }
           }
         }
@@ -610,6 +725,33 @@ impl<'me, 'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'me, 'ast> {
     walk_mut::walk_import_expression(self, expr);
   }
 
+  fn visit_new_expression(&mut self, expr: &mut ast::NewExpression<'ast>) {
+    // Point `new URL('./worker.js', import.meta.url)` of a worker to its entry chunk. An absolute url prefixed
+    // with `output.base` ignores `import.meta.url`.
+    if let Some(rec_id) = self.ctx.module.imports.get(&expr.span) {
+      let importee_id = self.ctx.module.import_records[*rec_id].resolved_module;
+      if let (Module::Normal(_), Some(ast::Argument::StringLiteral(str))) =
+        (&self.ctx.modules[importee_id], expr.arguments.first_mut())
+      {
+        let importer_chunk_id = self.ctx.chunk_graph.module_to_chunk[self.ctx.module.idx]
+          .expect("Normal module should belong to a chunk");
+        let importer_chunk = &self.ctx.chunk_graph.chunk_table[importer_chunk_id];
+
+        let importee_chunk_id = self.ctx.chunk_graph.entry_module_to_entry_chunk[&importee_id];
+        let importee_chunk = &self.ctx.chunk_graph.chunk_table[importee_chunk_id];
+
+        let url = importee_chunk
+          .absolute_preliminary_filename
+          .as_ref()
+          .and_then(|file_path| self.prefixed_url_for_file(file_path))
+          .unwrap_or_else(|| importer_chunk.import_path_for(importee_chunk));
+        str.value = self.snippet.atom(&url);
+      }
+    }
+
+    walk_mut::walk_new_expression(self, expr);
+  }
+
   fn visit_assignment_target_property(
     &mut self,
     property: &mut ast::AssignmentTargetProperty<'ast>,
@@ -0,0 +1,85 @@
+use oxc::{
+  ast::ast::{self, Expression, Statement},
+  span::SPAN,
+};
+use rolldown_common::SymbolRef;
+
+use super::ScopeHoistingFinalizer;
+
+impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
+  /// Whether names are restored by `__name` in this module. The runtime module defines `__name`, so it's
+  /// skipped.
+  pub fn keeps_names(&self) -> bool {
+    self.ctx.options.keep_names && self.ctx.id != self.ctx.runtime.id()
+  }
+
+  /// The name of the binding in the source. Bindings created by the bundler don't have a `SymbolId` and
+  /// return `None`.
+  fn original_name_of(&self, id: &ast::BindingIdentifier<'ast>) -> Option<&'me str> {
+    let symbol_id = id.symbol_id.get()?;
+    Some(SymbolRef::from((self.ctx.id, symbol_id)).name(self.ctx.symbol_db))
+  }
+
+  /// The name to keep for `function foo() {}` or `class Foo {}` used as an expression.
+  pub fn kept_name_of_expr(&self, expr: &Expression<'ast>) -> Option<&'me str> {
+    if !self.keeps_names() {
+      return None;
+    }
+    match expr {
+      Expression::FunctionExpression(func) => self.original_name_of(func.id.as_ref()?),
+      Expression::ClassExpression(class) => self.original_name_of(class.id.as_ref()?),
+      _ => None,
+    }
+  }
+
+  /// The name to keep for an anonymous function or class named after the binding it's assigned to, such as
+  /// `foo` in `const foo = () => {}`.
+  pub fn kept_name_of_declarator(&self, decl: &ast::VariableDeclarator<'ast>) -> Option<&'me str> {
+    if !self.keeps_names() {
+      return None;
+    }
+    let ast::BindingPatternKind::BindingIdentifier(id) = &decl.id.kind else {
+      return None;
+    };
+    match decl.init.as_ref()? {
+      Expression::ArrowFunctionExpression(_) => {}
+      Expression::FunctionExpression(func) if func.id.is_none() => {}
+      Expression::ClassExpression(class) if class.id.is_none() => {}
+      _ => return None,
+    }
+    self.original_name_of(id)
+  }
+
+  /// The name to keep for a function or class declaration.
+  pub fn kept_name_of_stmt(&self, stmt: &Statement<'ast>) -> Option<&'me str> {
+    if !self.keeps_names() {
+      return None;
+    }
+    match stmt {
+      Statement::FunctionDeclaration(func) => self.original_name_of(func.id.as_ref()?),
+      Statement::ClassDeclaration(class) => self.original_name_of(class.id.as_ref()?),
+      _ => None,
+    }
+  }
+
+  /// `__name(target, "name")`
+  pub fn keep_name_call_expr(&self, target: Expression<'ast>, name: &str) -> Expression<'ast> {
+    self.snippet.alloc_call_expr_with_2arg_expr_expr(
+      self.canonical_name_for_runtime("__name"),
+      target,
+      self.snippet.string_literal_expr(name, SPAN),
+    )
+  }
+
+  /// `__name(foo, "name");` placed after the declaration of `foo`, if `stmt` still declares a function or
+  /// class after being finalized.
+  pub fn keep_name_stmt(&self, stmt: &Statement<'ast>, name: &str) -> Option<Statement<'ast>> {
+    let id = match stmt {
+      Statement::FunctionDeclaration(func) => func.id.as_ref()?,
+      Statement::ClassDeclaration(class) => class.id.as_ref()?,
+      _ => return None,
+    };
+    let target = self.snippet.id_ref_expr(&id.name, SPAN);
+    Some(self.snippet.builder.statement_expression(SPAN, self.keep_name_call_expr(target, name)))
+  }
+}
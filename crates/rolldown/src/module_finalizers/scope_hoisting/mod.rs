@@ -1,13 +1,21 @@
 use oxc::{
   allocator::{Allocator, IntoIn},
-  ast::ast::{self, IdentifierReference, Statement},
+  ast::{
+    ast::{self, IdentifierReference, Statement},
+    NONE,
+  },
   span::{Atom, SPAN},
+  syntax::operator::{BinaryOperator, UnaryOperator, UpdateOperator},
+};
+use rolldown_common::{
+  AstScopes, ConstEnumValue, ImportRecordIdx, Module, OutputFormat, SymbolRef, WrapKind,
 };
-use rolldown_common::{AstScopes, ImportRecordIdx, Module, OutputFormat, SymbolRef, WrapKind};
 use rolldown_ecmascript::{AstSnippet, BindingPatternExt, TakeIn};
 
+mod file_url;
 mod finalizer_context;
 mod impl_visit_mut;
+mod keep_names;
 pub use finalizer_context::ScopeHoistingFinalizerContext;
 use rolldown_rstr::Rstr;
 use rolldown_utils::ecma_script::is_validate_identifier_name;
@@ -197,68 +205,12 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
 
     let exports_len = self.ctx.linking_info.canonical_exports().count();
 
-    let export_all_externals_rec_ids = &self.ctx.linking_info.star_exports_from_external_modules;
-
-    let mut re_export_external_stmts: Option<_> = None;
-    if !export_all_externals_rec_ids.is_empty() {
-      // construct `__reExport(exports, foo_exports)`
-      let re_export_fn_name = self.canonical_name_for_runtime("__reExport");
-      match self.ctx.options.format {
-        OutputFormat::Esm => {
-          let stmts = export_all_externals_rec_ids.iter().copied().flat_map(|idx| {
-            let rec = &self.ctx.module.import_records[idx];
-            let importee_namespace_name = self.canonical_name_for(rec.namespace_ref);
-            let m = self.ctx.modules.get(rec.resolved_module);
-            let Some(Module::External(module)) = m else {
-              return vec![];
-            };
-            // Insert `import * as ns from 'ext'`external module in esm format
-            // Insert `__reExport(exports, ns)`
-            let importee_name = &module.name;
-            vec![
-              self.snippet.import_star_stmt(importee_name, importee_namespace_name),
-              self.snippet.builder.statement_expression(
-                SPAN,
-                self.snippet.call_expr_with_2arg_expr(
-                  re_export_fn_name,
-                  var_name,
-                  importee_namespace_name,
-                ),
-              ),
-            ]
-          });
-          re_export_external_stmts = Some(stmts.collect::<Vec<_>>());
-        }
-        OutputFormat::Cjs | OutputFormat::Iife | OutputFormat::Umd => {
-          let stmts = export_all_externals_rec_ids.iter().copied().map(|idx| {
-            // Insert `__reExport(exports, require('ext'))`
-            let importer_namespace_name =
-              self.canonical_name_for(self.ctx.module.namespace_object_ref);
-            let rec = &self.ctx.module.import_records[idx];
-            let importee = &self.ctx.modules[rec.resolved_module];
-            let stmt: ast::Statement = self
-              .snippet
-              .alloc_call_expr_with_2arg_expr_expr(
-                re_export_fn_name,
-                self.snippet.id_ref_expr(importer_namespace_name, SPAN),
-                self.snippet.call_expr_with_arg_expr_expr(
-                  "require",
-                  self.snippet.string_literal_expr(importee.id(), SPAN),
-                ),
-              )
-              .into_in(self.alloc);
-
-            stmt
-          });
-          re_export_external_stmts = Some(stmts.collect());
-        }
-        OutputFormat::App => unreachable!(),
-      }
-    };
+    let re_export_external_stmts = self.generate_re_exports_of_external_modules(var_name);
 
     if exports_len == 0 {
       let mut ret = vec![decl_stmt];
-      ret.extend(re_export_external_stmts.unwrap_or_default());
+      ret.extend(re_export_external_stmts);
+      ret.extend(self.generate_freeze_of_module_namespace_object(var_name));
       return ret;
     }
 
@@ -279,7 +231,11 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
           } else {
             ast::PropertyKey::StringLiteral(self.snippet.alloc_string_literal(prop_name, SPAN))
           },
-          value: self.snippet.only_return_arrow_expr(returned),
+          value: if self.ctx.options.generated_code.arrow_function_helpers() {
+            self.snippet.only_return_arrow_expr(returned)
+          } else {
+            self.snippet.only_return_function_expr(returned)
+          },
           ..TakeIn::dummy(self.alloc)
         }
         .into_in(self.alloc),
@@ -300,8 +256,222 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
       .into_in(self.alloc),
     );
     let mut ret = vec![decl_stmt, export_call_stmt];
-    ret.extend(re_export_external_stmts.unwrap_or_default());
+    ret.extend(re_export_external_stmts);
+    ret.extend(self.generate_freeze_of_module_namespace_object(var_name));
 
     ret
   }
+
+  /// Constructs `__reExport(ns_name, ns)` for the external modules re-exported by `export * from 'ext'`,
+  /// with the imports of them that the format needs.
+  fn generate_re_exports_of_external_modules(&self, var_name: &str) -> Vec<ast::Statement<'ast>> {
+    let export_all_externals_rec_ids = &self.ctx.linking_info.star_exports_from_external_modules;
+
+    if export_all_externals_rec_ids.is_empty() {
+      return vec![];
+    }
+
+    // construct `__reExport(exports, foo_exports)`
+    let re_export_fn_name = self.canonical_name_for_runtime("__reExport");
+    match self.ctx.options.format {
+      OutputFormat::Esm => {
+        let stmts = export_all_externals_rec_ids.iter().copied().flat_map(|idx| {
+          let rec = &self.ctx.module.import_records[idx];
+          let importee_namespace_name = self.canonical_name_for(rec.namespace_ref);
+          let m = self.ctx.modules.get(rec.resolved_module);
+          let Some(Module::External(module)) = m else {
+            return vec![];
+          };
+          // Insert `import * as ns from 'ext'`external module in esm format
+          // Insert `__reExport(exports, ns)`
+          let importee_name = &module.name;
+          vec![
+            self.snippet.import_star_stmt(importee_name, importee_namespace_name),
+            self.snippet.builder.statement_expression(
+              SPAN,
+              self.snippet.call_expr_with_2arg_expr(
+                re_export_fn_name,
+                var_name,
+                importee_namespace_name,
+              ),
+            ),
+          ]
+        });
+        stmts.collect()
+      }
+      OutputFormat::System => {
+        // The namespace of the external module is assigned by the setter of `System.register`.
+        // Insert `__reExport(exports, ns)`
+        let stmts = export_all_externals_rec_ids.iter().copied().map(|idx| {
+          let rec = &self.ctx.module.import_records[idx];
+          let importee_namespace_name = self.canonical_name_for(rec.namespace_ref);
+          self.snippet.builder.statement_expression(
+            SPAN,
+            self.snippet.call_expr_with_2arg_expr(
+              re_export_fn_name,
+              var_name,
+              importee_namespace_name,
+            ),
+          )
+        });
+        stmts.collect()
+      }
+      OutputFormat::Cjs | OutputFormat::Iife | OutputFormat::Umd => {
+        let stmts = export_all_externals_rec_ids.iter().copied().map(|idx| {
+          // Insert `__reExport(exports, require('ext'))`
+          let importer_namespace_name =
+            self.canonical_name_for(self.ctx.module.namespace_object_ref);
+          let rec = &self.ctx.module.import_records[idx];
+          let importee = &self.ctx.modules[rec.resolved_module];
+          let stmt: ast::Statement = self
+            .snippet
+            .alloc_call_expr_with_2arg_expr_expr(
+              re_export_fn_name,
+              self.snippet.id_ref_expr(importer_namespace_name, SPAN),
+              self.snippet.call_expr_with_arg_expr_expr(
+                "require",
+                self.snippet.string_literal_expr(importee.id(), SPAN),
+              ),
+            )
+            .into_in(self.alloc);
+
+          stmt
+        });
+        stmts.collect()
+      }
+      OutputFormat::App => unreachable!(),
+    }
+  }
+
+  /// Constructs `Object.freeze(ns_name)` with `output.freeze`. Namespaces with dynamic exports are filled by
+  /// `__reExport(...)` later on, so they are left unfrozen.
+  fn generate_freeze_of_module_namespace_object(
+    &self,
+    var_name: &str,
+  ) -> Option<ast::Statement<'ast>> {
+    if !self.ctx.options.freeze || self.ctx.linking_info.has_dynamic_exports {
+      return None;
+    }
+    let mut freeze_call_expr = self.snippet.builder.call_expression(
+      SPAN,
+      self.snippet.literal_prop_access_member_expr_expr("Object", "freeze"),
+      NONE,
+      self.snippet.builder.vec(),
+      false,
+    );
+    freeze_call_expr.arguments.push(ast::Argument::from(self.snippet.id_ref_expr(var_name, SPAN)));
+    Some(self.snippet.builder.statement_expression(
+      SPAN,
+      ast::Expression::CallExpression(freeze_call_expr.into_in(self.alloc)),
+    ))
+  }
+
+  /// Returns the exported names of the binding reassigned by `a = 1` or `a++`, if the output format is system.
+  fn reassigned_export_names(&self, expr: &ast::Expression<'ast>) -> Option<&'me [Rstr]> {
+    let reassignable_exports = self.ctx.reassignable_exports?;
+    let id_ref = match expr {
+      ast::Expression::AssignmentExpression(assign_expr) => match &assign_expr.left {
+        ast::AssignmentTarget::AssignmentTargetIdentifier(id_ref) => id_ref,
+        _ => return None,
+      },
+      ast::Expression::UpdateExpression(update_expr) => match &update_expr.argument {
+        ast::SimpleAssignmentTarget::AssignmentTargetIdentifier(id_ref) => id_ref,
+        _ => return None,
+      },
+      _ => return None,
+    };
+    let symbol_id = self.scope.symbol_id_for(id_ref.reference_id.get()?)?;
+    let canonical_ref = self.ctx.symbol_db.canonical_ref_for((self.ctx.id, symbol_id).into());
+    reassignable_exports.get(&canonical_ref).map(Vec::as_slice)
+  }
+
+  /// Returns the literal of `E.A` or `E['A']` if `E` is a `const enum`, declared in any module of the
+  /// build, and the value of its member `A` is known at compile time.
+  fn inlined_const_enum_member(
+    &self,
+    expr: &ast::Expression<'ast>,
+  ) -> Option<ast::Expression<'ast>> {
+    let (object, property, span) = match expr {
+      ast::Expression::StaticMemberExpression(member_expr) => {
+        (&member_expr.object, member_expr.property.name.as_str(), member_expr.span)
+      }
+      ast::Expression::ComputedMemberExpression(member_expr) => match &member_expr.expression {
+        ast::Expression::StringLiteral(lit) => {
+          (&member_expr.object, lit.value.as_str(), member_expr.span)
+        }
+        _ => return None,
+      },
+      _ => return None,
+    };
+    let ast::Expression::Identifier(id_ref) = object else {
+      return None;
+    };
+    let symbol_id = self.scope.symbol_id_for(id_ref.reference_id.get()?)?;
+    let canonical_ref = self.ctx.symbol_db.canonical_ref_for((self.ctx.id, symbol_id).into());
+    let Module::Normal(owner) = &self.ctx.modules[canonical_ref.owner] else {
+      return None;
+    };
+    match owner.const_enums.get(&canonical_ref.symbol)?.get(property)? {
+      ConstEnumValue::String(value) => Some(self.snippet.string_literal_expr(value, span)),
+      // `NaN` and `Infinity` might be shadowed, so these members are still read from the enum object.
+      ConstEnumValue::Number(value) if !value.is_finite() => None,
+      ConstEnumValue::Number(value) => {
+        let raw = self.alloc.alloc_str(&value.abs().to_string());
+        let literal = self.snippet.number_expr(value.abs(), raw);
+        if value.is_sign_negative() {
+          Some(self.snippet.builder.expression_unary(span, UnaryOperator::UnaryNegation, literal))
+        } else {
+          Some(literal)
+        }
+      }
+    }
+  }
+
+  /// Rewrites `a = 1` to `exports('a', a = 1)` and `a++` to `(exports('a', a + 1), a++)`, so the importers of
+  /// the chunk see the new value in system format.
+  fn wrap_with_exports_call(&self, expr: &mut ast::Expression<'ast>, exported_names: &[Rstr]) {
+    let postfix_update = match expr {
+      ast::Expression::UpdateExpression(update_expr) if !update_expr.prefix => {
+        match &update_expr.argument {
+          ast::SimpleAssignmentTarget::AssignmentTargetIdentifier(id_ref) => {
+            let operator = match update_expr.operator {
+              UpdateOperator::Increment => BinaryOperator::Addition,
+              UpdateOperator::Decrement => BinaryOperator::Subtraction,
+            };
+            Some((id_ref.name.clone(), operator))
+          }
+          _ => None,
+        }
+      }
+      _ => None,
+    };
+
+    let export_call = |name: &Rstr, value: ast::Expression<'ast>| {
+      let mut call_expr = self.snippet.call_expr("exports");
+      call_expr
+        .arguments
+        .push(ast::Argument::from(self.snippet.string_literal_expr(name.as_str(), SPAN)));
+      call_expr.arguments.push(ast::Argument::from(value));
+      ast::Expression::CallExpression(call_expr.into_in(self.alloc))
+    };
+
+    if let Some((name, operator)) = postfix_update {
+      let mut new_value = self.snippet.builder.expression_binary(
+        SPAN,
+        self.snippet.builder.expression_identifier_reference(SPAN, name),
+        operator,
+        self.snippet.number_expr(1.0, "1"),
+      );
+      for exported_name in exported_names {
+        new_value = export_call(exported_name, new_value);
+      }
+      *expr = self.snippet.seq2_in_paren_expr(new_value, expr.take_in(self.alloc));
+    } else {
+      let mut new_expr = expr.take_in(self.alloc);
+      for exported_name in exported_names {
+        new_expr = export_call(exported_name, new_expr);
+      }
+      *expr = new_expr;
+    }
+  }
 }
@@ -7,22 +7,28 @@ use crate::module_loader::runtime_module_task::RuntimeModuleTaskResult;
 use crate::module_loader::task_context::TaskContext;
 use crate::runtime::{RuntimeModuleBrief, RUNTIME_MODULE_ID};
 use crate::type_alias::IndexEcmaAst;
+use crate::types::build_stats::ModuleRequestStats;
+use crate::utils::load_source::get_module_loader_from_file_extension;
+use crate::utils::log_level::is_severity_enabled;
 use arcstr::ArcStr;
 use oxc::index::IndexVec;
 use oxc::span::Span;
 use oxc::transformer::ReplaceGlobalDefinesConfig;
 use rolldown_common::side_effects::{DeterminedSideEffects, HookSideEffects};
 use rolldown_common::{
-  EntryPoint, EntryPointKind, ExternalModule, ImportKind, ImportRecordIdx, ImporterRecord, Module,
-  ModuleIdx, ModuleTable, ResolvedId, SymbolNameRefToken, SymbolRefDb,
+  EmittedAsset, EntryPoint, EntryPointKind, ExternalModule, ImportKind, ImportRecordIdx,
+  ImporterRecord, Module, ModuleIdx, ModuleTable, ModuleType, ResolvedId, SymbolNameRefToken,
+  SymbolRefDb,
 };
 use rolldown_error::{BuildDiagnostic, BuildResult};
-use rolldown_fs::OsFileSystem;
+use rolldown_fs::{FileSystem, OsFileSystem};
 use rolldown_plugin::SharedPluginDriver;
 use rolldown_utils::ecma_script::legitimize_identifier_name;
+use rolldown_utils::path_ext::PathExt;
 use rolldown_utils::rustc_hash::FxHashSetExt;
 use rustc_hash::{FxHashMap, FxHashSet};
 use std::sync::Arc;
+use sugar_path::SugarPath;
 
 use crate::{SharedOptions, SharedResolver};
 
@@ -53,12 +59,23 @@ pub struct ModuleLoader {
   shared_context: Arc<TaskContext>,
   rx: tokio::sync::mpsc::Receiver<Msg>,
   visited: FxHashMap<ArcStr, ModuleIdx>,
+  // Requests of an already visited module are counted as deduplicated.
+  module_requests: ModuleRequestStats,
   runtime_id: ModuleIdx,
   remaining: u32,
   intermediate_normal_modules: IntermediateNormalModules,
   symbol_ref_db: SymbolRefDb,
 }
 
+/// A chunk emitted by `PluginContext#emit_chunk`, whose ids are already resolved.
+pub struct ResolvedEmittedChunk {
+  pub reference_id: ArcStr,
+  pub name: Option<ArcStr>,
+  pub file_name: Option<ArcStr>,
+  pub resolved_id: ResolvedId,
+  pub implicitly_loaded_after_one_of: Vec<ArcStr>,
+}
+
 pub struct ModuleLoaderOutput {
   // Stored all modules
   pub module_table: ModuleTable,
@@ -68,6 +85,8 @@ pub struct ModuleLoaderOutput {
   pub entry_points: Vec<EntryPoint>,
   pub runtime: RuntimeModuleBrief,
   pub warnings: Vec<BuildDiagnostic>,
+  pub module_requests: ModuleRequestStats,
+  pub bytes_read: u64,
 }
 
 impl ModuleLoader {
@@ -111,7 +130,7 @@ impl ModuleLoader {
     let symbols = SymbolRefDb::default();
     let runtime_id = intermediate_normal_modules.alloc_ecma_module_idx();
 
-    let task = RuntimeModuleTask::new(runtime_id, tx_to_runtime_module);
+    let task = RuntimeModuleTask::new(runtime_id, tx_to_runtime_module, Arc::clone(&options));
 
     #[cfg(target_family = "wasm")]
     {
@@ -130,6 +149,7 @@ impl ModuleLoader {
       rx,
       options,
       visited: FxHashMap::from_iter([(RUNTIME_MODULE_ID.into(), runtime_id)]),
+      module_requests: ModuleRequestStats::default(),
       runtime_id,
       // runtime module is always there
       remaining: 1,
@@ -144,9 +164,19 @@ impl ModuleLoader {
     owner: Option<ModuleTaskOwner>,
   ) -> ModuleIdx {
     match self.visited.entry(resolved_id.id.clone()) {
-      std::collections::hash_map::Entry::Occupied(visited) => *visited.get(),
+      std::collections::hash_map::Entry::Occupied(visited) => {
+        self.module_requests.deduplicated += 1;
+        *visited.get()
+      }
       std::collections::hash_map::Entry::Vacant(not_visited) => {
-        if resolved_id.is_external {
+        self.module_requests.unique += 1;
+        // Entries are always bundled, even if they have the `copy` module type.
+        let copied_asset_reference_id = if resolved_id.is_external || owner.is_none() {
+          None
+        } else {
+          Self::try_emit_copied_asset(&self.shared_context, &resolved_id)
+        };
+        if resolved_id.is_external || copied_asset_reference_id.is_some() {
           let idx = self.intermediate_normal_modules.alloc_ecma_module_idx();
           not_visited.insert(idx);
           let external_module_side_effects = if let Some(hook_side_effects) =
@@ -164,19 +194,27 @@ impl ModuleLoader {
               }
               rolldown_common::TreeshakeOptions::Boolean(true) => unreachable!(),
               rolldown_common::TreeshakeOptions::Option(ref opt) => match opt.module_side_effects {
-                rolldown_common::ModuleSideEffects::Boolean(false) => {
+                rolldown_common::ModuleSideEffects::Boolean(false)
+                | rolldown_common::ModuleSideEffects::NoExternal => {
                   DeterminedSideEffects::UserDefined(false)
                 }
                 _ => DeterminedSideEffects::NoTreeshake,
               },
             }
           };
-          let ext = ExternalModule::new(
+          let binding_name: ArcStr = if copied_asset_reference_id.is_some() {
+            legitimize_identifier_name(&resolved_id.id.as_path().representative_file_name()).into()
+          } else {
+            legitimize_identifier_name(&resolved_id.id).into()
+          };
+          let mut ext = ExternalModule::new(
             idx,
             ArcStr::clone(&resolved_id.id),
             external_module_side_effects,
-            SymbolNameRefToken::new(idx, legitimize_identifier_name(&resolved_id.id).into()),
+            SymbolNameRefToken::new(idx, binding_name),
           );
+          ext.copied_asset_reference_id = copied_asset_reference_id;
+          ext.attributes = owner.and_then(|owner| owner.attributes);
           self.intermediate_normal_modules.modules[idx] = Some(ext.into());
           idx
         } else {
@@ -201,10 +239,30 @@ impl ModuleLoader {
     }
   }
 
+  /// Emits the file of `resolved_id` as an asset if it has the `copy` module type, so it's imported
+  /// from the output directory instead of being bundled. Returns the reference id of the asset.
+  fn try_emit_copied_asset(ctx: &TaskContext, resolved_id: &ResolvedId) -> Option<ArcStr> {
+    let module_type =
+      get_module_loader_from_file_extension(&resolved_id.id, &ctx.options.module_types)?;
+    if module_type != ModuleType::Copy {
+      return None;
+    }
+    let path = resolved_id.id.as_path();
+    // Files that can't be read are loaded as usual, so the module task reports the error.
+    let content = ctx.fs.read(path).ok()?;
+    Some(ctx.plugin_driver.file_emitter().emit_file(EmittedAsset {
+      name: path.file_name().map(|name| name.to_string_lossy().into_owned()),
+      original_file_name: Some(path.to_string_lossy().into_owned()),
+      file_name: None,
+      source: content.into(),
+    }))
+  }
+
   #[tracing::instrument(level = "debug", skip_all)]
   pub async fn fetch_all_modules(
     mut self,
     user_defined_entries: Vec<(Option<ArcStr>, ResolvedId)>,
+    emitted_chunks: Vec<ResolvedEmittedChunk>,
   ) -> anyhow::Result<BuildResult<ModuleLoaderOutput>> {
     if self.options.input.is_empty() {
       return Err(anyhow::format_err!("You must supply options.input to rolldown"));
@@ -213,7 +271,7 @@ impl ModuleLoader {
     let mut errors = vec![];
     let mut all_warnings: Vec<BuildDiagnostic> = vec![];
 
-    let entries_count = user_defined_entries.len() + /* runtime */ 1;
+    let entries_count = user_defined_entries.len() + emitted_chunks.len() + /* runtime */ 1;
     self.intermediate_normal_modules.modules.reserve(entries_count);
     self.intermediate_normal_modules.index_ecma_ast.reserve(entries_count);
 
@@ -224,18 +282,56 @@ impl ModuleLoader {
       .into_iter()
       .map(|(name, info)| EntryPoint {
         name,
+        file_name: None,
         id: self.try_spawn_new_task(info, /* is_user_defined_entry */ None),
         kind: EntryPointKind::UserDefined,
+        reference_ids: vec![],
+        implicitly_loaded_after: vec![],
       })
       .inspect(|e| {
         user_defined_entry_ids.insert(e.id);
       })
       .collect::<Vec<_>>();
 
+    // Emitted chunks are considered as user-defined entries too.
+    let mut implicitly_loaded_after_one_of = vec![];
+    for emitted_chunk in emitted_chunks {
+      let id = self.try_spawn_new_task(emitted_chunk.resolved_id, None);
+      if let Some(entry_point) = entry_points.iter_mut().find(|e| e.id == id) {
+        // Same as rollup, `implicitly_loaded_after_one_of` is ignored if the module is already an entry.
+        entry_point.reference_ids.push(emitted_chunk.reference_id);
+        if entry_point.file_name.is_none() {
+          entry_point.file_name = emitted_chunk.file_name;
+        }
+        continue;
+      }
+      user_defined_entry_ids.insert(id);
+      implicitly_loaded_after_one_of
+        .push((entry_points.len(), emitted_chunk.implicitly_loaded_after_one_of));
+      entry_points.push(EntryPoint {
+        name: emitted_chunk.name,
+        file_name: emitted_chunk.file_name,
+        id,
+        kind: EntryPointKind::UserDefined,
+        reference_ids: vec![emitted_chunk.reference_id],
+        implicitly_loaded_after: vec![],
+      });
+    }
+    for (entry_index, ids) in implicitly_loaded_after_one_of {
+      entry_points[entry_index].implicitly_loaded_after = ids
+        .iter()
+        .filter_map(|id| self.visited.get(id).copied())
+        .filter(|idx| user_defined_entry_ids.contains(idx))
+        .collect();
+    }
+
     let mut dynamic_import_entry_ids = FxHashSet::default();
+    let mut worker_entry_ids = FxHashSet::default();
 
     let mut runtime_brief: Option<RuntimeModuleBrief> = None;
 
+    let mut bytes_read = 0u64;
+
     while self.remaining > 0 {
       let Some(msg) = self.rx.recv().await else {
         break;
@@ -248,9 +344,17 @@ impl ModuleLoader {
             mut module,
             raw_import_records,
             warnings,
+            errors: module_errors,
+            bytes_read: module_bytes_read,
             ecma_related,
           } = task_result;
-          all_warnings.extend(warnings);
+          all_warnings.extend(
+            warnings
+              .into_iter()
+              .filter(|warning| is_severity_enabled(self.options.log_level, warning.severity())),
+          );
+          errors.extend(module_errors);
+          bytes_read += module_bytes_read as u64;
 
           let import_records: IndexVec<ImportRecordIdx, rolldown_common::ResolvedImportRecord> =
             raw_import_records
@@ -262,6 +366,7 @@ impl ModuleLoader {
                   normal_module.source.clone(),
                   normal_module.stable_id.as_str().into(),
                   Span::new(raw_rec.module_request_start, raw_rec.module_request_end()),
+                  raw_rec.attributes.clone(),
                 );
                 let id = self.try_spawn_new_task(info, Some(owner));
                 // Dynamic imported module will be considered as an entry
@@ -269,10 +374,17 @@ impl ModuleLoader {
                   kind: raw_rec.kind,
                   importer_path: module.id().to_string().into(),
                 });
-                if matches!(raw_rec.kind, ImportKind::DynamicImport)
-                  && !user_defined_entry_ids.contains(&id)
-                {
-                  dynamic_import_entry_ids.insert(id);
+                if !user_defined_entry_ids.contains(&id) {
+                  match raw_rec.kind {
+                    ImportKind::DynamicImport => {
+                      dynamic_import_entry_ids.insert(id);
+                    }
+                    // Workers run in their own global scope, so they are always separate entries.
+                    ImportKind::NewUrl => {
+                      worker_entry_ids.insert(id);
+                    }
+                    _ => {}
+                  }
                 }
                 raw_rec.into_resolved(id)
               })
@@ -331,16 +443,20 @@ impl ModuleLoader {
       .collect();
 
     // if `inline_dynamic_imports` is set to be true, here we should not put dynamic imports to entries
-    if !self.options.inline_dynamic_imports {
-      let mut dynamic_import_entry_ids = dynamic_import_entry_ids.into_iter().collect::<Vec<_>>();
-      dynamic_import_entry_ids.sort_unstable_by_key(|id| modules[*id].stable_id());
-
-      entry_points.extend(dynamic_import_entry_ids.into_iter().map(|id| EntryPoint {
-        name: None,
-        id,
-        kind: EntryPointKind::DynamicImport,
-      }));
+    if self.options.inline_dynamic_imports {
+      dynamic_import_entry_ids.clear();
     }
+    dynamic_import_entry_ids.extend(worker_entry_ids);
+    let mut dynamic_import_entry_ids = dynamic_import_entry_ids.into_iter().collect::<Vec<_>>();
+    dynamic_import_entry_ids.sort_unstable_by_key(|id| modules[*id].stable_id());
+    entry_points.extend(dynamic_import_entry_ids.into_iter().map(|id| EntryPoint {
+      name: None,
+      file_name: None,
+      id,
+      kind: EntryPointKind::DynamicImport,
+      reference_ids: vec![],
+      implicitly_loaded_after: vec![],
+    }));
 
     Ok(Ok(ModuleLoaderOutput {
       module_table: ModuleTable { modules },
@@ -349,6 +465,8 @@ impl ModuleLoader {
       entry_points,
       runtime: runtime_brief.expect("Failed to find runtime module. This should not happen"),
       warnings: all_warnings,
+      module_requests: self.module_requests,
+      bytes_read,
     }))
   }
 }
@@ -1,24 +1,38 @@
 use arcstr::ArcStr;
 use futures::future::join_all;
 use oxc::{index::IndexVec, span::Span};
-use rolldown_plugin::{SharedPluginDriver, __inner::resolve_id_check_external};
+use rolldown_plugin::{
+  HookTransformCssArgs, SharedPluginDriver, __inner::resolve_id_check_external,
+};
 use rolldown_resolver::ResolveError;
 use rolldown_rstr::Rstr;
-use rolldown_utils::{ecma_script::legitimize_identifier_name, path_ext::PathExt};
-use std::sync::Arc;
+use rolldown_utils::{
+  base64::to_standard_base64, ecma_script::legitimize_identifier_name, mime::guess_mime,
+  path_ext::PathExt,
+};
+use rustc_hash::FxHashMap;
+use std::{path::Path, sync::Arc};
 use sugar_path::SugarPath;
 
 use rolldown_common::{
-  ImportKind, ImportRecordIdx, ModuleDefFormat, ModuleId, ModuleIdx, ModuleType, NormalModule,
-  RawImportRecord, ResolvedId, StrOrBytes,
+  side_effects::HookSideEffects, EmittedAsset, ExportsKind, ImportAttributes, ImportKind,
+  ImportRecordIdx, ModuleDefFormat, ModuleId, ModuleIdx, ModuleType, NormalModule, RawImportRecord,
+  ResolvedId, StrOrBytes, SymbolRefDbForModule, TreeshakeOptions,
 };
+use rolldown_css::{parse_css_targets, CssCompiler};
+use rolldown_ecmascript::EcmaAst;
 use rolldown_error::{
-  BuildDiagnostic, BuildResult, DiagnosableArcstr, UnloadableDependencyContext,
+  BuildDiagnostic, BuildResult, DiagnosableArcstr, DiagnosticOptions, UnloadableDependencyContext,
 };
+use rolldown_loader_utils::{text_to_string_literal, wasm_to_esm, WasmSource};
 
 use super::{task_context::TaskContext, Msg};
 use crate::{
-  css::create_css_view,
+  css::{
+    create_css_view,
+    local_classes::{is_css_module_id, render_css_module_exports, scope_css_module},
+    resolve_url_reference_assets,
+  },
   ecmascript::ecma_module_view_factory::{create_ecma_view, CreateEcmaViewReturn},
   module_loader::NormalModuleTaskResult,
   runtime::RUNTIME_MODULE_ID,
@@ -27,15 +41,26 @@ use crate::{
   SharedOptions, SharedResolver,
 };
 
+/// Prefix of the ids of placeholder modules, which replace imports that can't be resolved if the build
+/// doesn't bail.
+const UNRESOLVED_MODULE_ID_PREFIX: &str = "\0rolldown:unresolved:";
+
 pub struct ModuleTaskOwner {
   source: ArcStr,
   importer_id: Rstr,
   importee_span: Span,
+  /// The attributes of the import that loads the module first.
+  pub attributes: Option<ImportAttributes>,
 }
 
 impl ModuleTaskOwner {
-  pub fn new(source: ArcStr, importer_id: Rstr, importee_span: Span) -> Self {
-    ModuleTaskOwner { source, importer_id, importee_span }
+  pub fn new(
+    source: ArcStr,
+    importer_id: Rstr,
+    importee_span: Span,
+    attributes: Option<ImportAttributes>,
+  ) -> Self {
+    ModuleTaskOwner { source, importer_id, importee_span, attributes }
   }
 }
 
@@ -61,20 +86,133 @@ impl ModuleTask {
 
   #[tracing::instrument(name="NormalModuleTask::run", level = "trace", skip_all, fields(module_id = ?self.resolved_id.id))]
   pub async fn run(mut self) {
-    match self.run_inner().await {
-      Ok(()) => {
-        if !self.errors.is_empty() {
-          self.ctx.tx.send(Msg::BuildErrors(self.errors)).await.expect("Send should not fail");
-        }
-      }
-      Err(errs) => {
-        self.ctx.tx.send(Msg::BuildErrors(errs.into_vec())).await.expect("Send should not fail");
+    // Errors left in `self.errors` once `run_inner` returns stopped the module from being loaded.
+    let errors = match self.run_inner().await {
+      Ok(()) => std::mem::take(&mut self.errors),
+      Err(errs) => errs.into_vec(),
+    };
+    if errors.is_empty() {
+      return;
+    }
+    // The module is replaced with a placeholder, rather than leaving a hole in the module graph, if the
+    // build recovers from errors or keeps going to report all of them.
+    let errors = if self.ctx.options.error_recovery || !self.ctx.options.bail {
+      match self.replace_with_placeholder(errors).await {
+        Ok(()) => return,
+        Err(errors) => errors,
       }
+    } else {
+      errors
+    };
+    self.ctx.tx.send(Msg::BuildErrors(errors)).await.expect("Send should not fail");
+  }
+
+  /// Replaces the module that failed to load with one throwing `errors` once it's imported. With
+  /// `error_recovery`, they are reported as warnings, so the rest of the bundle is still emitted.
+  async fn replace_with_placeholder(
+    &self,
+    errors: Vec<BuildDiagnostic>,
+  ) -> Result<(), Vec<BuildDiagnostic>> {
+    let opts = DiagnosticOptions { cwd: self.ctx.options.cwd.clone() };
+    let message = errors.iter().map(|err| err.message(&opts)).collect::<Vec<_>>().join("\n");
+    let mut warnings = vec![];
+    let placeholder = match self.create_placeholder_module(&message, &mut warnings).await {
+      Ok(ret) => ret,
+      Err(err) => return Err(errors.into_iter().chain(err.into_vec()).collect()),
+    };
+    let errors = if self.ctx.options.error_recovery {
+      warnings.extend(errors.into_iter().map(BuildDiagnostic::with_severity_warning));
+      vec![]
+    } else {
+      errors
+    };
+    self.send_placeholder_module(placeholder, warnings, errors).await;
+    Ok(())
+  }
+
+  async fn send_placeholder_module(
+    &self,
+    (module, ast, symbols): (NormalModule, EcmaAst, SymbolRefDbForModule),
+    warnings: Vec<BuildDiagnostic>,
+    errors: Vec<BuildDiagnostic>,
+  ) {
+    if let Err(_err) = self
+      .ctx
+      .tx
+      .send(Msg::NormalModuleDone(NormalModuleTaskResult {
+        resolved_deps: IndexVec::default(),
+        module_idx: self.module_idx,
+        warnings,
+        errors,
+        bytes_read: 0,
+        ecma_related: Some((ast, symbols)),
+        module: module.into(),
+        raw_import_records: IndexVec::default(),
+      }))
+      .await
+    {
+      // The main thread is dead, nothing we can do to handle these send failures.
     }
   }
 
+  async fn create_placeholder_module(
+    &self,
+    message: &str,
+    warnings: &mut Vec<BuildDiagnostic>,
+  ) -> BuildResult<(NormalModule, EcmaAst, SymbolRefDbForModule)> {
+    let source = format!("throw new Error({});\n", text_to_string_literal(message)?);
+    let id = ModuleId::new(ArcStr::clone(&self.resolved_id.id));
+    let stable_id = id.stabilize(&self.ctx.options.cwd);
+    let repr_name = self.resolved_id.id.as_path().representative_file_name().into_owned();
+
+    let CreateEcmaViewReturn { view: mut ecma_view, ast, symbols, .. } = create_ecma_view(
+      &mut CreateModuleContext {
+        module_index: self.module_idx,
+        plugin_driver: &self.ctx.plugin_driver,
+        resolved_id: &self.resolved_id,
+        options: &self.ctx.options,
+        warnings,
+        errors: &mut vec![],
+        module_type: ModuleType::Js,
+        replace_global_define_config: None,
+      },
+      CreateModuleViewArgs {
+        source: StrOrBytes::Str(source),
+        sourcemap_chain: vec![],
+        hook_side_effects: None,
+      },
+    )
+    .await?;
+    // Imports of any name from the module are fine, as the module throws before they are read.
+    ecma_view.exports_kind = ExportsKind::CommonJs;
+
+    let module = NormalModule {
+      repr_name: legitimize_identifier_name(&repr_name).into_owned(),
+      stable_id,
+      id,
+      debug_id: self.resolved_id.debug_id(&self.ctx.options.cwd),
+      idx: self.module_idx,
+      exec_order: u32::MAX,
+      is_user_defined_entry: self.is_user_defined_entry,
+      module_type: ModuleType::Js,
+      ecma_view,
+      css_view: None,
+    };
+    Ok((module, ast, symbols))
+  }
+
   #[expect(clippy::too_many_lines)]
   async fn run_inner(&mut self) -> BuildResult<()> {
+    if let Some(specifier) = self.resolved_id.id.strip_prefix(UNRESOLVED_MODULE_ID_PREFIX) {
+      // The resolve error itself is reported by the importer.
+      let mut warnings = vec![];
+      let placeholder = self
+        .create_placeholder_module(&format!("Could not resolve \"{specifier}\""), &mut warnings)
+        .await?;
+      self.send_placeholder_module(placeholder, warnings, vec![]).await;
+      return Ok(());
+    }
+
     let mut hook_side_effects = self.resolved_id.side_effects.take();
     let mut sourcemap_chain = vec![];
     let mut warnings = vec![];
@@ -87,6 +225,7 @@ impl ModuleTask {
       &mut sourcemap_chain,
       &mut hook_side_effects,
       &self.ctx.options,
+      self.owner.as_ref().and_then(|owner| owner.attributes.as_ref()),
     )
     .await
     {
@@ -105,6 +244,8 @@ impl ModuleTask {
       }
     };
 
+    let bytes_read = source.len();
+
     let mut source = match source {
       StrOrBytes::Str(source) => {
         // Run plugin transform.
@@ -122,7 +263,13 @@ impl ModuleTask {
       StrOrBytes::Bytes(_) => source,
     };
 
-    // TODO: module type should be able to updated by transform hook, for now we don't impl it.
+    if module_type.is_css_preprocessor() {
+      return Err(anyhow::format_err!(
+        "`{:?}` is a `{module_type}` stylesheet, rolldown can't compile it by itself. Please use a plugin that compiles it and returns `moduleType: 'css'` from the load/transform hook",
+        self.resolved_id.id
+      ))?;
+    }
+
     if let ModuleType::Custom(_) = module_type {
       // TODO: should provide some diagnostics for user how they should handle the module type.
       // e.g.
@@ -133,6 +280,21 @@ impl ModuleTask {
       ))?;
     };
 
+    if matches!(module_type, ModuleType::Copy) {
+      return Err(anyhow::format_err!(
+        "`{:?}` has the `copy` module type, which only applies to files matched by `moduleTypes`. They are copied to the output directory and imported from there instead of being bundled",
+        self.resolved_id.id
+      ))?;
+    }
+
+    if matches!(module_type, ModuleType::File) {
+      source = StrOrBytes::Str(self.render_file_module_url(source.try_into_bytes()?).await?);
+    }
+
+    if matches!(module_type, ModuleType::Wasm) {
+      source = StrOrBytes::Str(self.render_wasm_glue_module(source.try_into_bytes()?).await?);
+    }
+
     let repr_name = self.resolved_id.id.as_path().representative_file_name().into_owned();
     let repr_name = legitimize_identifier_name(&repr_name);
 
@@ -142,12 +304,53 @@ impl ModuleTask {
     let mut raw_import_records = IndexVec::default();
 
     let css_view = if matches!(module_type, ModuleType::Css) {
-      let css_source: ArcStr = source.try_into_string()?.into();
+      let mut css_source = source.try_into_string()?;
+      // Maps of the `load` and `transform` hooks belong to the stylesheet, rather than the js module
+      // that replaces it.
+      let mut css_sourcemap_chain = std::mem::take(&mut sourcemap_chain);
+      css_source = self
+        .ctx
+        .plugin_driver
+        .transform_css(
+          &HookTransformCssArgs {
+            id: &self.resolved_id.id,
+            code: &css_source,
+            module_type: &module_type,
+          },
+          &mut css_sourcemap_chain,
+        )
+        .await?;
+      if let Some(css_target) = &self.ctx.options.css_target {
+        // Lower before creating the view, so import records and url references point into the lowered source.
+        let browsers = parse_css_targets(css_target)?;
+        css_source = CssCompiler::lower(&css_source, stable_id.to_string(), browsers)?;
+        // The lowered stylesheet is printed from scratch, so earlier maps no longer line up with it.
+        css_sourcemap_chain.clear();
+      }
+      let local_classes = if is_css_module_id(&self.resolved_id.id) {
+        let (scoped_source, local_classes) = scope_css_module(&stable_id, &css_source);
+        css_source = scoped_source;
+        Some(local_classes)
+      } else {
+        None
+      };
+      let css_source: ArcStr = css_source.into();
       // FIXME: This makes creating `EcmaView` rely on creating `CssView` first, while they should be done in parallel.
-      source = StrOrBytes::Str(String::new());
-      let create_ret = create_css_view(&stable_id, &css_source);
-      raw_import_records = create_ret.1;
-      Some(create_ret.0)
+      source = StrOrBytes::Str(match &local_classes {
+        Some(local_classes) => render_css_module_exports(local_classes)?,
+        None => String::new(),
+      });
+      let (mut css_view, css_raw_import_records) = create_css_view(&stable_id, &css_source);
+      css_view.local_classes = local_classes;
+      css_view.sourcemap_chain = css_sourcemap_chain;
+      resolve_url_reference_assets(
+        &mut css_view,
+        &self.resolved_id.id,
+        &self.ctx.resolver,
+        &self.ctx.fs,
+      )?;
+      raw_import_records = css_raw_import_records;
+      Some(css_view)
     } else {
       None
     };
@@ -159,6 +362,7 @@ impl ModuleTask {
         resolved_id: &self.resolved_id,
         options: &self.ctx.options,
         warnings: &mut warnings,
+        errors: &mut self.errors,
         module_type: module_type.clone(),
         replace_global_define_config: self.ctx.meta.replace_global_define_config.clone(),
       },
@@ -180,6 +384,7 @@ impl ModuleTask {
       .resolve_dependencies(
         &raw_import_records,
         ecma_view.source.clone(),
+        &ecma_view.imports,
         &mut warnings,
         &module_type,
       )
@@ -223,6 +428,8 @@ impl ModuleTask {
         resolved_deps,
         module_idx: self.module_idx,
         warnings,
+        errors: std::mem::take(&mut self.errors),
+        bytes_read,
         ecma_related: Some((ast, symbols)),
         module: module.into(),
         raw_import_records,
@@ -242,6 +449,7 @@ impl ModuleTask {
     importer: &str,
     specifier: &str,
     kind: ImportKind,
+    attributes: Option<&ImportAttributes>,
   ) -> anyhow::Result<Result<ResolvedId, ResolveError>> {
     // Check runtime module
     if specifier == RUNTIME_MODULE_ID {
@@ -266,14 +474,56 @@ impl ModuleTask {
       Arc::default(),
       false,
       bundle_options,
+      attributes,
     )
     .await
   }
 
+  /// Returns the expression of the url of a `file` module, which the module exports by default. The
+  /// content is emitted as an asset, unless `assets_inline_limit` inlines it as a base64 data url.
+  async fn render_file_module_url(&self, content: Vec<u8>) -> anyhow::Result<String> {
+    let path = Path::new(self.resolved_id.id.as_str());
+    if let Some(assets_inline_limit) = &self.ctx.options.assets_inline_limit {
+      if assets_inline_limit.should_inline(&self.resolved_id.id, content.len()).await? {
+        let mime = guess_mime(path, &content)?;
+        return text_to_string_literal(&format!(
+          "data:{mime};base64,{}",
+          to_standard_base64(&content)
+        ));
+      }
+    }
+    let file_emitter = self.ctx.plugin_driver.file_emitter();
+    let reference_id = file_emitter.emit_file(EmittedAsset {
+      name: path.file_name().map(|name| name.to_string_lossy().into_owned()),
+      original_file_name: Some(path.to_string_lossy().into_owned()),
+      file_name: None,
+      source: content.into(),
+    });
+    let file_name = file_emitter.resolve_file_name(&reference_id).await?;
+    // The url is resolved against `output.base` when the importing chunk is rendered.
+    Ok(match &self.ctx.options.base {
+      Some(_) => format!("import.meta.ROLLUP_FILE_URL_{reference_id}"),
+      None => text_to_string_literal(&file_name)?,
+    })
+  }
+
+  /// Returns the module instantiating a `wasm` module. The binary is emitted like a `file` module,
+  /// unless `wasm.inline` embeds it as base64.
+  async fn render_wasm_glue_module(&self, content: Vec<u8>) -> anyhow::Result<String> {
+    let platform = self.ctx.options.platform;
+    if self.ctx.options.wasm.inline() {
+      let base64 = to_standard_base64(&content);
+      return Ok(wasm_to_esm(&WasmSource::Base64(&base64), platform, RUNTIME_MODULE_ID));
+    }
+    let url = self.render_file_module_url(content).await?;
+    Ok(wasm_to_esm(&WasmSource::Url(&url), platform, RUNTIME_MODULE_ID))
+  }
+
   pub async fn resolve_dependencies(
     &mut self,
     dependencies: &IndexVec<ImportRecordIdx, RawImportRecord>,
     source: ArcStr,
+    imports: &FxHashMap<Span, ImportRecordIdx>,
     warnings: &mut Vec<BuildDiagnostic>,
     module_type: &ModuleType,
   ) -> anyhow::Result<BuildResult<IndexVec<ImportRecordIdx, ResolvedId>>> {
@@ -285,10 +535,22 @@ impl ModuleTask {
       let plugin_driver = Arc::clone(&self.ctx.plugin_driver);
       let importer = &self.resolved_id.id;
       let kind = item.kind;
+      let attributes = item.attributes.as_ref();
       async move {
-        Self::resolve_id(&bundle_options, &resolver, &plugin_driver, importer, &specifier, kind)
-          .await
-          .map(|id| (specifier, idx, id))
+        let mut resolved_id = Self::resolve_id(
+          &bundle_options,
+          &resolver,
+          &plugin_driver,
+          importer,
+          &specifier,
+          kind,
+          attributes,
+        )
+        .await?;
+        if let Ok(info) = &mut resolved_id {
+          Self::resolve_external_side_effects(&bundle_options, info).await?;
+        }
+        anyhow::Ok((specifier, idx, resolved_id))
       }
     });
 
@@ -307,60 +569,125 @@ impl ModuleTask {
         }
         Err(e) => {
           let dep = &dependencies[idx];
-          match &e {
-            ResolveError::NotFound(..) => {
-              warnings.push(
-                BuildDiagnostic::resolve_error(
-                  source.clone(),
-                  self.resolved_id.id.clone(),
-                  if dep.is_unspanned() || is_css_module {
-                    DiagnosableArcstr::String(specifier.as_str().into())
-                  } else {
-                    DiagnosableArcstr::Span(Span::new(
-                      dep.module_request_start,
-                      dep.module_request_end(),
-                    ))
-                  },
-                  "Module not found, treating it as an external dependency".into(),
-                  Some("UNRESOLVED_IMPORT"),
-                )
-                .with_severity_warning(),
-              );
-              ret.push(ResolvedId {
-                id: specifier.to_string().into(),
-                ignored: false,
-                module_def_format: ModuleDefFormat::Unknown,
-                is_external: true,
-                package_json: None,
-                side_effects: None,
-              });
-            }
-            e => {
-              let reason = rolldown_resolver::error::oxc_resolve_error_to_reason(e);
-              build_errors.push(BuildDiagnostic::resolve_error(
-                source.clone(),
-                self.resolved_id.id.clone(),
-                if dep.is_unspanned() || is_css_module {
-                  DiagnosableArcstr::String(specifier.as_str().into())
-                } else {
-                  DiagnosableArcstr::Span(Span::new(
-                    dep.module_request_start,
-                    dep.module_request_end(),
-                  ))
-                },
-                reason,
-                None,
-              ));
-            }
+          let (importee, import_span) = if dep.is_unspanned() || is_css_module {
+            (DiagnosableArcstr::String(specifier.as_str().into()), None)
+          } else {
+            // Point at the whole import statement or call, so the code frame shows where the dependency is imported.
+            let import_span =
+              imports.iter().find_map(|(span, rec_idx)| (*rec_idx == idx).then_some(*span));
+            (
+              DiagnosableArcstr::Span(Span::new(
+                dep.module_request_start,
+                dep.module_request_end(),
+              )),
+              import_span,
+            )
           };
+          let (resolved_id, diagnostic) = self.resolve_error_fallback(
+            &e,
+            &specifier,
+            dep.kind,
+            importee,
+            import_span,
+            source.clone(),
+          );
+          ret.push(resolved_id);
+          if matches!(e, ResolveError::NotFound(..)) {
+            warnings.push(diagnostic);
+          } else {
+            build_errors.push(diagnostic);
+          }
         }
       }
     }
 
     if build_errors.is_empty() {
       Ok(Ok(ret))
-    } else {
+    } else if self.ctx.options.error_recovery {
+      // The importer itself is fine, so it's kept and only the unresolved imports throw.
+      warnings.extend(build_errors.into_iter().map(BuildDiagnostic::with_severity_warning));
+      Ok(Ok(ret))
+    } else if self.ctx.options.bail {
       Ok(Err(build_errors.into()))
+    } else {
+      self.errors.extend(build_errors);
+      Ok(Ok(ret))
     }
   }
+
+  /// `treeshake.moduleSideEffects` may be an async function, so the side effects of external modules are
+  /// determined here, where they're resolved.
+  async fn resolve_external_side_effects(
+    options: &SharedOptions,
+    info: &mut ResolvedId,
+  ) -> anyhow::Result<()> {
+    if let TreeshakeOptions::Option(opt) = &options.treeshake {
+      if info.is_external && info.side_effects.is_none() {
+        let has_side_effects = opt.module_side_effects.resolve(&info.id, &info.id, true).await?;
+        info.side_effects =
+          Some(if has_side_effects { HookSideEffects::True } else { HookSideEffects::False });
+      }
+    }
+    Ok(())
+  }
+
+  /// Returns what an unresolved dependency is replaced with, and the diagnostic to report. Modules that
+  /// aren't found are treated as external with a warning.
+  fn resolve_error_fallback(
+    &self,
+    error: &ResolveError,
+    specifier: &str,
+    kind: ImportKind,
+    importee: DiagnosableArcstr,
+    import_span: Option<Span>,
+    source: ArcStr,
+  ) -> (ResolvedId, BuildDiagnostic) {
+    let attempt = self.ctx.resolver.resolve_attempt(
+      Some(Path::new(self.resolved_id.id.as_str())),
+      specifier,
+      kind,
+    );
+    if let ResolveError::NotFound(..) = error {
+      let diagnostic = BuildDiagnostic::resolve_error(
+        source,
+        self.resolved_id.id.clone(),
+        importee,
+        import_span,
+        attempt,
+        "Module not found, treating it as an external dependency".into(),
+        Some("UNRESOLVED_IMPORT"),
+      )
+      .with_severity_warning();
+      let resolved_id = ResolvedId {
+        id: specifier.to_string().into(),
+        ignored: false,
+        module_def_format: ModuleDefFormat::Unknown,
+        is_external: true,
+        package_json: None,
+        side_effects: None,
+      };
+      return (resolved_id, diagnostic);
+    }
+    let reason = rolldown_resolver::error::oxc_resolve_error_to_reason(error);
+    // Only the import is replaced with a placeholder module, which keeps the module graph going
+    // if the build recovers from errors or doesn't bail.
+    let resolved_id = ResolvedId {
+      id: format!("{UNRESOLVED_MODULE_ID_PREFIX}{specifier}").into(),
+      ignored: false,
+      module_def_format: ModuleDefFormat::Unknown,
+      is_external: false,
+      package_json: None,
+      side_effects: None,
+    };
+    let diagnostic = BuildDiagnostic::resolve_error(
+      source,
+      self.resolved_id.id.clone(),
+      importee,
+      import_span,
+      attempt,
+      reason,
+      None,
+    );
+    (resolved_id, diagnostic)
+  }
 }
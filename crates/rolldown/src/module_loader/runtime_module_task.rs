@@ -2,22 +2,25 @@ use arcstr::ArcStr;
 use oxc::index::IndexVec;
 use oxc::span::SourceType;
 use rolldown_common::{
-  side_effects::DeterminedSideEffects, AstScopes, EcmaView, EcmaViewMeta, ExportsKind,
-  ModuleDefFormat, ModuleId, ModuleIdx, ModuleType, NormalModule, SymbolRef, SymbolRefDbForModule,
+  side_effects::DeterminedSideEffects, AstScopes, CommonJsOptions, EcmaView, EcmaViewMeta,
+  ExportsKind, ModuleDefFormat, ModuleId, ModuleIdx, ModuleType, NormalModule,
+  PropertyReadSideEffects, SymbolRef, SymbolRefDbForModule,
 };
 use rolldown_ecmascript::{EcmaAst, EcmaCompiler};
 use rolldown_error::{BuildDiagnostic, BuildResult};
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use super::Msg;
 use crate::{
   ast_scanner::{AstScanner, ScanResult},
   runtime::{RuntimeModuleBrief, RUNTIME_MODULE_ID},
   utils::tweak_ast_for_scanning::tweak_ast_for_scanning,
+  SharedOptions,
 };
 pub struct RuntimeModuleTask {
   tx: tokio::sync::mpsc::Sender<Msg>,
   module_id: ModuleIdx,
+  options: SharedOptions,
   errors: Vec<BuildDiagnostic>,
 }
 
@@ -37,13 +40,50 @@ pub struct MakeEcmaAstResult {
 }
 
 impl RuntimeModuleTask {
-  pub fn new(id: ModuleIdx, tx: tokio::sync::mpsc::Sender<Msg>) -> Self {
-    Self { module_id: id, tx, errors: Vec::new() }
+  pub fn new(id: ModuleIdx, tx: tokio::sync::mpsc::Sender<Msg>, options: SharedOptions) -> Self {
+    Self { module_id: id, tx, options, errors: Vec::new() }
   }
 
   #[tracing::instrument(name = "RuntimeNormalModuleTaskResult::run", level = "debug", skip_all)]
   pub fn run(mut self) -> anyhow::Result<()> {
-    let source: ArcStr = arcstr::literal!(include_str!("../runtime/runtime-without-comments.js"));
+    // `__name`, `__dynamicRequire` and the decorator helpers are only defined if they're used, so the
+    // runtime stays the same for other builds.
+    let dynamic_require = !self.options.commonjs.ignore_dynamic_requires();
+    // The `es5` preset of `generatedCode` uses the copies of the runtime written with function expressions.
+    let es5 = !self.options.generated_code.arrow_function_helpers();
+    let source: ArcStr =
+      if self.options.keep_names || self.options.decorator.legacy() || dynamic_require || es5 {
+        let mut source = if es5 {
+          include_str!("../runtime/runtime-es5.js")
+        } else {
+          include_str!("../runtime/runtime-without-comments.js")
+        }
+        .to_string();
+        if self.options.keep_names {
+          source.push_str(if es5 {
+            include_str!("../runtime/runtime-keep-names-es5.js")
+          } else {
+            include_str!("../runtime/runtime-keep-names.js")
+          });
+        }
+        if self.options.decorator.legacy() {
+          source.push_str(if es5 {
+            include_str!("../runtime/runtime-decorators-es5.js")
+          } else {
+            include_str!("../runtime/runtime-decorators.js")
+          });
+        }
+        if dynamic_require {
+          source.push_str(if es5 {
+            include_str!("../runtime/runtime-dynamic-require-es5.js")
+          } else {
+            include_str!("../runtime/runtime-dynamic-require.js")
+          });
+        }
+        source.into()
+      } else {
+        arcstr::literal!(include_str!("../runtime/runtime-without-comments.js"))
+      };
 
     let ecma_ast_result = self.make_ecma_ast(RUNTIME_MODULE_ID, &source);
 
@@ -110,6 +150,8 @@ impl RuntimeModuleTask {
         def_format: ModuleDefFormat::EsmMjs,
         ast_usage,
         self_referenced_class_decl_symbol_ids: FxHashSet::default(),
+        const_enums: FxHashMap::default(),
+        constants: FxHashMap::default(),
         meta: {
           let mut meta = EcmaViewMeta::default();
           meta.set_included(false);
@@ -158,6 +200,11 @@ impl RuntimeModuleTask {
       source,
       &facade_path,
       ast.comments(),
+      &[],
+      PropertyReadSideEffects::default(),
+      true,
+      true,
+      CommonJsOptions::default(),
     );
     let namespace_object_ref = scanner.namespace_object_ref;
     let scan_result = scanner.scan(ast.program())?;
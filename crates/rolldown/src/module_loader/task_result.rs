@@ -10,6 +10,10 @@ pub struct NormalModuleTaskResult {
   pub resolved_deps: IndexVec<ImportRecordIdx, ResolvedId>,
   pub raw_import_records: IndexVec<ImportRecordIdx, RawImportRecord>,
   pub warnings: Vec<BuildDiagnostic>,
+  /// Errors that didn't stop loading the module, if the build doesn't bail.
+  pub errors: Vec<BuildDiagnostic>,
+  /// Size of the source returned by `load` hook or read from the file system.
+  pub bytes_read: usize,
   pub module: Module,
   pub ecma_related: Option<(EcmaAst, SymbolRefDbForModule)>,
 }
@@ -84,3 +84,16 @@ export var __toBinary = /* @__PURE__ */ (() => {
     return bytes
   }
 })()
+
+// This is for the "wasm" module type. Streaming instantiation requires the
+// "application/wasm" content type, so other responses are instantiated from
+// their bytes instead.
+export var __instantiateWasm = async (source, imports) => {
+  if (typeof source == 'string') {
+    var response = await fetch(source)
+    if (WebAssembly.instantiateStreaming && response.headers.get('Content-Type') == 'application/wasm')
+      return (await WebAssembly.instantiateStreaming(response, imports)).instance
+    source = await response.arrayBuffer()
+  }
+  return (await WebAssembly.instantiate(source, imports)).instance
+}
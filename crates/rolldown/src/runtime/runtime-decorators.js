@@ -0,0 +1,10 @@
+export var __decorateClass = (decorators, target, key, kind) => {
+  var result = kind > 1 ? void 0 : kind ? __getOwnPropDesc(target, key) : target
+  for (var i = decorators.length - 1, decorator; i >= 0; i--)
+    if (decorator = decorators[i])
+      result = (kind ? decorator(target, key, result) : decorator(result)) || result
+  if (kind && result) __defProp(target, key, result)
+  return result
+}
+export var __decorateParam = (index, decorator) => (target, key) => decorator(target, key, index)
+export var __decorateMetadata = (key, value) => typeof Reflect === 'object' && typeof Reflect.metadata === 'function' ? Reflect.metadata(key, value) : void 0
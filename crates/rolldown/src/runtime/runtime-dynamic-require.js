@@ -0,0 +1 @@
+var __dynamicRequire = id => { throw Error('Could not dynamically require "' + id + '". Enable `commonjs.ignoreDynamicRequires` to keep the `require` call as it is.') }
@@ -0,0 +1,82 @@
+
+var __create = Object.create
+var __defProp = Object.defineProperty
+var __getOwnPropDesc = Object.getOwnPropertyDescriptor
+var __getOwnPropNames = Object.getOwnPropertyNames
+var __getProtoOf = Object.getPrototypeOf
+var __hasOwnProp = Object.prototype.hasOwnProperty
+var __esm = function (fn, res) {
+  return function () {
+    return fn && (res = (0, fn[__getOwnPropNames(fn)[0]])(fn = 0)), res
+  }
+}
+var __esmMin = function (fn, res) {
+  return function () {
+    return fn && (res = fn(fn = 0)), res
+  }
+}
+var __commonJS = function (cb, mod) {
+  return function () {
+    return mod || (0, cb[__getOwnPropNames(cb)[0]])((mod = { exports: {} }).exports, mod), mod.exports
+  }
+}
+var __commonJSMin = function (cb, mod) {
+  return function () {
+    return mod || cb((mod = { exports: {} }).exports, mod), mod.exports
+  }
+}
+var __export = function (target, all) {
+  for (var name in all)
+    __defProp(target, name, { get: all[name], enumerable: true })
+}
+var __copyProps = function (to, from, except, desc) {
+  if (from && typeof from === 'object' || typeof from === 'function')
+    for (var keys = __getOwnPropNames(from), i = 0, n = keys.length, key; i < n; i++) {
+      key = keys[i]
+      if (!__hasOwnProp.call(to, key) && key !== except)
+        __defProp(to, key, { get: function (k) { return from[k] }.bind(null, key), enumerable: !(desc = __getOwnPropDesc(from, key)) || desc.enumerable })
+    }
+  return to
+}
+var __reExport = function (target, mod, secondTarget) {
+  return __copyProps(target, mod, 'default'),
+    secondTarget && __copyProps(secondTarget, mod, 'default')
+}
+var __toESM = function (mod, isNodeMode, target) {
+  return target = mod != null ? __create(__getProtoOf(mod)) : {},
+    __copyProps(
+      isNodeMode || !mod || !mod.__esModule
+        ? __defProp(target, 'default', { value: mod, enumerable: true })
+        : target,
+      mod)
+}
+var __toCommonJS = function (mod) {
+  return __copyProps(__defProp({}, '__esModule', { value: true }), mod)
+}
+export var __toBinaryNode = function (base64) {
+  return new Uint8Array(Buffer.from(base64, 'base64'))
+}
+export var __toBinary = /* @__PURE__ */ (function () {
+  var table = new Uint8Array(128)
+  for (var i = 0; i < 64; i++) table[i < 26 ? i + 65 : i < 52 ? i + 71 : i < 62 ? i - 4 : i * 4 - 205] = i
+  return function (base64) {
+    var n = base64.length, bytes = new Uint8Array((n - (base64[n - 1] == '=') - (base64[n - 2] == '=')) * 3 / 4 | 0)
+    for (var i = 0, j = 0; i < n;) {
+      var c0 = table[base64.charCodeAt(i++)], c1 = table[base64.charCodeAt(i++)]
+      var c2 = table[base64.charCodeAt(i++)], c3 = table[base64.charCodeAt(i++)]
+      bytes[j++] = (c0 << 2) | (c1 >> 4)
+      bytes[j++] = (c1 << 4) | (c2 >> 2)
+      bytes[j++] = (c2 << 6) | c3
+    }
+    return bytes
+  }
+})()
+export var __instantiateWasm = async function (source, imports) {
+  if (typeof source == 'string') {
+    var response = await fetch(source)
+    if (WebAssembly.instantiateStreaming && response.headers.get('Content-Type') == 'application/wasm')
+      return (await WebAssembly.instantiateStreaming(response, imports)).instance
+    source = await response.arrayBuffer()
+  }
+  return (await WebAssembly.instantiate(source, imports)).instance
+}
@@ -0,0 +1 @@
+var __name = function (target, value) { return __defProp(target, 'name', { value: value, configurable: true }) }
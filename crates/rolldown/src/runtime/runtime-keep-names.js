@@ -0,0 +1 @@
+var __name = (target, value) => __defProp(target, 'name', { value, configurable: true })
@@ -55,3 +55,12 @@ export var __toBinary = /* @__PURE__ */ (() => {
     return bytes
   }
 })()
+export var __instantiateWasm = async (source, imports) => {
+  if (typeof source == 'string') {
+    var response = await fetch(source)
+    if (WebAssembly.instantiateStreaming && response.headers.get('Content-Type') == 'application/wasm')
+      return (await WebAssembly.instantiateStreaming(response, imports)).instance
+    source = await response.arrayBuffer()
+  }
+  return (await WebAssembly.instantiate(source, imports)).instance
+}
@@ -0,0 +1,61 @@
+use itertools::Itertools;
+use rolldown_common::InstantiationKind;
+use rolldown_error::BuildDiagnostic;
+use rolldown_utils::rayon::{IntoParallelRefIterator, ParallelIterator};
+
+use crate::type_alias::IndexAssets;
+
+use super::GenerateStage;
+
+/// How many of the largest modules are listed in the warning.
+const LARGEST_MODULES_COUNT: usize = 5;
+
+impl<'a> GenerateStage<'a> {
+  /// Warn about rendered chunks larger than `chunk_size_warning_limit`, so accidental bloat is noticed.
+  #[allow(clippy::cast_precision_loss)] // Chunks are far smaller than 2^52 bytes.
+  pub fn check_chunk_sizes(&self, assets: &IndexAssets, warnings: &mut Vec<BuildDiagnostic>) {
+    let limit = self.options.chunk_size_warning_limit;
+    if !limit.is_enabled() {
+      return;
+    }
+
+    let found = assets
+      .par_iter()
+      .filter_map(|asset| {
+        let InstantiationKind::Ecma(ecma_meta) = &asset.meta else {
+          return None;
+        };
+        let size = asset.content.len();
+        let raw = limit.raw.filter(|limit| size as f64 > *limit).map(|limit| (size, limit));
+        let gzip = limit.gzip.and_then(|limit| {
+          let gzip_size = gzip_size(asset.content.as_bytes());
+          (gzip_size as f64 > limit).then_some((gzip_size, limit))
+        });
+        if raw.is_none() && gzip.is_none() {
+          return None;
+        }
+        let largest_modules = ecma_meta
+          .rendered_chunk
+          .modules
+          .iter()
+          .map(|(id, module)| (id.to_string(), module.rendered_length))
+          .filter(|(_, size)| *size > 0)
+          .sorted_by(|(a_id, a_size), (b_id, b_size)| b_size.cmp(a_size).then(a_id.cmp(b_id)))
+          .take(LARGEST_MODULES_COUNT)
+          .collect();
+        Some(
+          BuildDiagnostic::chunk_size_exceeded(asset.filename.clone(), raw, gzip, largest_modules)
+            .with_severity_warning(),
+        )
+      })
+      .collect::<Vec<_>>();
+
+    warnings.extend(found);
+  }
+}
+
+/// The size of the content compressed by gzip with the default level, which is the deflate stream wrapped
+/// by a 10 bytes header and a 8 bytes trailer.
+fn gzip_size(content: &[u8]) -> usize {
+  miniz_oxide::deflate::compress_to_vec(content, 6).len() + 18
+}
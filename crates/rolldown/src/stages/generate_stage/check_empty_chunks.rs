@@ -0,0 +1,28 @@
+use rolldown_common::InstantiationKind;
+use rolldown_error::{BuildDiagnostic, Severity};
+
+use crate::{type_alias::IndexAssets, utils::log_level::is_severity_enabled};
+
+use super::GenerateStage;
+
+impl<'a> GenerateStage<'a> {
+  /// Reports chunks whose modules don't render any code. They are mostly intended, e.g. entries that only
+  /// import css, so they are only reported under the `debug` log level.
+  pub fn check_empty_chunks(&self, assets: &IndexAssets, warnings: &mut Vec<BuildDiagnostic>) {
+    if !is_severity_enabled(self.options.log_level, Severity::Debug) {
+      return;
+    }
+    for asset in assets {
+      let InstantiationKind::Ecma(ecma_meta) = &asset.meta else {
+        continue;
+      };
+      let rendered_chunk = &ecma_meta.rendered_chunk;
+      if rendered_chunk.modules.values().all(|module| module.rendered_length == 0) {
+        warnings.push(
+          BuildDiagnostic::empty_bundle(rendered_chunk.name.to_string())
+            .with_severity(Severity::Debug),
+        );
+      }
+    }
+  }
+}
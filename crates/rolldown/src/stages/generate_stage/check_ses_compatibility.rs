@@ -0,0 +1,151 @@
+use arcstr::ArcStr;
+use oxc::{
+  ast::{
+    ast::{AssignmentExpression, Expression, MemberExpression, UnaryExpression, UnaryOperator},
+    visit::walk,
+    Visit,
+  },
+  span::{GetSpan, SourceType, Span},
+};
+use rolldown_common::InstantiationKind;
+use rolldown_ecmascript::EcmaCompiler;
+use rolldown_error::BuildDiagnostic;
+use rolldown_utils::rayon::{IntoParallelRefIterator, ParallelIterator};
+
+use crate::type_alias::IndexAssets;
+
+use super::GenerateStage;
+
+/// Intrinsics that are frozen by `lockdown()` in hardened JavaScript.
+const FROZEN_INTRINSICS: &[&str] = &[
+  "AggregateError",
+  "Array",
+  "ArrayBuffer",
+  "BigInt",
+  "BigInt64Array",
+  "BigUint64Array",
+  "Boolean",
+  "DataView",
+  "Date",
+  "Error",
+  "EvalError",
+  "FinalizationRegistry",
+  "Float32Array",
+  "Float64Array",
+  "Function",
+  "Int16Array",
+  "Int32Array",
+  "Int8Array",
+  "Intl",
+  "JSON",
+  "Map",
+  "Math",
+  "Number",
+  "Object",
+  "Promise",
+  "Proxy",
+  "RangeError",
+  "ReferenceError",
+  "Reflect",
+  "RegExp",
+  "Set",
+  "String",
+  "Symbol",
+  "SyntaxError",
+  "TypeError",
+  "URIError",
+  "Uint16Array",
+  "Uint32Array",
+  "Uint8Array",
+  "Uint8ClampedArray",
+  "WeakMap",
+  "WeakRef",
+  "WeakSet",
+];
+
+impl<'a> GenerateStage<'a> {
+  /// Checks the final ecma assets for patterns that throw or silently change behavior under frozen intrinsics.
+  pub fn check_ses_compatibility(&self, assets: &IndexAssets, warnings: &mut Vec<BuildDiagnostic>) {
+    if !self.options.ses_compatible {
+      return;
+    }
+
+    let found = assets
+      .par_iter()
+      .filter(|asset| matches!(asset.meta, InstantiationKind::Ecma(_)))
+      .map(|asset| {
+        let source: ArcStr = asset.content.as_str().into();
+        let Ok(ast) = EcmaCompiler::parse(&asset.filename, source.clone(), SourceType::mjs())
+        else {
+          // The output of rolldown is always parsable unless plugins break it, which is out of the scope of this check.
+          return vec![];
+        };
+        let mut checker = SesCompatibilityChecker { incompatibilities: vec![] };
+        checker.visit_program(ast.program());
+        checker
+          .incompatibilities
+          .into_iter()
+          .map(|(span, reason)| {
+            BuildDiagnostic::ses_incompatible(asset.filename.clone(), source.clone(), span, reason)
+              .with_severity_warning()
+          })
+          .collect::<Vec<_>>()
+      })
+      .collect::<Vec<_>>();
+
+    warnings.extend(found.into_iter().flatten());
+  }
+}
+
+struct SesCompatibilityChecker {
+  incompatibilities: Vec<(Span, String)>,
+}
+
+impl SesCompatibilityChecker {
+  fn check_mutated_member_expr(&mut self, member_expr: &MemberExpression, span: Span) {
+    if member_expr.static_property_name() == Some("__proto__") {
+      self.incompatibilities.push((span, "mutating `__proto__`".to_string()));
+    } else if let Some(intrinsic) = intrinsic_root(member_expr.object()) {
+      self.incompatibilities.push((span, format!("mutating the frozen intrinsic `{intrinsic}`")));
+    }
+  }
+}
+
+/// Returns the name of the intrinsic if the expression is `Array`, `Array.prototype` or a deeper member of them.
+fn intrinsic_root<'a>(expr: &Expression<'a>) -> Option<&'a str> {
+  match expr {
+    Expression::Identifier(ident) if FROZEN_INTRINSICS.contains(&ident.name.as_str()) => {
+      Some(ident.name.as_str())
+    }
+    _ => expr.as_member_expression().and_then(|member_expr| intrinsic_root(member_expr.object())),
+  }
+}
+
+impl<'a> Visit<'a> for SesCompatibilityChecker {
+  fn visit_assignment_expression(&mut self, it: &AssignmentExpression<'a>) {
+    if let Some(member_expr) = it.left.as_member_expression() {
+      self.check_mutated_member_expr(member_expr, it.left.span());
+    }
+    walk::walk_assignment_expression(self, it);
+  }
+
+  fn visit_unary_expression(&mut self, it: &UnaryExpression<'a>) {
+    if matches!(it.operator, UnaryOperator::Delete) {
+      if let Some(member_expr) = it.argument.as_member_expression() {
+        self.check_mutated_member_expr(member_expr, it.span);
+      }
+    }
+    walk::walk_unary_expression(self, it);
+  }
+
+  fn visit_member_expression(&mut self, it: &MemberExpression<'a>) {
+    if it.object().is_specific_id("arguments")
+      && matches!(it.static_property_name(), Some("callee" | "caller"))
+    {
+      self
+        .incompatibilities
+        .push((it.span(), "accessing `arguments.callee` or `arguments.caller`".to_string()));
+    }
+    walk::walk_member_expression(self, it);
+  }
+}
@@ -1,12 +1,17 @@
-use std::cmp::{Ordering, Reverse};
+use std::{
+  cmp::{Ordering, Reverse},
+  sync::Arc,
+};
 
 use crate::{chunk_graph::ChunkGraph, types::linking_metadata::LinkingMetadataVec};
 use arcstr::ArcStr;
 use itertools::Itertools;
 use oxc::index::IndexVec;
-use rolldown_common::{Chunk, ChunkIdx, ChunkKind, Module, ModuleIdx, ModuleTable, OutputFormat};
+use rolldown_common::{
+  Chunk, ChunkIdx, ChunkKind, ManualChunksModuleInfos, Module, ModuleIdx, ModuleTable, OutputFormat,
+};
 use rolldown_error::{BuildDiagnostic, InvalidOptionTypes};
-use rolldown_utils::{rustc_hash::FxHashMapExt, BitSet};
+use rolldown_utils::{indexmap::FxIndexMap, rustc_hash::FxHashMapExt, BitSet};
 use rustc_hash::{FxHashMap, FxHashSet};
 
 use super::GenerateStage;
@@ -19,6 +24,9 @@ pub struct SplittingInfo {
 
 pub type IndexSplittingInfo = IndexVec<ModuleIdx, SplittingInfo>;
 
+/// The name of the chunk created by `output.runtimeChunk`.
+const RUNTIME_CHUNK_NAME: &str = "rolldown-runtime";
+
 impl<'a> GenerateStage<'a> {
   #[tracing::instrument(level = "debug", skip_all)]
   pub async fn generate_chunks(&mut self) -> anyhow::Result<ChunkGraph> {
@@ -27,6 +35,13 @@ impl<'a> GenerateStage<'a> {
         self.link_output.entries.iter().filter(|entry| entry.kind.is_user_defined()).count();
       debug_assert!(user_defined_entry_count == 1, "IIFE/UMD format only supports one entry point");
     }
+    // Dynamic imports are inlined into the importer, so all code is supposed to end up in a single chunk.
+    if self.options.inline_dynamic_imports && self.link_output.entries.len() > 1 {
+      self.link_output.errors.push(BuildDiagnostic::invalid_option(
+        InvalidOptionTypes::InlineDynamicImportsWithMultipleEntries,
+        self.link_output.entries.len().to_string(),
+      ));
+    }
     let entries_len: u32 =
       self.link_output.entries.len().try_into().expect("Too many entries, u32 overflowed.");
     // If we are in test environment, to make the runtime module always fall into a standalone chunk,
@@ -74,10 +89,40 @@ impl<'a> GenerateStage<'a> {
       );
     });
 
+    self.exclude_implicitly_loaded_modules(&mut index_splitting_info);
+
     let mut module_to_assigned: IndexVec<ModuleIdx, bool> =
       oxc::index::index_vec![false; self.link_output.module_table.modules.len()];
 
-    self.apply_advanced_chunks(&index_splitting_info, &mut module_to_assigned, &mut chunk_graph);
+    // The runtime module is held back until all other modules are assigned, so it's known whether there
+    // are other chunks to share it with.
+    let hoist_runtime = self.options.runtime_chunk
+      && !self.options.preserve_modules
+      && self.link_output.module_table.modules[self.link_output.runtime.id()]
+        .as_normal()
+        .is_some_and(|runtime| runtime.meta.is_included());
+    if hoist_runtime {
+      module_to_assigned[self.link_output.runtime.id()] = true;
+    }
+
+    if self.options.preserve_modules {
+      self.assign_preserved_modules(
+        &index_splitting_info,
+        &entry_module_to_entry_chunk,
+        &mut module_to_assigned,
+        &mut chunk_graph,
+      );
+    } else {
+      self
+        .apply_manual_chunks(&index_splitting_info, &mut module_to_assigned, &mut chunk_graph)
+        .await?;
+      self
+        .apply_advanced_chunks(&index_splitting_info, &mut module_to_assigned, &mut chunk_graph)
+        .await?;
+    }
+
+    let merged_bits =
+      self.merge_small_common_chunks(&index_splitting_info, &module_to_assigned, &bits_to_chunk);
 
     // 1. Assign modules to corresponding chunks
     // 2. Create shared chunks to store modules that belong to multiple chunks.
@@ -98,6 +143,7 @@ impl<'a> GenerateStage<'a> {
         !bits.is_empty(),
         "Empty bits means the module is not reachable, so it should bail out with `is_included: false` {:?}", normal_module.stable_id
       );
+      let bits = merged_bits.get(bits).unwrap_or(bits);
 
       if let Some(chunk_id) = bits_to_chunk.get(bits).copied() {
         chunk_graph.add_module_to_chunk(normal_module.idx, chunk_id);
@@ -109,6 +155,10 @@ impl<'a> GenerateStage<'a> {
       }
     }
 
+    if hoist_runtime {
+      self.assign_runtime_module(&index_splitting_info, &mut chunk_graph);
+    }
+
     if matches!(self.options.format, OutputFormat::Iife | OutputFormat::Umd)
       && chunk_graph.chunk_table.len() > 1
     {
@@ -252,13 +302,302 @@ impl<'a> GenerateStage<'a> {
     });
   }
 
+  /// Puts the runtime module into a chunk of its own for `output.runtimeChunk`, which is imported by all
+  /// chunks using its helpers. If there is only one chunk, the runtime module stays in it.
+  fn assign_runtime_module(
+    &self,
+    index_splitting_info: &IndexSplittingInfo,
+    chunk_graph: &mut ChunkGraph,
+  ) {
+    let runtime_idx = self.link_output.runtime.id();
+    let bits = &index_splitting_info[runtime_idx].bits;
+    let chunk_idx = if chunk_graph.chunk_table.len() == 1 {
+      ChunkIdx::new(0)
+    } else {
+      chunk_graph.add_chunk(Chunk::new(
+        Some(ArcStr::from(RUNTIME_CHUNK_NAME)),
+        bits.clone(),
+        vec![],
+        ChunkKind::Common,
+      ))
+    };
+    chunk_graph.add_module_to_chunk(runtime_idx, chunk_idx);
+  }
+
+  /// An implicitly loaded entry is guaranteed to be loaded after one of `implicitly_loaded_after`.
+  /// So modules reachable from all of these entries are already loaded and don't belong to it.
+  fn exclude_implicitly_loaded_modules(&self, index_splitting_info: &mut IndexSplittingInfo) {
+    let entry_bit_by_module: FxHashMap<ModuleIdx, u32> = self
+      .link_output
+      .entries
+      .iter()
+      .enumerate()
+      .map(|(i, entry)| (entry.id, i.try_into().expect("Too many entries, u32 overflowed.")))
+      .collect();
+
+    for entry_point in &self.link_output.entries {
+      if entry_point.implicitly_loaded_after.is_empty() {
+        continue;
+      }
+      let entry_bit = entry_bit_by_module[&entry_point.id];
+      let loaded_before_bits = entry_point
+        .implicitly_loaded_after
+        .iter()
+        .filter_map(|module_idx| entry_bit_by_module.get(module_idx).copied())
+        .collect::<Vec<_>>();
+      if loaded_before_bits.is_empty() {
+        continue;
+      }
+
+      for (module_idx, splitting_info) in index_splitting_info.iter_mut_enumerated() {
+        if module_idx == entry_point.id || !splitting_info.bits.has_bit(entry_bit) {
+          continue;
+        }
+        if loaded_before_bits.iter().all(|bit| splitting_info.bits.has_bit(*bit)) {
+          splitting_info.bits.clear_bit(entry_bit);
+          splitting_info.share_count -= 1;
+        }
+      }
+    }
+  }
+
+  /// Common chunks smaller than `experimental_min_chunk_size` are merged into a larger common chunk that is
+  /// loaded by all of their entries. Only chunks without side effects are merged, because their code runs
+  /// for more entries afterwards. Their dependencies must be loaded by the larger chunk already, so merging
+  /// doesn't load other chunks for more entries either.
+  ///
+  /// Returns the bits of merged chunks mapped to the bits of the chunks they are merged into.
+  #[allow(clippy::cast_precision_loss)] // We consider `usize` to `f64` is safe here
+  fn merge_small_common_chunks(
+    &self,
+    index_splitting_info: &IndexSplittingInfo,
+    module_to_assigned: &IndexVec<ModuleIdx, bool>,
+    bits_to_chunk: &FxHashMap<BitSet, ChunkIdx>,
+  ) -> FxHashMap<BitSet, BitSet> {
+    #[derive(Default)]
+    struct PendingChunk {
+      modules: FxHashSet<ModuleIdx>,
+      size: f64,
+      has_side_effects: bool,
+    }
+
+    let mut merged_bits = FxHashMap::default();
+    let Some(min_chunk_size) = self.options.experimental_min_chunk_size else {
+      return merged_bits;
+    };
+
+    // Collected in execution order, so chunks of the same size are merged the same way in every build.
+    let mut pending_chunks: FxIndexMap<BitSet, PendingChunk> = FxIndexMap::default();
+    for module in self
+      .link_output
+      .module_table
+      .modules
+      .iter()
+      .filter_map(Module::as_normal)
+      .sorted_by_key(|module| module.exec_order)
+    {
+      let bits = &index_splitting_info[module.idx].bits;
+      if !module.meta.is_included()
+        || module_to_assigned[module.idx]
+        || bits_to_chunk.contains_key(bits)
+      {
+        continue;
+      }
+      let pending_chunk = pending_chunks.entry(bits.clone()).or_default();
+      pending_chunk.modules.insert(module.idx);
+      pending_chunk.size += module.source.len() as f64;
+      pending_chunk.has_side_effects |= module.side_effects.has_side_effects();
+    }
+
+    let mut merge_targets = FxHashSet::default();
+    for (bits, pending_chunk) in
+      pending_chunks.iter().sorted_by(|(_, a), (_, b)| a.size.total_cmp(&b.size))
+    {
+      if pending_chunk.size >= min_chunk_size
+        || pending_chunk.has_side_effects
+        || merge_targets.contains(bits)
+      {
+        continue;
+      }
+      let is_loaded_with = |target_bits: &BitSet| {
+        pending_chunk.modules.iter().all(|module_idx| {
+          self.link_output.metas[*module_idx].dependencies.iter().all(|dep_idx| {
+            let is_normal_included = self.link_output.module_table.modules[*dep_idx]
+              .as_normal()
+              .is_some_and(|dep| dep.meta.is_included());
+            !is_normal_included
+              || pending_chunk.modules.contains(dep_idx)
+              || index_splitting_info[*dep_idx].bits.is_superset_of(target_bits)
+          })
+        })
+      };
+      let target = pending_chunks
+        .iter()
+        .filter(|(target_bits, _)| {
+          *target_bits != bits
+            && !merged_bits.contains_key(*target_bits)
+            && target_bits.is_superset_of(bits)
+            && is_loaded_with(target_bits)
+        })
+        .min_by(|(a_bits, a), (b_bits, b)| {
+          a_bits.count_ones().cmp(&b_bits.count_ones()).then(a.size.total_cmp(&b.size))
+        });
+      if let Some((target_bits, _)) = target {
+        merge_targets.insert(target_bits.clone());
+        merged_bits.insert(bits.clone(), target_bits.clone());
+      }
+    }
+
+    merged_bits
+  }
+
+  /// Gives each module a chunk of its own, so the output mirrors the structure of the sources. Stylesheets
+  /// join the chunk of the first module importing them, so their css is emitted next to that js chunk.
+  fn assign_preserved_modules(
+    &self,
+    index_splitting_info: &IndexSplittingInfo,
+    entry_module_to_entry_chunk: &FxHashMap<ModuleIdx, ChunkIdx>,
+    module_to_assigned: &mut IndexVec<ModuleIdx, bool>,
+    chunk_graph: &mut ChunkGraph,
+  ) {
+    let modules = &self.link_output.module_table.modules;
+    let included_modules = modules
+      .iter()
+      .filter_map(Module::as_normal)
+      .filter(|module| module.meta.is_included())
+      .sorted_by_key(|module| module.exec_order)
+      .collect::<Vec<_>>();
+
+    let mut assigned_chunks = vec![];
+    for module in &included_modules {
+      let chunk_idx = match entry_module_to_entry_chunk.get(&module.idx) {
+        Some(chunk_idx) => *chunk_idx,
+        None if module.css_view.is_none() => chunk_graph.add_chunk(Chunk::new(
+          None,
+          index_splitting_info[module.idx].bits.clone(),
+          vec![],
+          ChunkKind::Common,
+        )),
+        None => continue,
+      };
+      chunk_graph.add_module_to_chunk(module.idx, chunk_idx);
+      module_to_assigned[module.idx] = true;
+      assigned_chunks.push((module.idx, chunk_idx));
+    }
+
+    for (importer_idx, chunk_idx) in assigned_chunks {
+      let mut stack = vec![importer_idx];
+      while let Some(module_idx) = stack.pop() {
+        for &dep_idx in &self.link_output.metas[module_idx].dependencies {
+          let Module::Normal(dep) = &modules[dep_idx] else {
+            continue;
+          };
+          if module_to_assigned[dep_idx] || dep.css_view.is_none() || !dep.meta.is_included() {
+            continue;
+          }
+          chunk_graph.add_module_to_chunk(dep_idx, chunk_idx);
+          module_to_assigned[dep_idx] = true;
+          // Stylesheets imported by `@import` belong to the same chunk.
+          stack.push(dep_idx);
+        }
+      }
+    }
+
+    // Stylesheets that are not imported by any js module.
+    for module in included_modules {
+      if module_to_assigned[module.idx] {
+        continue;
+      }
+      let chunk_idx = chunk_graph.add_chunk(Chunk::new(
+        None,
+        index_splitting_info[module.idx].bits.clone(),
+        vec![],
+        ChunkKind::Common,
+      ));
+      chunk_graph.add_module_to_chunk(module.idx, chunk_idx);
+      module_to_assigned[module.idx] = true;
+    }
+  }
+
+  /// Creates a chunk for each name given by `output.manualChunks`. Modules are put into the chunk of the
+  /// first name that claims them, then their static dependencies join the same chunk unless they are claimed
+  /// by another one.
+  async fn apply_manual_chunks(
+    &self,
+    index_splitting_info: &IndexSplittingInfo,
+    module_to_assigned: &mut IndexVec<ModuleIdx, bool>,
+    chunk_graph: &mut ChunkGraph,
+  ) -> anyhow::Result<()> {
+    let Some(manual_chunks) = &self.options.manual_chunks else {
+      return Ok(());
+    };
+
+    let modules = &self.link_output.module_table.modules;
+    let module_infos: ManualChunksModuleInfos = Arc::new(
+      modules
+        .iter()
+        .filter_map(Module::as_normal)
+        .map(|module| (module.id.to_string(), Arc::new(module.to_module_info())))
+        .collect(),
+    );
+
+    let mut name_to_chunk: FxHashMap<ArcStr, ChunkIdx> = FxHashMap::default();
+    let mut claimed_modules = vec![];
+    for module in modules
+      .iter()
+      .filter_map(Module::as_normal)
+      .filter(|module| module.meta.is_included())
+      .sorted_by_key(|module| module.exec_order)
+    {
+      // The runtime module is not written by users, so it's only pulled in as a dependency.
+      if module_to_assigned[module.idx] || module.idx == self.link_output.runtime.id() {
+        continue;
+      }
+      let Some(name) = manual_chunks.call(module.id.as_str(), &module_infos).await? else {
+        continue;
+      };
+      let chunk_idx = *name_to_chunk.entry(ArcStr::from(name)).or_insert_with_key(|name| {
+        chunk_graph.add_chunk(Chunk::new(
+          Some(name.clone()),
+          index_splitting_info[module.idx].bits.clone(),
+          vec![],
+          ChunkKind::Common,
+        ))
+      });
+      chunk_graph.chunk_table[chunk_idx].bits.union(&index_splitting_info[module.idx].bits);
+      chunk_graph.add_module_to_chunk(module.idx, chunk_idx);
+      module_to_assigned[module.idx] = true;
+      claimed_modules.push((module.idx, chunk_idx));
+    }
+
+    for (claimed_idx, chunk_idx) in claimed_modules {
+      let mut stack = vec![claimed_idx];
+      while let Some(module_idx) = stack.pop() {
+        for &dep_idx in &self.link_output.metas[module_idx].dependencies {
+          let Module::Normal(dep) = &modules[dep_idx] else {
+            continue;
+          };
+          if module_to_assigned[dep_idx] || !dep.meta.is_included() {
+            continue;
+          }
+          chunk_graph.chunk_table[chunk_idx].bits.union(&index_splitting_info[dep_idx].bits);
+          chunk_graph.add_module_to_chunk(dep_idx, chunk_idx);
+          module_to_assigned[dep_idx] = true;
+          stack.push(dep_idx);
+        }
+      }
+    }
+
+    Ok(())
+  }
+
   #[allow(clippy::too_many_lines)] // TODO(hyf0): refactor
-  fn apply_advanced_chunks(
+  async fn apply_advanced_chunks(
     &mut self,
     index_splitting_info: &IndexSplittingInfo,
     module_to_assigned: &mut IndexVec<ModuleIdx, bool>,
     chunk_graph: &mut ChunkGraph,
-  ) {
+  ) -> anyhow::Result<()> {
     fn add_module_and_dependencies_to_group_recursively(
       module_group: &mut ModuleGroup,
       module: ModuleIdx,
@@ -317,17 +656,17 @@ impl<'a> GenerateStage<'a> {
     }
 
     let Some(chunking_options) = &self.options.advanced_chunks else {
-      return;
+      return Ok(());
     };
 
     let Some(match_groups) =
       chunking_options.groups.as_ref().map(|inner| inner.iter().collect::<Vec<_>>())
     else {
-      return;
+      return Ok(());
     };
 
     if match_groups.is_empty() {
-      return;
+      return Ok(());
     }
 
     let mut index_module_groups: IndexVec<ModuleGroupIdx, ModuleGroup> = IndexVec::new();
@@ -346,8 +685,10 @@ impl<'a> GenerateStage<'a> {
       let splitting_info = &index_splitting_info[normal_module.idx];
 
       for (match_group_index, match_group) in match_groups.iter().copied().enumerate() {
-        let is_matched =
-          match_group.test.as_ref().map_or(true, |test| test.matches(&normal_module.id));
+        let is_matched = match &match_group.test {
+          Some(test) => test.call(&normal_module.id).await?,
+          None => true,
+        };
 
         if !is_matched {
           continue;
@@ -404,26 +745,70 @@ impl<'a> GenerateStage<'a> {
         }
       }
 
-      let chunk = Chunk::new(
-        Some(this_module_group.name.clone()),
-        index_splitting_info
-          [this_module_group.modules.iter().next().copied().expect("must have one")]
-        .bits
-        .clone(),
-        vec![],
-        ChunkKind::Common,
-      );
+      let allow_max_size = match_groups[this_module_group.match_group_index]
+        .max_size
+        .map_or(chunking_options.max_size, Some);
+
+      for modules in split_module_group_by_max_size(
+        &this_module_group.modules,
+        allow_max_size,
+        &self.link_output.module_table,
+      ) {
+        let chunk = Chunk::new(
+          Some(this_module_group.name.clone()),
+          index_splitting_info[modules[0]].bits.clone(),
+          vec![],
+          ChunkKind::Common,
+        );
 
-      let chunk_idx = chunk_graph.add_chunk(chunk);
+        let chunk_idx = chunk_graph.add_chunk(chunk);
 
-      this_module_group.modules.iter().copied().for_each(|module_idx| {
-        module_groups.iter_mut().for_each(|group| {
-          group.remove_module(module_idx, &self.link_output.module_table);
+        modules.into_iter().for_each(|module_idx| {
+          module_groups.iter_mut().for_each(|group| {
+            group.remove_module(module_idx, &self.link_output.module_table);
+          });
+          chunk_graph.chunk_table[chunk_idx].bits.union(&index_splitting_info[module_idx].bits);
+          chunk_graph.add_module_to_chunk(module_idx, chunk_idx);
+          module_to_assigned[module_idx] = true;
         });
-        chunk_graph.chunk_table[chunk_idx].bits.union(&index_splitting_info[module_idx].bits);
-        chunk_graph.add_module_to_chunk(module_idx, chunk_idx);
-        module_to_assigned[module_idx] = true;
-      });
+      }
+    }
+
+    Ok(())
+  }
+}
+
+/// Splits the modules of a group into parts in their execution order, so that each part is not larger than
+/// `max_size`. A part always contains at least one module, even if the module alone exceeds `max_size`.
+#[allow(clippy::cast_precision_loss)] // We consider `usize` to `f64` is safe here
+fn split_module_group_by_max_size(
+  modules: &FxHashSet<ModuleIdx>,
+  max_size: Option<f64>,
+  module_table: &ModuleTable,
+) -> Vec<Vec<ModuleIdx>> {
+  let modules = modules
+    .iter()
+    .copied()
+    .sorted_by_key(|module_idx| module_table.modules[*module_idx].exec_order())
+    .collect::<Vec<_>>();
+  let Some(max_size) = max_size else {
+    return vec![modules];
+  };
+
+  let mut parts: Vec<Vec<ModuleIdx>> = vec![];
+  let mut part_size = 0.0;
+  for module_idx in modules {
+    let size = module_table.modules[module_idx].size() as f64;
+    match parts.last_mut() {
+      Some(part) if part_size + size <= max_size => {
+        part.push(module_idx);
+        part_size += size;
+      }
+      _ => {
+        parts.push(vec![module_idx]);
+        part_size = size;
+      }
     }
   }
+  parts
 }
@@ -175,7 +175,7 @@ impl<'a> GenerateStage<'a> {
                 if !importee_module.meta.is_included() {
                   return;
                 }
-                if matches!(rec.kind, ImportKind::DynamicImport) {
+                if matches!(rec.kind, ImportKind::DynamicImport | ImportKind::NewUrl) {
                   let importee_chunk = chunk_graph.module_to_chunk[importee_module.idx]
                     .expect("importee chunk should exist");
                   cross_chunk_dynamic_imports.insert(importee_chunk);
@@ -0,0 +1,188 @@
+use std::fmt::Write as _;
+
+use oxc::{
+  ast::{
+    ast::{
+      BindingIdentifier, IdentifierName, IdentifierReference, LabelIdentifier, PrivateIdentifier,
+    },
+    Visit,
+  },
+  sourcemap::Token,
+  span::{SourceType, Span},
+};
+use rolldown_common::{Charset, InstantiationKind};
+use rolldown_ecmascript::EcmaCompiler;
+use rolldown_sourcemap::SourceMap;
+use rolldown_utils::rayon::{IntoParallelRefMutIterator, ParallelIterator};
+
+use crate::type_alias::IndexAssets;
+
+use super::GenerateStage;
+
+impl<'a> GenerateStage<'a> {
+  /// Escapes the non-ASCII characters of ecma assets for `output.charset: 'ascii'`. Every character is
+  /// escaped as `\uXXXX`, which is valid in strings, templates, regexes and identifiers alike, except
+  /// that characters outside the BMP are escaped as `\u{XXXXX}` in identifiers, where surrogate pairs
+  /// aren't allowed.
+  pub fn escape_non_ascii_assets(&self, assets: &mut IndexAssets) {
+    if !matches!(self.options.charset, Charset::Ascii) {
+      return;
+    }
+
+    assets.par_iter_mut().for_each(|asset| {
+      if !matches!(asset.meta, InstantiationKind::Ecma(_)) || asset.content.is_ascii() {
+        return;
+      }
+      let identifier_spans = if asset.content.chars().any(|c| c.len_utf16() == 2) {
+        collect_non_ascii_identifier_spans(&asset.filename, &asset.content)
+      } else {
+        vec![]
+      };
+      let (content, shifts) = escape_non_ascii(&asset.content, &identifier_spans);
+      asset.content = content;
+      if let Some(map) = &asset.map {
+        asset.map = Some(shift_sourcemap_columns(map, &shifts));
+      }
+    });
+  }
+}
+
+/// The columns of escaped characters on each line, with the number of UTF-16 code units each escape
+/// adds.
+type ColumnShifts = Vec<Vec<(u32, u32)>>;
+
+#[allow(clippy::cast_possible_truncation)]
+fn escape_non_ascii(code: &str, identifier_spans: &[Span]) -> (String, ColumnShifts) {
+  let mut escaped = String::with_capacity(code.len());
+  let mut shifts: ColumnShifts = vec![vec![]];
+  let mut column = 0;
+  let mut backslashes = 0;
+  for (offset, c) in code.char_indices() {
+    if c.is_ascii() {
+      escaped.push(c);
+      if c == '\n' {
+        shifts.push(vec![]);
+        column = 0;
+      } else {
+        column += 1;
+      }
+      backslashes = if c == '\\' { backslashes + 1 } else { 0 };
+      continue;
+    }
+
+    let mut start_column = column;
+    let mut width = c.len_utf16() as u32;
+    // `\é` in a string or regex is the same as `é`, so the backslash is replaced together with it.
+    if backslashes % 2 == 1 {
+      escaped.pop();
+      start_column -= 1;
+      width += 1;
+    }
+    backslashes = 0;
+
+    let start = escaped.len();
+    if c.len_utf16() == 2 && is_in_spans(identifier_spans, offset) {
+      write!(escaped, "\\u{{{:X}}}", c as u32).expect("should write to string");
+    } else {
+      for unit in c.encode_utf16(&mut [0; 2]) {
+        write!(escaped, "\\u{unit:04X}").expect("should write to string");
+      }
+    }
+    let escaped_width = (escaped.len() - start) as u32;
+    shifts.last_mut().expect("should have a line").push((start_column, escaped_width - width));
+    column += c.len_utf16() as u32;
+  }
+  (escaped, shifts)
+}
+
+fn is_in_spans(spans: &[Span], offset: usize) -> bool {
+  let idx = spans.partition_point(|span| span.start as usize <= offset);
+  idx > 0 && offset < spans[idx - 1].end as usize
+}
+
+/// Moves the generated columns of `map` past the escapes inserted before them.
+fn shift_sourcemap_columns(map: &SourceMap, shifts: &ColumnShifts) -> SourceMap {
+  let tokens = map
+    .get_tokens()
+    .map(|token| {
+      let (line, column) = (token.get_dst_line(), token.get_dst_col());
+      let shift = shifts.get(line as usize).map_or(0, |line_shifts| {
+        line_shifts.iter().take_while(|(start, _)| *start < column).map(|(_, added)| added).sum()
+      });
+      Token::new(
+        line,
+        column + shift,
+        token.get_src_line(),
+        token.get_src_col(),
+        token.get_source_id(),
+        token.get_name_id(),
+      )
+    })
+    .collect::<Vec<_>>();
+
+  SourceMap::new(
+    None,
+    map.get_names().map(Into::into).collect::<Vec<_>>(),
+    None,
+    map.get_sources().map(Into::into).collect::<Vec<_>>(),
+    map.get_source_contents().map(|x| x.map(Into::into).collect::<Vec<_>>()),
+    tokens,
+    None,
+  )
+}
+
+/// The spans of identifiers containing non-ASCII characters, sorted by their start.
+fn collect_non_ascii_identifier_spans(filename: &str, code: &str) -> Vec<Span> {
+  let Ok(ast) = EcmaCompiler::parse(filename, code, SourceType::mjs()) else {
+    return vec![];
+  };
+  let mut collector = NonAsciiIdentifierCollector { spans: vec![] };
+  collector.visit_program(ast.program());
+  collector.spans.sort_unstable_by_key(|span| span.start);
+  collector.spans
+}
+
+struct NonAsciiIdentifierCollector {
+  spans: Vec<Span>,
+}
+
+impl NonAsciiIdentifierCollector {
+  fn add(&mut self, name: &str, span: Span) {
+    if !name.is_ascii() {
+      self.spans.push(span);
+    }
+  }
+}
+
+impl<'a> Visit<'a> for NonAsciiIdentifierCollector {
+  fn visit_identifier_name(&mut self, it: &IdentifierName<'a>) {
+    self.add(&it.name, it.span);
+  }
+
+  fn visit_identifier_reference(&mut self, it: &IdentifierReference<'a>) {
+    self.add(&it.name, it.span);
+  }
+
+  fn visit_binding_identifier(&mut self, it: &BindingIdentifier<'a>) {
+    self.add(&it.name, it.span);
+  }
+
+  fn visit_label_identifier(&mut self, it: &LabelIdentifier<'a>) {
+    self.add(&it.name, it.span);
+  }
+
+  fn visit_private_identifier(&mut self, it: &PrivateIdentifier<'a>) {
+    self.add(&it.name, it.span);
+  }
+}
+
+#[test]
+fn test_escape_non_ascii() {
+  let (code, shifts) = escape_non_ascii("const s = 'é';\n/\\é/", &[]);
+  assert_eq!(code, "const s = '\\u00E9';\n/\\u00E9/");
+  assert_eq!(shifts, vec![vec![(11, 5)], vec![(1, 4)]]);
+
+  let code = "const 𝑥 = '😀'";
+  let (escaped, _) = escape_non_ascii(code, &[Span::new(6, 10)]);
+  assert_eq!(escaped, "const \\u{1D465} = '\\uD83D\\uDE00'");
+}
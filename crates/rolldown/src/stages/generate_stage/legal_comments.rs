@@ -0,0 +1,143 @@
+use std::path::Path;
+
+use arcstr::ArcStr;
+use oxc::{ast::CommentKind, span::SourceType};
+use rolldown_common::{
+  is_legal_comment, InstantiationKind, LegalComments, MinifyOptions, Output, OutputAsset,
+};
+use rolldown_ecmascript::EcmaCompiler;
+use rolldown_sourcemap::{
+  collapse_sourcemaps, lines_count, ConcatSource, RawSource, SourceMapSource,
+};
+use rolldown_utils::rayon::{IntoParallelRefMutIterator, ParallelIterator};
+
+use crate::type_alias::IndexAssets;
+
+use super::GenerateStage;
+
+impl<'a> GenerateStage<'a> {
+  /// Legal comments are taken out of the chunks unless they stay in place. The minifier removes every
+  /// comment, so `inline` takes them out of minified chunks too and puts them back after minification.
+  fn extracts_legal_comments(&self) -> bool {
+    match self.options.legal_comments {
+      LegalComments::Inline => self.options.minify.as_ref().is_some_and(MinifyOptions::codegen),
+      _ => true,
+    }
+  }
+
+  /// Removes the legal comments of ecma assets. Returns the comments of each asset in their order of
+  /// appearance, without duplicates.
+  pub fn extract_legal_comments(&self, assets: &mut IndexAssets) -> Vec<Vec<String>> {
+    if !self.extracts_legal_comments() {
+      return vec![];
+    }
+
+    assets
+      .par_iter_mut()
+      .map(|asset| {
+        if !matches!(asset.meta, InstantiationKind::Ecma(_)) {
+          return vec![];
+        }
+        let source: ArcStr = asset.content.as_str().into();
+        let Ok(mut ast) = EcmaCompiler::parse(&asset.filename, source.clone(), SourceType::mjs())
+        else {
+          return vec![];
+        };
+
+        let legal_comments = ast.program.with_mut(|fields| {
+          let mut legal_comments: Vec<String> = vec![];
+          fields.program.comments.retain(|comment| {
+            let text = comment.span.source_text(&source);
+            if !is_legal_comment(text) {
+              return true;
+            }
+            let text = match comment.kind {
+              CommentKind::Line => format!("//{text}"),
+              CommentKind::Block => format!("/*{text}*/"),
+            };
+            if !legal_comments.contains(&text) {
+              legal_comments.push(text);
+            }
+            false
+          });
+          legal_comments
+        });
+        if legal_comments.is_empty() {
+          return legal_comments;
+        }
+
+        let ret = EcmaCompiler::print(&ast, &asset.filename, asset.map.is_some());
+        asset.content = ret.code;
+        if let (Some(origin_map), Some(new_map)) = (&asset.map, &ret.map) {
+          asset.map = Some(collapse_sourcemaps(vec![origin_map, new_map]));
+        }
+        legal_comments
+      })
+      .collect()
+  }
+
+  /// Puts the legal comments taken out by `extract_legal_comments` where `output.legalComments` wants
+  /// them. Returns the `<chunk>.LEGAL.txt` files to emit.
+  pub fn place_legal_comments(
+    &self,
+    assets: &mut IndexAssets,
+    legal_comments: Vec<Vec<String>>,
+  ) -> Vec<Output> {
+    let mut legal_assets = vec![];
+    for (asset, legal_comments) in assets.iter_mut().zip(legal_comments) {
+      if legal_comments.is_empty() {
+        continue;
+      }
+      let text = legal_comments.join("\n");
+      match self.options.legal_comments {
+        LegalComments::None => {}
+        // A hashbang must stay on the first line, so the comments go to the end of such chunks instead.
+        LegalComments::Inline if !asset.content.starts_with("#!") => {
+          let content = std::mem::take(&mut asset.content);
+          let mut concat_source = ConcatSource::default();
+          concat_source.add_source(Box::new(RawSource::new(text)));
+          match asset.map.take() {
+            Some(map) => {
+              let lines_count = lines_count(&content);
+              concat_source.add_source(Box::new(SourceMapSource::new(content, map, lines_count)));
+            }
+            None => concat_source.add_source(Box::new(RawSource::new(content))),
+          }
+          (asset.content, asset.map) = concat_source.content_and_sourcemap();
+        }
+        LegalComments::Inline | LegalComments::Eof => {
+          push_line(&mut asset.content, &text);
+        }
+        LegalComments::Linked | LegalComments::External => {
+          let legal_filename = format!("{}.LEGAL.txt", asset.filename);
+          if matches!(self.options.legal_comments, LegalComments::Linked) {
+            let basename = Path::new(&legal_filename)
+              .file_name()
+              .expect("should have filename")
+              .to_string_lossy()
+              .into_owned();
+            push_line(
+              &mut asset.content,
+              &format!("/*! For license information please see {basename} */"),
+            );
+          }
+          legal_assets.push(Output::Asset(Box::new(OutputAsset {
+            filename: legal_filename.into(),
+            source: format!("{text}\n").into(),
+            original_file_name: None,
+            name: None,
+          })));
+        }
+      }
+    }
+    legal_assets
+  }
+}
+
+fn push_line(content: &mut String, line: &str) {
+  if !content.is_empty() && !content.ends_with('\n') {
+    content.push('\n');
+  }
+  content.push_str(line);
+  content.push('\n');
+}
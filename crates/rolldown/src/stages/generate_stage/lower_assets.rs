@@ -0,0 +1,228 @@
+use std::path::Path;
+
+use arcstr::ArcStr;
+use oxc::{
+  ast::{
+    ast::{
+      ArrowFunctionExpression, AssignmentExpression, AssignmentOperator, BigIntLiteral,
+      BinaryExpression, BinaryOperator, CatchClause, ChainExpression, ForOfStatement, Function,
+      LogicalExpression, LogicalOperator, MethodDefinition, ObjectAssignmentTarget,
+      ObjectExpression, ObjectPattern, ObjectPropertyKind, PrivateInExpression, PropertyDefinition,
+      PropertyKey, StaticBlock,
+    },
+    visit::walk,
+    Visit,
+  },
+  semantic::{ScopeFlags, SemanticBuilder},
+  span::{SourceType, Span},
+  transformer::{EnvOptions, Targets, TransformOptions, Transformer},
+};
+use rolldown_common::{EsTarget, InstantiationKind};
+use rolldown_ecmascript::EcmaCompiler;
+use rolldown_error::{BuildDiagnostic, Severity};
+use rolldown_sourcemap::collapse_sourcemaps;
+use rolldown_utils::rayon::{IntoParallelRefMutIterator, ParallelIterator};
+
+use crate::type_alias::IndexAssets;
+
+use super::GenerateStage;
+
+impl<'a> GenerateStage<'a> {
+  /// Lowers the syntax of ecma assets to `output.target`. Syntax the transformer leaves untouched is
+  /// reported as errors, since the chunks would fail to parse in the engines of the target.
+  pub fn lower_assets(
+    &self,
+    assets: &mut IndexAssets,
+    errors: &mut Vec<BuildDiagnostic>,
+  ) -> anyhow::Result<()> {
+    let target = self.options.target;
+    let Some(query) = target.browserslist_query() else {
+      return Ok(());
+    };
+
+    let found = assets
+      .par_iter_mut()
+      .map(|asset| -> anyhow::Result<Vec<BuildDiagnostic>> {
+        if !matches!(asset.meta, InstantiationKind::Ecma(_)) {
+          return Ok(vec![]);
+        }
+        let source: ArcStr = asset.content.as_str().into();
+        let Ok(mut ast) = EcmaCompiler::parse(&asset.filename, source.clone(), SourceType::mjs())
+        else {
+          // The output of rolldown is always parsable unless plugins break it, which is out of the scope of lowering.
+          return Ok(vec![]);
+        };
+
+        let transform_options = TransformOptions::from_preset_env(&EnvOptions {
+          targets: Targets::from_query(query),
+          ..EnvOptions::default()
+        })
+        .map_err(|err| anyhow::anyhow!("Failed to lower chunks to {target}: {err:?}"))?;
+        let ret = ast.program.with_mut(|fields| {
+          let (symbols, scopes) = SemanticBuilder::new()
+            .build(fields.program)
+            .semantic
+            .into_symbol_table_and_scope_tree();
+          Transformer::new(fields.allocator, Path::new(&asset.filename), transform_options)
+            .build_with_symbols_and_scopes(symbols, scopes, fields.program)
+        });
+
+        let mut diagnostics = BuildDiagnostic::from_oxc_diagnostics(
+          ret.errors,
+          &source,
+          &asset.filename,
+          &Severity::Error,
+        );
+        let mut checker = UnloweredSyntaxChecker { target, found: vec![] };
+        checker.visit_program(ast.program());
+        diagnostics.extend(checker.found.into_iter().map(|(span, feature)| {
+          BuildDiagnostic::unsupported_target_syntax(
+            asset.filename.clone(),
+            source.clone(),
+            span,
+            feature.to_string(),
+            target.to_string(),
+          )
+        }));
+
+        let ret = EcmaCompiler::print(&ast, &asset.filename, asset.map.is_some());
+        asset.content = ret.code;
+        if let (Some(origin_map), Some(new_map)) = (&asset.map, &ret.map) {
+          asset.map = Some(collapse_sourcemaps(vec![origin_map, new_map]));
+        }
+        Ok(diagnostics)
+      })
+      .collect::<anyhow::Result<Vec<_>>>()?;
+
+    errors.extend(found.into_iter().flatten());
+    Ok(())
+  }
+}
+
+/// Finds syntax newer than the target, which is left in the chunks after lowering. Module syntax such as
+/// `import()` and `import.meta` is decided by `output.format` instead, so it isn't reported.
+struct UnloweredSyntaxChecker {
+  target: EsTarget,
+  found: Vec<(Span, &'static str)>,
+}
+
+impl UnloweredSyntaxChecker {
+  fn check(&mut self, year: u32, span: Span, feature: &'static str) {
+    if !self.target.supports(year) {
+      self.found.push((span, feature));
+    }
+  }
+}
+
+impl<'a> Visit<'a> for UnloweredSyntaxChecker {
+  fn visit_binary_expression(&mut self, it: &BinaryExpression<'a>) {
+    if matches!(it.operator, BinaryOperator::Exponential) {
+      self.check(2016, it.span, "the exponentiation operator");
+    }
+    walk::walk_binary_expression(self, it);
+  }
+
+  fn visit_assignment_expression(&mut self, it: &AssignmentExpression<'a>) {
+    match it.operator {
+      AssignmentOperator::Exponential => self.check(2016, it.span, "the exponentiation operator"),
+      AssignmentOperator::LogicalAnd
+      | AssignmentOperator::LogicalOr
+      | AssignmentOperator::LogicalNullish => {
+        self.check(2021, it.span, "logical assignment operators");
+      }
+      _ => {}
+    }
+    walk::walk_assignment_expression(self, it);
+  }
+
+  fn visit_function(&mut self, it: &Function<'a>, flags: ScopeFlags) {
+    match (it.r#async, it.generator) {
+      (true, true) => self.check(2018, it.span, "async generators"),
+      (true, false) => self.check(2017, it.span, "async functions"),
+      _ => {}
+    }
+    walk::walk_function(self, it, flags);
+  }
+
+  fn visit_arrow_function_expression(&mut self, it: &ArrowFunctionExpression<'a>) {
+    if it.r#async {
+      self.check(2017, it.span, "async functions");
+    }
+    walk::walk_arrow_function_expression(self, it);
+  }
+
+  fn visit_for_of_statement(&mut self, it: &ForOfStatement<'a>) {
+    if it.r#await {
+      self.check(2018, it.span, "`for await` loops");
+    }
+    walk::walk_for_of_statement(self, it);
+  }
+
+  fn visit_object_expression(&mut self, it: &ObjectExpression<'a>) {
+    for property in &it.properties {
+      if let ObjectPropertyKind::SpreadProperty(spread) = property {
+        self.check(2018, spread.span, "object spread");
+      }
+    }
+    walk::walk_object_expression(self, it);
+  }
+
+  fn visit_object_pattern(&mut self, it: &ObjectPattern<'a>) {
+    if let Some(rest) = &it.rest {
+      self.check(2018, rest.span, "object rest");
+    }
+    walk::walk_object_pattern(self, it);
+  }
+
+  fn visit_object_assignment_target(&mut self, it: &ObjectAssignmentTarget<'a>) {
+    if let Some(rest) = &it.rest {
+      self.check(2018, rest.span, "object rest");
+    }
+    walk::walk_object_assignment_target(self, it);
+  }
+
+  fn visit_catch_clause(&mut self, it: &CatchClause<'a>) {
+    if it.param.is_none() {
+      self.check(2019, it.span, "optional catch binding");
+    }
+    walk::walk_catch_clause(self, it);
+  }
+
+  fn visit_chain_expression(&mut self, it: &ChainExpression<'a>) {
+    self.check(2020, it.span, "optional chaining");
+    walk::walk_chain_expression(self, it);
+  }
+
+  fn visit_logical_expression(&mut self, it: &LogicalExpression<'a>) {
+    if matches!(it.operator, LogicalOperator::Coalesce) {
+      self.check(2020, it.span, "the nullish coalescing operator");
+    }
+    walk::walk_logical_expression(self, it);
+  }
+
+  fn visit_big_int_literal(&mut self, it: &BigIntLiteral<'a>) {
+    self.check(2020, it.span, "BigInt literals");
+  }
+
+  fn visit_property_definition(&mut self, it: &PropertyDefinition<'a>) {
+    self.check(2022, it.span, "class fields");
+    walk::walk_property_definition(self, it);
+  }
+
+  fn visit_method_definition(&mut self, it: &MethodDefinition<'a>) {
+    if matches!(it.key, PropertyKey::PrivateIdentifier(_)) {
+      self.check(2022, it.span, "private methods");
+    }
+    walk::walk_method_definition(self, it);
+  }
+
+  fn visit_static_block(&mut self, it: &StaticBlock<'a>) {
+    self.check(2022, it.span, "class static blocks");
+    walk::walk_static_block(self, it);
+  }
+
+  fn visit_private_in_expression(&mut self, it: &PrivateInExpression<'a>) {
+    self.check(2022, it.span, "`#field in obj` checks");
+    walk::walk_private_in_expression(self, it);
+  }
+}
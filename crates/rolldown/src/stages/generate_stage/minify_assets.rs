@@ -8,13 +8,17 @@ use super::GenerateStage;
 
 impl<'a> GenerateStage<'a> {
   pub fn minify_assets(&mut self, assets: &mut IndexAssets) -> anyhow::Result<()> {
-    if self.options.minify {
+    if let Some(minify_options) = &self.options.minify {
       assets.par_iter_mut().try_for_each(|asset| -> anyhow::Result<()> {
         match asset.meta {
           rolldown_common::InstantiationKind::Ecma(_) => {
             // TODO: Do we need to ensure `asset.filename` to be absolute path?
-            let (minified_content, new_map) =
-              EcmaCompiler::minify(&asset.content, asset.map.is_some(), &asset.filename)?;
+            let (minified_content, new_map) = EcmaCompiler::minify(
+              &asset.content,
+              asset.map.is_some(),
+              &asset.filename,
+              minify_options,
+            )?;
             asset.content = minified_content;
             match (&asset.map, &new_map) {
               (Some(origin_map), Some(new_map)) => {
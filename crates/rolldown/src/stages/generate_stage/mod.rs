@@ -1,4 +1,7 @@
-use std::collections::hash_map::Entry;
+use std::{
+  collections::hash_map::Entry,
+  path::{Path, PathBuf},
+};
 
 use anyhow::Result;
 use arcstr::ArcStr;
@@ -6,26 +9,33 @@ use oxc::{ast::VisitMut, index::IndexVec};
 use rolldown_ecmascript::AstSnippet;
 use rustc_hash::{FxHashMap, FxHashSet};
 
-use rolldown_common::{ChunkIdx, ChunkKind, FileNameRenderOptions, Module, PreliminaryFilename};
+use rolldown_common::{
+  ChunkIdx, ChunkKind, FileNameRenderOptions, Module, OutputFormat, PreliminaryFilename,
+};
 use rolldown_plugin::SharedPluginDriver;
 use rolldown_utils::{
   extract_hash_pattern::extract_hash_pattern,
   path_buf_ext::PathBufExt,
   path_ext::PathExt,
   rayon::{IntoParallelRefIterator, IntoParallelRefMutIterator, ParallelIterator},
-  sanitize_file_name::sanitize_file_name,
+  virtual_module::is_virtual_module_id,
 };
 use sugar_path::SugarPath;
 
 use crate::{
   chunk_graph::ChunkGraph,
+  css::emit_url_reference_assets,
   module_finalizers::{
     isolating::{IsolatingModuleFinalizer, IsolatingModuleFinalizerContext},
     scope_hoisting::ScopeHoistingFinalizerContext,
   },
   stages::link_stage::LinkStageOutput,
   utils::{
-    chunk::{deconflict_chunk_symbols::deconflict_chunk_symbols, generate_pre_rendered_chunk},
+    chunk::{
+      common_chunk_name::generate_common_chunk_name,
+      deconflict_chunk_symbols::deconflict_chunk_symbols, generate_pre_rendered_chunk,
+      render_chunk_exports::get_reassignable_export_names,
+    },
     extract_meaningful_input_name_from_path::try_extract_meaningful_input_name_from_path,
     finalize_normal_module,
     hash_placeholder::HashPlaceholderGenerator,
@@ -33,8 +43,14 @@ use crate::{
   BundleOutput, SharedOptions,
 };
 
+mod check_chunk_sizes;
+mod check_empty_chunks;
+mod check_ses_compatibility;
 mod code_splitting;
 mod compute_cross_chunk_links;
+mod escape_non_ascii_assets;
+mod legal_comments;
+mod lower_assets;
 mod minify_assets;
 mod render_chunk_to_assets;
 
@@ -55,22 +71,33 @@ impl<'a> GenerateStage<'a> {
 
   #[tracing::instrument(level = "debug", skip_all)]
   pub async fn generate(&mut self) -> Result<BundleOutput> {
+    self.emit_css_url_assets();
+    // File names of assets emitted by build hooks are needed to render the urls referencing them.
+    self.plugin_driver.file_emitter().generate_pending_file_names().await?;
+    self.resolve_copied_asset_paths();
+
     let mut chunk_graph = self.generate_chunks().await?;
 
     self.compute_cross_chunk_links(&mut chunk_graph);
 
     let index_chunk_id_to_name =
       self.generate_chunk_name_and_preliminary_filenames(&mut chunk_graph).await?;
+    let emitted_file_paths = self.collect_emitted_file_paths(&chunk_graph);
 
     chunk_graph.chunk_table.par_iter_mut().for_each(|chunk| {
-      deconflict_chunk_symbols(
-        chunk,
-        self.link_output,
-        &self.options.format,
-        &index_chunk_id_to_name,
-      );
+      deconflict_chunk_symbols(chunk, self.link_output, self.options, &index_chunk_id_to_name);
     });
 
+    let reassignable_exports = if matches!(self.options.format, OutputFormat::System) {
+      chunk_graph
+        .chunk_table
+        .iter()
+        .map(|chunk| get_reassignable_export_names(chunk, self.link_output))
+        .collect::<IndexVec<ChunkIdx, _>>()
+    } else {
+      IndexVec::default()
+    };
+
     let ast_table_iter = self.link_output.ast_table.par_iter_mut();
     ast_table_iter
       .filter(|(_ast, owner)| {
@@ -99,6 +126,8 @@ impl<'a> GenerateStage<'a> {
               runtime: &self.link_output.runtime,
               chunk_graph: &chunk_graph,
               options: self.options,
+              reassignable_exports: reassignable_exports.get(chunk_id),
+              emitted_file_paths: &emitted_file_paths,
             },
             ast,
           );
@@ -126,6 +155,66 @@ impl<'a> GenerateStage<'a> {
     self.render_chunk_to_assets(&mut chunk_graph).await
   }
 
+  /// Assets referenced by `url()` in stylesheets are only emitted if the stylesheets are included.
+  fn emit_css_url_assets(&mut self) {
+    let file_emitter = self.plugin_driver.file_emitter();
+    for module in &mut self.link_output.module_table.modules {
+      let Module::Normal(module) = module else {
+        continue;
+      };
+      if !module.meta.is_included() {
+        continue;
+      }
+      if let Some(css_view) = &mut module.css_view {
+        emit_url_reference_assets(css_view, file_emitter);
+      }
+    }
+  }
+
+  /// Files of the `copy` module type are imported by their paths relative to the importing chunks.
+  fn resolve_copied_asset_paths(&mut self) {
+    let file_emitter = self.plugin_driver.file_emitter();
+    let out_dir = self.options.cwd.join(&self.options.dir);
+    for module in &mut self.link_output.module_table.modules {
+      let Module::External(module) = module else {
+        continue;
+      };
+      if let Some(reference_id) = &module.copied_asset_reference_id {
+        let file_name = file_emitter.get_file_name(reference_id);
+        module.copied_asset_path =
+          Some(file_name.as_str().absolutize_with(out_dir.as_path()).expect_into_string());
+      }
+    }
+  }
+
+  /// Absolute paths of the files emitted by plugins by their reference ids, which resolve
+  /// `import.meta.ROLLUP_FILE_URL_<referenceId>`. Emitted chunks point at their preliminary filenames, whose
+  /// hash placeholders are replaced along with the ones of imports.
+  fn collect_emitted_file_paths(&self, chunk_graph: &ChunkGraph) -> FxHashMap<ArcStr, String> {
+    let out_dir = self.options.cwd.join(&self.options.dir);
+    let mut paths = self
+      .plugin_driver
+      .file_emitter()
+      .asset_file_names()
+      .into_iter()
+      .map(|(reference_id, file_name)| {
+        (reference_id, file_name.as_str().absolutize_with(out_dir.as_path()).expect_into_string())
+      })
+      .collect::<FxHashMap<_, _>>();
+    for entry_point in &self.link_output.entries {
+      let Some(chunk_idx) = chunk_graph.entry_module_to_entry_chunk.get(&entry_point.id) else {
+        continue;
+      };
+      let Some(path) = &chunk_graph.chunk_table[*chunk_idx].absolute_preliminary_filename else {
+        continue;
+      };
+      for reference_id in &entry_point.reference_ids {
+        paths.insert(reference_id.clone(), path.clone());
+      }
+    }
+    paths
+  }
+
   // Notices:
   // - Should generate filenames that are stable cross builds and os.
   // #[tracing::instrument(level = "debug", skip_all)]
@@ -136,44 +225,105 @@ impl<'a> GenerateStage<'a> {
   ) -> anyhow::Result<FxHashMap<ChunkIdx, ArcStr>> {
     let modules = &self.link_output.module_table.modules;
 
+    // Preserved modules are named after their paths relative to `preserve_modules_root` if they are inside
+    // of it, otherwise relative to the common directory of all modules. Virtual modules go to `_virtual/`.
+    let preserve_modules_root = if self.options.preserve_modules {
+      common_dir(
+        chunk_graph
+          .chunk_table
+          .iter()
+          .flat_map(|chunk| chunk.modules.iter())
+          .map(|module_idx| Path::new(modules[*module_idx].id()))
+          .filter(|path| path.is_absolute()),
+      )
+    } else {
+      None
+    };
+
     let mut index_chunk_id_to_name = FxHashMap::default();
-    let mut index_pre_generated_names: IndexVec<ChunkIdx, ArcStr> = chunk_graph
+    let pre_generated_names = chunk_graph
       .chunk_table
       .as_vec()
       .par_iter()
       .map(|chunk| {
         if let Some(name) = &chunk.name {
-          return name.clone();
+          return PreGeneratedName::Verbatim(name.clone());
+        }
+        if self.options.preserve_modules {
+          let module_idx = match chunk.kind {
+            ChunkKind::EntryPoint { module, is_user_defined, .. } => {
+              (!is_user_defined).then_some(module)
+            }
+            // Stylesheets share the chunk of their importer, which is the module to be named after.
+            ChunkKind::Common => chunk
+              .modules
+              .iter()
+              .find(|module_idx| {
+                modules[**module_idx].as_normal().is_some_and(|module| module.css_view.is_none())
+              })
+              .or(chunk.modules.first())
+              .copied(),
+          };
+          if let Some(module_idx) = module_idx {
+            let path = Path::new(modules[module_idx].id());
+            let root = self
+              .options
+              .preserve_modules_root
+              .as_ref()
+              .filter(|root| path.starts_with(root))
+              .or(preserve_modules_root.as_ref());
+            return match root {
+              Some(root) if path.is_absolute() => PreGeneratedName::Verbatim(ArcStr::from(
+                path.with_extension("").relative(root).expect_to_slash(),
+              )),
+              _ => PreGeneratedName::Unsanitized {
+                prefix: "_virtual/",
+                name: path.representative_file_name().into_owned(),
+              },
+            };
+          }
         }
         match chunk.kind {
           ChunkKind::EntryPoint { module: entry_module_id, is_user_defined, .. } => {
             let module = &modules[entry_module_id];
-            let generated = if is_user_defined {
-              try_extract_meaningful_input_name_from_path(module.id())
-                .map(ArcStr::from)
-                .unwrap_or(arcstr::literal!("input"))
+            // Ids of virtual modules could contain anything, so their names need to be sanitized.
+            if is_user_defined && !is_virtual_module_id(module.id()) {
+              PreGeneratedName::Verbatim(
+                try_extract_meaningful_input_name_from_path(module.id())
+                  .map(ArcStr::from)
+                  .unwrap_or(arcstr::literal!("input")),
+              )
             } else {
-              ArcStr::from(sanitize_file_name(module.id().as_path().representative_file_name()))
-            };
-            generated
-          }
-          ChunkKind::Common => {
-            // - rollup use the first entered/last executed module as the `[name]` of common chunks.
-            // - esbuild always use 'chunk' as the `[name]`. However we try to make the name more meaningful here.
-            let first_executed_non_runtime_module =
-              chunk.modules.iter().rev().find(|each| **each != self.link_output.runtime.id());
-            first_executed_non_runtime_module.map_or_else(
-              || arcstr::literal!("chunk"),
-              |module_id| {
-                let module = &modules[*module_id];
-                ArcStr::from(sanitize_file_name(module.id().as_path().representative_file_name()))
-              },
-            )
+              PreGeneratedName::Unsanitized {
+                prefix: "",
+                name: module.id().as_path().representative_file_name().into_owned(),
+              }
+            }
           }
+          ChunkKind::Common => PreGeneratedName::Unsanitized {
+            prefix: "",
+            name: generate_common_chunk_name(
+              chunk,
+              self.link_output,
+              self.options.chunk_name_strategy,
+            ),
+          },
         }
       })
-      .collect::<Vec<_>>()
-      .into();
+      .collect::<Vec<_>>();
+
+    // `sanitizeFileName` might be a js function, so it's called after names are generated in parallel.
+    let mut index_pre_generated_names: IndexVec<ChunkIdx, ArcStr> =
+      IndexVec::with_capacity(pre_generated_names.len());
+    for pre_generated_name in pre_generated_names {
+      index_pre_generated_names.push(match pre_generated_name {
+        PreGeneratedName::Verbatim(name) => name,
+        PreGeneratedName::Unsanitized { prefix, name } => {
+          let sanitized = self.options.sanitize_filename.call(&name).await?;
+          ArcStr::from(format!("{prefix}{sanitized}"))
+        }
+      });
+    }
 
     let mut hash_placeholder_generator = HashPlaceholderGenerator::default();
     let mut used_name_counts: FxHashMap<ArcStr, u32> = FxHashMap::default();
@@ -227,17 +377,35 @@ impl<'a> GenerateStage<'a> {
         pre_generated_name.clone()
       };
 
-      let hash_placeholder =
-        extracted_hash_pattern.map(|p| hash_placeholder_generator.generate(p.len.unwrap_or(8)));
+      let hash_characters = self.options.hash_characters;
+      let hash_placeholder = extracted_hash_pattern
+        .map(|p| {
+          hash_characters.hash_len(p.len).map(|len| hash_placeholder_generator.generate(len))
+        })
+        .transpose()?;
 
-      let css_hash_placeholder =
-        extracted_css_hash_pattern.map(|p| hash_placeholder_generator.generate(p.len.unwrap_or(8)));
+      let css_hash_placeholder = extracted_css_hash_pattern
+        .map(|p| {
+          hash_characters.hash_len(p.len).map(|len| hash_placeholder_generator.generate(len))
+        })
+        .transpose()?;
 
       let preliminary = filename_template.render(&FileNameRenderOptions {
         name: Some(&chunk_name),
         hash: hash_placeholder.as_deref(),
         ..Default::default()
       });
+      // Chunks emitted by plugins with an explicit `fileName` are written to it as is.
+      let preliminary = match chunk.kind {
+        ChunkKind::EntryPoint { module, .. } => self
+          .link_output
+          .entries
+          .iter()
+          .find(|entry_point| entry_point.id == module)
+          .and_then(|entry_point| entry_point.file_name.as_ref())
+          .map_or(preliminary, ToString::to_string),
+        ChunkKind::Common => preliminary,
+      };
 
       let css_preliminary = css_filename_template.render(&FileNameRenderOptions {
         name: Some(&chunk_name),
@@ -260,3 +428,22 @@ impl<'a> GenerateStage<'a> {
     Ok(index_chunk_id_to_name)
   }
 }
+
+/// The `[name]` of a chunk, which is sanitized by `sanitizeFileName` if it's derived from module ids.
+enum PreGeneratedName {
+  Verbatim(ArcStr),
+  Unsanitized { prefix: &'static str, name: String },
+}
+
+/// Finds the deepest directory that contains all of `paths`.
+fn common_dir<'p>(mut paths: impl Iterator<Item = &'p Path>) -> Option<PathBuf> {
+  let mut common = paths.next()?.parent()?.to_path_buf();
+  for path in paths {
+    while !path.starts_with(&common) {
+      if !common.pop() {
+        return None;
+      }
+    }
+  }
+  Some(common)
+}
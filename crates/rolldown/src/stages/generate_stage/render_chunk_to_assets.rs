@@ -1,23 +1,35 @@
-use std::path::Path;
+use std::{collections::hash_map::Entry, path::Path};
 
+use arcstr::ArcStr;
 use futures::future::try_join_all;
 use indexmap::IndexSet;
 use oxc::index::{index_vec, IndexVec};
-use rolldown_common::{Asset, InstantiationKind, Output, OutputAsset, OutputChunk, SourceMapType};
+use rolldown_common::{
+  Asset, ChunkIdx, InstantiationKind, Output, OutputAsset, OutputChunk, SourceMapType,
+};
 use rolldown_ecmascript::EcmaCompiler;
 use rolldown_error::BuildDiagnostic;
-use rolldown_utils::rayon::{IntoParallelRefIterator, ParallelIterator};
+use rolldown_sourcemap::SourceMap;
+use rolldown_utils::{
+  rayon::{IntoParallelRefIterator, ParallelIterator},
+  virtual_module::{display_virtual_module_id, is_virtual_module_id},
+  xxhash::xxhash_base64_url,
+};
+use rustc_hash::{FxHashMap, FxHashSet};
 use sugar_path::SugarPath;
 
 use crate::{
   chunk_graph::ChunkGraph,
   css::css_generator::CssGenerator,
   ecmascript::ecma_generator::EcmaGenerator,
-  type_alias::{IndexChunkToAssets, IndexInstantiatedChunks},
-  types::generator::{GenerateContext, Generator},
+  type_alias::{IndexAssets, IndexChunkToAssets, IndexInstantiatedChunks},
+  types::{
+    build_stats::BuildStats,
+    generator::{GenerateContext, Generator},
+  },
   utils::{
     augment_chunk_hash::augment_chunk_hash, chunk::finalize_chunks::finalize_assets,
-    render_chunks::render_chunks,
+    log_level::is_severity_enabled, render_chunks::render_chunks,
   },
   BundleOutput,
 };
@@ -39,12 +51,35 @@ impl<'a> GenerateStage<'a> {
 
     augment_chunk_hash(self.plugin_driver, &mut instantiated_chunks).await?;
 
-    let mut assets = finalize_assets(chunk_graph, instantiated_chunks, &index_chunk_to_assets);
+    let mut assets = finalize_assets(
+      chunk_graph,
+      instantiated_chunks,
+      &index_chunk_to_assets,
+      self.options.hash_characters,
+    );
+
+    self.lower_assets(&mut assets, &mut errors)?;
+
+    let legal_comments = self.extract_legal_comments(&mut assets);
 
     self.minify_assets(&mut assets)?;
 
+    let legal_assets = self.place_legal_comments(&mut assets, legal_comments);
+
+    self.escape_non_ascii_assets(&mut assets);
+
+    self.check_ses_compatibility(&assets, &mut warnings);
+
+    self.check_chunk_sizes(&assets, &mut warnings);
+
+    self.check_empty_chunks(&assets, &mut warnings);
+
+    self.set_emitted_chunk_file_names(chunk_graph, &assets);
+
+    let chunk_to_css_filenames = self.collect_chunk_to_css_filenames(chunk_graph, &mut assets);
+
     let mut output = Vec::with_capacity(assets.len());
-    let mut output_assets = vec![];
+    let mut output_assets = legal_assets;
     for Asset {
       mut map,
       meta: rendered_chunk,
@@ -52,71 +87,23 @@ impl<'a> GenerateStage<'a> {
       file_dir,
       preliminary_filename,
       filename,
+      origin_chunk,
       ..
     } in assets
     {
       if let InstantiationKind::Ecma(ecma_meta) = rendered_chunk {
         let rendered_chunk = ecma_meta.rendered_chunk;
         if let Some(map) = map.as_mut() {
-          map.set_file(&rendered_chunk.filename);
-
-          let map_filename = format!("{}.map", rendered_chunk.filename.as_str());
-          let map_path = file_dir.join(&map_filename);
-
-          if let Some(source_map_ignore_list) = &self.options.sourcemap_ignore_list {
-            let mut x_google_ignore_list = vec![];
-            for (index, source) in map.get_sources().enumerate() {
-              if source_map_ignore_list.call(source, map_path.to_string_lossy().as_ref()).await? {
-                #[allow(clippy::cast_possible_truncation)]
-                x_google_ignore_list.push(index as u32);
-              }
-            }
-            if !x_google_ignore_list.is_empty() {
-              map.set_x_google_ignore_list(x_google_ignore_list);
-            }
-          }
-
-          if let Some(sourcemap_path_transform) = &self.options.sourcemap_path_transform {
-            let mut sources = Vec::with_capacity(map.get_sources().count());
-            for source in map.get_sources() {
-              sources.push(
-                sourcemap_path_transform.call(source, map_path.to_string_lossy().as_ref()).await?,
-              );
-            }
-            map.set_sources(sources.iter().map(std::convert::AsRef::as_ref).collect::<Vec<_>>());
-          }
-
-          // Normalize the windows path at final.
-          let sources =
-            map.get_sources().map(|x| x.to_slash_lossy().to_string()).collect::<Vec<_>>();
-          map.set_sources(sources.iter().map(std::convert::AsRef::as_ref).collect::<Vec<_>>());
-
-          if let Some(sourcemap) = &self.options.sourcemap {
-            match sourcemap {
-              SourceMapType::File | SourceMapType::Hidden => {
-                let source = map.to_json_string();
-                output_assets.push(Output::Asset(Box::new(OutputAsset {
-                  filename: map_filename.as_str().into(),
-                  source: source.into(),
-                  original_file_name: None,
-                  name: None,
-                })));
-                if matches!(sourcemap, SourceMapType::File) {
-                  code.push_str(&format!(
-                    "\n//# sourceMappingURL={}",
-                    Path::new(&map_filename)
-                      .file_name()
-                      .expect("should have filename")
-                      .to_string_lossy()
-                  ));
-                }
-              }
-              SourceMapType::Inline => {
-                let data_url = map.to_data_url();
-                code.push_str(&format!("\n//# sourceMappingURL={data_url}"));
-              }
-            }
-          }
+          self
+            .emit_sourcemap(
+              map,
+              &rendered_chunk.filename,
+              &file_dir,
+              &mut code,
+              false,
+              &mut output_assets,
+            )
+            .await?;
         }
 
         let sourcemap_filename =
@@ -137,11 +124,18 @@ impl<'a> GenerateStage<'a> {
           module_ids: rendered_chunk.module_ids,
           imports: rendered_chunk.imports,
           dynamic_imports: rendered_chunk.dynamic_imports,
+          imported_css: chunk_to_css_filenames[origin_chunk].clone(),
           map,
           sourcemap_filename,
           preliminary_filename: preliminary_filename.to_string(),
         })));
       } else {
+        // Css files are the only other assets generated from chunks.
+        if let Some(map) = map.as_mut() {
+          self
+            .emit_sourcemap(map, &filename, &file_dir, &mut code, true, &mut output_assets)
+            .await?;
+        }
         output.push(Output::Asset(Box::new(OutputAsset {
           filename: filename.clone().into(),
           source: code.into(),
@@ -169,7 +163,158 @@ impl<'a> GenerateStage<'a> {
 
     output.extend(output_assets);
 
-    Ok(BundleOutput { assets: output, errors, warnings, watch_files: vec![] })
+    warnings.retain(|warning| is_severity_enabled(self.options.log_level, warning.severity()));
+
+    Ok(BundleOutput {
+      assets: output,
+      errors,
+      warnings,
+      watch_files: vec![],
+      stats: BuildStats::default(),
+    })
+  }
+
+  /// Applies the sourcemap options to the map of a chunk or a css file, then either emits it as a `.map`
+  /// asset or inlines it, depending on `output.sourcemap`.
+  async fn emit_sourcemap(
+    &self,
+    map: &mut SourceMap,
+    filename: &str,
+    file_dir: &Path,
+    code: &mut String,
+    is_css: bool,
+    output_assets: &mut Vec<Output>,
+  ) -> anyhow::Result<()> {
+    map.set_file(filename);
+
+    let map_filename = format!("{filename}.map");
+    let map_path = file_dir.join(&map_filename);
+
+    if let Some(source_map_ignore_list) = &self.options.sourcemap_ignore_list {
+      let mut x_google_ignore_list = vec![];
+      for (index, source) in map.get_sources().enumerate() {
+        if source_map_ignore_list.call(source, map_path.to_string_lossy().as_ref()).await? {
+          #[allow(clippy::cast_possible_truncation)]
+          x_google_ignore_list.push(index as u32);
+        }
+      }
+      if !x_google_ignore_list.is_empty() {
+        map.set_x_google_ignore_list(x_google_ignore_list);
+      }
+    }
+
+    if let Some(sourcemap_path_transform) = &self.options.sourcemap_path_transform {
+      let mut sources = Vec::with_capacity(map.get_sources().count());
+      for source in map.get_sources() {
+        sources
+          .push(sourcemap_path_transform.call(source, map_path.to_string_lossy().as_ref()).await?);
+      }
+      map.set_sources(sources.iter().map(std::convert::AsRef::as_ref).collect::<Vec<_>>());
+    }
+
+    // Normalize the windows path at final, and make the ids of virtual modules readable.
+    let sources = map
+      .get_sources()
+      .map(|x| {
+        if is_virtual_module_id(x) {
+          display_virtual_module_id(x)
+        } else {
+          x.to_slash_lossy().to_string()
+        }
+      })
+      .collect::<Vec<_>>();
+    map.set_sources(sources.iter().map(std::convert::AsRef::as_ref).collect::<Vec<_>>());
+
+    if let Some(sourcemap) = &self.options.sourcemap {
+      match sourcemap {
+        SourceMapType::File | SourceMapType::Hidden => {
+          let source = map.to_json_string();
+          output_assets.push(Output::Asset(Box::new(OutputAsset {
+            filename: map_filename.as_str().into(),
+            source: source.into(),
+            original_file_name: None,
+            name: None,
+          })));
+          if matches!(sourcemap, SourceMapType::File) {
+            let url = Path::new(&map_filename)
+              .file_name()
+              .expect("should have filename")
+              .to_string_lossy()
+              .into_owned();
+            code.push_str(&render_source_mapping_url(&url, is_css));
+          }
+        }
+        SourceMapType::Inline => {
+          let data_url = map.to_data_url();
+          code.push_str(&render_source_mapping_url(&data_url, is_css));
+        }
+      }
+    }
+    Ok(())
+  }
+
+  /// Make file names of chunks emitted by plugins available to `PluginContext#get_file_name`.
+  fn set_emitted_chunk_file_names(&self, chunk_graph: &ChunkGraph, assets: &IndexAssets) {
+    for entry_point in &self.link_output.entries {
+      if entry_point.reference_ids.is_empty() {
+        continue;
+      }
+      let Some(chunk_idx) = chunk_graph.entry_module_to_entry_chunk.get(&entry_point.id) else {
+        continue;
+      };
+      let Some(asset) = assets.iter().find(|asset| {
+        asset.origin_chunk == *chunk_idx && matches!(asset.meta, InstantiationKind::Ecma(_))
+      }) else {
+        continue;
+      };
+      for reference_id in &entry_point.reference_ids {
+        self
+          .plugin_driver
+          .file_emitter()
+          .set_chunk_file_name(reference_id.clone(), asset.filename.as_str().into());
+      }
+    }
+  }
+
+  /// Css is generated per chunk, so a chunk's css file shares the chunk's loading boundary.
+  ///
+  /// Chunks with byte-identical css, such as entries importing the same component library, share the first
+  /// emitted css file and the copies are dropped. Preserved modules import their css files by name, so
+  /// they are kept as is.
+  fn collect_chunk_to_css_filenames(
+    &self,
+    chunk_graph: &ChunkGraph,
+    assets: &mut IndexAssets,
+  ) -> IndexVec<ChunkIdx, Vec<ArcStr>> {
+    let mut chunk_to_css_filenames = index_vec![vec![]; chunk_graph.chunk_table.len()];
+    let mut filename_by_content_hash: FxHashMap<String, ArcStr> = FxHashMap::default();
+    let mut duplicated_assets = FxHashSet::default();
+    for (asset_idx, asset) in
+      assets.iter_enumerated().filter(|(_, asset)| matches!(asset.meta, InstantiationKind::None))
+    {
+      let filename = ArcStr::from(asset.filename.as_str());
+      let filename = if self.options.preserve_modules {
+        filename
+      } else {
+        match filename_by_content_hash.entry(xxhash_base64_url(asset.content.as_bytes())) {
+          Entry::Occupied(occ) => {
+            duplicated_assets.insert(asset_idx);
+            occ.get().clone()
+          }
+          Entry::Vacant(vac) => vac.insert(filename).clone(),
+        }
+      };
+      chunk_to_css_filenames[asset.origin_chunk].push(filename);
+    }
+
+    if !duplicated_assets.is_empty() {
+      *assets = std::mem::take(assets)
+        .into_iter_enumerated()
+        .filter(|(asset_idx, _)| !duplicated_assets.contains(asset_idx))
+        .map(|(_, asset)| asset)
+        .collect();
+    }
+    chunk_to_css_filenames
   }
 
   async fn instantiate_chunks(
@@ -282,3 +427,12 @@ impl<'a> GenerateStage<'a> {
     chunk_to_codegen_ret
   }
 }
+
+/// Css doesn't support line comments, so the url is put in a block comment.
+fn render_source_mapping_url(url: &str, is_css: bool) -> String {
+  if is_css {
+    format!("\n/*# sourceMappingURL={url} */")
+  } else {
+    format!("\n//# sourceMappingURL={url}")
+  }
+}
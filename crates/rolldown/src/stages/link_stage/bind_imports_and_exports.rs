@@ -2,11 +2,12 @@ use arcstr::ArcStr;
 // TODO: The current implementation for matching imports is enough so far but incomplete. It needs to be refactored
 // if we want more enhancements related to exports.
 use rolldown_common::{
-  ExportsKind, IndexModules, Module, ModuleIdx, ModuleType, NamespaceAlias, ResolvedExport,
-  Specifier, SymbolOrMemberExprRef, SymbolRef, SymbolRefDb,
+  ExportsKind, IndexModules, Module, ModuleIdx, ModuleType, NamedImport, NamespaceAlias,
+  NormalModule, ResolvedExport, Specifier, SymbolOrMemberExprRef, SymbolRef, SymbolRefDb,
 };
-use rolldown_error::{AmbiguousExternalNamespaceModule, BuildDiagnostic};
+use rolldown_error::{AmbiguousExternalNamespaceModule, BuildDiagnostic, MissingExportSuggestion};
 use rolldown_rstr::{Rstr, ToRstr};
+use rolldown_utils::did_you_mean::did_you_mean;
 #[cfg(not(target_family = "wasm"))]
 use rolldown_utils::rayon::IndexedParallelIterator;
 use rolldown_utils::rayon::{
@@ -381,50 +382,13 @@ impl<'a> BindImportsAndExportsContext<'a> {
       match ret {
         MatchImportKind::_Ignore | MatchImportKind::Cycle => {}
         MatchImportKind::Ambiguous { symbol_ref, potentially_ambiguous_symbol_refs } => {
-          let importee = self.normal_modules[rec.resolved_module].stable_id().to_string();
-
-          let mut exporter = Vec::with_capacity(potentially_ambiguous_symbol_refs.len() + 1);
-          if let Some(owner) = self.normal_modules[symbol_ref.owner].as_normal() {
-            if let Specifier::Literal(name) = &named_import.imported {
-              let named_export = &owner.named_exports[name];
-              exporter.push(AmbiguousExternalNamespaceModule {
-                source: owner.source.clone(),
-                filename: owner.stable_id.to_string(),
-                span_of_identifier: named_export.span,
-              });
-            }
-          }
-
-          exporter.extend(
-            potentially_ambiguous_symbol_refs
-              .iter()
-              .filter_map(|&symbol_ref| {
-                if let Some(owner) = self.normal_modules[symbol_ref.owner].as_normal() {
-                  if let Specifier::Literal(name) = &named_import.imported {
-                    let named_export = &owner.named_exports[name];
-                    return Some(AmbiguousExternalNamespaceModule {
-                      source: owner.source.clone(),
-                      filename: owner.stable_id.to_string(),
-                      span_of_identifier: named_export.span,
-                    });
-                  }
-                }
-
-                None
-              })
-              .collect::<Vec<_>>(),
+          let diagnostic = self.ambiguous_external_namespace_diagnostic(
+            module,
+            named_import,
+            rec.resolved_module,
+            std::iter::once(symbol_ref).chain(potentially_ambiguous_symbol_refs),
           );
-
-          self.errors.push(BuildDiagnostic::ambiguous_external_namespace(
-            named_import.imported.to_string(),
-            importee,
-            AmbiguousExternalNamespaceModule {
-              source: module.source.clone(),
-              filename: module.stable_id.to_string(),
-              span_of_identifier: named_import.span_imported,
-            },
-            exporter,
-          ));
+          self.errors.push(diagnostic);
         }
         MatchImportKind::Normal { symbol } => {
           self.symbol_db.link(*imported_as_ref, symbol);
@@ -437,19 +401,83 @@ impl<'a> BindImportsAndExportsContext<'a> {
             Some(NamespaceAlias { property_name: alias, namespace_ref });
         }
         MatchImportKind::NoMatch => {
-          let importee = &self.normal_modules[rec.resolved_module];
-          self.errors.push(BuildDiagnostic::missing_export(
-            module.stable_id.to_string(),
-            importee.stable_id().to_string(),
-            module.source.clone(),
-            named_import.imported.to_string(),
-            named_import.span_imported,
-          ));
+          let diagnostic =
+            self.missing_export_diagnostic(module, named_import, rec.resolved_module);
+          self.errors.push(diagnostic);
         }
       }
     }
   }
 
+  /// Every candidate export of an ambiguous import is pointed at, if it's a named export of a module.
+  fn ambiguous_external_namespace_diagnostic(
+    &self,
+    module: &NormalModule,
+    named_import: &NamedImport,
+    importee: ModuleIdx,
+    candidate_refs: impl Iterator<Item = SymbolRef>,
+  ) -> BuildDiagnostic {
+    let exporter = candidate_refs
+      .filter_map(|symbol_ref| {
+        let owner = self.normal_modules[symbol_ref.owner].as_normal()?;
+        let Specifier::Literal(name) = &named_import.imported else {
+          return None;
+        };
+        Some(AmbiguousExternalNamespaceModule {
+          source: owner.source.clone(),
+          filename: owner.stable_id.to_string(),
+          span_of_identifier: owner.named_exports[name].span,
+        })
+      })
+      .collect::<Vec<_>>();
+    BuildDiagnostic::ambiguous_external_namespace(
+      named_import.imported.to_string(),
+      self.normal_modules[importee].stable_id().to_string(),
+      AmbiguousExternalNamespaceModule {
+        source: module.source.clone(),
+        filename: module.stable_id.to_string(),
+        span_of_identifier: named_import.span_imported,
+      },
+      exporter,
+    )
+  }
+
+  /// Lists the exports of the importee, and suggests the closest one to the imported name.
+  fn missing_export_diagnostic(
+    &self,
+    module: &NormalModule,
+    named_import: &NamedImport,
+    importee: ModuleIdx,
+  ) -> BuildDiagnostic {
+    let importee = &self.normal_modules[importee];
+    let resolved_exports = &self.metas[importee.idx()].resolved_exports;
+    let mut exports = resolved_exports.keys().map(ToString::to_string).collect::<Vec<_>>();
+    exports.sort_unstable();
+    let suggestion = match &named_import.imported {
+      Specifier::Literal(name) => did_you_mean(name, exports.iter().map(String::as_str)),
+      Specifier::Star => None,
+    }
+    .and_then(|name| {
+      let name = name.to_rstr();
+      let owner = self.normal_modules[resolved_exports[&name].symbol_ref.owner].as_normal()?;
+      Some(MissingExportSuggestion {
+        span: owner.named_exports.get(&name)?.span,
+        name: name.to_string(),
+        stable_exporter: owner.stable_id.to_string(),
+        exporter_source: owner.source.clone(),
+      })
+    });
+    BuildDiagnostic::missing_export(
+      module.stable_id.to_string(),
+      importee.stable_id().to_string(),
+      module.source.clone(),
+      named_import.imported.to_string(),
+      named_import.span_imported,
+      exports,
+      suggestion,
+    )
+  }
+
   fn advance_import_tracker(&self, ctx: &mut MatchingContext) -> ImportStatus {
     let tracker = ctx.current_tracker();
     let importer = &self.normal_modules[tracker.importer]
@@ -0,0 +1,83 @@
+use rolldown_common::{ImportKind, ImportRecordMeta, Module, ModuleIdx, NormalModule, StmtInfoIdx};
+use rolldown_error::BuildDiagnostic;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use super::LinkStage;
+
+impl<'a> LinkStage<'a> {
+  /// Tree shaking only includes the modules re-exported by `export * from` whose exports are used, unless
+  /// they have side effects. Reports the barrel modules that include such modules although none of their
+  /// exports are used, since the side effects are often unintended, such as registering components.
+  #[tracing::instrument(level = "debug", skip_all)]
+  pub(super) fn check_barrel_side_effects(&mut self) {
+    if !self.options.checks.barrel_side_effects.unwrap_or(false)
+      || !self.options.treeshake.enabled()
+    {
+      return;
+    }
+
+    let modules = &self.module_table.modules;
+    let is_included =
+      |idx: ModuleIdx| modules[idx].as_normal().is_some_and(|module| module.meta.is_included());
+    // The modules imported by anything other than `export * from`, whose inclusion is intended.
+    let mut imported = FxHashSet::default();
+    let mut barrels_by_re_exported: FxHashMap<ModuleIdx, Vec<&NormalModule>> = FxHashMap::default();
+    for importer in modules.iter().filter_map(Module::as_normal) {
+      if !importer.meta.is_included() {
+        continue;
+      }
+      for rec in &importer.import_records {
+        if matches!(rec.kind, ImportKind::Import)
+          && rec.meta.contains(ImportRecordMeta::IS_EXPORT_START)
+        {
+          if is_included(rec.resolved_module) {
+            barrels_by_re_exported.entry(rec.resolved_module).or_default().push(importer);
+          }
+        } else {
+          imported.insert(rec.resolved_module);
+        }
+      }
+    }
+
+    let mut re_exported = barrels_by_re_exported.into_iter().collect::<Vec<_>>();
+    re_exported.sort_unstable_by_key(|(idx, _)| modules[*idx].exec_order());
+    for (re_exported_idx, barrels) in re_exported {
+      let Module::Normal(module) = &modules[re_exported_idx] else {
+        continue;
+      };
+      if imported.contains(&re_exported_idx)
+        || self.entries.iter().any(|entry| entry.id == re_exported_idx)
+        || !module.side_effects.has_side_effects()
+        || self.is_any_export_used(module)
+      {
+        continue;
+      }
+      for barrel in barrels {
+        let Some((span, _)) = barrel.imports.iter().find(|(_, rec_idx)| {
+          let rec = &barrel.import_records[**rec_idx];
+          rec.resolved_module == re_exported_idx
+            && rec.meta.contains(ImportRecordMeta::IS_EXPORT_START)
+        }) else {
+          continue;
+        };
+        self.warnings.push(
+          BuildDiagnostic::barrel_side_effects(
+            barrel.stable_id.to_string(),
+            barrel.source.clone(),
+            module.stable_id.to_string(),
+            *span,
+          )
+          .with_severity_warning(),
+        );
+      }
+    }
+  }
+
+  fn is_any_export_used(&self, module: &NormalModule) -> bool {
+    // The namespace object is included if any module uses all of its exports.
+    module.stmt_infos[StmtInfoIdx::new(0)].is_included
+      || module.named_exports.values().any(|local_export| {
+        self.used_symbol_refs.contains(&self.symbols.canonical_ref_for(local_export.referenced))
+      })
+  }
+}
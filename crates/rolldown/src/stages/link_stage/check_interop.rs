@@ -0,0 +1,88 @@
+use rolldown_common::{EcmaModuleAstUsage, ExportsKind, ImportKind, Module, Specifier};
+use rolldown_error::BuildDiagnostic;
+
+use super::LinkStage;
+
+impl<'a> LinkStage<'a> {
+  /// Warns about imports whose meaning depends on how CommonJS and ES modules interop. They are only
+  /// reported if enabled in `checks`, since such imports are common in code that works as intended.
+  #[tracing::instrument(level = "debug", skip_all)]
+  pub(super) fn check_interop(&mut self) {
+    let checks = &self.options.checks;
+    let check_default_import = checks.commonjs_default_import.unwrap_or(false);
+    let check_named_import = checks.commonjs_named_import.unwrap_or(false);
+    let check_require_esm = checks.require_esm.unwrap_or(false);
+    if !(check_default_import || check_named_import || check_require_esm) {
+      return;
+    }
+
+    for importer in self.module_table.modules.iter().filter_map(Module::as_normal) {
+      if check_default_import || check_named_import {
+        for named_import in importer.named_imports.values() {
+          let rec = &importer.import_records[named_import.record_id];
+          let Module::Normal(importee) = &self.module_table.modules[rec.resolved_module] else {
+            continue;
+          };
+          if !matches!(importee.exports_kind, ExportsKind::CommonJs) {
+            continue;
+          }
+          match &named_import.imported {
+            Specifier::Literal(imported) if imported.as_str() == "default" => {
+              if check_default_import
+                && !importee.ast_usage.contains(EcmaModuleAstUsage::EsModuleMarker)
+              {
+                self.warnings.push(
+                  BuildDiagnostic::commonjs_default_import(
+                    importer.stable_id.to_string(),
+                    importer.source.clone(),
+                    importee.stable_id.to_string(),
+                    named_import.span_imported,
+                  )
+                  .with_severity_warning(),
+                );
+              }
+            }
+            Specifier::Literal(imported) => {
+              if check_named_import {
+                self.warnings.push(
+                  BuildDiagnostic::commonjs_named_import(
+                    importer.stable_id.to_string(),
+                    importer.source.clone(),
+                    importee.stable_id.to_string(),
+                    imported.to_string(),
+                    named_import.span_imported,
+                  )
+                  .with_severity_warning(),
+                );
+              }
+            }
+            Specifier::Star => {}
+          }
+        }
+      }
+
+      if check_require_esm {
+        for (span, rec_idx) in &importer.imports {
+          let rec = &importer.import_records[*rec_idx];
+          if !matches!(rec.kind, ImportKind::Require) {
+            continue;
+          }
+          let Module::Normal(importee) = &self.module_table.modules[rec.resolved_module] else {
+            continue;
+          };
+          if matches!(importee.exports_kind, ExportsKind::Esm) {
+            self.warnings.push(
+              BuildDiagnostic::require_esm(
+                importer.stable_id.to_string(),
+                importer.source.clone(),
+                importee.stable_id.to_string(),
+                *span,
+              )
+              .with_severity_warning(),
+            );
+          }
+        }
+      }
+    }
+  }
+}
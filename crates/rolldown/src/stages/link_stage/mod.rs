@@ -3,7 +3,7 @@ use std::{ptr::addr_of, sync::Mutex};
 use oxc::index::IndexVec;
 use rolldown_common::{
   EntryPoint, ExportsKind, ImportKind, ImportRecordMeta, Module, ModuleIdx, ModuleTable,
-  OutputFormat, StmtInfo, SymbolRef, SymbolRefDb, WrapKind,
+  OutputFormat, StmtInfo, StmtInfoMeta, SymbolRef, SymbolRefDb, WrapKind,
 };
 use rolldown_error::BuildDiagnostic;
 use rolldown_utils::{
@@ -16,6 +16,7 @@ use crate::{
   runtime::RuntimeModuleBrief,
   type_alias::IndexEcmaAst,
   types::linking_metadata::{LinkingMetadata, LinkingMetadataVec},
+  utils::log_level::is_severity_enabled,
   SharedOptions,
 };
 
@@ -24,7 +25,10 @@ use self::wrapping::create_wrapper;
 use super::scan_stage::ScanStageOutput;
 
 mod bind_imports_and_exports;
+mod check_barrel_side_effects;
+mod check_interop;
 mod generate_lazy_export;
+mod propagate_constants;
 mod sort_modules;
 pub(crate) mod tree_shaking;
 mod wrapping;
@@ -70,12 +74,11 @@ impl<'a> LinkStage<'a> {
           dependencies: module
             .import_records()
             .iter()
-            .filter_map(|rec| {
-              if options.inline_dynamic_imports || !matches!(rec.kind, ImportKind::DynamicImport) {
-                Some(rec.resolved_module)
-              } else {
-                None
-              }
+            .filter_map(|rec| match rec.kind {
+              ImportKind::DynamicImport if !options.inline_dynamic_imports => None,
+              // Workers are never executed by their importers.
+              ImportKind::NewUrl => None,
+              _ => Some(rec.resolved_module),
             })
             .collect(),
           star_exports_from_external_modules: module.as_normal().map_or(vec![], |inner| {
@@ -103,15 +106,22 @@ impl<'a> LinkStage<'a> {
     self.sort_modules();
 
     self.determine_module_exports_kind();
+    self.check_interop();
     self.wrap_modules();
     self.generate_lazy_export();
     self.bind_imports_and_exports();
+    self.propagate_constants();
     self.create_exports_for_ecma_modules();
     self.reference_needed_symbols();
     self.include_statements();
+    self.check_barrel_side_effects();
+    self.remove_unused_css_module_rules();
     self.patch_module_dependencies();
     tracing::trace!("meta {:#?}", self.metas.iter_enumerated().collect::<Vec<_>>());
 
+    let log_level = self.options.log_level;
+    self.warnings.retain(|warning| is_severity_enabled(log_level, warning.severity()));
+
     LinkStageOutput {
       module_table: self.module_table,
       entries: self.entries,
@@ -225,12 +235,13 @@ impl<'a> LinkStage<'a> {
           ImportKind::AtImport => {
             unreachable!("A Js module would never import a CSS module via `@import`");
           }
+          ImportKind::NewUrl => {}
         }
       });
 
       let is_entry = entry_ids_set.contains(&importer.idx);
       if matches!(importer.exports_kind, ExportsKind::CommonJs)
-        && (!is_entry || matches!(self.options.format, OutputFormat::Esm))
+        && (!is_entry || matches!(self.options.format, OutputFormat::Esm | OutputFormat::System))
       {
         self.metas[importer.idx].wrap_kind = WrapKind::Cjs;
       }
@@ -275,6 +286,17 @@ impl<'a> LinkStage<'a> {
       // store the symbol reference to the declared statement index
       let mut declared_symbol_for_stmt_pairs = vec![];
       stmt_infos.infos.iter_mut_enumerated().for_each(|(stmt_idx, stmt_info)| {
+        if self.options.keep_names
+          && stmt_info.meta.contains(StmtInfoMeta::HAS_NAMED_FN_OR_CLASS)
+          && importer.idx != self.runtime.id()
+        {
+          stmt_info.referenced_symbols.push(self.runtime.resolve_symbol("__name").into());
+        }
+        if stmt_info.meta.contains(StmtInfoMeta::HAS_DYNAMIC_REQUIRE)
+          && !self.options.commonjs.ignore_dynamic_requires()
+        {
+          stmt_info.referenced_symbols.push(self.runtime.resolve_symbol("__dynamicRequire").into());
+        }
         stmt_info.import_records.iter().for_each(|rec_id| {
           let rec = &importer.import_records[*rec_id];
           match &self.module_table.modules[rec.resolved_module] {
@@ -291,14 +313,26 @@ impl<'a> LinkStage<'a> {
                     );
                   } else {
                     // import ... from 'external' or export ... from 'external'
-                    let cjs_format = matches!(self.options.format, OutputFormat::Cjs);
-                    if cjs_format && !rec.meta.contains(ImportRecordMeta::IS_PLAIN_IMPORT) {
+                    if importee.interop.needs_to_esm(&self.options.format)
+                      && !rec.meta.contains(ImportRecordMeta::IS_PLAIN_IMPORT)
+                    {
                       stmt_info
                         .referenced_symbols
                         .push(self.runtime.resolve_symbol("__toESM").into());
                     }
                   }
                 }
+                ImportKind::DynamicImport => {
+                  // `import('external')` is turned into `require('external')` without `output.dynamicImportInCjs`.
+                  if matches!(self.options.format, OutputFormat::Cjs)
+                    && !self.options.dynamic_import_in_cjs
+                    && importee.interop.needs_to_esm(&self.options.format)
+                  {
+                    stmt_info
+                      .referenced_symbols
+                      .push(self.runtime.resolve_symbol("__toESM").into());
+                  }
+                }
                 _ => {}
               }
             }
@@ -432,6 +466,7 @@ impl<'a> LinkStage<'a> {
                 ImportKind::AtImport => {
                   unreachable!("A Js module would never import a CSS module via `@import`");
                 }
+                ImportKind::NewUrl => {}
               }
             }
           }
@@ -461,6 +496,7 @@ impl<'a> LinkStage<'a> {
             declared_symbols: vec![*symbol_ref],
             referenced_symbols: vec![],
             side_effect: false,
+            meta: StmtInfoMeta::empty(),
             is_included: false,
             import_records: Vec::new(),
             debug_label: None,
@@ -486,7 +522,7 @@ impl<'a> LinkStage<'a> {
           if !meta.star_exports_from_external_modules.is_empty() {
             referenced_symbols.push(self.runtime.resolve_symbol("__reExport").into());
             match self.options.format {
-              OutputFormat::Esm => {
+              OutputFormat::Esm | OutputFormat::System => {
                 meta.star_exports_from_external_modules.iter().copied().for_each(|rec_idx| {
                   referenced_symbols.push(ecma_module.import_records[rec_idx].namespace_ref.into());
                   declared_symbols.push(ecma_module.import_records[rec_idx].namespace_ref);
@@ -503,6 +539,7 @@ impl<'a> LinkStage<'a> {
             declared_symbols,
             referenced_symbols,
             side_effect: false,
+            meta: StmtInfoMeta::empty(),
             is_included: false,
             import_records: Vec::new(),
             debug_label: None,
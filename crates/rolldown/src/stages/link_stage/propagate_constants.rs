@@ -0,0 +1,272 @@
+use oxc::{
+  allocator,
+  ast::{
+    ast::{
+      ArrowFunctionExpression, Expression, Function, IdentifierReference, Statement,
+      VariableDeclaration, VariableDeclarationKind,
+    },
+    visit::{walk, walk_mut},
+    AstBuilder, Visit, VisitMut,
+  },
+  semantic::{ScopeFlags, SymbolId},
+  span::SPAN,
+  syntax::operator::{BinaryOperator, UnaryOperator},
+};
+use rolldown_common::{AstScopes, ConstantValue, Module, ModuleIdx, ModuleTable, SymbolRefDb};
+use rolldown_utils::rayon::{IntoParallelRefMutIterator, ParallelIterator};
+use rustc_hash::FxHashSet;
+
+use crate::{
+  ast_scanner::side_effect_detector::SideEffectDetector,
+  utils::collect_constants::evaluate_constant,
+};
+
+use super::LinkStage;
+
+/// A top-level statement whose branches were removed.
+struct FoldedStmt {
+  stmt_idx: usize,
+  /// The symbols that were only referenced by the removed branches.
+  unreferenced_symbols: Vec<SymbolId>,
+  side_effect: bool,
+}
+
+impl<'a> LinkStage<'a> {
+  /// Removes the branches of `if` statements and conditional expressions whose conditions are decided
+  /// by constants, such as `if (IS_LEGACY)` with `export const IS_LEGACY = false` in another module.
+  /// The symbols only referenced by the removed branches are no longer referenced by their statements,
+  /// so tree shaking can drop them.
+  pub fn propagate_constants(&mut self) {
+    if !self.options.treeshake.enabled() {
+      return;
+    }
+    let has_constants = self
+      .module_table
+      .modules
+      .iter()
+      .filter_map(Module::as_normal)
+      .any(|module| !module.constants.is_empty());
+    if !has_constants {
+      return;
+    }
+
+    let module_table = &self.module_table;
+    let symbols = &self.symbols;
+    let options = self.options;
+    let folded_stmts = self
+      .ast_table
+      .par_iter_mut()
+      .filter_map(|(ast, module_idx)| {
+        let Module::Normal(module) = &module_table.modules[*module_idx] else {
+          return None;
+        };
+        // The statement of a lazy export is rewritten by the link stage.
+        if module.meta.has_lazy_export() {
+          return None;
+        }
+        let folded_stmts = ast.program.with_mut(|fields| {
+          let mut folder = ConstantFolder {
+            module_idx: *module_idx,
+            scope: &module.scope,
+            modules: module_table,
+            symbols,
+            builder: AstBuilder::new(fields.allocator),
+            removed: SymbolCollector::new(&module.scope),
+          };
+          let no_side_effects_functions = FxHashSet::default();
+          let mut folded_stmts = vec![];
+          for (stmt_idx, stmt) in fields.program.body.iter_mut().enumerate() {
+            folder.visit_statement(stmt);
+            if folder.removed.symbols.is_empty() {
+              continue;
+            }
+            let removed = std::mem::take(&mut folder.removed.symbols);
+            let mut remaining = SymbolCollector::new(&module.scope);
+            remaining.visit_statement(stmt);
+            let unreferenced_symbols =
+              removed.into_iter().filter(|symbol| !remaining.symbols.contains(symbol)).collect();
+            let side_effect = SideEffectDetector::new(
+              &module.scope,
+              fields.source,
+              &fields.program.comments,
+              options.treeshake.manual_pure_functions(),
+              options.treeshake.property_read_side_effects(),
+              options.treeshake.annotations(),
+              &no_side_effects_functions,
+              options.treeshake.unknown_global_side_effects(),
+            )
+            .detect_side_effect_of_stmt(stmt);
+            folded_stmts.push(FoldedStmt { stmt_idx, unreferenced_symbols, side_effect });
+          }
+          folded_stmts
+        });
+        (!folded_stmts.is_empty()).then_some((*module_idx, folded_stmts))
+      })
+      .collect::<Vec<_>>();
+
+    for (module_idx, folded_stmts) in folded_stmts {
+      let Module::Normal(module) = &mut self.module_table.modules[module_idx] else {
+        continue;
+      };
+      for folded in folded_stmts {
+        let Some(stmt_info) =
+          module.stmt_infos.infos.iter_mut().find(|info| info.stmt_idx == Some(folded.stmt_idx))
+        else {
+          continue;
+        };
+        stmt_info.referenced_symbols.retain(|reference| {
+          let symbol_ref = reference.symbol_ref();
+          symbol_ref.owner != module_idx
+            || !folded.unreferenced_symbols.contains(&symbol_ref.symbol)
+        });
+        // Functions annotated with `/*#__NO_SIDE_EFFECTS__*/` aren't known here, so the detector might
+        // consider the remaining code to have side effects while the scanner didn't.
+        stmt_info.side_effect &= folded.side_effect;
+      }
+    }
+  }
+}
+
+struct ConstantFolder<'me, 'ast> {
+  module_idx: ModuleIdx,
+  scope: &'me AstScopes,
+  modules: &'me ModuleTable,
+  symbols: &'me SymbolRefDb,
+  builder: AstBuilder<'ast>,
+  /// The symbols referenced by the removed branches.
+  removed: SymbolCollector<'me>,
+}
+
+impl<'me, 'ast> ConstantFolder<'me, 'ast> {
+  fn constant_of(&self, ident: &IdentifierReference) -> Option<&'me ConstantValue> {
+    let symbol_id = self.scope.symbol_id_for(ident.reference_id.get()?)?;
+    let canonical_ref = self.symbols.canonical_ref_for((self.module_idx, symbol_id).into());
+    let Module::Normal(owner) = &self.modules.modules[canonical_ref.owner] else {
+      return None;
+    };
+    owner.constants.get(&canonical_ref.symbol)
+  }
+
+  /// Whether `expr` is truthy, if it's decided by constants.
+  fn truthiness_of(&self, expr: &Expression) -> Option<bool> {
+    match expr {
+      Expression::Identifier(ident) => self.constant_of(ident).map(ConstantValue::is_truthy),
+      Expression::ParenthesizedExpression(paren) => self.truthiness_of(&paren.expression),
+      Expression::UnaryExpression(unary) if unary.operator == UnaryOperator::LogicalNot => {
+        self.truthiness_of(&unary.argument).map(|truthy| !truthy)
+      }
+      Expression::BinaryExpression(binary) => {
+        let is_equality = match binary.operator {
+          BinaryOperator::StrictEquality => true,
+          BinaryOperator::StrictInequality => false,
+          _ => return None,
+        };
+        let ((Expression::Identifier(ident), literal) | (literal, Expression::Identifier(ident))) =
+          (&binary.left, &binary.right)
+        else {
+          return None;
+        };
+        let value = self.constant_of(ident)?;
+        let literal = evaluate_constant(literal)?;
+        Some((*value == literal) == is_equality)
+      }
+      _ => None,
+    }
+  }
+}
+
+impl<'me, 'ast> VisitMut<'ast> for ConstantFolder<'me, 'ast> {
+  fn visit_statements(&mut self, it: &mut allocator::Vec<'ast, Statement<'ast>>) {
+    walk_mut::walk_statements(self, it);
+    // Drop the `;`s left by the removed `if` statements.
+    it.retain(|stmt| !matches!(stmt, Statement::EmptyStatement(empty) if empty.span == SPAN));
+  }
+
+  fn visit_statement(&mut self, it: &mut Statement<'ast>) {
+    if let Statement::IfStatement(if_stmt) = it {
+      if let Some(truthy) = self.truthiness_of(&if_stmt.test) {
+        let removed_branch =
+          if truthy { if_stmt.alternate.as_ref() } else { Some(&if_stmt.consequent) };
+        // `var`s are hoisted out of the branch, so the branch can't be removed.
+        if !removed_branch.is_some_and(has_var_declaration) {
+          self.removed.visit_expression(&if_stmt.test);
+          if let Some(removed_branch) = removed_branch {
+            self.removed.visit_statement(removed_branch);
+          }
+          let kept_branch = if truthy {
+            Some(std::mem::replace(&mut if_stmt.consequent, self.builder.statement_empty(SPAN)))
+          } else {
+            if_stmt.alternate.take()
+          };
+          *it = kept_branch.unwrap_or_else(|| self.builder.statement_empty(SPAN));
+          self.visit_statement(it);
+          return;
+        }
+      }
+    }
+    walk_mut::walk_statement(self, it);
+  }
+
+  fn visit_expression(&mut self, it: &mut Expression<'ast>) {
+    if let Expression::ConditionalExpression(cond_expr) = it {
+      if let Some(truthy) = self.truthiness_of(&cond_expr.test) {
+        let cond_expr = &mut **cond_expr;
+        self.removed.visit_expression(&cond_expr.test);
+        let (kept, removed) = if truthy {
+          (&mut cond_expr.consequent, &cond_expr.alternate)
+        } else {
+          (&mut cond_expr.alternate, &cond_expr.consequent)
+        };
+        self.removed.visit_expression(removed);
+        *it = std::mem::replace(kept, self.builder.expression_null_literal(SPAN));
+        self.visit_expression(it);
+        return;
+      }
+    }
+    walk_mut::walk_expression(self, it);
+  }
+}
+
+/// Collects the symbols of the module referenced by the visited code.
+struct SymbolCollector<'me> {
+  scope: &'me AstScopes,
+  symbols: FxHashSet<SymbolId>,
+}
+
+impl<'me> SymbolCollector<'me> {
+  fn new(scope: &'me AstScopes) -> Self {
+    Self { scope, symbols: FxHashSet::default() }
+  }
+}
+
+impl<'me, 'ast> Visit<'ast> for SymbolCollector<'me> {
+  fn visit_identifier_reference(&mut self, it: &IdentifierReference<'ast>) {
+    if let Some(symbol_id) = it.reference_id.get().and_then(|id| self.scope.symbol_id_for(id)) {
+      self.symbols.insert(symbol_id);
+    }
+  }
+}
+
+fn has_var_declaration(stmt: &Statement) -> bool {
+  let mut finder = VarDeclarationFinder { found: false };
+  finder.visit_statement(stmt);
+  finder.found
+}
+
+struct VarDeclarationFinder {
+  found: bool,
+}
+
+impl<'ast> Visit<'ast> for VarDeclarationFinder {
+  fn visit_variable_declaration(&mut self, it: &VariableDeclaration<'ast>) {
+    if it.kind == VariableDeclarationKind::Var {
+      self.found = true;
+    }
+    walk::walk_variable_declaration(self, it);
+  }
+
+  // `var`s of functions stay in the functions.
+  fn visit_function(&mut self, _it: &Function<'ast>, _flags: ScopeFlags) {}
+
+  fn visit_arrow_function_expression(&mut self, _it: &ArrowFunctionExpression<'ast>) {}
+}
@@ -1,7 +1,11 @@
 use std::iter;
 
-use rolldown_common::{Module, ModuleIdx};
+use rolldown_common::{Module, ModuleIdx, NormalModule};
 use rolldown_error::BuildDiagnostic;
+use rolldown_utils::{
+  indexmap::FxIndexSet,
+  pattern_filter::{self, FilterResult, StringOrRegex},
+};
 use rustc_hash::{FxHashMap, FxHashSet};
 
 use super::LinkStage;
@@ -45,7 +49,7 @@ impl<'a> LinkStage<'a> {
 
     let mut sorted_modules = Vec::with_capacity(self.module_table.modules.len());
     let mut next_exec_order = 0;
-    let mut circular_dependencies = FxHashSet::default();
+    let mut circular_dependencies = FxIndexSet::default();
     while let Some(status) = execution_stack.pop() {
       match status {
         Status::ToBeExecuted(id) => {
@@ -107,17 +111,30 @@ impl<'a> LinkStage<'a> {
       }
     }
 
-    if self.options.checks.circular_dependency.unwrap_or(true) && !circular_dependencies.is_empty()
-    {
-      let cycles = circular_dependencies.into_iter().collect::<Vec<_>>();
-      for cycle in cycles {
-        let paths = cycle
-          .iter()
-          .copied()
-          .filter_map(|id| self.module_table.modules[id].as_normal())
-          .map(|module| module.id.to_string())
-          .collect::<Vec<_>>();
-        self.warnings.push(BuildDiagnostic::circular_dependency(paths).with_severity_warning());
+    let checks = &self.options.checks;
+    for cycle in circular_dependencies {
+      let modules = cycle
+        .iter()
+        .filter_map(|id| self.module_table.modules[*id].as_normal())
+        .collect::<Vec<_>>();
+      if checks
+        .circular_dependency_ignore
+        .as_deref()
+        .is_some_and(|patterns| modules.iter().any(|module| matches_any(patterns, module)))
+      {
+        continue;
+      }
+      let is_error = checks
+        .circular_dependency_error
+        .as_deref()
+        .is_some_and(|patterns| modules.iter().all(|module| matches_any(patterns, module)));
+      let diagnostic = BuildDiagnostic::circular_dependency(
+        modules.iter().map(|module| module.id.to_string()).collect(),
+      );
+      if is_error {
+        self.errors.push(diagnostic);
+      } else {
+        self.warnings.push(diagnostic.with_severity_warning());
       }
     }
 
@@ -129,3 +146,10 @@ impl<'a> LinkStage<'a> {
     );
   }
 }
+
+fn matches_any(patterns: &[StringOrRegex], module: &NormalModule) -> bool {
+  matches!(
+    pattern_filter::filter(None, Some(patterns), &module.id, &module.stable_id),
+    FilterResult::Match(true)
+  )
+}
@@ -2,9 +2,10 @@ use crate::types::linking_metadata::LinkingMetadataVec;
 use oxc::index::IndexVec;
 use rolldown_common::side_effects::DeterminedSideEffects;
 use rolldown_common::{
-  IndexModules, Module, ModuleIdx, ModuleType, NormalModule, StmtInfoIdx, SymbolOrMemberExprRef,
-  SymbolRef, SymbolRefDb,
+  CssUnusedRulesRemover, IndexModules, Module, ModuleIdx, ModuleType, NormalModule, StmtInfoIdx,
+  SymbolOrMemberExprRef, SymbolRef, SymbolRefDb,
 };
+use rolldown_rstr::Rstr;
 use rolldown_utils::rayon::{IntoParallelRefMutIterator, ParallelIterator};
 use rustc_hash::FxHashSet;
 
@@ -189,6 +190,55 @@ impl LinkStage<'_> {
     );
   }
 
+  /// Removes the rules of css modules that only match classes, which are never used by js. A class is
+  /// used if the statement declaring its export is included.
+  pub fn remove_unused_css_module_rules(&mut self) {
+    if !self.options.treeshake.enabled() {
+      return;
+    }
+    self.module_table.modules.par_iter_mut().filter_map(Module::as_normal_mut).for_each(|module| {
+      let Some(local_classes) =
+        module.css_view.as_ref().and_then(|view| view.local_classes.as_ref())
+      else {
+        return;
+      };
+      let used_classes = local_classes
+        .names
+        .iter()
+        .filter(|(name, _)| {
+          module.named_exports.get(&Rstr::new(name)).is_some_and(|local_export| {
+            module
+              .stmt_infos
+              .declared_stmts_by_symbol(&local_export.referenced)
+              .iter()
+              .any(|stmt_info_idx| module.stmt_infos.get(*stmt_info_idx).is_included)
+          })
+        })
+        .map(|(_, scoped)| scoped.as_str())
+        .collect::<FxHashSet<_>>();
+      let ranges = local_classes
+        .rules
+        .iter()
+        .filter(|rule| {
+          !rule.selectors.is_empty()
+            && rule
+              .selectors
+              .iter()
+              .all(|classes| classes.iter().any(|class| !used_classes.contains(class.as_str())))
+        })
+        .map(|rule| rule.range)
+        .collect::<Vec<_>>();
+      if !ranges.is_empty() {
+        module
+          .css_view
+          .as_mut()
+          .unwrap()
+          .mutations
+          .push(Box::new(CssUnusedRulesRemover { ranges }));
+      }
+    });
+  }
+
   fn determine_side_effects(&mut self) {
     #[derive(Debug, Clone, Copy)]
     enum SideEffectCache {
@@ -1,7 +1,7 @@
 use oxc::index::IndexVec;
 use rolldown_common::{
   ExportsKind, IndexModules, Module, ModuleIdx, NormalModule, NormalizedBundlerOptions, StmtInfo,
-  SymbolRefDb, WrapKind,
+  StmtInfoMeta, SymbolRefDb, WrapKind,
 };
 
 use crate::{
@@ -160,6 +160,7 @@ pub fn create_wrapper(
           runtime.resolve_symbol("__commonJSMin").into()
         }],
         side_effect: false,
+        meta: StmtInfoMeta::empty(),
         is_included: false,
         import_records: Vec::new(),
         debug_label: None,
@@ -189,6 +190,7 @@ pub fn create_wrapper(
           runtime.resolve_symbol("__esmMin").into()
         }],
         side_effect: false,
+        meta: StmtInfoMeta::empty(),
         is_included: false,
         import_records: Vec::new(),
         debug_label: None,
@@ -3,16 +3,22 @@ use std::sync::Arc;
 use anyhow::Result;
 use arcstr::ArcStr;
 use futures::future::join_all;
-use rolldown_common::{EntryPoint, ImportKind, ModuleTable, ResolvedId, SymbolRefDb};
+use rolldown_common::{
+  EntryPoint, ImportKind, InteropMode, Module, ModuleTable, ResolvedId, SymbolRefDb,
+};
 use rolldown_error::{BuildDiagnostic, BuildResult};
 use rolldown_fs::OsFileSystem;
 use rolldown_plugin::SharedPluginDriver;
 use rolldown_resolver::ResolveError;
 
 use crate::{
-  module_loader::{module_loader::ModuleLoaderOutput, ModuleLoader},
+  module_loader::{
+    module_loader::{ModuleLoaderOutput, ResolvedEmittedChunk},
+    ModuleLoader,
+  },
   runtime::RuntimeModuleBrief,
   type_alias::IndexEcmaAst,
+  types::build_stats::ModuleRequestStats,
   utils::resolve_id::resolve_id,
   SharedOptions, SharedResolver,
 };
@@ -33,6 +39,8 @@ pub struct ScanStageOutput {
   pub runtime: RuntimeModuleBrief,
   pub warnings: Vec<BuildDiagnostic>,
   pub errors: Vec<BuildDiagnostic>,
+  pub module_requests: ModuleRequestStats,
+  pub bytes_read: u64,
 }
 
 impl ScanStage {
@@ -65,20 +73,31 @@ impl ScanStage {
       }
     };
 
+    let emitted_chunks = match self.resolve_emitted_chunks().await? {
+      Ok(chunks) => chunks,
+      Err(errors) => {
+        return Ok(Err(errors));
+      }
+    };
+
     let ModuleLoaderOutput {
-      module_table,
+      mut module_table,
       entry_points,
       symbol_ref_db,
       runtime,
       warnings,
       index_ecma_ast,
-    } = match module_loader.fetch_all_modules(user_entries).await? {
+      module_requests,
+      bytes_read,
+    } = match module_loader.fetch_all_modules(user_entries, emitted_chunks).await? {
       Ok(output) => output,
       Err(errors) => {
         return Ok(Err(errors));
       }
     };
 
+    self.resolve_external_interop(&mut module_table).await?;
+
     Ok(Ok(ScanStageOutput {
       module_table,
       entry_points,
@@ -87,9 +106,27 @@ impl ScanStage {
       warnings,
       index_ecma_ast,
       errors: vec![],
+      module_requests,
+      bytes_read,
     }))
   }
 
+  /// Resolve `OutputOptions.interop` for each external module, since it could be an async function.
+  /// `commonjs.esmExternals` takes precedence over it.
+  async fn resolve_external_interop(&self, module_table: &mut ModuleTable) -> Result<()> {
+    let esm_externals = self.options.commonjs.esm_externals();
+    for module in &mut module_table.modules {
+      if let Module::External(external_module) = module {
+        external_module.interop = if esm_externals {
+          InteropMode::EsModule
+        } else {
+          self.options.interop.call(&external_module.name).await?
+        };
+      }
+    }
+    Ok(())
+  }
+
   /// Resolve `InputOptions.input`
 
   #[tracing::instrument(level = "debug", skip_all)]
@@ -115,6 +152,7 @@ impl ScanStage {
         None,
         Arc::default(),
         true,
+        None,
       )
       .await;
 
@@ -158,4 +196,74 @@ impl ScanStage {
 
     Ok(Ok(ret))
   }
+
+  /// Resolve chunks emitted by plugins in `buildStart` hook
+  #[tracing::instrument(level = "debug", skip_all)]
+  async fn resolve_emitted_chunks(&self) -> Result<BuildResult<Vec<ResolvedEmittedChunk>>> {
+    let mut ret = vec![];
+    let mut errors = vec![];
+
+    for (reference_id, chunk) in self.plugin_driver.file_emitter().seal_emitted_chunks() {
+      let resolved_id =
+        match self.resolve_emitted_chunk_id(&chunk.id, chunk.importer.as_deref()).await? {
+          Ok(resolved_id) => resolved_id,
+          Err(ResolveError::NotFound(..)) => {
+            errors.push(BuildDiagnostic::unresolved_entry(&chunk.id, None));
+            continue;
+          }
+          Err(e @ ResolveError::PackagePathNotExported(..)) => {
+            errors.push(BuildDiagnostic::unresolved_entry(&chunk.id, Some(e)));
+            continue;
+          }
+          Err(e) => return Err(e.into()),
+        };
+      if resolved_id.is_external {
+        errors.push(BuildDiagnostic::entry_cannot_be_external(resolved_id.id.to_string()));
+        continue;
+      }
+
+      let mut implicitly_loaded_after_one_of =
+        Vec::with_capacity(chunk.implicitly_loaded_after_one_of.len());
+      for id in &chunk.implicitly_loaded_after_one_of {
+        // Unresolvable ids can't refer to any entry, so they are simply ignored.
+        if let Ok(resolved_id) = self.resolve_emitted_chunk_id(id, None).await? {
+          implicitly_loaded_after_one_of.push(resolved_id.id);
+        }
+      }
+
+      ret.push(ResolvedEmittedChunk {
+        reference_id,
+        name: chunk.name.clone(),
+        file_name: chunk.file_name.clone(),
+        resolved_id,
+        implicitly_loaded_after_one_of,
+      });
+    }
+
+    if !errors.is_empty() {
+      return Ok(Err(errors.into()));
+    }
+
+    Ok(Ok(ret))
+  }
+
+  async fn resolve_emitted_chunk_id(
+    &self,
+    specifier: &str,
+    importer: Option<&str>,
+  ) -> Result<Result<ResolvedId, ResolveError>> {
+    resolve_id(
+      &self.resolver,
+      &self.plugin_driver,
+      specifier,
+      importer,
+      true,
+      ImportKind::Import,
+      None,
+      Arc::default(),
+      true,
+      None,
+    )
+    .await
+  }
 }
@@ -0,0 +1,86 @@
+use std::time::Duration;
+
+use rolldown_common::{ExportsKind, Module, ModuleTable, ModuleType};
+
+/// Structured statistics of a build, so tools could trend the performance without parsing logs.
+///
+/// The module requests are the only cache of a build, so only the scan stage reports cache hits and
+/// misses. The link and generate stages don't cache anything and only report their timing.
+#[derive(Debug, Default, Clone)]
+pub struct BuildStats {
+  pub scan: ScanStats,
+  pub link: StageStats,
+  pub generate: StageStats,
+  pub modules: ModuleStats,
+  /// Bytes of the sources returned by the `load` hook or read from the file system.
+  pub bytes_read: u64,
+  pub chunks_rendered: u32,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct StageStats {
+  pub duration: Duration,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ScanStats {
+  pub duration: Duration,
+  pub module_requests: ModuleRequestStats,
+}
+
+/// Modules imported by several importers are only loaded once, the other requests of them are deduplicated.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ModuleRequestStats {
+  /// Cache misses, the requests that loaded a module.
+  pub unique: u32,
+  /// Cache hits, the requests of modules that were already loaded.
+  pub deduplicated: u32,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ModuleStats {
+  pub esm: u32,
+  pub cjs: u32,
+  pub json: u32,
+  pub css: u32,
+  pub asset: u32,
+  pub wasm: u32,
+  pub empty: u32,
+  pub external: u32,
+}
+
+impl ModuleStats {
+  pub fn from_module_table(module_table: &ModuleTable) -> Self {
+    let mut stats = Self::default();
+    for module in &module_table.modules {
+      let Module::Normal(module) = module else {
+        stats.external += 1;
+        continue;
+      };
+      match module.module_type {
+        ModuleType::Json => stats.json += 1,
+        ModuleType::Css => stats.css += 1,
+        ModuleType::Text
+        | ModuleType::Base64
+        | ModuleType::Dataurl
+        | ModuleType::Binary
+        | ModuleType::File
+        | ModuleType::Copy => stats.asset += 1,
+        ModuleType::Wasm => stats.wasm += 1,
+        ModuleType::Empty => stats.empty += 1,
+        ModuleType::Js
+        | ModuleType::Jsx
+        | ModuleType::Ts
+        | ModuleType::Tsx
+        | ModuleType::Custom(_) => {
+          if matches!(module.ecma_view.exports_kind, ExportsKind::CommonJs) {
+            stats.cjs += 1;
+          } else {
+            stats.esm += 1;
+          }
+        }
+      }
+    }
+    stats
+  }
+}
@@ -15,6 +15,8 @@ pub struct CreateModuleContext<'a> {
   pub options: &'a SharedOptions,
   pub module_type: ModuleType,
   pub warnings: &'a mut Vec<BuildDiagnostic>,
+  /// Errors that don't stop loading the module, if the build doesn't bail.
+  pub errors: &'a mut Vec<BuildDiagnostic>,
   pub replace_global_define_config: Option<ReplaceGlobalDefinesConfig>,
 }
 
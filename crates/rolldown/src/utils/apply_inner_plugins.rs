@@ -7,4 +7,12 @@ use rolldown_plugin::__inner::SharedPluginable;
 /// we need to apply these plugins after user's plugins to control the final order of plugins.
 pub fn apply_inner_plugins(user_plugins: &mut Vec<SharedPluginable>) {
   user_plugins.push(Arc::new(rolldown_plugin_data_url::DataUrlPlugin::default()));
+  // `import(`./locales/${lang}.json`)` is rewritten to pick the module from an `import.meta.glob`
+  // call, so it has to be applied before the plugin expanding the calls.
+  user_plugins.push(Arc::new(rolldown_plugin_dynamic_import_vars::DynamicImportVarsPlugin {}));
+  // `import.meta.glob` is expanded like Vite does. It's a no-op if a user's `importGlobPlugin` has
+  // expanded the calls already.
+  user_plugins.push(Arc::new(rolldown_plugin_import_glob::ImportGlobPlugin {
+    config: rolldown_plugin_import_glob::ImportGlobPluginConfig::default(),
+  }));
 }
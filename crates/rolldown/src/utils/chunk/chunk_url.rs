@@ -0,0 +1,29 @@
+use rolldown_common::OutputFormat;
+
+/// The script of an iife or umd chunk, captured when the chunk is evaluated. `document.currentScript` is
+/// `null` once the script has run, so reading it in callbacks or async code would miss the chunk.
+pub const DOCUMENT_CURRENT_SCRIPT: &str = "_documentCurrentScript";
+
+/// The url of the chunk being rendered, in the way `format` can access it. Iife and umd chunks read the
+/// script captured by `render_document_current_script`.
+pub fn chunk_url_expr(format: &OutputFormat) -> &'static str {
+  match format {
+    OutputFormat::Esm | OutputFormat::App => "import.meta.url",
+    OutputFormat::System => "module.meta.url",
+    OutputFormat::Cjs => "require(\"url\").pathToFileURL(__filename).href",
+    OutputFormat::Iife => {
+      "(_documentCurrentScript && _documentCurrentScript.src || document.baseURI)"
+    }
+    // Umd chunks loaded by Node.js don't have a `document`.
+    OutputFormat::Umd => {
+      "(typeof document === \"undefined\" ? require(\"url\").pathToFileURL(__filename).href : _documentCurrentScript && _documentCurrentScript.src || document.baseURI)"
+    }
+  }
+}
+
+/// Renders the declaration of `DOCUMENT_CURRENT_SCRIPT`, which goes at the top of iife and umd chunks that use it.
+pub fn render_document_current_script() -> String {
+  format!(
+    "var {DOCUMENT_CURRENT_SCRIPT} = typeof document !== \"undefined\" ? document.currentScript : null;"
+  )
+}
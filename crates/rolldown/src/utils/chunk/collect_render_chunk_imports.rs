@@ -1,5 +1,5 @@
 use arcstr::ArcStr;
-use rolldown_common::{Chunk, Specifier, SymbolNameRefToken};
+use rolldown_common::{Chunk, ImportAttributes, InteropMode, Specifier, SymbolNameRefToken};
 
 use crate::{chunk_graph::ChunkGraph, stages::link_stage::LinkStageOutput};
 
@@ -18,6 +18,8 @@ pub struct ExternalRenderImportStmt {
   pub path: ArcStr,
   pub binding_name_token: SymbolNameRefToken, // for cjs __toESM(require('foo')) and iife get deconflict name
   pub specifiers: RenderImportDeclarationSpecifier,
+  pub interop: InteropMode,
+  pub attributes: Option<ImportAttributes>,
 }
 
 pub struct NormalRenderImportStmt {
@@ -45,8 +47,11 @@ impl RenderImportStmt {
     }
   }
 
-  pub fn is_external(&self) -> bool {
-    matches!(self, Self::ExternalRenderImportStmt(_))
+  pub fn attributes(&self) -> Option<&ImportAttributes> {
+    match self {
+      Self::ExternalRenderImportStmt(e) => e.attributes.as_ref(),
+      Self::NormalRenderImportStmt(_) => None,
+    }
   }
 }
 
@@ -108,11 +113,13 @@ pub fn collect_render_chunk_imports(
             has_importee_imported = true;
             render_import_stmts.push(RenderImportStmt::ExternalRenderImportStmt(
               ExternalRenderImportStmt {
-                path: importee.name.clone(),
+                path: importee.import_path_for(chunk),
                 binding_name_token: importee.name_token_for_external_binding.clone(),
                 specifiers: RenderImportDeclarationSpecifier::ImportStarSpecifier(
                   alias.as_str().into(),
                 ),
+                interop: importee.interop,
+                attributes: importee.attributes.clone(),
               },
             ));
             None
@@ -131,9 +138,11 @@ pub fn collect_render_chunk_imports(
     {
       render_import_stmts.push(RenderImportStmt::ExternalRenderImportStmt(
         ExternalRenderImportStmt {
-          path: importee.name.clone(),
+          path: importee.import_path_for(chunk),
           binding_name_token: importee.name_token_for_external_binding.clone(),
           specifiers: RenderImportDeclarationSpecifier::ImportSpecifier(specifiers),
+          interop: importee.interop,
+          attributes: importee.attributes.clone(),
         },
       ));
     }
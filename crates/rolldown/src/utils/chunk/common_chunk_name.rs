@@ -0,0 +1,74 @@
+use rolldown_common::{Chunk, ChunkNameStrategy, Module};
+use rolldown_utils::path_ext::PathExt;
+use sugar_path::SugarPath;
+
+use crate::stages::link_stage::LinkStageOutput;
+
+/// Generates the `[name]` of a common chunk, which has no entry module to be named after. It's not
+/// sanitized yet.
+/// - rollup use the first entered/last executed module as the `[name]` of common chunks.
+/// - esbuild always use 'chunk' as the `[name]`. However we try to make the name more meaningful here.
+pub fn generate_common_chunk_name(
+  chunk: &Chunk,
+  link_output: &LinkStageOutput,
+  strategy: ChunkNameStrategy,
+) -> String {
+  let modules = &link_output.module_table.modules;
+  // Modules of the chunk are sorted by execution order, so the first entered module comes last.
+  let non_runtime_modules = || {
+    chunk
+      .modules
+      .iter()
+      .rev()
+      .filter(|each| **each != link_output.runtime.id())
+      .map(|each| &modules[*each])
+  };
+
+  let named_module = match strategy {
+    ChunkNameStrategy::FirstEntered => non_runtime_modules().next(),
+    ChunkNameStrategy::Dominant => find_dominant_module(non_runtime_modules()),
+    ChunkNameStrategy::Package => {
+      let mut package_names = non_runtime_modules().map(|module| package_name_of(module.id()));
+      if let Some(Some(first)) = package_names.next() {
+        if package_names.all(|name| name.as_deref() == Some(first.as_str())) {
+          return first.trim_start_matches('@').replace('/', "-");
+        }
+      }
+      find_dominant_module(non_runtime_modules())
+    }
+  };
+
+  named_module.map_or_else(
+    || "chunk".to_string(),
+    |module| module.id().as_path().representative_file_name().into_owned(),
+  )
+}
+
+/// The largest module. The first entered one wins if there are several of the same size.
+fn find_dominant_module<'a>(mut modules: impl Iterator<Item = &'a Module>) -> Option<&'a Module> {
+  let first = modules.next()?;
+  Some(modules.fold(
+    first,
+    |dominant, module| {
+      if module.size() > dominant.size() {
+        module
+      } else {
+        dominant
+      }
+    },
+  ))
+}
+
+/// The name of the package in `node_modules` that the module belongs to, such as `react-dom` or
+/// `@vue/shared`.
+fn package_name_of(id: &str) -> Option<String> {
+  let id = id.replace('\\', "/");
+  let (_, rest) = id.rsplit_once("/node_modules/")?;
+  let mut segments = rest.split('/');
+  let name = segments.next()?;
+  if name.starts_with('@') {
+    segments.next().map(|scoped| format!("{name}/{scoped}"))
+  } else {
+    Some(name.to_string())
+  }
+}
@@ -2,7 +2,7 @@ use std::borrow::Cow;
 
 use crate::{stages::link_stage::LinkStageOutput, utils::renamer::Renamer};
 use arcstr::ArcStr;
-use rolldown_common::{Chunk, ChunkIdx, ChunkKind, OutputFormat};
+use rolldown_common::{Chunk, ChunkIdx, ChunkKind, NormalizedBundlerOptions, OutputFormat};
 use rolldown_rstr::ToRstr;
 use rustc_hash::FxHashMap;
 
@@ -10,13 +10,17 @@ use rustc_hash::FxHashMap;
 pub fn deconflict_chunk_symbols(
   chunk: &mut Chunk,
   link_output: &LinkStageOutput,
-  format: &OutputFormat,
+  options: &NormalizedBundlerOptions,
   index_chunk_id_to_name: &FxHashMap<ChunkIdx, ArcStr>,
 ) {
+  let format = &options.format;
   let mut renamer =
     Renamer::new(&link_output.symbol_db, link_output.module_table.modules.len(), format);
 
-  if matches!(format, OutputFormat::Iife | OutputFormat::Umd) {
+  // Cjs chunks only bind externals if their named imports can't be destructured.
+  if matches!(format, OutputFormat::Iife | OutputFormat::Umd)
+    || (matches!(format, OutputFormat::Cjs) && !options.generated_code.destructuring())
+  {
     // deconflict iife introduce symbols by external
     // Also AMD, but we don't support them yet.
     chunk
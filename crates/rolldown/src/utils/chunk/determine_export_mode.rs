@@ -47,14 +47,18 @@ pub fn determine_export_mode(
       } else {
         let has_default_export = exports.iter().any(|(name, _)| name.as_str() == "default");
         if has_default_export {
-          let name = &ctx.chunk.name;
-          let chunk = ArcStr::from("chunk");
-          let name = name.as_ref().unwrap_or(&chunk);
+          // Consumers access the exports through `output.name`, like rollup.
+          let name = ctx.options.name.as_deref().unwrap_or("chunk");
           ctx.warnings.push(
             BuildDiagnostic::mixed_export(
               ArcStr::from(module.stable_id.as_str()),
               ArcStr::from(name),
-              exports.iter().map(|(name, _)| name.as_str().into()).collect(),
+              exports
+                .iter()
+                .map(|(name, _)| name.as_str())
+                .filter(|name| *name != "default")
+                .map(ArcStr::from)
+                .collect(),
             )
             .with_severity_warning(),
           );
@@ -3,6 +3,13 @@ use rolldown_common::ExportsKind;
 use crate::types::generator::GenerateContext;
 
 pub fn determine_use_strict(ctx: &GenerateContext) -> bool {
+  // Hardened JavaScript environments only support strict mode code, so there is no sloppy mode to fall back to.
+  if ctx.options.ses_compatible {
+    return true;
+  }
+  if let Some(strict) = ctx.options.strict {
+    return strict;
+  }
   ctx
     .chunk
     .modules
@@ -1,13 +1,10 @@
-use std::hash::Hash;
-
 use arcstr::ArcStr;
 use itertools::Itertools;
 use oxc::index::{index_vec, IndexVec};
-use rolldown_common::{AssetIdx, InstantiationKind, ModuleId};
+use rolldown_common::{AssetIdx, HashCharacters, InstantiationKind, ModuleId};
 #[cfg(not(target_family = "wasm"))]
 use rolldown_utils::rayon::IndexedParallelIterator;
 use rolldown_utils::{
-  base64::to_url_safe_base64,
   rayon::{
     IntoParallelIterator, IntoParallelRefIterator, IntoParallelRefMutIterator, ParallelIterator,
   },
@@ -27,6 +24,7 @@ pub fn finalize_assets(
   chunk_graph: &mut ChunkGraph,
   preliminary_assets: IndexInstantiatedChunks,
   index_chunk_to_assets: &IndexChunkToAssets,
+  hash_characters: HashCharacters,
 ) -> IndexAssets {
   let asset_idx_by_placeholder = preliminary_assets
     .iter_enumerated()
@@ -66,36 +64,36 @@ pub fn finalize_assets(
     .map(|(asset_idx, mut hasher)| {
       let asset_idx = AssetIdx::from(asset_idx);
       // Start to calculate hash, first we hash itself
-      index_standalone_content_hashes[asset_idx].hash(&mut hasher);
+      hash_str(&mut hasher, &index_standalone_content_hashes[asset_idx]);
 
       // hash itself's preliminary filename to prevent different chunks that have the same content from having the same hash
-      preliminary_assets[asset_idx].preliminary_filename.hash(&mut hasher);
+      hash_str(&mut hasher, &preliminary_assets[asset_idx].preliminary_filename);
 
       let dependencies = &index_asset_dependencies[asset_idx];
       dependencies.iter().copied().for_each(|dep_id| {
-        index_standalone_content_hashes[dep_id].hash(&mut hasher);
+        hash_str(&mut hasher, &index_standalone_content_hashes[dep_id]);
       });
 
-      // Hash content that provided by users if it's exist
-      if let Some(augment_chunk_hash) = &preliminary_assets[asset_idx].augment_chunk_hash {
-        augment_chunk_hash.as_bytes().hash(&mut hasher);
+      // Hash content that provided by users or generators if it's exist. Assets of the same chunk share the
+      // chunk's hash, so changing e.g. only the css of a chunk needs to invalidate it too.
+      let origin_chunk = preliminary_assets[asset_idx].origin_chunk;
+      for sibling_idx in &index_chunk_to_assets[origin_chunk] {
+        if let Some(augment_chunk_hash) = &preliminary_assets[*sibling_idx].augment_chunk_hash {
+          hash_bytes(&mut hasher, augment_chunk_hash.as_bytes());
+        }
       }
 
-      let digested = hasher.digest128();
-      to_url_safe_base64(digested.to_le_bytes())
+      hash_characters.encode(hasher.digest128())
     })
     .collect::<Vec<_>>()
     .into();
 
-  let final_hashes_by_placeholder = chunk_graph
-    .chunk_table
-    .iter()
-    .zip(&index_final_hashes)
-    .filter_map(|(chunk, hash)| {
-      chunk
+  // Chunks could have multiple assets, so placeholders are mapped by assets instead of chunks.
+  let final_hashes_by_placeholder = index_final_hashes
+    .iter_enumerated()
+    .filter_map(|(asset_idx, hash)| {
+      preliminary_assets[asset_idx]
         .preliminary_filename
-        .as_ref()
-        .unwrap()
         .hash_placeholder()
         .map(|hash_placeholder| (hash_placeholder.into(), &hash[..hash_placeholder.len()]))
     })
@@ -148,3 +146,17 @@ pub fn finalize_assets(
 
   assets
 }
+
+// `std::hash::Hash` writes lengths as `usize` in the native width and endianness, so hashes would differ
+// between platforms, e.g. the native bindings and wasm. These write the same bytes as `Hash` does on
+// 64-bit little-endian platforms, regardless of the platform.
+
+fn hash_str(hasher: &mut Xxh3, value: &str) {
+  hasher.update(value.as_bytes());
+  hasher.update(&[0xff]);
+}
+
+fn hash_bytes(hasher: &mut Xxh3, value: &[u8]) {
+  hasher.update(&(value.len() as u64).to_le_bytes());
+  hasher.update(value);
+}
@@ -8,7 +8,9 @@ use crate::{chunk_graph::ChunkGraph, stages::link_stage::LinkStageOutput};
 
 use self::render_chunk_exports::get_chunk_export_names;
 
+pub mod chunk_url;
 pub mod collect_render_chunk_imports;
+pub mod common_chunk_name;
 pub mod deconflict_chunk_symbols;
 pub mod determine_export_mode;
 pub mod determine_use_strict;
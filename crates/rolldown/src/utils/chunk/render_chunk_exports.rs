@@ -6,7 +6,11 @@ use rolldown_common::{
   OutputFormat, SymbolRef, SymbolRefDb, WrapKind,
 };
 use rolldown_rstr::Rstr;
-use rolldown_utils::ecma_script::{is_validate_identifier_name, property_access_str};
+use rolldown_utils::ecma_script::{
+  is_object_prototype_property_name, is_validate_identifier_name, property_access_str,
+  property_access_str_without_reserved_names,
+};
+use rustc_hash::FxHashMap;
 
 #[allow(clippy::too_many_lines)]
 pub fn render_chunk_exports(
@@ -87,18 +91,28 @@ pub fn render_chunk_exports(
                       options,
                       &link_output.module_table.modules,
                     ) {
-                      format!(
-                        "Object.defineProperty(exports, '{exported_name}', {{
-  enumerable: true,
-  get: function () {{
-    return {exported_value};
-  }}
-}});"
+                      render_define_export_property(
+                        options,
+                        &exported_name,
+                        &format!("get: {}", render_getter(options, &exported_value)),
+                      )
+                    } else if options.ses_compatible
+                      && is_object_prototype_property_name(&exported_name)
+                    {
+                      // `exports.toString = ...` throws once `Object.prototype` is frozen.
+                      render_define_export_property(
+                        options,
+                        &exported_name,
+                        &format!("value: {exported_value}"),
                       )
                     } else {
                       format!(
                         "{left_value} = {exported_value}",
-                        left_value = property_access_str("exports", &exported_name)
+                        left_value = if options.generated_code.reserved_names_as_props() {
+                          property_access_str("exports", &exported_name)
+                        } else {
+                          property_access_str_without_reserved_names("exports", &exported_name)
+                        }
                       )
                     }
                   }
@@ -126,22 +140,18 @@ pub fn render_chunk_exports(
             if let Some(ns_alias) = &symbol.namespace_alias {
               let canonical_ns_name = &chunk.canonical_names[&ns_alias.namespace_ref];
               let property_name = &ns_alias.property_name;
-              s.push_str(&format!(
-                "Object.defineProperty(exports, '{exported_name}', {{
-  enumerable: true,
-  get: function () {{
-    return {canonical_ns_name}.{property_name};
-  }}
-}});\n"
+              let getter = render_getter(options, &format!("{canonical_ns_name}.{property_name}"));
+              s.push_str(&render_define_export_property(
+                options,
+                &exported_name,
+                &format!("get: {getter}"),
               ));
+              s.push('\n');
             } else {
-              s.push_str(&format!(
-                "Object.defineProperty(exports, '{exported_name}', {{
-  enumerable: true,
-  get: function () {{
-    return {canonical_name};
-  }}
-}});"
+              s.push_str(&render_define_export_property(
+                options,
+                &exported_name,
+                &format!("get: {}", render_getter(options, canonical_name)),
               ));
             };
           });
@@ -150,10 +160,63 @@ pub fn render_chunk_exports(
 
       Some(s)
     }
+    OutputFormat::System => {
+      let rendered_items = export_items
+        .into_iter()
+        .map(|(exported_name, export_ref)| {
+          let canonical_ref = link_output.symbol_db.canonical_ref_for(export_ref);
+          let symbol = link_output.symbol_db.get(canonical_ref);
+          let exported_value = if let Some(ns_alias) = &symbol.namespace_alias {
+            let canonical_ns_name = &chunk.canonical_names[&ns_alias.namespace_ref];
+            format!("{canonical_ns_name}.{}", ns_alias.property_name)
+          } else {
+            chunk.canonical_names[&canonical_ref].to_string()
+          };
+          if options.generated_code.object_shorthand() && exported_name.as_str() == exported_value {
+            exported_name.to_string()
+          } else if is_validate_identifier_name(&exported_name) {
+            format!("{exported_name}: {exported_value}")
+          } else {
+            format!("'{exported_name}': {exported_value}")
+          }
+        })
+        .collect::<Vec<_>>();
+      Some(format!("exports({{ {} }});", rendered_items.join(", ")))
+    }
     OutputFormat::App => None,
   }
 }
 
+/// Renders the getter of a live binding, which is `function () { return value; }` or `() => value` with
+/// `generatedCode.arrowFunctions`.
+pub fn render_getter(options: &NormalizedBundlerOptions, value: &str) -> String {
+  if options.generated_code.arrow_functions() {
+    format!("() => {value}")
+  } else if options.compact {
+    format!("function () {{ return {value}; }}")
+  } else {
+    format!("function () {{\n    return {value};\n  }}")
+  }
+}
+
+/// Renders `Object.defineProperty(exports, 'name', { enumerable: true, ...property })`, which is kept on a
+/// single line with `output.compact`.
+fn render_define_export_property(
+  options: &NormalizedBundlerOptions,
+  exported_name: &str,
+  property: &str,
+) -> String {
+  if options.compact {
+    format!(
+      "Object.defineProperty(exports, '{exported_name}', {{ enumerable: true, {property} }});"
+    )
+  } else {
+    format!(
+      "Object.defineProperty(exports, '{exported_name}', {{\n  enumerable: true,\n  {property}\n}});"
+    )
+  }
+}
+
 pub fn get_export_items(chunk: &Chunk, graph: &LinkStageOutput) -> Vec<(Rstr, SymbolRef)> {
   match chunk.kind {
     ChunkKind::EntryPoint { module, .. } => {
@@ -177,12 +240,31 @@ pub fn get_export_items(chunk: &Chunk, graph: &LinkStageOutput) -> Vec<(Rstr, Sy
   }
 }
 
+/// Collects the exported names of each binding of the chunk that might be reassigned, so assignments to it could
+/// be followed by an `exports(...)` call in system format.
+pub fn get_reassignable_export_names(
+  chunk: &Chunk,
+  graph: &LinkStageOutput,
+) -> FxHashMap<SymbolRef, Vec<Rstr>> {
+  let mut names: FxHashMap<SymbolRef, Vec<Rstr>> = FxHashMap::default();
+  for (exported_name, export_ref) in get_export_items(chunk, graph) {
+    let canonical_ref = graph.symbol_db.canonical_ref_for(export_ref);
+    if graph.symbol_db.get(canonical_ref).namespace_alias.is_some()
+      || canonical_ref.is_not_reassigned(&graph.symbol_db).unwrap_or(false)
+    {
+      continue;
+    }
+    names.entry(canonical_ref).or_default().push(exported_name);
+  }
+  names
+}
+
 pub fn get_chunk_export_names(
   chunk: &Chunk,
   graph: &LinkStageOutput,
   options: &NormalizedBundlerOptions,
 ) -> Vec<String> {
-  if matches!(options.format, OutputFormat::Esm) {
+  if matches!(options.format, OutputFormat::Esm | OutputFormat::System) {
     if let ChunkKind::EntryPoint { module: entry_id, .. } = &chunk.kind {
       let entry_meta = &graph.metas[*entry_id];
       if matches!(entry_meta.wrap_kind, WrapKind::Cjs) {
@@ -0,0 +1,190 @@
+use oxc::{
+  ast::ast::{Declaration, Expression, Program, Statement, TSEnumDeclaration, TSEnumMemberName},
+  span::CompactStr,
+  syntax::{
+    number::ToJsString,
+    operator::{BinaryOperator, UnaryOperator},
+  },
+};
+use rolldown_common::{ConstEnumMembers, ConstEnumValue};
+use rustc_hash::FxHashMap;
+
+/// The top-level enum declarations of `program`, including exported ones.
+pub fn top_level_enums<'a, 'p>(
+  program: &'p Program<'a>,
+) -> impl Iterator<Item = &'p TSEnumDeclaration<'a>> {
+  program.body.iter().filter_map(|stmt| match stmt {
+    Statement::TSEnumDeclaration(decl) => Some(&**decl),
+    Statement::ExportNamedDeclaration(export) => match &export.declaration {
+      Some(Declaration::TSEnumDeclaration(decl)) => Some(&**decl),
+      _ => None,
+    },
+    _ => None,
+  })
+}
+
+/// Evaluates the members of the top-level `const enum`s of `program`, keyed by the names of the enums.
+/// Members whose values can't be known at compile time are left out, and so are the members
+/// auto-incremented from them.
+pub fn collect_const_enums(program: &Program) -> FxHashMap<CompactStr, ConstEnumMembers> {
+  let mut enums: FxHashMap<CompactStr, ConstEnumMembers> = FxHashMap::default();
+  for decl in top_level_enums(program).filter(|decl| decl.r#const && !decl.declare) {
+    let mut members = ConstEnumMembers::default();
+    let mut next = Some(0.0);
+    for member in &decl.members {
+      let name = match &member.id {
+        TSEnumMemberName::StaticIdentifier(id) => id.name.as_str(),
+        TSEnumMemberName::StaticStringLiteral(lit) => lit.value.as_str(),
+        _ => break,
+      };
+      let value = match &member.initializer {
+        Some(init) => {
+          Evaluator { enum_name: &decl.id.name, members: &members, enums: &enums }.evaluate(init)
+        }
+        None => next.map(ConstEnumValue::Number),
+      };
+      next = match &value {
+        Some(ConstEnumValue::Number(value)) => Some(value + 1.0),
+        _ => None,
+      };
+      if let Some(value) = value {
+        members.insert(name.into(), value);
+      }
+    }
+    enums.insert(decl.id.name.as_str().into(), members);
+  }
+  enums
+}
+
+struct Evaluator<'me> {
+  enum_name: &'me str,
+  /// The members of the enum evaluated so far.
+  members: &'me ConstEnumMembers,
+  /// The enums declared before this one.
+  enums: &'me FxHashMap<CompactStr, ConstEnumMembers>,
+}
+
+impl<'me> Evaluator<'me> {
+  fn member(&self, enum_name: &str, member: &str) -> Option<ConstEnumValue> {
+    if enum_name == self.enum_name {
+      self.members.get(member).cloned()
+    } else {
+      self.enums.get(enum_name)?.get(member).cloned()
+    }
+  }
+
+  fn evaluate(&self, expr: &Expression) -> Option<ConstEnumValue> {
+    match expr {
+      Expression::NumericLiteral(lit) => Some(ConstEnumValue::Number(lit.value)),
+      Expression::StringLiteral(lit) => Some(ConstEnumValue::String(lit.value.as_str().into())),
+      Expression::TemplateLiteral(lit) if lit.expressions.is_empty() => {
+        Some(ConstEnumValue::String(lit.quasis.first()?.value.cooked.as_ref()?.as_str().into()))
+      }
+      Expression::ParenthesizedExpression(paren) => self.evaluate(&paren.expression),
+      // Members of the enum can be referenced without the enum name.
+      Expression::Identifier(ident) => self.members.get(ident.name.as_str()).cloned(),
+      Expression::StaticMemberExpression(member_expr) => match &member_expr.object {
+        Expression::Identifier(object) => self.member(&object.name, &member_expr.property.name),
+        _ => None,
+      },
+      Expression::ComputedMemberExpression(member_expr) => {
+        match (&member_expr.object, &member_expr.expression) {
+          (Expression::Identifier(object), Expression::StringLiteral(property)) => {
+            self.member(&object.name, &property.value)
+          }
+          _ => None,
+        }
+      }
+      Expression::UnaryExpression(unary) => {
+        let ConstEnumValue::Number(value) = self.evaluate(&unary.argument)? else {
+          return None;
+        };
+        let value = match unary.operator {
+          UnaryOperator::UnaryPlus => value,
+          UnaryOperator::UnaryNegation => -value,
+          UnaryOperator::BitwiseNot => f64::from(!to_int32(value)),
+          _ => return None,
+        };
+        Some(ConstEnumValue::Number(value))
+      }
+      Expression::BinaryExpression(binary) => {
+        let left = self.evaluate(&binary.left)?;
+        let right = self.evaluate(&binary.right)?;
+        match (left, right) {
+          (ConstEnumValue::Number(left), ConstEnumValue::Number(right)) => {
+            evaluate_numeric_binary(binary.operator, left, right).map(ConstEnumValue::Number)
+          }
+          (left, right) if binary.operator == BinaryOperator::Addition => Some(
+            ConstEnumValue::String(format!("{}{}", to_string(&left), to_string(&right)).into()),
+          ),
+          _ => None,
+        }
+      }
+      _ => None,
+    }
+  }
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn evaluate_numeric_binary(operator: BinaryOperator, left: f64, right: f64) -> Option<f64> {
+  let value = match operator {
+    BinaryOperator::Addition => left + right,
+    BinaryOperator::Subtraction => left - right,
+    BinaryOperator::Multiplication => left * right,
+    BinaryOperator::Division => left / right,
+    BinaryOperator::Remainder => left % right,
+    BinaryOperator::Exponential => left.powf(right),
+    BinaryOperator::BitwiseOR => f64::from(to_int32(left) | to_int32(right)),
+    BinaryOperator::BitwiseAnd => f64::from(to_int32(left) & to_int32(right)),
+    BinaryOperator::BitwiseXOR => f64::from(to_int32(left) ^ to_int32(right)),
+    BinaryOperator::ShiftLeft => f64::from(to_int32(left).wrapping_shl(to_int32(right) as u32)),
+    BinaryOperator::ShiftRight => f64::from(to_int32(left).wrapping_shr(to_int32(right) as u32)),
+    BinaryOperator::ShiftRightZeroFill => {
+      f64::from((to_int32(left) as u32).wrapping_shr(to_int32(right) as u32))
+    }
+    _ => return None,
+  };
+  Some(value)
+}
+
+/// The `ToInt32` abstract operation of ECMAScript.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss, clippy::cast_possible_wrap)]
+fn to_int32(value: f64) -> i32 {
+  if !value.is_finite() {
+    return 0;
+  }
+  value.trunc().rem_euclid(4_294_967_296.0) as u32 as i32
+}
+
+fn to_string(value: &ConstEnumValue) -> String {
+  match value {
+    ConstEnumValue::Number(value) => value.to_js_string(),
+    ConstEnumValue::String(value) => value.to_string(),
+  }
+}
+
+#[test]
+fn test_collect_const_enums() {
+  let allocator = oxc::allocator::Allocator::default();
+  let source = "
+    export const enum A { X, Y = 'y', Z = X + 2 * 3 }
+    const enum B { X = A.Z << 1, Y, Z = `${A.Y}`, W = A.Y + '-' + Y, V = '' + -0, U = '' + 1e21 }
+    enum C { X }
+    declare const enum D { X }
+  ";
+  let ret = oxc::parser::Parser::new(&allocator, source, oxc::span::SourceType::ts()).parse();
+  let enums = collect_const_enums(&ret.program);
+
+  assert_eq!(enums.len(), 2);
+  let a = &enums["A"];
+  assert_eq!(a["X"], ConstEnumValue::Number(0.0));
+  assert_eq!(a["Y"], ConstEnumValue::String("y".into()));
+  assert_eq!(a["Z"], ConstEnumValue::Number(6.0));
+  let b = &enums["B"];
+  assert_eq!(b["X"], ConstEnumValue::Number(12.0));
+  assert_eq!(b["Y"], ConstEnumValue::Number(13.0));
+  assert!(!b.contains_key("Z"));
+  assert_eq!(b["W"], ConstEnumValue::String("y-13".into()));
+  assert_eq!(b["V"], ConstEnumValue::String("0".into()));
+  assert_eq!(b["U"], ConstEnumValue::String("1e+21".into()));
+}
@@ -0,0 +1,92 @@
+use oxc::{
+  ast::ast::{
+    BindingPatternKind, Declaration, Expression, Program, Statement, VariableDeclaration,
+    VariableDeclarationKind,
+  },
+  syntax::operator::UnaryOperator,
+};
+use rolldown_common::{ConstantValue, Constants};
+
+/// Collects the top-level `const`s of `program`, including exported ones, that are initialized with
+/// primitive literals.
+pub fn collect_constants(program: &Program) -> Constants {
+  let mut constants = Constants::default();
+  for stmt in &program.body {
+    let decl = match stmt {
+      Statement::VariableDeclaration(decl) => decl,
+      Statement::ExportNamedDeclaration(export) => match &export.declaration {
+        Some(Declaration::VariableDeclaration(decl)) => decl,
+        _ => continue,
+      },
+      _ => continue,
+    };
+    collect_constants_of_var_decl(decl, &mut constants);
+  }
+  constants
+}
+
+fn collect_constants_of_var_decl(decl: &VariableDeclaration, constants: &mut Constants) {
+  if decl.kind != VariableDeclarationKind::Const {
+    return;
+  }
+  for declarator in &decl.declarations {
+    let BindingPatternKind::BindingIdentifier(id) = &declarator.id.kind else {
+      continue;
+    };
+    let (Some(symbol_id), Some(value)) =
+      (id.symbol_id.get(), declarator.init.as_ref().and_then(evaluate_constant))
+    else {
+      continue;
+    };
+    constants.insert(symbol_id, value);
+  }
+}
+
+/// The value of `expr` if it is a primitive literal, such as `1`, `-1`, `"a"` or `!0`.
+pub fn evaluate_constant(expr: &Expression) -> Option<ConstantValue> {
+  match expr {
+    Expression::NumericLiteral(lit) => Some(ConstantValue::Number(lit.value)),
+    Expression::StringLiteral(lit) => Some(ConstantValue::String(lit.value.as_str().into())),
+    Expression::BooleanLiteral(lit) => Some(ConstantValue::Boolean(lit.value)),
+    Expression::TemplateLiteral(lit) if lit.expressions.is_empty() => {
+      Some(ConstantValue::String(lit.quasis.first()?.value.cooked.as_ref()?.as_str().into()))
+    }
+    Expression::ParenthesizedExpression(paren) => evaluate_constant(&paren.expression),
+    Expression::UnaryExpression(unary) => {
+      match (unary.operator, evaluate_constant(&unary.argument)?) {
+        (UnaryOperator::UnaryNegation, ConstantValue::Number(value)) => {
+          Some(ConstantValue::Number(-value))
+        }
+        // Minifiers write booleans as `!0` and `!1`.
+        (UnaryOperator::LogicalNot, value) => Some(ConstantValue::Boolean(!value.is_truthy())),
+        _ => None,
+      }
+    }
+    _ => None,
+  }
+}
+
+#[test]
+fn test_collect_constants() {
+  let allocator = oxc::allocator::Allocator::default();
+  let source = "
+    export const VERSION = '1.2.3', IS_LEGACY = false, LEVEL = -1, MINIFIED = !0;
+    const NAME = `name`, OBJ = {}, [A] = [1];
+    let MUTABLE = true;
+  ";
+  let ret = oxc::parser::Parser::new(&allocator, source, oxc::span::SourceType::mjs()).parse();
+  let semantic = oxc::semantic::SemanticBuilder::new().build(&ret.program).semantic;
+  let constants = collect_constants(&ret.program);
+
+  let value_of = |name: &str| {
+    let symbol_id = semantic.scopes().get_root_binding(name).expect("should be declared");
+    constants.get(&symbol_id).cloned()
+  };
+  assert_eq!(constants.len(), 5);
+  assert_eq!(value_of("VERSION"), Some(ConstantValue::String("1.2.3".into())));
+  assert_eq!(value_of("IS_LEGACY"), Some(ConstantValue::Boolean(false)));
+  assert_eq!(value_of("LEVEL"), Some(ConstantValue::Number(-1.0)));
+  assert_eq!(value_of("MINIFIED"), Some(ConstantValue::Boolean(true)));
+  assert_eq!(value_of("NAME"), Some(ConstantValue::String("name".into())));
+  assert_eq!(value_of("MUTABLE"), None);
+}
@@ -0,0 +1,93 @@
+use oxc::{
+  allocator,
+  ast::{
+    ast::{Expression, Statement},
+    visit::walk_mut,
+    AstBuilder, VisitMut,
+  },
+  semantic::SymbolTable,
+  span::SPAN,
+};
+use rolldown_common::NormalizedBundlerOptions;
+
+/// Removes the code configured by `drop` and `drop_labels`.
+pub struct Dropper<'a, 'me> {
+  builder: AstBuilder<'a>,
+  symbols: &'me SymbolTable,
+  options: &'me NormalizedBundlerOptions,
+}
+
+impl<'a, 'me> Dropper<'a, 'me> {
+  pub fn new(
+    builder: AstBuilder<'a>,
+    symbols: &'me SymbolTable,
+    options: &'me NormalizedBundlerOptions,
+  ) -> Self {
+    Self { builder, symbols, options }
+  }
+
+  /// Whether `expr` is a call of the global `console`, such as `console.log(a)` or
+  /// `console.log.apply(console, a)`.
+  fn is_dropped_console_call(&self, expr: &Expression<'a>) -> bool {
+    if !self.options.drop_console {
+      return false;
+    }
+    let Expression::CallExpression(call) = expr else {
+      return false;
+    };
+    let Some(mut member_expr) = call.callee.as_member_expression() else {
+      return false;
+    };
+    while let Some(object) = member_expr.object().as_member_expression() {
+      member_expr = object;
+    }
+    match member_expr.object() {
+      Expression::Identifier(ident) => {
+        ident.name == "console"
+          && ident
+            .reference_id
+            .get()
+            .map_or(true, |id| self.symbols.get_reference(id).symbol_id().is_none())
+      }
+      _ => false,
+    }
+  }
+
+  fn is_dropped_stmt(&self, stmt: &Statement<'a>) -> bool {
+    match stmt {
+      Statement::DebuggerStatement(_) => self.options.drop_debugger,
+      Statement::LabeledStatement(labeled) => {
+        self.options.drop_labels.iter().any(|label| label == labeled.label.name.as_str())
+      }
+      Statement::ExpressionStatement(expr_stmt) => {
+        self.is_dropped_console_call(&expr_stmt.expression)
+      }
+      _ => false,
+    }
+  }
+}
+
+impl<'a, 'me> VisitMut<'a> for Dropper<'a, 'me> {
+  fn visit_statements(&mut self, it: &mut allocator::Vec<'a, Statement<'a>>) {
+    it.retain(|stmt| !self.is_dropped_stmt(stmt));
+    walk_mut::walk_statements(self, it);
+  }
+
+  fn visit_statement(&mut self, it: &mut Statement<'a>) {
+    // Statements that aren't in a list, such as `if (a) debugger`, are replaced with `;`.
+    if self.is_dropped_stmt(it) {
+      *it = self.builder.statement_empty(SPAN);
+      return;
+    }
+    walk_mut::walk_statement(self, it);
+  }
+
+  fn visit_expression(&mut self, it: &mut Expression<'a>) {
+    // `const a = console.log(b)` => `const a = void 0`
+    if self.is_dropped_console_call(it) {
+      *it = self.builder.void_0(SPAN);
+      return;
+    }
+    walk_mut::walk_expression(self, it);
+  }
+}
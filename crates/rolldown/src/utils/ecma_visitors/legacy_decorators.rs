@@ -0,0 +1,615 @@
+use std::mem;
+
+use oxc::{
+  allocator::{self, Box},
+  ast::{
+    ast::{
+      ArrayExpressionElement, AssignmentOperator, AssignmentTarget, BindingPattern,
+      BindingPatternKind, Class, ClassElement, ClassType, Declaration, Decorator,
+      ExportDefaultDeclaration, ExportDefaultDeclarationKind, ExportNamedDeclaration, Expression,
+      FormalParameters, ImportOrExportKind, MethodDefinition, MethodDefinitionKind, NumberBase,
+      Program, PropertyKey, Statement, TSLiteral, TSType, TSTypeAnnotation, TSTypeName,
+      VariableDeclarationKind,
+    },
+    visit::walk_mut,
+    AstBuilder, VisitMut, NONE,
+  },
+  span::{Atom, SPAN},
+  syntax::operator::{BinaryOperator, UnaryOperator},
+};
+use rustc_hash::FxHashSet;
+
+use crate::runtime::RUNTIME_MODULE_ID;
+
+/// `kind` of `__decorateClass` for methods and accessors, whose descriptors are passed to the
+/// decorators.
+const METHOD_KIND: f64 = 1.0;
+/// `kind` of `__decorateClass` for fields, which don't have descriptors.
+const FIELD_KIND: f64 = 2.0;
+
+/// Transforms decorators with the semantics of `experimentalDecorators` of TypeScript, calling the
+/// `__decorateClass` helper of the runtime after the decorated classes. It runs before the
+/// TypeScript transformer, as `emitDecoratorMetadata` needs the types of the decorated
+/// declarations.
+pub struct LegacyDecorators<'a, 'me> {
+  builder: AstBuilder<'a>,
+  source: &'me str,
+  emit_decorator_metadata: bool,
+  /// The runtime helpers used by the module, in the order they're first used.
+  used_helpers: Vec<&'static str>,
+  /// The names generated for the module, which mustn't appear in the source.
+  generated_names: FxHashSet<String>,
+}
+
+enum ClassExport<'a> {
+  Named(Box<'a, ExportNamedDeclaration<'a>>),
+  Default(Box<'a, ExportDefaultDeclaration<'a>>),
+}
+
+/// The runtime value of a type for `emitDecoratorMetadata`.
+#[derive(PartialEq)]
+enum SerializedType<'a> {
+  /// `void 0`
+  Void,
+  /// A global constructor, such as `Number`.
+  Global(&'static str),
+  /// A type named in the module. It might not exist at runtime, so it's guarded with `typeof`.
+  Reference(Atom<'a>),
+}
+
+impl<'a, 'me> LegacyDecorators<'a, 'me> {
+  pub fn new(builder: AstBuilder<'a>, source: &'me str, emit_decorator_metadata: bool) -> Self {
+    Self {
+      builder,
+      source,
+      emit_decorator_metadata,
+      used_helpers: vec![],
+      generated_names: FxHashSet::default(),
+    }
+  }
+
+  /// Whether any decorators were transformed.
+  pub fn is_transformed(&self) -> bool {
+    !self.used_helpers.is_empty()
+  }
+
+  fn generate_name(&mut self, base: &str) -> Atom<'a> {
+    let mut name = base.to_string();
+    let mut suffix = 1;
+    while self.source.contains(&name) || self.generated_names.contains(&name) {
+      suffix += 1;
+      name = format!("{base}{suffix}");
+    }
+    let atom = self.builder.atom(&name);
+    self.generated_names.insert(name);
+    atom
+  }
+
+  fn helper_call(
+    &mut self,
+    helper: &'static str,
+    arguments: impl IntoIterator<Item = Expression<'a>>,
+  ) -> Expression<'a> {
+    if !self.used_helpers.contains(&helper) {
+      self.used_helpers.push(helper);
+    }
+    let builder = self.builder;
+    builder.expression_call(
+      SPAN,
+      builder.expression_identifier_reference(SPAN, helper),
+      NONE,
+      builder.vec_from_iter(arguments.into_iter().map(|arg| builder.argument_expression(arg))),
+      false,
+    )
+  }
+
+  fn array_expr(&self, elements: impl IntoIterator<Item = Expression<'a>>) -> Expression<'a> {
+    self.builder.expression_array(
+      SPAN,
+      self.builder.vec_from_iter(elements.into_iter().map(ArrayExpressionElement::from)),
+      None,
+    )
+  }
+
+  fn number_expr(&self, value: f64) -> Expression<'a> {
+    self.builder.expression_numeric_literal(SPAN, value, value.to_string(), NumberBase::Decimal)
+  }
+
+  fn take_decorators(
+    &self,
+    decorators: &mut allocator::Vec<'a, Decorator<'a>>,
+  ) -> Vec<Expression<'a>> {
+    mem::replace(decorators, self.builder.vec())
+      .into_iter()
+      .map(|decorator| decorator.expression)
+      .collect()
+  }
+
+  /// `@dec` of the `index`th parameter becomes `__decorateParam(index, dec)`.
+  #[allow(clippy::cast_precision_loss)]
+  fn take_param_decorators(&mut self, params: &mut FormalParameters<'a>) -> Vec<Expression<'a>> {
+    let mut decorators = vec![];
+    for (index, param) in params.items.iter_mut().enumerate() {
+      for decorator in mem::replace(&mut param.decorators, self.builder.vec()) {
+        let index = self.number_expr(index as f64);
+        decorators.push(self.helper_call("__decorateParam", [index, decorator.expression]));
+      }
+    }
+    decorators
+  }
+
+  fn transform_statement(&mut self, stmt: Statement<'a>, out: &mut Vec<Statement<'a>>) {
+    if !is_decorated_class_stmt(&stmt) {
+      out.push(stmt);
+      return;
+    }
+    match stmt {
+      Statement::ClassDeclaration(class) => self.transform_class(class, None, out),
+      Statement::ExportNamedDeclaration(mut export) => {
+        let Some(Declaration::ClassDeclaration(class)) = export.declaration.take() else {
+          unreachable!("checked by `is_decorated_class_stmt`")
+        };
+        self.transform_class(class, Some(ClassExport::Named(export)), out);
+      }
+      Statement::ExportDefaultDeclaration(mut export) => {
+        let placeholder =
+          self.builder.export_default_declaration_kind_expression(self.builder.void_0(SPAN));
+        let ExportDefaultDeclarationKind::ClassDeclaration(class) =
+          mem::replace(&mut export.declaration, placeholder)
+        else {
+          unreachable!("checked by `is_decorated_class_stmt`")
+        };
+        self.transform_class(class, Some(ClassExport::Default(export)), out);
+      }
+      _ => unreachable!("checked by `is_decorated_class_stmt`"),
+    }
+  }
+
+  /// ```js
+  /// @dec class A { @dec2 m() {} }
+  /// // =>
+  /// let A = class A { m() {} };
+  /// __decorateClass([dec2], A.prototype, "m", 1);
+  /// A = __decorateClass([dec], A);
+  /// ```
+  fn transform_class(
+    &mut self,
+    mut class: Box<'a, Class<'a>>,
+    export: Option<ClassExport<'a>>,
+    out: &mut Vec<Statement<'a>>,
+  ) {
+    let name = match &class.id {
+      Some(id) => id.name.clone(),
+      None => self.generate_name("_default"),
+    };
+    let member_decorations = self.member_decorations(&mut class, &name, out);
+    let class_decorators = self.class_decorators(&mut class);
+
+    // Class decorators may replace the class, so it's bound with `let`. Anonymous default exports
+    // need a binding to be referenced too.
+    let needs_binding = !class_decorators.is_empty() || class.id.is_none();
+    let mut default_export = None;
+    if needs_binding {
+      class.r#type = ClassType::ClassExpression;
+      let decl = self.builder.alloc_variable_declaration(
+        SPAN,
+        VariableDeclarationKind::Let,
+        self.builder.vec1(self.builder.variable_declarator(
+          SPAN,
+          VariableDeclarationKind::Let,
+          self.binding_pattern(&name),
+          Some(Expression::ClassExpression(class)),
+          false,
+        )),
+        false,
+      );
+      match export {
+        Some(ClassExport::Named(mut export)) => {
+          export.declaration = Some(Declaration::VariableDeclaration(decl));
+          out.push(Statement::ExportNamedDeclaration(export));
+        }
+        Some(ClassExport::Default(mut export)) => {
+          export.declaration =
+            self.builder.export_default_declaration_kind_expression(self.id_ref_expr(&name));
+          default_export = Some(export);
+          out.push(Statement::VariableDeclaration(decl));
+        }
+        None => out.push(Statement::VariableDeclaration(decl)),
+      }
+    } else {
+      match export {
+        Some(ClassExport::Named(mut export)) => {
+          export.declaration = Some(Declaration::ClassDeclaration(class));
+          out.push(Statement::ExportNamedDeclaration(export));
+        }
+        Some(ClassExport::Default(mut export)) => {
+          export.declaration = ExportDefaultDeclarationKind::ClassDeclaration(class);
+          out.push(Statement::ExportDefaultDeclaration(export));
+        }
+        None => out.push(Statement::ClassDeclaration(class)),
+      }
+    }
+
+    out.extend(member_decorations);
+    if !class_decorators.is_empty() {
+      let decorators = self.array_expr(class_decorators);
+      let call = self.helper_call("__decorateClass", [decorators, self.id_ref_expr(&name)]);
+      let assignment = self.builder.expression_assignment(
+        SPAN,
+        AssignmentOperator::Assign,
+        AssignmentTarget::AssignmentTargetIdentifier(
+          self.builder.alloc_identifier_reference(SPAN, name.as_str()),
+        ),
+        call,
+      );
+      out.push(self.builder.statement_expression(SPAN, assignment));
+    }
+    if let Some(export) = default_export {
+      out.push(Statement::ExportDefaultDeclaration(export));
+    }
+  }
+
+  /// The decorators of the class itself and of the parameters of its constructor.
+  fn class_decorators(&mut self, class: &mut Class<'a>) -> Vec<Expression<'a>> {
+    let mut decorators = self.take_decorators(&mut class.decorators);
+    let constructor = class.body.body.iter_mut().find_map(|element| match element {
+      ClassElement::MethodDefinition(method)
+        if method.kind == MethodDefinitionKind::Constructor && method.value.body.is_some() =>
+      {
+        Some(method)
+      }
+      _ => None,
+    });
+    if let Some(constructor) = constructor {
+      decorators.extend(self.take_param_decorators(&mut constructor.value.params));
+      if !decorators.is_empty() && self.emit_decorator_metadata {
+        let types = self.param_types_expr(&constructor.value.params);
+        decorators.push(self.metadata("design:paramtypes", types));
+      }
+    }
+    decorators
+  }
+
+  /// The `__decorateClass` calls of the members, instance members first.
+  fn member_decorations(
+    &mut self,
+    class: &mut Class<'a>,
+    class_name: &Atom<'a>,
+    out: &mut Vec<Statement<'a>>,
+  ) -> Vec<Statement<'a>> {
+    let mut decorations = vec![];
+    for is_static in [false, true] {
+      for element in class.body.body.iter_mut() {
+        let (decorators, key, computed, kind) = match element {
+          ClassElement::MethodDefinition(method)
+            if method.r#static == is_static
+              && method.kind != MethodDefinitionKind::Constructor
+              && method.value.body.is_some() =>
+          {
+            let mut decorators = self.take_decorators(&mut method.decorators);
+            decorators.extend(self.take_param_decorators(&mut method.value.params));
+            if decorators.is_empty() {
+              continue;
+            }
+            if self.emit_decorator_metadata {
+              decorators.extend(self.method_metadata(method));
+            }
+            let computed = method.computed;
+            (decorators, &mut method.key, computed, METHOD_KIND)
+          }
+          ClassElement::PropertyDefinition(prop)
+            if prop.r#static == is_static && !prop.declare && !prop.decorators.is_empty() =>
+          {
+            let mut decorators = self.take_decorators(&mut prop.decorators);
+            if self.emit_decorator_metadata {
+              let ty = self.type_annotation_expr(prop.type_annotation.as_deref());
+              decorators.push(self.metadata("design:type", ty));
+            }
+            let computed = prop.computed;
+            (decorators, &mut prop.key, computed, FIELD_KIND)
+          }
+          _ => continue,
+        };
+        let Some(key) = self.decoration_key(key, computed, out) else {
+          continue;
+        };
+        let target = if is_static {
+          self.id_ref_expr(class_name)
+        } else {
+          Expression::from(self.builder.member_expression_static(
+            SPAN,
+            self.id_ref_expr(class_name),
+            self.builder.identifier_name(SPAN, "prototype"),
+            false,
+          ))
+        };
+        let decorators = self.array_expr(decorators);
+        let kind = self.number_expr(kind);
+        let call = self.helper_call("__decorateClass", [decorators, target, key, kind]);
+        decorations.push(self.builder.statement_expression(SPAN, call));
+      }
+    }
+    decorations
+  }
+
+  /// The key of a decorated member as passed to `__decorateClass`. Computed keys are evaluated
+  /// once, into a variable declared before the class.
+  fn decoration_key(
+    &mut self,
+    key: &mut PropertyKey<'a>,
+    computed: bool,
+    out: &mut Vec<Statement<'a>>,
+  ) -> Option<Expression<'a>> {
+    match key {
+      PropertyKey::PrivateIdentifier(_) => None,
+      PropertyKey::StaticIdentifier(id) if !computed => {
+        Some(self.builder.expression_string_literal(SPAN, id.name.as_str()))
+      }
+      PropertyKey::StringLiteral(lit) => {
+        Some(self.builder.expression_string_literal(SPAN, lit.value.as_str()))
+      }
+      PropertyKey::NumericLiteral(lit) => Some(self.number_expr(lit.value)),
+      _ => {
+        let expr = key.as_expression_mut()?;
+        let name = self.generate_name("_key");
+        out.push(Statement::VariableDeclaration(self.builder.alloc_variable_declaration(
+          SPAN,
+          VariableDeclarationKind::Var,
+          self.builder.vec1(self.builder.variable_declarator(
+            SPAN,
+            VariableDeclarationKind::Var,
+            self.binding_pattern(&name),
+            None,
+            false,
+          )),
+          false,
+        )));
+        let value = mem::replace(expr, self.builder.void_0(SPAN));
+        *expr = self.builder.expression_assignment(
+          SPAN,
+          AssignmentOperator::Assign,
+          AssignmentTarget::AssignmentTargetIdentifier(
+            self.builder.alloc_identifier_reference(SPAN, name.as_str()),
+          ),
+          value,
+        );
+        Some(self.id_ref_expr(&name))
+      }
+    }
+  }
+
+  fn binding_pattern(&self, name: &Atom<'a>) -> BindingPattern<'a> {
+    self.builder.binding_pattern(
+      self.builder.binding_pattern_kind_binding_identifier(SPAN, name.as_str()),
+      NONE,
+      false,
+    )
+  }
+
+  fn id_ref_expr(&self, name: &str) -> Expression<'a> {
+    self.builder.expression_identifier_reference(SPAN, self.builder.atom(name))
+  }
+
+  fn metadata(&mut self, key: &'static str, value: Expression<'a>) -> Expression<'a> {
+    let key = self.builder.expression_string_literal(SPAN, key);
+    self.helper_call("__decorateMetadata", [key, value])
+  }
+
+  fn method_metadata(&mut self, method: &MethodDefinition<'a>) -> Vec<Expression<'a>> {
+    let function = &method.value;
+    match method.kind {
+      MethodDefinitionKind::Get => {
+        let ty = self.type_annotation_expr(function.return_type.as_deref());
+        let param_types = self.array_expr([]);
+        vec![self.metadata("design:type", ty), self.metadata("design:paramtypes", param_types)]
+      }
+      MethodDefinitionKind::Set => {
+        let param_types = self.param_types_expr(&function.params);
+        let ty = self.type_annotation_expr(
+          function.params.items.first().and_then(|param| param_type_annotation(&param.pattern)),
+        );
+        vec![self.metadata("design:type", ty), self.metadata("design:paramtypes", param_types)]
+      }
+      _ => {
+        let ty = self.serialized_type_expr(SerializedType::Global("Function"));
+        let param_types = self.param_types_expr(&function.params);
+        let return_type = match &function.return_type {
+          Some(annotation) => {
+            self.serialized_type_expr(serialize_type(&annotation.type_annotation))
+          }
+          None if function.r#async => self.serialized_type_expr(SerializedType::Global("Promise")),
+          None => self.builder.void_0(SPAN),
+        };
+        vec![
+          self.metadata("design:type", ty),
+          self.metadata("design:paramtypes", param_types),
+          self.metadata("design:returntype", return_type),
+        ]
+      }
+    }
+  }
+
+  fn param_types_expr(&self, params: &FormalParameters<'a>) -> Expression<'a> {
+    let mut types = params
+      .items
+      .iter()
+      .map(|param| self.type_annotation_expr(param_type_annotation(&param.pattern)))
+      .collect::<Vec<_>>();
+    if let Some(rest) = &params.rest {
+      // `...args: T[]` is serialized as `T`.
+      let ty = match rest.argument.type_annotation.as_deref().map(|a| &a.type_annotation) {
+        Some(TSType::TSArrayType(array)) => serialize_type(&array.element_type),
+        Some(ty) => serialize_type(ty),
+        None => SerializedType::Global("Object"),
+      };
+      types.push(self.serialized_type_expr(ty));
+    }
+    self.array_expr(types)
+  }
+
+  fn type_annotation_expr(&self, annotation: Option<&TSTypeAnnotation<'a>>) -> Expression<'a> {
+    let ty = annotation.map_or(SerializedType::Global("Object"), |annotation| {
+      serialize_type(&annotation.type_annotation)
+    });
+    self.serialized_type_expr(ty)
+  }
+
+  fn serialized_type_expr(&self, ty: SerializedType<'a>) -> Expression<'a> {
+    match ty {
+      SerializedType::Void => self.builder.void_0(SPAN),
+      SerializedType::Global(name) => self.id_ref_expr(name),
+      // `typeof A === "undefined" ? Object : A`
+      SerializedType::Reference(name) => self.builder.expression_conditional(
+        SPAN,
+        self.builder.expression_binary(
+          SPAN,
+          self.builder.expression_unary(SPAN, UnaryOperator::Typeof, self.id_ref_expr(&name)),
+          BinaryOperator::StrictEquality,
+          self.builder.expression_string_literal(SPAN, "undefined"),
+        ),
+        self.id_ref_expr("Object"),
+        self.id_ref_expr(&name),
+      ),
+    }
+  }
+}
+
+/// Whether `stmt` declares a class with decorators, which might be exported.
+fn is_decorated_class_stmt(stmt: &Statement) -> bool {
+  match stmt {
+    Statement::ClassDeclaration(class) => is_decorated(class),
+    Statement::ExportNamedDeclaration(export) => match &export.declaration {
+      Some(Declaration::ClassDeclaration(class)) => is_decorated(class),
+      _ => false,
+    },
+    Statement::ExportDefaultDeclaration(export) => match &export.declaration {
+      ExportDefaultDeclarationKind::ClassDeclaration(class) => is_decorated(class),
+      _ => false,
+    },
+    _ => false,
+  }
+}
+
+fn is_decorated(class: &Class) -> bool {
+  if class.declare {
+    return false;
+  }
+  !class.decorators.is_empty()
+    || class.body.body.iter().any(|element| match element {
+      ClassElement::MethodDefinition(method) => {
+        !method.decorators.is_empty()
+          || method.value.params.items.iter().any(|param| !param.decorators.is_empty())
+      }
+      ClassElement::PropertyDefinition(prop) => !prop.decorators.is_empty(),
+      _ => false,
+    })
+}
+
+fn param_type_annotation<'p, 'a>(
+  pattern: &'p BindingPattern<'a>,
+) -> Option<&'p TSTypeAnnotation<'a>> {
+  pattern.type_annotation.as_deref().or_else(|| match &pattern.kind {
+    // `a: number = 1`
+    BindingPatternKind::AssignmentPattern(assignment) => assignment.left.type_annotation.as_deref(),
+    _ => None,
+  })
+}
+
+fn serialize_type<'a>(ty: &TSType<'a>) -> SerializedType<'a> {
+  match ty {
+    TSType::TSVoidKeyword(_)
+    | TSType::TSUndefinedKeyword(_)
+    | TSType::TSNullKeyword(_)
+    | TSType::TSNeverKeyword(_) => SerializedType::Void,
+    TSType::TSNumberKeyword(_) => SerializedType::Global("Number"),
+    TSType::TSStringKeyword(_) | TSType::TSTemplateLiteralType(_) => {
+      SerializedType::Global("String")
+    }
+    TSType::TSBooleanKeyword(_) => SerializedType::Global("Boolean"),
+    TSType::TSSymbolKeyword(_) => SerializedType::Global("Symbol"),
+    // `BigInt` doesn't exist in older runtimes.
+    TSType::TSBigIntKeyword(_) => SerializedType::Reference(Atom::from("BigInt")),
+    TSType::TSArrayType(_) | TSType::TSTupleType(_) => SerializedType::Global("Array"),
+    TSType::TSFunctionType(_) | TSType::TSConstructorType(_) => SerializedType::Global("Function"),
+    TSType::TSParenthesizedType(paren) => serialize_type(&paren.type_annotation),
+    TSType::TSTypeOperatorType(operator) => serialize_type(&operator.type_annotation),
+    TSType::TSLiteralType(literal) => match &literal.literal {
+      TSLiteral::BooleanLiteral(_) => SerializedType::Global("Boolean"),
+      TSLiteral::NumericLiteral(_) | TSLiteral::UnaryExpression(_) => {
+        SerializedType::Global("Number")
+      }
+      TSLiteral::StringLiteral(_) | TSLiteral::TemplateLiteral(_) => {
+        SerializedType::Global("String")
+      }
+      TSLiteral::BigIntLiteral(_) => SerializedType::Reference(Atom::from("BigInt")),
+      TSLiteral::NullLiteral(_) => SerializedType::Void,
+      TSLiteral::RegExpLiteral(_) => SerializedType::Global("Object"),
+    },
+    TSType::TSUnionType(union) => serialize_union(union.types.iter()),
+    TSType::TSIntersectionType(intersection) => serialize_union(intersection.types.iter()),
+    TSType::TSTypeReference(reference) => match &reference.type_name {
+      TSTypeName::IdentifierReference(id) => SerializedType::Reference(id.name.clone()),
+      TSTypeName::QualifiedName(_) => SerializedType::Global("Object"),
+    },
+    _ => SerializedType::Global("Object"),
+  }
+}
+
+/// A union is serialized as the type of its members if they're all the same, ignoring `null` and
+/// `undefined`, and as `Object` otherwise.
+fn serialize_union<'p, 'a: 'p>(types: impl Iterator<Item = &'p TSType<'a>>) -> SerializedType<'a> {
+  let mut serialized = SerializedType::Void;
+  for ty in types {
+    let ty = serialize_type(ty);
+    if ty == SerializedType::Void || ty == serialized {
+      continue;
+    }
+    if serialized != SerializedType::Void {
+      return SerializedType::Global("Object");
+    }
+    serialized = ty;
+  }
+  serialized
+}
+
+impl<'a, 'me> VisitMut<'a> for LegacyDecorators<'a, 'me> {
+  fn visit_program(&mut self, it: &mut Program<'a>) {
+    walk_mut::walk_program(self, it);
+    if self.used_helpers.is_empty() {
+      return;
+    }
+    // import { __decorateClass } from "rolldown:runtime";
+    let builder = self.builder;
+    let specifiers = builder.vec_from_iter(self.used_helpers.iter().map(|helper| {
+      builder.import_declaration_specifier_import_specifier(
+        SPAN,
+        builder.module_export_name_identifier_name(SPAN, *helper),
+        builder.binding_identifier(SPAN, *helper),
+        ImportOrExportKind::Value,
+      )
+    }));
+    it.body.insert(
+      0,
+      Statement::ImportDeclaration(builder.alloc_import_declaration(
+        SPAN,
+        Some(specifiers),
+        builder.string_literal(SPAN, RUNTIME_MODULE_ID),
+        NONE,
+        ImportOrExportKind::Value,
+      )),
+    );
+  }
+
+  fn visit_statements(&mut self, it: &mut allocator::Vec<'a, Statement<'a>>) {
+    walk_mut::walk_statements(self, it);
+    let has_decorated_class = it.iter().any(is_decorated_class_stmt);
+    if !has_decorated_class {
+      return;
+    }
+    let mut stmts = vec![];
+    for stmt in mem::replace(it, self.builder.vec()) {
+      self.transform_statement(stmt, &mut stmts);
+    }
+    it.extend(stmts);
+  }
+}
@@ -1,9 +1,35 @@
 use oxc::{
-  ast::{visit::walk_mut, VisitMut},
+  ast::{
+    ast::{Decorator, Program},
+    visit::walk_mut,
+    Visit, VisitMut,
+  },
   span::{GetSpanMut, Span},
 };
 use rustc_hash::FxHashSet;
 
+mod dropper;
+mod legacy_decorators;
+
+pub use dropper::Dropper;
+pub use legacy_decorators::LegacyDecorators;
+
+/// Returns the span of the first decorator of the program.
+pub fn find_first_decorator(program: &Program) -> Option<Span> {
+  #[derive(Default)]
+  struct FirstDecorator(Option<Span>);
+
+  impl<'a> Visit<'a> for FirstDecorator {
+    fn visit_decorator(&mut self, it: &Decorator<'a>) {
+      self.0.get_or_insert(it.span);
+    }
+  }
+
+  let mut visitor = FirstDecorator::default();
+  visitor.visit_program(program);
+  visitor.0
+}
+
 /// Make sure there aren't any duplicate spans in the AST.
 pub struct EnsureSpanUniqueness {
   // visited_spans: FxHashMap</* start */ u32, /* ends */ FxHashSet<u32>>,
@@ -27,6 +53,11 @@ impl<'a> VisitMut<'a> for EnsureSpanUniqueness {
     self.ensure_uniqueness(it.span_mut());
     walk_mut::walk_import_expression(self, it);
   }
+
+  fn visit_new_expression(&mut self, it: &mut oxc::ast::ast::NewExpression<'a>) {
+    self.ensure_uniqueness(it.span_mut());
+    walk_mut::walk_new_expression(self, it);
+  }
 }
 
 impl EnsureSpanUniqueness {
@@ -10,12 +10,12 @@ const HASH_PLACEHOLDER_LEFT: &str = "!~{";
 const HASH_PLACEHOLDER_RIGHT: &str = "}~";
 const HASH_PLACEHOLDER_OVERHEAD: usize = HASH_PLACEHOLDER_LEFT.len() + HASH_PLACEHOLDER_RIGHT.len();
 
-// This is the size of a 128-bits xxhash with base64url encoding
-const MAX_HASH_SIZE: usize = 22;
+// This is the size of a 128-bits xxhash with hex encoding, which is the longest `output.hashCharacters`
+const MAX_HASH_SIZE: usize = 32;
 // const DEFAULT_HASH_SIZE: usize = 8;
 
 static REPLACER_REGEX: LazyLock<Regex> = LazyLock::new(|| {
-  let pattern = "!~\\{[0-9a-zA-Z_$]{1,17}\\}~";
+  let pattern = "!~\\{[0-9a-zA-Z_$]{1,27}\\}~";
   Regex::new(pattern).expect("failed to compile regex")
 });
 
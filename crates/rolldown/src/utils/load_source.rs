@@ -1,8 +1,10 @@
 use rolldown_common::{
-  side_effects::HookSideEffects, ModuleType, NormalizedBundlerOptions, ResolvedId, StrOrBytes,
+  side_effects::HookSideEffects, ImportAttributes, ModuleType, NormalizedBundlerOptions,
+  ResolvedId, StrOrBytes,
 };
 use rolldown_plugin::{HookLoadArgs, PluginDriver};
 use rolldown_sourcemap::SourceMap;
+use rolldown_utils::virtual_module::is_virtual_module_id;
 use rustc_hash::FxHashMap;
 use sugar_path::SugarPath;
 
@@ -13,9 +15,10 @@ pub async fn load_source(
   sourcemap_chain: &mut Vec<SourceMap>,
   side_effects: &mut Option<HookSideEffects>,
   options: &NormalizedBundlerOptions,
+  attributes: Option<&ImportAttributes>,
 ) -> anyhow::Result<(StrOrBytes, ModuleType)> {
   let (maybe_source, maybe_module_type) = if let Some(load_hook_output) =
-    plugin_driver.load(&HookLoadArgs { id: &resolved_id.id }).await?
+    plugin_driver.load(&HookLoadArgs { id: &resolved_id.id, attributes }).await?
   {
     sourcemap_chain.extend(load_hook_output.map);
     if let Some(v) = load_hook_output.side_effects {
@@ -25,6 +28,10 @@ pub async fn load_source(
     (Some(load_hook_output.code), load_hook_output.module_type)
   } else if resolved_id.ignored {
     (Some(String::new()), Some(ModuleType::Js))
+  } else if is_virtual_module_id(&resolved_id.id) {
+    anyhow::bail!(
+      "virtual modules aren't read from the file system, but no plugin returned its content in the `load` hook"
+    );
   } else {
     (None, None)
   };
@@ -32,7 +39,10 @@ pub async fn load_source(
   match (maybe_source, maybe_module_type) {
     (Some(source), Some(module_type)) => Ok((source.into(), module_type)),
     (source, None) => {
-      let guessed = get_module_loader_from_file_extension(&resolved_id.id, &options.module_types);
+      // `with { type: 'json' }` loads the module as json, regardless of its extension.
+      let guessed = attributes
+        .and_then(ImportAttributes::module_type)
+        .or_else(|| get_module_loader_from_file_extension(&resolved_id.id, &options.module_types));
       match (source, guessed) {
         (None, None) => {
           // - Unknown module type,
@@ -41,7 +51,12 @@ pub async fn load_source(
           Ok((StrOrBytes::Str(fs.read_to_string(resolved_id.id.as_path())?), ModuleType::Js))
         }
         (source, Some(guessed)) => match &guessed {
-          ModuleType::Base64 | ModuleType::Binary | ModuleType::Dataurl => Ok((
+          ModuleType::Base64
+          | ModuleType::Binary
+          | ModuleType::Dataurl
+          | ModuleType::File
+          | ModuleType::Copy
+          | ModuleType::Wasm => Ok((
             StrOrBytes::Bytes({
               source
                 .map(String::into_bytes)
@@ -73,7 +88,7 @@ pub async fn load_source(
 }
 
 /// ref: https://github.com/evanw/esbuild/blob/9c13ae1f06dfa909eb4a53882e3b7e4216a503fe/internal/bundler/bundler.go#L1161-L1183
-fn get_module_loader_from_file_extension<S: AsRef<str>>(
+pub fn get_module_loader_from_file_extension<S: AsRef<str>>(
   id: S,
   module_types: &FxHashMap<String, ModuleType>,
 ) -> Option<ModuleType> {
@@ -0,0 +1,12 @@
+use rolldown_common::LogLevel;
+use rolldown_error::Severity;
+
+/// Whether diagnostics of `severity` should be reported under `log_level`. Errors are always reported.
+pub fn is_severity_enabled(log_level: LogLevel, severity: Severity) -> bool {
+  match severity {
+    Severity::Error => true,
+    Severity::Warn => log_level >= LogLevel::Warn,
+    Severity::Info => log_level >= LogLevel::Info,
+    Severity::Debug => log_level >= LogLevel::Debug,
+  }
+}
@@ -9,19 +9,24 @@ pub mod apply_inner_plugins;
 pub mod augment_chunk_hash;
 pub mod call_expression_ext;
 pub mod chunk;
+pub mod collect_const_enums;
+pub mod collect_constants;
 pub mod ecma_visitors;
 pub mod extract_meaningful_input_name_from_path;
 pub mod hash_placeholder;
 pub mod load_source;
+pub mod log_level;
 pub mod make_ast_symbol_and_scope;
 pub mod normalize_options;
 pub mod parse_to_ecma_ast;
 pub mod pre_process_ecma_ast;
+pub mod public_dir;
 pub mod renamer;
 pub mod render_chunks;
 pub mod render_ecma_module;
 pub mod resolve_id;
 pub mod transform_source;
+pub mod tsconfig;
 pub mod tweak_ast_for_scanning;
 
 #[tracing::instrument(level = "trace", skip_all)]
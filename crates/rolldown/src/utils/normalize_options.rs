@@ -1,7 +1,14 @@
 use oxc::transformer::InjectGlobalVariablesConfig;
-use rolldown_common::{InjectImport, ModuleType, NormalizedBundlerOptions, Platform};
+use rolldown_common::{
+  DecoratorOptions, DropKind, InjectImport, ModuleType, NormalizedBundlerOptions, Platform,
+  PublicPath, RawMinifyOptions,
+};
 use rustc_hash::FxHashMap;
 
+use super::tsconfig::{
+  decorator_options_from_tsconfig, jsx_options_from_tsconfig, read_tsconfig_compiler_options,
+};
+
 pub struct NormalizeOptionsReturn {
   pub options: NormalizedBundlerOptions,
   pub resolve_options: rolldown_resolver::ResolveOptions,
@@ -25,7 +32,13 @@ pub fn normalize_options(mut raw_options: crate::BundlerOptions) -> NormalizeOpt
       ("tsx".to_string(), ModuleType::Tsx),
       ("json".to_string(), ModuleType::Json),
       ("txt".to_string(), ModuleType::Text),
+      ("wasm".to_string(), ModuleType::Wasm),
       ("css".to_string(), ModuleType::Css),
+      ("scss".to_string(), ModuleType::Custom("scss".to_string())),
+      ("sass".to_string(), ModuleType::Custom("sass".to_string())),
+      ("less".to_string(), ModuleType::Custom("less".to_string())),
+      ("styl".to_string(), ModuleType::Custom("stylus".to_string())),
+      ("stylus".to_string(), ModuleType::Custom("stylus".to_string())),
     ]
     .into_iter()
     .collect(),
@@ -74,23 +87,36 @@ pub fn normalize_options(mut raw_options: crate::BundlerOptions) -> NormalizeOpt
       .unwrap_or_default(),
   );
 
+  let cwd =
+    raw_options.cwd.unwrap_or_else(|| std::env::current_dir().expect("Failed to get current dir"));
+
+  let tsconfig_compiler_options = raw_resolve
+    .tsconfig_filename
+    .as_ref()
+    .and_then(|filename| read_tsconfig_compiler_options(&cwd.join(filename)));
+
+  let minify = raw_options.minify.and_then(RawMinifyOptions::normalize);
+  // https://github.com/evanw/esbuild/blob/d34e79e2a998c21bb71d57b92b0017ca11756912/internal/bundler/bundler.go#L2767
+  let profiler_names = raw_options.profiler_names.unwrap_or(minify.is_none());
+
+  let drop = raw_options.drop.unwrap_or_default();
+
   let normalized = NormalizedBundlerOptions {
     input: raw_options.input.unwrap_or_default(),
-    cwd: raw_options
-      .cwd
-      .unwrap_or_else(|| std::env::current_dir().expect("Failed to get current dir")),
     external: raw_options.external,
     treeshake: raw_options.treeshake,
     platform,
     name: raw_options.name,
+    no_conflict: raw_options.no_conflict.unwrap_or(false),
     entry_filenames: raw_options.entry_filenames.unwrap_or_else(|| "[name].js".to_string().into()),
     chunk_filenames: raw_options
       .chunk_filenames
       .unwrap_or_else(|| "[name]-[hash].js".to_string().into()),
     asset_filenames: raw_options
       .asset_filenames
-      .unwrap_or_else(|| "assets/[name]-[hash][extname]".to_string())
-      .into(),
+      .unwrap_or_else(|| "assets/[name]-[hash][extname]".to_string().into()),
+    hash_characters: raw_options.hash_characters.unwrap_or_default(),
+    sanitize_filename: raw_options.sanitize_filename.unwrap_or_default(),
     css_entry_filenames: raw_options
       .css_entry_filenames
       .unwrap_or_else(|| "[name].css".to_string().into()),
@@ -102,29 +128,76 @@ pub fn normalize_options(mut raw_options: crate::BundlerOptions) -> NormalizeOpt
     intro: raw_options.intro,
     outro: raw_options.outro,
     es_module: raw_options.es_module.unwrap_or_default(),
+    interop: raw_options.interop.unwrap_or_default(),
     dir: raw_options.dir.unwrap_or_else(|| "dist".to_string()),
+    public_dir: raw_options.public_dir,
     format: raw_options.format.unwrap_or(crate::OutputFormat::Esm),
     exports: raw_options.exports.unwrap_or(crate::OutputExports::Auto),
     globals,
+    generated_code: raw_options.generated_code.unwrap_or_default(),
     sourcemap: raw_options.sourcemap,
     sourcemap_ignore_list: raw_options.sourcemap_ignore_list,
     sourcemap_path_transform: raw_options.sourcemap_path_transform,
     shim_missing_exports: raw_options.shim_missing_exports.unwrap_or(false),
     module_types: loaders,
+    assets_inline_limit: raw_options.assets_inline_limit,
     experimental: raw_options.experimental.unwrap_or_default(),
-    minify: raw_options.minify.unwrap_or(false),
+    minify,
     define: raw_options.define.map(|inner| inner.into_iter().collect()).unwrap_or_default(),
     inject: raw_options.inject.unwrap_or_default(),
     oxc_inject_global_variables_config,
     extend: raw_options.extend.unwrap_or(false),
+    compact: raw_options.compact.unwrap_or(false),
+    strict: raw_options.strict,
     external_live_bindings: raw_options.external_live_bindings.unwrap_or(true),
+    external_import_attributes: raw_options.external_import_attributes.unwrap_or(true),
+    freeze: raw_options.freeze.unwrap_or(true),
+    dynamic_import_in_cjs: raw_options.dynamic_import_in_cjs.unwrap_or(true),
     inline_dynamic_imports: raw_options.inline_dynamic_imports.unwrap_or(false),
+    manual_chunks: raw_options.manual_chunks,
     advanced_chunks: raw_options.advanced_chunks,
+    experimental_min_chunk_size: raw_options.experimental_min_chunk_size,
+    chunk_name_strategy: raw_options.chunk_name_strategy.unwrap_or_default(),
+    chunk_size_warning_limit: raw_options.chunk_size_warning_limit.unwrap_or_default(),
+    runtime_chunk: raw_options.runtime_chunk.unwrap_or(false),
+    keep_names: raw_options.keep_names.unwrap_or(false),
+    target: raw_options.target.unwrap_or_default(),
+    legal_comments: raw_options.legal_comments.unwrap_or_default(),
+    charset: raw_options.charset.unwrap_or_default(),
     checks: raw_options.checks.unwrap_or_default(),
-    // https://github.com/evanw/esbuild/blob/d34e79e2a998c21bb71d57b92b0017ca11756912/internal/bundler/bundler.go#L2767
-    profiler_names: raw_options.profiler_names.unwrap_or(!raw_options.minify.unwrap_or(false)),
-    jsx: raw_options.jsx,
+    profiler_names,
+    jsx: raw_options.jsx.or_else(|| jsx_options_from_tsconfig(tsconfig_compiler_options.as_ref()?)),
+    decorator: {
+      let raw_decorator = raw_options.decorator.unwrap_or_default();
+      let tsconfig_decorator =
+        tsconfig_compiler_options.as_ref().map(decorator_options_from_tsconfig).unwrap_or_default();
+      DecoratorOptions {
+        legacy: raw_decorator.legacy.or(tsconfig_decorator.legacy),
+        emit_decorator_metadata: raw_decorator
+          .emit_decorator_metadata
+          .or(tsconfig_decorator.emit_decorator_metadata),
+      }
+    },
+    json: raw_options.json.unwrap_or_default(),
+    wasm: raw_options.wasm.unwrap_or_default(),
+    commonjs: raw_options.commonjs.unwrap_or_default(),
     watch: raw_options.watch.unwrap_or_default(),
+    ses_compatible: raw_options.ses_compatible.unwrap_or(false),
+    css_mode: raw_options.css_mode.unwrap_or_default(),
+    css_banner: raw_options.css_banner,
+    css_footer: raw_options.css_footer,
+    css_target: raw_options.css_target,
+    preserve_modules: raw_options.preserve_modules.unwrap_or(false),
+    preserve_modules_root: raw_options.preserve_modules_root.map(|root| cwd.join(root)),
+    base: raw_options.base.map(PublicPath::from),
+    log_level: raw_options.log_level.unwrap_or_default(),
+    cwd,
+    on_log: raw_options.on_log,
+    bail: raw_options.bail.unwrap_or(true),
+    error_recovery: raw_options.error_recovery.unwrap_or(false),
+    drop_console: drop.contains(&DropKind::Console),
+    drop_debugger: drop.contains(&DropKind::Debugger),
+    drop_labels: raw_options.drop_labels.unwrap_or_default(),
   };
 
   NormalizeOptionsReturn { options: normalized, resolve_options: raw_resolve }
@@ -3,15 +3,16 @@ use std::path::Path;
 use arcstr::ArcStr;
 use oxc::{
   semantic::{ScopeTree, SymbolTable},
-  span::SourceType as OxcSourceType,
+  span::{CompactStr, SourceType as OxcSourceType},
   transformer::ReplaceGlobalDefinesConfig,
 };
-use rolldown_common::{ModuleType, NormalizedBundlerOptions, StrOrBytes};
+use rolldown_common::{ConstEnumMembers, ModuleType, NormalizedBundlerOptions, StrOrBytes};
 use rolldown_ecmascript::{EcmaAst, EcmaCompiler};
 use rolldown_error::{BuildDiagnostic, BuildResult, Severity};
 use rolldown_loader_utils::{binary_to_esm, json_to_esm, text_to_string_literal};
 use rolldown_plugin::{HookTransformAstArgs, PluginDriver};
 use rolldown_utils::mime::guess_mime;
+use rustc_hash::FxHashMap;
 
 use super::pre_process_ecma_ast::PreProcessEcmaAst;
 
@@ -33,6 +34,7 @@ pub struct ParseToEcmaAstResult {
   pub scope_tree: ScopeTree,
   pub has_lazy_export: bool,
   pub warning: Vec<BuildDiagnostic>,
+  pub const_enums: FxHashMap<CompactStr, ConstEnumMembers>,
 }
 
 pub fn parse_to_ecma_ast(
@@ -52,11 +54,14 @@ pub fn parse_to_ecma_ast(
     ModuleType::Ts => (source.try_into_string()?, OxcParseType::Ts),
     ModuleType::Tsx => (source.try_into_string()?, OxcParseType::Tsx),
     ModuleType::Css => {
-      let content = "export {}".to_string();
+      // Css modules export their classes, while other stylesheets don't export anything.
+      let content = source.try_into_string()?;
+      let content = if content.is_empty() { "export {}".to_string() } else { content };
       (content, OxcParseType::Js)
     }
     ModuleType::Json => {
-      let content = json_to_esm(&source.try_into_string()?)?;
+      let source = source.try_into_string()?;
+      let content = json_to_esm(&source, options.json.stringify(source.len()))?;
       (content, OxcParseType::Js)
     }
     ModuleType::Text => {
@@ -82,7 +87,19 @@ pub fn parse_to_ecma_ast(
       let encoded = rolldown_utils::base64::to_standard_base64(source);
       (binary_to_esm(&encoded, options.platform, RUNTIME_MODULE_ID), OxcParseType::Js)
     }
+    ModuleType::File => {
+      // The module task has replaced the content with the expression of the emitted file's url.
+      has_lazy_export = true;
+      (source.try_into_string()?, OxcParseType::Js)
+    }
+    ModuleType::Wasm => {
+      // The module task has replaced the content with the glue code instantiating the binary.
+      (source.try_into_string()?, OxcParseType::Js)
+    }
     ModuleType::Empty => (String::new(), OxcParseType::Js),
+    ModuleType::Copy => {
+      unreachable!("Copied files are external modules, see `ModuleTask::run_inner`")
+    }
     ModuleType::Custom(custom_type) => {
       // TODO: should provide friendly error message to say that this type is not supported by rolldown.
       // Users should handle this type in load/transform hooks
@@ -109,7 +126,8 @@ pub fn parse_to_ecma_ast(
     id: stable_id,
   })?;
 
-  PreProcessEcmaAst::default()
+  let mut pre_processor = PreProcessEcmaAst::default();
+  pre_processor
     .build(
       ecma_ast,
       &parsed_type,
@@ -126,7 +144,14 @@ pub fn parse_to_ecma_ast(
         )
       },
       |(ast, symbol_table, scope_tree, warning)| {
-        Ok(ParseToEcmaAstResult { ast, symbol_table, scope_tree, has_lazy_export, warning })
+        Ok(ParseToEcmaAstResult {
+          ast,
+          symbol_table,
+          scope_tree,
+          has_lazy_export,
+          warning,
+          const_enums: std::mem::take(&mut pre_processor.const_enums),
+        })
       },
     )
 }
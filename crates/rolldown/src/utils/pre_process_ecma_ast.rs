@@ -1,22 +1,25 @@
 use std::path::Path;
 
 use itertools::Itertools;
-use oxc::ast::VisitMut;
+use oxc::ast::{AstBuilder, VisitMut};
 use oxc::diagnostics::{OxcDiagnostic, Severity as OxcSeverity};
 use oxc::minifier::{CompressOptions, Compressor};
 use oxc::semantic::{ScopeTree, SemanticBuilder, Stats, SymbolTable};
+use oxc::span::CompactStr;
 use oxc::transformer::{
   InjectGlobalVariables, ReplaceGlobalDefines, ReplaceGlobalDefinesConfig, TransformOptions,
   Transformer,
 };
 
-use rolldown_common::NormalizedBundlerOptions;
+use rolldown_common::{ConstEnumMembers, NormalizedBundlerOptions};
 use rolldown_ecmascript::{EcmaAst, WithMutFields};
 use rolldown_error::{BuildDiagnostic, Severity};
+use rustc_hash::FxHashMap;
 
 use crate::types::oxc_parse_type::OxcParseType;
 
-use super::ecma_visitors::EnsureSpanUniqueness;
+use super::collect_const_enums::{collect_const_enums, top_level_enums};
+use super::ecma_visitors::{find_first_decorator, Dropper, EnsureSpanUniqueness, LegacyDecorators};
 use super::tweak_ast_for_scanning::tweak_ast_for_scanning;
 
 #[derive(Default)]
@@ -26,6 +29,10 @@ pub struct PreProcessEcmaAst {
 
   /// Semantic statistics.
   stats: Stats,
+
+  /// The top-level `const enum`s of TypeScript modules, keyed by their names. They are collected before
+  /// the transformer turns them into plain objects.
+  pub const_enums: FxHashMap<CompactStr, ConstEnumMembers>,
 }
 
 impl PreProcessEcmaAst {
@@ -51,13 +58,23 @@ impl PreProcessEcmaAst {
         semantic_ret.errors,
         &source,
         path,
-        &Severity::Warning,
+        &Severity::Warn,
       ));
     }
 
     self.stats = semantic_ret.semantic.stats();
     let (mut symbols, mut scopes) = semantic_ret.semantic.into_symbol_table_and_scope_tree();
 
+    if matches!(parse_type, OxcParseType::Ts | OxcParseType::Tsx) {
+      self.collect_const_enums(&ast, &symbols, path, &mut warning);
+    }
+
+    if let Some(semantic) =
+      self.transform_decorators(&mut ast, parse_type, path, bundle_options, &mut warning)
+    {
+      (symbols, scopes) = semantic;
+    }
+
     // Transform TypeScript and jsx.
     if !matches!(parse_type, OxcParseType::Js) {
       let ret = ast.program.with_mut(move |fields| {
@@ -65,13 +82,15 @@ impl PreProcessEcmaAst {
         match parse_type {
           OxcParseType::Js => unreachable!("Should not reach here"),
           OxcParseType::Jsx | OxcParseType::Tsx => {
+            // Pragma comments, such as `/** @jsxImportSource preact */`, still override these options
+            // per module.
+            if let Some(jsx) = &bundle_options.jsx {
+              transformer_options.jsx = jsx.clone();
+            }
             transformer_options.jsx.jsx_plugin = true;
           }
           OxcParseType::Ts => {}
         }
-        if let Some(jsx) = &bundle_options.jsx {
-          transformer_options.jsx = jsx.clone();
-        }
 
         Transformer::new(fields.allocator, Path::new(path), transformer_options)
           .build_with_symbols_and_scopes(symbols, scopes, fields.program)
@@ -115,6 +134,14 @@ impl PreProcessEcmaAst {
         self.ast_changed = true;
       }
 
+      if bundle_options.drop_console
+        || bundle_options.drop_debugger
+        || !bundle_options.drop_labels.is_empty()
+      {
+        Dropper::new(AstBuilder::new(allocator), &symbols, bundle_options).visit_program(program);
+        self.ast_changed = true;
+      }
+
       if bundle_options.treeshake.enabled() {
         // Perform dead code elimination.
         // NOTE: `CompressOptions::dead_code_elimination` will remove `ParenthesizedExpression`s from the AST.
@@ -148,4 +175,82 @@ impl PreProcessEcmaAst {
 
     Ok((ast, symbols, scopes, warning))
   }
+
+  /// Collects the top-level `const enum`s, and warns about ambient ones that are used as values, as the
+  /// transformer removes them and leaves their usages to globals.
+  fn collect_const_enums(
+    &mut self,
+    ast: &EcmaAst,
+    symbols: &SymbolTable,
+    path: &str,
+    warning: &mut Vec<BuildDiagnostic>,
+  ) {
+    ast.program.with_dependent(|_owner, dep| {
+      self.const_enums = collect_const_enums(&dep.program);
+      for decl in top_level_enums(&dep.program).filter(|decl| decl.r#const && decl.declare) {
+        let Some(symbol_id) = decl.id.symbol_id.get() else { continue };
+        let is_used_as_value = symbols
+          .get_resolved_reference_ids(symbol_id)
+          .iter()
+          .any(|reference_id| symbols.get_reference(*reference_id).is_value());
+        if is_used_as_value {
+          warning.push(
+            BuildDiagnostic::ambient_const_enum(
+              path.to_string(),
+              ast.source().clone(),
+              decl.id.span,
+              decl.id.name.to_string(),
+            )
+            .with_severity_warning(),
+          );
+        }
+      }
+    });
+  }
+
+  /// Legacy decorators are transformed before TypeScript, as the types of the decorated declarations
+  /// are needed by `emitDecoratorMetadata`. Other decorators are kept as they are, with a warning.
+  ///
+  /// Returns the rebuilt semantic data if the AST is changed.
+  fn transform_decorators(
+    &mut self,
+    ast: &mut EcmaAst,
+    parse_type: &OxcParseType,
+    path: &str,
+    bundle_options: &NormalizedBundlerOptions,
+    warning: &mut Vec<BuildDiagnostic>,
+  ) -> Option<(SymbolTable, ScopeTree)> {
+    let source = ast.source().clone();
+    let transforms_legacy_decorators = matches!(parse_type, OxcParseType::Ts | OxcParseType::Tsx)
+      && bundle_options.decorator.legacy();
+    if !transforms_legacy_decorators {
+      if source.contains('@') {
+        let span = ast.program.with_dependent(|_owner, dep| find_first_decorator(&dep.program));
+        if let Some(span) = span {
+          warning.push(
+            BuildDiagnostic::unsupported_decorators(path.to_string(), source, span)
+              .with_severity_warning(),
+          );
+        }
+      }
+      return None;
+    }
+    let is_transformed = ast.program.with_mut(|fields| {
+      let mut transformer = LegacyDecorators::new(
+        AstBuilder::new(fields.allocator),
+        &source,
+        bundle_options.decorator.emit_decorator_metadata(),
+      );
+      transformer.visit_program(fields.program);
+      transformer.is_transformed()
+    });
+    if !is_transformed {
+      return None;
+    }
+    // The transformer needs the references added for the helpers and the decorated classes.
+    let semantic_ret =
+      ast.program.with_mut(|WithMutFields { program, .. }| SemanticBuilder::new().build(program));
+    self.stats = semantic_ret.semantic.stats();
+    Some(semantic_ret.semantic.into_symbol_table_and_scope_tree())
+  }
 }
@@ -0,0 +1,34 @@
+use std::path::{Path, PathBuf};
+
+use rolldown_fs::FileSystem;
+use sugar_path::SugarPath;
+
+/// Collects the files under `public_dir` recursively, as their absolute paths along with their paths
+/// relative to `public_dir`, which are also their file names in the output. A missing directory has no
+/// files.
+pub fn collect_public_files(
+  fs: &dyn FileSystem,
+  public_dir: &Path,
+) -> anyhow::Result<Vec<(PathBuf, String)>> {
+  if !fs.exists(public_dir) {
+    return Ok(vec![]);
+  }
+  let mut files = vec![];
+  let mut dirs = vec![public_dir.to_path_buf()];
+  while let Some(dir) = dirs.pop() {
+    let entries = fs.read_dir(&dir).map_err(|err| {
+      anyhow::anyhow!("Could not read the public directory {:?}", dir).context(err)
+    })?;
+    for path in entries {
+      if fs.is_dir(&path) {
+        dirs.push(path);
+      } else {
+        let filename = path.relative(public_dir).to_slash_lossy().into_owned();
+        files.push((path, filename));
+      }
+    }
+  }
+  // Entries of directories come in arbitrary order.
+  files.sort_unstable_by(|(_, a), (_, b)| a.cmp(b));
+  Ok(files)
+}
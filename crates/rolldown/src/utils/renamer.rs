@@ -41,6 +41,7 @@ impl<'name> Renamer<'name> {
       OutputFormat::Esm | OutputFormat::App => vec![],
       OutputFormat::Cjs => vec!["module", "require", "__filename", "__dirname", "exports"],
       OutputFormat::Iife | OutputFormat::Umd => vec!["exports"], // Also for  AMD, but we don't support them yet.
+      OutputFormat::System => vec!["exports", "module"],
     };
     Self {
       canonical_names: FxHashMap::default(),
@@ -5,16 +5,22 @@ use rolldown_sourcemap::{collapse_sourcemaps, lines_count, RawSource, Source, So
 pub fn render_ecma_module(
   module: &NormalModule,
   options: &NormalizedBundlerOptions,
-  render_output: CodegenReturn,
+  mut render_output: CodegenReturn,
 ) -> Option<Vec<Box<dyn Source + Send>>> {
   if render_output.code.is_empty() {
     None
   } else {
     let mut sources: Vec<Box<dyn rolldown_sourcemap::Source + Send>> = vec![];
-    sources.push(Box::new(RawSource::new(format!(
-      "//#region {debug_module_id}",
-      debug_module_id = module.debug_id
-    ))));
+    // `output.compact` drops the region comments and the blank line after each module.
+    if options.compact {
+      let trimmed_len = render_output.code.trim_end_matches('\n').len();
+      render_output.code.truncate(trimmed_len);
+    } else {
+      sources.push(Box::new(RawSource::new(format!(
+        "//#region {debug_module_id}",
+        debug_module_id = module.debug_id
+      ))));
+    }
 
     let enable_sourcemap = options.sourcemap.is_some() && !module.is_virtual();
 
@@ -43,7 +49,9 @@ pub fn render_ecma_module(
       sources.push(Box::new(RawSource::new(render_output.code)));
     }
 
-    sources.push(Box::new(RawSource::new("//#endregion".to_string())));
+    if !options.compact {
+      sources.push(Box::new(RawSource::new("//#endregion".to_string())));
+    }
 
     Some(sources)
   }
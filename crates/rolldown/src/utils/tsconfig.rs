@@ -0,0 +1,45 @@
+use std::path::Path;
+
+use oxc::transformer::{JsxOptions, JsxRuntime};
+use rolldown_common::DecoratorOptions;
+use serde_json::Value;
+
+/// Reads `compilerOptions` of a tsconfig, whose options are used when the matching options of rolldown
+/// aren't configured. `extends` isn't followed. Returns `None` if the tsconfig can't be read.
+pub fn read_tsconfig_compiler_options(path: &Path) -> Option<Value> {
+  let mut text = std::fs::read_to_string(path).ok()?;
+  json_strip_comments::strip(&mut text).ok()?;
+  let mut tsconfig: Value = serde_json::from_str(&text).ok()?;
+  Some(tsconfig.get_mut("compilerOptions")?.take())
+}
+
+/// Reads the jsx options from `compilerOptions` of a tsconfig. Returns `None` if it leaves jsx to the
+/// default.
+pub fn jsx_options_from_tsconfig(compiler_options: &Value) -> Option<JsxOptions> {
+  let get_str = |key: &str| compiler_options.get(key).and_then(Value::as_str);
+
+  let mut options = JsxOptions::default();
+  match get_str("jsx")? {
+    "react" => options.runtime = JsxRuntime::Classic,
+    "react-jsx" => options.runtime = JsxRuntime::Automatic,
+    "react-jsxdev" => {
+      options.runtime = JsxRuntime::Automatic;
+      options.development = true;
+    }
+    // `preserve` and `react-native` keep jsx in the output, which isn't supported.
+    _ => return None,
+  }
+  options.pragma = get_str("jsxFactory").map(ToString::to_string);
+  options.pragma_frag = get_str("jsxFragmentFactory").map(ToString::to_string);
+  options.import_source = get_str("jsxImportSource").map(ToString::to_string);
+  Some(options)
+}
+
+/// Reads `experimentalDecorators` and `emitDecoratorMetadata` from `compilerOptions` of a tsconfig.
+pub fn decorator_options_from_tsconfig(compiler_options: &Value) -> DecoratorOptions {
+  let get_bool = |key: &str| compiler_options.get(key).and_then(Value::as_bool);
+  DecoratorOptions {
+    legacy: get_bool("experimentalDecorators"),
+    emit_decorator_metadata: get_bool("emitDecoratorMetadata"),
+  }
+}
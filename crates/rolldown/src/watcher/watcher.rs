@@ -112,7 +112,6 @@ impl Watcher {
 
     self.emitter.emit(WatcherEvent::Event, BundleEventKind::BundleStart.into()).await?;
     bundler.plugin_driver = bundler.plugin_driver.new_shared_from_self();
-    bundler.file_emitter.clear();
 
     let output = {
       if bundler.options.watch.skip_write {
@@ -1,4 +1,4 @@
-use std::path::{Component, PathBuf};
+use std::path::{Component, Path, PathBuf};
 
 use rolldown_common::Output;
 use rolldown_testing::{
@@ -64,3 +64,68 @@ async fn filename_with_hash() {
   let output = format!("```\n{}\n```", snapshot_outputs.join("\n"));
   insta::assert_snapshot!(output);
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn filename_with_hash_is_independent_of_project_root() {
+  let cwd = std::env::current_dir().unwrap();
+  let fixtures_dir = cwd.join("tests/rolldown");
+  let temp_dir = std::env::temp_dir().join(format!("rolldown-hash-{}", std::process::id()));
+  // The copies are nested at different depths, so absolute paths differ in both content and length.
+  let roots = [temp_dir.join("a"), temp_dir.join("b").join("nested").join("project")];
+  for root in &roots {
+    copy_fixtures(&fixtures_dir, root);
+  }
+
+  let mut config_paths = glob::glob("./tests/rolldown/**/_config.json")
+    .unwrap()
+    .map(|p| p.unwrap().relative(&fixtures_dir))
+    .collect::<Vec<_>>();
+  config_paths.sort();
+
+  for path in config_paths {
+    if path.components().map(Component::as_os_str).any(|c| c.to_string_lossy().starts_with('.')) {
+      continue;
+    }
+    let mut outputs = vec![];
+    for root in &roots {
+      let config_path = root.join(&path);
+      let TestConfig { config: mut options, meta } = read_test_config(&config_path);
+      options.cwd = Some(config_path.parent().unwrap().to_path_buf());
+
+      let integration_test =
+        IntegrationTest::new(TestMeta { write_to_disk: false, hash_in_filename: true, ..meta });
+      let mut assets = integration_test
+        .bundle(options)
+        .await
+        .assets
+        .into_iter()
+        .map(|asset| (asset.filename().to_string(), asset.content_as_bytes().to_vec()))
+        .collect::<Vec<_>>();
+      assets.sort();
+      outputs.push(assets);
+    }
+    assert!(
+      outputs[0] == outputs[1],
+      "Output of {} differs between project roots",
+      path.to_slash_lossy()
+    );
+  }
+
+  std::fs::remove_dir_all(temp_dir).unwrap();
+}
+
+fn copy_fixtures(from: &Path, to: &Path) {
+  std::fs::create_dir_all(to).unwrap();
+  for entry in std::fs::read_dir(from).unwrap() {
+    let entry = entry.unwrap();
+    let target = to.join(entry.file_name());
+    if entry.file_type().unwrap().is_dir() {
+      // Outputs written by other tests are not inputs of the fixtures.
+      if entry.file_name() != "dist" {
+        copy_fixtures(&entry.path(), &target);
+      }
+    } else {
+      std::fs::copy(entry.path(), target).unwrap();
+    }
+  }
+}
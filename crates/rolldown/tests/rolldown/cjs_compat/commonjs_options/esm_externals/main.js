@@ -0,0 +1,4 @@
+import { a } from 'ext'
+import * as ns from 'ext2'
+
+console.log(a, ns)
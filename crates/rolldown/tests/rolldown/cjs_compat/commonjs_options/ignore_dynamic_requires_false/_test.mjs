@@ -0,0 +1,4 @@
+import assert from 'assert'
+import { load } from './dist/main.js'
+
+assert.throws(() => load('./dep.js'), /Could not dynamically require "\.\/dep\.js"/)
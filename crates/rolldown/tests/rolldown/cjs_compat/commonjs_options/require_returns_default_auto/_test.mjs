@@ -0,0 +1,6 @@
+import assert from 'assert'
+import { named, onlyDefault } from './dist/main.js'
+
+assert.equal(onlyDefault, 'only default')
+assert.equal(named.default, 'default')
+assert.equal(named.value, 'named')
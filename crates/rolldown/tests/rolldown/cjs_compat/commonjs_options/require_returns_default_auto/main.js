@@ -0,0 +1,4 @@
+const onlyDefault = require('./only_default.js')
+const named = require('./named.js')
+
+export { onlyDefault, named }
@@ -0,0 +1,2 @@
+export const value = 'named'
+export default 'default'
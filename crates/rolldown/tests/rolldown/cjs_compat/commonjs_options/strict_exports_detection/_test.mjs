@@ -0,0 +1,4 @@
+import assert from 'assert'
+import { format } from './dist/main.js'
+
+assert.equal(format, 'esm')
@@ -0,0 +1 @@
+globalThis.format = typeof exports === 'undefined' ? 'esm' : 'cjs'
@@ -0,0 +1,3 @@
+import './dep.mjs'
+
+export const format = globalThis.format
@@ -0,0 +1,4 @@
+import assert from 'assert'
+import './dist/main.js'
+
+assert.equal(globalThis.result, 'value')
@@ -0,0 +1,3 @@
+import { value } from './dep.js'
+
+globalThis.result = typeof module === 'undefined' ? value : 'cjs'
@@ -0,0 +1,3 @@
+import './a.js'
+import './vendor/c.js'
+import './utils/e.js'
@@ -0,0 +1,2 @@
+import './f.js'
+console.log('e')
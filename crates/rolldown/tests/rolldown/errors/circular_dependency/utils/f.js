@@ -0,0 +1,2 @@
+import './e.js'
+console.log('f')
@@ -0,0 +1,2 @@
+import "test/abc"
+import "./a.js"
@@ -0,0 +1 @@
+export { bar }
@@ -0,0 +1,3 @@
+import a, { b } from 'ext'
+
+console.log(a, b)
@@ -0,0 +1 @@
+console.log('a')
\ No newline at end of file
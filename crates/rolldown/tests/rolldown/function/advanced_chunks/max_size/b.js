@@ -0,0 +1 @@
+console.log('b')
\ No newline at end of file
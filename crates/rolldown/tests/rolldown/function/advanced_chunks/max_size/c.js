@@ -0,0 +1 @@
+console.log('c')
\ No newline at end of file
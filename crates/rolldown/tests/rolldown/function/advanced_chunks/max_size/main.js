@@ -0,0 +1,3 @@
+import './a'
+import './b'
+import './c'
\ No newline at end of file
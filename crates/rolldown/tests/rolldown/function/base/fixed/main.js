@@ -0,0 +1,6 @@
+import assert from 'node:assert'
+import url from './logo.svg'
+
+assert.strictEqual(url, 'https://cdn.example.com/static/assets/logo-1aXBFds3.svg')
+
+export const startWorker = () => new Worker(new URL('./worker.js', import.meta.url), { type: 'module' })
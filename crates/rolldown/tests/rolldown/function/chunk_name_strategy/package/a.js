@@ -0,0 +1,3 @@
+import dom from 'react-dom'
+
+console.log('a', dom)
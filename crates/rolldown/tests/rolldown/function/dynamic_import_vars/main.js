@@ -0,0 +1,9 @@
+import assert from 'node:assert'
+
+const load = (lang) => import(`./locales/${lang}.json`)
+
+assert.strictEqual((await load('en')).default.hello, 'Hello')
+assert.strictEqual((await load('fr')).hello, 'Bonjour')
+await assert.rejects(load('de'), {
+  message: 'Unknown variable dynamic import: ./locales/de.json',
+})
@@ -0,0 +1,2 @@
+export const validator = "boolean_true";
+export const value = true;
\ No newline at end of file
@@ -0,0 +1,4 @@
+import { small } from "./small.js";
+import { shared } from "./shared.js";
+
+console.log(small, shared);
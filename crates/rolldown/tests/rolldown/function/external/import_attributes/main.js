@@ -0,0 +1,3 @@
+import data from 'external' with { type: 'json' }
+import * as ns from 'external2' with { type: 'json' }
+console.log(data, ns)
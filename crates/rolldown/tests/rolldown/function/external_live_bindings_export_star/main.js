@@ -0,0 +1,3 @@
+export * from 'node:fs'
+
+export const nonExternal = 'nonExternal'
@@ -0,0 +1,6 @@
+import nodePath from 'node:path'
+console.log(nodePath)
+
+// test deconflict
+const node_path = {}
+console.log(node_path)
\ No newline at end of file
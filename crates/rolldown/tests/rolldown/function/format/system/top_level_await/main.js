@@ -0,0 +1,6 @@
+export let count = await Promise.resolve(0)
+
+export async function inc() {
+  await Promise.resolve()
+  count++
+}
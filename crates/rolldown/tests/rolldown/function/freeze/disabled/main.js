@@ -0,0 +1,4 @@
+import assert from 'node:assert'
+import * as ns from './foo'
+
+assert.equal(Object.isFrozen(ns), false)
@@ -0,0 +1,4 @@
+import a from 'ext'
+export * as out from 'ext2'
+
+console.log(a)
@@ -0,0 +1,6 @@
+import a from 'ext'
+export * as out from 'ext2'
+
+var cjs = require('./cjs.js')
+
+console.log(a, cjs)
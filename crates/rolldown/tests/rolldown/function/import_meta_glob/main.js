@@ -0,0 +1,8 @@
+import assert from 'node:assert'
+
+const eager = import.meta.glob('./eager/*.js', { eager: true, import: 'default' })
+const lazy = import.meta.glob('./lazy/*.js')
+
+assert.deepStrictEqual(Object.keys(eager), ['./eager/a.js', './eager/b.js'])
+const c = await lazy['./lazy/c.js']()
+assert.strictEqual(c.default, 'c')
@@ -0,0 +1,4 @@
+import a from 'lib-a'
+import b from 'lib-b'
+
+console.log(a, b)
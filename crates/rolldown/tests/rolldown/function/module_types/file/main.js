@@ -0,0 +1,4 @@
+import assert from 'node:assert';
+import url from './logo.svg'
+
+assert.strictEqual(url, 'assets/logo-1aXBFds3.svg')
@@ -0,0 +1,4 @@
+import assert from 'node:assert';
+import url from './logo.svg'
+
+assert(url.startsWith('data:image/svg+xml;base64,'))
@@ -0,0 +1,4 @@
+import assert from 'node:assert'
+import data from './data.txt' with { type: 'json' }
+
+assert.deepStrictEqual(data, { works: true })
@@ -0,0 +1,4 @@
+import assert from 'node:assert'
+import { version } from './pkg.json'
+
+assert.strictEqual(version, '1.0.0')
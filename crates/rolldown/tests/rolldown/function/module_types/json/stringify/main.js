@@ -0,0 +1,5 @@
+import assert from 'node:assert'
+import { dependencies, version } from './pkg.json'
+
+assert.deepStrictEqual(dependencies, { foo: '^1.0.0' })
+assert.strictEqual(version, '1.0.0')
@@ -0,0 +1,3 @@
+import init from './empty.wasm'
+
+export const instance = await init()
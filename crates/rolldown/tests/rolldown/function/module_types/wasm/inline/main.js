@@ -0,0 +1,5 @@
+import assert from 'node:assert'
+import init from './empty.wasm'
+
+const instance = await init()
+assert(instance instanceof WebAssembly.Instance)
@@ -0,0 +1,7 @@
+import assert from 'node:assert'
+import fs from 'node:fs'
+
+const read = (file) => fs.readFileSync(new URL(file, import.meta.url), 'utf8')
+
+assert.strictEqual(read('./robots.txt'), 'User-agent: *\n')
+assert.strictEqual(read('./nested/data.txt'), 'nested\n')
@@ -0,0 +1 @@
+throw new Error('The emitted main.js should not be overwritten')
@@ -0,0 +1,5 @@
+import assert from 'node:assert'
+import main from './dist/main.js'
+
+assert.strictEqual(main.toString(), 'main')
+assert.strictEqual(main.value, 3)
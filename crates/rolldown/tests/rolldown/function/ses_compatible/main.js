@@ -0,0 +1,9 @@
+import './polyfill.js'
+
+function toString() {
+  return 'main'
+}
+
+const value = [1, 2, 3].last()
+
+export { toString, value }
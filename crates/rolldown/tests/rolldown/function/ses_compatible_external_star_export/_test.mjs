@@ -0,0 +1,6 @@
+import assert from 'node:assert'
+import fs from 'node:fs'
+import main from './dist/main.js'
+
+assert.strictEqual(main.readFileSync, fs.readFileSync)
+assert.strictEqual(main.nonExternal, 'nonExternal')
@@ -0,0 +1,4 @@
+import assert from 'node:assert'
+import './dist/main.js'
+
+assert.strictEqual(globalThis.test.module.a, 1)
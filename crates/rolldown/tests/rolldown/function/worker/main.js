@@ -0,0 +1,4 @@
+const worker = new Worker(new URL('./worker.js', import.meta.url), { type: 'module' })
+worker.postMessage(1)
+
+new Worker(new URL('./classic.js', import.meta.url))
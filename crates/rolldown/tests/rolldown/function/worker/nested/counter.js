@@ -0,0 +1,9 @@
+let count = 0
+
+self.onconnect = (e) => {
+  const port = e.ports[0]
+  port.onmessage = (msg) => {
+    count += msg.data
+    port.postMessage(count)
+  }
+}
@@ -0,0 +1,9 @@
+import { double } from './nested/math.js'
+
+const counter = new SharedWorker(new URL('./nested/counter.js', import.meta.url), {
+  type: 'module',
+})
+
+self.onmessage = (e) => {
+  counter.port.postMessage(double(e.data))
+}
@@ -1 +1,3 @@
+mod on_log;
 mod plugin_context;
+mod service_worker;
@@ -0,0 +1,40 @@
+use std::{borrow::Cow, sync::Arc};
+
+use rolldown::{BundlerOptions, InputItem, OnLogAction};
+use rolldown_plugin::{HookOnLogArgs, HookOnLogReturn, Plugin, PluginContext};
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+
+#[derive(Debug)]
+struct SilenceEval;
+
+impl Plugin for SilenceEval {
+  fn name(&self) -> Cow<'static, str> {
+    "SilenceEval".into()
+  }
+
+  async fn on_log(&self, _ctx: &PluginContext, args: &HookOnLogArgs<'_>) -> HookOnLogReturn {
+    if args.log.code == "EVAL" {
+      return Ok(OnLogAction::Drop);
+    }
+    Ok(OnLogAction::Keep)
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn drop_warning() {
+  let cwd = abs_file_dir!();
+
+  IntegrationTest::new(TestMeta { expect_executed: false, ..Default::default() })
+    .run_with_plugins(
+      BundlerOptions {
+        input: Some(vec![InputItem {
+          name: Some("main".to_string()),
+          import: "./main.js".to_string(),
+        }]),
+        cwd: Some(cwd),
+        ..Default::default()
+      },
+      vec![Arc::new(SilenceEval)],
+    )
+    .await;
+}
@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use rolldown::{BundlerOptions, InputItem, SourceMapType};
+use rolldown_plugin_service_worker::{ServiceWorkerPlugin, ServiceWorkerPluginConfig};
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn inject_precache_manifest() {
+  let cwd = abs_file_dir!();
+
+  IntegrationTest::new(TestMeta {
+    expect_executed: false,
+    visualize_sourcemap: true,
+    ..Default::default()
+  })
+  .run_with_plugins(
+    BundlerOptions {
+      input: Some(vec![
+        InputItem { name: Some("main".to_string()), import: "./main.js".to_string() },
+        InputItem { name: Some("sw".to_string()), import: "./sw.js".to_string() },
+      ]),
+      cwd: Some(cwd),
+      sourcemap: Some(SourceMapType::File),
+      ..Default::default()
+    },
+    vec![Arc::new(ServiceWorkerPlugin {
+      config: ServiceWorkerPluginConfig { entry: "sw.js".to_string(), ..Default::default() },
+    })],
+  )
+  .await;
+}
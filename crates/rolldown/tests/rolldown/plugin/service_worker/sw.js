@@ -0,0 +1,6 @@
+const manifest = self.__ROLLDOWN_PRECACHE_MANIFEST
+const legacyManifest = self.__ROLLDOWN_PRECACHE_MANIFEST_V2
+
+this.addEventListener('install', () => {
+  console.log(manifest, legacyManifest)
+})
@@ -0,0 +1,6 @@
+import assert from 'node:assert'
+import fs from 'node:fs'
+
+const map = JSON.parse(fs.readFileSync(new URL('./dist/main.css.map', import.meta.url), 'utf-8'))
+assert.strictEqual(map.file, 'main.css')
+assert.deepStrictEqual(map.sources, ['../dep.css', '../main.css'])
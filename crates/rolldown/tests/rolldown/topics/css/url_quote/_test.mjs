@@ -0,0 +1,5 @@
+import assert from 'node:assert'
+import fs from 'node:fs'
+
+assert.ok(fs.existsSync(new URL('./dist/assets/logo (dark).svg', import.meta.url)))
+assert.ok(fs.existsSync(new URL('./dist/assets/light logo.svg', import.meta.url)))
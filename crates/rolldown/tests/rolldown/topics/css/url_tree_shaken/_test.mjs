@@ -0,0 +1,5 @@
+import assert from 'node:assert'
+import fs from 'node:fs'
+
+// The stylesheet is tree-shaken, so the asset it references isn't emitted.
+assert.ok(!fs.existsSync(new URL('./dist/assets', import.meta.url)))
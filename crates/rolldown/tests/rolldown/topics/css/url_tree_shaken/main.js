@@ -0,0 +1,3 @@
+import './unused.css'
+
+console.log('main')
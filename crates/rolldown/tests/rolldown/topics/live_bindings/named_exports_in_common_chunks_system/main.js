@@ -0,0 +1,2 @@
+export * from './shared.js'
+import('./async-entry.js')
\ No newline at end of file
@@ -0,0 +1,9 @@
+export let count = 0;
+
+export function reset() {
+  count = 0;
+}
+
+export function inc() {
+  count += 1;
+}
\ No newline at end of file
@@ -0,0 +1,3 @@
+globalThis.dialogRegistered = true
+
+export const Dialog = 'dialog'
@@ -0,0 +1,3 @@
+export * from './button.js'
+export * from './dialog.js'
+export * from './tooltip.js'
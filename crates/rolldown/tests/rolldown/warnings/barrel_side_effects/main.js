@@ -0,0 +1,3 @@
+import { Button } from './components/index.js'
+
+console.log(Button)
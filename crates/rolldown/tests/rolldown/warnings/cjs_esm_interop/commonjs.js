@@ -0,0 +1,2 @@
+const esm = require('./esm.js')
+module.exports = { named: 'named', esm }
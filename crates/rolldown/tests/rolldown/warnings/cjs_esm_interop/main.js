@@ -0,0 +1,6 @@
+import assert from 'node:assert'
+import cjs, { named } from './commonjs.js'
+
+assert.strictEqual(named, 'named')
+assert.strictEqual(cjs.named, 'named')
+assert.strictEqual(cjs.esm.default, 'esm')
@@ -0,0 +1,8 @@
+function logged(value: Function, context: ClassMethodDecoratorContext) {
+  return value
+}
+
+export class Foo {
+  @logged
+  bar() {}
+}
@@ -3,7 +3,7 @@ use std::{path::PathBuf, sync::Arc};
 #[cfg(not(target_family = "wasm"))]
 use crate::worker_manager::WorkerManager;
 use crate::{
-  options::{BindingInputOptions, BindingOnLog, BindingOutputOptions},
+  options::{BindingInputOptions, BindingOutputOptions, BindingPrintLog},
   parallel_js_plugin_registry::ParallelJsPluginRegistry,
   types::{
     binding_log::BindingLog, binding_log_level::BindingLogLevel, binding_outputs::BindingOutputs,
@@ -17,13 +17,19 @@ use crate::{
 use napi::{tokio::sync::Mutex, Env};
 use napi_derive::napi;
 use rolldown::Bundler as NativeBundler;
-use rolldown_error::{BuildDiagnostic, DiagnosticOptions};
+use rolldown_error::{
+  BatchedBuildDiagnostic, BuildDiagnostic, DiagnosticOptions, DiagnosticsFormat, Severity,
+};
+
+/// How many diagnostics are reported by default, before the rest are only counted.
+const DEFAULT_MAX_DIAGNOSTICS: u32 = 100;
 
 #[napi]
 pub struct Bundler {
   inner: Arc<Mutex<NativeBundler>>,
-  on_log: BindingOnLog,
-  log_level: Option<BindingLogLevel>,
+  print_log: BindingPrintLog,
+  diagnostics_format: DiagnosticsFormat,
+  max_diagnostics: usize,
   cwd: PathBuf,
 }
 
@@ -39,8 +45,17 @@ impl Bundler {
   ) -> napi::Result<Self> {
     try_init_custom_trace_subscriber(env);
 
-    let log_level = input_options.log_level.take();
-    let on_log = input_options.on_log.take();
+    let print_log = input_options.print_log.take();
+    let diagnostics_format = input_options
+      .diagnostics_format
+      .take()
+      .as_deref()
+      .map(DiagnosticsFormat::try_from)
+      .transpose()
+      .map_err(|err| napi::Error::new(napi::Status::GenericFailure, err))?
+      .unwrap_or_default();
+    let max_diagnostics =
+      input_options.max_diagnostics.take().unwrap_or(DEFAULT_MAX_DIAGNOSTICS) as usize;
 
     #[cfg(target_family = "wasm")]
     // if we don't perform this warmup, the following call to `std::fs` will stuck
@@ -69,8 +84,9 @@ impl Bundler {
     Ok(Self {
       cwd: ret.bundler_options.cwd.clone().unwrap_or_else(|| std::env::current_dir().unwrap()),
       inner: Arc::new(Mutex::new(NativeBundler::with_plugins(ret.bundler_options, ret.plugins))),
-      log_level,
-      on_log,
+      print_log,
+      diagnostics_format,
+      max_diagnostics,
     })
   }
 
@@ -114,16 +130,11 @@ impl Bundler {
 
     let output = handle_result(bundler_core.scan().await)?;
 
+    // A SARIF log of a successful scan is dropped, there is no output to return it with.
     match output {
-      Ok(output) => {
-        self.handle_warnings(output.warnings).await;
-      }
-      Err(errs) => {
-        return Err(self.handle_errors(errs.into_vec()));
-      }
+      Ok(output) => self.handle_diagnostics(vec![], output.warnings).await.map(|_| ()),
+      Err(errs) => self.handle_diagnostics(errs.into_vec(), vec![]).await.map(|_| ()),
     }
-
-    Ok(())
   }
 
   #[allow(clippy::significant_drop_tightening)]
@@ -134,13 +145,9 @@ impl Bundler {
 
     let outputs = handle_result(bundler_core.write().await)?;
 
-    if !outputs.errors.is_empty() {
-      return Err(self.handle_errors(outputs.errors));
-    }
-
-    self.handle_warnings(outputs.warnings).await;
+    let sarif = self.handle_diagnostics(outputs.errors, outputs.warnings).await?;
 
-    Ok(outputs.assets.into())
+    Ok(BindingOutputs::from(outputs.assets).with_stats(outputs.stats).with_sarif(sarif))
   }
 
   #[allow(clippy::significant_drop_tightening)]
@@ -151,13 +158,9 @@ impl Bundler {
 
     let outputs = handle_result(bundler_core.generate().await)?;
 
-    if !outputs.errors.is_empty() {
-      return Err(self.handle_errors(outputs.errors));
-    }
-
-    self.handle_warnings(outputs.warnings).await;
+    let sarif = self.handle_diagnostics(outputs.errors, outputs.warnings).await?;
 
-    Ok(outputs.assets.into())
+    Ok(BindingOutputs::from(outputs.assets).with_stats(outputs.stats).with_sarif(sarif))
   }
 
   #[allow(clippy::significant_drop_tightening)]
@@ -177,6 +180,34 @@ impl Bundler {
     Ok(BindingWatcher::new(watcher))
   }
 
+  /// Returns the SARIF log of the diagnostics if `diagnosticsFormat` is `sarif`. If the build failed, the log
+  /// is the message of the returned error.
+  async fn handle_diagnostics(
+    &self,
+    errors: Vec<BuildDiagnostic>,
+    warnings: Vec<BuildDiagnostic>,
+  ) -> napi::Result<Option<String>> {
+    if matches!(self.diagnostics_format, DiagnosticsFormat::Sarif) {
+      let failed = !errors.is_empty();
+      // Errors and warnings are reported together as one SARIF log, instead of one by one.
+      let sarif = BatchedBuildDiagnostic::new(errors.into_iter().chain(warnings).collect())
+        .to_sarif(&DiagnosticOptions { cwd: self.cwd.clone() });
+      return if failed { Err(napi::Error::from_reason(sarif)) } else { Ok(Some(sarif)) };
+    }
+
+    // Repeated diagnostics are already aggregated in the core, the rest is capped to keep the output readable.
+    if !errors.is_empty() {
+      return Err(
+        self
+          .handle_errors(BatchedBuildDiagnostic::new(errors).cap(self.max_diagnostics).into_vec()),
+      );
+    }
+    self
+      .handle_warnings(BatchedBuildDiagnostic::new(warnings).cap(self.max_diagnostics).into_vec())
+      .await;
+    Ok(None)
+  }
+
   fn handle_errors(&self, errs: Vec<BuildDiagnostic>) -> napi::Error {
     errs.into_iter().for_each(|err| {
       eprintln!(
@@ -189,17 +220,17 @@ impl Bundler {
 
   #[allow(clippy::print_stdout, unused_must_use)]
   async fn handle_warnings(&self, warnings: Vec<BuildDiagnostic>) {
-    if let Some(log_level) = self.log_level {
-      if log_level == BindingLogLevel::Silent {
-        return;
-      }
-    }
-
-    if let Some(on_log) = self.on_log.as_ref() {
+    // Diagnostics are already filtered by `logLevel` and passed through `onLog` in the core.
+    if let Some(print_log) = self.print_log.as_ref() {
       for warning in warnings {
-        on_log
+        let level = match warning.severity() {
+          Severity::Error | Severity::Warn => BindingLogLevel::Warn,
+          Severity::Info => BindingLogLevel::Info,
+          Severity::Debug => BindingLogLevel::Debug,
+        };
+        print_log
           .call_async((
-            BindingLogLevel::Warn.to_string(),
+            level.to_string(),
             BindingLog {
               code: warning.kind().to_string(),
               message: warning
@@ -0,0 +1,40 @@
+use serde::Deserialize;
+
+use crate::options::plugin::types::binding_js_or_regex::{
+  bindingify_string_or_regex_array, BindingStringOrRegex,
+};
+
+#[napi_derive::napi(object, object_to_js = false)]
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BindingChecksOptions {
+  pub circular_dependency: Option<bool>,
+  pub circular_dependency_ignore: Option<Vec<BindingStringOrRegex>>,
+  pub circular_dependency_error: Option<Vec<BindingStringOrRegex>>,
+  pub commonjs_default_import: Option<bool>,
+  pub commonjs_named_import: Option<bool>,
+  pub require_esm: Option<bool>,
+  pub barrel_side_effects: Option<bool>,
+}
+
+impl TryFrom<BindingChecksOptions> for rolldown_common::ChecksOptions {
+  type Error = anyhow::Error;
+
+  fn try_from(value: BindingChecksOptions) -> Result<Self, Self::Error> {
+    Ok(Self {
+      circular_dependency: value.circular_dependency,
+      circular_dependency_ignore: value
+        .circular_dependency_ignore
+        .map(bindingify_string_or_regex_array)
+        .transpose()?,
+      circular_dependency_error: value
+        .circular_dependency_error
+        .map(bindingify_string_or_regex_array)
+        .transpose()?,
+      commonjs_default_import: value.commonjs_default_import,
+      commonjs_named_import: value.commonjs_named_import,
+      require_esm: value.require_esm,
+      barrel_side_effects: value.barrel_side_effects,
+    })
+  }
+}
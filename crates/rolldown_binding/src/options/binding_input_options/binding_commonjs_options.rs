@@ -0,0 +1,36 @@
+use napi::Either;
+use serde::Deserialize;
+
+#[napi_derive::napi(object)]
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BindingCommonJsOptions {
+  pub ignore_dynamic_requires: Option<bool>,
+  pub transform_mixed_es_modules: Option<bool>,
+  #[serde(skip_deserializing)]
+  #[napi(ts_type = "boolean | 'namespace' | 'auto' | 'preferred'")]
+  pub require_returns_default: Option<Either<bool, String>>,
+  pub esm_externals: Option<bool>,
+  pub strict_exports_detection: Option<bool>,
+}
+
+impl TryFrom<BindingCommonJsOptions> for rolldown_common::CommonJsOptions {
+  type Error = anyhow::Error;
+
+  fn try_from(value: BindingCommonJsOptions) -> Result<Self, Self::Error> {
+    Ok(Self {
+      ignore_dynamic_requires: value.ignore_dynamic_requires,
+      transform_mixed_es_modules: value.transform_mixed_es_modules,
+      require_returns_default: value
+        .require_returns_default
+        .map(|value| match value {
+          Either::A(value) => Ok(value.into()),
+          Either::B(value) => rolldown_common::RequireReturnsDefault::try_from(value.as_str()),
+        })
+        .transpose()
+        .map_err(anyhow::Error::msg)?,
+      esm_externals: value.esm_externals,
+      strict_exports_detection: value.strict_exports_detection,
+    })
+  }
+}
@@ -0,0 +1,9 @@
+use serde::Deserialize;
+
+#[napi_derive::napi(object)]
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BindingDecoratorOptions {
+  pub legacy: Option<bool>,
+  pub emit_decorator_metadata: Option<bool>,
+}
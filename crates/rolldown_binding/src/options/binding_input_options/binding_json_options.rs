@@ -0,0 +1,11 @@
+use napi::Either;
+use serde::Deserialize;
+
+#[napi_derive::napi(object)]
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BindingJsonOptions {
+  #[serde(skip_deserializing)]
+  #[napi(ts_type = "boolean | 'auto'")]
+  pub stringify: Option<Either<bool, String>>,
+}
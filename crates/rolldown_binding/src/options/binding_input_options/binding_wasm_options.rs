@@ -0,0 +1,8 @@
+use serde::Deserialize;
+
+#[napi_derive::napi(object)]
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BindingWasmOptions {
+  pub inline: Option<bool>,
+}
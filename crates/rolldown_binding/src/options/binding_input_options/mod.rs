@@ -4,19 +4,27 @@ use oxc_transform_napi::transform::JsxOptions;
 use std::collections::HashMap;
 
 use crate::types::{
-  binding_log::BindingLog, binding_log_level::BindingLogLevel, js_callback::JsCallback,
+  binding_log::{BindingLog, BindingOnLogOutput},
+  binding_log_level::BindingLogLevel,
+  js_callback::JsCallback,
 };
 use binding_inject_import::BindingInjectImport;
 use derivative::Derivative;
+use napi::Either;
 use napi_derive::napi;
 use serde::Deserialize;
 
 use self::{binding_input_item::BindingInputItem, binding_resolve_options::BindingResolveOptions};
 
 use super::plugin::BindingPluginOrParallelJsPluginPlaceholder;
+mod binding_checks_options;
+mod binding_commonjs_options;
+mod binding_decorator_options;
 mod binding_experimental_options;
 pub mod binding_inject_import;
 mod binding_input_item;
+mod binding_json_options;
+mod binding_wasm_options;
 mod binding_watch_option;
 // mod binding_jsx_options;
 mod binding_resolve_options;
@@ -69,14 +77,28 @@ pub struct BindingInputOptions {
   pub log_level: Option<BindingLogLevel>,
   #[derivative(Debug = "ignore")]
   #[serde(skip_deserializing)]
+  #[napi(
+    ts_type = "undefined | ((logLevel: 'debug' | 'warn' | 'info', log: BindingLog) => BindingOnLogOutput)"
+  )]
+  pub on_log: Option<JsCallback<(String, BindingLog), BindingOnLogOutput>>,
+  /// Prints the diagnostics kept by the `onLog` option and the `onLog` hook of plugins.
+  #[derivative(Debug = "ignore")]
+  #[serde(skip_deserializing)]
   #[napi(ts_type = "(logLevel: 'debug' | 'warn' | 'info', log: BindingLog) => void")]
-  pub on_log: BindingOnLog,
+  pub print_log: BindingPrintLog,
+  #[napi(ts_type = "'text' | 'sarif'")]
+  pub diagnostics_format: Option<String>,
+  pub max_diagnostics: Option<u32>,
   // extra
   pub cwd: String,
   // pub builtins: BuiltinsOptions,
   pub treeshake: Option<treeshake::BindingTreeshake>,
 
   pub module_types: Option<HashMap<String, String>>,
+  #[derivative(Debug = "ignore")]
+  #[serde(skip_deserializing)]
+  #[napi(ts_type = "number | ((id: string, size: number) => boolean)")]
+  pub assets_inline_limit: Option<BindingAssetsInlineLimit>,
   pub define: Option<Vec<(/* Target to be replaced */ String, /* Replacement */ String)>>,
   #[serde(skip_deserializing)]
   #[napi(ts_type = "Array<BindingInjectImportNamed | BindingInjectImportNamespace>")]
@@ -86,7 +108,20 @@ pub struct BindingInputOptions {
   #[serde(skip_deserializing)]
   #[derivative(Debug = "ignore")]
   pub jsx: Option<JsxOptions>,
+  pub decorator: Option<binding_decorator_options::BindingDecoratorOptions>,
+  pub json: Option<binding_json_options::BindingJsonOptions>,
+  pub wasm: Option<binding_wasm_options::BindingWasmOptions>,
+  pub commonjs: Option<binding_commonjs_options::BindingCommonJsOptions>,
   pub watch: Option<BindingWatchOption>,
+  pub checks: Option<binding_checks_options::BindingChecksOptions>,
+  pub css_target: Option<Vec<String>>,
+  pub bail: Option<bool>,
+  pub error_recovery: Option<bool>,
+  #[napi(ts_type = "Array<'console' | 'debugger'>")]
+  pub drop: Option<Vec<String>>,
+  pub drop_labels: Option<Vec<String>>,
 }
 
-pub type BindingOnLog = Option<JsCallback<(String, BindingLog), ()>>;
+pub type BindingPrintLog = Option<JsCallback<(String, BindingLog), ()>>;
+
+pub type BindingAssetsInlineLimit = Either<u32, JsCallback<(String, u32), bool>>;
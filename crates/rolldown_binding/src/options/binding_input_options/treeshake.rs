@@ -1,28 +1,75 @@
-use rolldown::{InnerOptions, ModuleSideEffects};
+use std::sync::Arc;
+
+use derivative::Derivative;
+use napi::{bindgen_prelude::Either3, Either};
+use rolldown::{InnerOptions, ModuleSideEffects, PropertyReadSideEffects};
 use rolldown_utils::js_regex::HybridRegex;
 use serde::Deserialize;
 
-#[napi_derive::napi(object)]
-#[derive(Deserialize, Debug, Default)]
+use crate::options::plugin::types::binding_js_or_regex::{
+  bindingify_string_or_regex_array, BindingStringOrRegex,
+};
+use crate::types::js_callback::{JsCallback, JsCallbackExt};
+
+pub type BindingModuleSideEffects =
+  Either3<String, Vec<String>, JsCallback<(String, bool), Option<bool>>>;
+
+#[napi_derive::napi(object, object_to_js = false)]
+#[derive(Deserialize, Default, Derivative)]
 #[serde(rename_all = "camelCase")]
+#[derivative(Debug)]
 pub struct BindingTreeshake {
-  pub module_side_effects: String,
+  #[derivative(Debug = "ignore")]
+  #[serde(skip_deserializing)]
+  #[napi(
+    ts_type = "string | string[] | ((id: string, external: boolean) => VoidNullable<boolean>)"
+  )]
+  pub module_side_effects: Option<BindingModuleSideEffects>,
+  pub manual_pure_functions: Option<Vec<BindingStringOrRegex>>,
+  #[serde(skip_deserializing)]
+  #[napi(ts_type = "boolean | 'always'")]
+  pub property_read_side_effects: Option<Either<bool, String>>,
+  pub annotations: Option<bool>,
+  pub unknown_global_side_effects: Option<bool>,
 }
 
 impl TryFrom<BindingTreeshake> for rolldown::TreeshakeOptions {
   fn try_from(value: BindingTreeshake) -> anyhow::Result<Self> {
-    match value.module_side_effects.as_str() {
-      "true" => {
-        Ok(Self::Option(InnerOptions { module_side_effects: ModuleSideEffects::Boolean(true) }))
-      }
-      "false" => {
-        Ok(Self::Option(InnerOptions { module_side_effects: ModuleSideEffects::Boolean(false) }))
-      }
-      _ => {
-        let regex = HybridRegex::new(&value.module_side_effects)?;
-        Ok(Self::Option(InnerOptions { module_side_effects: ModuleSideEffects::Regex(regex) }))
+    let module_side_effects = match value.module_side_effects {
+      None => ModuleSideEffects::Boolean(true),
+      Some(Either3::A(value)) => match value.as_str() {
+        "true" => ModuleSideEffects::Boolean(true),
+        "false" => ModuleSideEffects::Boolean(false),
+        "no-external" => ModuleSideEffects::NoExternal,
+        _ => ModuleSideEffects::Regex(HybridRegex::new(&value)?),
+      },
+      Some(Either3::B(globs)) => ModuleSideEffects::Globs(globs),
+      Some(Either3::C(func)) => ModuleSideEffects::Fn(Box::new(move |id, is_external| {
+        let func = Arc::clone(&func);
+        let id = id.to_string();
+        Box::pin(async move {
+          // Like rollup, only `false` means that the module is side-effect free.
+          Ok(func.invoke_async((id, is_external)).await?.unwrap_or(true))
+        })
+      })),
+    };
+    let manual_pure_functions =
+      value.manual_pure_functions.map(bindingify_string_or_regex_array).transpose()?;
+    let property_read_side_effects = match value.property_read_side_effects {
+      None => PropertyReadSideEffects::default(),
+      Some(Either::A(value)) => PropertyReadSideEffects::Boolean(value),
+      Some(Either::B(value)) if value == "always" => PropertyReadSideEffects::Always,
+      Some(Either::B(value)) => {
+        return Err(anyhow::anyhow!("Invalid `propertyReadSideEffects`: {value}"));
       }
-    }
+    };
+    Ok(Self::Option(InnerOptions {
+      module_side_effects,
+      manual_pure_functions,
+      property_read_side_effects,
+      annotations: value.annotations,
+      unknown_global_side_effects: value.unknown_global_side_effects,
+    }))
   }
 
   type Error = anyhow::Error;
@@ -3,17 +3,31 @@ mod types;
 use crate::types::js_callback::{JsCallback, MaybeAsyncJsCallback};
 use std::collections::HashMap;
 
+use super::super::types::binding_manual_chunks_meta::BindingManualChunksMeta;
 use super::super::types::binding_rendered_chunk::RenderedChunk;
 use super::plugin::BindingPluginOrParallelJsPluginPlaceholder;
+use crate::types::binding_pre_rendered_asset::BindingPreRenderedAsset;
 use crate::types::binding_pre_rendered_chunk::PreRenderedChunk;
 use derivative::Derivative;
 use napi::Either;
 use napi_derive::napi;
 use serde::Deserialize;
 use types::binding_advanced_chunks_options::BindingAdvancedChunksOptions;
+pub use types::binding_advanced_chunks_options::BindingMatchGroupTest;
+use types::binding_chunk_size_warning_limit::BindingChunkSizeWarningLimit;
+use types::binding_generated_code_options::BindingGeneratedCodeOptions;
+use types::binding_manual_chunks_group::BindingManualChunksGroup;
+use types::binding_minify_options::BindingMinifyOptions;
 
 pub type AddonOutputOption = MaybeAsyncJsCallback<RenderedChunk, Option<String>>;
+pub type AssetFileNamesOutputOption = Either<String, JsCallback<BindingPreRenderedAsset, String>>;
 pub type ChunkFileNamesOutputOption = Either<String, JsCallback<PreRenderedChunk, String>>;
+pub type InteropOutputOption = Either<String, JsCallback<String, String>>;
+pub type SanitizeFileNameOutputOption = Either<bool, JsCallback<String, String>>;
+pub type ManualChunksOutputOption = Either<
+  Vec<BindingManualChunksGroup>,
+  JsCallback<(String, BindingManualChunksMeta), Option<String>>,
+>;
 
 #[napi(object, object_to_js = false)]
 #[derive(Deserialize, Derivative)]
@@ -32,17 +46,29 @@ pub struct BindingOutputOptions {
   #[serde(skip_deserializing)]
   #[napi(ts_type = "string | ((chunk: PreRenderedChunk) => string)")]
   pub chunk_file_names: Option<ChunkFileNamesOutputOption>,
-  pub asset_file_names: Option<String>,
+  #[derivative(Debug = "ignore")]
+  #[serde(skip_deserializing)]
+  #[napi(ts_type = "string | ((chunk: PreRenderedChunk) => string)")]
+  pub css_entry_file_names: Option<ChunkFileNamesOutputOption>,
+  #[derivative(Debug = "ignore")]
+  #[serde(skip_deserializing)]
+  #[napi(ts_type = "string | ((chunk: PreRenderedChunk) => string)")]
+  pub css_chunk_file_names: Option<ChunkFileNamesOutputOption>,
+  #[derivative(Debug = "ignore")]
+  #[serde(skip_deserializing)]
+  #[napi(ts_type = "string | ((asset: BindingPreRenderedAsset) => string)")]
+  pub asset_file_names: Option<AssetFileNamesOutputOption>,
 
   // amd: NormalizedAmdOptions;
-  // assetFileNames: string | ((chunkInfo: PreRenderedAsset) => string);
   #[derivative(Debug = "ignore")]
   #[serde(skip_deserializing)]
   #[napi(ts_type = "(chunk: RenderedChunk) => MaybePromise<VoidNullable<string>>")]
   pub banner: Option<AddonOutputOption>,
   // chunkFileNames: string | ((chunkInfo: PreRenderedChunk) => string);
-  // compact: boolean;
+  pub compact: Option<bool>,
   pub dir: Option<String>,
+  pub public_dir: Option<String>,
+  pub dynamic_import_in_cjs: Option<bool>,
   // pub entry_file_names: String, // | ((chunkInfo: PreRenderedChunk) => string)
   #[serde(skip_deserializing)]
   #[napi(ts_type = "boolean | 'if-default-prop'")]
@@ -51,28 +77,41 @@ pub struct BindingOutputOptions {
   pub exports: Option<String>,
   pub extend: Option<bool>,
   pub external_live_bindings: Option<bool>,
+  pub external_import_attributes: Option<bool>,
   // footer: () => string | Promise<string>;
   #[derivative(Debug = "ignore")]
   #[serde(skip_deserializing)]
   #[napi(ts_type = "(chunk: RenderedChunk) => MaybePromise<VoidNullable<string>>")]
   pub footer: Option<AddonOutputOption>,
-  #[napi(ts_type = "'es' | 'cjs' | 'iife' | 'umd'")]
+  #[napi(ts_type = "'es' | 'cjs' | 'iife' | 'umd' | 'system'")]
   pub format: Option<String>,
-  // freeze: boolean;
-  // generatedCode: NormalizedGeneratedCodeOptions;
+  pub freeze: Option<bool>,
+  pub generated_code: Option<BindingGeneratedCodeOptions>,
   pub globals: Option<HashMap<String, String>>,
+  #[napi(ts_type = "'base64' | 'base36' | 'hex'")]
+  pub hash_characters: Option<String>,
   // hoistTransitiveImports: boolean;
   // indent: true | string;
   pub inline_dynamic_imports: Option<bool>,
-  // interop: GetInterop;
+  #[derivative(Debug = "ignore")]
+  #[serde(skip_deserializing)]
+  #[napi(
+    ts_type = "'auto' | 'esModule' | 'default' | 'defaultOnly' | ((id: string) => 'auto' | 'esModule' | 'default' | 'defaultOnly')"
+  )]
+  pub interop: Option<InteropOutputOption>,
   #[derivative(Debug = "ignore")]
   #[serde(skip_deserializing)]
   #[napi(ts_type = "(chunk: RenderedChunk) => MaybePromise<VoidNullable<string>>")]
   pub intro: Option<AddonOutputOption>,
-  // manualChunks: ManualChunksOption;
+  #[derivative(Debug = "ignore")]
+  #[serde(skip_deserializing)]
+  #[napi(
+    ts_type = "BindingManualChunksGroup[] | ((id: string, meta: BindingManualChunksMeta) => VoidNullable<string>)"
+  )]
+  pub manual_chunks: Option<ManualChunksOutputOption>,
   // minifyInternalExports: boolean;
   // namespaceToStringTag: boolean;
-  // noConflict: boolean;
+  pub no_conflict: Option<bool>,
   #[derivative(Debug = "ignore")]
   #[serde(skip_deserializing)]
   #[napi(ts_type = "(chunk: RenderedChunk) => MaybePromise<VoidNullable<string>>")]
@@ -82,9 +121,12 @@ pub struct BindingOutputOptions {
   #[napi(ts_type = "(BindingBuiltinPlugin | BindingPluginOptions | undefined)[]")]
   pub plugins: Vec<BindingPluginOrParallelJsPluginPlaceholder>,
   // preferConst: boolean;
-  // preserveModules: boolean;
-  // preserveModulesRoot: string | undefined;
-  // sanitizeFileName: (fileName: string) => string;
+  pub preserve_modules: Option<bool>,
+  pub preserve_modules_root: Option<String>,
+  #[derivative(Debug = "ignore")]
+  #[serde(skip_deserializing)]
+  #[napi(ts_type = "boolean | ((name: string) => string)")]
+  pub sanitize_file_name: Option<SanitizeFileNameOutputOption>,
   #[napi(ts_type = "'file' | 'inline' | 'hidden'")]
   pub sourcemap: Option<String>,
   #[derivative(Debug = "ignore")]
@@ -97,11 +139,37 @@ pub struct BindingOutputOptions {
   pub sourcemap_path_transform: Option<JsCallback<(String, String), String>>,
   // sourcemapExcludeSources: boolean;
   // sourcemapFile: string | undefined;
-  // strict: boolean;
+  pub strict: Option<bool>,
   // systemNullSetters: boolean;
   // validate: boolean;
 
   // --- Enhanced options
-  pub minify: Option<bool>,
+  pub minify: Option<BindingMinifyOptions>,
   pub advanced_chunks: Option<BindingAdvancedChunksOptions>,
+  pub experimental_min_chunk_size: Option<f64>,
+  #[napi(ts_type = "'firstEntered' | 'dominant' | 'package'")]
+  pub chunk_name_strategy: Option<String>,
+  pub chunk_size_warning_limit: Option<BindingChunkSizeWarningLimit>,
+  pub runtime_chunk: Option<bool>,
+  pub keep_names: Option<bool>,
+  #[napi(
+    ts_type = "'es2015' | 'es2016' | 'es2017' | 'es2018' | 'es2019' | 'es2020' | 'es2021' | 'es2022' | 'esnext'"
+  )]
+  pub target: Option<String>,
+  #[napi(ts_type = "'none' | 'inline' | 'eof' | 'linked' | 'external'")]
+  pub legal_comments: Option<String>,
+  #[napi(ts_type = "'ascii' | 'utf8'")]
+  pub charset: Option<String>,
+  pub ses_compatible: Option<bool>,
+  #[napi(ts_type = "'extract' | 'inline'")]
+  pub css_mode: Option<String>,
+  #[derivative(Debug = "ignore")]
+  #[serde(skip_deserializing)]
+  #[napi(ts_type = "(chunk: RenderedChunk) => MaybePromise<VoidNullable<string>>")]
+  pub css_banner: Option<AddonOutputOption>,
+  #[derivative(Debug = "ignore")]
+  #[serde(skip_deserializing)]
+  #[napi(ts_type = "(chunk: RenderedChunk) => MaybePromise<VoidNullable<string>>")]
+  pub css_footer: Option<AddonOutputOption>,
+  pub base: Option<String>,
 }
@@ -1,7 +1,11 @@
 use derivative::Derivative;
+use napi::bindgen_prelude::Either3;
 use serde::Deserialize;
 
-use crate::options::plugin::types::binding_js_or_regex::BindingStringOrRegex;
+use crate::options::plugin::types::binding_js_or_regex::JsRegExp;
+use crate::types::js_callback::JsCallback;
+
+pub type BindingMatchGroupTest = Either3<String, JsRegExp, JsCallback<String, Option<bool>>>;
 
 #[napi_derive::napi(object, object_to_js = false)]
 #[derive(Deserialize, Derivative)]
@@ -10,6 +14,7 @@ use crate::options::plugin::types::binding_js_or_regex::BindingStringOrRegex;
 pub struct BindingAdvancedChunksOptions {
   pub min_size: Option<f64>,
   pub min_share_count: Option<u32>,
+  pub max_size: Option<f64>,
   pub groups: Option<Vec<BindingMatchGroup>>,
 }
 
@@ -19,9 +24,13 @@ pub struct BindingAdvancedChunksOptions {
 #[derivative(Debug)]
 pub struct BindingMatchGroup {
   pub name: String,
-  pub test: Option<BindingStringOrRegex>,
+  #[derivative(Debug = "ignore")]
+  #[serde(skip_deserializing)]
+  #[napi(ts_type = "string | RegExp | ((id: string) => VoidNullable<boolean>)")]
+  pub test: Option<BindingMatchGroupTest>,
   // pub share_count: Option<u32>,
   pub priority: Option<u32>,
   pub min_size: Option<f64>,
+  pub max_size: Option<f64>,
   pub min_share_count: Option<u32>,
 }
@@ -0,0 +1,17 @@
+use derivative::Derivative;
+use serde::Deserialize;
+
+#[napi_derive::napi(object, object_to_js = false)]
+#[derive(Deserialize, Derivative)]
+#[serde(rename_all = "camelCase")]
+#[derivative(Debug)]
+pub struct BindingChunkSizeWarningLimit {
+  pub raw: Option<f64>,
+  pub gzip: Option<f64>,
+}
+
+impl From<BindingChunkSizeWarningLimit> for rolldown::ChunkSizeWarningLimit {
+  fn from(value: BindingChunkSizeWarningLimit) -> Self {
+    Self { raw: value.raw, gzip: value.gzip }
+  }
+}
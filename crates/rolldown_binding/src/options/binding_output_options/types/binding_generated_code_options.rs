@@ -0,0 +1,16 @@
+use derivative::Derivative;
+use serde::Deserialize;
+
+#[napi_derive::napi(object, object_to_js = false)]
+#[derive(Deserialize, Derivative)]
+#[serde(rename_all = "camelCase")]
+#[derivative(Debug)]
+pub struct BindingGeneratedCodeOptions {
+  #[napi(ts_type = "'es5' | 'es2015'")]
+  pub preset: Option<String>,
+  pub arrow_functions: Option<bool>,
+  pub const_bindings: Option<bool>,
+  pub object_shorthand: Option<bool>,
+  pub template_string: Option<bool>,
+  pub reserved_names_as_props: Option<bool>,
+}
@@ -0,0 +1,13 @@
+use derivative::Derivative;
+use serde::Deserialize;
+
+/// An entry of the object form of `output.manualChunks`. A list is used instead of an object, because the
+/// order of the entries decides which chunk a module listed more than once belongs to.
+#[napi_derive::napi(object, object_to_js = false)]
+#[derive(Deserialize, Derivative)]
+#[serde(rename_all = "camelCase")]
+#[derivative(Debug)]
+pub struct BindingManualChunksGroup {
+  pub name: String,
+  pub modules: Vec<String>,
+}
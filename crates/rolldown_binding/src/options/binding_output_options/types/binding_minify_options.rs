@@ -0,0 +1,58 @@
+use derivative::Derivative;
+use napi::Either;
+use serde::Deserialize;
+
+use crate::options::plugin::types::binding_js_or_regex::BindingStringOrRegex;
+
+#[napi_derive::napi(object, object_to_js = false)]
+#[derive(Deserialize, Derivative)]
+#[serde(rename_all = "camelCase")]
+#[derivative(Debug)]
+pub struct BindingMinifyOptions {
+  pub compress: Option<bool>,
+  #[serde(skip_deserializing)]
+  pub mangle: Option<Either<bool, BindingMangleOptions>>,
+  pub codegen: Option<bool>,
+}
+
+impl TryFrom<BindingMinifyOptions> for rolldown::RawMinifyOptions {
+  type Error = anyhow::Error;
+
+  fn try_from(value: BindingMinifyOptions) -> Result<Self, Self::Error> {
+    Ok(Self::Object(rolldown::MinifyOptions {
+      compress: value.compress,
+      mangle: value
+        .mangle
+        .map(|mangle| match mangle {
+          Either::A(mangle) => Ok(mangle.into()),
+          Either::B(mangle) => mangle.try_into().map(rolldown::RawMangleOptions::Object),
+        })
+        .transpose()?,
+      codegen: value.codegen,
+    }))
+  }
+}
+
+#[napi_derive::napi(object, object_to_js = false)]
+#[derive(Deserialize, Derivative)]
+#[serde(rename_all = "camelCase")]
+#[derivative(Debug)]
+pub struct BindingMangleOptions {
+  pub toplevel: Option<bool>,
+  pub reserved: Option<Vec<String>>,
+  pub keep_classnames: Option<bool>,
+  pub properties: Option<BindingStringOrRegex>,
+}
+
+impl TryFrom<BindingMangleOptions> for rolldown::MangleOptions {
+  type Error = anyhow::Error;
+
+  fn try_from(value: BindingMangleOptions) -> Result<Self, Self::Error> {
+    Ok(Self {
+      toplevel: value.toplevel,
+      reserved: value.reserved,
+      keep_classnames: value.keep_classnames,
+      properties: value.properties.map(TryInto::try_into).transpose()?,
+    })
+  }
+}
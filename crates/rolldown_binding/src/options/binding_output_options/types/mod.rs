@@ -1 +1,5 @@
 pub mod binding_advanced_chunks_options;
+pub mod binding_chunk_size_warning_limit;
+pub mod binding_generated_code_options;
+pub mod binding_manual_chunks_group;
+pub mod binding_minify_options;
@@ -3,7 +3,7 @@ use napi::bindgen_prelude::FromNapiValue;
 use napi::JsUnknown;
 use napi_derive::napi;
 use rolldown_plugin::__inner::Pluginable;
-use rolldown_plugin_alias::{Alias, AliasPlugin};
+use rolldown_plugin_alias::{Alias, AliasPlugin, AliasResolverFn};
 use rolldown_plugin_build_import_analysis::BuildImportAnalysisPlugin;
 use rolldown_plugin_dynamic_import_vars::DynamicImportVarsPlugin;
 use rolldown_plugin_import_glob::{ImportGlobPlugin, ImportGlobPluginConfig};
@@ -11,7 +11,9 @@ use rolldown_plugin_json::JsonPlugin;
 use rolldown_plugin_load_fallback::LoadFallbackPlugin;
 use rolldown_plugin_manifest::{ManifestPlugin, ManifestPluginConfig};
 use rolldown_plugin_module_preload_polyfill::ModulePreloadPolyfillPlugin;
+use rolldown_plugin_node_polyfills::NodePolyfillsPlugin;
 use rolldown_plugin_replace::{ReplaceOptions, ReplacePlugin};
+use rolldown_plugin_service_worker::{ServiceWorkerPlugin, ServiceWorkerPluginConfig};
 use rolldown_plugin_transform::TransformPlugin;
 use rolldown_plugin_wasm_fallback::WasmFallbackPlugin;
 use rolldown_plugin_wasm_helper::WasmHelperPlugin;
@@ -19,6 +21,7 @@ use serde::Deserialize;
 use std::{collections::HashMap, sync::Arc};
 
 use super::types::binding_js_or_regex::{bindingify_string_or_regex_array, BindingStringOrRegex};
+use crate::types::js_callback::{MaybeAsyncJsCallback, MaybeAsyncJsCallbackExt};
 
 #[allow(clippy::pub_underscore_fields)]
 #[napi(object)]
@@ -55,6 +58,8 @@ pub enum BindingBuiltinPluginName {
   JsonPlugin,
   BuildImportAnalysisPlugin,
   ReplacePlugin,
+  ServiceWorkerPlugin,
+  NodePolyfillsPlugin,
 }
 
 #[napi_derive::napi(object)]
@@ -90,6 +95,24 @@ impl From<BindingManifestPluginConfig> for ManifestPluginConfig {
   }
 }
 
+#[napi_derive::napi(object)]
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BindingServiceWorkerPluginConfig {
+  pub entry: String,
+  pub placeholder: Option<String>,
+}
+
+impl From<BindingServiceWorkerPluginConfig> for ServiceWorkerPluginConfig {
+  fn from(value: BindingServiceWorkerPluginConfig) -> Self {
+    let default = ServiceWorkerPluginConfig::default();
+    ServiceWorkerPluginConfig {
+      entry: value.entry,
+      placeholder: value.placeholder.unwrap_or(default.placeholder),
+    }
+  }
+}
+
 #[napi_derive::napi(object)]
 #[derive(Debug, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
@@ -123,11 +146,16 @@ pub struct BindingAliasPluginConfig {
 }
 
 #[napi_derive::napi(object, object_to_js = false)]
-#[derive(Debug, Deserialize)]
+#[derive(Deserialize, Derivative)]
+#[derivative(Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct BindingAliasPluginAlias {
   pub find: BindingStringOrRegex,
   pub replacement: String,
+  #[derivative(Debug = "ignore")]
+  #[serde(skip_deserializing)]
+  #[napi(ts_type = "(id: string, importer?: string) => MaybePromise<VoidNullable<string>>")]
+  pub custom_resolver: Option<MaybeAsyncJsCallback<(String, Option<String>), Option<String>>>,
 }
 
 #[napi_derive::napi(object)]
@@ -161,7 +189,18 @@ impl TryFrom<BindingAliasPluginConfig> for AliasPlugin {
   fn try_from(value: BindingAliasPluginConfig) -> Result<Self, Self::Error> {
     let mut ret = Vec::with_capacity(value.entries.len());
     for item in value.entries {
-      ret.push(Alias { find: item.find.try_into()?, replacement: item.replacement });
+      let custom_resolver = item.custom_resolver.map(|func| -> Arc<AliasResolverFn> {
+        Arc::new(move |id, importer| {
+          let func = Arc::clone(&func);
+          let args = (id.to_string(), importer.map(ToString::to_string));
+          Box::pin(async move { func.await_call(args).await.map_err(anyhow::Error::from) })
+        })
+      });
+      ret.push(Alias {
+        find: item.find.try_into()?,
+        replacement: item.replacement,
+        custom_resolver,
+      });
     }
 
     Ok(Self { entries: ret })
@@ -255,26 +294,31 @@ impl TryFrom<BindingBuiltinPlugin> for Arc<dyn Pluginable> {
         Arc::new(BuildImportAnalysisPlugin::try_from(config)?)
       }
       BindingBuiltinPluginName::ReplacePlugin => {
-        let config = if let Some(options) = plugin.options {
-          Some(BindingReplacePluginConfig::from_unknown(options)?)
+        let options = if let Some(options) = plugin.options {
+          BindingReplacePluginConfig::from_unknown(options)?.try_into()?
         } else {
-          None
+          ReplaceOptions::default()
         };
 
-        Arc::new(ReplacePlugin::with_options(config.map_or_else(ReplaceOptions::default, |opts| {
-          ReplaceOptions {
-            values: opts.values,
-            delimiters: opts.delimiters.map(|raw| (raw[0].clone(), raw[1].clone())),
-            prevent_assignment: opts.prevent_assignment.unwrap_or(false),
-            object_guards: opts.object_guards.unwrap_or(false),
-          }
-        })))
+        Arc::new(ReplacePlugin::with_options(options))
+      }
+      BindingBuiltinPluginName::ServiceWorkerPlugin => {
+        let config: ServiceWorkerPluginConfig = if let Some(options) = plugin.options {
+          BindingServiceWorkerPluginConfig::from_unknown(options)?.into()
+        } else {
+          return Err(napi::Error::new(
+            napi::Status::InvalidArg,
+            "Missing options for ServiceWorkerPlugin",
+          ));
+        };
+        Arc::new(ServiceWorkerPlugin { config })
       }
+      BindingBuiltinPluginName::NodePolyfillsPlugin => Arc::new(NodePolyfillsPlugin::default()),
     })
   }
 }
 
-#[napi_derive::napi(object)]
+#[napi_derive::napi(object, object_to_js = false)]
 #[derive(Debug, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct BindingReplacePluginConfig {
@@ -284,4 +328,21 @@ pub struct BindingReplacePluginConfig {
   pub delimiters: Option<Vec<String>>,
   pub prevent_assignment: Option<bool>,
   pub object_guards: Option<bool>,
+  pub include: Option<Vec<BindingStringOrRegex>>,
+  pub exclude: Option<Vec<BindingStringOrRegex>>,
+}
+
+impl TryFrom<BindingReplacePluginConfig> for ReplaceOptions {
+  type Error = anyhow::Error;
+
+  fn try_from(value: BindingReplacePluginConfig) -> Result<Self, Self::Error> {
+    Ok(ReplaceOptions {
+      values: value.values,
+      delimiters: value.delimiters.map(|raw| (raw[0].clone(), raw[1].clone())),
+      prevent_assignment: value.prevent_assignment.unwrap_or(false),
+      object_guards: value.object_guards.unwrap_or(false),
+      include: value.include.map(bindingify_string_or_regex_array).transpose()?.unwrap_or_default(),
+      exclude: value.exclude.map(bindingify_string_or_regex_array).transpose()?.unwrap_or_default(),
+    })
+  }
 }
@@ -7,7 +7,7 @@ use rolldown_plugin::PluginContext;
 use crate::{types::binding_module_info::BindingModuleInfo, utils::napi_error};
 
 use super::types::{
-  binding_emitted_asset::BindingEmittedAsset,
+  binding_emitted_asset::BindingEmittedAsset, binding_emitted_chunk::BindingEmittedChunk,
   binding_plugin_context_resolve_options::BindingPluginContextResolveOptions,
 };
 
@@ -46,6 +46,15 @@ impl BindingPluginContext {
     self.inner.emit_file(file.into()).to_string()
   }
 
+  #[napi]
+  pub fn emit_chunk(&self, chunk: BindingEmittedChunk) -> napi::Result<String> {
+    self
+      .inner
+      .emit_chunk(chunk.into())
+      .map(|reference_id| reference_id.to_string())
+      .map_err(|err| napi::Error::from_reason(err.to_string()))
+  }
+
   #[napi]
   pub fn get_file_name(&self, reference_id: String) -> String {
     self.inner.get_file_name(reference_id.as_str()).to_string()
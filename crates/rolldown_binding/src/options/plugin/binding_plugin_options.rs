@@ -3,10 +3,11 @@ use serde::Deserialize;
 use std::fmt::Debug;
 
 use crate::types::{
+  binding_log::BindingLog,
   binding_module_info::BindingModuleInfo,
   binding_outputs::{BindingOutputs, JsChangedOutputs},
   binding_rendered_chunk::RenderedChunk,
-  js_callback::MaybeAsyncJsCallback,
+  js_callback::{JsCallback, MaybeAsyncJsCallback},
 };
 
 use super::{
@@ -87,6 +88,18 @@ pub struct BindingPluginOptions {
   pub transform_meta: Option<BindingPluginHookMeta>,
   pub transform_filter: Option<BindingTransformHookFilter>,
 
+  #[serde(skip_deserializing)]
+  #[napi(
+    ts_type = "(ctx: BindingPluginContext, code: string, id: string, meta: BindingTransformHookExtraArgs) => MaybePromise<VoidNullable<BindingHookRenderChunkOutput>>"
+  )]
+  pub transform_css: Option<
+    MaybeAsyncJsCallback<
+      (BindingPluginContext, String, String, BindingTransformHookExtraArgs),
+      Option<BindingHookRenderChunkOutput>,
+    >,
+  >,
+  pub transform_css_meta: Option<BindingPluginHookMeta>,
+
   #[serde(skip_deserializing)]
   #[napi(
     ts_type = "(ctx: BindingPluginContext, module: BindingModuleInfo) => MaybePromise<VoidNullable>"
@@ -101,6 +114,11 @@ pub struct BindingPluginOptions {
   pub build_end: Option<MaybeAsyncJsCallback<(BindingPluginContext, Option<String>), ()>>,
   pub build_end_meta: Option<BindingPluginHookMeta>,
 
+  #[serde(skip_deserializing)]
+  #[napi(ts_type = "(level: 'debug' | 'warn' | 'info', log: BindingLog) => VoidNullable<boolean>")]
+  pub on_log: Option<JsCallback<(String, BindingLog), Option<bool>>>,
+  pub on_log_meta: Option<BindingPluginHookMeta>,
+
   #[serde(skip_deserializing)]
   #[napi(
     ts_type = "(ctx: BindingPluginContext, code: string, chunk: RenderedChunk) => MaybePromise<VoidNullable<BindingHookRenderChunkOutput>>"
@@ -113,6 +131,18 @@ pub struct BindingPluginOptions {
   >,
   pub render_chunk_meta: Option<BindingPluginHookMeta>,
 
+  #[serde(skip_deserializing)]
+  #[napi(
+    ts_type = "(ctx: BindingPluginContext, code: string, chunk: RenderedChunk) => MaybePromise<VoidNullable<BindingHookRenderChunkOutput>>"
+  )]
+  pub render_css_chunk: Option<
+    MaybeAsyncJsCallback<
+      (BindingPluginContext, String, RenderedChunk),
+      Option<BindingHookRenderChunkOutput>,
+    >,
+  >,
+  pub render_css_chunk_meta: Option<BindingPluginHookMeta>,
+
   #[serde(skip_deserializing)]
   #[napi(
     ts_type = "(ctx: BindingPluginContext, chunk: RenderedChunk) => MaybePromise<void | string>"
@@ -1,6 +1,9 @@
 use crate::types::{
-  binding_module_info::BindingModuleInfo, binding_outputs::update_outputs,
-  js_callback::MaybeAsyncJsCallbackExt,
+  binding_log::BindingLog,
+  binding_log_level::BindingLogLevel,
+  binding_module_info::BindingModuleInfo,
+  binding_outputs::update_outputs,
+  js_callback::{JsCallbackExt, MaybeAsyncJsCallbackExt},
 };
 use rolldown_plugin::{
   Plugin, __inner::SharedPluginable, typedmap::TypedMapKey, LoadHookFilter, ResolvedIdHookFilter,
@@ -88,6 +91,12 @@ impl Plugin for JsPlugin {
             is_entry: args.is_entry,
             kind: args.kind.to_string(),
             custom,
+            attributes: args
+              .attributes
+              .map(|attributes| {
+                attributes.iter().map(|(key, value)| (key.to_string(), value.to_string())).collect()
+              })
+              .unwrap_or_default(),
           },
         ))
         .await?
@@ -173,6 +182,32 @@ impl Plugin for JsPlugin {
     self.transform_meta.as_ref().map(Into::into)
   }
 
+  async fn transform_css(
+    &self,
+    ctx: &rolldown_plugin::PluginContext,
+    args: &rolldown_plugin::HookTransformCssArgs<'_>,
+  ) -> rolldown_plugin::HookRenderChunkReturn {
+    if let Some(cb) = &self.transform_css {
+      Ok(
+        cb.await_call((
+          ctx.clone().into(),
+          args.code.to_string(),
+          args.id.to_string(),
+          BindingTransformHookExtraArgs { module_type: args.module_type.to_string() },
+        ))
+        .await?
+        .map(TryInto::try_into)
+        .transpose()?,
+      )
+    } else {
+      Ok(None)
+    }
+  }
+
+  fn transform_css_meta(&self) -> Option<rolldown_plugin::PluginHookMeta> {
+    self.transform_css_meta.as_ref().map(Into::into)
+  }
+
   async fn module_parsed(
     &self,
     ctx: &rolldown_plugin::PluginContext,
@@ -203,6 +238,26 @@ impl Plugin for JsPlugin {
     self.build_end_meta.as_ref().map(Into::into)
   }
 
+  async fn on_log(
+    &self,
+    _ctx: &rolldown_plugin::PluginContext,
+    args: &rolldown_plugin::HookOnLogArgs<'_>,
+  ) -> rolldown_plugin::HookOnLogReturn {
+    if let Some(cb) = &self.on_log {
+      let level = BindingLogLevel::from(args.level).to_string();
+      let log = BindingLog { code: args.log.code.clone(), message: args.log.message.clone() };
+      // Like in rollup, returning `false` drops the diagnostic.
+      if cb.invoke_async((level, log)).await? == Some(false) {
+        return Ok(rolldown_common::OnLogAction::Drop);
+      }
+    }
+    Ok(rolldown_common::OnLogAction::Keep)
+  }
+
+  fn on_log_meta(&self) -> Option<rolldown_plugin::PluginHookMeta> {
+    self.on_log_meta.as_ref().map(Into::into)
+  }
+
   // --- Generate hooks ---
 
   async fn render_start(
@@ -324,6 +379,27 @@ impl Plugin for JsPlugin {
     self.render_chunk_meta.as_ref().map(Into::into)
   }
 
+  async fn render_css_chunk(
+    &self,
+    ctx: &rolldown_plugin::PluginContext,
+    args: &rolldown_plugin::HookRenderCssChunkArgs<'_>,
+  ) -> rolldown_plugin::HookRenderChunkReturn {
+    if let Some(cb) = &self.render_css_chunk {
+      Ok(
+        cb.await_call((ctx.clone().into(), args.code.to_string(), args.chunk.clone().into()))
+          .await?
+          .map(TryInto::try_into)
+          .transpose()?,
+      )
+    } else {
+      Ok(None)
+    }
+  }
+
+  fn render_css_chunk_meta(&self) -> Option<rolldown_plugin::PluginHookMeta> {
+    self.render_css_chunk_meta.as_ref().map(Into::into)
+  }
+
   async fn augment_chunk_hash(
     &self,
     ctx: &rolldown_plugin::PluginContext,
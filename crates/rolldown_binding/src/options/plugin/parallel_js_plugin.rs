@@ -122,6 +122,18 @@ impl Plugin for ParallelJsPlugin {
     }
   }
 
+  async fn transform_css(
+    &self,
+    ctx: &rolldown_plugin::PluginContext,
+    args: &rolldown_plugin::HookTransformCssArgs<'_>,
+  ) -> rolldown_plugin::HookRenderChunkReturn {
+    if self.first_plugin().transform_css.is_some() {
+      self.run_single(|plugin| plugin.call_transform_css(ctx, args)).await
+    } else {
+      Ok(None)
+    }
+  }
+
   async fn build_end(
     &self,
     ctx: &rolldown_plugin::PluginContext,
@@ -145,6 +157,18 @@ impl Plugin for ParallelJsPlugin {
     }
   }
 
+  async fn render_css_chunk(
+    &self,
+    ctx: &rolldown_plugin::PluginContext,
+    args: &rolldown_plugin::HookRenderCssChunkArgs<'_>,
+  ) -> rolldown_plugin::HookRenderChunkReturn {
+    if self.first_plugin().render_css_chunk.is_some() {
+      self.run_single(|plugin| plugin.call_render_css_chunk(ctx, args)).await
+    } else {
+      Ok(None)
+    }
+  }
+
   // --- Output hooks ---
 
   async fn generate_bundle(
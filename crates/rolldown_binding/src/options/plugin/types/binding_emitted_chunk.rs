@@ -0,0 +1,23 @@
+use serde::Deserialize;
+
+#[napi_derive::napi(object)]
+#[derive(Deserialize, Default, Debug)]
+pub struct BindingEmittedChunk {
+  pub name: Option<String>,
+  pub file_name: Option<String>,
+  pub id: String,
+  pub importer: Option<String>,
+  pub implicitly_loaded_after_one_of: Option<Vec<String>>,
+}
+
+impl From<BindingEmittedChunk> for rolldown_common::EmittedChunk {
+  fn from(value: BindingEmittedChunk) -> Self {
+    Self {
+      name: value.name.map(Into::into),
+      file_name: value.file_name.map(Into::into),
+      id: value.id,
+      importer: value.importer,
+      implicitly_loaded_after_one_of: value.implicitly_loaded_after_one_of.unwrap_or_default(),
+    }
+  }
+}
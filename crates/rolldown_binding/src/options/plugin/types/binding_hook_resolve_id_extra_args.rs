@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use derivative::Derivative;
 use serde::Deserialize;
 
@@ -8,6 +10,7 @@ use serde::Deserialize;
 pub struct BindingHookResolveIdExtraArgs {
   pub custom: Option<u32>,
   pub is_entry: bool,
-  #[napi(ts_type = "'import' | 'dynamic-import' | 'require-call'")]
+  #[napi(ts_type = "'import' | 'dynamic-import' | 'require-call' | 'new-url'")]
   pub kind: String,
+  pub attributes: HashMap<String, String>,
 }
@@ -0,0 +1,88 @@
+use napi_derive::napi;
+
+#[napi(object)]
+#[derive(Debug)]
+pub struct BindingBuildStats {
+  pub scan: BindingScanStats,
+  pub link: BindingStageStats,
+  pub generate: BindingStageStats,
+  pub modules: BindingModuleStats,
+  pub bytes_read: f64,
+  pub chunks_rendered: u32,
+}
+
+#[napi(object)]
+#[derive(Debug)]
+pub struct BindingStageStats {
+  /// In milliseconds.
+  pub duration: f64,
+}
+
+#[napi(object)]
+#[derive(Debug)]
+pub struct BindingScanStats {
+  /// In milliseconds.
+  pub duration: f64,
+  /// Cache misses of the module requests.
+  pub unique_module_requests: u32,
+  /// Cache hits of the module requests.
+  pub deduplicated_module_requests: u32,
+}
+
+#[napi(object)]
+#[derive(Debug)]
+pub struct BindingModuleStats {
+  pub esm: u32,
+  pub cjs: u32,
+  pub json: u32,
+  pub css: u32,
+  pub asset: u32,
+  pub wasm: u32,
+  pub empty: u32,
+  pub external: u32,
+}
+
+impl From<rolldown::BuildStats> for BindingBuildStats {
+  #[allow(clippy::cast_precision_loss)]
+  fn from(value: rolldown::BuildStats) -> Self {
+    Self {
+      scan: value.scan.into(),
+      link: value.link.into(),
+      generate: value.generate.into(),
+      modules: value.modules.into(),
+      bytes_read: value.bytes_read as f64,
+      chunks_rendered: value.chunks_rendered,
+    }
+  }
+}
+
+impl From<rolldown::StageStats> for BindingStageStats {
+  fn from(value: rolldown::StageStats) -> Self {
+    Self { duration: value.duration.as_secs_f64() * 1000.0 }
+  }
+}
+
+impl From<rolldown::ScanStats> for BindingScanStats {
+  fn from(value: rolldown::ScanStats) -> Self {
+    Self {
+      duration: value.duration.as_secs_f64() * 1000.0,
+      unique_module_requests: value.module_requests.unique,
+      deduplicated_module_requests: value.module_requests.deduplicated,
+    }
+  }
+}
+
+impl From<rolldown::ModuleStats> for BindingModuleStats {
+  fn from(value: rolldown::ModuleStats) -> Self {
+    Self {
+      esm: value.esm,
+      cjs: value.cjs,
+      json: value.json,
+      css: value.css,
+      asset: value.asset,
+      wasm: value.wasm,
+      empty: value.empty,
+      external: value.external,
+    }
+  }
+}
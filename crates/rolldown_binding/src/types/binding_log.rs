@@ -1,7 +1,32 @@
 use napi_derive::napi;
+use rolldown::OnLogAction;
 
 #[napi]
 pub struct BindingLog {
   pub code: String,
   pub message: String,
 }
+
+/// What the `onLog` option decided to do with a diagnostic.
+#[napi(object)]
+#[derive(Debug)]
+pub struct BindingOnLogOutput {
+  #[napi(ts_type = "'keep' | 'rewrite' | 'drop' | 'error'")]
+  pub action: String,
+  /// The message to report the diagnostic with, if it's rewritten.
+  pub message: Option<String>,
+}
+
+impl TryFrom<BindingOnLogOutput> for OnLogAction {
+  type Error = anyhow::Error;
+
+  fn try_from(value: BindingOnLogOutput) -> anyhow::Result<Self> {
+    match (value.action.as_str(), value.message) {
+      ("keep", _) => Ok(Self::Keep),
+      ("rewrite", Some(message)) => Ok(Self::Rewrite(message)),
+      ("drop", _) => Ok(Self::Drop),
+      ("error", _) => Ok(Self::Error),
+      (action, _) => Err(anyhow::format_err!("Invalid onLog action: {action:?}")),
+    }
+  }
+}
@@ -33,3 +33,25 @@ impl Display for BindingLogLevel {
     }
   }
 }
+
+impl From<BindingLogLevel> for rolldown::LogLevel {
+  fn from(value: BindingLogLevel) -> Self {
+    match value {
+      BindingLogLevel::Silent => Self::Silent,
+      BindingLogLevel::Warn => Self::Warn,
+      BindingLogLevel::Info => Self::Info,
+      BindingLogLevel::Debug => Self::Debug,
+    }
+  }
+}
+
+impl From<rolldown::LogLevel> for BindingLogLevel {
+  fn from(value: rolldown::LogLevel) -> Self {
+    match value {
+      rolldown::LogLevel::Silent => Self::Silent,
+      rolldown::LogLevel::Warn => Self::Warn,
+      rolldown::LogLevel::Info => Self::Info,
+      rolldown::LogLevel::Debug => Self::Debug,
+    }
+  }
+}
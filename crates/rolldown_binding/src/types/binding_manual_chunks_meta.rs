@@ -0,0 +1,29 @@
+use std::sync::Arc;
+
+use napi_derive::napi;
+use rolldown::ManualChunksModuleInfos;
+
+use super::binding_module_info::BindingModuleInfo;
+
+/// The second argument of the function form of `output.manualChunks`.
+#[napi]
+pub struct BindingManualChunksMeta {
+  module_infos: ManualChunksModuleInfos,
+}
+
+#[napi]
+impl BindingManualChunksMeta {
+  pub fn new(module_infos: ManualChunksModuleInfos) -> Self {
+    Self { module_infos }
+  }
+
+  #[napi]
+  pub fn get_module_info(&self, module_id: String) -> Option<BindingModuleInfo> {
+    self.module_infos.get(&module_id).map(|info| BindingModuleInfo::new(Arc::clone(info)))
+  }
+
+  #[napi]
+  pub fn get_module_ids(&self) -> Vec<String> {
+    self.module_infos.keys().cloned().collect()
+  }
+}
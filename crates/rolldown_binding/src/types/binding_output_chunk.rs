@@ -70,6 +70,11 @@ impl BindingOutputChunk {
     self.inner.dynamic_imports.iter().map(|x| x.to_string()).collect()
   }
 
+  #[napi(getter)]
+  pub fn imported_css(&self) -> Vec<String> {
+    self.inner.imported_css.iter().map(ToString::to_string).collect()
+  }
+
   // OutputChunk
   #[napi(getter)]
   pub fn code(&self) -> String {
@@ -111,6 +116,7 @@ pub struct JsOutputChunk {
   pub modules: HashMap<String, BindingRenderedModule>,
   pub imports: Vec<String>,
   pub dynamic_imports: Vec<String>,
+  pub imported_css: Vec<String>,
   // OutputChunk
   pub code: String,
   pub map: Option<BindingSourcemap>,
@@ -133,6 +139,7 @@ impl TryFrom<JsOutputChunk> for rolldown_common::OutputChunk {
       modules: chunk.modules.into_iter().map(|(key, value)| (key.into(), value.into())).collect(),
       imports: chunk.imports.into_iter().map(Into::into).collect(),
       dynamic_imports: chunk.dynamic_imports.into_iter().map(Into::into).collect(),
+      imported_css: chunk.imported_css.into_iter().map(Into::into).collect(),
       code: chunk.code,
       map: chunk.map.map(TryInto::try_into).transpose()?,
       sourcemap_filename: chunk.sourcemap_filename,
@@ -1,4 +1,5 @@
 use super::{
+  binding_build_stats::BindingBuildStats,
   binding_output_asset::{BindingOutputAsset, JsOutputAsset},
   binding_output_chunk::{BindingOutputChunk, JsOutputChunk},
 };
@@ -9,6 +10,8 @@ use napi_derive::napi;
 pub struct BindingOutputs {
   chunks: Vec<BindingOutputChunk>,
   assets: Vec<BindingOutputAsset>,
+  stats: Option<BindingBuildStats>,
+  sarif: Option<String>,
 }
 
 #[napi]
@@ -22,6 +25,32 @@ impl BindingOutputs {
   pub fn assets(&mut self) -> Vec<BindingOutputAsset> {
     std::mem::take(&mut self.assets)
   }
+
+  /// Only available on the result of `generate` and `write`.
+  #[napi(getter)]
+  pub fn stats(&mut self) -> Option<BindingBuildStats> {
+    self.stats.take()
+  }
+
+  /// The SARIF log of the diagnostics, only available if `diagnosticsFormat` is `sarif`.
+  #[napi(getter)]
+  pub fn sarif(&mut self) -> Option<String> {
+    self.sarif.take()
+  }
+}
+
+impl BindingOutputs {
+  #[must_use]
+  pub fn with_stats(mut self, stats: rolldown::BuildStats) -> Self {
+    self.stats = Some(stats.into());
+    self
+  }
+
+  #[must_use]
+  pub fn with_sarif(mut self, sarif: Option<String>) -> Self {
+    self.sarif = sarif;
+    self
+  }
 }
 
 impl From<Vec<rolldown_common::Output>> for BindingOutputs {
@@ -36,7 +65,7 @@ impl From<Vec<rolldown_common::Output>> for BindingOutputs {
         assets.push(BindingOutputAsset::new(*asset));
       }
     });
-    Self { chunks, assets }
+    Self { chunks, assets, stats: None, sarif: None }
   }
 }
 
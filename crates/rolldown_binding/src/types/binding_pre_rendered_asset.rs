@@ -0,0 +1,19 @@
+use crate::options::plugin::types::binding_asset_source::BindingAssetSource;
+
+#[napi_derive::napi(object)]
+#[derive(Debug)]
+pub struct BindingPreRenderedAsset {
+  pub name: Option<String>,
+  pub original_file_name: Option<String>,
+  pub source: BindingAssetSource,
+}
+
+impl From<rolldown_common::RollupPreRenderedAsset> for BindingPreRenderedAsset {
+  fn from(value: rolldown_common::RollupPreRenderedAsset) -> Self {
+    Self {
+      name: value.name,
+      original_file_name: value.original_file_name,
+      source: value.source.into(),
+    }
+  }
+}
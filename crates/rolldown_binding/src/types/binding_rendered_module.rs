@@ -21,6 +21,7 @@ impl From<rolldown_common::RenderedModule> for BindingRenderedModule {
 
 impl From<BindingRenderedModule> for rolldown_common::RenderedModule {
   fn from(value: BindingRenderedModule) -> Self {
-    Self { code: value.code }
+    let rendered_length = value.code.as_ref().map_or(0, String::len);
+    Self { code: value.code, rendered_length }
   }
 }
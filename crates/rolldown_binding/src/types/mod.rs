@@ -1,9 +1,12 @@
+pub mod binding_build_stats;
 pub mod binding_log;
 pub mod binding_log_level;
+pub mod binding_manual_chunks_meta;
 pub mod binding_module_info;
 pub mod binding_output_asset;
 pub mod binding_output_chunk;
 pub mod binding_outputs;
+pub mod binding_pre_rendered_asset;
 pub mod binding_pre_rendered_chunk;
 pub mod binding_rendered_chunk;
 pub mod binding_rendered_module;
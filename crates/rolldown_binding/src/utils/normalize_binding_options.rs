@@ -1,20 +1,31 @@
-use crate::options::ChunkFileNamesOutputOption;
+use crate::options::{
+  AssetFileNamesOutputOption, BindingMatchGroupTest, ChunkFileNamesOutputOption,
+};
 use crate::{
   options::binding_inject_import::normalize_binding_inject_import,
-  types::js_callback::JsCallbackExt,
+  types::{
+    binding_log::{BindingLog, BindingOnLogOutput},
+    binding_log_level::BindingLogLevel,
+    binding_manual_chunks_meta::BindingManualChunksMeta,
+    js_callback::{JsCallback, JsCallbackExt},
+  },
 };
 #[cfg_attr(target_family = "wasm", allow(unused))]
 use crate::{
   options::plugin::JsPlugin,
   types::{binding_rendered_chunk::RenderedChunk, js_callback::MaybeAsyncJsCallbackExt},
 };
-use napi::bindgen_prelude::Either;
+use napi::bindgen_prelude::{Either, Either3};
 use rolldown::{
-  AddonOutputOption, AdvancedChunksOptions, BundlerOptions, ChunkFilenamesOutputOption,
-  ExperimentalOptions, IsExternal, MatchGroup, ModuleType, OutputExports, OutputFormat, Platform,
+  AddonOutputOption, AdvancedChunksOptions, AssetFilenamesOutputOption, AssetsInlineLimit,
+  BundlerOptions, Charset, ChunkFilenamesOutputOption, ChunkNameStrategy, CssMode,
+  DecoratorOptions, DropKind, EsTarget, ExperimentalOptions, GeneratedCodeOptions,
+  GeneratedCodePreset, HashCharacters, InteropMode, InteropOutputOption, IsExternal, JsonOptions,
+  LegalComments, LogLevel, ManualChunksOption, MatchGroup, MatchGroupTest, ModuleType, OnLog,
+  OutputExports, OutputFormat, Platform, SanitizeFilename, WasmOptions,
 };
 use rolldown_plugin::__inner::SharedPluginable;
-use rolldown_utils::indexmap::FxIndexMap;
+use rolldown_utils::{indexmap::FxIndexMap, js_regex::HybridRegex};
 use std::collections::HashMap;
 use std::path::PathBuf;
 
@@ -57,6 +68,111 @@ fn normalize_chunk_file_names_option(
     .transpose()
 }
 
+fn normalize_asset_file_names_option(
+  option: Option<AssetFileNamesOutputOption>,
+) -> Option<AssetFilenamesOutputOption> {
+  option.map(move |value| match value {
+    Either::A(str) => AssetFilenamesOutputOption::String(str),
+    Either::B(func) => AssetFilenamesOutputOption::Fn(Box::new(move |asset| {
+      let func = Arc::clone(&func);
+      let asset = asset.clone();
+      Box::pin(async move { func.invoke_async(asset.into()).await.map_err(anyhow::Error::from) })
+    })),
+  })
+}
+
+fn normalize_sanitize_file_name_option(
+  option: Option<crate::options::SanitizeFileNameOutputOption>,
+) -> Option<SanitizeFilename> {
+  option.map(move |value| match value {
+    Either::A(value) => SanitizeFilename::Boolean(value),
+    Either::B(func) => SanitizeFilename::Fn(Box::new(move |name| {
+      let func = Arc::clone(&func);
+      let name = name.to_string();
+      Box::pin(async move { func.invoke_async(name).await.map_err(anyhow::Error::from) })
+    })),
+  })
+}
+
+fn normalize_assets_inline_limit_option(
+  option: Option<crate::options::BindingAssetsInlineLimit>,
+) -> Option<AssetsInlineLimit> {
+  option.map(move |value| match value {
+    Either::A(bytes) => AssetsInlineLimit::Bytes(bytes as usize),
+    Either::B(func) => AssetsInlineLimit::Fn(Box::new(move |id, size| {
+      let func = Arc::clone(&func);
+      let id = id.to_string();
+      let size = u32::try_from(size).unwrap_or(u32::MAX);
+      Box::pin(async move { func.invoke_async((id, size)).await.map_err(anyhow::Error::from) })
+    })),
+  })
+}
+
+fn normalize_on_log_option(
+  option: Option<JsCallback<(String, BindingLog), BindingOnLogOutput>>,
+) -> Option<OnLog> {
+  option.map(move |func| {
+    OnLog::new(Box::new(move |level, log| {
+      let func = Arc::clone(&func);
+      let level = BindingLogLevel::from(level).to_string();
+      let log = BindingLog { code: log.code, message: log.message };
+      Box::pin(async move { func.invoke_async((level, log)).await?.try_into() })
+    }))
+  })
+}
+
+fn normalize_interop_option(
+  option: Option<crate::options::InteropOutputOption>,
+) -> napi::Result<Option<InteropOutputOption>> {
+  option
+    .map(move |value| match value {
+      Either::A(mode) => InteropMode::try_from(mode.as_str())
+        .map(InteropOutputOption::Mode)
+        .map_err(|err| napi::Error::new(napi::Status::GenericFailure, err)),
+      Either::B(func) => Ok(InteropOutputOption::Fn(Box::new(move |id| {
+        let func = Arc::clone(&func);
+        let id = id.to_string();
+        Box::pin(async move {
+          let mode = func.invoke_async(id).await.map_err(anyhow::Error::from)?;
+          InteropMode::try_from(mode.as_str()).map_err(anyhow::Error::msg)
+        })
+      }))),
+    })
+    .transpose()
+}
+
+fn normalize_manual_chunks_option(
+  option: Option<crate::options::ManualChunksOutputOption>,
+) -> Option<ManualChunksOption> {
+  option.map(move |value| match value {
+    Either::A(groups) => ManualChunksOption::Groups(
+      groups.into_iter().map(|group| (group.name, group.modules)).collect(),
+    ),
+    Either::B(func) => ManualChunksOption::Fn(Box::new(move |id, module_infos| {
+      let func = Arc::clone(&func);
+      let id = id.to_string();
+      let meta = BindingManualChunksMeta::new(Arc::clone(module_infos));
+      Box::pin(async move { func.invoke_async((id, meta)).await.map_err(anyhow::Error::from) })
+    })),
+  })
+}
+
+fn normalize_match_group_test(test: BindingMatchGroupTest) -> MatchGroupTest {
+  match test {
+    Either3::A(source) => {
+      MatchGroupTest::Regex(HybridRegex::new(&source).expect("Invalid regex pass to test"))
+    }
+    Either3::B(regex) => MatchGroupTest::Regex(
+      HybridRegex::with_flags(&regex.source, &regex.flags).expect("Invalid regex pass to test"),
+    ),
+    Either3::C(func) => MatchGroupTest::Fn(Box::new(move |id| {
+      let func = Arc::clone(&func);
+      let id = id.to_string();
+      Box::pin(async move { func.invoke_async(id).await.map_err(anyhow::Error::from) })
+    })),
+  }
+}
+
 #[allow(clippy::too_many_lines)]
 pub fn normalize_binding_options(
   input_options: crate::options::BindingInputOptions,
@@ -135,15 +251,25 @@ pub fn normalize_binding_options(
       .map_err(|err| napi::Error::new(napi::Status::GenericFailure, err))?,
     shim_missing_exports: input_options.shim_missing_exports,
     name: output_options.name,
+    no_conflict: output_options.no_conflict,
     entry_filenames: normalize_chunk_file_names_option(output_options.entry_file_names)?,
     chunk_filenames: normalize_chunk_file_names_option(output_options.chunk_file_names)?,
-    asset_filenames: output_options.asset_file_names,
+    asset_filenames: normalize_asset_file_names_option(output_options.asset_file_names),
+    hash_characters: output_options
+      .hash_characters
+      .as_deref()
+      .map(HashCharacters::try_from)
+      .transpose()
+      .map_err(|err| napi::Error::new(napi::Status::GenericFailure, err))?,
+    sanitize_filename: normalize_sanitize_file_name_option(output_options.sanitize_file_name),
     dir: output_options.dir,
+    public_dir: output_options.public_dir,
     sourcemap: output_options.sourcemap.map(Into::into),
     es_module: output_options.es_module.map(|es_module| match es_module {
       Either::A(es_module_bool) => es_module_bool.into(),
       Either::B(es_module_string) => es_module_string.into(),
     }),
+    interop: normalize_interop_option(output_options.interop)?,
     banner: normalize_addon_option(output_options.banner),
     footer: normalize_addon_option(output_options.footer),
     intro: normalize_addon_option(output_options.intro),
@@ -163,44 +289,131 @@ pub fn normalize_binding_options(
       "app" => OutputFormat::App,
       "iife" => OutputFormat::Iife,
       "umd" => OutputFormat::Umd,
+      "system" => OutputFormat::System,
       _ => panic!("Invalid format: {format_str}"),
     }),
     globals: output_options.globals,
+    generated_code: output_options.generated_code.map(|inner| GeneratedCodeOptions {
+      preset: inner.preset.map(|preset| match preset.as_str() {
+        "es5" => GeneratedCodePreset::Es5,
+        "es2015" => GeneratedCodePreset::Es2015,
+        _ => panic!("Invalid generatedCode.preset: {preset}"),
+      }),
+      arrow_functions: inner.arrow_functions,
+      const_bindings: inner.const_bindings,
+      object_shorthand: inner.object_shorthand,
+      template_string: inner.template_string,
+      reserved_names_as_props: inner.reserved_names_as_props,
+    }),
     module_types,
+    assets_inline_limit: normalize_assets_inline_limit_option(input_options.assets_inline_limit),
     experimental: input_options.experimental.map(|inner| ExperimentalOptions {
       strict_execution_order: inner.strict_execution_order,
       disable_live_bindings: inner.disable_live_bindings,
     }),
-    minify: output_options.minify,
-    css_entry_filenames: None,
-    css_chunk_filenames: None,
+    minify: output_options.minify.map(TryInto::try_into).transpose()?,
+    css_entry_filenames: normalize_chunk_file_names_option(output_options.css_entry_file_names)?,
+    css_chunk_filenames: normalize_chunk_file_names_option(output_options.css_chunk_file_names)?,
     extend: output_options.extend,
+    compact: output_options.compact,
+    strict: output_options.strict,
     define: input_options.define.map(FxIndexMap::from_iter),
     inject: input_options
       .inject
       .map(|inner| inner.into_iter().map(normalize_binding_inject_import).collect()),
     external_live_bindings: output_options.external_live_bindings,
+    external_import_attributes: output_options.external_import_attributes,
+    freeze: output_options.freeze,
+    dynamic_import_in_cjs: output_options.dynamic_import_in_cjs,
     inline_dynamic_imports: output_options.inline_dynamic_imports,
+    manual_chunks: normalize_manual_chunks_option(output_options.manual_chunks),
     advanced_chunks: output_options.advanced_chunks.map(|inner| AdvancedChunksOptions {
       min_size: inner.min_size,
       min_share_count: inner.min_share_count,
+      max_size: inner.max_size,
       groups: inner.groups.map(|inner| {
         inner
           .into_iter()
           .map(|item| MatchGroup {
             name: item.name,
-            test: item.test.map(|inner| inner.try_into().expect("Invalid regex pass to test")),
+            test: item.test.map(normalize_match_group_test),
             priority: item.priority,
             min_size: item.min_size,
+            max_size: item.max_size,
             min_share_count: item.min_share_count,
           })
           .collect::<Vec<_>>()
       }),
     }),
-    checks: None,
+    experimental_min_chunk_size: output_options.experimental_min_chunk_size,
+    chunk_name_strategy: output_options
+      .chunk_name_strategy
+      .as_deref()
+      .map(ChunkNameStrategy::try_from)
+      .transpose()
+      .map_err(|err| napi::Error::new(napi::Status::GenericFailure, err))?,
+    chunk_size_warning_limit: output_options.chunk_size_warning_limit.map(Into::into),
+    runtime_chunk: output_options.runtime_chunk,
+    keep_names: output_options.keep_names,
+    target: output_options
+      .target
+      .as_deref()
+      .map(EsTarget::try_from)
+      .transpose()
+      .map_err(|err| napi::Error::new(napi::Status::GenericFailure, err))?,
+    legal_comments: output_options
+      .legal_comments
+      .as_deref()
+      .map(LegalComments::try_from)
+      .transpose()
+      .map_err(|err| napi::Error::new(napi::Status::GenericFailure, err))?,
+    charset: output_options
+      .charset
+      .as_deref()
+      .map(Charset::try_from)
+      .transpose()
+      .map_err(|err| napi::Error::new(napi::Status::GenericFailure, err))?,
+    checks: input_options.checks.map(TryInto::try_into).transpose()?,
     profiler_names: input_options.profiler_names,
     jsx: input_options.jsx.map(Into::into),
+    decorator: input_options.decorator.map(|inner| DecoratorOptions {
+      legacy: inner.legacy,
+      emit_decorator_metadata: inner.emit_decorator_metadata,
+    }),
+    json: input_options.json.map(|inner| JsonOptions {
+      stringify: inner.stringify.map(|stringify| match stringify {
+        Either::A(stringify_bool) => stringify_bool.into(),
+        Either::B(stringify_string) => stringify_string.into(),
+      }),
+    }),
+    wasm: input_options.wasm.map(|inner| WasmOptions { inline: inner.inline }),
+    commonjs: input_options.commonjs.map(TryInto::try_into).transpose()?,
     watch: input_options.watch.map(TryInto::try_into).transpose()?,
+    ses_compatible: output_options.ses_compatible,
+    css_mode: output_options
+      .css_mode
+      .as_deref()
+      .map(CssMode::try_from)
+      .transpose()
+      .map_err(|err| napi::Error::new(napi::Status::GenericFailure, err))?,
+    css_banner: normalize_addon_option(output_options.css_banner),
+    css_footer: normalize_addon_option(output_options.css_footer),
+    css_target: input_options.css_target,
+    preserve_modules: output_options.preserve_modules,
+    preserve_modules_root: output_options.preserve_modules_root,
+    base: output_options.base,
+    log_level: input_options.log_level.map(LogLevel::from),
+    on_log: normalize_on_log_option(input_options.on_log),
+    bail: input_options.bail,
+    error_recovery: input_options.error_recovery,
+    drop: input_options
+      .drop
+      .map(|kinds| {
+        kinds.iter().map(|kind| DropKind::try_from(kind.as_str())).collect::<Result<Vec<_>, _>>()
+      })
+      .transpose()
+      .map_err(|err| napi::Error::new(napi::Status::GenericFailure, err))?,
+    drop_labels: input_options.drop_labels,
   };
 
   #[cfg(not(target_family = "wasm"))]
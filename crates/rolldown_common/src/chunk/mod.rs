@@ -64,10 +64,14 @@ impl Chunk {
   }
 
   pub fn import_path_for(&self, importee: &Chunk) -> String {
+    self.import_path_for_file(importee.absolute_preliminary_filename.as_ref().unwrap())
+  }
+
+  /// The relative path to import the file at the absolute path `filename` from this chunk.
+  pub fn import_path_for_file(&self, filename: &str) -> String {
     let importer_dir =
       self.absolute_preliminary_filename.as_ref().unwrap().as_path().parent().unwrap();
-    let importee_filename = importee.absolute_preliminary_filename.as_ref().unwrap();
-    let import_path = importee_filename.relative(importer_dir).as_path().expect_to_slash();
+    let import_path = filename.relative(importer_dir).as_path().expect_to_slash();
 
     if import_path.starts_with('.') {
       import_path
@@ -81,7 +85,9 @@ impl Chunk {
     options: &'a NormalizedBundlerOptions,
     rollup_pre_rendered_chunk: &RollupPreRenderedChunk,
   ) -> anyhow::Result<FilenameTemplate> {
-    let ret = if matches!(self.kind, ChunkKind::EntryPoint { is_user_defined, .. } if is_user_defined)
+    // Preserved modules mirror the sources, so they are all named like entries.
+    let ret = if options.preserve_modules
+      || matches!(self.kind, ChunkKind::EntryPoint { is_user_defined, .. } if is_user_defined)
     {
       options.entry_filenames.call(rollup_pre_rendered_chunk).await?
     } else {
@@ -96,7 +102,8 @@ impl Chunk {
     options: &'a NormalizedBundlerOptions,
     rollup_pre_rendered_chunk: &RollupPreRenderedChunk,
   ) -> anyhow::Result<FilenameTemplate> {
-    let ret = if matches!(self.kind, ChunkKind::EntryPoint { is_user_defined, .. } if is_user_defined)
+    let ret = if options.preserve_modules
+      || matches!(self.kind, ChunkKind::EntryPoint { is_user_defined, .. } if is_user_defined)
     {
       options.css_entry_filenames.call(rollup_pre_rendered_chunk).await?
     } else {
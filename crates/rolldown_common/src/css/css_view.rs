@@ -1,8 +1,9 @@
 use arcstr::ArcStr;
 use oxc::index::IndexVec;
+use rolldown_utils::indexmap::FxIndexMap;
 
 use crate::{
-  types::source_mutation::BoxedSourceMutation, ImportRecordIdx, ResolvedImportRecord,
+  types::source_mutation::BoxedSourceMutation, EmittedAsset, ImportRecordIdx, ResolvedImportRecord,
   SourceMutation,
 };
 
@@ -10,11 +11,75 @@ use crate::{
 pub struct CssView {
   pub source: ArcStr,
   pub import_records: IndexVec<ImportRecordIdx, ResolvedImportRecord>,
+  /// Source ranges of `@import` rules, indexed by the import record that each rule creates.
+  /// The ranges don't include the trailing line break.
+  pub at_import_ranges: IndexVec<ImportRecordIdx, (usize, usize)>,
+  /// Source range of the leading `@charset` rule, if there is one.
+  pub at_charset_range: Option<(usize, usize)>,
+  /// Top-level `@layer` statements, which are merged and hoisted to the top of the chunk.
+  pub at_layer_statements: Vec<CssLayerStatement>,
+  pub url_references: Vec<CssUrlReference>,
+  /// Set if the stylesheet is a css module, such as `foo.module.css`.
+  pub local_classes: Option<CssLocalClasses>,
   pub mutations: Vec<BoxedSourceMutation>,
+  /// Sourcemaps returned by the `load`, `transform` and `transformCss` hooks, which map `source` back to the
+  /// original file.
+  pub sourcemap_chain: Vec<rolldown_sourcemap::SourceMap>,
+}
+
+impl CssView {
+  pub fn at_import_rule(&self, idx: ImportRecordIdx) -> &str {
+    let (start, end) = self.at_import_ranges[idx];
+    &self.source[start..end]
+  }
+
+  pub fn at_charset_rule(&self) -> Option<&str> {
+    self.at_charset_range.map(|(start, end)| &self.source[start..end])
+  }
+}
+
+/// A `@layer a, b;` statement that declares the order of layers without defining any rules.
+#[derive(Debug)]
+pub struct CssLayerStatement {
+  /// Source range of the whole statement, not including the trailing line break.
+  pub range: (usize, usize),
+  pub names: Vec<String>,
+}
+
+/// Classes of a css module, which are scoped to the module and exported to js.
+#[derive(Debug, Default)]
+pub struct CssLocalClasses {
+  /// Original class names, mapped to the scoped names that replace them in the source.
+  pub names: FxIndexMap<String, String>,
+  pub rules: Vec<CssLocalRule>,
+}
+
+/// A style rule of a css module.
+#[derive(Debug)]
+pub struct CssLocalRule {
+  /// Source range of the whole rule, including its block.
+  pub range: (usize, usize),
+  /// Scoped classes referenced by each selector of the rule's selector list.
+  pub selectors: Vec<Vec<String>>,
+}
+
+/// A `url()` or `image-set()` reference that needs to be rebased once the stylesheet is emitted.
+#[derive(Debug)]
+pub struct CssUrlReference {
+  /// Source range of the whole `url(...)` function or of the quoted string.
+  pub range: (usize, usize),
+  pub specifier: String,
+  /// Set if the specifier points to a local file. The file is only emitted as an asset in the generate
+  /// stage if the stylesheet is included, so tree-shaken stylesheets don't emit their assets.
+  pub asset: Option<EmittedAsset>,
+  /// Set once `asset` is emitted.
+  pub asset_reference_id: Option<ArcStr>,
 }
 
 #[derive(Debug, Default)]
 pub struct CssRenderer {
+  /// Ranges of `@import`, `@charset` and `@layer` statements. These rules are hoisted to the top of the
+  /// chunk, so they need to be removed from the module's content.
   pub at_import_ranges: Vec<(usize, usize)>,
 }
 
@@ -24,4 +89,26 @@ impl SourceMutation for CssRenderer {
       magic_string.remove(range.0, range.1);
     }
   }
+
+  fn removed_ranges(&self) -> &[(usize, usize)] {
+    &self.at_import_ranges
+  }
+}
+
+/// Removes the rules of a css module that only match classes, which are never used by js.
+#[derive(Debug, Default)]
+pub struct CssUnusedRulesRemover {
+  pub ranges: Vec<(usize, usize)>,
+}
+
+impl SourceMutation for CssUnusedRulesRemover {
+  fn apply(&self, magic_string: &mut string_wizard::MagicString<'_>) {
+    for range in &self.ranges {
+      magic_string.remove(range.0, range.1);
+    }
+  }
+
+  fn removed_ranges(&self) -> &[(usize, usize)] {
+    &self.ranges
+  }
 }
@@ -5,8 +5,9 @@ use rolldown_rstr::Rstr;
 use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{
-  side_effects::DeterminedSideEffects, AstScopes, EcmaAstIdx, ExportsKind, ImportRecordIdx,
-  LocalExport, ModuleDefFormat, ModuleId, NamedImport, ResolvedImportRecord, StmtInfos, SymbolRef,
+  side_effects::DeterminedSideEffects, AstScopes, ConstEnums, Constants, EcmaAstIdx, ExportsKind,
+  ImportRecordIdx, LocalExport, ModuleDefFormat, ModuleId, NamedImport, ResolvedImportRecord,
+  StmtInfos, SymbolRef,
 };
 
 bitflags! {
@@ -101,6 +102,11 @@ pub struct EcmaView {
   pub side_effects: DeterminedSideEffects,
   pub ast_usage: EcmaModuleAstUsage,
   pub self_referenced_class_decl_symbol_ids: FxHashSet<SymbolId>,
+  /// The `const enum`s of TypeScript modules, whose members are inlined into their usages across modules.
+  pub const_enums: ConstEnums,
+  /// The top-level `const`s initialized with primitive literals, whose values decide the branches of
+  /// the conditions reading them in the link stage.
+  pub constants: Constants,
   pub meta: EcmaViewMeta,
 }
 
@@ -110,5 +116,9 @@ bitflags! {
         const ModuleRef = 1;
         const ExportsRef = 1 << 1;
         const ModuleOrExports = Self::ModuleRef.bits() | Self::ExportsRef.bits();
+        /// The module sets `exports.__esModule`, so its `default` export is `exports.default` under interop.
+        const EsModuleMarker = 1 << 2;
+        /// The module uses `await` outside of functions, including `for await`.
+        const TopLevelAwait = 1 << 3;
     }
 }
@@ -1,13 +1,15 @@
-use crate::{AssetSource, FileNameRenderOptions, NormalizedBundlerOptions, Output, OutputAsset};
+use crate::{
+  AssetFilenamesOutputOption, AssetSource, FileNameRenderOptions, FilenameTemplate,
+  NormalizedBundlerOptions, Output, OutputAsset, RollupPreRenderedAsset,
+};
 use arcstr::ArcStr;
-use dashmap::{DashMap, DashSet};
+use dashmap::{mapref::entry::Entry, DashMap, DashSet};
 use rolldown_utils::extract_hash_pattern::extract_hash_pattern;
-use rolldown_utils::sanitize_file_name::sanitize_file_name;
-use rolldown_utils::xxhash::xxhash_base64_url;
+use rolldown_utils::xxhash::{xxhash_128, xxhash_base64_url};
 use std::ffi::OsStr;
 use std::path::Path;
-use std::sync::atomic::{AtomicUsize, Ordering};
-use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
 
 #[derive(Debug)]
 pub struct EmittedAsset {
@@ -17,11 +19,30 @@ pub struct EmittedAsset {
   pub source: AssetSource,
 }
 
+#[derive(Debug, Default)]
+pub struct EmittedChunk {
+  pub name: Option<ArcStr>,
+  /// The file name of the chunk, which is used as is instead of `entryFileNames`.
+  pub file_name: Option<ArcStr>,
+  pub id: String,
+  pub importer: Option<String>,
+  /// Ids of entries after one of which this chunk is guaranteed to be loaded. Modules that are
+  /// already loaded by all of these entries won't be loaded by this chunk again.
+  pub implicitly_loaded_after_one_of: Vec<String>,
+}
+
 #[derive(Debug)]
 pub struct FileEmitter {
   source_hash_to_reference_id: DashMap<ArcStr, ArcStr>,
   names: DashMap<ArcStr, u32>,
   files: DashMap<ArcStr, EmittedAsset>,
+  /// Reference ids of assets whose file names wait for the `assetFileNames` function, in the emitting order.
+  pending_files: Mutex<Vec<ArcStr>>,
+  /// Keep the emitting order, so that emitted chunks are added as entries in a stable order.
+  chunks: Mutex<Vec<(ArcStr, Arc<EmittedChunk>)>>,
+  chunk_file_names: DashMap<ArcStr, ArcStr>,
+  /// Set once emitted chunks are loaded as entries. Chunks emitted later would never be loaded.
+  chunks_sealed: AtomicBool,
   base_reference_id: AtomicUsize,
   options: Arc<NormalizedBundlerOptions>,
   /// Mark the files that have been emitted to bundle.
@@ -34,32 +55,126 @@ impl FileEmitter {
       source_hash_to_reference_id: DashMap::default(),
       names: DashMap::default(),
       files: DashMap::default(),
+      pending_files: Mutex::default(),
+      chunks: Mutex::default(),
+      chunk_file_names: DashMap::default(),
+      chunks_sealed: AtomicBool::new(false),
       base_reference_id: AtomicUsize::new(0),
       options,
       emitted_files: DashSet::default(),
     }
   }
 
-  pub fn emit_file(&self, mut file: EmittedAsset) -> ArcStr {
-    let hash: ArcStr = xxhash_base64_url(file.source.as_bytes()).into();
-    // Deduplicate assets if an explicit fileName is not provided
-    if file.file_name.is_none() {
-      if let Some(reference_id) = self.source_hash_to_reference_id.get(&hash) {
-        return reference_id.value().clone();
+  pub fn emit_file(&self, file: EmittedAsset) -> ArcStr {
+    let hash: ArcStr =
+      self.options.hash_characters.encode(xxhash_128(file.source.as_bytes())).into();
+    // Assets are emitted concurrently, so the entry is held until the asset is added. Otherwise assets
+    // with the same source could both miss the lookup and be emitted twice.
+    match self.source_hash_to_reference_id.entry(hash.clone()) {
+      Entry::Occupied(entry) => {
+        // Deduplicate assets if an explicit fileName is not provided
+        if file.file_name.is_none() {
+          return entry.get().clone();
+        }
+        self.add_file(file, &hash)
+      }
+      Entry::Vacant(entry) => {
+        // Assets without an explicit fileName reuse the first asset with the same source, including the
+        // ones named explicitly, so all references point at a single file.
+        let reference_id = self.add_file(file, &hash);
+        entry.insert(reference_id.clone());
+        reference_id
       }
     }
+  }
 
+  fn add_file(&self, mut file: EmittedAsset, hash: &ArcStr) -> ArcStr {
     let reference_id = self.assign_reference_id(file.file_name.clone());
+    self.generate_file_name(&mut file, hash);
     if file.file_name.is_none() {
-      self.source_hash_to_reference_id.insert(hash.clone(), reference_id.clone());
+      self.pending_files.lock().expect("should not be poisoned").push(reference_id.clone());
     }
-
-    self.generate_file_name(&mut file, &hash);
     self.files.insert(reference_id.clone(), file);
     reference_id
   }
 
+  /// The `assetFileNames` and `sanitizeFileName` functions are async, so the file names of assets emitted
+  /// while they're used are generated here rather than in `emit_file`.
+  pub async fn generate_pending_file_names(&self) -> anyhow::Result<()> {
+    let pending_files =
+      std::mem::take(&mut *self.pending_files.lock().expect("should not be poisoned"));
+    for reference_id in pending_files {
+      self.generate_pending_file_name(&reference_id).await?;
+    }
+    Ok(())
+  }
+
+  /// Returns the file name of an emitted asset, generating it first if it's still pending. Used when
+  /// the file name is needed before the generate stage, such as for the url exported by `file` modules.
+  pub async fn resolve_file_name(&self, reference_id: &ArcStr) -> anyhow::Result<ArcStr> {
+    let is_pending = {
+      let mut pending_files = self.pending_files.lock().expect("should not be poisoned");
+      let position = pending_files.iter().position(|pending| pending == reference_id);
+      position.map(|idx| pending_files.remove(idx)).is_some()
+    };
+    if is_pending {
+      self.generate_pending_file_name(reference_id).await?;
+    }
+    self.try_get_file_name(reference_id).map_err(anyhow::Error::msg)
+  }
+
+  async fn generate_pending_file_name(&self, reference_id: &ArcStr) -> anyhow::Result<()> {
+    let Some(asset) = self.files.get(reference_id).map(|file| RollupPreRenderedAsset {
+      name: file.name.clone(),
+      original_file_name: file.original_file_name.clone(),
+      source: file.source.clone(),
+    }) else {
+      return Ok(());
+    };
+    let template = FilenameTemplate::new(self.options.asset_filenames.call(&asset).await?);
+    let (stem, extension) = split_asset_name(asset.name.as_deref());
+    let name = match stem {
+      Some(stem) => Some(self.options.sanitize_filename.call(stem).await?),
+      None => None,
+    };
+    let hash: ArcStr =
+      self.options.hash_characters.encode(xxhash_128(asset.source.as_bytes())).into();
+    let file_name = self.render_file_name(&template, name.as_deref(), extension, &hash)?;
+    if let Some(mut file) = self.files.get_mut(reference_id) {
+      file.file_name = Some(file_name);
+    }
+    Ok(())
+  }
+
+  pub fn emit_chunk(&self, chunk: EmittedChunk) -> anyhow::Result<ArcStr> {
+    let mut chunks = self.chunks.lock().expect("should not be poisoned");
+    if self.chunks_sealed.load(Ordering::Relaxed) {
+      return Err(anyhow::format_err!(
+        "Chunks can only be emitted before modules are loaded, such as in the `buildStart` hook, but `{}` is emitted later.",
+        chunk.id
+      ));
+    }
+    let reference_id = self.assign_reference_id(None);
+    chunks.push((reference_id.clone(), chunk.into()));
+    Ok(reference_id)
+  }
+
+  /// Returns the chunks to be loaded as entries. Emitting chunks after this is an error.
+  pub fn seal_emitted_chunks(&self) -> Vec<(ArcStr, Arc<EmittedChunk>)> {
+    let chunks = self.chunks.lock().expect("should not be poisoned");
+    self.chunks_sealed.store(true, Ordering::Relaxed);
+    chunks.clone()
+  }
+
+  /// The file name of an emitted chunk is only known after the chunk is rendered.
+  pub fn set_chunk_file_name(&self, reference_id: ArcStr, file_name: ArcStr) {
+    self.chunk_file_names.insert(reference_id, file_name);
+  }
+
   pub fn try_get_file_name(&self, reference_id: &str) -> Result<ArcStr, String> {
+    if let Some(file_name) = self.chunk_file_names.get(reference_id) {
+      return Ok(file_name.clone());
+    }
     let file = self
       .files
       .get(reference_id)
@@ -73,6 +188,16 @@ impl FileEmitter {
       .unwrap_or_else(|_| panic!("{reference_id} should have file name"))
   }
 
+  /// The file names of emitted assets by their reference ids. Assets whose file names are still pending
+  /// are skipped.
+  pub fn asset_file_names(&self) -> Vec<(ArcStr, ArcStr)> {
+    self
+      .files
+      .iter()
+      .filter_map(|file| Some((file.key().clone(), file.file_name.clone()?)))
+      .collect()
+  }
+
   pub fn assign_reference_id(&self, filename: Option<ArcStr>) -> ArcStr {
     xxhash_base64_url(
       filename
@@ -87,58 +212,90 @@ impl FileEmitter {
   }
 
   pub fn generate_file_name(&self, file: &mut EmittedAsset, hash: &ArcStr) {
-    if file.file_name.is_none() {
-      let path = file.name.as_deref().map(Path::new);
-      let extension = path.and_then(|x| x.extension().and_then(OsStr::to_str));
-      let name = path
-        .and_then(|x| x.file_stem().and_then(OsStr::to_str))
-        .map(|x| sanitize_file_name(x.into()));
-      let extract_hash_pattern = extract_hash_pattern(self.options.asset_filenames.template());
-      let mut file_name: ArcStr = self
-        .options
-        .asset_filenames
-        .render(&FileNameRenderOptions {
-          name: name.as_deref(),
-          hash: extract_hash_pattern
-            .map(|p| &hash.as_str()[..p.len.map_or(8, |hash_len| hash_len.max(6))]),
-          ext: extension,
-        })
-        .into();
-      // deconflict file name
-      if let Some(count) = self.names.get_mut(file_name.as_str()).as_deref_mut() {
-        *count += 1;
-        let extension = extension.map(|e| format!(".{e}")).unwrap_or_default();
-        file_name = format!(
-          "{}{count}{extension}",
-          &file_name.to_string()[..file_name.len() - extension.len()],
-        )
-        .into();
-      } else {
-        self.names.insert(file_name.clone(), 1);
+    if file.file_name.is_some() {
+      return;
+    }
+    let AssetFilenamesOutputOption::String(template) = &self.options.asset_filenames else {
+      return;
+    };
+    let (stem, extension) = split_asset_name(file.name.as_deref());
+    let name = match stem {
+      Some(stem) => {
+        // Names sanitized by a function are generated in `generate_pending_file_names`.
+        let Some(name) = self.options.sanitize_filename.try_call_sync(stem) else {
+          return;
+        };
+        Some(name)
       }
-
+      None => None,
+    };
+    let template = FilenameTemplate::new(template.clone());
+    // The file name is generated in `generate_pending_file_names` instead, which reports the error.
+    if let Ok(file_name) = self.render_file_name(&template, name.as_deref(), extension, hash) {
       file.file_name = Some(file_name);
     }
   }
 
-  pub fn add_additional_files(&self, bundle: &mut Vec<Output>) {
+  fn render_file_name(
+    &self,
+    template: &FilenameTemplate,
+    name: Option<&str>,
+    extension: Option<&str>,
+    hash: &ArcStr,
+  ) -> anyhow::Result<ArcStr> {
+    let hash_len = extract_hash_pattern(template.template())
+      .map(|p| self.options.hash_characters.hash_len(p.len))
+      .transpose()?;
+    let mut file_name: ArcStr = template
+      .render(&FileNameRenderOptions {
+        name,
+        hash: hash_len.map(|hash_len| &hash.as_str()[..hash_len]),
+        ext: extension,
+      })
+      .into();
+    // deconflict file name
+    if let Some(count) = self.names.get_mut(file_name.as_str()).as_deref_mut() {
+      *count += 1;
+      let extension = extension.map(|e| format!(".{e}")).unwrap_or_default();
+      file_name = format!(
+        "{}{count}{extension}",
+        &file_name.to_string()[..file_name.len() - extension.len()],
+      )
+      .into();
+    } else {
+      self.names.insert(file_name.clone(), 1);
+    }
+    Ok(file_name)
+  }
+
+  pub async fn add_additional_files(&self, bundle: &mut Vec<Output>) -> anyhow::Result<()> {
+    self.generate_pending_file_names().await?;
+    let mut assets = vec![];
     self.files.iter_mut().for_each(|mut file| {
       let (key, value) = file.pair_mut();
       if self.emitted_files.contains(key) {
         return;
       }
       self.emitted_files.insert(key.clone());
-      bundle.push(Output::Asset(Box::new(OutputAsset {
+      assets.push(Output::Asset(Box::new(OutputAsset {
         filename: value.file_name.clone().expect("should have file name"),
         source: std::mem::take(&mut value.source),
         name: std::mem::take(&mut value.name),
         original_file_name: std::mem::take(&mut value.original_file_name),
       })));
     });
+    // Files are emitted concurrently while modules are loaded, make sure the order is deterministic
+    assets.sort_unstable_by(|a, b| a.filename().cmp(b.filename()));
+    bundle.extend(assets);
+    Ok(())
   }
 
   pub fn clear(&self) {
     self.files.clear();
+    self.pending_files.lock().expect("should not be poisoned").clear();
+    self.chunks.lock().expect("should not be poisoned").clear();
+    self.chunk_file_names.clear();
+    self.chunks_sealed.store(false, Ordering::Relaxed);
     self.names.clear();
     self.source_hash_to_reference_id.clear();
     self.base_reference_id.store(0, Ordering::Relaxed);
@@ -146,4 +303,13 @@ impl FileEmitter {
   }
 }
 
+/// Split the name of an asset into the `[name]` and the `[ext]` of its file name.
+fn split_asset_name(name: Option<&str>) -> (Option<&str>, Option<&str>) {
+  let path = name.map(Path::new);
+  (
+    path.and_then(|x| x.file_stem().and_then(OsStr::to_str)),
+    path.and_then(|x| x.extension().and_then(OsStr::to_str)),
+  )
+}
+
 pub type SharedFileEmitter = Arc<FileEmitter>;
@@ -3,7 +3,9 @@ use rolldown_utils::indexmap::FxIndexMap;
 use std::{collections::HashMap, fmt::Debug, path::PathBuf};
 use types::advanced_chunks_options::AdvancedChunksOptions;
 use types::checks_options::ChecksOptions;
+use types::commonjs_options::CommonJsOptions;
 use types::inject_import::InjectImport;
+use types::wasm_options::WasmOptions;
 use types::watch_option::WatchOption;
 
 #[cfg(feature = "deserialize_bundler_options")]
@@ -12,16 +14,29 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Deserializer};
 #[cfg(feature = "deserialize_bundler_options")]
 use serde_json::Value;
+#[cfg(feature = "deserialize_bundler_options")]
+use types::es_module_flag::RawEsModuleFlag;
 use types::experimental_options::ExperimentalOptions;
+use types::generated_code_options::GeneratedCodeOptions;
 
 use self::types::treeshake::TreeshakeOptions;
 use self::types::{
-  es_module_flag::EsModuleFlag, input_item::InputItem, is_external::IsExternal,
+  assets_inline_limit::AssetsInlineLimit, charset::Charset, chunk_name_strategy::ChunkNameStrategy,
+  chunk_size_warning_limit::ChunkSizeWarningLimit, css_mode::CssMode,
+  decorator_options::DecoratorOptions, drop_kind::DropKind, es_module_flag::EsModuleFlag,
+  es_target::EsTarget, hash_characters::HashCharacters, input_item::InputItem,
+  is_external::IsExternal, json_options::JsonOptions, legal_comments::LegalComments,
+  log_level::LogLevel, minify_options::RawMinifyOptions, on_log::OnLog,
   output_exports::OutputExports, output_format::OutputFormat, output_option::AddonOutputOption,
   platform::Platform, resolve_options::ResolveOptions, source_map_type::SourceMapType,
   sourcemap_path_transform::SourceMapPathTransform,
 };
-use crate::{ChunkFilenamesOutputOption, ModuleType, SourceMapIgnoreList};
+#[cfg(feature = "deserialize_bundler_options")]
+use crate::InteropMode;
+use crate::{
+  AssetFilenamesOutputOption, ChunkFilenamesOutputOption, InteropOutputOption, ManualChunksOption,
+  ModuleType, SanitizeFilename, SourceMapIgnoreList,
+};
 
 pub mod types;
 
@@ -45,6 +60,9 @@ pub struct BundlerOptions {
   pub shim_missing_exports: Option<bool>,
   // --- options for output
   pub name: Option<String>,
+  /// Make the global of `umd` bundles expose a `noConflict()` method, which restores the previous value of
+  /// the global and returns the exports. Defaults to `false`.
+  pub no_conflict: Option<bool>,
   #[cfg_attr(
     feature = "deserialize_bundler_options",
     serde(default, deserialize_with = "deserialize_chunk_filenames"),
@@ -69,13 +87,45 @@ pub struct BundlerOptions {
     schemars(with = "Option<String>")
   )]
   pub css_chunk_filenames: Option<ChunkFilenamesOutputOption>,
-  pub asset_filenames: Option<String>,
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(default, deserialize_with = "deserialize_asset_filenames"),
+    schemars(with = "Option<String>")
+  )]
+  pub asset_filenames: Option<AssetFilenamesOutputOption>,
+  /// The characters of `[hash]` in file names. Defaults to `base64`.
+  pub hash_characters: Option<HashCharacters>,
+  /// Whether to sanitize `[name]` of chunks and assets, or a function to do it. Defaults to `true`.
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(default, deserialize_with = "deserialize_sanitize_filename"),
+    schemars(with = "Option<bool>")
+  )]
+  pub sanitize_filename: Option<SanitizeFilename>,
   pub dir: Option<String>,
+  /// A directory whose files are copied as is into `dir` when writing the output, such as `favicon.ico` or
+  /// `robots.txt`. Files conflicting with the emitted ones aren't copied. Relative paths are resolved from
+  /// `cwd`.
+  pub public_dir: Option<String>,
   pub format: Option<OutputFormat>,
   pub exports: Option<OutputExports>,
   pub globals: Option<HashMap<String, String>>,
+  pub generated_code: Option<GeneratedCodeOptions>,
   pub sourcemap: Option<SourceMapType>,
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(default, deserialize_with = "deserialize_es_module"),
+    schemars(with = "Option<RawEsModuleFlag>")
+  )]
   pub es_module: Option<EsModuleFlag>,
+  /// How external modules are interpreted when they are imported in the `cjs`, `iife` and `umd` format.
+  /// Defaults to `auto`.
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(default, deserialize_with = "deserialize_interop"),
+    schemars(with = "Option<InteropMode>")
+  )]
+  pub interop: Option<InteropOutputOption>,
   #[cfg_attr(
     feature = "deserialize_bundler_options",
     serde(default, deserialize_with = "deserialize_addon"),
@@ -115,6 +165,14 @@ pub struct BundlerOptions {
 
   /// Key is the file extension. The extension should start with a `.`. E.g. `".txt"`.
   pub module_types: Option<HashMap<String, ModuleType>>,
+  /// Inline `file` modules smaller than this many bytes as base64 data urls instead of emitting them as
+  /// assets, or a function deciding it by the module id and the size. Nothing is inlined by default.
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(default, deserialize_with = "deserialize_assets_inline_limit"),
+    schemars(with = "Option<usize>")
+  )]
+  pub assets_inline_limit: Option<AssetsInlineLimit>,
   // --- options for resolve
   pub resolve: Option<ResolveOptions>,
   #[cfg_attr(
@@ -123,26 +181,154 @@ pub struct BundlerOptions {
   )]
   pub treeshake: TreeshakeOptions,
   pub experimental: Option<ExperimentalOptions>,
-  pub minify: Option<bool>,
+  /// Minify the rendered chunks with the oxc minifier. An object enables the steps of minification
+  /// separately.
+  pub minify: Option<RawMinifyOptions>,
+  /// Strip the region comments, blank lines and indentation that rolldown inserts between modules and in the
+  /// code it generates. The code of modules isn't minified. Defaults to `false`.
+  pub compact: Option<bool>,
   #[cfg_attr(
     feature = "deserialize_bundler_options",
     schemars(with = "Option<HashMap<String, String>>")
   )]
   pub define: Option<FxIndexMap<String, String>>,
+  /// Assign the exports of `iife` and `umd` bundles onto the existing object at `name`
+  /// (`this.MyLib.utils = this.MyLib.utils || {}`) instead of overwriting it, so multiple bundles can share a
+  /// namespace. Defaults to `false`.
   pub extend: Option<bool>,
+  /// Whether chunks of the `cjs`, `iife`, `umd` and `system` format start with `"use strict"`. If it's not
+  /// set, the directive is only emitted when every module of the chunk is strict already, either as an ES
+  /// module or by its own `"use strict"` prologue.
+  pub strict: Option<bool>,
   pub profiler_names: Option<bool>,
+  /// Import the given bindings into the modules that use them as free variables, such as `Buffer` or
+  /// `$`, so globals can be shimmed without editing each module. Modules that don't use them are left
+  /// as is.
   pub inject: Option<Vec<InjectImport>>,
   pub external_live_bindings: Option<bool>,
+  /// Keep the import attributes of external modules, such as `with { type: 'json' }`, in the imports
+  /// of `esm` chunks. Defaults to `true`.
+  pub external_import_attributes: Option<bool>,
+  /// Wrap namespace objects synthesized by rolldown with `Object.freeze()`. Defaults to `true`.
+  pub freeze: Option<bool>,
   pub inline_dynamic_imports: Option<bool>,
+  /// Keep `import()` expressions as they are in the `cjs` format. If it's `false`, they are turned into
+  /// `Promise.resolve().then(function() { return require(...); })` for tools that can't parse `import()` in
+  /// CommonJS files. Defaults to `true`.
+  pub dynamic_import_in_cjs: Option<bool>,
+  /// Put modules into chunks with the given names, either by listing the modules of each chunk or by a
+  /// function that returns the name of the chunk of a module.
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(default, deserialize_with = "deserialize_manual_chunks"),
+    schemars(with = "Option<HashMap<String, Vec<String>>>")
+  )]
+  pub manual_chunks: Option<ManualChunksOption>,
   pub advanced_chunks: Option<AdvancedChunksOptions>,
+  /// Merge common chunks smaller than this size, in bytes, into a larger common chunk that is loaded by
+  /// the same entries. Only chunks without side effects are merged.
+  pub experimental_min_chunk_size: Option<f64>,
+  /// How common chunks are named. Defaults to the first entered module of the chunk, like rollup.
+  pub chunk_name_strategy: Option<ChunkNameStrategy>,
+  /// Warn about chunks larger than these sizes, along with the modules contributing the most to them.
+  /// Chunks aren't checked by default.
+  pub chunk_size_warning_limit: Option<ChunkSizeWarningLimit>,
+  /// Put the runtime helpers of rolldown into a chunk shared by all other chunks, instead of duplicating
+  /// them into the chunks using them. Builds with a single chunk keep the helpers inline.
+  pub runtime_chunk: Option<bool>,
+  /// Keep the `name` of functions and classes as written in the source, even if they are renamed while
+  /// deconflicting or mangling. The names are restored by calls to the `__name` runtime helper.
+  pub keep_names: Option<bool>,
+  /// Lower the syntax of chunks to this version of ECMAScript, such as `es2015`. Syntax that can't be
+  /// lowered is reported as an error. Defaults to `esnext`, which keeps the syntax as is.
+  pub target: Option<EsTarget>,
+  /// What happens to legal comments, such as `/*! MIT */` or comments containing `@license`. They can be
+  /// kept in place (`inline`), removed (`none`), moved to the end of chunks (`eof`), or moved to a
+  /// `<chunk>.LEGAL.txt` file with (`linked`) or without (`external`) a comment linking it. Defaults to
+  /// `inline`.
+  pub legal_comments: Option<LegalComments>,
+  /// Set to `ascii` to escape the non-ASCII characters of chunks, such as `é` in strings, identifiers and
+  /// regexes, for servers that corrupt UTF-8 files. Defaults to `utf8`, which keeps them as is.
+  pub charset: Option<Charset>,
   pub checks: Option<ChecksOptions>,
+  /// How jsx in `.jsx` and `.tsx` modules is transformed. Defaults to the `jsx` options in `compilerOptions`
+  /// of `resolve.tsconfigFilename`. Pragma comments, such as `/** @jsxImportSource preact */`, override
+  /// these options per module.
   #[cfg_attr(
     feature = "deserialize_bundler_options",
     serde(deserialize_with = "deserialize_jsx", default),
     schemars(with = "Option<HashMap<String, String>>")
   )]
   pub jsx: Option<JsxOptions>,
+  /// How decorators of TypeScript modules are transformed. Each option defaults to its counterpart in
+  /// `compilerOptions` of `resolve.tsconfigFilename`, which is `experimentalDecorators` for `legacy`.
+  pub decorator: Option<DecoratorOptions>,
+  /// How JSON modules are transformed.
+  pub json: Option<JsonOptions>,
+  /// How WebAssembly modules are bundled.
+  pub wasm: Option<WasmOptions>,
+  /// How CommonJS modules and `require` calls are handled.
+  pub commonjs: Option<CommonJsOptions>,
   pub watch: Option<WatchOption>,
+  /// Avoid output patterns that break under hardened JavaScript (SES / frozen intrinsics) and check the
+  /// generated chunks for incompatible patterns.
+  pub ses_compatible: Option<bool>,
+  /// How the css of chunks is emitted. Defaults to extracting it into `.css` files.
+  pub css_mode: Option<CssMode>,
+  /// Code to insert at the top of each emitted css file, such as a license header.
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(default, deserialize_with = "deserialize_addon"),
+    schemars(with = "Option<String>")
+  )]
+  pub css_banner: Option<AddonOutputOption>,
+  /// Code to insert at the bottom of each emitted css file.
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(default, deserialize_with = "deserialize_addon"),
+    schemars(with = "Option<String>")
+  )]
+  pub css_footer: Option<AddonOutputOption>,
+  /// Browsers the css is lowered for, such as `["chrome90", "safari14"]`. Modern syntax is kept as is
+  /// if not set.
+  pub css_target: Option<Vec<String>>,
+  /// Emit a chunk for each module, keeping the structure of the sources instead of bundling them. The css
+  /// of each module is emitted next to its js chunk.
+  pub preserve_modules: Option<bool>,
+  /// With `preserve_modules`, modules inside this directory are named after their paths relative to it, so
+  /// `src/` can be stripped from the output paths. Other modules are named after their paths relative to
+  /// the common directory of all modules. Relative paths are resolved against `cwd`.
+  pub preserve_modules_root: Option<String>,
+  /// The public path that emitted assets are served from, such as `https://cdn.example.com/assets/`.
+  /// Urls referencing emitted files in css and js, such as assets, workers and `import.meta.ROLLUP_FILE_URL_*`,
+  /// are prefixed with it. Use `"auto"` to keep relative urls in css and resolve urls in js against the
+  /// location of the chunk at runtime.
+  pub base: Option<String>,
+  /// Diagnostics less severe than the level are dropped. Errors are always reported. Defaults to `info`.
+  pub log_level: Option<LogLevel>,
+  /// Receives each diagnostic that passes `log_level` before it's reported, and decides whether to
+  /// report, rewrite, drop it or fail the build with it. Called after the `on_log` hook of plugins.
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(default, skip_deserializing),
+    schemars(skip)
+  )]
+  pub on_log: Option<OnLog>,
+  /// Whether to stop loading the dependencies of a module once it fails to load, transform or scan, or one
+  /// of its imports fails to resolve. With `false`, failed modules and imports are replaced with
+  /// placeholders, so the rest of the module graph is still loaded and all errors are reported at once.
+  /// Defaults to `true`.
+  pub bail: Option<bool>,
+  /// Whether to replace modules that fail to load, transform or parse, and imports that fail to resolve,
+  /// with modules throwing the error once they are imported, so the rest of the bundle is still emitted,
+  /// e.g. for a dev server to display the error in the browser. The errors are reported as warnings then.
+  /// Defaults to `false`.
+  pub error_recovery: Option<bool>,
+  /// Remove `console` API calls or `debugger` statements from modules while they are transformed, before
+  /// tree shaking, whether chunks are minified or not.
+  pub drop: Option<Vec<DropKind>>,
+  /// Remove the statements labeled with these names, such as `DEV: console.log(value)`.
+  pub drop_labels: Option<Vec<String>>,
 }
 
 #[cfg(feature = "deserialize_bundler_options")]
@@ -154,6 +340,35 @@ where
   Ok(deserialized.map(IsExternal::from_vec))
 }
 
+#[cfg(feature = "deserialize_bundler_options")]
+fn deserialize_es_module<'de, D>(deserializer: D) -> Result<Option<EsModuleFlag>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let deserialized = Option::<RawEsModuleFlag>::deserialize(deserializer)?;
+  Ok(deserialized.map(From::from))
+}
+
+#[cfg(feature = "deserialize_bundler_options")]
+fn deserialize_interop<'de, D>(deserializer: D) -> Result<Option<InteropOutputOption>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let deserialized = Option::<InteropMode>::deserialize(deserializer)?;
+  Ok(deserialized.map(From::from))
+}
+
+#[cfg(feature = "deserialize_bundler_options")]
+fn deserialize_manual_chunks<'de, D>(
+  deserializer: D,
+) -> Result<Option<ManualChunksOption>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let deserialized = Option::<FxIndexMap<String, Vec<String>>>::deserialize(deserializer)?;
+  Ok(deserialized.map(From::from))
+}
+
 #[cfg(feature = "deserialize_bundler_options")]
 fn deserialize_addon<'de, D>(deserializer: D) -> Result<Option<AddonOutputOption>, D::Error>
 where
@@ -174,6 +389,39 @@ where
   Ok(deserialized.map(From::from))
 }
 
+#[cfg(feature = "deserialize_bundler_options")]
+fn deserialize_asset_filenames<'de, D>(
+  deserializer: D,
+) -> Result<Option<AssetFilenamesOutputOption>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let deserialized = Option::<String>::deserialize(deserializer)?;
+  Ok(deserialized.map(From::from))
+}
+
+#[cfg(feature = "deserialize_bundler_options")]
+fn deserialize_sanitize_filename<'de, D>(
+  deserializer: D,
+) -> Result<Option<SanitizeFilename>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let deserialized = Option::<bool>::deserialize(deserializer)?;
+  Ok(deserialized.map(From::from))
+}
+
+#[cfg(feature = "deserialize_bundler_options")]
+fn deserialize_assets_inline_limit<'de, D>(
+  deserializer: D,
+) -> Result<Option<AssetsInlineLimit>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let deserialized = Option::<usize>::deserialize(deserializer)?;
+  Ok(deserialized.map(From::from))
+}
+
 #[cfg(feature = "deserialize_bundler_options")]
 fn deserialize_treeshake<'de, D>(deserializer: D) -> Result<TreeshakeOptions, D::Error>
 where
@@ -185,17 +433,53 @@ where
     None | Some(Value::Bool(true)) => {
       Ok(TreeshakeOptions::Option(types::treeshake::InnerOptions {
         module_side_effects: types::treeshake::ModuleSideEffects::Boolean(true),
+        manual_pure_functions: None,
+        property_read_side_effects: types::treeshake::PropertyReadSideEffects::default(),
+        annotations: None,
+        unknown_global_side_effects: None,
       }))
     }
     Some(Value::Object(obj)) => {
       let module_side_effects = obj.get("moduleSideEffects").map_or_else(
         || Ok(types::treeshake::ModuleSideEffects::Boolean(true)),
-        |v| match v {
-          Value::Bool(b) => Ok(types::treeshake::ModuleSideEffects::Boolean(*b)),
-          _ => Err(serde::de::Error::custom("moduleSideEffects should be a `true` or `false`")),
-        },
+        |v| types::treeshake::deserialize_module_side_effects(v).map_err(serde::de::Error::custom),
       )?;
-      Ok(TreeshakeOptions::Option(types::treeshake::InnerOptions { module_side_effects }))
+      let manual_pure_functions = obj
+        .get("manualPureFunctions")
+        .map(|v| {
+          Vec::<String>::deserialize(v)
+            .map(|names| {
+              names
+                .into_iter()
+                .map(rolldown_utils::pattern_filter::StringOrRegex::String)
+                .collect::<Vec<_>>()
+            })
+            .map_err(serde::de::Error::custom)
+        })
+        .transpose()?;
+      let property_read_side_effects = obj
+        .get("propertyReadSideEffects")
+        .map(types::treeshake::PropertyReadSideEffects::deserialize)
+        .transpose()
+        .map_err(serde::de::Error::custom)?
+        .unwrap_or_default();
+      let annotations = obj
+        .get("annotations")
+        .map(bool::deserialize)
+        .transpose()
+        .map_err(serde::de::Error::custom)?;
+      let unknown_global_side_effects = obj
+        .get("unknownGlobalSideEffects")
+        .map(bool::deserialize)
+        .transpose()
+        .map_err(serde::de::Error::custom)?;
+      Ok(TreeshakeOptions::Option(types::treeshake::InnerOptions {
+        module_side_effects,
+        manual_pure_functions,
+        property_read_side_effects,
+        annotations,
+        unknown_global_side_effects,
+      }))
     }
     _ => Err(serde::de::Error::custom("treeshake should be a boolean or an object")),
   }
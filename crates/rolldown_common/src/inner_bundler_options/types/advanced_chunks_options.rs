@@ -1,3 +1,5 @@
+use std::{fmt::Debug, future::Future, pin::Pin};
+
 use rolldown_utils::js_regex::HybridRegex;
 #[cfg(feature = "deserialize_bundler_options")]
 use schemars::JsonSchema;
@@ -13,6 +15,9 @@ use serde::{Deserialize, Deserializer};
 pub struct AdvancedChunksOptions {
   pub min_share_count: Option<u32>,
   pub min_size: Option<f64>,
+  /// Groups larger than this are split into multiple chunks of the same name, in the execution order of
+  /// their modules. Applies to groups that don't set `maxSize` themselves.
+  pub max_size: Option<f64>,
   pub groups: Option<Vec<MatchGroup>>,
 }
 
@@ -29,15 +34,52 @@ pub struct MatchGroup {
     serde(deserialize_with = "deserialize_test", default),
     schemars(with = "Option<String>")
   )]
-  pub test: Option<HybridRegex>,
+  pub test: Option<MatchGroupTest>,
   // pub share_count: Option<u32>,
   pub priority: Option<u32>,
   pub min_size: Option<f64>,
+  /// A chunk of the group is never larger than this, unless it only contains a single module.
+  pub max_size: Option<f64>,
   pub min_share_count: Option<u32>,
 }
 
+type MatchGroupTestFunction = dyn Fn(&str) -> Pin<Box<(dyn Future<Output = anyhow::Result<Option<bool>>> + Send + 'static)>>
+  + Send
+  + Sync;
+
+/// Selects the modules of a group by their ids.
+pub enum MatchGroupTest {
+  Regex(HybridRegex),
+  /// Modules are selected if the function returns `true`.
+  Fn(Box<MatchGroupTestFunction>),
+}
+
+impl Debug for MatchGroupTest {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Regex(value) => write!(f, "MatchGroupTest::Regex({value:?})"),
+      Self::Fn(_) => write!(f, "MatchGroupTest::Fn(...)"),
+    }
+  }
+}
+
+impl MatchGroupTest {
+  pub async fn call(&self, id: &str) -> anyhow::Result<bool> {
+    match self {
+      Self::Regex(value) => Ok(value.matches(id)),
+      Self::Fn(value) => Ok(value(id).await?.unwrap_or(false)),
+    }
+  }
+}
+
+impl From<HybridRegex> for MatchGroupTest {
+  fn from(value: HybridRegex) -> Self {
+    Self::Regex(value)
+  }
+}
+
 #[cfg(feature = "deserialize_bundler_options")]
-fn deserialize_test<'de, D>(deserializer: D) -> Result<Option<HybridRegex>, D::Error>
+fn deserialize_test<'de, D>(deserializer: D) -> Result<Option<MatchGroupTest>, D::Error>
 where
   D: Deserializer<'de>,
 {
@@ -46,5 +88,5 @@ where
     .map(|inner| HybridRegex::new(&inner))
     .transpose()
     .map_err(|e| serde::de::Error::custom(format!("failed to deserialize {e:?} to HybridRegex")))?;
-  Ok(transformed)
+  Ok(transformed.map(From::from))
 }
@@ -0,0 +1,43 @@
+use std::{fmt::Debug, future::Future, pin::Pin};
+
+type AssetsInlineLimitFunction = dyn Fn(
+    &str,  // id
+    usize, // size
+  ) -> Pin<Box<(dyn Future<Output = anyhow::Result<bool>> + Send + 'static)>>
+  + Send
+  + Sync;
+
+/// `assetsInlineLimit`, which decides whether the content of a `file` module is inlined as a base64
+/// data url instead of being emitted as an asset.
+///
+/// - A number inlines the files smaller than this many bytes.
+/// - A function receives the module id and the size of the file in bytes, and returns whether to
+///   inline it.
+pub enum AssetsInlineLimit {
+  Bytes(usize),
+  Fn(Box<AssetsInlineLimitFunction>),
+}
+
+impl Debug for AssetsInlineLimit {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Bytes(value) => write!(f, "AssetsInlineLimit::Bytes({value:?})"),
+      Self::Fn(_) => write!(f, "AssetsInlineLimit::Fn(...)"),
+    }
+  }
+}
+
+impl AssetsInlineLimit {
+  pub async fn should_inline(&self, id: &str, size: usize) -> anyhow::Result<bool> {
+    match self {
+      Self::Bytes(limit) => Ok(size < *limit),
+      Self::Fn(value) => value(id, size).await,
+    }
+  }
+}
+
+impl From<usize> for AssetsInlineLimit {
+  fn from(value: usize) -> Self {
+    Self::Bytes(value)
+  }
+}
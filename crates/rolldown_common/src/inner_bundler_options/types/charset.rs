@@ -0,0 +1,31 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+/// The characters allowed in the code of chunks.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "lowercase", deny_unknown_fields)
+)]
+pub enum Charset {
+  /// Escape non-ASCII characters, for servers that don't serve chunks as UTF-8.
+  Ascii,
+  /// Keep non-ASCII characters as is.
+  #[default]
+  Utf8,
+}
+
+impl TryFrom<&str> for Charset {
+  type Error = String;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
+    match value {
+      "ascii" => Ok(Self::Ascii),
+      "utf8" => Ok(Self::Utf8),
+      _ => Err(format!("Unknown charset: {value:?}. Expected one of ascii or utf8")),
+    }
+  }
+}
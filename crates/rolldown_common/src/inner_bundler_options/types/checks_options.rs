@@ -1,8 +1,12 @@
+use rolldown_utils::pattern_filter::StringOrRegex;
 #[cfg(feature = "deserialize_bundler_options")]
 use schemars::JsonSchema;
 #[cfg(feature = "deserialize_bundler_options")]
 use serde::Deserialize;
 
+#[cfg(feature = "deserialize_bundler_options")]
+use super::watch_option::deserialize_string_or_regex;
+
 #[derive(Default, Debug)]
 #[cfg_attr(
   feature = "deserialize_bundler_options",
@@ -11,4 +15,28 @@ use serde::Deserialize;
 )]
 pub struct ChecksOptions {
   pub circular_dependency: Option<bool>,
+  /// Cycles going through a module that matches any of these patterns are not reported.
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(default, deserialize_with = "deserialize_string_or_regex"),
+    schemars(with = "Option<Vec<String>>")
+  )]
+  pub circular_dependency_ignore: Option<Vec<StringOrRegex>>,
+  /// Cycles whose modules all match any of these patterns are reported as errors rather than warnings.
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(default, deserialize_with = "deserialize_string_or_regex"),
+    schemars(with = "Option<Vec<String>>")
+  )]
+  pub circular_dependency_error: Option<Vec<StringOrRegex>>,
+  /// Warns about default imports of CommonJS modules that don't set `__esModule`, which get the whole
+  /// `module.exports`.
+  pub commonjs_default_import: Option<bool>,
+  /// Warns about named imports of CommonJS modules, which are read from `module.exports` at runtime.
+  pub commonjs_named_import: Option<bool>,
+  /// Warns about `require` calls of ES modules.
+  pub require_esm: Option<bool>,
+  /// Warns about modules that are only included for their side effects by `export * from` in barrel
+  /// modules, although none of their exports are used.
+  pub barrel_side_effects: Option<bool>,
 }
@@ -0,0 +1,36 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+/// How the `[name]` of common chunks is derived from their modules. Entry chunks are always named after
+/// their entry modules.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub enum ChunkNameStrategy {
+  /// Use the first entered module, which is the last executed one. It's the same as rollup.
+  #[default]
+  FirstEntered,
+  /// Use the largest module, which contributes the most code to the chunk.
+  Dominant,
+  /// Use the package if all modules of the chunk come from the same package in `node_modules`, such as
+  /// `react-dom`. Otherwise it falls back to `dominant`.
+  Package,
+}
+
+impl TryFrom<&str> for ChunkNameStrategy {
+  type Error = String;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
+    match value {
+      "firstEntered" => Ok(Self::FirstEntered),
+      "dominant" => Ok(Self::Dominant),
+      "package" => Ok(Self::Package),
+      _ => Err(format!("Unknown chunk name strategy: {value:?}")),
+    }
+  }
+}
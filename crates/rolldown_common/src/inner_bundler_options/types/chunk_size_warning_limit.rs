@@ -0,0 +1,25 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+/// Sizes in bytes above which a rendered chunk is reported with a `CHUNK_SIZE_EXCEEDED` warning. Chunks are
+/// measured after minification.
+#[derive(Debug, Default, Clone, Copy)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct ChunkSizeWarningLimit {
+  /// The limit of the size of the chunk as it's written to disk.
+  pub raw: Option<f64>,
+  /// The limit of the size of the chunk compressed by gzip, which is closer to the transferred size.
+  pub gzip: Option<f64>,
+}
+
+impl ChunkSizeWarningLimit {
+  pub fn is_enabled(&self) -> bool {
+    self.raw.is_some() || self.gzip.is_some()
+  }
+}
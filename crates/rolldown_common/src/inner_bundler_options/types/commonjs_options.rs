@@ -0,0 +1,130 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::{Deserialize, Deserializer};
+
+/// How CommonJS modules and `require` calls are handled. The defaults keep the behavior of rolldown,
+/// the options exist for tuning interop like `@rollup/plugin-commonjs` does.
+#[derive(Debug, Default, Clone, Copy)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct CommonJsOptions {
+  /// Keep `require` calls whose argument isn't a string literal as they are. If disabled, they throw an
+  /// error at runtime, since the required module can't be bundled. Defaults to `true`.
+  pub ignore_dynamic_requires: Option<bool>,
+  /// Treat modules that use both `import` and `module` or `exports` as CommonJS modules. If disabled,
+  /// they are ES modules and `module` and `exports` are left as global variables. Defaults to `true`.
+  pub transform_mixed_es_modules: Option<bool>,
+  /// What `require` of an ES module returns. `true` returns the default export, `auto` only does it for
+  /// modules that have nothing but a default export and `preferred` does it for any module with a
+  /// default export. Defaults to `namespace`, which returns the namespace object.
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(default, deserialize_with = "deserialize_require_returns_default"),
+    schemars(with = "Option<RawRequireReturnsDefault>")
+  )]
+  pub require_returns_default: Option<RequireReturnsDefault>,
+  /// Treat external modules as ES modules, so imports of them are not wrapped by the `__toESM` helper in
+  /// the `cjs`, `iife` and `umd` format, regardless of `output.interop`. Defaults to `false`.
+  pub esm_externals: Option<bool>,
+  /// Decide that `.mjs` modules and modules in packages with `"type": "module"` are ES modules from their
+  /// extension and `package.json`, even if they use `module` or `exports`. Defaults to `false`.
+  pub strict_exports_detection: Option<bool>,
+}
+
+impl CommonJsOptions {
+  pub fn ignore_dynamic_requires(&self) -> bool {
+    self.ignore_dynamic_requires.unwrap_or(true)
+  }
+
+  pub fn transform_mixed_es_modules(&self) -> bool {
+    self.transform_mixed_es_modules.unwrap_or(true)
+  }
+
+  pub fn require_returns_default(&self) -> RequireReturnsDefault {
+    self.require_returns_default.unwrap_or_default()
+  }
+
+  pub fn esm_externals(&self) -> bool {
+    self.esm_externals.unwrap_or(false)
+  }
+
+  pub fn strict_exports_detection(&self) -> bool {
+    self.strict_exports_detection.unwrap_or(false)
+  }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "kebab-case", deny_unknown_fields)
+)]
+pub enum RequireReturnsDefault {
+  #[default]
+  Namespace,
+  Always,
+  Auto,
+  Preferred,
+}
+
+impl RequireReturnsDefault {
+  /// Whether `require` returns the default export of an ES module with the given exports.
+  pub fn returns_default(self, has_default_export: bool, has_only_default_export: bool) -> bool {
+    match self {
+      Self::Namespace => false,
+      Self::Always => true,
+      Self::Auto => has_only_default_export,
+      Self::Preferred => has_default_export,
+    }
+  }
+}
+
+/// `requireReturnsDefault` as it's written in the config, which is a boolean or one of the flags.
+#[cfg(feature = "deserialize_bundler_options")]
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum RawRequireReturnsDefault {
+  Boolean(bool),
+  Flag(RequireReturnsDefault),
+}
+
+#[cfg(feature = "deserialize_bundler_options")]
+fn deserialize_require_returns_default<'de, D>(
+  deserializer: D,
+) -> Result<Option<RequireReturnsDefault>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let deserialized = Option::<RawRequireReturnsDefault>::deserialize(deserializer)?;
+  Ok(deserialized.map(|raw| match raw {
+    RawRequireReturnsDefault::Boolean(value) => value.into(),
+    RawRequireReturnsDefault::Flag(flag) => flag,
+  }))
+}
+
+impl From<bool> for RequireReturnsDefault {
+  fn from(value: bool) -> Self {
+    if value {
+      Self::Always
+    } else {
+      Self::Namespace
+    }
+  }
+}
+
+impl TryFrom<&str> for RequireReturnsDefault {
+  type Error = String;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
+    match value {
+      "namespace" => Ok(Self::Namespace),
+      "auto" => Ok(Self::Auto),
+      "preferred" => Ok(Self::Preferred),
+      _ => Err(format!("Invalid value for `commonjs.requireReturnsDefault`: {value}")),
+    }
+  }
+}
@@ -0,0 +1,31 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub enum CssMode {
+  /// Emit the css of each chunk as a separate `.css` file.
+  #[default]
+  Extract,
+  /// Embed the css of each chunk into the js chunk as a string, which is inserted as a `<style>` element
+  /// once the chunk is evaluated.
+  Inline,
+}
+
+impl TryFrom<&str> for CssMode {
+  type Error = String;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
+    match value {
+      "extract" => Ok(Self::Extract),
+      "inline" => Ok(Self::Inline),
+      _ => Err(format!("Unknown css mode: {value:?}")),
+    }
+  }
+}
@@ -0,0 +1,30 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+/// How decorators of TypeScript modules are transformed. Only legacy decorators are transformed, standard
+/// (stage 3) decorators aren't lowered yet and are kept as they are with an `UNSUPPORTED_DECORATORS` warning.
+#[derive(Debug, Default, Clone, Copy)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct DecoratorOptions {
+  /// Transform decorators with the semantics of `experimentalDecorators` of TypeScript.
+  pub legacy: Option<bool>,
+  /// Emit the types of decorated declarations for `reflect-metadata`, like `emitDecoratorMetadata` of
+  /// TypeScript. Only applies to legacy decorators.
+  pub emit_decorator_metadata: Option<bool>,
+}
+
+impl DecoratorOptions {
+  pub fn legacy(&self) -> bool {
+    self.legacy.unwrap_or(false)
+  }
+
+  pub fn emit_decorator_metadata(&self) -> bool {
+    self.legacy() && self.emit_decorator_metadata.unwrap_or(false)
+  }
+}
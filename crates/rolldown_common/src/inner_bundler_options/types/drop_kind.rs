@@ -0,0 +1,30 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+/// The kinds of code removed from modules by `drop`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub enum DropKind {
+  /// Calls of the global `console`, such as `console.log(value)`, along with their arguments.
+  Console,
+  /// `debugger` statements.
+  Debugger,
+}
+
+impl TryFrom<&str> for DropKind {
+  type Error = String;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
+    match value {
+      "console" => Ok(Self::Console),
+      "debugger" => Ok(Self::Debugger),
+      _ => Err(format!("Unknown drop: {value:?}. Expected `console` or `debugger`")),
+    }
+  }
+}
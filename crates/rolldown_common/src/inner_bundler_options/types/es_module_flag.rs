@@ -53,6 +53,25 @@ pub enum EsModuleFlag {
   IfDefaultProp,
 }
 
+/// `esModule` as it's written in the config, which accepts a boolean like rollup does.
+#[cfg(feature = "deserialize_bundler_options")]
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum RawEsModuleFlag {
+  Boolean(bool),
+  Flag(EsModuleFlag),
+}
+
+#[cfg(feature = "deserialize_bundler_options")]
+impl From<RawEsModuleFlag> for EsModuleFlag {
+  fn from(value: RawEsModuleFlag) -> Self {
+    match value {
+      RawEsModuleFlag::Boolean(value) => value.into(),
+      RawEsModuleFlag::Flag(flag) => flag,
+    }
+  }
+}
+
 impl From<bool> for EsModuleFlag {
   fn from(value: bool) -> Self {
     if value {
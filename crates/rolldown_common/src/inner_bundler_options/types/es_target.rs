@@ -0,0 +1,104 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+/// The version of ECMAScript the syntax of chunks is lowered to by `output.target`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "lowercase", deny_unknown_fields)
+)]
+pub enum EsTarget {
+  Es2015,
+  Es2016,
+  Es2017,
+  Es2018,
+  Es2019,
+  Es2020,
+  Es2021,
+  Es2022,
+  /// The latest syntax, which is never lowered.
+  #[default]
+  EsNext,
+}
+
+impl EsTarget {
+  /// The year of the edition, or `None` for `esnext`.
+  pub fn year(self) -> Option<u32> {
+    match self {
+      Self::Es2015 => Some(2015),
+      Self::Es2016 => Some(2016),
+      Self::Es2017 => Some(2017),
+      Self::Es2018 => Some(2018),
+      Self::Es2019 => Some(2019),
+      Self::Es2020 => Some(2020),
+      Self::Es2021 => Some(2021),
+      Self::Es2022 => Some(2022),
+      Self::EsNext => None,
+    }
+  }
+
+  /// Whether syntax introduced in the edition of `year` can be kept as is.
+  pub fn supports(self, year: u32) -> bool {
+    self.year().map_or(true, |target_year| year <= target_year)
+  }
+
+  /// A browserslist query for the transformer, or `None` if nothing needs to be lowered. The oxc
+  /// transformer selects its plugins by engines, so each edition is mapped to the first version of
+  /// Chrome supporting all of its syntax.
+  pub fn browserslist_query(self) -> Option<&'static str> {
+    match self {
+      Self::Es2015 => Some("chrome 51"),
+      Self::Es2016 => Some("chrome 52"),
+      Self::Es2017 => Some("chrome 58"),
+      Self::Es2018 => Some("chrome 64"),
+      Self::Es2019 => Some("chrome 66"),
+      Self::Es2020 => Some("chrome 80"),
+      Self::Es2021 => Some("chrome 85"),
+      Self::Es2022 => Some("chrome 94"),
+      Self::EsNext => None,
+    }
+  }
+}
+
+impl std::fmt::Display for EsTarget {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self.year() {
+      Some(year) => write!(f, "es{year}"),
+      None => write!(f, "esnext"),
+    }
+  }
+}
+
+impl TryFrom<&str> for EsTarget {
+  type Error = String;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
+    match value {
+      "es2015" => Ok(Self::Es2015),
+      "es2016" => Ok(Self::Es2016),
+      "es2017" => Ok(Self::Es2017),
+      "es2018" => Ok(Self::Es2018),
+      "es2019" => Ok(Self::Es2019),
+      "es2020" => Ok(Self::Es2020),
+      "es2021" => Ok(Self::Es2021),
+      "es2022" => Ok(Self::Es2022),
+      "esnext" => Ok(Self::EsNext),
+      _ => Err(format!(
+        "Unknown target: {value:?}. Expected one of es2015, es2016, ..., es2022 or esnext"
+      )),
+    }
+  }
+}
+
+#[test]
+fn test_try_from() {
+  assert_eq!(EsTarget::try_from("es2020"), Ok(EsTarget::Es2020));
+  assert_eq!(EsTarget::try_from("esnext"), Ok(EsTarget::EsNext));
+  assert!(EsTarget::try_from("es5").is_err());
+  assert!(EsTarget::Es2020.supports(2019));
+  assert!(!EsTarget::Es2019.supports(2020));
+  assert_eq!(EsTarget::Es2017.to_string(), "es2017");
+}
@@ -0,0 +1,92 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub enum GeneratedCodePreset {
+  /// Only emit syntax of ES5 in the code generated by rolldown.
+  Es5,
+  /// Use syntax of ES2015 in the code generated by rolldown, such as arrow functions and `const`.
+  Es2015,
+}
+
+/// Controls the syntax of the code synthesized by rolldown, such as imports of external modules, exports of
+/// chunks and interop snippets. The code of modules is not affected. It is rollup-compatible, see
+/// [rollup's docs](https://rollupjs.org/configuration-options/#output-generatedcode).
+///
+/// Each toggle falls back to the `preset` if it's not set. Without a preset, only `constBindings` and
+/// `reservedNamesAsProps` are enabled.
+#[derive(Debug, Default)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct GeneratedCodeOptions {
+  pub preset: Option<GeneratedCodePreset>,
+  /// Use arrow functions for getters of exports and callbacks.
+  pub arrow_functions: Option<bool>,
+  /// Use `const` instead of `var` to declare bindings.
+  pub const_bindings: Option<bool>,
+  /// Use `{ a }` instead of `{ a: a }` if the property has the same name as its value.
+  pub object_shorthand: Option<bool>,
+  /// Use template literals for multi-line strings, such as css inlined by `cssMode: 'inline'`.
+  pub template_string: Option<bool>,
+  /// Use reserved words like `default` as property names without quotes.
+  pub reserved_names_as_props: Option<bool>,
+}
+
+impl GeneratedCodeOptions {
+  fn preset_enables_es2015(&self) -> Option<bool> {
+    self.preset.map(|preset| matches!(preset, GeneratedCodePreset::Es2015))
+  }
+
+  pub fn arrow_functions(&self) -> bool {
+    self.arrow_functions.or_else(|| self.preset_enables_es2015()).unwrap_or(false)
+  }
+
+  pub fn const_bindings(&self) -> bool {
+    self.const_bindings.or_else(|| self.preset_enables_es2015()).unwrap_or(true)
+  }
+
+  pub fn object_shorthand(&self) -> bool {
+    self.object_shorthand.or_else(|| self.preset_enables_es2015()).unwrap_or(false)
+  }
+
+  pub fn template_string(&self) -> bool {
+    self.template_string.or_else(|| self.preset_enables_es2015()).unwrap_or(false)
+  }
+
+  /// Whether the runtime helpers and the wrappers and getters of exports in modules use arrow functions.
+  /// Unlike `arrow_functions`, they keep using them by default, unless it's disabled explicitly or by the
+  /// `es5` preset.
+  pub fn arrow_function_helpers(&self) -> bool {
+    self.arrow_functions.or_else(|| self.preset_enables_es2015()).unwrap_or(true)
+  }
+
+  /// Whether named imports of external modules are destructured, such as `const { a } = require("ext")`.
+  /// Destructuring is ES2015 syntax, so the `es5` preset assigns each import with a member access instead.
+  pub fn destructuring(&self) -> bool {
+    self.preset_enables_es2015().unwrap_or(true)
+  }
+
+  /// Reserved words are valid property names since ES5, so both presets enable it.
+  pub fn reserved_names_as_props(&self) -> bool {
+    self.reserved_names_as_props.unwrap_or(true)
+  }
+
+  /// `const` or `var`, depending on `constBindings`.
+  pub fn binding_keyword(&self) -> &'static str {
+    if self.const_bindings() {
+      "const"
+    } else {
+      "var"
+    }
+  }
+}
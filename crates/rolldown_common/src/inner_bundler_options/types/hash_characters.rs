@@ -0,0 +1,100 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+use rolldown_utils::base64::to_url_safe_base64;
+
+/// The characters of `[hash]` in file names. It is rollup-compatible, see
+/// [rollup's docs](https://rollupjs.org/configuration-options/#output-hashcharacters).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub enum HashCharacters {
+  /// `a-z`, `A-Z`, `0-9`, `-` and `_`.
+  #[default]
+  Base64,
+  /// `a-z` and `0-9`, which is safe for case-insensitive file systems.
+  Base36,
+  /// `a-f` and `0-9`.
+  Hex,
+}
+
+const DIGITS: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+impl HashCharacters {
+  /// The length of an encoded 128-bit hash, which is the longest `[hash:n]` could be.
+  pub fn max_hash_len(self) -> usize {
+    match self {
+      Self::Base64 => 22,
+      Self::Base36 => 25,
+      Self::Hex => 32,
+    }
+  }
+
+  /// The length of `[hash]` or `[hash:n]` in file names. It can't be longer than the whole hash.
+  pub fn hash_len(self, len: Option<usize>) -> anyhow::Result<usize> {
+    let max_len = self.max_hash_len();
+    match len {
+      Some(len) if len > max_len => Err(anyhow::anyhow!(
+        "Hashes can't be longer than {max_len} characters with the hash characters {self:?}, but `[hash:{len}]` is used."
+      )),
+      // Shorter hashes collide too easily.
+      _ => Ok(len.map_or(8, |len| len.max(6))),
+    }
+  }
+
+  /// Encodes a 128-bit hash. The least significant digits come first, so the prefixes used as `[hash:n]`
+  /// are evenly distributed.
+  pub fn encode(self, hash: u128) -> String {
+    let radix = match self {
+      Self::Base64 => return to_url_safe_base64(hash.to_le_bytes()),
+      Self::Base36 => 36,
+      Self::Hex => 16,
+    };
+    let mut value = hash;
+    (0..self.max_hash_len())
+      .map(|_| {
+        #[allow(clippy::cast_possible_truncation)] // The remainder is always less than 36
+        let digit = (value % radix) as usize;
+        value /= radix;
+        char::from(DIGITS[digit])
+      })
+      .collect()
+  }
+}
+
+impl TryFrom<&str> for HashCharacters {
+  type Error = String;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
+    match value {
+      "base64" => Ok(Self::Base64),
+      "base36" => Ok(Self::Base36),
+      "hex" => Ok(Self::Hex),
+      _ => Err(format!("Unknown hash characters: {value:?}")),
+    }
+  }
+}
+
+#[test]
+fn test_encode() {
+  assert_eq!(HashCharacters::Hex.encode(0x1f), format!("f1{}", "0".repeat(30)));
+  assert_eq!(HashCharacters::Base36.encode(36 * 2 + 35), format!("z2{}", "0".repeat(23)));
+  assert_eq!(HashCharacters::Base64.encode(u128::MAX).len(), 22);
+  assert_eq!(HashCharacters::Base36.encode(u128::MAX).len(), 25);
+}
+
+#[test]
+fn test_hash_len() {
+  assert_eq!(HashCharacters::Base64.hash_len(None).unwrap(), 8);
+  assert_eq!(HashCharacters::Base64.hash_len(Some(3)).unwrap(), 6);
+  assert_eq!(HashCharacters::Hex.hash_len(Some(32)).unwrap(), 32);
+  assert_eq!(
+    HashCharacters::Base64.hash_len(Some(23)).unwrap_err().to_string(),
+    "Hashes can't be longer than 22 characters with the hash characters Base64, but `[hash:23]` is used."
+  );
+}
@@ -0,0 +1,91 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::{Deserialize, Deserializer};
+
+/// JSON modules larger than this many bytes are stringified with `stringify: "auto"`.
+const AUTO_STRINGIFY_THRESHOLD: usize = 10 * 1000;
+
+/// How JSON modules are transformed. The top-level keys of objects are always exported as named
+/// exports, so tree shaking drops the values that aren't imported.
+#[derive(Debug, Default, Clone, Copy)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct JsonOptions {
+  /// Embed objects and arrays as `JSON.parse("...")` instead of object literals, which is parsed faster
+  /// for large values. `auto` only does it for files larger than 10kB. Defaults to `false`.
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(default, deserialize_with = "deserialize_stringify"),
+    schemars(with = "Option<RawJsonStringify>")
+  )]
+  pub stringify: Option<JsonStringify>,
+}
+
+impl JsonOptions {
+  /// Whether the values of a JSON module of `size` bytes should be stringified.
+  pub fn stringify(&self, size: usize) -> bool {
+    match self.stringify.unwrap_or_default() {
+      JsonStringify::Always => true,
+      JsonStringify::Never => false,
+      JsonStringify::Auto => size > AUTO_STRINGIFY_THRESHOLD,
+    }
+  }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "kebab-case", deny_unknown_fields)
+)]
+pub enum JsonStringify {
+  Always,
+  #[default]
+  Never,
+  Auto,
+}
+
+/// `stringify` as it's written in the config, which is a boolean or `auto`.
+#[cfg(feature = "deserialize_bundler_options")]
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum RawJsonStringify {
+  Boolean(bool),
+  Flag(JsonStringify),
+}
+
+#[cfg(feature = "deserialize_bundler_options")]
+fn deserialize_stringify<'de, D>(deserializer: D) -> Result<Option<JsonStringify>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let deserialized = Option::<RawJsonStringify>::deserialize(deserializer)?;
+  Ok(deserialized.map(|raw| match raw {
+    RawJsonStringify::Boolean(value) => value.into(),
+    RawJsonStringify::Flag(flag) => flag,
+  }))
+}
+
+impl From<bool> for JsonStringify {
+  fn from(value: bool) -> Self {
+    if value {
+      Self::Always
+    } else {
+      Self::Never
+    }
+  }
+}
+
+impl From<String> for JsonStringify {
+  fn from(value: String) -> Self {
+    if value == "auto" {
+      Self::Auto
+    } else {
+      unreachable!("unknown json stringify type")
+    }
+  }
+}
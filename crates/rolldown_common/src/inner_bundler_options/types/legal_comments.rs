@@ -0,0 +1,58 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+/// What happens to legal comments, which are comments starting with `//!` or `/*!`, or containing
+/// `@license` or `@preserve`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "lowercase", deny_unknown_fields)
+)]
+pub enum LegalComments {
+  /// Remove all legal comments.
+  None,
+  /// Keep legal comments where they are. Minified chunks keep them at the start, since the minifier
+  /// removes comments.
+  #[default]
+  Inline,
+  /// Move legal comments to the end of the chunk.
+  Eof,
+  /// Move legal comments to a `<chunk>.LEGAL.txt` file and link it with a comment at the end of the chunk.
+  Linked,
+  /// Move legal comments to a `<chunk>.LEGAL.txt` file without linking it.
+  External,
+}
+
+impl TryFrom<&str> for LegalComments {
+  type Error = String;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
+    match value {
+      "none" => Ok(Self::None),
+      "inline" => Ok(Self::Inline),
+      "eof" => Ok(Self::Eof),
+      "linked" => Ok(Self::Linked),
+      "external" => Ok(Self::External),
+      _ => Err(format!(
+        "Unknown legal comments mode: {value:?}. Expected one of none, inline, eof, linked or external"
+      )),
+    }
+  }
+}
+
+/// Whether the text of a comment, without `//` or `/* */`, marks a legal comment.
+pub fn is_legal_comment(text: &str) -> bool {
+  text.starts_with('!') || text.contains("@license") || text.contains("@preserve")
+}
+
+#[test]
+fn test_is_legal_comment() {
+  assert!(is_legal_comment("! MIT"));
+  assert!(is_legal_comment("*\n * @license MIT\n "));
+  assert!(is_legal_comment(" @preserve"));
+  assert!(!is_legal_comment(" #__PURE__ "));
+  assert!(!is_legal_comment(" licensed under MIT"));
+}
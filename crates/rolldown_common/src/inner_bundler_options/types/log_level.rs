@@ -0,0 +1,36 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub enum LogLevel {
+  /// Only report errors.
+  Silent,
+  /// Report errors and warnings.
+  Warn,
+  /// Report errors, warnings and informational diagnostics.
+  #[default]
+  Info,
+  /// Report all diagnostics.
+  Debug,
+}
+
+impl TryFrom<&str> for LogLevel {
+  type Error = String;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
+    match value {
+      "silent" => Ok(Self::Silent),
+      "warn" => Ok(Self::Warn),
+      "info" => Ok(Self::Info),
+      "debug" => Ok(Self::Debug),
+      _ => Err(format!("Unknown log level: {value:?}")),
+    }
+  }
+}
@@ -0,0 +1,152 @@
+use rolldown_utils::js_regex::HybridRegex;
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::{Deserialize, Deserializer};
+
+/// `output.minify`, which is either a boolean or the steps of minification to run.
+#[derive(Debug)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(untagged)
+)]
+pub enum RawMinifyOptions {
+  Boolean(bool),
+  Object(MinifyOptions),
+}
+
+impl RawMinifyOptions {
+  /// Returns the steps of minification to run, or `None` if chunks aren't minified.
+  pub fn normalize(self) -> Option<MinifyOptions> {
+    match self {
+      Self::Boolean(true) => Some(MinifyOptions::default()),
+      Self::Boolean(false) => None,
+      Self::Object(options) => Some(options),
+    }
+  }
+
+  pub fn is_enabled(&self) -> bool {
+    !matches!(self, Self::Boolean(false))
+  }
+}
+
+impl From<bool> for RawMinifyOptions {
+  fn from(value: bool) -> Self {
+    Self::Boolean(value)
+  }
+}
+
+/// The steps of minification run on rendered chunks by the oxc minifier. Each step is enabled unless it's
+/// set to `false`.
+#[derive(Debug, Default)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct MinifyOptions {
+  /// Rewrite the code into a shorter equivalent form and remove dead code.
+  pub compress: Option<bool>,
+  /// Shorten the names of local bindings. An object configures which names are kept.
+  pub mangle: Option<RawMangleOptions>,
+  /// Remove whitespace and comments when printing the code.
+  pub codegen: Option<bool>,
+}
+
+impl MinifyOptions {
+  pub fn compress(&self) -> bool {
+    self.compress.unwrap_or(true)
+  }
+
+  pub fn mangle(&self) -> bool {
+    self.mangle.as_ref().map_or(true, RawMangleOptions::is_enabled)
+  }
+
+  /// The options of mangling, if they are configured by an object.
+  pub fn mangle_options(&self) -> Option<&MangleOptions> {
+    match &self.mangle {
+      Some(RawMangleOptions::Object(options)) => Some(options),
+      _ => None,
+    }
+  }
+
+  pub fn codegen(&self) -> bool {
+    self.codegen.unwrap_or(true)
+  }
+}
+
+/// `output.minify.mangle`, which is either a boolean or the names to keep while mangling.
+#[derive(Debug)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(untagged)
+)]
+pub enum RawMangleOptions {
+  Boolean(bool),
+  Object(MangleOptions),
+}
+
+impl RawMangleOptions {
+  pub fn is_enabled(&self) -> bool {
+    !matches!(self, Self::Boolean(false))
+  }
+}
+
+impl From<bool> for RawMangleOptions {
+  fn from(value: bool) -> Self {
+    Self::Boolean(value)
+  }
+}
+
+/// Controls which names are mangled. Libraries relying on the names of functions and classes or on stable
+/// property keys can keep them here.
+#[derive(Debug, Default)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct MangleOptions {
+  /// Also mangle the names declared in the top-level scope of chunks. Defaults to `false`.
+  pub toplevel: Option<bool>,
+  /// Names that are never mangled, nor used as mangled names.
+  pub reserved: Option<Vec<String>>,
+  /// Keep the names of classes, so `cls.name` stays the same. Defaults to `false`.
+  pub keep_classnames: Option<bool>,
+  /// Mangle the names of properties matching this regex, except the ones ever written in quotes. Properties
+  /// aren't mangled by default, because the names of properties can't be proven to be unobservable.
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(deserialize_with = "deserialize_properties", default),
+    schemars(with = "Option<String>")
+  )]
+  pub properties: Option<HybridRegex>,
+}
+
+impl MangleOptions {
+  pub fn toplevel(&self) -> bool {
+    self.toplevel.unwrap_or(false)
+  }
+
+  pub fn keep_classnames(&self) -> bool {
+    self.keep_classnames.unwrap_or(false)
+  }
+
+  pub fn is_reserved(&self, name: &str) -> bool {
+    self.reserved.as_ref().is_some_and(|reserved| reserved.iter().any(|item| item == name))
+  }
+}
+
+#[cfg(feature = "deserialize_bundler_options")]
+fn deserialize_properties<'de, D>(deserializer: D) -> Result<Option<HybridRegex>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let deserialized = Option::<String>::deserialize(deserializer)?;
+  deserialized
+    .map(|inner| HybridRegex::new(&inner))
+    .transpose()
+    .map_err(|e| serde::de::Error::custom(format!("failed to deserialize {e:?} to HybridRegex")))
+}
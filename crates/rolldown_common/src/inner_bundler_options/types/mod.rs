@@ -1,20 +1,38 @@
 pub mod advanced_chunks_options;
+pub mod assets_inline_limit;
+pub mod charset;
 pub mod checks_options;
+pub mod chunk_name_strategy;
+pub mod chunk_size_warning_limit;
+pub mod commonjs_options;
+pub mod css_mode;
+pub mod decorator_options;
+pub mod drop_kind;
 pub mod es_module_flag;
+pub mod es_target;
 pub mod experimental_options;
 pub mod filename_template;
+pub mod generated_code_options;
+pub mod hash_characters;
 pub mod inject_import;
 pub mod input_item;
 pub mod is_external;
+pub mod json_options;
+pub mod legal_comments;
+pub mod log_level;
+pub mod minify_options;
 pub mod module_type;
 pub mod normalized_bundler_options;
+pub mod on_log;
 pub mod output_exports;
 pub mod output_format;
 pub mod output_option;
 pub mod platform;
+pub mod public_path;
 pub mod resolve_options;
 pub mod source_map_type;
 pub mod sourcemap_ignore_list;
 pub mod sourcemap_path_transform;
 pub mod treeshake;
+pub mod wasm_options;
 pub mod watch_option;
@@ -21,6 +21,9 @@ pub enum ModuleType {
   Base64,
   Dataurl,
   Binary,
+  File,
+  Copy,
+  Wasm,
   Empty,
   Css,
   Custom(String),
@@ -38,7 +41,11 @@ impl ModuleType {
       "base64" => Ok(Self::Base64),
       "dataurl" => Ok(Self::Dataurl),
       "binary" => Ok(Self::Binary),
+      "file" => Ok(Self::File),
+      "copy" => Ok(Self::Copy),
+      "wasm" => Ok(Self::Wasm),
       "empty" => Ok(Self::Empty),
+      "css" => Ok(Self::Css),
       _ => Err(anyhow::format_err!("Unknown module type: {s}")),
     }
   }
@@ -56,10 +63,20 @@ impl ModuleType {
       "base64" => Self::Base64,
       "dataurl" => Self::Dataurl,
       "binary" => Self::Binary,
+      "file" => Self::File,
+      "copy" => Self::Copy,
+      "wasm" => Self::Wasm,
       "empty" => Self::Empty,
+      "css" => Self::Css,
       _ => Self::Custom(s.as_ref().to_string()),
     }
   }
+
+  /// Stylesheet languages rolldown can't compile by itself. A plugin is expected to turn them into css
+  /// by returning `ModuleType::Css` from the `load` or `transform` hook.
+  pub fn is_css_preprocessor(&self) -> bool {
+    matches!(self, Self::Custom(ty) if matches!(ty.as_str(), "scss" | "sass" | "less" | "stylus"))
+  }
 }
 
 impl Display for ModuleType {
@@ -74,6 +91,9 @@ impl Display for ModuleType {
       ModuleType::Base64 => write!(f, "base64"),
       ModuleType::Dataurl => write!(f, "dataurl"),
       ModuleType::Binary => write!(f, "binary"),
+      ModuleType::File => write!(f, "file"),
+      ModuleType::Copy => write!(f, "copy"),
+      ModuleType::Wasm => write!(f, "wasm"),
       ModuleType::Empty => write!(f, "empty"),
       ModuleType::Css => write!(f, "css"),
       ModuleType::Custom(custom_type) => write!(f, "{custom_type}"),
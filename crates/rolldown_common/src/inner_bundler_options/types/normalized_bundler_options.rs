@@ -8,18 +8,37 @@ use oxc::transformer::{InjectGlobalVariablesConfig, JsxOptions};
 use rustc_hash::FxHashMap;
 
 use super::advanced_chunks_options::AdvancedChunksOptions;
+use super::assets_inline_limit::AssetsInlineLimit;
+use super::charset::Charset;
 use super::checks_options::ChecksOptions;
+use super::chunk_name_strategy::ChunkNameStrategy;
+use super::chunk_size_warning_limit::ChunkSizeWarningLimit;
+use super::commonjs_options::CommonJsOptions;
+use super::css_mode::CssMode;
+use super::decorator_options::DecoratorOptions;
+use super::es_target::EsTarget;
 use super::experimental_options::ExperimentalOptions;
-use super::output_option::ChunkFilenamesOutputOption;
+use super::generated_code_options::GeneratedCodeOptions;
+use super::hash_characters::HashCharacters;
+use super::json_options::JsonOptions;
+use super::legal_comments::LegalComments;
+use super::minify_options::MinifyOptions;
+use super::output_option::{
+  AssetFilenamesOutputOption, ChunkFilenamesOutputOption, SanitizeFilename,
+};
+use super::public_path::PublicPath;
 use super::treeshake::TreeshakeOptions;
+use super::wasm_options::WasmOptions;
 use super::watch_option::WatchOption;
 use super::{
-  filename_template::FilenameTemplate, is_external::IsExternal, output_exports::OutputExports,
+  is_external::IsExternal, log_level::LogLevel, on_log::OnLog, output_exports::OutputExports,
   output_format::OutputFormat, output_option::AddonOutputOption, platform::Platform,
   source_map_type::SourceMapType, sourcemap_ignore_list::SourceMapIgnoreList,
   sourcemap_path_transform::SourceMapPathTransform,
 };
-use crate::{EsModuleFlag, InjectImport, InputItem, ModuleType};
+use crate::{
+  EsModuleFlag, InjectImport, InputItem, InteropOutputOption, ManualChunksOption, ModuleType,
+};
 
 #[allow(clippy::struct_excessive_bools)] // Using raw booleans is more clear in this case
 #[derive(Debug)]
@@ -34,18 +53,25 @@ pub struct NormalizedBundlerOptions {
   pub shim_missing_exports: bool,
   /// The key is the extension. Unlike `BundlerOptions`, the extension doesn't start with a dot.
   pub module_types: FxHashMap<String, ModuleType>,
+  pub assets_inline_limit: Option<AssetsInlineLimit>,
   // --- Output
   pub name: Option<String>,
+  pub no_conflict: bool,
   pub css_entry_filenames: ChunkFilenamesOutputOption,
   pub css_chunk_filenames: ChunkFilenamesOutputOption,
   pub entry_filenames: ChunkFilenamesOutputOption,
   pub chunk_filenames: ChunkFilenamesOutputOption,
-  pub asset_filenames: FilenameTemplate,
+  pub asset_filenames: AssetFilenamesOutputOption,
+  pub hash_characters: HashCharacters,
+  pub sanitize_filename: SanitizeFilename,
   pub dir: String,
+  pub public_dir: Option<String>,
   pub format: OutputFormat,
   pub exports: OutputExports,
   pub es_module: EsModuleFlag,
+  pub interop: InteropOutputOption,
   pub globals: FxHashMap<String, String>,
+  pub generated_code: GeneratedCodeOptions,
   pub sourcemap: Option<SourceMapType>,
   pub banner: Option<AddonOutputOption>,
   pub footer: Option<AddonOutputOption>,
@@ -54,18 +80,54 @@ pub struct NormalizedBundlerOptions {
   pub sourcemap_ignore_list: Option<SourceMapIgnoreList>,
   pub sourcemap_path_transform: Option<SourceMapPathTransform>,
   pub experimental: ExperimentalOptions,
-  pub minify: bool,
+  /// `None` if chunks aren't minified.
+  pub minify: Option<MinifyOptions>,
+  pub compact: bool,
   pub extend: bool,
+  /// `None` means the directive is decided by the modules of each chunk.
+  pub strict: Option<bool>,
   pub define: Vec<(/* Target to be replaced */ String, /* Replacement */ String)>,
   pub inject: Vec<InjectImport>,
   pub oxc_inject_global_variables_config: InjectGlobalVariablesConfig,
   pub external_live_bindings: bool,
+  pub external_import_attributes: bool,
+  pub freeze: bool,
   pub inline_dynamic_imports: bool,
+  pub dynamic_import_in_cjs: bool,
+  pub manual_chunks: Option<ManualChunksOption>,
   pub advanced_chunks: Option<AdvancedChunksOptions>,
+  pub experimental_min_chunk_size: Option<f64>,
+  pub chunk_name_strategy: ChunkNameStrategy,
+  pub chunk_size_warning_limit: ChunkSizeWarningLimit,
+  pub runtime_chunk: bool,
+  pub keep_names: bool,
+  pub target: EsTarget,
+  pub legal_comments: LegalComments,
+  pub charset: Charset,
   pub checks: ChecksOptions,
   pub profiler_names: bool,
   pub jsx: Option<JsxOptions>,
+  pub decorator: DecoratorOptions,
+  pub json: JsonOptions,
+  pub wasm: WasmOptions,
+  pub commonjs: CommonJsOptions,
   pub watch: WatchOption,
+  pub ses_compatible: bool,
+  pub css_mode: CssMode,
+  pub css_banner: Option<AddonOutputOption>,
+  pub css_footer: Option<AddonOutputOption>,
+  pub css_target: Option<Vec<String>>,
+  pub preserve_modules: bool,
+  /// Absolute path of `preserve_modules_root`.
+  pub preserve_modules_root: Option<PathBuf>,
+  pub base: Option<PublicPath>,
+  pub log_level: LogLevel,
+  pub on_log: Option<OnLog>,
+  pub bail: bool,
+  pub error_recovery: bool,
+  pub drop_console: bool,
+  pub drop_debugger: bool,
+  pub drop_labels: Vec<String>,
 }
 
 pub type SharedNormalizedBundlerOptions = Arc<NormalizedBundlerOptions>;
@@ -0,0 +1,47 @@
+use std::fmt::Debug;
+use std::{future::Future, pin::Pin};
+
+use super::log_level::LogLevel;
+
+/// A diagnostic passed to `onLog` before it's reported.
+#[derive(Debug, Clone)]
+pub struct Log {
+  /// A stable code identifying the kind of the diagnostic, such as `CIRCULAR_DEPENDENCY`.
+  pub code: String,
+  pub message: String,
+}
+
+/// What to do with a diagnostic passed to `onLog`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OnLogAction {
+  /// Report the diagnostic.
+  Keep,
+  /// Report the diagnostic with a different message.
+  Rewrite(String),
+  /// Don't report the diagnostic.
+  Drop,
+  /// Fail the build with the diagnostic.
+  Error,
+}
+
+pub type OnLogFn = dyn Fn(LogLevel, Log) -> Pin<Box<(dyn Future<Output = anyhow::Result<OnLogAction>> + Send + 'static)>>
+  + Send
+  + Sync;
+
+pub struct OnLog(Box<OnLogFn>);
+
+impl Debug for OnLog {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "OnLog::Fn(...)")
+  }
+}
+
+impl OnLog {
+  pub fn new(f: Box<OnLogFn>) -> Self {
+    Self(f)
+  }
+
+  pub async fn call(&self, level: LogLevel, log: Log) -> anyhow::Result<OnLogAction> {
+    self.0(level, log).await
+  }
+}
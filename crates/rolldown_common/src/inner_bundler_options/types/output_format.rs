@@ -16,11 +16,12 @@ pub enum OutputFormat {
   App,
   Iife,
   Umd,
+  System,
 }
 
 impl OutputFormat {
   pub fn requires_scope_hoisting(&self) -> bool {
-    matches!(self, Self::Esm | Self::Cjs | Self::Iife | Self::Umd)
+    matches!(self, Self::Esm | Self::Cjs | Self::Iife | Self::Umd | Self::System)
   }
 }
 
@@ -32,6 +33,7 @@ impl Display for OutputFormat {
       Self::App => write!(f, "app"),
       Self::Iife => write!(f, "iife"),
       Self::Umd => write!(f, "umd"),
+      Self::System => write!(f, "system"),
     }
   }
 }
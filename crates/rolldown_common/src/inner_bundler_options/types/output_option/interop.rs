@@ -0,0 +1,112 @@
+use std::{fmt::Debug, future::Future, pin::Pin};
+
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+use crate::{Interop, OutputFormat};
+
+/// How the value returned by `require(...)` of an external module is interpreted in the `cjs`, `iife` and
+/// `umd` format. It is rollup-compatible, see [rollup's docs](https://rollupjs.org/configuration-options/#output-interop).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub enum InteropMode {
+  /// The external module is treated as the namespace object if it is marked with `__esModule`, otherwise
+  /// it becomes the default export. Both are done by the `__toESM` helper at runtime.
+  ///
+  /// In the `iife` and `umd` format, globals of external modules are used as they are.
+  #[default]
+  Auto,
+  /// The external module is assumed to be transpiled from an ES module, so it's used as the namespace
+  /// object without any helper.
+  EsModule,
+  /// The external module is always the default export, and its properties are still readable as named
+  /// exports.
+  Default,
+  /// Like `default`, but importing named exports from the external module is an error.
+  DefaultOnly,
+}
+
+impl InteropMode {
+  /// Whether the `__toESM` helper is needed to import the external module in the given format.
+  pub fn needs_to_esm(self, format: &OutputFormat) -> bool {
+    match format {
+      OutputFormat::Cjs => !matches!(self, Self::EsModule),
+      OutputFormat::Iife | OutputFormat::Umd => {
+        matches!(self, Self::Default | Self::DefaultOnly)
+      }
+      _ => false,
+    }
+  }
+
+  /// How the `__toESM` helper is called to import the external module in the given format, if it's needed.
+  pub fn to_esm_interop(self, format: &OutputFormat) -> Option<Interop> {
+    if !self.needs_to_esm(format) {
+      return None;
+    }
+    match self {
+      Self::Auto => Some(Interop::Babel),
+      Self::EsModule | Self::Default | Self::DefaultOnly => Some(Interop::Node),
+    }
+  }
+}
+
+impl TryFrom<&str> for InteropMode {
+  type Error = String;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
+    match value {
+      "auto" => Ok(Self::Auto),
+      "esModule" => Ok(Self::EsModule),
+      "default" => Ok(Self::Default),
+      "defaultOnly" => Ok(Self::DefaultOnly),
+      _ => Err(format!("Invalid interop: {value}")),
+    }
+  }
+}
+
+type InteropFunction = dyn Fn(&str) -> Pin<Box<(dyn Future<Output = anyhow::Result<InteropMode>> + Send + 'static)>>
+  + Send
+  + Sync;
+
+/// `output.interop`, which is either the same mode for all external modules or a function that receives
+/// the id of an external module and returns its mode.
+pub enum InteropOutputOption {
+  Mode(InteropMode),
+  Fn(Box<InteropFunction>),
+}
+
+impl Debug for InteropOutputOption {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Mode(value) => write!(f, "InteropOutputOption::Mode({value:?})"),
+      Self::Fn(_) => write!(f, "InteropOutputOption::Fn(...)"),
+    }
+  }
+}
+
+impl Default for InteropOutputOption {
+  fn default() -> Self {
+    Self::Mode(InteropMode::default())
+  }
+}
+
+impl InteropOutputOption {
+  pub async fn call(&self, id: &str) -> anyhow::Result<InteropMode> {
+    match self {
+      Self::Mode(value) => Ok(*value),
+      Self::Fn(value) => value(id).await,
+    }
+  }
+}
+
+impl From<InteropMode> for InteropOutputOption {
+  fn from(value: InteropMode) -> Self {
+    Self::Mode(value)
+  }
+}
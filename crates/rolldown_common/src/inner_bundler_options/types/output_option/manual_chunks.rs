@@ -0,0 +1,70 @@
+use std::{fmt::Debug, future::Future, pin::Pin, sync::Arc};
+
+use rolldown_utils::indexmap::FxIndexMap;
+
+use crate::ModuleInfo;
+
+/// Infos of the modules of the bundle keyed by their ids, which back `getModuleInfo` and `getModuleIds` in
+/// the function form of `output.manualChunks`.
+pub type ManualChunksModuleInfos = Arc<FxIndexMap<String, Arc<ModuleInfo>>>;
+
+type ManualChunksFunction = dyn Fn(
+    &str,
+    &ManualChunksModuleInfos,
+  ) -> Pin<Box<(dyn Future<Output = anyhow::Result<Option<String>>> + Send + 'static)>>
+  + Send
+  + Sync;
+
+/// `output.manualChunks`, which puts modules into chunks with the given names. Static dependencies of these
+/// modules join the same chunk unless they are put into another one. It is rollup-compatible, see
+/// [rollup's docs](https://rollupjs.org/configuration-options/#output-manualchunks).
+pub enum ManualChunksOption {
+  /// Names of chunks mapped to the modules they contain. A module is listed by its id, or by the name of
+  /// the package it belongs to, such as `react`.
+  Groups(FxIndexMap<String, Vec<String>>),
+  /// A function that receives the id of each module and returns the name of its chunk, if any.
+  Fn(Box<ManualChunksFunction>),
+}
+
+impl Debug for ManualChunksOption {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Groups(value) => write!(f, "ManualChunksOption::Groups({value:?})"),
+      Self::Fn(_) => write!(f, "ManualChunksOption::Fn(...)"),
+    }
+  }
+}
+
+impl ManualChunksOption {
+  /// The name of the chunk that the module is put into, if any.
+  pub async fn call(
+    &self,
+    id: &str,
+    module_infos: &ManualChunksModuleInfos,
+  ) -> anyhow::Result<Option<String>> {
+    match self {
+      Self::Groups(groups) => Ok(
+        groups
+          .iter()
+          .find(|(_, modules)| modules.iter().any(|module| is_module_of(id, module)))
+          .map(|(name, _)| name.clone()),
+      ),
+      Self::Fn(value) => value(id, module_infos).await,
+    }
+  }
+}
+
+impl From<FxIndexMap<String, Vec<String>>> for ManualChunksOption {
+  fn from(value: FxIndexMap<String, Vec<String>>) -> Self {
+    Self::Groups(value)
+  }
+}
+
+/// Whether the module with the `id` is the listed module itself or a file of the listed package.
+fn is_module_of(id: &str, listed: &str) -> bool {
+  if id == listed {
+    return true;
+  }
+  let id = id.replace('\\', "/");
+  id.contains(&format!("/node_modules/{listed}/"))
+}
@@ -1,5 +1,13 @@
 mod addon;
+mod asset_filenames;
 mod chunk_filenames;
+mod interop;
+mod manual_chunks;
+mod sanitize_filename;
 
 pub use addon::{AddonFunction, AddonOutputOption};
+pub use asset_filenames::AssetFilenamesOutputOption;
 pub use chunk_filenames::ChunkFilenamesOutputOption;
+pub use interop::{InteropMode, InteropOutputOption};
+pub use manual_chunks::{ManualChunksModuleInfos, ManualChunksOption};
+pub use sanitize_filename::SanitizeFilename;
@@ -0,0 +1,59 @@
+use std::{fmt::Debug, future::Future, pin::Pin};
+
+use rolldown_utils::sanitize_file_name::sanitize_file_name;
+
+type SanitizeFilenameFunction = dyn Fn(&str) -> Pin<Box<(dyn Future<Output = anyhow::Result<String>> + Send + 'static)>>
+  + Send
+  + Sync;
+
+/// `output.sanitizeFileName`, which decides how `[name]` of chunks and assets derived from module ids
+/// and asset names is made safe for file systems and urls.
+///
+/// - `true` replaces characters other than letters, digits, `-` and `_` with `_`, which covers NUL,
+///   control and meta characters stripped by rollup.
+/// - `false` keeps names as they are.
+/// - A function receives the name and returns the sanitized one.
+pub enum SanitizeFilename {
+  Boolean(bool),
+  Fn(Box<SanitizeFilenameFunction>),
+}
+
+impl Debug for SanitizeFilename {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Boolean(value) => write!(f, "SanitizeFilename::Boolean({value:?})"),
+      Self::Fn(_) => write!(f, "SanitizeFilename::Fn(...)"),
+    }
+  }
+}
+
+impl Default for SanitizeFilename {
+  fn default() -> Self {
+    Self::Boolean(true)
+  }
+}
+
+impl SanitizeFilename {
+  /// Sanitize the name without calling the function, which is `None` if it's a function.
+  pub fn try_call_sync(&self, name: &str) -> Option<String> {
+    match self {
+      Self::Boolean(true) => Some(sanitize_file_name(name.into())),
+      Self::Boolean(false) => Some(name.to_string()),
+      Self::Fn(_) => None,
+    }
+  }
+
+  pub async fn call(&self, name: &str) -> anyhow::Result<String> {
+    match self {
+      Self::Boolean(true) => Ok(sanitize_file_name(name.into())),
+      Self::Boolean(false) => Ok(name.to_string()),
+      Self::Fn(value) => value(name).await,
+    }
+  }
+}
+
+impl From<bool> for SanitizeFilename {
+  fn from(value: bool) -> Self {
+    Self::Boolean(value)
+  }
+}
@@ -0,0 +1,34 @@
+/// Where emitted assets are served from, used to render the urls that reference them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PublicPath {
+  /// Resolve urls at runtime against the location of the referencing chunk, which is `import.meta.url`,
+  /// `document.currentScript` or `__filename` depending on the format. Stylesheets keep relative urls, which
+  /// browsers already resolve against the stylesheet.
+  Auto,
+  /// Prefix urls with a fixed base, such as `https://cdn.example.com/assets/`. Always ends with `/`.
+  Url(String),
+}
+
+impl From<String> for PublicPath {
+  fn from(value: String) -> Self {
+    if value == "auto" {
+      return Self::Auto;
+    }
+    let mut base = value;
+    if !base.ends_with('/') {
+      base.push('/');
+    }
+    Self::Url(base)
+  }
+}
+
+impl PublicPath {
+  /// Returns the url of `file_name`, which is relative to the output directory, if it's known at compile
+  /// time.
+  pub fn prefixed_url(&self, file_name: &str) -> Option<String> {
+    match self {
+      Self::Auto => None,
+      Self::Url(base) => Some(format!("{base}{file_name}")),
+    }
+  }
+}
@@ -1,9 +1,16 @@
-use rolldown_utils::js_regex::HybridRegex;
+use std::{fmt::Debug, future::Future, pin::Pin};
+
+use rolldown_utils::{js_regex::HybridRegex, pattern_filter::StringOrRegex};
+
+use crate::side_effects::glob_match_with_normalized_pattern;
 #[cfg(feature = "deserialize_bundler_options")]
 use schemars::JsonSchema;
 #[cfg(feature = "deserialize_bundler_options")]
 use serde::{Deserialize, Deserializer};
 
+#[cfg(feature = "deserialize_bundler_options")]
+use super::watch_option::deserialize_string_or_regex;
+
 #[derive(Debug)]
 #[cfg_attr(
   feature = "deserialize_bundler_options",
@@ -18,21 +25,99 @@ pub enum TreeshakeOptions {
 impl Default for TreeshakeOptions {
   /// Used for snapshot testing
   fn default() -> Self {
-    TreeshakeOptions::Option(InnerOptions { module_side_effects: ModuleSideEffects::Boolean(true) })
+    TreeshakeOptions::Option(InnerOptions {
+      module_side_effects: ModuleSideEffects::Boolean(true),
+      manual_pure_functions: None,
+      property_read_side_effects: PropertyReadSideEffects::default(),
+      annotations: None,
+      unknown_global_side_effects: None,
+    })
   }
 }
 
-#[derive(Debug)]
+type ModuleSideEffectsFunction = dyn Fn(
+    &str, // id
+    bool, // is_external
+  ) -> Pin<Box<(dyn Future<Output = anyhow::Result<bool>> + Send + 'static)>>
+  + Send
+  + Sync;
+
+/// Whether modules have side effects, unless plugins tell otherwise. Modules with side effects still go
+/// through the `sideEffects` of their `package.json` and the analysis of their code, while the others
+/// are always dropped if unused.
 pub enum ModuleSideEffects {
   Regex(HybridRegex),
   Boolean(bool),
+  /// `'no-external'`: only the modules that aren't external have side effects.
+  NoExternal,
+  /// Only the modules matching these globs have side effects. The globs are matched like the globs of
+  /// `sideEffects` in `package.json`.
+  Globs(Vec<String>),
+  /// Modules have side effects if the function returns `true`.
+  Fn(Box<ModuleSideEffectsFunction>),
 }
 
-impl ModuleSideEffects {
-  pub fn resolve(&self, path: &str) -> bool {
+impl Debug for ModuleSideEffects {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     match self {
-      ModuleSideEffects::Regex(reg) => reg.matches(path),
+      Self::Regex(value) => write!(f, "ModuleSideEffects::Regex({value:?})"),
+      Self::Boolean(value) => write!(f, "ModuleSideEffects::Boolean({value:?})"),
+      Self::NoExternal => write!(f, "ModuleSideEffects::NoExternal"),
+      Self::Globs(value) => write!(f, "ModuleSideEffects::Globs({value:?})"),
+      Self::Fn(_) => write!(f, "ModuleSideEffects::Fn(...)"),
+    }
+  }
+}
+
+impl ModuleSideEffects {
+  /// The function is called with `id`, while the regex and the globs match `stable_id`, the id relative
+  /// to `cwd`.
+  pub async fn resolve(
+    &self,
+    id: &str,
+    stable_id: &str,
+    is_external: bool,
+  ) -> anyhow::Result<bool> {
+    Ok(match self {
+      ModuleSideEffects::Regex(reg) => reg.matches(stable_id),
       ModuleSideEffects::Boolean(b) => *b,
+      ModuleSideEffects::NoExternal => !is_external,
+      ModuleSideEffects::Globs(globs) => {
+        globs.iter().any(|glob| glob_match_with_normalized_pattern(glob, stable_id))
+      }
+      ModuleSideEffects::Fn(f) => f(id, is_external).await?,
+    })
+  }
+}
+
+/// Whether reading a property, such as `foo.bar`, may have side effects by calling a getter.
+#[derive(Debug, Clone, Copy)]
+pub enum PropertyReadSideEffects {
+  /// `true` keeps the reads that aren't known to be side-effect free, such as the reads of
+  /// `Object.assign`. `false` assumes that no getters have side effects.
+  Boolean(bool),
+  /// Keeps every read, including the reads of known globals.
+  Always,
+}
+
+impl Default for PropertyReadSideEffects {
+  fn default() -> Self {
+    PropertyReadSideEffects::Boolean(true)
+  }
+}
+
+#[cfg(feature = "deserialize_bundler_options")]
+impl<'de> Deserialize<'de> for PropertyReadSideEffects {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    match serde_json::Value::deserialize(deserializer)? {
+      serde_json::Value::Bool(b) => Ok(PropertyReadSideEffects::Boolean(b)),
+      serde_json::Value::String(s) if s == "always" => Ok(PropertyReadSideEffects::Always),
+      _ => Err(serde::de::Error::custom(
+        "propertyReadSideEffects should be `true`, `false` or `\"always\"`",
+      )),
     }
   }
 }
@@ -41,6 +126,36 @@ impl TreeshakeOptions {
   pub fn enabled(&self) -> bool {
     matches!(self, TreeshakeOptions::Option(_))
   }
+
+  pub fn manual_pure_functions(&self) -> &[StringOrRegex] {
+    match self {
+      TreeshakeOptions::Option(options) => {
+        options.manual_pure_functions.as_deref().unwrap_or_default()
+      }
+      TreeshakeOptions::Boolean(_) => &[],
+    }
+  }
+
+  pub fn property_read_side_effects(&self) -> PropertyReadSideEffects {
+    match self {
+      TreeshakeOptions::Option(options) => options.property_read_side_effects,
+      TreeshakeOptions::Boolean(_) => PropertyReadSideEffects::default(),
+    }
+  }
+
+  pub fn annotations(&self) -> bool {
+    match self {
+      TreeshakeOptions::Option(options) => options.annotations.unwrap_or(true),
+      TreeshakeOptions::Boolean(_) => true,
+    }
+  }
+
+  pub fn unknown_global_side_effects(&self) -> bool {
+    match self {
+      TreeshakeOptions::Option(options) => options.unknown_global_side_effects.unwrap_or(true),
+      TreeshakeOptions::Boolean(_) => true,
+    }
+  }
 }
 
 #[derive(Debug)]
@@ -50,22 +165,59 @@ impl TreeshakeOptions {
   serde(rename_all = "camelCase", deny_unknown_fields)
 )]
 pub struct InnerOptions {
+  /// Whether modules have side effects: a boolean, `"no-external"` or the globs of the modules with
+  /// side effects.
   #[cfg_attr(
     feature = "deserialize_bundler_options",
     serde(deserialize_with = "deserialize_module_side_effects"),
-    schemars(with = "Option<bool>")
+    schemars(with = "Option<serde_json::Value>")
   )]
   pub module_side_effects: ModuleSideEffects,
+  /// Functions whose calls are side-effect free, like calls annotated with `/*#__PURE__*/`. A name such
+  /// as `styled` also covers its properties and the functions it returns, such as `styled.div` and
+  /// `styled(Button)()`. A regex is matched against the whole callee, such as `console.log`.
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(default, deserialize_with = "deserialize_string_or_regex"),
+    schemars(with = "Option<Vec<String>>")
+  )]
+  pub manual_pure_functions: Option<Vec<StringOrRegex>>,
+  /// Whether reading a property may have side effects. `false` drops unused reads such as `foo.bar`
+  /// as long as `foo` is side-effect free, and `"always"` keeps even the reads of known globals.
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(default),
+    schemars(with = "Option<serde_json::Value>")
+  )]
+  pub property_read_side_effects: PropertyReadSideEffects,
+  /// Whether to respect `/*#__PURE__*/` and `/*#__NO_SIDE_EFFECTS__*/` annotations. Defaults to `true`.
+  #[cfg_attr(feature = "deserialize_bundler_options", serde(default))]
+  pub annotations: Option<bool>,
+  /// Whether reading a global variable that isn't known to exist, such as `$` or `window.$`, may throw.
+  /// `false` drops such unused reads. Defaults to `true`.
+  #[cfg_attr(feature = "deserialize_bundler_options", serde(default))]
+  pub unknown_global_side_effects: Option<bool>,
 }
 
 #[cfg(feature = "deserialize_bundler_options")]
-fn deserialize_module_side_effects<'de, D>(deserializer: D) -> Result<ModuleSideEffects, D::Error>
+pub(crate) fn deserialize_module_side_effects<'de, D>(
+  deserializer: D,
+) -> Result<ModuleSideEffects, D::Error>
 where
   D: Deserializer<'de>,
 {
-  let deserialized = Option::<bool>::deserialize(deserializer)?;
+  use serde_json::Value;
+
+  let deserialized = Option::<Value>::deserialize(deserializer)?;
   match deserialized {
-    Some(false) => Ok(ModuleSideEffects::Boolean(false)),
-    Some(true) | None => Ok(ModuleSideEffects::Boolean(true)),
+    Some(Value::Bool(false)) => Ok(ModuleSideEffects::Boolean(false)),
+    Some(Value::Bool(true)) | None => Ok(ModuleSideEffects::Boolean(true)),
+    Some(Value::String(value)) if value == "no-external" => Ok(ModuleSideEffects::NoExternal),
+    Some(value @ Value::Array(_)) => Vec::<String>::deserialize(value)
+      .map(ModuleSideEffects::Globs)
+      .map_err(serde::de::Error::custom),
+    _ => Err(serde::de::Error::custom(
+      "moduleSideEffects should be a boolean, `\"no-external\"` or an array of globs",
+    )),
   }
 }
@@ -0,0 +1,24 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+/// How WebAssembly modules are bundled. A `wasm` module exports a function by default, which takes the
+/// imports object and resolves to the `WebAssembly.Instance`.
+#[derive(Debug, Default, Clone, Copy)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct WasmOptions {
+  /// Embed the binary as base64 instead of emitting it as an asset, for outputs that must be a single
+  /// file. Defaults to `false`.
+  pub inline: Option<bool>,
+}
+
+impl WasmOptions {
+  pub fn inline(&self) -> bool {
+    self.inline.unwrap_or(false)
+  }
+}
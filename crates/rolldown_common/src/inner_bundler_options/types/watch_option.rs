@@ -30,7 +30,7 @@ pub struct WatchOption {
 }
 
 #[cfg(feature = "deserialize_bundler_options")]
-fn deserialize_string_or_regex<'de, D>(
+pub(crate) fn deserialize_string_or_regex<'de, D>(
   deserializer: D,
 ) -> Result<Option<Vec<StringOrRegex>>, D::Error>
 where
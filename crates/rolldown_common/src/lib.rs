@@ -13,24 +13,47 @@ mod types;
 pub mod bundler_options {
   pub use crate::inner_bundler_options::{
     types::{
-      advanced_chunks_options::{AdvancedChunksOptions, MatchGroup},
+      advanced_chunks_options::{AdvancedChunksOptions, MatchGroup, MatchGroupTest},
+      assets_inline_limit::AssetsInlineLimit,
+      charset::Charset,
+      checks_options::ChecksOptions,
+      chunk_name_strategy::ChunkNameStrategy,
+      chunk_size_warning_limit::ChunkSizeWarningLimit,
+      commonjs_options::{CommonJsOptions, RequireReturnsDefault},
+      css_mode::CssMode,
+      decorator_options::DecoratorOptions,
+      drop_kind::DropKind,
       es_module_flag::EsModuleFlag,
+      es_target::EsTarget,
       experimental_options::ExperimentalOptions,
       filename_template::{FileNameRenderOptions, FilenameTemplate},
+      generated_code_options::{GeneratedCodeOptions, GeneratedCodePreset},
+      hash_characters::HashCharacters,
       inject_import::InjectImport,
       input_item::InputItem,
       is_external::IsExternal,
+      json_options::{JsonOptions, JsonStringify},
+      legal_comments::{is_legal_comment, LegalComments},
+      log_level::LogLevel,
+      minify_options::{MangleOptions, MinifyOptions, RawMangleOptions, RawMinifyOptions},
       module_type::ModuleType,
       normalized_bundler_options::{NormalizedBundlerOptions, SharedNormalizedBundlerOptions},
+      on_log::{Log, OnLog, OnLogAction, OnLogFn},
       output_exports::OutputExports,
       output_format::OutputFormat,
-      output_option::{AddonFunction, AddonOutputOption, ChunkFilenamesOutputOption},
+      output_option::{
+        AddonFunction, AddonOutputOption, AssetFilenamesOutputOption, ChunkFilenamesOutputOption,
+        InteropMode, InteropOutputOption, ManualChunksModuleInfos, ManualChunksOption,
+        SanitizeFilename,
+      },
       platform::Platform,
+      public_path::PublicPath,
       resolve_options::ResolveOptions,
       source_map_type::SourceMapType,
       sourcemap_ignore_list::SourceMapIgnoreList,
       sourcemap_path_transform::SourceMapPathTransform,
-      treeshake::{InnerOptions, ModuleSideEffects, TreeshakeOptions},
+      treeshake::{InnerOptions, ModuleSideEffects, PropertyReadSideEffects, TreeshakeOptions},
+      wasm_options::WasmOptions,
       watch_option::{NotifyOption, WatchOption},
     },
     BundlerOptions,
@@ -49,14 +72,17 @@ pub use crate::{
   css::{
     css_module::CssModule,
     css_module_idx::CssModuleIdx,
-    css_view::{CssRenderer, CssView},
+    css_view::{
+      CssLayerStatement, CssLocalClasses, CssLocalRule, CssRenderer, CssUnusedRulesRemover,
+      CssUrlReference, CssView,
+    },
   },
   ecmascript::{
     ecma_asset_meta::EcmaAssetMeta,
     ecma_view::{EcmaModuleAstUsage, EcmaView, EcmaViewMeta},
     module_idx::ModuleIdx,
   },
-  file_emitter::{EmittedAsset, FileEmitter, SharedFileEmitter},
+  file_emitter::{EmittedAsset, EmittedChunk, FileEmitter, SharedFileEmitter},
   module::{external_module::ExternalModule, normal_module::NormalModule, Module},
   types::asset::Asset,
   types::asset_idx::AssetIdx,
@@ -66,10 +92,13 @@ pub use crate::{
   types::bundler_file_system::BundlerFileSystem,
   types::chunk_idx::ChunkIdx,
   types::chunk_kind::ChunkKind,
+  types::const_enum::{ConstEnumMembers, ConstEnumValue, ConstEnums},
+  types::constant_value::{ConstantValue, Constants},
   types::ecma_ast_idx::EcmaAstIdx,
   types::entry_point::{EntryPoint, EntryPointKind},
   types::exports_kind::ExportsKind,
   types::external_module_idx::ExternalModuleIdx,
+  types::import_attributes::ImportAttributes,
   types::import_kind::ImportKind,
   types::import_record::{
     ImportRecordIdx, ImportRecordMeta, RawImportRecord, ResolvedImportRecord,
@@ -93,11 +122,12 @@ pub use crate::{
   types::rendered_module::RenderedModule,
   types::resolved_export::ResolvedExport,
   types::resolved_request_info::ResolvedId,
+  types::rollup_pre_rendered_asset::RollupPreRenderedAsset,
   types::rollup_pre_rendered_chunk::RollupPreRenderedChunk,
   types::rollup_rendered_chunk::RollupRenderedChunk,
   types::side_effects,
   types::source_mutation::SourceMutation,
-  types::stmt_info::{DebugStmtInfoForTreeShaking, StmtInfo, StmtInfoIdx, StmtInfos},
+  types::stmt_info::{DebugStmtInfoForTreeShaking, StmtInfo, StmtInfoIdx, StmtInfoMeta, StmtInfos},
   types::str_or_bytes::StrOrBytes,
   types::symbol_name_ref_token::SymbolNameRefToken,
   types::symbol_or_member_expr_ref::SymbolOrMemberExprRef,
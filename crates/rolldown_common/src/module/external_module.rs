@@ -1,5 +1,8 @@
 use crate::side_effects::DeterminedSideEffects;
-use crate::{ImportRecordIdx, ModuleIdx, ResolvedImportRecord, SymbolNameRefToken};
+use crate::{
+  Chunk, ImportAttributes, ImportRecordIdx, InteropMode, ModuleIdx, ResolvedImportRecord,
+  SymbolNameRefToken,
+};
 use arcstr::ArcStr;
 use oxc::index::IndexVec;
 
@@ -12,6 +15,16 @@ pub struct ExternalModule {
   pub name: ArcStr,
   pub import_records: IndexVec<ImportRecordIdx, ResolvedImportRecord>,
   pub side_effects: DeterminedSideEffects,
+  /// Resolved from `output.interop` once all modules are loaded.
+  pub interop: InteropMode,
+  /// Set if the module is a file of the `copy` module type, which is emitted as an asset and imported by
+  /// its path relative to the importing chunk.
+  pub copied_asset_reference_id: Option<ArcStr>,
+  /// Absolute path of the copied asset, which is known once the generate stage starts.
+  pub copied_asset_path: Option<String>,
+  /// The attributes of the import that references the module first, which are kept in the imports of
+  /// esm chunks unless `output.externalImportAttributes` is `false`.
+  pub attributes: Option<ImportAttributes>,
 }
 
 impl ExternalModule {
@@ -28,6 +41,18 @@ impl ExternalModule {
       name: module_id,
       import_records: IndexVec::default(),
       side_effects,
+      interop: InteropMode::default(),
+      copied_asset_reference_id: None,
+      copied_asset_path: None,
+      attributes: None,
+    }
+  }
+
+  /// The specifier to import the module with from `chunk`.
+  pub fn import_path_for(&self, chunk: &Chunk) -> ArcStr {
+    match &self.copied_asset_path {
+      Some(path) => chunk.import_path_for_file(path).into(),
+      None => self.name.clone(),
     }
   }
 }
@@ -122,6 +122,14 @@ impl Module {
     matches!(self, Self::Normal(..))
   }
 
+  /// Returns `true` if the module is [`External`].
+  ///
+  /// [`External`]: Module::External
+  #[must_use]
+  pub fn is_external(&self) -> bool {
+    matches!(self, Self::External(..))
+  }
+
   pub fn size(&self) -> usize {
     match self {
       Module::Normal(v) => v.source.len(),
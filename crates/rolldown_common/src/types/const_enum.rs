@@ -0,0 +1,15 @@
+use oxc::{semantic::SymbolId, span::CompactStr};
+use rustc_hash::FxHashMap;
+
+/// The value of a `const enum` member, which is inlined into the usages of the member.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConstEnumValue {
+  Number(f64),
+  String(CompactStr),
+}
+
+/// The members of a `const enum` whose values are known at compile time.
+pub type ConstEnumMembers = FxHashMap<CompactStr, ConstEnumValue>;
+
+/// The `const enum`s declared at the top level of a module, keyed by their symbols.
+pub type ConstEnums = FxHashMap<SymbolId, ConstEnumMembers>;
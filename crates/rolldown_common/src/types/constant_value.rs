@@ -0,0 +1,24 @@
+use oxc::{semantic::SymbolId, span::CompactStr};
+use rustc_hash::FxHashMap;
+
+/// The value of a top-level `const` initialized with a primitive literal, such as
+/// `export const IS_LEGACY = false`. It's propagated into the conditions that read it across modules.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConstantValue {
+  Number(f64),
+  String(CompactStr),
+  Boolean(bool),
+}
+
+impl ConstantValue {
+  pub fn is_truthy(&self) -> bool {
+    match self {
+      ConstantValue::Number(value) => value.abs() > 0.0,
+      ConstantValue::String(value) => !value.is_empty(),
+      ConstantValue::Boolean(value) => *value,
+    }
+  }
+}
+
+/// The top-level constants of a module, keyed by their symbols.
+pub type Constants = FxHashMap<SymbolId, ConstantValue>;
@@ -5,8 +5,15 @@ use crate::ModuleIdx;
 #[derive(Debug, Hash, PartialEq, Eq, Clone)]
 pub struct EntryPoint {
   pub name: Option<ArcStr>,
+  /// Set if the entry is a chunk emitted with an explicit `fileName`.
+  pub file_name: Option<ArcStr>,
   pub id: ModuleIdx,
   pub kind: EntryPointKind,
+  /// Reference ids of chunks emitted by plugins for this entry.
+  pub reference_ids: Vec<ArcStr>,
+  /// Entries after one of which this entry is guaranteed to be loaded.
+  /// See `EmittedChunk::implicitly_loaded_after_one_of`.
+  pub implicitly_loaded_after: Vec<ModuleIdx>,
 }
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone)]
@@ -0,0 +1,44 @@
+use rolldown_rstr::Rstr;
+use rolldown_utils::ecma_script::property_key_str;
+
+use crate::ModuleType;
+
+/// `{ type: 'json' }` in `import data from './data.json' with { type: 'json' }`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportAttributes(Box<[(Rstr, Rstr)]>);
+
+impl ImportAttributes {
+  pub fn new(attributes: Vec<(Rstr, Rstr)>) -> Self {
+    Self(attributes.into_boxed_slice())
+  }
+
+  pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+    self.0.iter().map(|(key, value)| (key.as_str(), value.as_str()))
+  }
+
+  pub fn get(&self, key: &str) -> Option<&str> {
+    self.iter().find_map(|(k, value)| (k == key).then_some(value))
+  }
+
+  /// The module type requested by the `type` attribute, which takes precedence over the extension of
+  /// the imported file.
+  pub fn module_type(&self) -> Option<ModuleType> {
+    match self.get("type")? {
+      "json" => Some(ModuleType::Json),
+      "css" => Some(ModuleType::Css),
+      _ => None,
+    }
+  }
+
+  /// Renders the attributes as ` with { type: "json" }`, to be appended to the module request.
+  pub fn to_with_clause(&self) -> String {
+    let entries = self
+      .iter()
+      .map(|(key, value)| {
+        let value = serde_json::to_string(value).expect("Strings should be serializable");
+        format!("{}: {value}", property_key_str(key, true))
+      })
+      .collect::<Vec<_>>();
+    format!(" with {{ {} }}", entries.join(", "))
+  }
+}
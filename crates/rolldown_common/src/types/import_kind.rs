@@ -6,6 +6,8 @@ pub enum ImportKind {
   DynamicImport,
   Require,
   AtImport,
+  /// `new URL('./worker.js', import.meta.url)` passed to `new Worker(...)` or `new SharedWorker(...)`.
+  NewUrl,
 }
 
 impl ImportKind {
@@ -23,6 +25,7 @@ impl TryFrom<&str> for ImportKind {
       "dynamic-import" => Ok(Self::DynamicImport),
       "require-call" => Ok(Self::Require),
       "import-rule" => Ok(Self::AtImport),
+      "new-url" => Ok(Self::NewUrl),
       _ => Err(format!("Invalid import kind: {value:?}")),
     }
   }
@@ -37,6 +40,7 @@ impl Display for ImportKind {
       Self::Require => write!(f, "require-call"),
       // TODO(hyf0): check if this literal is the same as esbuild's
       Self::AtImport => write!(f, "import-rule"),
+      Self::NewUrl => write!(f, "new-url"),
     }
   }
 }
@@ -5,7 +5,7 @@ use std::{
 
 use rolldown_rstr::Rstr;
 
-use crate::{ImportKind, ModuleIdx, SymbolRef};
+use crate::{ImportAttributes, ImportKind, ModuleIdx, SymbolRef};
 
 oxc::index::define_index_type! {
   pub struct ImportRecordIdx = u32;
@@ -50,6 +50,8 @@ pub struct ImportRecord<State: Debug> {
   /// `namespace_ref` represent the potential `import_foo` in above example. It's useless if we imported n esm module.
   pub namespace_ref: SymbolRef,
   pub meta: ImportRecordMeta,
+  /// `{ type: 'json' }` in `import data from './data.json' with { type: 'json' }`.
+  pub attributes: Option<ImportAttributes>,
 }
 
 impl<State: Debug> ImportRecord<State> {
@@ -86,6 +88,7 @@ impl RawImportRecord {
       kind,
       namespace_ref,
       meta: ImportRecordMeta::empty(),
+      attributes: None,
       state: ImportRecordStateStart { module_request_start },
     }
   }
@@ -102,6 +105,7 @@ impl RawImportRecord {
       kind: self.kind,
       namespace_ref: self.namespace_ref,
       meta: self.meta,
+      attributes: self.attributes,
     }
   }
 }
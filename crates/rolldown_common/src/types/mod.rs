@@ -6,10 +6,13 @@ pub mod ast_scopes;
 pub mod bundler_file_system;
 pub mod chunk_idx;
 pub mod chunk_kind;
+pub mod const_enum;
+pub mod constant_value;
 pub mod ecma_ast_idx;
 pub mod entry_point;
 pub mod exports_kind;
 pub mod external_module_idx;
+pub mod import_attributes;
 pub mod import_kind;
 pub mod import_record;
 pub mod importer_record;
@@ -31,6 +34,7 @@ pub mod package_json;
 pub mod rendered_module;
 pub mod resolved_export;
 pub mod resolved_request_info;
+pub mod rollup_pre_rendered_asset;
 pub mod rollup_pre_rendered_chunk;
 pub mod rollup_rendered_chunk;
 pub mod side_effects;
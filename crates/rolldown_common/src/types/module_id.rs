@@ -1,7 +1,10 @@
 use std::path::{Path, PathBuf};
 
 use arcstr::ArcStr;
-use rolldown_utils::path_ext::PathExt;
+use rolldown_utils::{
+  path_ext::PathExt,
+  virtual_module::{display_virtual_module_id, is_virtual_module_id},
+};
 use sugar_path::SugarPath;
 
 /// `ModuleId` is the unique string identifier for each module.
@@ -19,6 +22,11 @@ impl ModuleId {
     &self.0
   }
 
+  /// Whether the module is generated by a plugin, which means its id starts with `\0`.
+  pub fn is_virtual(&self) -> bool {
+    is_virtual_module_id(&self.0)
+  }
+
   pub fn stabilize(&self, cwd: &Path) -> String {
     stabilize_module_id(&self.0, cwd)
   }
@@ -60,9 +68,8 @@ impl ModuleId {
 pub(crate) fn stabilize_module_id(module_id: &str, cwd: &Path) -> String {
   if module_id.as_path().is_absolute() {
     module_id.relative(cwd).as_path().expect_to_slash()
-  } else if module_id.starts_with('\0') {
-    // handle virtual modules
-    module_id.replace('\0', "\\0")
+  } else if is_virtual_module_id(module_id) {
+    display_virtual_module_id(module_id)
   } else {
     module_id.to_string()
   }
@@ -2,5 +2,5 @@ use crate::{ecmascript::ecma_view::EcmaView, CssView};
 
 pub enum ModuleView {
   Ecma(EcmaView),
-  Css(CssView),
+  Css(Box<CssView>),
 }
@@ -20,6 +20,9 @@ pub struct OutputChunk {
   pub modules: FxHashMap<ModuleId, RenderedModule>,
   pub imports: Vec<ModuleId>,
   pub dynamic_imports: Vec<ModuleId>,
+  /// Css files generated from the same modules as this chunk. Loaders could use it to fetch the
+  /// styles alongside the chunk, e.g. for lazily loaded routes.
+  pub imported_css: Vec<ArcStr>,
   // OutputChunk
   pub code: String,
   pub map: Option<SourceMap>,
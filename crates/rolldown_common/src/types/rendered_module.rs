@@ -1,4 +1,6 @@
 #[derive(Debug, Clone)]
 pub struct RenderedModule {
   pub code: Option<String>,
+  /// The length of the code the module contributes to the chunk.
+  pub rendered_length: usize,
 }
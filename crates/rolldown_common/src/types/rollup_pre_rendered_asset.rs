@@ -0,0 +1,8 @@
+use crate::AssetSource;
+
+#[derive(Debug, Clone)]
+pub struct RollupPreRenderedAsset {
+  pub name: Option<String>,
+  pub original_file_name: Option<String>,
+  pub source: AssetSource,
+}
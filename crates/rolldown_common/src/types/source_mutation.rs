@@ -4,6 +4,8 @@ use string_wizard::MagicString;
 
 pub trait SourceMutation: Debug + Send + Sync {
   fn apply(&self, magic_string: &mut MagicString<'_>);
+  /// Source ranges removed by `apply`, which are needed to map the mutated source back to the original one.
+  fn removed_ranges(&self) -> &[(usize, usize)];
 }
 
 pub type BoxedSourceMutation = Box<dyn SourceMutation>;
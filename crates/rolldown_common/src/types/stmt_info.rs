@@ -66,6 +66,18 @@ oxc::index::define_index_type! {
   pub struct StmtInfoIdx = u32;
 }
 
+bitflags::bitflags! {
+  #[derive(Debug, Default, Clone, Copy)]
+  pub struct StmtInfoMeta: u8 {
+    /// The statement contains functions or classes with a name, either declared or inferred from
+    /// the binding they are assigned to. Their names are restored by `__name` under `output.keepNames`.
+    const HAS_NAMED_FN_OR_CLASS = 1;
+    /// The statement contains `require` calls whose argument isn't a string literal. They're
+    /// rewritten to calls of `__dynamicRequire` unless `commonjs.ignoreDynamicRequires` is enabled.
+    const HAS_DYNAMIC_REQUIRE = 1 << 1;
+  }
+}
+
 #[derive(Default, Debug)]
 pub struct StmtInfo {
   /// The index of this statement in the module body.
@@ -81,6 +93,7 @@ pub struct StmtInfo {
   /// Top level symbols referenced by this statement.
   pub referenced_symbols: Vec<SymbolOrMemberExprRef>,
   pub side_effect: bool,
+  pub meta: StmtInfoMeta,
   pub is_included: bool,
   pub import_records: Vec<ImportRecordIdx>,
   pub debug_label: Option<String>,
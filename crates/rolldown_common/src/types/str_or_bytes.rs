@@ -5,6 +5,17 @@ pub enum StrOrBytes {
 }
 
 impl StrOrBytes {
+  pub fn len(&self) -> usize {
+    match self {
+      Self::Str(s) => s.len(),
+      Self::Bytes(b) => b.len(),
+    }
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
   pub fn try_into_string(self) -> anyhow::Result<String> {
     match self {
       Self::Str(s) => Ok(s),
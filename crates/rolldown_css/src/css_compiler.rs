@@ -1,5 +1,7 @@
 use lightningcss::{
-  stylesheet::{ParserOptions, StyleSheet},
+  printer::PrinterOptions,
+  stylesheet::{MinifyOptions, ParserFlags, ParserOptions, StyleSheet},
+  targets::{Browsers, Targets},
   traits::IntoOwned,
 };
 
@@ -20,13 +22,38 @@ impl CssCompiler {
     );
     Ok(CssAst { stylesheet })
   }
+
+  /// Lowers syntax the `browsers` don't support, such as nesting, `oklch()`, custom media queries
+  /// and logical properties, and prints the stylesheet back.
+  pub fn lower(source: &str, filename: String, browsers: Browsers) -> anyhow::Result<String> {
+    let options = ParserOptions {
+      filename,
+      flags: ParserFlags::NESTING | ParserFlags::CUSTOM_MEDIA,
+      ..Default::default()
+    };
+    let mut stylesheet =
+      StyleSheet::parse(source, options).map_err(lightningcss::error::Error::into_owned)?;
+
+    let targets = Targets::from(browsers);
+    stylesheet.minify(MinifyOptions { targets, ..Default::default() })?;
+    let res = stylesheet.to_css(PrinterOptions { targets, ..Default::default() })?;
+    Ok(res.code)
+  }
 }
 
 #[test]
 fn basic_test() {
-  use lightningcss::printer::PrinterOptions;
   let ast = CssCompiler::parse(".bar { color: green; }", "Noop".to_string()).unwrap();
   let res = ast.stylesheet.to_css(PrinterOptions::default()).unwrap();
 
   assert_eq!(res.code, ".bar {\n  color: green;\n}\n");
 }
+
+#[test]
+fn lower_test() {
+  let browsers = Browsers { chrome: Some(90 << 16), ..Default::default() };
+  let code =
+    CssCompiler::lower(".foo { .bar { color: green; } }", "Noop".to_string(), browsers).unwrap();
+
+  assert_eq!(code, ".foo .bar {\n  color: green;\n}\n");
+}
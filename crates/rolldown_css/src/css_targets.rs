@@ -0,0 +1,53 @@
+use lightningcss::targets::Browsers;
+
+/// Parses esbuild-style targets such as `chrome90` or `safari14.1` into the browsers lightningcss
+/// lowers syntax for.
+pub fn parse_css_targets(targets: &[String]) -> anyhow::Result<Browsers> {
+  let mut browsers = Browsers::default();
+  for target in targets {
+    let split_at = target.find(|c: char| c.is_ascii_digit()).unwrap_or(target.len());
+    let (name, version) = target.split_at(split_at);
+    let version = parse_version(version)
+      .ok_or_else(|| anyhow::format_err!("Invalid css target {target:?}: missing version"))?;
+    let slot = match name.to_ascii_lowercase().as_str() {
+      "android" => &mut browsers.android,
+      "chrome" => &mut browsers.chrome,
+      "edge" => &mut browsers.edge,
+      "firefox" => &mut browsers.firefox,
+      "ie" => &mut browsers.ie,
+      "ios" | "ios_saf" => &mut browsers.ios_saf,
+      "opera" => &mut browsers.opera,
+      "safari" => &mut browsers.safari,
+      "samsung" => &mut browsers.samsung,
+      _ => anyhow::bail!("Invalid css target {target:?}: unknown browser {name:?}"),
+    };
+    // Keep the oldest version if the same browser is listed more than once.
+    *slot = Some(slot.map_or(version, |existing| existing.min(version)));
+  }
+  Ok(browsers)
+}
+
+/// lightningcss encodes `major.minor.patch` as `major << 16 | minor << 8 | patch`.
+fn parse_version(version: &str) -> Option<u32> {
+  let mut parts = version.split('.').map(str::parse::<u32>);
+  let major = parts.next()?.ok()?;
+  let minor = parts.next().transpose().ok()?.unwrap_or(0);
+  let patch = parts.next().transpose().ok()?.unwrap_or(0);
+  if parts.next().is_some() || minor > 0xff || patch > 0xff {
+    return None;
+  }
+  Some((major << 16) | (minor << 8) | patch)
+}
+
+#[test]
+fn parse_css_targets_test() {
+  let browsers =
+    parse_css_targets(&["chrome90".to_string(), "safari14.1".to_string(), "chrome80".to_string()])
+      .unwrap();
+  assert_eq!(browsers.chrome, Some(80 << 16));
+  assert_eq!(browsers.safari, Some((14 << 16) | (1 << 8)));
+  assert_eq!(browsers.firefox, None);
+
+  assert!(parse_css_targets(&["netscape4".to_string()]).is_err());
+  assert!(parse_css_targets(&["chrome".to_string()]).is_err());
+}
@@ -1,4 +1,5 @@
 mod css_ast;
 mod css_compiler;
+mod css_targets;
 
-pub use crate::{css_ast::CssAst, css_compiler::CssCompiler};
+pub use crate::{css_ast::CssAst, css_compiler::CssCompiler, css_targets::parse_css_targets};
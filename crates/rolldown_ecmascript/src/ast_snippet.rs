@@ -288,6 +288,7 @@ impl<'ast> AstSnippet<'ast> {
   ///  or
   ///  __commonJSMin when `options.profiler_names` is false
   /// ```
+  #[allow(clippy::too_many_arguments)]
   pub fn commonjs_wrapper_stmt(
     &self,
     binding_name: PassedStr,
@@ -295,6 +296,7 @@ impl<'ast> AstSnippet<'ast> {
     statements: allocator::Vec<'ast, Statement<'ast>>,
     ast_usage: EcmaModuleAstUsage,
     profiler_names: bool,
+    arrow_functions: bool,
     stable_id: &str,
   ) -> ast::Statement<'ast> {
     // (exports, module) => {}
@@ -370,10 +372,24 @@ impl<'ast> AstSnippet<'ast> {
         None,
       );
       commonjs_call_expr.arguments.push(ast::Argument::ObjectExpression(obj_expr));
-    } else {
+    } else if arrow_functions {
       let arrow_expr =
         self.builder.alloc_arrow_function_expression(SPAN, false, false, NONE, params, NONE, body);
       commonjs_call_expr.arguments.push(ast::Argument::ArrowFunctionExpression(arrow_expr));
+    } else {
+      commonjs_call_expr.arguments.push(ast::Argument::from(self.builder.expression_function(
+        FunctionType::FunctionExpression,
+        SPAN,
+        None,
+        false,
+        false,
+        false,
+        NONE,
+        NONE,
+        params,
+        NONE,
+        Some(body),
+      )));
     };
 
     // var require_foo = ...
@@ -394,6 +410,7 @@ impl<'ast> AstSnippet<'ast> {
     esm_fn_name: PassedStr,
     statements: allocator::Vec<'ast, Statement<'ast>>,
     profiler_names: bool,
+    arrow_functions: bool,
     stable_id: &str,
   ) -> ast::Statement<'ast> {
     // () => { ... }
@@ -440,10 +457,24 @@ impl<'ast> AstSnippet<'ast> {
         None,
       );
       commonjs_call_expr.arguments.push(ast::Argument::ObjectExpression(obj_expr));
-    } else {
+    } else if arrow_functions {
       let arrow_expr =
         self.builder.alloc_arrow_function_expression(SPAN, false, false, NONE, params, NONE, body);
       commonjs_call_expr.arguments.push(ast::Argument::ArrowFunctionExpression(arrow_expr));
+    } else {
+      commonjs_call_expr.arguments.push(ast::Argument::from(self.builder.expression_function(
+        FunctionType::FunctionExpression,
+        SPAN,
+        None,
+        false,
+        false,
+        false,
+        NONE,
+        NONE,
+        params,
+        NONE,
+        Some(body),
+      )));
     };
 
     // var init_foo = ...
@@ -494,6 +525,31 @@ impl<'ast> AstSnippet<'ast> {
     ast::AssignmentTarget::AssignmentTargetIdentifier(self.alloc_id_ref(id, span))
   }
 
+  /// ```js
+  /// function () { return xx }
+  /// ```
+  pub fn only_return_function_expr(&self, expr: ast::Expression<'ast>) -> ast::Expression<'ast> {
+    let statements = self.builder.vec1(self.builder.statement_return(SPAN, Some(expr)));
+    self.builder.expression_function(
+      FunctionType::FunctionExpression,
+      SPAN,
+      None,
+      false,
+      false,
+      false,
+      NONE,
+      NONE,
+      self.builder.formal_parameters(
+        SPAN,
+        ast::FormalParameterKind::FormalParameter,
+        self.builder.vec(),
+        NONE,
+      ),
+      NONE,
+      Some(self.builder.function_body(SPAN, self.builder.vec(), statements)),
+    )
+  }
+
   /// ```js
   /// () => xx
   /// ```
@@ -4,16 +4,20 @@ use arcstr::ArcStr;
 use oxc::{
   allocator::Allocator,
   codegen::{CodeGenerator, Codegen, CodegenOptions, CodegenReturn},
-  minifier::{Minifier, MinifierOptions},
+  minifier::{CompressOptions, Minifier, MinifierOptions},
   parser::{ParseOptions, Parser},
   sourcemap::SourceMap,
   span::SourceType,
 };
+use rolldown_common::MinifyOptions;
 use rolldown_error::{BuildDiagnostic, BuildResult};
 
-use crate::ecma_ast::{
-  program_cell::{ProgramCell, ProgramCellDependent, ProgramCellOwner},
-  EcmaAst,
+use crate::{
+  ecma_ast::{
+    program_cell::{ProgramCell, ProgramCellDependent, ProgramCellOwner},
+    EcmaAst,
+  },
+  mangler,
 };
 pub struct EcmaCompiler;
 
@@ -66,16 +70,29 @@ impl EcmaCompiler {
     source_text: &str,
     enable_sourcemap: bool,
     filename: &str,
+    minify_options: &MinifyOptions,
   ) -> anyhow::Result<(String, Option<SourceMap>)> {
     let allocator = Allocator::default();
     let program = Parser::new(&allocator, source_text, SourceType::default()).parse().program;
     let program = allocator.alloc(program);
-    let options = MinifierOptions { mangle: true, ..MinifierOptions::default() };
+    // Mangling configured by an object is done by `mangler` instead of the oxc mangler.
+    let mangle_options = minify_options.mangle_options();
+    let options = MinifierOptions {
+      mangle: minify_options.mangle() && mangle_options.is_none(),
+      compress: if minify_options.compress() {
+        CompressOptions::default()
+      } else {
+        CompressOptions::all_false()
+      },
+    };
     let ret = Minifier::new(options).build(&allocator, program);
+    if let Some(mangle_options) = mangle_options {
+      mangler::mangle(program, &allocator, mangle_options);
+    }
     let ret = Codegen::new()
       .with_options(CodegenOptions {
         source_map_path: enable_sourcemap.then(|| PathBuf::from(filename)),
-        minify: true,
+        minify: minify_options.codegen(),
         ..CodegenOptions::default()
       })
       .with_mangler(ret.mangler)
@@ -3,6 +3,7 @@ mod ast_snippet;
 mod ecma_ast;
 mod ecma_compiler;
 mod ext;
+mod mangler;
 
 pub use crate::{
   allocator_helpers::take_in::TakeIn,
@@ -0,0 +1,331 @@
+//! Mangling for `output.minify.mangle` configured by an object. The oxc mangler renames every local binding,
+//! so this one is used instead to keep the names asked for, and to mangle properties if it's opted in.
+//!
+//! Each mangled binding gets a name that is unique in the whole chunk, so renaming can't make a binding
+//! shadow another one.
+
+use oxc::{
+  allocator::Allocator,
+  ast::{
+    ast,
+    visit::{walk, walk_mut},
+    AstBuilder, Visit, VisitMut,
+  },
+  semantic::{ScopeTree, SemanticBuilder, SymbolFlags, SymbolId, SymbolTable},
+  syntax::keyword::is_reserved_keyword_or_global_object,
+};
+use rolldown_common::MangleOptions;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::BindingPatternExt;
+
+/// Characters of mangled names, roughly ordered by how often they appear in minified code, which helps gzip.
+const HEAD_CHARS: &[u8] = b"etnrisoalcufdhpmgvybwxkjqzETNRISOALCUFDHPMGVYBWXKJQZ$_";
+const TAIL_CHARS: &[u8] = b"etnrisoalcufdhpmgvybwxkjqzETNRISOALCUFDHPMGVYBWXKJQZ$_0123456789";
+
+pub fn mangle<'a>(
+  program: &mut ast::Program<'a>,
+  allocator: &'a Allocator,
+  options: &MangleOptions,
+) {
+  let (symbols, scopes) =
+    SemanticBuilder::new().build(program).semantic.into_symbol_table_and_scope_tree();
+
+  let mut collector = Collector::new(&symbols);
+  collector.visit_program(program);
+
+  let symbol_names = assign_symbol_names(&symbols, &scopes, &collector, options);
+  let property_names = assign_property_names(&collector, options);
+
+  let mut renamer = Renamer {
+    builder: AstBuilder::new(allocator),
+    symbols: &symbols,
+    symbol_names,
+    property_names,
+  };
+  renamer.visit_program(program);
+}
+
+fn assign_symbol_names(
+  symbols: &SymbolTable,
+  scopes: &ScopeTree,
+  collector: &Collector,
+  options: &MangleOptions,
+) -> FxHashMap<SymbolId, String> {
+  // A direct `eval` could reference any binding by its name.
+  if scopes.root_unresolved_references().keys().any(|name| name.as_str() == "eval") {
+    return FxHashMap::default();
+  }
+
+  let root_scope_id = scopes.root_scope_id();
+  let is_kept = |symbol_id: SymbolId| {
+    let name = symbols.get_name(symbol_id);
+    (!options.toplevel() && symbols.get_scope_id(symbol_id) == root_scope_id)
+      || options.is_reserved(name)
+      || (options.keep_classnames()
+        && (symbols.get_flags(symbol_id).contains(SymbolFlags::Class)
+          || collector.class_name_symbols.contains(&symbol_id)))
+      || collector.unsafe_symbols.contains(&symbol_id)
+  };
+
+  let mut taken = scopes
+    .root_unresolved_references()
+    .keys()
+    .map(ToString::to_string)
+    .chain(options.reserved.iter().flatten().cloned())
+    .collect::<FxHashSet<_>>();
+  let mut mangled = vec![];
+  for (symbol_id, references) in symbols.resolved_references.iter_enumerated() {
+    if is_kept(symbol_id) {
+      taken.insert(symbols.get_name(symbol_id).to_string());
+    } else {
+      mangled.push((symbol_id, references.len()));
+    }
+  }
+
+  // Bindings referenced more often get shorter names.
+  mangled.sort_by(|(a_id, a_count), (b_id, b_count)| b_count.cmp(a_count).then(a_id.cmp(b_id)));
+  let mut names = NameGenerator::new(&taken);
+  mangled.into_iter().map(|(symbol_id, _)| (symbol_id, names.next_name())).collect()
+}
+
+fn assign_property_names(
+  collector: &Collector,
+  options: &MangleOptions,
+) -> FxHashMap<String, String> {
+  let Some(regex) = &options.properties else {
+    return FxHashMap::default();
+  };
+
+  let (mangled, kept): (Vec<_>, Vec<_>) = collector.property_names.iter().partition(|name| {
+    regex.matches(name)
+      && !options.is_reserved(name)
+      && !collector.unsafe_property_names.contains(name.as_str())
+  });
+  let taken = kept.into_iter().cloned().collect::<FxHashSet<_>>();
+  let mut names = NameGenerator::new(&taken);
+  mangled.into_iter().map(|name| (name.clone(), names.next_name())).collect()
+}
+
+struct NameGenerator<'a> {
+  next: usize,
+  taken: &'a FxHashSet<String>,
+}
+
+impl<'a> NameGenerator<'a> {
+  fn new(taken: &'a FxHashSet<String>) -> Self {
+    Self { next: 0, taken }
+  }
+
+  fn next_name(&mut self) -> String {
+    loop {
+      let name = nth_name(self.next);
+      self.next += 1;
+      if !self.taken.contains(&name) && !is_reserved_keyword_or_global_object(&name) {
+        return name;
+      }
+    }
+  }
+}
+
+/// Returns the `n`-th name of the sequence `e`, `t`, ..., `_`, `ee`, `te`, ...
+fn nth_name(mut n: usize) -> String {
+  let mut name = String::new();
+  name.push(char::from(HEAD_CHARS[n % HEAD_CHARS.len()]));
+  n /= HEAD_CHARS.len();
+  while n > 0 {
+    n -= 1;
+    name.push(char::from(TAIL_CHARS[n % TAIL_CHARS.len()]));
+    n /= TAIL_CHARS.len();
+  }
+  name
+}
+
+/// Collects the names that can't be mangled safely, and the names of properties.
+struct Collector<'s> {
+  symbols: &'s SymbolTable,
+  /// Bindings that can't be renamed without changing the code around them, such as `a` in `({ a } = b)`
+  /// or the bindings exported by declarations.
+  unsafe_symbols: FxHashSet<SymbolId>,
+  /// Bindings whose name becomes the name of an anonymous class, such as `A` in `const A = class {}`.
+  class_name_symbols: FxHashSet<SymbolId>,
+  /// Names of properties, in the order they first appear.
+  property_names: Vec<String>,
+  seen_property_names: FxHashSet<String>,
+  /// Properties that are read by shorthand assignment targets, or written with quotes.
+  unsafe_property_names: FxHashSet<String>,
+}
+
+impl<'s> Collector<'s> {
+  fn new(symbols: &'s SymbolTable) -> Self {
+    Self {
+      symbols,
+      unsafe_symbols: FxHashSet::default(),
+      class_name_symbols: FxHashSet::default(),
+      property_names: vec![],
+      seen_property_names: FxHashSet::default(),
+      unsafe_property_names: FxHashSet::default(),
+    }
+  }
+
+  fn add_property_name(&mut self, name: &str) {
+    if self.seen_property_names.insert(name.to_string()) {
+      self.property_names.push(name.to_string());
+    }
+  }
+}
+
+impl<'a, 's> Visit<'a> for Collector<'s> {
+  fn visit_assignment_target_property_identifier(
+    &mut self,
+    it: &ast::AssignmentTargetPropertyIdentifier<'a>,
+  ) {
+    if let Some(symbol_id) =
+      it.binding.reference_id.get().and_then(|id| self.symbols.get_reference(id).symbol_id())
+    {
+      self.unsafe_symbols.insert(symbol_id);
+    }
+    self.unsafe_property_names.insert(it.binding.name.to_string());
+    walk::walk_assignment_target_property_identifier(self, it);
+  }
+
+  fn visit_export_named_declaration(&mut self, it: &ast::ExportNamedDeclaration<'a>) {
+    match &it.declaration {
+      Some(ast::Declaration::VariableDeclaration(decl)) => {
+        for declarator in &decl.declarations {
+          self
+            .unsafe_symbols
+            .extend(declarator.id.binding_identifiers().iter().filter_map(|id| id.symbol_id.get()));
+        }
+      }
+      Some(ast::Declaration::FunctionDeclaration(func)) => {
+        self.unsafe_symbols.extend(func.id.as_ref().and_then(|id| id.symbol_id.get()));
+      }
+      Some(ast::Declaration::ClassDeclaration(class)) => {
+        self.unsafe_symbols.extend(class.id.as_ref().and_then(|id| id.symbol_id.get()));
+      }
+      _ => {}
+    }
+    walk::walk_export_named_declaration(self, it);
+  }
+
+  fn visit_variable_declarator(&mut self, it: &ast::VariableDeclarator<'a>) {
+    if let (
+      ast::BindingPatternKind::BindingIdentifier(id),
+      Some(ast::Expression::ClassExpression(class)),
+    ) = (&it.id.kind, &it.init)
+    {
+      if class.id.is_none() {
+        self.class_name_symbols.extend(id.symbol_id.get());
+      }
+    }
+    walk::walk_variable_declarator(self, it);
+  }
+
+  fn visit_property_key(&mut self, it: &ast::PropertyKey<'a>) {
+    match it {
+      ast::PropertyKey::StaticIdentifier(ident) => self.add_property_name(&ident.name),
+      ast::PropertyKey::StringLiteral(lit) => {
+        self.add_property_name(&lit.value);
+        self.unsafe_property_names.insert(lit.value.to_string());
+      }
+      _ => {}
+    }
+    walk::walk_property_key(self, it);
+  }
+
+  fn visit_static_member_expression(&mut self, it: &ast::StaticMemberExpression<'a>) {
+    self.add_property_name(&it.property.name);
+    walk::walk_static_member_expression(self, it);
+  }
+
+  fn visit_computed_member_expression(&mut self, it: &ast::ComputedMemberExpression<'a>) {
+    if let ast::Expression::StringLiteral(lit) = &it.expression {
+      self.add_property_name(&lit.value);
+      self.unsafe_property_names.insert(lit.value.to_string());
+    }
+    walk::walk_computed_member_expression(self, it);
+  }
+}
+
+struct Renamer<'a, 's> {
+  builder: AstBuilder<'a>,
+  symbols: &'s SymbolTable,
+  symbol_names: FxHashMap<SymbolId, String>,
+  property_names: FxHashMap<String, String>,
+}
+
+impl<'a, 's> Renamer<'a, 's> {
+  fn property_name(&self, name: &str) -> Option<ast::Atom<'a>> {
+    self.property_names.get(name).map(|name| self.builder.atom(name))
+  }
+}
+
+impl<'a, 's> VisitMut<'a> for Renamer<'a, 's> {
+  fn visit_binding_identifier(&mut self, it: &mut ast::BindingIdentifier<'a>) {
+    if let Some(name) = it.symbol_id.get().and_then(|id| self.symbol_names.get(&id)) {
+      it.name = self.builder.atom(name);
+    }
+  }
+
+  fn visit_identifier_reference(&mut self, it: &mut ast::IdentifierReference<'a>) {
+    if let Some(name) = it
+      .reference_id
+      .get()
+      .and_then(|id| self.symbols.get_reference(id).symbol_id())
+      .and_then(|id| self.symbol_names.get(&id))
+    {
+      it.name = self.builder.atom(name);
+    }
+  }
+
+  fn visit_property_key(&mut self, it: &mut ast::PropertyKey<'a>) {
+    if let ast::PropertyKey::StaticIdentifier(ident) = it {
+      if let Some(name) = self.property_name(&ident.name) {
+        ident.name = name;
+      }
+    }
+    walk_mut::walk_property_key(self, it);
+  }
+
+  fn visit_static_member_expression(&mut self, it: &mut ast::StaticMemberExpression<'a>) {
+    if let Some(name) = self.property_name(&it.property.name) {
+      it.property.name = name;
+    }
+    walk_mut::walk_static_member_expression(self, it);
+  }
+
+  fn visit_object_property(&mut self, it: &mut ast::ObjectProperty<'a>) {
+    walk_mut::walk_object_property(self, it);
+    // Ensure `{ a }` is printed as `{ a: e }` after `a` is renamed.
+    if it.shorthand {
+      if let (ast::PropertyKey::StaticIdentifier(key), ast::Expression::Identifier(value)) =
+        (&it.key, &it.value)
+      {
+        if key.name != value.name {
+          it.shorthand = false;
+        }
+      }
+    }
+  }
+
+  fn visit_binding_property(&mut self, it: &mut ast::BindingProperty<'a>) {
+    walk_mut::walk_binding_property(self, it);
+    // Ensure `const { a } = b` and `const { a = 1 } = b` keep reading `a` after the binding is renamed.
+    if it.shorthand {
+      let value = match &it.value.kind {
+        ast::BindingPatternKind::AssignmentPattern(assign_pat) => &assign_pat.left.kind,
+        kind => kind,
+      };
+      if let (
+        ast::PropertyKey::StaticIdentifier(key),
+        ast::BindingPatternKind::BindingIdentifier(id),
+      ) = (&it.key, value)
+      {
+        if key.name != id.name {
+          it.shorthand = false;
+        }
+      }
+    }
+  }
+}
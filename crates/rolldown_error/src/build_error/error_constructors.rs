@@ -5,7 +5,7 @@ use super::BuildDiagnostic;
 use arcstr::ArcStr;
 use oxc::diagnostics::OxcDiagnostic;
 use oxc::{diagnostics::LabeledSpan, span::Span};
-use rolldown_resolver::ResolveError;
+use rolldown_resolver::{ResolveAttempt, ResolveError};
 
 use crate::events::export_undefined_variable::ExportUndefinedVariable;
 use crate::events::illegal_identifier_as_name::IllegalIdentifierAsName;
@@ -19,20 +19,34 @@ use crate::events::unhandleable_error::UnhandleableError;
 use crate::events::unloadable_dependency::{UnloadableDependency, UnloadableDependencyContext};
 use crate::events::DiagnosableArcstr;
 use crate::events::{
+  ambient_const_enum::AmbientConstEnum,
   ambiguous_external_namespace::{AmbiguousExternalNamespace, AmbiguousExternalNamespaceModule},
+  barrel_side_effects::BarrelSideEffects,
+  chunk_size_exceeded::ChunkSizeExceeded,
   circular_dependency::CircularDependency,
+  commonjs_default_import::CommonJsDefaultImport,
+  commonjs_named_import::CommonJsNamedImport,
   commonjs_variable_in_esm::{CjsExportSpan, CommonJsVariableInEsm},
+  css_ordering_conflict::CssOrderingConflict,
+  empty_bundle::EmptyBundle,
   eval::Eval,
   external_entry::ExternalEntry,
+  file_name_conflict::FileNameConflict,
   forbid_const_assign::ForbidConstAssign,
   invalid_export_option::InvalidExportOption,
-  missing_export::MissingExport,
+  missing_export::{MissingExport, MissingExportSuggestion},
   mixed_export::MixedExport,
   parse_error::ParseError,
+  require_esm::RequireEsm,
+  ses_incompatible::SesIncompatible,
   sourcemap_error::SourceMapError,
+  unanalyzable_dynamic_import::UnanalyzableDynamicImport,
+  unexpected_named_import::UnexpectedNamedImport,
   unresolved_entry::UnresolvedEntry,
   unresolved_import::UnresolvedImport,
   unresolved_import_treated_as_external::UnresolvedImportTreatedAsExternal,
+  unsupported_decorators::UnsupportedDecorators,
+  unsupported_target_syntax::UnsupportedTargetSyntax,
   NapiError,
 };
 
@@ -74,10 +88,20 @@ impl BuildDiagnostic {
     source: ArcStr,
     importer_id: ArcStr,
     importee: DiagnosableArcstr,
+    import_span: Option<Span>,
+    attempt: ResolveAttempt,
     reason: String,
     title: Option<&'static str>,
   ) -> Self {
-    Self::new_inner(DiagnosableResolveError { source, importer_id, importee, reason, title })
+    Self::new_inner(DiagnosableResolveError {
+      source,
+      importer_id,
+      importee,
+      import_span,
+      attempt,
+      reason,
+      title,
+    })
   }
 
   pub fn unloadable_dependency(
@@ -114,6 +138,8 @@ impl BuildDiagnostic {
     importer_source: ArcStr,
     imported_specifier: String,
     imported_specifier_span: Span,
+    exports: Vec<String>,
+    suggestion: Option<MissingExportSuggestion>,
   ) -> Self {
     Self::new_inner(MissingExport {
       stable_importer,
@@ -121,11 +147,13 @@ impl BuildDiagnostic {
       importer_source,
       imported_specifier,
       imported_specifier_span,
+      exports,
+      suggestion,
     })
   }
 
-  pub fn mixed_export(module_name: ArcStr, entry_module: ArcStr, export_keys: Vec<ArcStr>) -> Self {
-    Self::new_inner(MixedExport { module_name, entry_module, export_keys })
+  pub fn mixed_export(entry_module: ArcStr, name: ArcStr, export_keys: Vec<ArcStr>) -> Self {
+    Self::new_inner(MixedExport { entry_module, name, export_keys })
   }
 
   pub fn missing_global_name(module_name: ArcStr, guessed_name: ArcStr) -> Self {
@@ -151,6 +179,10 @@ impl BuildDiagnostic {
   ) -> Self {
     Self::new_inner(InvalidExportOption { export_mode, export_keys, entry_module })
   }
+
+  pub fn unexpected_named_import(importee: ArcStr, imported: ArcStr) -> Self {
+    Self::new_inner(UnexpectedNamedImport { importee, imported })
+  }
   // Esbuild
   pub fn commonjs_variable_in_esm(
     filename: String,
@@ -207,11 +239,7 @@ impl BuildDiagnostic {
           error.message.to_string(),
           error.labels.take().unwrap_or_default(),
         );
-        if matches!(severity, Severity::Warning) {
-          diagnostic.with_severity_warning()
-        } else {
-          diagnostic
-        }
+        diagnostic.with_severity(*severity)
       })
       .collect::<Vec<_>>()
   }
@@ -237,6 +265,43 @@ impl BuildDiagnostic {
     Self::new_inner(Eval { filename, span, source })
   }
 
+  pub fn commonjs_default_import(
+    importer: String,
+    importer_source: ArcStr,
+    importee: String,
+    span: Span,
+  ) -> Self {
+    Self::new_inner(CommonJsDefaultImport { importer, importer_source, importee, span })
+  }
+
+  pub fn commonjs_named_import(
+    importer: String,
+    importer_source: ArcStr,
+    importee: String,
+    imported: String,
+    span: Span,
+  ) -> Self {
+    Self::new_inner(CommonJsNamedImport { importer, importer_source, importee, imported, span })
+  }
+
+  pub fn require_esm(
+    importer: String,
+    importer_source: ArcStr,
+    importee: String,
+    span: Span,
+  ) -> Self {
+    Self::new_inner(RequireEsm { importer, importer_source, importee, span })
+  }
+
+  pub fn barrel_side_effects(
+    barrel: String,
+    barrel_source: ArcStr,
+    re_exported: String,
+    span: Span,
+  ) -> Self {
+    Self::new_inner(BarrelSideEffects { barrel, barrel_source, re_exported, span })
+  }
+
   pub fn export_undefined_variable(
     filename: String,
     source: ArcStr,
@@ -246,6 +311,63 @@ impl BuildDiagnostic {
     Self::new_inner(ExportUndefinedVariable { filename, source, span, name })
   }
 
+  pub fn ses_incompatible(filename: String, source: ArcStr, span: Span, reason: String) -> Self {
+    Self::new_inner(SesIncompatible { filename, source, span, reason })
+  }
+
+  pub fn css_ordering_conflict(
+    filename: String,
+    source: ArcStr,
+    span: Span,
+    reason: String,
+  ) -> Self {
+    Self::new_inner(CssOrderingConflict { filename, source, span, reason })
+  }
+
+  pub fn chunk_size_exceeded(
+    filename: String,
+    raw: Option<(usize, f64)>,
+    gzip: Option<(usize, f64)>,
+    largest_modules: Vec<(String, usize)>,
+  ) -> Self {
+    Self::new_inner(ChunkSizeExceeded { filename, raw, gzip, largest_modules })
+  }
+
+  pub fn file_name_conflict(filename: String) -> Self {
+    Self::new_inner(FileNameConflict { filename })
+  }
+
+  pub fn empty_bundle(chunk_name: String) -> Self {
+    Self::new_inner(EmptyBundle { chunk_name })
+  }
+
+  pub fn unsupported_target_syntax(
+    filename: String,
+    source: ArcStr,
+    span: Span,
+    feature: String,
+    target: String,
+  ) -> Self {
+    Self::new_inner(UnsupportedTargetSyntax { filename, source, span, feature, target })
+  }
+
+  pub fn ambient_const_enum(filename: String, source: ArcStr, span: Span, name: String) -> Self {
+    Self::new_inner(AmbientConstEnum { filename, source, span, name })
+  }
+
+  pub fn unanalyzable_dynamic_import(
+    filename: String,
+    source: ArcStr,
+    span: Span,
+    reason: String,
+  ) -> Self {
+    Self::new_inner(UnanalyzableDynamicImport { filename, source, span, reason })
+  }
+
+  pub fn unsupported_decorators(filename: String, source: ArcStr, span: Span) -> Self {
+    Self::new_inner(UnsupportedDecorators { filename, source, span })
+  }
+
   pub fn unhandleable_error(err: anyhow::Error) -> Self {
     Self::new_inner(UnhandleableError(err))
   }
@@ -5,8 +5,14 @@ use std::{
   ops::{Deref, DerefMut},
 };
 
+use rolldown_utils::indexmap::FxIndexMap;
+
 use crate::{
-  diagnostic::Diagnostic, events::BuildEvent, types::diagnostic_options::DiagnosticOptions,
+  diagnostic::Diagnostic,
+  events::{
+    omitted_diagnostics::OmittedDiagnostics, repeated_diagnostic::RepeatedDiagnostic, BuildEvent,
+  },
+  types::diagnostic_options::DiagnosticOptions,
 };
 
 use self::severity::Severity;
@@ -16,6 +22,8 @@ pub struct BuildDiagnostic {
   inner: Box<dyn BuildEvent>,
   source: Option<Box<dyn std::error::Error + 'static + Send + Sync>>,
   severity: Severity,
+  /// Replaces the message of `inner`, e.g. if it's rewritten by `onLog`.
+  message: Option<String>,
 }
 
 fn _assert_build_error_send_sync() {
@@ -25,7 +33,7 @@ fn _assert_build_error_send_sync() {
 
 impl Display for BuildDiagnostic {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    self.inner.message(&DiagnosticOptions::default()).fmt(f)
+    self.message(&DiagnosticOptions::default()).fmt(f)
   }
 }
 
@@ -34,6 +42,30 @@ impl BuildDiagnostic {
     self.inner.kind()
   }
 
+  /// A stable code identifying the kind of the diagnostic, such as `UNRESOLVED_IMPORT`, for filtering
+  /// diagnostics programmatically.
+  pub fn code(&self) -> String {
+    self.kind().to_string()
+  }
+
+  pub fn severity(&self) -> Severity {
+    self.severity
+  }
+
+  pub fn message(&self, opts: &DiagnosticOptions) -> String {
+    self.message.clone().unwrap_or_else(|| self.inner.message(opts))
+  }
+
+  pub(crate) fn primary_message(&self, opts: &DiagnosticOptions) -> String {
+    self.message.clone().unwrap_or_else(|| self.inner.primary_message(opts))
+  }
+
+  #[must_use]
+  pub fn with_message(mut self, message: String) -> Self {
+    self.message = Some(message);
+    self
+  }
+
   #[must_use]
   pub fn with_source(
     mut self,
@@ -44,26 +76,38 @@ impl BuildDiagnostic {
   }
 
   #[must_use]
-  pub fn with_severity_warning(mut self) -> Self {
-    self.severity = Severity::Warning;
+  pub fn with_severity(mut self, severity: Severity) -> Self {
+    self.severity = severity;
     self
   }
 
+  #[must_use]
+  pub fn with_severity_warning(self) -> Self {
+    self.with_severity(Severity::Warn)
+  }
+
   pub fn into_diagnostic(self) -> Diagnostic {
     self.into_diagnostic_with(&DiagnosticOptions::default())
   }
 
   pub fn into_diagnostic_with(self, opts: &DiagnosticOptions) -> Diagnostic {
+    self.to_diagnostic_with(opts)
+  }
+
+  pub fn to_diagnostic_with(&self, opts: &DiagnosticOptions) -> Diagnostic {
     let mut diagnostic =
       Diagnostic::new(self.kind().to_string(), self.inner.message(opts), self.severity);
     self.inner.on_diagnostic(&mut diagnostic, opts);
+    if let Some(message) = &self.message {
+      diagnostic.title.clone_from(message);
+    }
     diagnostic
   }
 
   // --- private
 
   fn new_inner(inner: impl Into<Box<dyn BuildEvent>>) -> Self {
-    Self { inner: inner.into(), source: None, severity: Severity::Error }
+    Self { inner: inner.into(), source: None, severity: Severity::Error, message: None }
   }
 }
 
@@ -115,6 +159,58 @@ impl BatchedBuildDiagnostic {
   pub fn into_vec(self) -> Vec<BuildDiagnostic> {
     self.0
   }
+
+  /// Collapses diagnostics with the same code, severity, primary message and help into one, which keeps a
+  /// few representative locations and reports how many more occurrences there are. The help is part of
+  /// the key, as it's only kept once for all of the occurrences.
+  #[must_use]
+  pub fn aggregate(self, opts: &DiagnosticOptions) -> Self {
+    let mut groups: FxIndexMap<(String, Severity, String, Option<String>), Vec<BuildDiagnostic>> =
+      FxIndexMap::default();
+    for diagnostic in self.0 {
+      let key = (
+        diagnostic.code(),
+        diagnostic.severity,
+        diagnostic.primary_message(opts),
+        diagnostic.to_diagnostic_with(opts).help,
+      );
+      groups.entry(key).or_default().push(diagnostic);
+    }
+    Self(
+      groups
+        .into_values()
+        .map(|mut occurrences| {
+          if occurrences.len() == 1 {
+            occurrences.remove(0)
+          } else {
+            let severity = occurrences[0].severity;
+            BuildDiagnostic::new_inner(RepeatedDiagnostic { occurrences }).with_severity(severity)
+          }
+        })
+        .collect(),
+    )
+  }
+
+  /// Keeps the first `max` diagnostics and replaces the rest with one diagnostic counting them per code, so
+  /// a badly broken build doesn't report tens of thousands of them.
+  #[must_use]
+  pub fn cap(mut self, max: usize) -> Self {
+    if self.0.len() <= max {
+      return self;
+    }
+    let omitted = self.0.split_off(max);
+    let severity = omitted[0].severity;
+    let mut counts: Vec<(String, usize)> = vec![];
+    for diagnostic in omitted {
+      let code = diagnostic.code();
+      match counts.iter_mut().find(|(existing, _)| *existing == code) {
+        Some((_, count)) => *count += 1,
+        None => counts.push((code, 1)),
+      }
+    }
+    self.0.push(BuildDiagnostic::new_inner(OmittedDiagnostics { counts }).with_severity(severity));
+    self
+  }
 }
 
 impl Deref for BatchedBuildDiagnostic {
@@ -130,3 +226,54 @@ impl DerefMut for BatchedBuildDiagnostic {
     &mut self.0
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use arcstr::ArcStr;
+  use oxc::span::Span;
+
+  use crate::{BatchedBuildDiagnostic, BuildDiagnostic, DiagnosticOptions, Severity};
+
+  fn eval_in(filename: &str) -> BuildDiagnostic {
+    BuildDiagnostic::eval(filename.to_string(), ArcStr::from("eval('1');"), Span::new(0, 4))
+      .with_severity_warning()
+  }
+
+  #[test]
+  fn test_aggregate() {
+    let opts = DiagnosticOptions::default();
+    let diagnostics = BatchedBuildDiagnostic::new(vec![
+      eval_in("a.js"),
+      BuildDiagnostic::circular_dependency(vec!["a.js".to_string(), "b.js".to_string()]),
+      eval_in("b.js"),
+      eval_in("c.js").with_severity(Severity::Info),
+      eval_in("d.js"),
+      eval_in("e.js"),
+      eval_in("f.js"),
+    ])
+    .aggregate(&opts);
+
+    // Groups keep the order of their first occurrence, and differing severities aren't merged.
+    let summary = diagnostics
+      .iter()
+      .map(|diagnostic| (diagnostic.code(), diagnostic.severity()))
+      .collect::<Vec<_>>();
+    assert_eq!(
+      summary,
+      vec![
+        ("EVAL".to_string(), Severity::Warn),
+        ("CIRCULAR_DEPENDENCY".to_string(), Severity::Error),
+        ("EVAL".to_string(), Severity::Info),
+      ]
+    );
+
+    let repeated = diagnostics[0].to_diagnostic_with(&opts).to_serialized();
+    let files = repeated.labels.iter().map(|label| label.file.as_str()).collect::<Vec<_>>();
+    assert_eq!(files, vec!["a.js", "b.js", "d.js"]);
+    assert_eq!(repeated.help.as_deref(), Some("and 2 more occurrences"));
+
+    let single = diagnostics[2].to_diagnostic_with(&opts).to_serialized();
+    assert_eq!(single.labels.len(), 1);
+    assert_eq!(single.help, None);
+  }
+}
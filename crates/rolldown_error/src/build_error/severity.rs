@@ -1,5 +1,11 @@
-#[derive(Debug, Clone, Copy)]
+use serde::Serialize;
+
+/// Ordered from the most to the least severe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub enum Severity {
   Error,
-  Warning,
+  Warn,
+  Info,
+  Debug,
 }
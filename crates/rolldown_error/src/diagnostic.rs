@@ -1,6 +1,7 @@
 use crate::build_error::severity::Severity;
 use arcstr::ArcStr;
 use ariadne::{sources, Config, Label, Report, ReportBuilder, ReportKind};
+use serde::Serialize;
 use std::{fmt::Display, ops::Range};
 
 #[derive(Debug, Clone)]
@@ -11,11 +12,44 @@ pub struct Diagnostic {
   pub(crate) kind: String,
   pub(crate) title: String,
   pub(crate) files: Vec<(/* filename */ ArcStr, /* file content */ ArcStr)>,
-  pub(crate) labels: Vec<Label<(/* filename */ ArcStr, Range<usize>)>>,
+  pub(crate) labels: Vec<DiagnosticLabel>,
   pub(crate) help: Option<String>,
   pub(crate) severity: Severity,
 }
 
+#[derive(Debug, Clone)]
+pub(crate) struct DiagnosticLabel {
+  file: ArcStr,
+  range: Range<usize>,
+  message: String,
+}
+
+/// The serializable form of a [`Diagnostic`], for tools such as editors and CI that consume diagnostics
+/// programmatically.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SerializedDiagnostic {
+  /// A stable code identifying the kind of the diagnostic, such as `UNRESOLVED_IMPORT`.
+  pub code: String,
+  pub severity: Severity,
+  pub message: String,
+  pub help: Option<String>,
+  pub labels: Vec<SerializedDiagnosticLabel>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SerializedDiagnosticLabel {
+  pub file: String,
+  /// Byte offsets of the labeled span in the file.
+  pub start: usize,
+  pub end: usize,
+  /// 1-based line and 0-based column, in characters, of the start of the span.
+  pub line: usize,
+  pub column: usize,
+  pub message: String,
+}
+
 type AriadneReportBuilder = ReportBuilder<'static, (ArcStr, Range<usize>)>;
 type AriadneReport = Report<'static, (ArcStr, Range<usize>)>;
 
@@ -56,16 +90,67 @@ impl Diagnostic {
   ) -> &mut Self {
     let range = range.into();
     let range = range.start as usize..range.end as usize;
-    let label = Label::new((file_id.0.clone(), range)).with_message(message);
-    self.labels.push(label);
+    self.labels.push(DiagnosticLabel { file: file_id.0.clone(), range, message });
     self
   }
 
+  /// Adds the files and labels of `other`, so occurrences of a repeated diagnostic are rendered in one report.
+  pub(crate) fn merge_locations(&mut self, other: Diagnostic) {
+    for (filename, content) in other.files {
+      if self.files.iter().all(|(id, _)| id != &filename) {
+        self.files.push((filename, content));
+      }
+    }
+    self.labels.extend(other.labels);
+    if self.help.is_none() {
+      self.help = other.help;
+    }
+  }
+
+  pub fn code(&self) -> &str {
+    &self.kind
+  }
+
+  pub fn to_serialized(&self) -> SerializedDiagnostic {
+    SerializedDiagnostic {
+      code: self.kind.clone(),
+      severity: self.severity,
+      message: self.title.clone(),
+      help: self.help.clone(),
+      labels: self
+        .labels
+        .iter()
+        .map(|label| {
+          let content = self
+            .files
+            .iter()
+            .find_map(|(filename, content)| (filename == &label.file).then_some(content.as_str()))
+            .unwrap_or_default();
+          let before = content.get(..label.range.start).unwrap_or_default();
+          let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+          SerializedDiagnosticLabel {
+            file: label.file.to_string(),
+            start: label.range.start,
+            end: label.range.end,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count(),
+            message: label.message.clone(),
+          }
+        })
+        .collect(),
+    }
+  }
+
+  pub fn to_json(&self) -> String {
+    serde_json::to_string(&self.to_serialized()).expect("Diagnostic should be serializable")
+  }
+
   fn init_report_builder(&mut self) -> AriadneReportBuilder {
     let mut builder = AriadneReport::build(
       match self.severity {
         Severity::Error => ReportKind::Error,
-        Severity::Warning => ReportKind::Warning,
+        Severity::Warn => ReportKind::Warning,
+        Severity::Info | Severity::Debug => ReportKind::Advice,
       },
       "",
       0,
@@ -73,8 +158,10 @@ impl Diagnostic {
     .with_code(self.kind.clone())
     .with_message(self.title.clone());
 
-    for label in self.labels.clone() {
-      builder = builder.with_label(label);
+    for label in &self.labels {
+      builder = builder.with_label(
+        Label::new((label.file.clone(), label.range.clone())).with_message(label.message.clone()),
+      );
     }
 
     if let Some(help) = &self.help {
@@ -105,3 +192,80 @@ impl Display for Diagnostic {
     self.convert_to_string(false).fmt(f)
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use arcstr::ArcStr;
+  use oxc::span::Span;
+  use serde_json::{json, Value};
+
+  use super::Diagnostic;
+  use crate::{BuildDiagnostic, DiagnosticOptions, Severity};
+
+  #[test]
+  fn test_to_json() {
+    let mut diagnostic =
+      Diagnostic::new("EVAL".to_string(), "Use of eval".to_string(), Severity::Warn);
+    let file_id = diagnostic.add_file("main.js", "const ä = 1;\nä = 2;");
+    diagnostic
+      .add_label(&file_id, 14..16, "`ä` is used here".to_string())
+      .add_label(&file_id, 11..12, "a number".to_string())
+      .add_help("Remove it".to_string());
+
+    let json: Value = serde_json::from_str(&diagnostic.to_json()).unwrap();
+    assert_eq!(
+      json,
+      json!({
+        "code": "EVAL",
+        "severity": "warn",
+        "message": "Use of eval",
+        "help": "Remove it",
+        "labels": [
+          { "file": "main.js", "start": 14, "end": 16, "line": 2, "column": 0, "message": "`ä` is used here" },
+          // The column counts characters rather than bytes.
+          { "file": "main.js", "start": 11, "end": 12, "line": 1, "column": 10, "message": "a number" },
+        ],
+      })
+    );
+  }
+
+  #[test]
+  fn test_to_serialized() {
+    let source = ArcStr::from("const a = 1;\na = 2;");
+    let serialized = BuildDiagnostic::forbid_const_assign(
+      "main.js".to_string(),
+      source,
+      "a".to_string(),
+      Span::new(6, 11),
+      Span::new(13, 18),
+    )
+    .to_diagnostic_with(&DiagnosticOptions::default())
+    .to_serialized();
+
+    assert_eq!(serialized.code, "ILLEGAL_REASSIGNMENT");
+    assert_eq!(serialized.severity, Severity::Error);
+    assert_eq!(serialized.message, "Unexpected re-assignment of const variable `a`");
+    assert_eq!(serialized.help, None);
+    let labels = serialized
+      .labels
+      .iter()
+      .map(|label| {
+        (
+          label.file.as_str(),
+          label.start,
+          label.end,
+          label.line,
+          label.column,
+          label.message.as_str(),
+        )
+      })
+      .collect::<Vec<_>>();
+    assert_eq!(
+      labels,
+      vec![
+        ("main.js", 13, 18, 2, 0, "`a` is re-assigned here"),
+        ("main.js", 6, 11, 1, 6, "`a` is declared here as const"),
+      ]
+    );
+  }
+}
@@ -18,10 +18,25 @@ pub enum EventKind {
   SourcemapError,
   MissingExport,
   InvalidExportOption,
+  UnexpectedNamedImport,
+  FileNameConflict,
+  EmptyBundle,
   // --- These kinds are rolldown specific
   IllegalReassignment,
   UnloadableDependency,
   ResolveError(Option<&'static str>),
+  SesIncompatible,
+  CssOrderingConflict,
+  CommonJsDefaultImport,
+  CommonJsNamedImport,
+  RequireEsm,
+  BarrelSideEffects,
+  OmittedDiagnostics,
+  ChunkSizeExceeded,
+  UnsupportedTargetSyntax,
+  AmbientConstEnum,
+  UnanalyzableDynamicImport,
+  UnsupportedDecorators,
   // !! Only add new kind if it's not covered by the kinds from rollup !!
 
   // TODO remove following kinds
@@ -55,6 +70,9 @@ impl Display for EventKind {
       EventKind::CircularDependency => write!(f, "CIRCULAR_DEPENDENCY"),
       EventKind::MissingExport => write!(f, "MISSING_EXPORT"),
       EventKind::InvalidExportOption => write!(f, "INVALID_EXPORT_OPTION"),
+      EventKind::UnexpectedNamedImport => write!(f, "UNEXPECTED_NAMED_IMPORT"),
+      EventKind::FileNameConflict => write!(f, "FILE_NAME_CONFLICT"),
+      EventKind::EmptyBundle => write!(f, "EMPTY_BUNDLE"),
       EventKind::InvalidOption => write!(f, "INVALID_OPTION"),
       // --- Rolldown specific
       EventKind::NapiError => write!(f, "NAPI_ERROR"),
@@ -66,6 +84,18 @@ impl Display for EventKind {
         None => write!(f, "RESOLVE_ERROR"),
       },
       EventKind::ImportIsUndefined => write!(f, "IMPORT_IS_UNDEFINED"),
+      EventKind::SesIncompatible => write!(f, "SES_INCOMPATIBLE"),
+      EventKind::CssOrderingConflict => write!(f, "CSS_ORDERING_CONFLICT"),
+      EventKind::CommonJsDefaultImport => write!(f, "COMMONJS_DEFAULT_IMPORT"),
+      EventKind::CommonJsNamedImport => write!(f, "COMMONJS_NAMED_IMPORT"),
+      EventKind::RequireEsm => write!(f, "REQUIRE_ESM"),
+      EventKind::BarrelSideEffects => write!(f, "BARREL_SIDE_EFFECTS"),
+      EventKind::OmittedDiagnostics => write!(f, "OMITTED_DIAGNOSTICS"),
+      EventKind::ChunkSizeExceeded => write!(f, "CHUNK_SIZE_EXCEEDED"),
+      EventKind::UnsupportedTargetSyntax => write!(f, "UNSUPPORTED_TARGET_SYNTAX"),
+      EventKind::AmbientConstEnum => write!(f, "AMBIENT_CONST_ENUM"),
+      EventKind::UnanalyzableDynamicImport => write!(f, "UNANALYZABLE_DYNAMIC_IMPORT"),
+      EventKind::UnsupportedDecorators => write!(f, "UNSUPPORTED_DECORATORS"),
       EventKind::UnhandleableError => write!(f, "UNHANDLEABLE_ERROR"),
     }
   }
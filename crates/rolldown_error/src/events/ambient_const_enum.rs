@@ -0,0 +1,53 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use crate::{
+  diagnostic::Diagnostic, event_kind::EventKind, types::diagnostic_options::DiagnosticOptions,
+};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct AmbientConstEnum {
+  pub filename: String,
+  pub source: ArcStr,
+  /// The span of the name of the `declare const enum`.
+  pub span: Span,
+  pub name: String,
+}
+
+impl BuildEvent for AmbientConstEnum {
+  fn kind(&self) -> EventKind {
+    EventKind::AmbientConstEnum
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      "'{}' uses the ambient const enum `{}`, whose definition isn't part of the build. Its members can't be inlined and are read from a global `{}` at runtime.",
+      opts.stabilize_path(&self.filename),
+      self.name,
+      self.name
+    )
+  }
+
+  fn primary_message(&self, _opts: &DiagnosticOptions) -> String {
+    format!(
+      "The members of the ambient const enum `{}` can't be inlined and are read from a global `{}` at runtime.",
+      self.name, self.name
+    )
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    let filename = opts.stabilize_path(&self.filename);
+
+    diagnostic.title = self.primary_message(opts);
+
+    let file_id = diagnostic.add_file(filename, self.source.clone());
+
+    diagnostic.add_label(
+      &file_id,
+      self.span.start..self.span.end,
+      "The definition of this enum isn't part of the build.".to_string(),
+    );
+  }
+}
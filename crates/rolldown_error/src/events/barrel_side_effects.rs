@@ -0,0 +1,46 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use crate::{diagnostic::Diagnostic, types::diagnostic_options::DiagnosticOptions};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct BarrelSideEffects {
+  pub barrel: String,
+  pub barrel_source: ArcStr,
+  pub re_exported: String,
+  pub span: Span,
+}
+
+impl BuildEvent for BarrelSideEffects {
+  fn kind(&self) -> crate::event_kind::EventKind {
+    crate::event_kind::EventKind::BarrelSideEffects
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      "{} Re-exported by \"{}\".",
+      self.primary_message(opts),
+      opts.stabilize_path(&self.barrel)
+    )
+  }
+
+  fn primary_message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      "\"{}\" is included for its side effects, although none of its exports are used. Mark it as side effect free in `sideEffects` of its package.json or `treeshake.moduleSideEffects` if that's intended.",
+      opts.stabilize_path(&self.re_exported)
+    )
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    diagnostic.title = self.primary_message(opts);
+    let file_id =
+      diagnostic.add_file(opts.stabilize_path(&self.barrel), self.barrel_source.clone());
+    diagnostic.add_label(
+      &file_id,
+      self.span.start..self.span.end,
+      "Re-exported by this `export *`".to_string(),
+    );
+  }
+}
@@ -0,0 +1,39 @@
+use super::BuildEvent;
+use crate::{event_kind::EventKind, types::diagnostic_options::DiagnosticOptions};
+
+#[derive(Debug)]
+pub struct ChunkSizeExceeded {
+  pub filename: String,
+  /// The size of the chunk and its limit, if the limit is exceeded.
+  pub raw: Option<(usize, f64)>,
+  /// The size of the chunk compressed by gzip and its limit, if the limit is exceeded.
+  pub gzip: Option<(usize, f64)>,
+  /// Ids of the modules contributing the most to the chunk, along with their rendered sizes.
+  pub largest_modules: Vec<(String, usize)>,
+}
+
+impl BuildEvent for ChunkSizeExceeded {
+  fn kind(&self) -> EventKind {
+    EventKind::ChunkSizeExceeded
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    let mut exceeded = vec![];
+    if let Some((size, limit)) = self.raw {
+      exceeded.push(format!("{size} bytes, larger than the limit of {limit} bytes"));
+    }
+    if let Some((size, limit)) = self.gzip {
+      exceeded.push(format!("{size} bytes gzipped, larger than the limit of {limit} bytes"));
+    }
+    let mut message = format!("Chunk '{}' is {}.", self.filename, exceeded.join(" and "));
+    if !self.largest_modules.is_empty() {
+      let modules = self
+        .largest_modules
+        .iter()
+        .map(|(id, size)| format!("'{}' ({size} bytes)", opts.stabilize_path(id)))
+        .collect::<Vec<_>>();
+      message.push_str(&format!(" The largest modules are {}.", modules.join(", ")));
+    }
+    message
+  }
+}
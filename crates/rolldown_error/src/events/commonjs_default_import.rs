@@ -0,0 +1,46 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use crate::{diagnostic::Diagnostic, types::diagnostic_options::DiagnosticOptions};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct CommonJsDefaultImport {
+  pub importer: String,
+  pub importer_source: ArcStr,
+  pub importee: String,
+  pub span: Span,
+}
+
+impl BuildEvent for CommonJsDefaultImport {
+  fn kind(&self) -> crate::event_kind::EventKind {
+    crate::event_kind::EventKind::CommonJsDefaultImport
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      "{} Imported by \"{}\".",
+      self.primary_message(opts),
+      opts.stabilize_path(&self.importer)
+    )
+  }
+
+  fn primary_message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      "The default import of \"{}\" is its whole `module.exports`, because the CommonJS module doesn't set `__esModule`.",
+      opts.stabilize_path(&self.importee)
+    )
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    diagnostic.title = self.primary_message(opts);
+    let file_id =
+      diagnostic.add_file(opts.stabilize_path(&self.importer), self.importer_source.clone());
+    diagnostic.add_label(
+      &file_id,
+      self.span.start..self.span.end,
+      "Default import of a CommonJS module".to_string(),
+    );
+  }
+}
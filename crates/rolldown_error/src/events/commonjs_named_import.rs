@@ -0,0 +1,48 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use crate::{diagnostic::Diagnostic, types::diagnostic_options::DiagnosticOptions};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct CommonJsNamedImport {
+  pub importer: String,
+  pub importer_source: ArcStr,
+  pub importee: String,
+  pub imported: String,
+  pub span: Span,
+}
+
+impl BuildEvent for CommonJsNamedImport {
+  fn kind(&self) -> crate::event_kind::EventKind {
+    crate::event_kind::EventKind::CommonJsNamedImport
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      "{} Imported by \"{}\".",
+      self.primary_message(opts),
+      opts.stabilize_path(&self.importer)
+    )
+  }
+
+  fn primary_message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      "\"{}\" of the CommonJS module \"{}\" is read from its `module.exports` at runtime, so it can't be checked at build time.",
+      self.imported,
+      opts.stabilize_path(&self.importee)
+    )
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    diagnostic.title = self.primary_message(opts);
+    let file_id =
+      diagnostic.add_file(opts.stabilize_path(&self.importer), self.importer_source.clone());
+    diagnostic.add_label(
+      &file_id,
+      self.span.start..self.span.end,
+      "Named import of a CommonJS module".to_string(),
+    );
+  }
+}
@@ -0,0 +1,42 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use crate::{diagnostic::Diagnostic, types::diagnostic_options::DiagnosticOptions};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct CssOrderingConflict {
+  pub filename: String,
+  pub source: ArcStr,
+  pub span: Span,
+  pub reason: String,
+}
+
+impl BuildEvent for CssOrderingConflict {
+  fn kind(&self) -> crate::event_kind::EventKind {
+    crate::event_kind::EventKind::CssOrderingConflict
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      "'{}' conflicts with the stylesheets bundled before it: {}.",
+      opts.stabilize_path(&self.filename),
+      self.reason
+    )
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    let filename = opts.stabilize_path(&self.filename);
+
+    diagnostic.title = format!("Conflicting css rule: {}.", self.reason);
+
+    let file_id = diagnostic.add_file(filename, self.source.clone());
+
+    diagnostic.add_label(
+      &file_id,
+      self.span.start..self.span.end,
+      "The earlier declaration takes precedence over this rule.".to_string(),
+    );
+  }
+}
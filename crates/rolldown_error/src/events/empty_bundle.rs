@@ -0,0 +1,17 @@
+use super::BuildEvent;
+use crate::{event_kind::EventKind, types::diagnostic_options::DiagnosticOptions};
+
+#[derive(Debug)]
+pub struct EmptyBundle {
+  pub chunk_name: String,
+}
+
+impl BuildEvent for EmptyBundle {
+  fn kind(&self) -> EventKind {
+    EventKind::EmptyBundle
+  }
+
+  fn message(&self, _opts: &DiagnosticOptions) -> String {
+    format!("Generated an empty chunk: \"{}\".", self.chunk_name)
+  }
+}
@@ -21,10 +21,14 @@ impl BuildEvent for Eval {
     format!("Use of eval in '{}' is strongly discouraged as it poses security risks and may cause issues with minification.", self.filename)
   }
 
+  fn primary_message(&self, _opts: &DiagnosticOptions) -> String {
+    "Use of eval is strongly discouraged as it poses security risks and may cause issues with minification.".to_string()
+  }
+
   fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
     let filename = opts.stabilize_path(&self.filename);
 
-    diagnostic.title = "Use of eval is strongly discouraged as it poses security risks and may cause issues with minification.".to_string();
+    diagnostic.title = self.primary_message(opts);
 
     let file_id = diagnostic.add_file(filename, self.source.clone());
 
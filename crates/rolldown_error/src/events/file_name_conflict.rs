@@ -0,0 +1,21 @@
+use super::BuildEvent;
+use crate::{event_kind::EventKind, types::diagnostic_options::DiagnosticOptions};
+
+#[derive(Debug)]
+pub struct FileNameConflict {
+  /// The path of the file relative to the output directory.
+  pub filename: String,
+}
+
+impl BuildEvent for FileNameConflict {
+  fn kind(&self) -> EventKind {
+    EventKind::FileNameConflict
+  }
+
+  fn message(&self, _opts: &DiagnosticOptions) -> String {
+    format!(
+      "The file '{}' of the public directory conflicts with an emitted file of the same name, so it isn't copied.",
+      self.filename
+    )
+  }
+}
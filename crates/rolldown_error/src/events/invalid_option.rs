@@ -4,6 +4,7 @@ use crate::{DiagnosticOptions, EventKind};
 #[derive(Debug)]
 pub enum InvalidOptionTypes {
   UnsupportedCodeSplittingFormat,
+  InlineDynamicImportsWithMultipleEntries,
 }
 
 #[derive(Debug)]
@@ -22,6 +23,9 @@ impl BuildEvent for InvalidOption {
       InvalidOptionTypes::UnsupportedCodeSplittingFormat => {
         format!("Invalid value \"{}\" for option \"format\". UMD and IIFE are not supported for code splitting. You may set `output.inlineDynamicImports` to `true` when using dynamic imports.", self.option)
       }
+      InvalidOptionTypes::InlineDynamicImportsWithMultipleEntries => {
+        format!("Invalid value \"true\" for option \"output.inlineDynamicImports\". Multiple entries are not supported when inlining dynamic imports, but {} entries were found. Bundle each entry separately, or remove the option to allow code splitting.", self.option)
+      }
     }
   }
 }
@@ -5,6 +5,9 @@ use crate::{event_kind::EventKind, types::diagnostic_options::DiagnosticOptions}
 
 use super::BuildEvent;
 
+/// At most this many exports of the importee are listed in the help, so huge modules don't flood the output.
+const MAX_LISTED_EXPORTS: usize = 10;
+
 #[derive(Debug)]
 pub struct MissingExport {
   pub stable_importer: String,
@@ -12,6 +15,37 @@ pub struct MissingExport {
   pub importer_source: ArcStr,
   pub imported_specifier: String,
   pub imported_specifier_span: Span,
+  /// Sorted names of the exports of the importee.
+  pub exports: Vec<String>,
+  pub suggestion: Option<MissingExportSuggestion>,
+}
+
+/// The export that the missing one is likely a typo of, and where it's declared.
+#[derive(Debug)]
+pub struct MissingExportSuggestion {
+  pub name: String,
+  pub stable_exporter: String,
+  pub exporter_source: ArcStr,
+  pub span: Span,
+}
+
+impl MissingExport {
+  fn exports_help(&self) -> String {
+    if self.exports.is_empty() {
+      return format!(r#""{}" has no exports."#, self.stable_importee);
+    }
+    let mut listed = self
+      .exports
+      .iter()
+      .take(MAX_LISTED_EXPORTS)
+      .map(|name| format!(r#""{name}""#))
+      .collect::<Vec<_>>()
+      .join(", ");
+    if self.exports.len() > MAX_LISTED_EXPORTS {
+      listed.push_str(&format!(" and {} more", self.exports.len() - MAX_LISTED_EXPORTS));
+    }
+    format!(r#""{}" exports {listed}."#, self.stable_importee)
+  }
 }
 
 impl BuildEvent for MissingExport {
@@ -20,10 +54,14 @@ impl BuildEvent for MissingExport {
   }
 
   fn message(&self, _opts: &DiagnosticOptions) -> String {
-    format!(
+    let mut message = format!(
       r#""{}" is not exported by "{}", imported by "{}"."#,
       self.imported_specifier, &self.stable_importee, &self.stable_importer
-    )
+    );
+    if let Some(suggestion) = &self.suggestion {
+      message.push_str(&format!(r#" Did you mean "{}"?"#, suggestion.name));
+    }
+    message
   }
 
   fn on_diagnostic(
@@ -42,5 +80,20 @@ impl BuildEvent for MissingExport {
       self.imported_specifier_span.start..self.imported_specifier_span.end,
       "Missing export".to_string(),
     );
+
+    if let Some(suggestion) = &self.suggestion {
+      let exporter_file = if suggestion.stable_exporter == self.stable_importer {
+        importer_file
+      } else {
+        diagnostic.add_file(suggestion.stable_exporter.clone(), suggestion.exporter_source.clone())
+      };
+      diagnostic.add_label(
+        &exporter_file,
+        suggestion.span.start..suggestion.span.end,
+        format!(r#"Did you mean "{}"?"#, suggestion.name),
+      );
+    }
+
+    diagnostic.add_help(self.exports_help());
   }
 }
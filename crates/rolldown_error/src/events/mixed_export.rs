@@ -4,8 +4,10 @@ use arcstr::ArcStr;
 
 #[derive(Debug)]
 pub struct MixedExport {
-  pub module_name: ArcStr,
   pub entry_module: ArcStr,
+  /// `output.name`, or `chunk` if it's not set.
+  pub name: ArcStr,
+  /// Named exports of the entry module, excluding `default`.
   pub export_keys: Vec<ArcStr>,
 }
 
@@ -19,7 +21,7 @@ impl BuildEvent for MixedExport {
       r#"Entry module "{}" is using named (including {}) and default exports together. Consumers of your bundle will have to use `{}.default` to access the default export, which may not be what you want. Use `output.exports: "named"` to disable this warning."#,
       &self.entry_module,
       &self.export_keys.iter().map(|k| format!(r#""{k}""#)).collect::<Vec<_>>().join(", "),
-      &self.module_name
+      &self.name
     )
   }
 }
@@ -7,12 +7,20 @@ use crate::{
   diagnostic::Diagnostic, event_kind::EventKind, types::diagnostic_options::DiagnosticOptions,
 };
 
+pub mod ambient_const_enum;
 pub mod ambiguous_external_namespace;
+pub mod barrel_side_effects;
+pub mod chunk_size_exceeded;
 pub mod circular_dependency;
+pub mod commonjs_default_import;
+pub mod commonjs_named_import;
 pub mod commonjs_variable_in_esm;
+pub mod css_ordering_conflict;
+pub mod empty_bundle;
 pub mod eval;
 pub mod export_undefined_variable;
 pub mod external_entry;
+pub mod file_name_conflict;
 pub mod forbid_const_assign;
 pub mod illegal_identifier_as_name;
 pub mod import_is_undefined;
@@ -23,20 +31,34 @@ pub mod missing_global_name;
 pub mod missing_name_option_for_iife_export;
 pub mod missing_name_option_for_umd_export;
 pub mod mixed_export;
+pub mod omitted_diagnostics;
 pub mod parse_error;
+pub mod repeated_diagnostic;
+pub mod require_esm;
 pub mod resolve_error;
+pub mod ses_incompatible;
 pub mod sourcemap_error;
+pub mod unanalyzable_dynamic_import;
+pub mod unexpected_named_import;
 pub mod unhandleable_error;
 pub mod unloadable_dependency;
 pub mod unresolved_entry;
 pub mod unresolved_import;
 pub mod unresolved_import_treated_as_external;
+pub mod unsupported_decorators;
+pub mod unsupported_target_syntax;
 
 pub trait BuildEvent: Debug + Sync + Send {
   fn kind(&self) -> EventKind;
 
   fn message(&self, opts: &DiagnosticOptions) -> String;
 
+  /// The message without the location of the diagnostic. Repeated diagnostics sharing the same code and
+  /// primary message are reported as one.
+  fn primary_message(&self, opts: &DiagnosticOptions) -> String {
+    self.message(opts)
+  }
+
   fn on_diagnostic(&self, _diagnostic: &mut Diagnostic, _opts: &DiagnosticOptions) {}
 }
 
@@ -0,0 +1,27 @@
+use crate::{event_kind::EventKind, types::diagnostic_options::DiagnosticOptions};
+
+use super::BuildEvent;
+
+/// Diagnostics past the `maxDiagnostics` limit, counted per code rather than reported one by one.
+#[derive(Debug)]
+pub struct OmittedDiagnostics {
+  /// Codes in the order they were first omitted, along with how many diagnostics of each were omitted.
+  pub counts: Vec<(String, usize)>,
+}
+
+impl BuildEvent for OmittedDiagnostics {
+  fn kind(&self) -> EventKind {
+    EventKind::OmittedDiagnostics
+  }
+
+  fn message(&self, _opts: &DiagnosticOptions) -> String {
+    let total = self.counts.iter().map(|(_, count)| count).sum::<usize>();
+    let suffix = if total == 1 { "diagnostic was" } else { "diagnostics were" };
+    let counts =
+      self.counts.iter().map(|(code, count)| format!("{count} {code}")).collect::<Vec<_>>();
+    format!(
+      "{total} more {suffix} omitted: {}. Use `diagnosticsFormat: 'sarif'` to get all of them.",
+      counts.join(", ")
+    )
+  }
+}
@@ -0,0 +1,53 @@
+use crate::{
+  diagnostic::Diagnostic, event_kind::EventKind, types::diagnostic_options::DiagnosticOptions,
+  BuildDiagnostic,
+};
+
+use super::BuildEvent;
+
+/// Only the first few occurrences are rendered as code frames, so a missing package imported from hundreds
+/// of files doesn't flood the output.
+const MAX_REPRESENTATIVE_OCCURRENCES: usize = 3;
+
+/// Occurrences of diagnostics sharing the same code, primary message and help, reported as one diagnostic.
+#[derive(Debug)]
+pub struct RepeatedDiagnostic {
+  pub occurrences: Vec<BuildDiagnostic>,
+}
+
+impl BuildEvent for RepeatedDiagnostic {
+  fn kind(&self) -> EventKind {
+    self.occurrences[0].kind()
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    self.occurrences[0].primary_message(opts)
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    // Occurrences in the same place, e.g. a module importing a missing export several times, keep the title
+    // a single occurrence has. Others only share the primary message.
+    let first_message = self.occurrences[0].message(opts);
+    let same_message =
+      self.occurrences[1..].iter().all(|occurrence| occurrence.message(opts) == first_message);
+    let mut title = None;
+    for occurrence in self.occurrences.iter().take(MAX_REPRESENTATIVE_OCCURRENCES) {
+      let occurrence = occurrence.to_diagnostic_with(opts);
+      title.get_or_insert_with(|| occurrence.title.clone());
+      diagnostic.merge_locations(occurrence);
+    }
+    let rest = self.occurrences.len().saturating_sub(MAX_REPRESENTATIVE_OCCURRENCES);
+    if rest > 0 {
+      let suffix = if rest == 1 { "occurrence" } else { "occurrences" };
+      let help = match &diagnostic.help {
+        Some(help) => format!("{help}\nand {rest} more {suffix}"),
+        None => format!("and {rest} more {suffix}"),
+      };
+      diagnostic.add_help(help);
+    }
+    diagnostic.title = match title {
+      Some(title) if same_message => title,
+      _ => self.message(opts),
+    };
+  }
+}
@@ -0,0 +1,46 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use crate::{diagnostic::Diagnostic, types::diagnostic_options::DiagnosticOptions};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct RequireEsm {
+  pub importer: String,
+  pub importer_source: ArcStr,
+  pub importee: String,
+  pub span: Span,
+}
+
+impl BuildEvent for RequireEsm {
+  fn kind(&self) -> crate::event_kind::EventKind {
+    crate::event_kind::EventKind::RequireEsm
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      "{} Required by \"{}\".",
+      self.primary_message(opts),
+      opts.stabilize_path(&self.importer)
+    )
+  }
+
+  fn primary_message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      "The ES module \"{}\" is loaded by `require`, which returns its namespace object rather than its default export.",
+      opts.stabilize_path(&self.importee)
+    )
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    diagnostic.title = self.primary_message(opts);
+    let file_id =
+      diagnostic.add_file(opts.stabilize_path(&self.importer), self.importer_source.clone());
+    diagnostic.add_label(
+      &file_id,
+      self.span.start..self.span.end,
+      "`require` of an ES module".to_string(),
+    );
+  }
+}
@@ -1,5 +1,7 @@
 use crate::types::diagnostic_options::DiagnosticOptions;
 use arcstr::ArcStr;
+use oxc::span::Span;
+use rolldown_resolver::ResolveAttempt;
 
 use super::{BuildEvent, DiagnosableArcstr};
 
@@ -8,21 +10,58 @@ pub struct DiagnosableResolveError {
   pub source: ArcStr,
   pub importer_id: ArcStr,
   pub importee: DiagnosableArcstr,
+  /// Span of the `import`/`export` declaration or the `require`/`import()` call containing the importee.
+  pub import_span: Option<Span>,
+  pub attempt: ResolveAttempt,
   pub reason: String,
   pub title: Option<&'static str>,
 }
 
+impl DiagnosableResolveError {
+  fn importee_str(&self) -> &str {
+    match &self.importee {
+      DiagnosableArcstr::String(str) => str.as_str(),
+      DiagnosableArcstr::Span(span) => &self.source.as_str()[*span],
+    }
+  }
+
+  /// Explains what the resolver tried, e.g. why the `exports` field of a package rejected a subpath.
+  fn attempt_help(&self, opts: &DiagnosticOptions) -> String {
+    let quote = |items: &[String]| {
+      items.iter().map(|item| format!(r#""{item}""#)).collect::<Vec<_>>().join(", ")
+    };
+    let extensions = quote(&self.attempt.extensions);
+    let Some(node_modules_dirs) = &self.attempt.node_modules_dirs else {
+      return format!("Tried the extensions {extensions}.");
+    };
+    let tried = format!(
+      "Tried the conditions {} and the extensions {extensions}",
+      quote(&self.attempt.conditions)
+    );
+    if node_modules_dirs.is_empty() {
+      format!("{tried}, but no node_modules directory was found.")
+    } else {
+      let dirs = node_modules_dirs.iter().map(|dir| opts.stabilize_path(dir)).collect::<Vec<_>>();
+      format!("{tried} in {}.", quote(&dirs))
+    }
+  }
+}
+
 impl BuildEvent for DiagnosableResolveError {
   fn kind(&self) -> crate::event_kind::EventKind {
     crate::event_kind::EventKind::ResolveError(self.title)
   }
 
   fn message(&self, opts: &DiagnosticOptions) -> String {
-    let importee = match &self.importee {
-      DiagnosableArcstr::String(str) => str.as_str(),
-      DiagnosableArcstr::Span(span) => &self.source.as_str()[*span],
-    };
-    format!("Could not resolve {} in {}", importee, opts.stabilize_path(self.importer_id.as_str()))
+    format!(
+      "Could not resolve {} in {}",
+      self.importee_str(),
+      opts.stabilize_path(self.importer_id.as_str())
+    )
+  }
+
+  fn primary_message(&self, _opts: &DiagnosticOptions) -> String {
+    format!("Could not resolve {}", self.importee_str())
   }
 
   fn on_diagnostic(
@@ -33,12 +72,14 @@ impl BuildEvent for DiagnosableResolveError {
     let stable_id = opts.stabilize_path(self.importer_id.as_str());
     let importer_file = diagnostic.add_file(stable_id, self.source.clone());
 
-    match self.importee {
-      DiagnosableArcstr::Span(span) if !span.is_unspanned() => {
-        diagnostic.add_label(&importer_file, span.start..span.end, self.reason.clone());
-      }
-      _ => {}
+    let label_span = match self.importee {
+      DiagnosableArcstr::Span(span) => self.import_span.or(Some(span)),
+      DiagnosableArcstr::String(_) => self.import_span,
     };
+    if let Some(span) = label_span.filter(|span| !span.is_unspanned()) {
+      diagnostic.add_label(&importer_file, span.start..span.end, self.reason.clone());
+    }
     diagnostic.title = self.message(opts);
+    diagnostic.add_help(self.attempt_help(opts));
   }
 }
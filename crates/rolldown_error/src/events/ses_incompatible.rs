@@ -0,0 +1,43 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use crate::{diagnostic::Diagnostic, types::diagnostic_options::DiagnosticOptions};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct SesIncompatible {
+  pub filename: String,
+  pub source: ArcStr,
+  pub span: Span,
+  pub reason: String,
+}
+
+impl BuildEvent for SesIncompatible {
+  fn kind(&self) -> crate::event_kind::EventKind {
+    crate::event_kind::EventKind::SesIncompatible
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      "'{}' contains a pattern that is incompatible with hardened JavaScript: {}.",
+      opts.stabilize_path(&self.filename),
+      self.reason
+    )
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    let filename = opts.stabilize_path(&self.filename);
+
+    diagnostic.title =
+      format!("Pattern is incompatible with hardened JavaScript: {}.", self.reason);
+
+    let file_id = diagnostic.add_file(filename, self.source.clone());
+
+    diagnostic.add_label(
+      &file_id,
+      self.span.start..self.span.end,
+      "This will throw or behave differently once intrinsics are frozen.".to_string(),
+    );
+  }
+}
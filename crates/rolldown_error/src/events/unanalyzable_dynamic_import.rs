@@ -0,0 +1,38 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use crate::{diagnostic::Diagnostic, types::diagnostic_options::DiagnosticOptions};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct UnanalyzableDynamicImport {
+  pub filename: String,
+  pub source: ArcStr,
+  pub span: Span,
+  pub reason: String,
+}
+
+impl BuildEvent for UnanalyzableDynamicImport {
+  fn kind(&self) -> crate::event_kind::EventKind {
+    crate::event_kind::EventKind::UnanalyzableDynamicImport
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!("{} in {}", self.reason, opts.stabilize_path(&self.filename))
+  }
+
+  fn primary_message(&self, _opts: &DiagnosticOptions) -> String {
+    self.reason.clone()
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    diagnostic.title = self.primary_message(opts);
+    let file_id = diagnostic.add_file(opts.stabilize_path(&self.filename), self.source.clone());
+    diagnostic.add_label(
+      &file_id,
+      self.span.start..self.span.end,
+      "Add `/* @vite-ignore */` to keep it as is.".to_string(),
+    );
+  }
+}
@@ -0,0 +1,51 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use crate::{
+  diagnostic::Diagnostic, event_kind::EventKind, types::diagnostic_options::DiagnosticOptions,
+};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct UnsupportedDecorators {
+  pub filename: String,
+  pub source: ArcStr,
+  /// The span of the first decorator of the module.
+  pub span: Span,
+}
+
+impl BuildEvent for UnsupportedDecorators {
+  fn kind(&self) -> EventKind {
+    EventKind::UnsupportedDecorators
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      "'{}' uses standard decorators, which are kept as they are. Only legacy decorators of TypeScript modules are transformed, with `decorator.legacy` enabled.",
+      opts.stabilize_path(&self.filename),
+    )
+  }
+
+  fn primary_message(&self, _opts: &DiagnosticOptions) -> String {
+    "Transforming standard decorators is not supported yet.".to_string()
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    let filename = opts.stabilize_path(&self.filename);
+
+    diagnostic.title = self.primary_message(opts);
+
+    let file_id = diagnostic.add_file(filename, self.source.clone());
+
+    diagnostic.add_label(
+      &file_id,
+      self.span.start..self.span.end,
+      "This decorator is kept as it is.".to_string(),
+    );
+
+    diagnostic.help = Some(
+      "Enable `decorator.legacy` if the decorators are written for `experimentalDecorators` of TypeScript.".to_string(),
+    );
+  }
+}
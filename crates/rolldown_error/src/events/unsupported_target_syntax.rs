@@ -0,0 +1,54 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use crate::{
+  diagnostic::Diagnostic, event_kind::EventKind, types::diagnostic_options::DiagnosticOptions,
+};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct UnsupportedTargetSyntax {
+  pub filename: String,
+  pub source: ArcStr,
+  pub span: Span,
+  /// The syntax, such as "optional chaining".
+  pub feature: String,
+  pub target: String,
+}
+
+impl BuildEvent for UnsupportedTargetSyntax {
+  fn kind(&self) -> EventKind {
+    EventKind::UnsupportedTargetSyntax
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      "'{}' contains {}, which can't be lowered to the configured target {} yet.",
+      opts.stabilize_path(&self.filename),
+      self.feature,
+      self.target
+    )
+  }
+
+  fn primary_message(&self, _opts: &DiagnosticOptions) -> String {
+    format!(
+      "Lowering {} to the configured target {} is not supported yet.",
+      self.feature, self.target
+    )
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    let filename = opts.stabilize_path(&self.filename);
+
+    diagnostic.title = self.primary_message(opts);
+
+    let file_id = diagnostic.add_file(filename, self.source.clone());
+
+    diagnostic.add_label(
+      &file_id,
+      self.span.start..self.span.end,
+      format!("This syntax is newer than {}.", self.target),
+    );
+  }
+}
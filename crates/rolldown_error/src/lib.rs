@@ -2,21 +2,25 @@ mod build_error;
 mod diagnostic;
 mod event_kind;
 mod events;
+mod sarif;
 mod type_aliases;
 mod types;
 
 pub use types::result_ext::ResultExt;
 
 pub use crate::{
-  build_error::{severity::Severity, BuildDiagnostic},
+  build_error::{severity::Severity, BatchedBuildDiagnostic, BuildDiagnostic},
+  diagnostic::{Diagnostic, SerializedDiagnostic, SerializedDiagnosticLabel},
   event_kind::EventKind,
   events::ambiguous_external_namespace::AmbiguousExternalNamespaceModule,
   events::commonjs_variable_in_esm::CjsExportSpan,
   events::invalid_option::InvalidOptionTypes,
+  events::missing_export::MissingExportSuggestion,
   events::unloadable_dependency::UnloadableDependencyContext,
   events::DiagnosableArcstr,
   type_aliases::{BuildResult, SingleBuildResult},
   types::diagnostic_options::DiagnosticOptions,
+  types::diagnostics_format::DiagnosticsFormat,
 };
 
 fn _usage_should_able_to_auto_convert_outside_errors() -> BuildResult<()> {
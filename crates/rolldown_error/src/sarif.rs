@@ -0,0 +1,178 @@
+use serde_json::{json, Value};
+
+use crate::{
+  build_error::BatchedBuildDiagnostic, diagnostic::SerializedDiagnosticLabel, DiagnosticOptions,
+  Severity,
+};
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+impl BatchedBuildDiagnostic {
+  /// Serializes the diagnostics into a SARIF 2.1 log. Each code becomes a rule and labels become the
+  /// locations of results.
+  pub fn to_sarif(&self, opts: &DiagnosticOptions) -> String {
+    let mut rules: Vec<String> = vec![];
+    let results = self
+      .iter()
+      .map(|diagnostic| {
+        let serialized = diagnostic.to_diagnostic_with(opts).to_serialized();
+        let rule_index =
+          rules.iter().position(|code| code == &serialized.code).unwrap_or_else(|| {
+            rules.push(serialized.code.clone());
+            rules.len() - 1
+          });
+        let mut labels = serialized.labels.iter();
+        let mut result = json!({
+          "ruleId": serialized.code,
+          "ruleIndex": rule_index,
+          "level": sarif_level(serialized.severity),
+          "message": { "text": serialized.message },
+          "locations": labels.next().map(|label| vec![sarif_location(label)]).unwrap_or_default(),
+        });
+        let related_locations = labels
+          .map(|label| {
+            let mut location = sarif_location(label);
+            location["message"] = json!({ "text": label.message });
+            location
+          })
+          .collect::<Vec<_>>();
+        if !related_locations.is_empty() {
+          result["relatedLocations"] = related_locations.into();
+        }
+        result
+      })
+      .collect::<Vec<_>>();
+
+    json!({
+      "$schema": SARIF_SCHEMA,
+      "version": "2.1.0",
+      "runs": [{
+        "tool": {
+          "driver": {
+            "name": "rolldown",
+            "informationUri": "https://rolldown.rs",
+            "rules": rules.iter().map(|code| json!({ "id": code })).collect::<Vec<_>>(),
+          },
+        },
+        "results": results,
+      }],
+    })
+    .to_string()
+  }
+}
+
+fn sarif_level(severity: Severity) -> &'static str {
+  match severity {
+    Severity::Error => "error",
+    Severity::Warn => "warning",
+    Severity::Info | Severity::Debug => "note",
+  }
+}
+
+fn sarif_location(label: &SerializedDiagnosticLabel) -> Value {
+  json!({
+    "physicalLocation": {
+      "artifactLocation": { "uri": label.file },
+      "region": {
+        "startLine": label.line,
+        // SARIF columns are 1-based.
+        "startColumn": label.column + 1,
+        "byteOffset": label.start,
+        "byteLength": label.end - label.start,
+      },
+    },
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use arcstr::ArcStr;
+  use oxc::span::Span;
+  use serde_json::{json, Value};
+
+  use crate::{BatchedBuildDiagnostic, BuildDiagnostic, DiagnosticOptions, Severity};
+
+  fn span_of(source: &str, text: &str) -> Span {
+    let start = u32::try_from(source.find(text).unwrap()).unwrap();
+    Span::new(start, start + u32::try_from(text.len()).unwrap())
+  }
+
+  #[test]
+  fn test_to_sarif() {
+    let main = ArcStr::from("const a = 1;\na = eval('2');");
+    let other = ArcStr::from("eval('3');");
+    let diagnostics = BatchedBuildDiagnostic::new(vec![
+      BuildDiagnostic::eval("main.js".to_string(), main.clone(), span_of(&main, "eval"))
+        .with_severity_warning(),
+      BuildDiagnostic::forbid_const_assign(
+        "main.js".to_string(),
+        main.clone(),
+        "a".to_string(),
+        span_of(&main, "a = 1"),
+        span_of(&main, "a = eval"),
+      ),
+      BuildDiagnostic::eval("other.js".to_string(), other.clone(), span_of(&other, "eval"))
+        .with_severity(Severity::Info),
+      BuildDiagnostic::circular_dependency(vec!["a.js".to_string(), "b.js".to_string()])
+        .with_severity(Severity::Debug),
+    ]);
+    let sarif: Value =
+      serde_json::from_str(&diagnostics.to_sarif(&DiagnosticOptions::default())).unwrap();
+    assert_eq!(sarif["version"], "2.1.0");
+    let run = &sarif["runs"][0];
+
+    // Repeated codes share one rule.
+    assert_eq!(
+      run["tool"]["driver"]["rules"],
+      json!([{ "id": "EVAL" }, { "id": "ILLEGAL_REASSIGNMENT" }, { "id": "CIRCULAR_DEPENDENCY" }])
+    );
+    let results = run["results"].as_array().unwrap();
+    let summary = results
+      .iter()
+      .map(|result| {
+        (
+          result["ruleId"].as_str().unwrap(),
+          result["ruleIndex"].as_u64().unwrap(),
+          result["level"].as_str().unwrap(),
+        )
+      })
+      .collect::<Vec<_>>();
+    assert_eq!(
+      summary,
+      vec![
+        ("EVAL", 0, "warning"),
+        ("ILLEGAL_REASSIGNMENT", 1, "error"),
+        ("EVAL", 0, "note"),
+        ("CIRCULAR_DEPENDENCY", 2, "note"),
+      ]
+    );
+
+    assert_eq!(
+      results[0]["locations"],
+      json!([{
+        "physicalLocation": {
+          "artifactLocation": { "uri": "main.js" },
+          "region": { "startLine": 2, "startColumn": 5, "byteOffset": 17, "byteLength": 4 },
+        },
+      }])
+    );
+    // Labels after the first one become related locations.
+    assert_eq!(
+      results[1]["locations"][0]["physicalLocation"]["region"],
+      json!({ "startLine": 2, "startColumn": 1, "byteOffset": 13, "byteLength": 8 })
+    );
+    assert_eq!(
+      results[1]["relatedLocations"],
+      json!([{
+        "physicalLocation": {
+          "artifactLocation": { "uri": "main.js" },
+          "region": { "startLine": 1, "startColumn": 7, "byteOffset": 6, "byteLength": 5 },
+        },
+        "message": { "text": "`a` is declared here as const" },
+      }])
+    );
+    // Diagnostics without labels have no locations.
+    assert_eq!(results[3]["locations"], json!([]));
+    assert!(results[3].get("relatedLocations").is_none());
+  }
+}
@@ -0,0 +1,22 @@
+/// How diagnostics of a build are reported.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticsFormat {
+  /// Print each diagnostic as a code frame.
+  #[default]
+  Text,
+  /// Print all diagnostics of a build as one SARIF 2.1 log, for tools such as CI that annotate pull
+  /// requests.
+  Sarif,
+}
+
+impl TryFrom<&str> for DiagnosticsFormat {
+  type Error = String;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
+    match value {
+      "text" => Ok(Self::Text),
+      "sarif" => Ok(Self::Sarif),
+      _ => Err(format!("Unknown diagnostics format: {value:?}")),
+    }
+  }
+}
@@ -1,2 +1,3 @@
 pub mod diagnostic_options;
+pub mod diagnostics_format;
 pub mod result_ext;
@@ -1,4 +1,7 @@
-use std::{io, path::Path};
+use std::{
+  io,
+  path::{Path, PathBuf},
+};
 
 use oxc_resolver::FileSystem as OxcResolverFileSystem;
 
@@ -23,8 +26,20 @@ pub trait FileSystem: Send + Sync + OxcResolverFileSystem {
   /// * See [std::path::Path::exists]
   fn exists(&self, path: &Path) -> bool;
 
+  /// # Errors
+  ///
+  /// * See [std::path::Path::is_dir]
+  fn is_dir(&self, path: &Path) -> bool;
+
   /// # Errors
   ///
   /// * See [std::fs::read]
   fn read(&self, path: &Path) -> io::Result<Vec<u8>>;
+
+  /// Returns the paths of the entries in the directory.
+  ///
+  /// # Errors
+  ///
+  /// * See [std::fs::read_dir]
+  fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>>;
 }
@@ -76,6 +76,13 @@ impl FileSystem for MemoryFileSystem {
     self.fs.exists(path.to_string_lossy().as_ref()).is_ok()
   }
 
+  fn is_dir(&self, path: &Path) -> bool {
+    self
+      .fs
+      .metadata(path.to_string_lossy().as_ref())
+      .is_ok_and(|metadata| metadata.file_type == vfs::VfsFileType::Directory)
+  }
+
   fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
     let mut buf = Vec::new();
     self
@@ -85,6 +92,17 @@ impl FileSystem for MemoryFileSystem {
       .read_to_end(&mut buf)?;
     Ok(buf)
   }
+
+  fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+    Ok(
+      self
+        .fs
+        .read_dir(&path.to_string_lossy())
+        .map_err(|err| io::Error::new(io::ErrorKind::NotFound, err))?
+        .map(|name| path.join(name))
+        .collect(),
+    )
+  }
 }
 
 impl OxcResolverFileSystem for MemoryFileSystem {
@@ -166,6 +184,11 @@ mod tests {
       fs.read(Path::new("/module_2/utils/index.js")).map_err(|err| err.to_string())?
     );
 
+    assert_eq!(
+      vec![Path::new("/module_2/utils/index.js").to_path_buf()],
+      fs.read_dir(Path::new("/module_2/utils")).map_err(|err| err.to_string())?
+    );
+
     Ok(())
   }
 }
@@ -28,9 +28,17 @@ impl FileSystem for OsFileSystem {
     path.exists()
   }
 
+  fn is_dir(&self, path: &Path) -> bool {
+    path.is_dir()
+  }
+
   fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
     std::fs::read(path)
   }
+
+  fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+    std::fs::read_dir(path)?.map(|entry| entry.map(|entry| entry.path())).collect()
+  }
 }
 
 impl OxcResolverFileSystem for OsFileSystem {
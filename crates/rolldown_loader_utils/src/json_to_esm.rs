@@ -3,7 +3,9 @@ use rolldown_utils::ecma_script::is_validate_assignee_identifier_name;
 use serde_json::Value;
 // TODO: handling https://github.com/tc39/proposal-json-superset
 
-pub fn json_to_esm(json: &str) -> anyhow::Result<String> {
+/// With `stringify`, objects and arrays are rendered as `JSON.parse("...")`, which engines parse faster
+/// than object literals.
+pub fn json_to_esm(json: &str, stringify: bool) -> anyhow::Result<String> {
   // TODO: use zero-copy deserialization
   let json_value: Value = serde_json::from_str(json.trim_start_matches("\u{FEFF}"))?;
 
@@ -13,16 +15,11 @@ pub fn json_to_esm(json: &str) -> anyhow::Result<String> {
       let mut exported_items_for_default_export = Vec::with_capacity(map.len());
       for (idx, (key, value)) in map.iter().enumerate() {
         if is_validate_assignee_identifier_name(key) {
-          source
-            .push_str(&format!("export const {key} = {};\n", serde_json::to_string_pretty(value)?));
+          source.push_str(&format!("export const {key} = {};\n", value_to_expr(value, stringify)?));
           exported_items_for_default_export.push(key.to_string());
         } else {
           let valid_id = format!("key_{}", itoa::Buffer::new().format(idx));
-          source.push_str(&format!(
-            "const {} = {};\n",
-            valid_id,
-            serde_json::to_string_pretty(value)?
-          ));
+          source.push_str(&format!("const {} = {};\n", valid_id, value_to_expr(value, stringify)?));
           source.push_str(&format!("export {{ {valid_id} as '{key}' }};\n"));
           exported_items_for_default_export.push(format!("'{key}': {valid_id}"));
         };
@@ -33,9 +30,38 @@ pub fn json_to_esm(json: &str) -> anyhow::Result<String> {
       ));
       Ok(source)
     }
+    Value::Array(_) if stringify => {
+      Ok(format!("export default {}", value_to_expr(&json_value, true)?))
+    }
     _ => {
       let json_str = serde_json::to_string(&json_value)?;
       Ok(format!("export default {json_str}"))
     }
   }
 }
+
+fn value_to_expr(value: &Value, stringify: bool) -> anyhow::Result<String> {
+  if stringify && (value.is_object() || value.is_array()) {
+    let json_str = serde_json::to_string(&serde_json::to_string(value)?)?;
+    Ok(format!("/*#__PURE__*/ JSON.parse({json_str})"))
+  } else {
+    Ok(serde_json::to_string_pretty(value)?)
+  }
+}
+
+#[test]
+fn test_json_to_esm_stringify() {
+  let json = r#"{ "dependencies": { "a": "^1.0.0" }, "files": ["dist"], "version": "1.0.0" }"#;
+  assert_eq!(
+    json_to_esm(json, true).unwrap(),
+    r#"export const dependencies = /*#__PURE__*/ JSON.parse("{\"a\":\"^1.0.0\"}");
+export const files = /*#__PURE__*/ JSON.parse("[\"dist\"]");
+export const version = "1.0.0";
+export default { dependencies, files, version };"#
+  );
+  assert_eq!(
+    json_to_esm("[1, 2]", true).unwrap(),
+    r#"export default /*#__PURE__*/ JSON.parse("[1,2]")"#
+  );
+  assert_eq!(json_to_esm("[1, 2]", false).unwrap(), "export default [1,2]");
+}
@@ -1,7 +1,9 @@
 mod binary_to_esm;
 mod json_to_esm;
 mod text_to_esm;
+mod wasm_to_esm;
 
 pub use binary_to_esm::binary_to_esm;
 pub use json_to_esm::json_to_esm;
 pub use text_to_esm::text_to_string_literal;
+pub use wasm_to_esm::{wasm_to_esm, WasmSource};
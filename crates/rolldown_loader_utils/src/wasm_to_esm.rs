@@ -0,0 +1,42 @@
+use rolldown_common::Platform;
+
+/// Where the glue module of a WebAssembly module gets the binary from.
+pub enum WasmSource<'a> {
+  /// The expression of the url of the emitted binary, which is fetched.
+  Url(&'a str),
+  /// The base64 of the inlined binary.
+  Base64(&'a str),
+}
+
+/// The glue module exports a function by default, which takes the imports object and resolves to the
+/// `WebAssembly.Instance`.
+pub fn wasm_to_esm(source: &WasmSource, platform: Platform, runtime_module_id: &str) -> String {
+  match source {
+    WasmSource::Url(url) => [
+      "import { __instantiateWasm } from '",
+      runtime_module_id,
+      "'; export default (imports) => __instantiateWasm(",
+      url,
+      ", imports)",
+    ]
+    .concat(),
+    WasmSource::Base64(base64) => {
+      let to_binary = match platform {
+        Platform::Node => "__toBinaryNode",
+        _ => "__toBinary",
+      };
+      [
+        "import { __instantiateWasm, ",
+        to_binary,
+        " } from '",
+        runtime_module_id,
+        "'; export default (imports) => __instantiateWasm(",
+        to_binary,
+        "('",
+        base64,
+        "'), imports)",
+      ]
+      .concat()
+    }
+  }
+}
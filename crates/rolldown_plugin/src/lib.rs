@@ -20,8 +20,8 @@ pub mod __inner {
 pub use crate::{
   plugin::{
     HookAugmentChunkHashReturn, HookInjectionOutputReturn, HookLoadReturn, HookNoopReturn,
-    HookRenderChunkReturn, HookResolveIdReturn, HookTransformAstReturn, HookTransformReturn,
-    Plugin,
+    HookOnLogReturn, HookRenderChunkReturn, HookResolveIdReturn, HookTransformAstReturn,
+    HookTransformReturn, Plugin,
   },
   plugin_context::PluginContext,
   plugin_driver::{PluginDriver, SharedPluginDriver},
@@ -34,13 +34,16 @@ pub use crate::{
   },
   types::hook_load_args::HookLoadArgs,
   types::hook_load_output::HookLoadOutput,
+  types::hook_on_log_args::HookOnLogArgs,
   types::hook_render_chunk_args::HookRenderChunkArgs,
   types::hook_render_chunk_output::HookRenderChunkOutput,
+  types::hook_render_css_chunk_args::HookRenderCssChunkArgs,
   types::hook_render_error::HookRenderErrorArgs,
   types::hook_resolve_id_args::HookResolveIdArgs,
   types::hook_resolve_id_output::HookResolveIdOutput,
   types::hook_transform_args::HookTransformArgs,
   types::hook_transform_ast_args::HookTransformAstArgs,
+  types::hook_transform_css_args::HookTransformCssArgs,
   types::hook_transform_output::HookTransformOutput,
   types::plugin_context_resolve_options::PluginContextResolveOptions,
 };
@@ -9,12 +9,12 @@ use crate::{
     hook_transform_ast_args::HookTransformAstArgs,
     hook_transform_output::HookTransformOutput,
   },
-  HookAddonArgs, HookBuildEndArgs, HookLoadArgs, HookLoadOutput, HookRenderChunkArgs,
-  HookRenderChunkOutput, HookResolveIdArgs, HookResolveIdOutput, HookTransformArgs,
-  SharedTransformPluginContext,
+  HookAddonArgs, HookBuildEndArgs, HookLoadArgs, HookLoadOutput, HookOnLogArgs,
+  HookRenderChunkArgs, HookRenderChunkOutput, HookRenderCssChunkArgs, HookResolveIdArgs,
+  HookResolveIdOutput, HookTransformArgs, HookTransformCssArgs, SharedTransformPluginContext,
 };
 use anyhow::Result;
-use rolldown_common::{ModuleInfo, Output, RollupRenderedChunk, WatcherChangeKind};
+use rolldown_common::{ModuleInfo, OnLogAction, Output, RollupRenderedChunk, WatcherChangeKind};
 use rolldown_ecmascript::EcmaAst;
 
 pub type HookResolveIdReturn = Result<Option<HookResolveIdOutput>>;
@@ -25,6 +25,7 @@ pub type HookNoopReturn = Result<()>;
 pub type HookRenderChunkReturn = Result<Option<HookRenderChunkOutput>>;
 pub type HookAugmentChunkHashReturn = Result<Option<String>>;
 pub type HookInjectionOutputReturn = Result<Option<String>>;
+pub type HookOnLogReturn = Result<OnLogAction>;
 
 pub trait Plugin: Any + Debug + Send + Sync + 'static {
   fn name(&self) -> Cow<'static, str>;
@@ -95,6 +96,20 @@ pub trait Plugin: Any + Debug + Send + Sync + 'static {
     None
   }
 
+  /// Called with the source of each stylesheet once it's css, after `transform`. The returned code replaces
+  /// the source, so this is the place for PostCSS-like processing.
+  fn transform_css(
+    &self,
+    _ctx: &PluginContext,
+    _args: &HookTransformCssArgs<'_>,
+  ) -> impl std::future::Future<Output = HookRenderChunkReturn> + Send {
+    async { Ok(None) }
+  }
+
+  fn transform_css_meta(&self) -> Option<PluginHookMeta> {
+    None
+  }
+
   fn module_parsed(
     &self,
     _ctx: &PluginContext,
@@ -119,6 +134,20 @@ pub trait Plugin: Any + Debug + Send + Sync + 'static {
     None
   }
 
+  /// Called with each diagnostic that passes `log_level` before it's reported. Returning anything other
+  /// than `OnLogAction::Keep` takes effect immediately, and later plugins won't see the diagnostic.
+  fn on_log(
+    &self,
+    _ctx: &PluginContext,
+    _args: &HookOnLogArgs<'_>,
+  ) -> impl std::future::Future<Output = HookOnLogReturn> + Send {
+    async { Ok(OnLogAction::Keep) }
+  }
+
+  fn on_log_meta(&self) -> Option<PluginHookMeta> {
+    None
+  }
+
   // --- Generate hooks ---
 
   fn render_start(
@@ -192,6 +221,19 @@ pub trait Plugin: Any + Debug + Send + Sync + 'static {
     None
   }
 
+  /// Like `render_chunk`, but for the css file assembled for a chunk.
+  fn render_css_chunk(
+    &self,
+    _ctx: &PluginContext,
+    _args: &HookRenderCssChunkArgs<'_>,
+  ) -> impl std::future::Future<Output = HookRenderChunkReturn> + Send {
+    async { Ok(None) }
+  }
+
+  fn render_css_chunk_meta(&self) -> Option<PluginHookMeta> {
+    None
+  }
+
   fn augment_chunk_hash(
     &self,
     _ctx: &PluginContext,
@@ -105,6 +105,7 @@ impl PluginContextImpl {
       normalized_extra_options.custom,
       false,
       &self.options,
+      None,
     )
     .await
   }
@@ -113,6 +114,10 @@ impl PluginContextImpl {
     self.file_emitter.emit_file(file)
   }
 
+  pub fn emit_chunk(&self, chunk: rolldown_common::EmittedChunk) -> anyhow::Result<ArcStr> {
+    self.file_emitter.emit_chunk(chunk)
+  }
+
   pub fn try_get_file_name(&self, reference_id: &str) -> Result<ArcStr, String> {
     self.file_emitter.try_get_file_name(reference_id)
   }
@@ -144,6 +149,10 @@ impl PluginContextImpl {
     Some(ids)
   }
 
+  pub fn options(&self) -> &SharedNormalizedBundlerOptions {
+    &self.options
+  }
+
   pub fn cwd(&self) -> &PathBuf {
     self.resolver.cwd()
   }
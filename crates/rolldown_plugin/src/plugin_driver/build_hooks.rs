@@ -6,11 +6,14 @@ use crate::{
     hook_resolve_id_skipped::HookResolveIdSkipped, hook_transform_ast_args::HookTransformAstArgs,
     plugin_idx::PluginIdx,
   },
-  HookBuildEndArgs, HookLoadArgs, HookLoadReturn, HookNoopReturn, HookResolveIdArgs,
-  HookResolveIdReturn, HookTransformArgs, PluginContext, PluginDriver, TransformPluginContext,
+  HookBuildEndArgs, HookLoadArgs, HookLoadReturn, HookNoopReturn, HookOnLogArgs, HookOnLogReturn,
+  HookResolveIdArgs, HookResolveIdReturn, HookTransformArgs, HookTransformCssArgs, PluginContext,
+  PluginDriver, TransformPluginContext,
 };
 use anyhow::Result;
-use rolldown_common::{side_effects::HookSideEffects, ModuleInfo, ModuleType};
+use rolldown_common::{
+  side_effects::HookSideEffects, Log, LogLevel, ModuleInfo, ModuleType, OnLogAction,
+};
 use rolldown_sourcemap::SourceMap;
 use rolldown_utils::{futures::block_on_spawn_all, unique_arc::UniqueArc};
 
@@ -218,6 +221,31 @@ impl PluginDriver {
     Ok(code)
   }
 
+  pub async fn transform_css(
+    &self,
+    args: &HookTransformCssArgs<'_>,
+    sourcemap_chain: &mut Vec<SourceMap>,
+  ) -> Result<String> {
+    let mut code = args.code.to_string();
+    for (_, plugin, ctx) in
+      self.iter_plugin_with_context_by_order(&self.order_by_transform_css_meta)
+    {
+      if let Some(r) = plugin
+        .call_transform_css(
+          ctx,
+          &HookTransformCssArgs { id: args.id, code: &code, module_type: args.module_type },
+        )
+        .await?
+      {
+        code = r.code;
+        if let Some(map) = r.map {
+          sourcemap_chain.push(map);
+        }
+      }
+    }
+    Ok(code)
+  }
+
   pub fn transform_ast(&self, mut args: HookTransformAstArgs) -> HookTransformAstReturn {
     for (_, plugin, ctx) in
       self.iter_plugin_with_context_by_order(&self.order_by_transform_ast_meta)
@@ -245,4 +273,16 @@ impl PluginDriver {
     }
     Ok(())
   }
+
+  /// Passes the diagnostic through the `on_log` hook of plugins, and returns the first action that isn't
+  /// `OnLogAction::Keep`.
+  pub async fn on_log(&self, level: LogLevel, log: &Log) -> HookOnLogReturn {
+    for (_, plugin, ctx) in self.iter_plugin_with_context_by_order(&self.order_by_on_log_meta) {
+      let action = plugin.call_on_log(ctx, &HookOnLogArgs { level, log }).await?;
+      if action != OnLogAction::Keep {
+        return Ok(action);
+      }
+    }
+    Ok(OnLogAction::Keep)
+  }
 }
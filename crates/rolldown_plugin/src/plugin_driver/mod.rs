@@ -140,6 +140,10 @@ impl PluginDriver {
     }
   }
 
+  pub fn file_emitter(&self) -> &SharedFileEmitter {
+    &self.file_emitter
+  }
+
   pub fn iter_plugin_with_context_by_order<'me>(
     &'me self,
     ordered_plugins: &'me [PluginIdx],
@@ -167,14 +171,17 @@ pub struct HookOrderIndicates {
   pub order_by_resolve_dynamic_import_meta: Vec<PluginIdx>,
   pub order_by_load_meta: Vec<PluginIdx>,
   pub order_by_transform_meta: Vec<PluginIdx>,
+  pub order_by_transform_css_meta: Vec<PluginIdx>,
   pub order_by_module_parsed_meta: Vec<PluginIdx>,
   pub order_by_build_end_meta: Vec<PluginIdx>,
+  pub order_by_on_log_meta: Vec<PluginIdx>,
   pub order_by_render_start_meta: Vec<PluginIdx>,
   pub order_by_banner_meta: Vec<PluginIdx>,
   pub order_by_footer_meta: Vec<PluginIdx>,
   pub order_by_intro_meta: Vec<PluginIdx>,
   pub order_by_outro_meta: Vec<PluginIdx>,
   pub order_by_render_chunk_meta: Vec<PluginIdx>,
+  pub order_by_render_css_chunk_meta: Vec<PluginIdx>,
   pub order_by_augment_chunk_hash_meta: Vec<PluginIdx>,
   pub order_by_render_error_meta: Vec<PluginIdx>,
   pub order_by_generate_bundle_meta: Vec<PluginIdx>,
@@ -201,12 +208,18 @@ impl HookOrderIndicates {
       order_by_transform_meta: Self::sort_plugins_by_hook_meta(index_plugins, |p| {
         p.call_transform_meta()
       }),
+      order_by_transform_css_meta: Self::sort_plugins_by_hook_meta(index_plugins, |p| {
+        p.call_transform_css_meta()
+      }),
       order_by_module_parsed_meta: Self::sort_plugins_by_hook_meta(index_plugins, |p| {
         p.call_module_parsed_meta()
       }),
       order_by_build_end_meta: Self::sort_plugins_by_hook_meta(index_plugins, |p| {
         p.call_build_end_meta()
       }),
+      order_by_on_log_meta: Self::sort_plugins_by_hook_meta(index_plugins, |p| {
+        p.call_on_log_meta()
+      }),
       order_by_render_start_meta: Self::sort_plugins_by_hook_meta(index_plugins, |p| {
         p.call_render_start_meta()
       }),
@@ -221,6 +234,9 @@ impl HookOrderIndicates {
       order_by_render_chunk_meta: Self::sort_plugins_by_hook_meta(index_plugins, |p| {
         p.call_render_chunk_meta()
       }),
+      order_by_render_css_chunk_meta: Self::sort_plugins_by_hook_meta(index_plugins, |p| {
+        p.call_render_css_chunk_meta()
+      }),
       order_by_augment_chunk_hash_meta: Self::sort_plugins_by_hook_meta(index_plugins, |p| {
         p.call_augment_chunk_hash_meta()
       }),
@@ -1,6 +1,8 @@
 use crate::types::hook_render_error::HookRenderErrorArgs;
 use crate::{HookAddonArgs, PluginDriver};
-use crate::{HookAugmentChunkHashReturn, HookNoopReturn, HookRenderChunkArgs};
+use crate::{
+  HookAugmentChunkHashReturn, HookNoopReturn, HookRenderChunkArgs, HookRenderCssChunkArgs,
+};
 use anyhow::{Ok, Result};
 use rolldown_common::{Output, RollupRenderedChunk};
 use rolldown_sourcemap::SourceMap;
@@ -91,6 +93,24 @@ impl PluginDriver {
     Ok((args.code, sourcemap_chain))
   }
 
+  pub async fn render_css_chunk(
+    &self,
+    mut args: HookRenderCssChunkArgs<'_>,
+  ) -> Result<(String, Vec<SourceMap>)> {
+    let mut sourcemap_chain = vec![];
+    for (_, plugin, ctx) in
+      self.iter_plugin_with_context_by_order(&self.order_by_render_css_chunk_meta)
+    {
+      if let Some(r) = plugin.call_render_css_chunk(ctx, &args).await? {
+        args.code = r.code;
+        if let Some(map) = r.map {
+          sourcemap_chain.push(map);
+        }
+      }
+    }
+    Ok((args.code, sourcemap_chain))
+  }
+
   pub async fn augment_chunk_hash(
     &self,
     chunk: &RollupRenderedChunk,
@@ -119,7 +139,7 @@ impl PluginDriver {
       self.iter_plugin_with_context_by_order(&self.order_by_generate_bundle_meta)
     {
       plugin.call_generate_bundle(ctx, bundle, is_write).await?;
-      ctx.file_emitter.add_additional_files(bundle);
+      ctx.file_emitter.add_additional_files(bundle).await?;
     }
     Ok(())
   }
@@ -128,7 +148,7 @@ impl PluginDriver {
     for (_, plugin, ctx) in self.iter_plugin_with_context_by_order(&self.order_by_write_bundle_meta)
     {
       plugin.call_write_bundle(ctx, bundle).await?;
-      ctx.file_emitter.add_additional_files(bundle);
+      ctx.file_emitter.add_additional_files(bundle).await?;
     }
     Ok(())
   }
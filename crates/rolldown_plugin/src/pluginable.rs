@@ -8,8 +8,9 @@ use crate::{
     hook_render_error::HookRenderErrorArgs,
     hook_transform_ast_args::HookTransformAstArgs,
   },
-  HookAddonArgs, HookBuildEndArgs, HookInjectionOutputReturn, HookLoadArgs, HookRenderChunkArgs,
-  HookResolveIdArgs, HookTransformArgs, Plugin, SharedTransformPluginContext,
+  HookAddonArgs, HookBuildEndArgs, HookInjectionOutputReturn, HookLoadArgs, HookOnLogArgs,
+  HookRenderChunkArgs, HookRenderCssChunkArgs, HookResolveIdArgs, HookTransformArgs,
+  HookTransformCssArgs, Plugin, SharedTransformPluginContext,
 };
 use anyhow::Ok;
 use rolldown_common::{ModuleInfo, Output, RollupRenderedChunk, WatcherChangeKind};
@@ -17,6 +18,7 @@ use rolldown_common::{ModuleInfo, Output, RollupRenderedChunk, WatcherChangeKind
 pub use crate::plugin::HookAugmentChunkHashReturn;
 pub use crate::plugin::HookLoadReturn;
 pub use crate::plugin::HookNoopReturn;
+pub use crate::plugin::HookOnLogReturn;
 pub use crate::plugin::HookRenderChunkReturn;
 pub use crate::plugin::HookResolveIdReturn;
 pub use crate::plugin::HookTransformAstReturn;
@@ -73,6 +75,14 @@ pub trait Pluginable: Any + Debug + Send + Sync + 'static {
 
   fn call_transform_meta(&self) -> Option<PluginHookMeta>;
 
+  async fn call_transform_css(
+    &self,
+    _ctx: &PluginContext,
+    _args: &HookTransformCssArgs,
+  ) -> HookRenderChunkReturn;
+
+  fn call_transform_css_meta(&self) -> Option<PluginHookMeta>;
+
   fn call_transform_ast(
     &self,
     _ctx: &PluginContext,
@@ -97,6 +107,10 @@ pub trait Pluginable: Any + Debug + Send + Sync + 'static {
 
   fn call_build_end_meta(&self) -> Option<PluginHookMeta>;
 
+  async fn call_on_log(&self, _ctx: &PluginContext, _args: &HookOnLogArgs) -> HookOnLogReturn;
+
+  fn call_on_log_meta(&self) -> Option<PluginHookMeta>;
+
   // --- Generate hooks ---
 
   async fn call_render_start(&self, _ctx: &PluginContext) -> HookNoopReturn;
@@ -143,6 +157,14 @@ pub trait Pluginable: Any + Debug + Send + Sync + 'static {
 
   fn call_render_chunk_meta(&self) -> Option<PluginHookMeta>;
 
+  async fn call_render_css_chunk(
+    &self,
+    _ctx: &PluginContext,
+    _args: &HookRenderCssChunkArgs,
+  ) -> HookRenderChunkReturn;
+
+  fn call_render_css_chunk_meta(&self) -> Option<PluginHookMeta>;
+
   async fn call_augment_chunk_hash(
     &self,
     _ctx: &PluginContext,
@@ -273,6 +295,18 @@ impl<T: Plugin> Pluginable for T {
     Plugin::transform_meta(self)
   }
 
+  async fn call_transform_css(
+    &self,
+    ctx: &PluginContext,
+    args: &HookTransformCssArgs,
+  ) -> HookRenderChunkReturn {
+    Plugin::transform_css(self, ctx, args).await
+  }
+
+  fn call_transform_css_meta(&self) -> Option<PluginHookMeta> {
+    Plugin::transform_css_meta(self)
+  }
+
   async fn call_module_parsed(
     &self,
     ctx: &PluginContext,
@@ -297,6 +331,14 @@ impl<T: Plugin> Pluginable for T {
     Plugin::build_end_meta(self)
   }
 
+  async fn call_on_log(&self, ctx: &PluginContext, args: &HookOnLogArgs) -> HookOnLogReturn {
+    Plugin::on_log(self, ctx, args).await
+  }
+
+  fn call_on_log_meta(&self) -> Option<PluginHookMeta> {
+    Plugin::on_log_meta(self)
+  }
+
   async fn call_render_start(&self, ctx: &PluginContext) -> HookNoopReturn {
     Plugin::render_start(self, ctx).await
   }
@@ -365,6 +407,18 @@ impl<T: Plugin> Pluginable for T {
     Plugin::render_chunk_meta(self)
   }
 
+  async fn call_render_css_chunk(
+    &self,
+    ctx: &PluginContext,
+    args: &HookRenderCssChunkArgs,
+  ) -> HookRenderChunkReturn {
+    Plugin::render_css_chunk(self, ctx, args).await
+  }
+
+  fn call_render_css_chunk_meta(&self) -> Option<PluginHookMeta> {
+    Plugin::render_css_chunk_meta(self)
+  }
+
   async fn call_augment_chunk_hash(
     &self,
     ctx: &PluginContext,
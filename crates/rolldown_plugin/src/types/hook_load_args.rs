@@ -1,4 +1,8 @@
+use rolldown_common::ImportAttributes;
+
 #[derive(Debug)]
 pub struct HookLoadArgs<'a> {
   pub id: &'a str,
+  /// The attributes of the import that loads the module first, such as `{ type: 'json' }`.
+  pub attributes: Option<&'a ImportAttributes>,
 }
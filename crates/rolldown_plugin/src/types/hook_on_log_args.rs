@@ -0,0 +1,7 @@
+use rolldown_common::{Log, LogLevel};
+
+#[derive(Debug)]
+pub struct HookOnLogArgs<'a> {
+  pub level: LogLevel,
+  pub log: &'a Log,
+}
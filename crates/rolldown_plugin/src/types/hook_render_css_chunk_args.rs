@@ -0,0 +1,8 @@
+use rolldown_common::RollupRenderedChunk;
+
+#[derive(Debug)]
+pub struct HookRenderCssChunkArgs<'a> {
+  pub code: String,
+  /// The js chunk the stylesheet is emitted for.
+  pub chunk: &'a RollupRenderedChunk,
+}
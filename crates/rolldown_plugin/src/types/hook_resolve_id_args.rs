@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use rolldown_common::ImportKind;
+use rolldown_common::{ImportAttributes, ImportKind};
 use typedmap::TypedDashMap;
 
 #[derive(Debug)]
@@ -12,4 +12,6 @@ pub struct HookResolveIdArgs<'a> {
   // field to determine the import kind.
   pub kind: ImportKind,
   pub custom: Arc<TypedDashMap>,
+  /// `{ type: 'json' }` in `import data from './data.json' with { type: 'json' }`.
+  pub attributes: Option<&'a ImportAttributes>,
 }
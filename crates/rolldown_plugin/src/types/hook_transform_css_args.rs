@@ -0,0 +1,10 @@
+use rolldown_common::ModuleType;
+
+#[derive(Debug)]
+pub struct HookTransformCssArgs<'a> {
+  pub id: &'a str,
+  pub code: &'a String,
+  /// The module type the stylesheet was loaded or transformed as, such as `css` for a `.scss` file compiled
+  /// by a plugin.
+  pub module_type: &'a ModuleType,
+}
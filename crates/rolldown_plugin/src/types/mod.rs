@@ -3,14 +3,17 @@ pub mod hook_build_end_args;
 pub mod hook_filter;
 pub mod hook_load_args;
 pub mod hook_load_output;
+pub mod hook_on_log_args;
 pub mod hook_render_chunk_args;
 pub mod hook_render_chunk_output;
+pub mod hook_render_css_chunk_args;
 pub mod hook_render_error;
 pub mod hook_resolve_id_args;
 pub mod hook_resolve_id_output;
 pub mod hook_resolve_id_skipped;
 pub mod hook_transform_args;
 pub mod hook_transform_ast_args;
+pub mod hook_transform_css_args;
 pub mod hook_transform_output;
 pub mod plugin_context_resolve_options;
 pub mod plugin_idx;
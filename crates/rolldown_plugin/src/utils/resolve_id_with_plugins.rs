@@ -1,8 +1,11 @@
 use crate::{
   types::hook_resolve_id_skipped::HookResolveIdSkipped, HookResolveIdArgs, PluginDriver,
 };
-use rolldown_common::{ImportKind, ModuleDefFormat, ResolvedId, SharedNormalizedBundlerOptions};
+use rolldown_common::{
+  ImportAttributes, ImportKind, ModuleDefFormat, ResolvedId, SharedNormalizedBundlerOptions,
+};
 use rolldown_resolver::{ResolveError, Resolver};
+use rolldown_utils::virtual_module::is_virtual_module_id;
 use std::{path::Path, sync::Arc};
 use typedmap::TypedDashMap;
 
@@ -26,6 +29,7 @@ pub async fn resolve_id_check_external(
   custom: Arc<TypedDashMap>,
   is_user_defined_entry: bool,
   bundle_options: &SharedNormalizedBundlerOptions,
+  attributes: Option<&ImportAttributes>,
 ) -> anyhow::Result<Result<ResolvedId, ResolveError>> {
   // Check external with unresolved path
   if let Some(is_external) = bundle_options.external.as_ref() {
@@ -51,6 +55,7 @@ pub async fn resolve_id_check_external(
     skipped_resolve_calls,
     custom,
     is_user_defined_entry,
+    attributes,
   )
   .await?;
 
@@ -79,6 +84,7 @@ pub async fn resolve_id_with_plugins(
   skipped_resolve_calls: Option<Vec<Arc<HookResolveIdSkipped>>>,
   custom: Arc<TypedDashMap>,
   is_user_defined_entry: bool,
+  attributes: Option<&ImportAttributes>,
 ) -> anyhow::Result<Result<ResolvedId, ResolveError>> {
   if matches!(import_kind, ImportKind::DynamicImport) {
     if let Some(r) = plugin_driver
@@ -89,6 +95,7 @@ pub async fn resolve_id_with_plugins(
           is_entry,
           kind: import_kind,
           custom: Arc::clone(&custom),
+          attributes,
         },
         skipped_resolve_calls.as_ref(),
       )
@@ -113,6 +120,7 @@ pub async fn resolve_id_with_plugins(
         is_entry,
         kind: import_kind,
         custom: Arc::clone(&custom),
+        attributes,
       },
       skipped_resolve_calls.as_ref(),
     )
@@ -128,6 +136,18 @@ pub async fn resolve_id_with_plugins(
     }));
   }
 
+  // Virtual modules are only known by plugins, so the resolver shouldn't look for them in the file system.
+  if is_virtual_module_id(request) {
+    return Ok(Ok(ResolvedId {
+      id: request.to_string().into(),
+      module_def_format: ModuleDefFormat::from_path(request),
+      ignored: false,
+      is_external: false,
+      package_json: None,
+      side_effects: None,
+    }));
+  }
+
   // Auto external http url or data url
   if is_http_url(request) || is_data_url(request) {
     return Ok(Ok(ResolvedId {
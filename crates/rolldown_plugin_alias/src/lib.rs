@@ -1,20 +1,42 @@
 use std::borrow::Cow;
+use std::fmt::Debug;
+use std::future::Future;
+use std::pin::Pin;
 use std::sync::Arc;
 
 use rolldown_plugin::{HookResolveIdOutput, Plugin, PluginContext, PluginContextResolveOptions};
 use rolldown_utils::pattern_filter::StringOrRegex;
 
+pub type AliasResolverFn = dyn Fn(
+    &str,         // id with the alias replaced
+    Option<&str>, // importer
+  ) -> Pin<Box<dyn Future<Output = anyhow::Result<Option<String>>> + Send + 'static>>
+  + Send
+  + Sync;
+
 #[derive(Debug, Default)]
 pub struct AliasPlugin {
-  // We don't support `customResolver` and `resolverFunction`, it will generate many threadSafeFunction in queue, and slowdown the
-  // performance, downstream user should fallback to js alias plugin when needs advance feature.
+  // We don't support `resolverFunction` on the plugin level, since calling js for every import slows down the
+  // build. Only the ids matched by an entry with a `custom_resolver` are passed to it.
   pub entries: Vec<Alias>,
 }
 
-#[derive(Debug)]
 pub struct Alias {
   pub find: StringOrRegex,
   pub replacement: String,
+  /// Resolves the id with the alias replaced instead of the other plugins and the resolver. Returning `None`
+  /// falls back to them.
+  pub custom_resolver: Option<Arc<AliasResolverFn>>,
+}
+
+impl Debug for Alias {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("Alias")
+      .field("find", &self.find)
+      .field("replacement", &self.replacement)
+      .field("custom_resolver", &self.custom_resolver.as_ref().map(|_| "Fn(...)"))
+      .finish()
+  }
 }
 
 impl AliasPlugin {
@@ -46,20 +68,29 @@ impl Plugin for AliasPlugin {
     args: &rolldown_plugin::HookResolveIdArgs<'_>,
   ) -> rolldown_plugin::HookResolveIdReturn {
     let importee = args.specifier;
+    // Entries are tried in order, and only the first matching one applies.
     let match_entry = self.entries.iter().find(|alias| Self::matches(&alias.find, importee));
     let Some(match_entry) = match_entry else {
       return Ok(None);
     };
 
     let update_id = match &match_entry.find {
-      StringOrRegex::String(find) => importee.replace(find, &match_entry.replacement),
+      // A string only matches the start of the importee.
+      StringOrRegex::String(find) => importee.replacen(find.as_str(), &match_entry.replacement, 1),
       StringOrRegex::Regex(find) => find.replace_all(importee, &match_entry.replacement),
     };
+
+    if let Some(custom_resolver) = &match_entry.custom_resolver {
+      if let Some(id) = custom_resolver(&update_id, args.importer).await? {
+        return Ok(Some(HookResolveIdOutput { id, ..Default::default() }));
+      }
+    }
+
     Ok(
       ctx
         .resolve(
           &update_id,
-          None,
+          args.importer,
           Some(PluginContextResolveOptions {
             import_kind: args.kind,
             skip_self: true,
@@ -68,7 +99,11 @@ impl Plugin for AliasPlugin {
         )
         .await?
         .map(|resolved_id| {
-          Some(HookResolveIdOutput { id: resolved_id.id.to_string(), ..Default::default() })
+          Some(HookResolveIdOutput {
+            id: resolved_id.id.to_string(),
+            external: Some(resolved_id.is_external),
+            side_effects: resolved_id.side_effects,
+          })
         })?,
     )
   }
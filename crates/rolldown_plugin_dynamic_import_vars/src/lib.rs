@@ -1,24 +1,50 @@
 use oxc::{
   ast::{
     ast::{Expression, ImportOrExportKind, PropertyKind, Statement},
+    visit::walk_mut,
     AstBuilder, VisitMut, NONE,
   },
   span::{Span, SPAN},
   syntax::number::NumberBase,
 };
 use parse_pattern::{parse_pattern, DynamicImportPattern, DynamicImportRequest};
+use regex::Regex;
 use rolldown_plugin::{
   HookLoadArgs, HookLoadOutput, HookLoadReturn, HookResolveIdArgs, HookResolveIdOutput,
   HookResolveIdReturn, HookTransformAstArgs, HookTransformAstReturn, Plugin, PluginContext,
 };
-use std::borrow::Cow;
-use to_glob::to_glob_pattern;
+use std::{borrow::Cow, path::Path, sync::LazyLock};
+use sugar_path::SugarPath;
+pub use to_glob::to_glob_pattern;
 mod parse_pattern;
 mod should_ignore;
 mod to_glob;
 
 const DYNAMIC_IMPORT_HELPER: &str = "\0rolldown_dynamic_import_helper.js";
 
+static IGNORE_COMMENT_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+  Regex::new(r"/\*\s*@(?:vite|rolldown)-ignore\s*\*/").expect("failed to compile regex")
+});
+
+/// Whether the `import()` expression at `span` opts out of the expansion with a `/* @vite-ignore */`
+/// or `/* @rolldown-ignore */` comment.
+pub fn has_ignore_comment(source: &str, span: Span) -> bool {
+  // The spans of the nodes generated by plugins might be out of the source.
+  source
+    .get(span.start as usize..span.end as usize)
+    .is_some_and(|code| IGNORE_COMMENT_REGEX.is_match(code))
+}
+
+/// Whether any file matches the glob `pattern`, which is relative to the directory of `importer`.
+fn has_matches(importer: &Path, pattern: &str) -> bool {
+  let Some(dir) = importer.parent() else {
+    return false;
+  };
+  let pattern = pattern.strip_prefix("./").unwrap_or(pattern);
+  let glob = format!("{}/{pattern}", glob::Pattern::escape(&dir.to_slash_lossy()));
+  glob::glob(&glob).is_ok_and(|mut paths| paths.any(|path| path.is_ok_and(|path| path.is_file())))
+}
+
 #[derive(Debug)]
 pub struct DynamicImportVarsPlugin {}
 
@@ -55,10 +81,18 @@ impl Plugin for DynamicImportVarsPlugin {
     _ctx: &PluginContext,
     mut args: HookTransformAstArgs,
   ) -> HookTransformAstReturn {
-    // TODO: Ignore if includes a marker like "/* @rolldown-ignore */"
+    if !args.ast.source().contains("import") {
+      return Ok(args.ast);
+    }
+    let importer = args.cwd.join(args.id);
     args.ast.program.with_mut(|fields| {
       let ast_builder: AstBuilder = AstBuilder::new(fields.allocator);
-      let mut visitor = DynamicImportVarsVisit { ast_builder, need_helper: false };
+      let mut visitor = DynamicImportVarsVisit {
+        ast_builder,
+        source: fields.source.as_str(),
+        importer: &importer,
+        need_helper: false,
+      };
       visitor.visit_program(fields.program);
       if visitor.need_helper {
         fields.program.body.push(visitor.import_helper());
@@ -68,21 +102,32 @@ impl Plugin for DynamicImportVarsPlugin {
   }
 }
 
-pub struct DynamicImportVarsVisit<'ast> {
+pub struct DynamicImportVarsVisit<'ast, 'a> {
   ast_builder: AstBuilder<'ast>,
+  source: &'ast str,
+  importer: &'a Path,
   need_helper: bool,
 }
 
-impl<'ast> VisitMut<'ast> for DynamicImportVarsVisit<'ast> {
+impl<'ast, 'a> VisitMut<'ast> for DynamicImportVarsVisit<'ast, 'a> {
   #[allow(clippy::too_many_lines)]
   fn visit_expression(&mut self, expr: &mut Expression<'ast>) {
     if let Expression::ImportExpression(import_expr) = expr {
       // TODO: Support @/path via options.createResolver
-      // TODO: handle error
-      let pattern = to_glob_pattern(&import_expr.source).unwrap();
-      if let Some(pattern) = pattern {
+      // Only template literals are expanded. The imports that can't be analyzed are left as is, the
+      // scanner reports them.
+      if let (true, false, Ok(Some(pattern))) = (
+        matches!(import_expr.source, Expression::TemplateLiteral(_)),
+        has_ignore_comment(self.source, import_expr.span),
+        to_glob_pattern(&import_expr.source),
+      ) {
         let DynamicImportPattern { glob_params, user_pattern, raw_pattern: _ } =
           parse_pattern(pattern.as_str());
+        // The import is kept as is if no module matches, it might be resolved at runtime.
+        if !has_matches(self.importer, &user_pattern) {
+          walk_mut::walk_expression(self, expr);
+          return;
+        }
         self.need_helper = true;
         *expr = self.call_helper(
           import_expr.span,
@@ -95,10 +140,11 @@ impl<'ast> VisitMut<'ast> for DynamicImportVarsVisit<'ast> {
         );
       }
     }
+    walk_mut::walk_expression(self, expr);
   }
 }
 
-impl<'ast> DynamicImportVarsVisit<'ast> {
+impl<'ast, 'a> DynamicImportVarsVisit<'ast, 'a> {
   /// generates:
   /// ```js
   /// __variableDynamicImportRuntimeHelper((import.meta.glob(pattern, params)), expr, segments)
@@ -218,3 +264,14 @@ impl<'ast> DynamicImportVarsVisit<'ast> {
     )
   }
 }
+
+#[test]
+fn test_has_ignore_comment() {
+  let source =
+    "import(/* @vite-ignore */ `./${a}`); import(`./${b}.js`); import(/*@rolldown-ignore*/ `./${c}`)";
+  assert!(has_ignore_comment(source, Span::new(0, 35)));
+  assert!(!has_ignore_comment(source, Span::new(37, 56)));
+  assert!(has_ignore_comment(source, Span::new(58, 95)));
+  // Generated nodes might have spans out of the source.
+  assert!(!has_ignore_comment(source, Span::new(100, 120)));
+}
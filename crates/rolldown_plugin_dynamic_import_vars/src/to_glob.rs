@@ -24,7 +24,9 @@ fn expr_to_str(expr: &Expression) -> String {
   codegen.into_source_text()
 }
 
-pub(crate) fn to_glob_pattern(expr: &Expression) -> anyhow::Result<Option<String>> {
+/// Converts the source of a dynamic import to a glob pattern matching the modules it could import.
+/// Returns `None` if the source isn't dynamic or imports an URL.
+pub fn to_glob_pattern(expr: &Expression) -> anyhow::Result<Option<String>> {
   let glob = expr_to_glob(expr)?;
 
   if should_ignore(&glob) {
@@ -42,6 +42,12 @@ impl Plugin for ImportGlobPlugin {
     _ctx: &PluginContext,
     mut args: HookTransformAstArgs,
   ) -> HookTransformAstReturn {
+    // The plugin is applied to every module by default, so skip visiting the modules that can't
+    // contain any `import.meta.glob` calls. The dynamic import vars plugin rewrites `import()` to
+    // such calls, so they might not appear in the source.
+    if !args.ast.source().contains("import") {
+      return Ok(args.ast);
+    }
     args.ast.program.with_mut(|fields| {
       let ast_builder = AstBuilder::new(fields.allocator);
       let normalized_path = args.cwd.join(args.id);
@@ -0,0 +1,141 @@
+use std::borrow::Cow;
+
+use oxc::{
+  semantic::SemanticBuilder,
+  transformer::{InjectGlobalVariables, InjectGlobalVariablesConfig, InjectImport},
+};
+use rolldown_common::side_effects::HookSideEffects;
+use rolldown_plugin::{
+  HookLoadArgs, HookLoadOutput, HookLoadReturn, HookResolveIdArgs, HookResolveIdOutput,
+  HookResolveIdReturn, HookTransformAstArgs, HookTransformAstReturn, Plugin, PluginContext,
+};
+
+const POLYFILL_ID_PREFIX: &str = "\0polyfill-node.";
+
+/// Builtins that have a browser implementation. Their behavior is compared with node's builtins by the
+/// `builtin-plugin/node-polyfills-*` fixtures of `packages/rolldown`.
+const POLYFILLS: [(&str, &str); 10] = [
+  ("assert", include_str!("polyfills/assert.js")),
+  ("events", include_str!("polyfills/events.js")),
+  ("os", include_str!("polyfills/os.js")),
+  ("path", include_str!("polyfills/path.js")),
+  ("process", include_str!("polyfills/process.js")),
+  ("querystring", include_str!("polyfills/querystring.js")),
+  ("stream", include_str!("polyfills/stream.js")),
+  ("timers", include_str!("polyfills/timers.js")),
+  ("tty", include_str!("polyfills/tty.js")),
+  ("util", include_str!("polyfills/util.js")),
+];
+
+/// Builtins that can't work in browsers. They are resolved to empty modules, so the code that only
+/// touches them when running on node still bundles.
+const EMPTY_BUILTINS: [&str; 12] = [
+  "child_process",
+  "cluster",
+  "dgram",
+  "dns",
+  "fs",
+  "module",
+  "net",
+  "readline",
+  "repl",
+  "tls",
+  "v8",
+  "worker_threads",
+];
+
+/// Not a builtin, so `import 'global'` still resolves to the npm package. It's only imported by the
+/// injected shims.
+const GLOBAL_POLYFILL: (&str, &str) = ("global", include_str!("polyfills/global.js"));
+
+/// Replaces node builtins with their browser implementations, and injects imports of the `process` and
+/// `global` shims into the modules that use them as globals. Polyfills are plain ES modules without
+/// side effects, so the unused parts of them are tree-shaken.
+#[derive(Debug)]
+pub struct NodePolyfillsPlugin {
+  inject_config: InjectGlobalVariablesConfig,
+}
+
+impl Default for NodePolyfillsPlugin {
+  fn default() -> Self {
+    let inject = |name: &str| InjectImport::default_specifier(&polyfill_id(name), name);
+    Self {
+      inject_config: InjectGlobalVariablesConfig::new(vec![inject("process"), inject("global")]),
+    }
+  }
+}
+
+fn polyfill_id(name: &str) -> String {
+  format!("{POLYFILL_ID_PREFIX}{name}.js")
+}
+
+impl Plugin for NodePolyfillsPlugin {
+  fn name(&self) -> Cow<'static, str> {
+    Cow::Borrowed("builtin:node-polyfills")
+  }
+
+  async fn resolve_id(
+    &self,
+    _ctx: &PluginContext,
+    args: &HookResolveIdArgs<'_>,
+  ) -> HookResolveIdReturn {
+    // The injected shims and the polyfills importing each other.
+    if args.specifier.starts_with(POLYFILL_ID_PREFIX) {
+      return Ok(Some(HookResolveIdOutput {
+        id: args.specifier.to_string(),
+        ..Default::default()
+      }));
+    }
+    let name = args.specifier.strip_prefix("node:").unwrap_or(args.specifier);
+    let is_builtin =
+      POLYFILLS.iter().any(|(builtin, _)| *builtin == name) || EMPTY_BUILTINS.contains(&name);
+    Ok(is_builtin.then(|| HookResolveIdOutput { id: polyfill_id(name), ..Default::default() }))
+  }
+
+  async fn load(&self, _ctx: &PluginContext, args: &HookLoadArgs<'_>) -> HookLoadReturn {
+    let Some(name) = args.id.strip_prefix(POLYFILL_ID_PREFIX).and_then(|id| id.strip_suffix(".js"))
+    else {
+      return Ok(None);
+    };
+    let code = if EMPTY_BUILTINS.contains(&name) {
+      "export default {}"
+    } else {
+      let Some(code) = POLYFILLS
+        .iter()
+        .chain([&GLOBAL_POLYFILL])
+        .find_map(|&(polyfill, code)| (polyfill == name).then_some(code))
+      else {
+        return Ok(None);
+      };
+      code
+    };
+    Ok(Some(HookLoadOutput {
+      code: code.to_string(),
+      side_effects: Some(HookSideEffects::False),
+      ..Default::default()
+    }))
+  }
+
+  fn transform_ast(
+    &self,
+    _ctx: &PluginContext,
+    mut args: HookTransformAstArgs,
+  ) -> HookTransformAstReturn {
+    // Skip building the semantic data for the modules that can't reference the globals.
+    let source = args.ast.source();
+    if !source.contains("process") && !source.contains("global") {
+      return Ok(args.ast);
+    }
+    args.ast.program.with_mut(|fields| {
+      let (symbols, scopes) =
+        SemanticBuilder::new().build(fields.program).semantic.into_symbol_table_and_scope_tree();
+      // The semantic data is only needed for the injection, it's rebuilt for the transformed ast later.
+      let _ = InjectGlobalVariables::new(fields.allocator, self.inject_config.clone()).build(
+        symbols,
+        scopes,
+        fields.program,
+      );
+    });
+    Ok(args.ast)
+  }
+}
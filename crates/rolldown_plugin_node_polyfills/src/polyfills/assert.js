@@ -0,0 +1,309 @@
+// A browser implementation of node's `assert`. Deep equality compares own enumerable keys, the names
+// and messages of errors, and the entries of maps and sets in order.
+import { inspect } from '\0polyfill-node.util.js'
+
+export class AssertionError extends Error {
+  constructor(options) {
+    super(
+      options.message ??
+        `${inspect(options.actual)} ${options.operator} ${inspect(options.expected)}`,
+    )
+    this.name = 'AssertionError'
+    this.code = 'ERR_ASSERTION'
+    this.actual = options.actual
+    this.expected = options.expected
+    this.operator = options.operator
+    this.generatedMessage = options.message === undefined
+  }
+}
+
+function innerFail(actual, expected, message, operator) {
+  if (message instanceof Error) {
+    throw message
+  }
+  throw new AssertionError({ actual, expected, message, operator })
+}
+
+// Like node, `NaN` loosely equals itself.
+function isLooseEqual(actual, expected) {
+  return actual == expected || (actual !== actual && expected !== expected)
+}
+
+function isObject(value) {
+  return typeof value === 'object' && value !== null
+}
+
+function isDeepEqual(actual, expected, strict) {
+  if (Object.is(actual, expected)) {
+    return true
+  }
+  if (!isObject(actual) || !isObject(expected)) {
+    // Objects are never loosely equal to primitives, unlike with `==`.
+    return (
+      !strict &&
+      !isObject(actual) &&
+      !isObject(expected) &&
+      isLooseEqual(actual, expected)
+    )
+  }
+  if (
+    strict &&
+    Object.getPrototypeOf(actual) !== Object.getPrototypeOf(expected)
+  ) {
+    return false
+  }
+  if (
+    Object.prototype.toString.call(actual) !==
+    Object.prototype.toString.call(expected)
+  ) {
+    return false
+  }
+  if (
+    actual instanceof Error &&
+    expected instanceof Error &&
+    (actual.name !== expected.name || actual.message !== expected.message)
+  ) {
+    return false
+  }
+  if (actual instanceof Date && expected instanceof Date) {
+    return actual.getTime() === expected.getTime()
+  }
+  if (actual instanceof RegExp && expected instanceof RegExp) {
+    return String(actual) === String(expected)
+  }
+  if (
+    (actual instanceof Map && expected instanceof Map) ||
+    (actual instanceof Set && expected instanceof Set)
+  ) {
+    return isDeepEqual([...actual], [...expected], strict)
+  }
+  if (Array.isArray(actual) !== Array.isArray(expected)) {
+    return false
+  }
+  const actualKeys = Object.keys(actual)
+  if (actualKeys.length !== Object.keys(expected).length) {
+    return false
+  }
+  return actualKeys.every(
+    (key) =>
+      Object.prototype.hasOwnProperty.call(expected, key) &&
+      isDeepEqual(actual[key], expected[key], strict),
+  )
+}
+
+function matchesError(error, expected) {
+  if (expected instanceof RegExp) {
+    return expected.test(String(error))
+  }
+  if (typeof expected === 'function') {
+    if (expected.prototype !== undefined && error instanceof expected) {
+      return true
+    }
+    if (Error.isPrototypeOf(expected)) {
+      return false
+    }
+    return expected.call({}, error) === true
+  }
+  return Object.keys(expected).every((key) =>
+    expected[key] instanceof RegExp && typeof error[key] === 'string'
+      ? expected[key].test(error[key])
+      : isDeepEqual(error[key], expected[key], true),
+  )
+}
+
+export function ok(value, message) {
+  if (!value) {
+    innerFail(
+      value,
+      true,
+      message ?? 'The expression evaluated to a falsy value',
+      '==',
+    )
+  }
+}
+
+export function fail(message = 'Failed') {
+  innerFail(undefined, undefined, message, 'fail')
+}
+
+export function equal(actual, expected, message) {
+  if (!isLooseEqual(actual, expected)) {
+    innerFail(actual, expected, message, '==')
+  }
+}
+
+export function notEqual(actual, expected, message) {
+  if (isLooseEqual(actual, expected)) {
+    innerFail(actual, expected, message, '!=')
+  }
+}
+
+export function strictEqual(actual, expected, message) {
+  if (!Object.is(actual, expected)) {
+    innerFail(actual, expected, message, 'strictEqual')
+  }
+}
+
+export function notStrictEqual(actual, expected, message) {
+  if (Object.is(actual, expected)) {
+    innerFail(actual, expected, message, 'notStrictEqual')
+  }
+}
+
+export function deepEqual(actual, expected, message) {
+  if (!isDeepEqual(actual, expected, false)) {
+    innerFail(actual, expected, message, 'deepEqual')
+  }
+}
+
+export function notDeepEqual(actual, expected, message) {
+  if (isDeepEqual(actual, expected, false)) {
+    innerFail(actual, expected, message, 'notDeepEqual')
+  }
+}
+
+export function deepStrictEqual(actual, expected, message) {
+  if (!isDeepEqual(actual, expected, true)) {
+    innerFail(actual, expected, message, 'deepStrictEqual')
+  }
+}
+
+export function notDeepStrictEqual(actual, expected, message) {
+  if (isDeepEqual(actual, expected, true)) {
+    innerFail(actual, expected, message, 'notDeepStrictEqual')
+  }
+}
+
+export function throws(fn, expected, message) {
+  if (typeof expected === 'string') {
+    message = expected
+    expected = undefined
+  }
+  try {
+    fn()
+  } catch (error) {
+    if (expected !== undefined && !matchesError(error, expected)) {
+      innerFail(error, expected, message, 'throws')
+    }
+    return
+  }
+  innerFail(
+    undefined,
+    expected,
+    message ?? 'Missing expected exception.',
+    'throws',
+  )
+}
+
+export function doesNotThrow(fn, message) {
+  try {
+    fn()
+  } catch (error) {
+    innerFail(
+      error,
+      undefined,
+      message ?? `Got unwanted exception.\nActual message: "${error?.message}"`,
+      'doesNotThrow',
+    )
+  }
+}
+
+export async function rejects(promiseOrFn, expected, message) {
+  if (typeof expected === 'string') {
+    message = expected
+    expected = undefined
+  }
+  try {
+    await (typeof promiseOrFn === 'function' ? promiseOrFn() : promiseOrFn)
+  } catch (error) {
+    if (expected !== undefined && !matchesError(error, expected)) {
+      innerFail(error, expected, message, 'rejects')
+    }
+    return
+  }
+  innerFail(
+    undefined,
+    expected,
+    message ?? 'Missing expected rejection.',
+    'rejects',
+  )
+}
+
+export async function doesNotReject(promiseOrFn, message) {
+  try {
+    await (typeof promiseOrFn === 'function' ? promiseOrFn() : promiseOrFn)
+  } catch (error) {
+    innerFail(
+      error,
+      undefined,
+      message ?? `Got unwanted rejection.\nActual message: "${error?.message}"`,
+      'doesNotReject',
+    )
+  }
+}
+
+export function match(string, regexp, message) {
+  if (!regexp.test(string)) {
+    innerFail(string, regexp, message, 'match')
+  }
+}
+
+export function doesNotMatch(string, regexp, message) {
+  if (regexp.test(string)) {
+    innerFail(string, regexp, message, 'doesNotMatch')
+  }
+}
+
+export function ifError(value) {
+  if (value !== null && value !== undefined) {
+    const message = value instanceof Error ? value.message : inspect(value)
+    innerFail(
+      value,
+      null,
+      `ifError got unwanted exception: ${message}`,
+      'ifError',
+    )
+  }
+}
+
+const methods = {
+  AssertionError,
+  ok,
+  fail,
+  equal,
+  notEqual,
+  strictEqual,
+  notStrictEqual,
+  deepEqual,
+  notDeepEqual,
+  deepStrictEqual,
+  notDeepStrictEqual,
+  throws,
+  doesNotThrow,
+  rejects,
+  doesNotReject,
+  match,
+  doesNotMatch,
+  ifError,
+}
+
+// `assert.strict` is the same as `assert` with the loose comparisons replaced by the strict ones.
+export function strict(value, message) {
+  ok(value, message)
+}
+
+Object.assign(strict, methods, {
+  equal: strictEqual,
+  notEqual: notStrictEqual,
+  deepEqual: deepStrictEqual,
+  notDeepEqual: notDeepStrictEqual,
+  strict,
+})
+
+function assert(value, message) {
+  ok(value, message)
+}
+
+Object.assign(assert, methods, { strict })
+
+export default assert
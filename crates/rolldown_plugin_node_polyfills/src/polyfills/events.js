@@ -0,0 +1,212 @@
+// A browser implementation of node's `events`. `EventEmitter` is a plain constructor rather than a
+// class, since old packages still call it as `EventEmitter.call(this)`.
+
+export function EventEmitter() {
+  EventEmitter.init.call(this)
+}
+
+export default EventEmitter
+
+EventEmitter.EventEmitter = EventEmitter
+EventEmitter.defaultMaxListeners = 10
+
+EventEmitter.init = function () {
+  if (
+    this._events === undefined ||
+    this._events === Object.getPrototypeOf(this)._events
+  ) {
+    this._events = Object.create(null)
+    this._eventsCount = 0
+  }
+  this._maxListeners = this._maxListeners || undefined
+}
+
+function checkListener(listener) {
+  if (typeof listener !== 'function') {
+    throw new TypeError(
+      `The "listener" argument must be of type Function. Received type ${typeof listener}`,
+    )
+  }
+}
+
+// Listeners of an event are always kept in an array.
+function addListener(target, type, listener, prepend) {
+  checkListener(listener)
+  if (target._events === undefined) {
+    EventEmitter.init.call(target)
+  }
+  if (target._events.newListener !== undefined) {
+    target.emit('newListener', type, listener.listener ?? listener)
+  }
+  const listeners = target._events[type]
+  if (listeners === undefined) {
+    target._events[type] = [listener]
+    target._eventsCount++
+  } else if (prepend) {
+    listeners.unshift(listener)
+  } else {
+    listeners.push(listener)
+  }
+  const max = target.getMaxListeners()
+  const added = target._events[type]
+  if (max > 0 && added.length > max && !added.warned) {
+    added.warned = true
+    console.warn(
+      `Possible EventEmitter memory leak detected. ${added.length} ${String(type)} listeners added. Use emitter.setMaxListeners() to increase limit`,
+    )
+  }
+  return target
+}
+
+function onceWrapper(target, type, listener) {
+  let fired = false
+  const wrapped = function (...args) {
+    if (fired) {
+      return
+    }
+    fired = true
+    target.removeListener(type, wrapped)
+    return listener.apply(target, args)
+  }
+  wrapped.listener = listener
+  return wrapped
+}
+
+EventEmitter.prototype._events = undefined
+EventEmitter.prototype._eventsCount = 0
+EventEmitter.prototype._maxListeners = undefined
+
+EventEmitter.prototype.setMaxListeners = function (n) {
+  this._maxListeners = n
+  return this
+}
+
+EventEmitter.prototype.getMaxListeners = function () {
+  return this._maxListeners === undefined
+    ? EventEmitter.defaultMaxListeners
+    : this._maxListeners
+}
+
+EventEmitter.prototype.emit = function (type, ...args) {
+  const listeners = this._events === undefined ? undefined : this._events[type]
+  if (listeners === undefined) {
+    if (type === 'error') {
+      const error = args[0]
+      if (error instanceof Error) {
+        throw error
+      }
+      throw new Error(`Unhandled error. (${error})`)
+    }
+    return false
+  }
+  // Listeners added or removed while emitting don't affect this emit.
+  for (const listener of listeners.slice()) {
+    listener.apply(this, args)
+  }
+  return true
+}
+
+EventEmitter.prototype.addListener = function (type, listener) {
+  return addListener(this, type, listener, false)
+}
+
+EventEmitter.prototype.on = EventEmitter.prototype.addListener
+
+EventEmitter.prototype.prependListener = function (type, listener) {
+  return addListener(this, type, listener, true)
+}
+
+EventEmitter.prototype.once = function (type, listener) {
+  checkListener(listener)
+  return this.on(type, onceWrapper(this, type, listener))
+}
+
+EventEmitter.prototype.prependOnceListener = function (type, listener) {
+  checkListener(listener)
+  return this.prependListener(type, onceWrapper(this, type, listener))
+}
+
+EventEmitter.prototype.removeListener = function (type, listener) {
+  checkListener(listener)
+  const listeners = this._events === undefined ? undefined : this._events[type]
+  if (listeners === undefined) {
+    return this
+  }
+  for (let i = listeners.length - 1; i >= 0; i--) {
+    if (listeners[i] === listener || listeners[i].listener === listener) {
+      listeners.splice(i, 1)
+      if (listeners.length === 0) {
+        delete this._events[type]
+        this._eventsCount--
+      }
+      if (this._events.removeListener !== undefined) {
+        this.emit('removeListener', type, listener)
+      }
+      break
+    }
+  }
+  return this
+}
+
+EventEmitter.prototype.off = EventEmitter.prototype.removeListener
+
+EventEmitter.prototype.removeAllListeners = function (type) {
+  if (this._events === undefined) {
+    return this
+  }
+  if (type === undefined) {
+    this._events = Object.create(null)
+    this._eventsCount = 0
+  } else if (this._events[type] !== undefined) {
+    delete this._events[type]
+    this._eventsCount--
+  }
+  return this
+}
+
+EventEmitter.prototype.listeners = function (type) {
+  const listeners = this._events === undefined ? undefined : this._events[type]
+  return listeners === undefined
+    ? []
+    : listeners.map((listener) => listener.listener ?? listener)
+}
+
+EventEmitter.prototype.rawListeners = function (type) {
+  const listeners = this._events === undefined ? undefined : this._events[type]
+  return listeners === undefined ? [] : listeners.slice()
+}
+
+EventEmitter.prototype.listenerCount = function (type) {
+  const listeners = this._events === undefined ? undefined : this._events[type]
+  return listeners === undefined ? 0 : listeners.length
+}
+
+EventEmitter.prototype.eventNames = function () {
+  return this._eventsCount > 0 ? Reflect.ownKeys(this._events) : []
+}
+
+export function once(emitter, type) {
+  return new Promise((resolve, reject) => {
+    const onError = (error) => {
+      emitter.removeListener(type, onEvent)
+      reject(error)
+    }
+    const onEvent = (...args) => {
+      if (type !== 'error') {
+        emitter.removeListener('error', onError)
+      }
+      resolve(args)
+    }
+    emitter.once(type, onEvent)
+    if (type !== 'error') {
+      emitter.once('error', onError)
+    }
+  })
+}
+
+export function listenerCount(emitter, type) {
+  return emitter.listenerCount(type)
+}
+
+EventEmitter.once = once
+EventEmitter.listenerCount = listenerCount
@@ -0,0 +1,91 @@
+// A browser implementation of node's `os`, describing a generic system that has no resources of its own.
+
+export const EOL = '\n'
+export const devNull = '/dev/null'
+
+export function platform() {
+  return 'browser'
+}
+
+export function type() {
+  return 'Browser'
+}
+
+export function arch() {
+  return 'javascript'
+}
+
+export function release() {
+  return typeof navigator === 'undefined' ? '' : navigator.appVersion
+}
+
+export function hostname() {
+  return typeof location === 'undefined' ? '' : location.hostname
+}
+
+export function homedir() {
+  return '/'
+}
+
+export function tmpdir() {
+  return '/tmp'
+}
+
+export function endianness() {
+  return 'LE'
+}
+
+export function availableParallelism() {
+  return typeof navigator === 'undefined'
+    ? 1
+    : navigator.hardwareConcurrency || 1
+}
+
+export function cpus() {
+  return []
+}
+
+export function totalmem() {
+  return 0
+}
+
+export function freemem() {
+  return 0
+}
+
+export function uptime() {
+  return 0
+}
+
+export function loadavg() {
+  return [0, 0, 0]
+}
+
+export function networkInterfaces() {
+  return {}
+}
+
+export function userInfo() {
+  return { uid: -1, gid: -1, username: '', homedir: '/', shell: null }
+}
+
+export default {
+  EOL,
+  devNull,
+  platform,
+  type,
+  arch,
+  release,
+  hostname,
+  homedir,
+  tmpdir,
+  endianness,
+  availableParallelism,
+  cpus,
+  totalmem,
+  freemem,
+  uptime,
+  loadavg,
+  networkInterfaces,
+  userInfo,
+}
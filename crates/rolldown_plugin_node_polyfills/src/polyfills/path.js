@@ -0,0 +1,300 @@
+// A browser implementation of node's `path`, following the posix flavor. The working directory is `/`.
+
+const SLASH = 47
+const DOT = 46
+
+function assertPath(path) {
+  if (typeof path !== 'string') {
+    throw new TypeError(
+      `The "path" argument must be of type string. Received ${typeof path}`,
+    )
+  }
+}
+
+// Resolves `.` and `..` segments, without caring about leading and trailing slashes.
+function normalizeString(path, allowAboveRoot) {
+  let res = ''
+  let lastSegmentLength = 0
+  let lastSlash = -1
+  let dots = 0
+  let code = 0
+  for (let i = 0; i <= path.length; ++i) {
+    if (i < path.length) {
+      code = path.charCodeAt(i)
+    } else if (code === SLASH) {
+      break
+    } else {
+      code = SLASH
+    }
+    if (code === SLASH) {
+      if (lastSlash === i - 1 || dots === 1) {
+        // Empty segment or `.`
+      } else if (dots === 2) {
+        if (
+          res.length < 2 ||
+          lastSegmentLength !== 2 ||
+          res.charCodeAt(res.length - 1) !== DOT ||
+          res.charCodeAt(res.length - 2) !== DOT
+        ) {
+          if (res.length > 2) {
+            const lastSlashIndex = res.lastIndexOf('/')
+            if (lastSlashIndex === -1) {
+              res = ''
+              lastSegmentLength = 0
+            } else {
+              res = res.slice(0, lastSlashIndex)
+              lastSegmentLength = res.length - 1 - res.lastIndexOf('/')
+            }
+            lastSlash = i
+            dots = 0
+            continue
+          } else if (res.length !== 0) {
+            res = ''
+            lastSegmentLength = 0
+            lastSlash = i
+            dots = 0
+            continue
+          }
+        }
+        if (allowAboveRoot) {
+          res += res.length > 0 ? '/..' : '..'
+          lastSegmentLength = 2
+        }
+      } else {
+        if (res.length > 0) {
+          res += `/${path.slice(lastSlash + 1, i)}`
+        } else {
+          res = path.slice(lastSlash + 1, i)
+        }
+        lastSegmentLength = i - lastSlash - 1
+      }
+      lastSlash = i
+      dots = 0
+    } else if (code === DOT && dots !== -1) {
+      ++dots
+    } else {
+      dots = -1
+    }
+  }
+  return res
+}
+
+export const sep = '/'
+export const delimiter = ':'
+
+export function resolve(...paths) {
+  let resolvedPath = ''
+  let resolvedAbsolute = false
+  for (let i = paths.length - 1; i >= -1 && !resolvedAbsolute; i--) {
+    const path = i >= 0 ? paths[i] : '/'
+    assertPath(path)
+    if (path.length === 0) {
+      continue
+    }
+    resolvedPath = `${path}/${resolvedPath}`
+    resolvedAbsolute = path.charCodeAt(0) === SLASH
+  }
+  resolvedPath = normalizeString(resolvedPath, !resolvedAbsolute)
+  if (resolvedAbsolute) {
+    return `/${resolvedPath}`
+  }
+  return resolvedPath.length > 0 ? resolvedPath : '.'
+}
+
+export function normalize(path) {
+  assertPath(path)
+  if (path.length === 0) {
+    return '.'
+  }
+  const absolute = path.charCodeAt(0) === SLASH
+  const trailingSeparator = path.charCodeAt(path.length - 1) === SLASH
+  path = normalizeString(path, !absolute)
+  if (path.length === 0) {
+    if (absolute) {
+      return '/'
+    }
+    return trailingSeparator ? './' : '.'
+  }
+  if (trailingSeparator) {
+    path += '/'
+  }
+  return absolute ? `/${path}` : path
+}
+
+export function isAbsolute(path) {
+  assertPath(path)
+  return path.length > 0 && path.charCodeAt(0) === SLASH
+}
+
+export function join(...paths) {
+  let joined
+  for (const path of paths) {
+    assertPath(path)
+    if (path.length > 0) {
+      joined = joined === undefined ? path : `${joined}/${path}`
+    }
+  }
+  return joined === undefined ? '.' : normalize(joined)
+}
+
+export function relative(from, to) {
+  assertPath(from)
+  assertPath(to)
+  if (from === to) {
+    return ''
+  }
+  from = resolve(from)
+  to = resolve(to)
+  if (from === to) {
+    return ''
+  }
+  // Both are absolute now, so skip the leading slashes.
+  const fromStart = 1
+  const fromEnd = from.length
+  const fromLen = fromEnd - fromStart
+  const toStart = 1
+  const toLen = to.length - toStart
+  const length = fromLen < toLen ? fromLen : toLen
+  let lastCommonSep = -1
+  let i = 0
+  for (; i < length; i++) {
+    const fromCode = from.charCodeAt(fromStart + i)
+    if (fromCode !== to.charCodeAt(toStart + i)) {
+      break
+    } else if (fromCode === SLASH) {
+      lastCommonSep = i
+    }
+  }
+  if (i === length) {
+    if (toLen > length) {
+      if (to.charCodeAt(toStart + i) === SLASH) {
+        // `from` is the parent of `to`
+        return to.slice(toStart + i + 1)
+      }
+      if (i === 0) {
+        // `from` is the root
+        return to.slice(toStart + i)
+      }
+    } else if (fromLen > length) {
+      if (from.charCodeAt(fromStart + i) === SLASH) {
+        // `to` is the parent of `from`
+        lastCommonSep = i
+      } else if (i === 0) {
+        // `to` is the root
+        lastCommonSep = 0
+      }
+    }
+  }
+  let out = ''
+  for (i = fromStart + lastCommonSep + 1; i <= fromEnd; ++i) {
+    if (i === fromEnd || from.charCodeAt(i) === SLASH) {
+      out += out.length === 0 ? '..' : '/..'
+    }
+  }
+  return `${out}${to.slice(toStart + lastCommonSep)}`
+}
+
+export function toNamespacedPath(path) {
+  return path
+}
+
+export function dirname(path) {
+  assertPath(path)
+  if (path.length === 0) {
+    return '.'
+  }
+  const hasRoot = path.charCodeAt(0) === SLASH
+  let end = -1
+  let matchedSlash = true
+  for (let i = path.length - 1; i >= 1; --i) {
+    if (path.charCodeAt(i) === SLASH) {
+      if (!matchedSlash) {
+        end = i
+        break
+      }
+    } else {
+      matchedSlash = false
+    }
+  }
+  if (end === -1) {
+    return hasRoot ? '/' : '.'
+  }
+  if (hasRoot && end === 1) {
+    return '//'
+  }
+  return path.slice(0, end)
+}
+
+export function basename(path, suffix) {
+  assertPath(path)
+  let end = path.length
+  while (end > 1 && path.charCodeAt(end - 1) === SLASH) {
+    end--
+  }
+  const base = path.slice(path.lastIndexOf('/', end - 1) + 1, end)
+  if (suffix !== undefined && suffix !== base && base.endsWith(suffix)) {
+    return base.slice(0, base.length - suffix.length)
+  }
+  return base
+}
+
+export function extname(path) {
+  const base = basename(path)
+  const dot = base.lastIndexOf('.')
+  // Dotfiles like `.gitignore` don't have extensions.
+  if (dot <= 0 || base === '..') {
+    return ''
+  }
+  return base.slice(dot)
+}
+
+export function format(pathObject) {
+  const dir = pathObject.dir || pathObject.root
+  const base =
+    pathObject.base || `${pathObject.name || ''}${pathObject.ext || ''}`
+  if (!dir) {
+    return base
+  }
+  return dir === pathObject.root ? `${dir}${base}` : `${dir}/${base}`
+}
+
+export function parse(path) {
+  assertPath(path)
+  let end = path.length
+  while (end > 1 && path.charCodeAt(end - 1) === SLASH) {
+    end--
+  }
+  const lastSlash = path.lastIndexOf('/', end - 1)
+  const base = basename(path)
+  const ext = extname(path)
+  return {
+    root: path.charCodeAt(0) === SLASH ? '/' : '',
+    dir: lastSlash > 0 ? path.slice(0, lastSlash) : lastSlash === 0 ? '/' : '',
+    base,
+    ext,
+    name: ext ? base.slice(0, base.length - ext.length) : base,
+  }
+}
+
+const path = {
+  sep,
+  delimiter,
+  resolve,
+  normalize,
+  isAbsolute,
+  join,
+  relative,
+  toNamespacedPath,
+  dirname,
+  basename,
+  extname,
+  format,
+  parse,
+}
+
+// Like on posix systems in node, `path.posix` is `path` itself.
+path.posix = path
+
+export { path as posix }
+
+export default path
@@ -0,0 +1,106 @@
+// A browser implementation of node's `process`. `nextTick` callbacks are run as microtasks.
+
+function noop() {}
+
+export const title = 'browser'
+export const browser = true
+export const env = {}
+export const argv = []
+export const execArgv = []
+export const version = ''
+export const versions = {}
+export const platform = 'browser'
+export const pid = 1
+export const release = { name: 'browser' }
+export const config = {}
+
+export function nextTick(callback, ...args) {
+  queueMicrotask(() => callback(...args))
+}
+
+export function cwd() {
+  return '/'
+}
+
+export function chdir() {
+  throw new Error('process.chdir is not supported')
+}
+
+export function umask() {
+  return 0
+}
+
+export function hrtime(previous) {
+  const now = performance.now()
+  let seconds = Math.floor(now / 1e3)
+  let nanoseconds = Math.floor((now % 1e3) * 1e6)
+  if (previous) {
+    seconds -= previous[0]
+    nanoseconds -= previous[1]
+    if (nanoseconds < 0) {
+      seconds--
+      nanoseconds += 1e9
+    }
+  }
+  return [seconds, nanoseconds]
+}
+
+export function uptime() {
+  return performance.now() / 1e3
+}
+
+export function binding() {
+  throw new Error('process.binding is not supported')
+}
+
+export function emitWarning(warning) {
+  console.warn(warning)
+}
+
+export function listeners() {
+  return []
+}
+
+export {
+  noop as on,
+  noop as addListener,
+  noop as once,
+  noop as off,
+  noop as removeListener,
+  noop as removeAllListeners,
+  noop as emit,
+  noop as prependListener,
+  noop as prependOnceListener,
+}
+
+export default {
+  title,
+  browser,
+  env,
+  argv,
+  execArgv,
+  version,
+  versions,
+  platform,
+  pid,
+  release,
+  config,
+  nextTick,
+  cwd,
+  chdir,
+  umask,
+  hrtime,
+  uptime,
+  binding,
+  emitWarning,
+  listeners,
+  on: noop,
+  addListener: noop,
+  once: noop,
+  off: noop,
+  removeListener: noop,
+  removeAllListeners: noop,
+  emit: noop,
+  prependListener: noop,
+  prependOnceListener: noop,
+}
@@ -0,0 +1,93 @@
+// A browser implementation of node's `querystring`.
+
+export function escape(str) {
+  return encodeURIComponent(str)
+}
+
+export function unescape(str) {
+  try {
+    return decodeURIComponent(str)
+  } catch {
+    return str
+  }
+}
+
+function stringifyPrimitive(value) {
+  switch (typeof value) {
+    case 'string':
+      return value
+    case 'boolean':
+      return value ? 'true' : 'false'
+    case 'bigint':
+      return String(value)
+    case 'number':
+      return Number.isFinite(value) ? String(value) : ''
+    default:
+      return ''
+  }
+}
+
+export function stringify(obj, sep = '&', eq = '=', options) {
+  const encode = options?.encodeURIComponent ?? escape
+  if (obj === null || typeof obj !== 'object') {
+    return ''
+  }
+  return (
+    Object.keys(obj)
+      .map((key) => {
+        const value = obj[key]
+        const prefix = `${encode(stringifyPrimitive(key))}${eq}`
+        if (Array.isArray(value)) {
+          return value
+            .map((item) => `${prefix}${encode(stringifyPrimitive(item))}`)
+            .join(sep)
+        }
+        return `${prefix}${encode(stringifyPrimitive(value))}`
+      })
+      // Empty arrays have no pairs.
+      .filter(Boolean)
+      .join(sep)
+  )
+}
+
+export function parse(qs, sep = '&', eq = '=', options) {
+  const decode = options?.decodeURIComponent ?? unescape
+  const maxKeys = options?.maxKeys ?? 1000
+  const result = Object.create(null)
+  if (typeof qs !== 'string' || qs.length === 0) {
+    return result
+  }
+  let pairs = qs.split(sep)
+  if (maxKeys > 0) {
+    pairs = pairs.slice(0, maxKeys)
+  }
+  for (const pair of pairs) {
+    if (pair.length === 0) {
+      continue
+    }
+    const index = pair.indexOf(eq)
+    const rawKey = index >= 0 ? pair.slice(0, index) : pair
+    const rawValue = index >= 0 ? pair.slice(index + eq.length) : ''
+    const key = decode(rawKey.replace(/\+/g, ' '))
+    const value = decode(rawValue.replace(/\+/g, ' '))
+    if (!(key in result)) {
+      result[key] = value
+    } else if (Array.isArray(result[key])) {
+      result[key].push(value)
+    } else {
+      result[key] = [result[key], value]
+    }
+  }
+  return result
+}
+
+export { parse as decode, stringify as encode }
+
+export default {
+  decode: parse,
+  encode: stringify,
+  escape,
+  parse,
+  stringify,
+  unescape,
+}
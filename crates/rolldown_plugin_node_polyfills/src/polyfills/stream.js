@@ -0,0 +1,740 @@
+// A browser implementation of node's `stream`. It covers reading, writing, piping and transforming,
+// but backpressure is only reported by `write()` rather than enforced. Like `events`, the streams are
+// plain constructors so that old packages can still inherit from them with `util.inherits`.
+import EventEmitter from '\0polyfill-node.events.js'
+import { inherits } from '\0polyfill-node.util.js'
+
+const DEFAULT_HIGH_WATER_MARK = 16 * 1024
+const DEFAULT_OBJECT_HIGH_WATER_MARK = 16
+
+function chunkLength(state, chunk) {
+  return state.objectMode ? 1 : chunk.length
+}
+
+function concat(chunks) {
+  if (chunks.length === 1) {
+    return chunks[0]
+  }
+  if (typeof chunks[0] === 'string') {
+    return chunks.join('')
+  }
+  const result = new Uint8Array(
+    chunks.reduce((length, chunk) => length + chunk.length, 0),
+  )
+  let offset = 0
+  for (const chunk of chunks) {
+    result.set(chunk, offset)
+    offset += chunk.length
+  }
+  return result
+}
+
+function emitClose(stream) {
+  if (stream._closeEmitted) {
+    return
+  }
+  stream._closeEmitted = true
+  stream.emit('close')
+}
+
+// Streams close once all of their sides are done.
+function maybeClose(stream) {
+  const readableState = stream._readableState
+  const writableState = stream._writableState
+  if (
+    (readableState === undefined || readableState.endEmitted) &&
+    (writableState === undefined || writableState.finished)
+  ) {
+    emitClose(stream)
+  }
+}
+
+function destroy(error) {
+  const readableState = this._readableState
+  const writableState = this._writableState
+  if (this.destroyed) {
+    return this
+  }
+  this.destroyed = true
+  if (readableState !== undefined) {
+    readableState.destroyed = true
+  }
+  if (writableState !== undefined) {
+    writableState.destroyed = true
+  }
+  this._destroy(error ?? null, (err) => {
+    queueMicrotask(() => {
+      if (err) {
+        this.emit('error', err)
+      }
+      emitClose(this)
+    })
+  })
+  return this
+}
+
+export function Stream(options) {
+  EventEmitter.call(this, options)
+}
+
+inherits(Stream, EventEmitter)
+
+Stream.prototype.destroyed = false
+
+Stream.prototype._destroy = function (error, callback) {
+  callback(error)
+}
+
+Stream.prototype.pipe = function (dest, options = {}) {
+  const onData = (chunk) => {
+    if (dest.write(chunk) === false && typeof this.pause === 'function') {
+      this.pause()
+    }
+  }
+  const onDrain = () => {
+    if (typeof this.resume === 'function') {
+      this.resume()
+    }
+  }
+  const onEnd = () => {
+    if (options.end !== false) {
+      dest.end()
+    }
+  }
+  const cleanup = () => {
+    this.removeListener('data', onData)
+    this.removeListener('end', onEnd)
+    dest.removeListener('drain', onDrain)
+  }
+  if (this._readableState !== undefined) {
+    this._readableState.pipes.push({ dest, cleanup })
+  }
+  this.on('data', onData)
+  this.once('end', onEnd)
+  dest.on('drain', onDrain)
+  dest.emit('pipe', this)
+  return dest
+}
+
+export function Readable(options = {}) {
+  if (!(this instanceof Readable)) {
+    return new Readable(options)
+  }
+  Stream.call(this, options)
+  const objectMode = !!(options.objectMode || options.readableObjectMode)
+  this._readableState = {
+    objectMode,
+    highWaterMark:
+      options.readableHighWaterMark ??
+      options.highWaterMark ??
+      (objectMode ? DEFAULT_OBJECT_HIGH_WATER_MARK : DEFAULT_HIGH_WATER_MARK),
+    buffer: [],
+    length: 0,
+    decoder: null,
+    // `null` until the stream is consumed in either way.
+    flowing: null,
+    readableListening: false,
+    readCalled: false,
+    reading: false,
+    ended: false,
+    endEmitted: false,
+    scheduled: false,
+    destroyed: false,
+    pipes: [],
+  }
+  this.readable = true
+  if (options.encoding) {
+    this.setEncoding(options.encoding)
+  }
+  if (typeof options.read === 'function') {
+    this._read = options.read
+  }
+  if (typeof options.destroy === 'function') {
+    this._destroy = options.destroy
+  }
+}
+
+inherits(Readable, Stream)
+
+function endEmit(stream) {
+  const state = stream._readableState
+  if (state.endEmitted) {
+    return
+  }
+  state.endEmitted = true
+  stream.readable = false
+  stream.emit('end')
+  maybeClose(stream)
+}
+
+function tick(stream) {
+  const state = stream._readableState
+  if (
+    state.destroyed ||
+    state.endEmitted ||
+    (state.flowing === null && !state.readableListening && !state.readCalled)
+  ) {
+    return
+  }
+  if (state.flowing) {
+    while (state.flowing && state.buffer.length > 0) {
+      const chunk = state.buffer.shift()
+      state.length -= chunkLength(state, chunk)
+      stream.emit('data', chunk)
+    }
+  } else if (state.buffer.length > 0 || state.ended) {
+    stream.emit('readable')
+  }
+  if (state.ended) {
+    if (state.buffer.length === 0) {
+      endEmit(stream)
+    }
+  } else if (
+    !state.reading &&
+    state.length < state.highWaterMark &&
+    (state.flowing || state.readableListening || state.readCalled)
+  ) {
+    state.reading = true
+    stream._read(state.highWaterMark)
+  }
+}
+
+function scheduleTick(stream) {
+  const state = stream._readableState
+  if (state.scheduled) {
+    return
+  }
+  state.scheduled = true
+  queueMicrotask(() => {
+    state.scheduled = false
+    tick(stream)
+  })
+}
+
+function addChunk(stream, chunk, prepend) {
+  const state = stream._readableState
+  state.reading = false
+  if (chunk === null) {
+    state.ended = true
+  } else if (state.ended && !prepend) {
+    stream.destroy(new Error('stream.push() after EOF'))
+    return false
+  } else {
+    if (state.decoder !== null && typeof chunk !== 'string') {
+      chunk = state.decoder.decode(chunk, { stream: true })
+    }
+    if (prepend) {
+      state.buffer.unshift(chunk)
+    } else {
+      state.buffer.push(chunk)
+    }
+    state.length += chunkLength(state, chunk)
+  }
+  scheduleTick(stream)
+  return !state.ended && state.length < state.highWaterMark
+}
+
+Readable.prototype.destroy = destroy
+
+Readable.prototype._read = function () {
+  this.destroy(new Error('The _read() method is not implemented'))
+}
+
+Readable.prototype.push = function (chunk) {
+  return addChunk(this, chunk, false)
+}
+
+Readable.prototype.unshift = function (chunk) {
+  return addChunk(this, chunk, true)
+}
+
+Readable.prototype.read = function (n) {
+  const state = this._readableState
+  state.readCalled = true
+  scheduleTick(this)
+  if (state.buffer.length === 0) {
+    return null
+  }
+  if (state.objectMode) {
+    state.length--
+    return state.buffer.shift()
+  }
+  if (n !== undefined && n > state.length && !state.ended) {
+    return null
+  }
+  const data = concat(state.buffer)
+  const size = n === undefined || n > data.length ? data.length : n
+  state.buffer = size < data.length ? [data.slice(size)] : []
+  state.length -= size
+  return data.slice(0, size)
+}
+
+Readable.prototype.on = function (type, listener) {
+  const result = Stream.prototype.on.call(this, type, listener)
+  const state = this._readableState
+  if (type === 'data') {
+    if (state.flowing !== false) {
+      this.resume()
+    }
+  } else if (type === 'readable') {
+    state.readableListening = true
+    state.flowing = false
+    scheduleTick(this)
+  }
+  return result
+}
+
+Readable.prototype.addListener = Readable.prototype.on
+
+Readable.prototype.resume = function () {
+  const state = this._readableState
+  if (!state.flowing) {
+    state.flowing = true
+    this.emit('resume')
+  }
+  scheduleTick(this)
+  return this
+}
+
+Readable.prototype.pause = function () {
+  const state = this._readableState
+  if (state.flowing !== false) {
+    state.flowing = false
+    this.emit('pause')
+  }
+  return this
+}
+
+Readable.prototype.isPaused = function () {
+  return this._readableState.flowing === false
+}
+
+Readable.prototype.setEncoding = function (encoding) {
+  this._readableState.decoder = new TextDecoder(encoding)
+  return this
+}
+
+Readable.prototype.unpipe = function (dest) {
+  const state = this._readableState
+  state.pipes = state.pipes.filter((pipe) => {
+    if (dest !== undefined && pipe.dest !== dest) {
+      return true
+    }
+    pipe.cleanup()
+    pipe.dest.emit('unpipe', this)
+    return false
+  })
+  return this
+}
+
+Readable.prototype[Symbol.asyncIterator] = async function* () {
+  const state = this._readableState
+  let error = null
+  let wake = null
+  const onChange = () => {
+    if (wake !== null) {
+      wake()
+      wake = null
+    }
+  }
+  const onError = (err) => {
+    error = err
+    onChange()
+  }
+  this.on('readable', onChange)
+  this.on('end', onChange)
+  this.on('error', onError)
+  try {
+    while (true) {
+      const chunk = this.read()
+      if (chunk !== null) {
+        yield chunk
+        continue
+      }
+      if (error !== null) {
+        throw error
+      }
+      if (state.endEmitted) {
+        return
+      }
+      await new Promise((resolve) => {
+        wake = resolve
+      })
+    }
+  } finally {
+    this.removeListener('readable', onChange)
+    this.removeListener('end', onChange)
+    this.removeListener('error', onError)
+    if (!state.endEmitted) {
+      this.destroy()
+    }
+  }
+}
+
+Readable.from = function (iterable, options) {
+  const iterator = iterable[Symbol.asyncIterator]
+    ? iterable[Symbol.asyncIterator]()
+    : iterable[Symbol.iterator]()
+  let reading = false
+  return new Readable({
+    objectMode: true,
+    ...options,
+    read() {
+      if (reading) {
+        return
+      }
+      reading = true
+      Promise.resolve(iterator.next()).then(
+        ({ value, done }) => {
+          reading = false
+          this.push(done ? null : value)
+        },
+        (error) => this.destroy(error),
+      )
+    },
+  })
+}
+
+function initWritable(stream, options) {
+  const objectMode = !!(options.objectMode || options.writableObjectMode)
+  stream._writableState = {
+    objectMode,
+    highWaterMark:
+      options.writableHighWaterMark ??
+      options.highWaterMark ??
+      (objectMode ? DEFAULT_OBJECT_HIGH_WATER_MARK : DEFAULT_HIGH_WATER_MARK),
+    buffer: [],
+    length: 0,
+    corked: 0,
+    writing: false,
+    needDrain: false,
+    ending: false,
+    finishing: false,
+    finished: false,
+    destroyed: false,
+  }
+  stream.writable = true
+  if (typeof options.write === 'function') {
+    stream._write = options.write
+  }
+  if (typeof options.final === 'function') {
+    stream._final = options.final
+  }
+  if (typeof options.destroy === 'function') {
+    stream._destroy = options.destroy
+  }
+}
+
+export function Writable(options = {}) {
+  if (!(this instanceof Writable)) {
+    return new Writable(options)
+  }
+  Stream.call(this, options)
+  initWritable(this, options)
+}
+
+inherits(Writable, Stream)
+
+function finish(stream) {
+  const state = stream._writableState
+  if (state.finishing) {
+    return
+  }
+  state.finishing = true
+  const done = (error) => {
+    if (error) {
+      stream.destroy(error)
+      return
+    }
+    state.finished = true
+    stream.emit('finish')
+    maybeClose(stream)
+  }
+  if (typeof stream._final === 'function') {
+    stream._final(done)
+  } else {
+    queueMicrotask(() => done())
+  }
+}
+
+function writeNext(stream) {
+  const state = stream._writableState
+  if (state.destroyed) {
+    return
+  }
+  const entry = state.buffer.shift()
+  if (entry === undefined) {
+    if (state.ending) {
+      finish(stream)
+    }
+    return
+  }
+  state.writing = true
+  stream._write(entry.chunk, entry.encoding, (error) => {
+    state.writing = false
+    state.length -= chunkLength(state, entry.chunk)
+    if (error) {
+      entry.callback?.(error)
+      stream.destroy(error)
+      return
+    }
+    queueMicrotask(() => {
+      entry.callback?.(null)
+      if (state.needDrain && state.length === 0) {
+        state.needDrain = false
+        stream.emit('drain')
+      }
+      if (state.corked === 0) {
+        writeNext(stream)
+      }
+    })
+  })
+}
+
+Writable.prototype.destroy = destroy
+
+Writable.prototype._write = function (chunk, encoding, callback) {
+  callback(new Error('The _write() method is not implemented'))
+}
+
+Writable.prototype.write = function (chunk, encoding, callback) {
+  if (typeof encoding === 'function') {
+    callback = encoding
+    encoding = undefined
+  }
+  const state = this._writableState
+  if (state.ending || state.destroyed) {
+    const error = new Error('write after end')
+    queueMicrotask(() => {
+      callback?.(error)
+      this.emit('error', error)
+    })
+    return false
+  }
+  state.length += chunkLength(state, chunk)
+  const ok = state.length < state.highWaterMark
+  if (!ok) {
+    state.needDrain = true
+  }
+  state.buffer.push({ chunk, encoding: encoding ?? 'utf8', callback })
+  if (!state.writing && state.corked === 0) {
+    writeNext(this)
+  }
+  return ok
+}
+
+Writable.prototype.end = function (chunk, encoding, callback) {
+  if (typeof chunk === 'function') {
+    callback = chunk
+    chunk = null
+  } else if (typeof encoding === 'function') {
+    callback = encoding
+    encoding = undefined
+  }
+  if (chunk !== null && chunk !== undefined) {
+    this.write(chunk, encoding)
+  }
+  if (callback) {
+    this.once('finish', callback)
+  }
+  const state = this._writableState
+  if (!state.ending) {
+    state.ending = true
+    state.corked = 0
+    this.writable = false
+    if (!state.writing) {
+      writeNext(this)
+    }
+  }
+  return this
+}
+
+Writable.prototype.cork = function () {
+  this._writableState.corked++
+}
+
+Writable.prototype.uncork = function () {
+  const state = this._writableState
+  if (state.corked > 0 && --state.corked === 0 && !state.writing) {
+    writeNext(this)
+  }
+}
+
+Writable.prototype.setDefaultEncoding = function () {
+  return this
+}
+
+export function Duplex(options = {}) {
+  if (!(this instanceof Duplex)) {
+    return new Duplex(options)
+  }
+  Readable.call(this, options)
+  initWritable(this, options)
+  this.allowHalfOpen = options.allowHalfOpen !== false
+  if (!this.allowHalfOpen) {
+    this.once('end', () => this.end())
+  }
+}
+
+inherits(Duplex, Readable)
+
+for (const method of [
+  '_write',
+  'write',
+  'end',
+  'cork',
+  'uncork',
+  'setDefaultEncoding',
+]) {
+  Duplex.prototype[method] = Writable.prototype[method]
+}
+
+export function Transform(options = {}) {
+  if (!(this instanceof Transform)) {
+    return new Transform(options)
+  }
+  Duplex.call(this, options)
+  if (typeof options.transform === 'function') {
+    this._transform = options.transform
+  }
+  if (typeof options.flush === 'function') {
+    this._flush = options.flush
+  }
+}
+
+inherits(Transform, Duplex)
+
+Transform.prototype._transform = function (chunk, encoding, callback) {
+  callback(new Error('The _transform() method is not implemented'))
+}
+
+Transform.prototype._write = function (chunk, encoding, callback) {
+  this._transform(chunk, encoding, (error, data) => {
+    if (error) {
+      callback(error)
+      return
+    }
+    if (data !== undefined && data !== null) {
+      this.push(data)
+    }
+    callback()
+  })
+}
+
+Transform.prototype._final = function (callback) {
+  const done = (error, data) => {
+    if (error) {
+      callback(error)
+      return
+    }
+    if (data !== undefined && data !== null) {
+      this.push(data)
+    }
+    this.push(null)
+    callback()
+  }
+  if (typeof this._flush === 'function') {
+    this._flush(done)
+  } else {
+    done()
+  }
+}
+
+// Chunks are pushed as soon as they are transformed.
+Transform.prototype._read = function () {}
+
+export function PassThrough(options) {
+  if (!(this instanceof PassThrough)) {
+    return new PassThrough(options)
+  }
+  Transform.call(this, options)
+}
+
+inherits(PassThrough, Transform)
+
+PassThrough.prototype._transform = function (chunk, encoding, callback) {
+  callback(null, chunk)
+}
+
+export function finished(stream, callback) {
+  const readableState = stream._readableState
+  const writableState = stream._writableState
+  const cleanup = () => {
+    stream.removeListener('end', onEnd)
+    stream.removeListener('finish', onFinish)
+    stream.removeListener('error', done)
+    stream.removeListener('close', onClose)
+  }
+  function done(error) {
+    cleanup()
+    callback.call(stream, error)
+  }
+  const onEnd = () => {
+    if (writableState === undefined || writableState.finished) {
+      done()
+    }
+  }
+  const onFinish = () => {
+    if (readableState === undefined || readableState.endEmitted) {
+      done()
+    }
+  }
+  const onClose = () => {
+    if (
+      (readableState !== undefined && !readableState.endEmitted) ||
+      (writableState !== undefined && !writableState.finished)
+    ) {
+      done(new Error('Premature close'))
+    }
+  }
+  stream.on('end', onEnd)
+  stream.on('finish', onFinish)
+  stream.on('error', done)
+  stream.on('close', onClose)
+  return cleanup
+}
+
+export function pipeline(...streams) {
+  const callback =
+    typeof streams[streams.length - 1] === 'function' ? streams.pop() : () => {}
+  if (Array.isArray(streams[0])) {
+    streams = streams[0]
+  }
+  let called = false
+  const done = (error) => {
+    if (called) {
+      return
+    }
+    called = true
+    if (error) {
+      for (const stream of streams) {
+        stream.destroy()
+      }
+    }
+    callback(error)
+  }
+  streams.forEach((stream, i) => {
+    if (i < streams.length - 1) {
+      stream.pipe(streams[i + 1])
+    }
+    finished(stream, (error) => {
+      if (error) {
+        done(error)
+      } else if (i === streams.length - 1) {
+        done()
+      }
+    })
+  })
+  return streams[streams.length - 1]
+}
+
+Stream.Stream = Stream
+Stream.Readable = Readable
+Stream.Writable = Writable
+Stream.Duplex = Duplex
+Stream.Transform = Transform
+Stream.PassThrough = PassThrough
+Stream.finished = finished
+Stream.pipeline = pipeline
+
+export default Stream
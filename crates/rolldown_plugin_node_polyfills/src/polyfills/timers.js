@@ -0,0 +1,34 @@
+// Node's `timers` backed by the timers of browsers. `setImmediate` is a timeout without delay.
+
+export function setTimeout(callback, delay, ...args) {
+  return globalThis.setTimeout(callback, delay, ...args)
+}
+
+export function clearTimeout(id) {
+  globalThis.clearTimeout(id)
+}
+
+export function setInterval(callback, delay, ...args) {
+  return globalThis.setInterval(callback, delay, ...args)
+}
+
+export function clearInterval(id) {
+  globalThis.clearInterval(id)
+}
+
+export function setImmediate(callback, ...args) {
+  return globalThis.setTimeout(callback, 0, ...args)
+}
+
+export function clearImmediate(id) {
+  globalThis.clearTimeout(id)
+}
+
+export default {
+  setTimeout,
+  clearTimeout,
+  setInterval,
+  clearInterval,
+  setImmediate,
+  clearImmediate,
+}
@@ -0,0 +1,15 @@
+// Browsers have no terminals, so nothing is a tty.
+
+export function isatty() {
+  return false
+}
+
+export function ReadStream() {
+  throw new Error('tty.ReadStream is not supported in browsers')
+}
+
+export function WriteStream() {
+  throw new Error('tty.WriteStream is not supported in browsers')
+}
+
+export default { isatty, ReadStream, WriteStream }
@@ -0,0 +1,244 @@
+// A browser implementation of the commonly used parts of node's `util`.
+
+export function inherits(ctor, superCtor) {
+  Object.defineProperty(ctor, 'super_', {
+    value: superCtor,
+    writable: true,
+    configurable: true,
+  })
+  Object.setPrototypeOf(ctor.prototype, superCtor.prototype)
+}
+
+function formatValue(value, depth, seen) {
+  switch (typeof value) {
+    case 'string':
+      return `'${value}'`
+    case 'bigint':
+      return `${value}n`
+    case 'symbol':
+      return value.toString()
+    case 'function':
+      return `[Function: ${value.name || '(anonymous)'}]`
+    case 'object':
+      break
+    default:
+      return String(value)
+  }
+  if (value === null) {
+    return 'null'
+  }
+  if (value instanceof Error) {
+    return value.stack || String(value)
+  }
+  if (value instanceof Date) {
+    return value.toISOString()
+  }
+  if (value instanceof RegExp) {
+    return String(value)
+  }
+  if (seen.has(value)) {
+    return '[Circular]'
+  }
+  const isArray = Array.isArray(value)
+  if (depth < 0) {
+    return isArray ? '[Array]' : '[Object]'
+  }
+  seen.add(value)
+  const entries = isArray
+    ? value.map((item) => formatValue(item, depth - 1, seen))
+    : Object.keys(value).map((key) => {
+        const name = /^[A-Za-z_$][\w$]*$/.test(key) ? key : `'${key}'`
+        return `${name}: ${formatValue(value[key], depth - 1, seen)}`
+      })
+  seen.delete(value)
+  if (entries.length === 0) {
+    return isArray ? '[]' : '{}'
+  }
+  return isArray ? `[ ${entries.join(', ')} ]` : `{ ${entries.join(', ')} }`
+}
+
+export function inspect(value, options = {}) {
+  return formatValue(value, options.depth ?? 2, new Set())
+}
+
+function formatArg(arg) {
+  return typeof arg === 'string' ? arg : inspect(arg)
+}
+
+export function format(template, ...args) {
+  if (typeof template !== 'string') {
+    return [template, ...args].map(formatArg).join(' ')
+  }
+  let index = 0
+  let str = template.replace(/%[sdifjoO%]/g, (token) => {
+    if (token === '%%') {
+      return '%'
+    }
+    if (index >= args.length) {
+      return token
+    }
+    const arg = args[index++]
+    if (typeof arg === 'bigint' && ['%s', '%d', '%i'].includes(token)) {
+      return `${arg}n`
+    }
+    switch (token) {
+      case '%s':
+        return typeof arg === 'object' && arg !== null
+          ? inspect(arg)
+          : String(arg)
+      case '%d':
+        return String(Number(arg))
+      case '%i':
+        return String(parseInt(arg))
+      case '%f':
+        return String(parseFloat(arg))
+      case '%j':
+        try {
+          return JSON.stringify(arg)
+        } catch {
+          return '[Circular]'
+        }
+      default:
+        return inspect(arg)
+    }
+  })
+  for (const arg of args.slice(index)) {
+    str += ` ${formatArg(arg)}`
+  }
+  return str
+}
+
+export function deprecate(fn, message) {
+  let warned = false
+  return function (...args) {
+    if (!warned) {
+      console.warn(`DeprecationWarning: ${message}`)
+      warned = true
+    }
+    return fn.apply(this, args)
+  }
+}
+
+export function debuglog() {
+  return () => {}
+}
+
+const customPromisify = Symbol.for('nodejs.util.promisify.custom')
+
+export function promisify(original) {
+  if (typeof original[customPromisify] === 'function') {
+    return original[customPromisify]
+  }
+  return function (...args) {
+    return new Promise((resolve, reject) => {
+      original.call(this, ...args, (error, value) =>
+        error ? reject(error) : resolve(value),
+      )
+    })
+  }
+}
+
+promisify.custom = customPromisify
+
+export function callbackify(original) {
+  return function (...args) {
+    const callback = args.pop()
+    original.apply(this, args).then(
+      (value) => queueMicrotask(() => callback(null, value)),
+      (error) => queueMicrotask(() => callback(error)),
+    )
+  }
+}
+
+export const isArray = Array.isArray
+
+export function isBoolean(value) {
+  return typeof value === 'boolean'
+}
+
+export function isNull(value) {
+  return value === null
+}
+
+export function isNullOrUndefined(value) {
+  return value === null || value === undefined
+}
+
+export function isNumber(value) {
+  return typeof value === 'number'
+}
+
+export function isString(value) {
+  return typeof value === 'string'
+}
+
+export function isSymbol(value) {
+  return typeof value === 'symbol'
+}
+
+export function isUndefined(value) {
+  return value === undefined
+}
+
+export function isRegExp(value) {
+  return value instanceof RegExp
+}
+
+export function isObject(value) {
+  return typeof value === 'object' && value !== null
+}
+
+export function isDate(value) {
+  return value instanceof Date
+}
+
+export function isError(value) {
+  return value instanceof Error
+}
+
+export function isFunction(value) {
+  return typeof value === 'function'
+}
+
+export function isPrimitive(value) {
+  return (
+    value === null || (typeof value !== 'object' && typeof value !== 'function')
+  )
+}
+
+export const types = {
+  isDate,
+  isRegExp,
+  isNativeError: isError,
+  isPromise: (value) => value instanceof Promise,
+}
+
+export const TextEncoder = globalThis.TextEncoder
+export const TextDecoder = globalThis.TextDecoder
+
+export default {
+  inherits,
+  inspect,
+  format,
+  deprecate,
+  debuglog,
+  promisify,
+  callbackify,
+  isArray,
+  isBoolean,
+  isNull,
+  isNullOrUndefined,
+  isNumber,
+  isString,
+  isSymbol,
+  isUndefined,
+  isRegExp,
+  isObject,
+  isDate,
+  isError,
+  isFunction,
+  isPrimitive,
+  types,
+  TextEncoder,
+  TextDecoder,
+}
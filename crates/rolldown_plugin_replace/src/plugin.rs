@@ -1,11 +1,14 @@
 use std::ops::Range;
+use std::path::Path;
 use std::{cmp::Reverse, collections::HashMap, sync::LazyLock};
 
 // use fancy_regex::Regex;
 use regex::Regex;
 use rolldown_plugin::{HookRenderChunkOutput, HookTransformOutput, Plugin};
+use rolldown_utils::pattern_filter::{self, StringOrRegex};
 use rustc_hash::FxHashMap;
 use string_wizard::MagicString;
+use sugar_path::SugarPath;
 
 use crate::utils::expand_typeof_replacements;
 
@@ -16,6 +19,10 @@ pub struct ReplaceOptions {
   pub delimiters: Option<(String, String)>,
   pub prevent_assignment: bool,
   pub object_guards: bool,
+  /// Globs are matched against paths relative to `cwd` in `transform`, and against file names of chunks in
+  /// `render_chunk`. Regexes are matched against the ids and file names as is.
+  pub include: Vec<StringOrRegex>,
+  pub exclude: Vec<StringOrRegex>,
 }
 
 // We don't reuse `HybridRegex` in `rolldown_utils`, since
@@ -31,6 +38,8 @@ pub struct ReplacePlugin {
   matcher: HybridRegex,
   prevent_assignment: bool,
   values: FxHashMap</* Target */ String, /* Replacement */ String>,
+  include: Vec<StringOrRegex>,
+  exclude: Vec<StringOrRegex>,
 }
 
 static NON_ASSIGNMENT_MATCHER: LazyLock<Regex> =
@@ -65,9 +74,15 @@ impl ReplacePlugin {
       matcher,
       prevent_assignment: options.prevent_assignment,
       values: values.into_iter().collect(),
+      include: options.include,
+      exclude: options.exclude,
     }
   }
 
+  fn filter(&self, id: &str, stable_id: &str) -> bool {
+    pattern_filter::filter(Some(&self.exclude), Some(&self.include), id, stable_id).inner()
+  }
+
   fn try_replace<'text>(
     &'text self,
     code: &'text str,
@@ -157,9 +172,13 @@ impl Plugin for ReplacePlugin {
 
   async fn transform(
     &self,
-    _ctx: rolldown_plugin::SharedTransformPluginContext,
+    ctx: rolldown_plugin::SharedTransformPluginContext,
     args: &rolldown_plugin::HookTransformArgs<'_>,
   ) -> rolldown_plugin::HookTransformReturn {
+    let stable_id = Path::new(args.id).relative(ctx.inner.cwd());
+    if !self.filter(args.id, &stable_id.to_string_lossy()) {
+      return Ok(None);
+    }
     let mut magic_string = MagicString::new(args.code);
     if self.try_replace(args.code, &mut magic_string) {
       return Ok(Some(HookTransformOutput {
@@ -175,6 +194,9 @@ impl Plugin for ReplacePlugin {
     _ctx: &rolldown_plugin::PluginContext,
     args: &rolldown_plugin::HookRenderChunkArgs<'_>,
   ) -> rolldown_plugin::HookRenderChunkReturn {
+    if !self.filter(&args.chunk.filename, &args.chunk.filename) {
+      return Ok(None);
+    }
     let mut magic_string = MagicString::new(&args.code);
     if self.try_replace(&args.code, &mut magic_string) {
       return Ok(Some(HookRenderChunkOutput { code: magic_string.to_string(), map: None }));
@@ -0,0 +1,28 @@
+use std::sync::Arc;
+
+use rolldown::BundlerOptions;
+
+use rolldown_plugin_replace::{ReplaceOptions, ReplacePlugin};
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+use rolldown_utils::pattern_filter::StringOrRegex;
+
+// doesn't replace in modules and chunks matched by `exclude`
+#[tokio::test(flavor = "multi_thread")]
+async fn exclude() {
+  let cwd = abs_file_dir!();
+
+  IntegrationTest::new(TestMeta { expect_executed: false, ..Default::default() })
+    .run_with_plugins(
+      BundlerOptions {
+        input: Some(vec!["./input.js".to_string().into(), "./other.js".to_string().into()]),
+        cwd: Some(cwd),
+        ..Default::default()
+      },
+      vec![Arc::new(ReplacePlugin::with_options(ReplaceOptions {
+        values: [("__VERSION__".to_string(), "\"1.0.0\"".to_string())].into(),
+        exclude: vec![StringOrRegex::String("other.js".to_string())],
+        ..Default::default()
+      }))],
+    )
+    .await;
+}
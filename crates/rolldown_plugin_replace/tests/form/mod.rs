@@ -1,5 +1,6 @@
 mod assignment;
 mod delimiters;
+mod exclude;
 mod match_variables;
 mod process_check;
 mod replace_nothing;
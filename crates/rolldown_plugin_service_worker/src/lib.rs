@@ -0,0 +1,164 @@
+use std::{borrow::Cow, path::Path};
+
+use oxc::{
+  ast::{
+    ast::{Class, Expression, Function},
+    visit::walk_mut,
+    AstBuilder, VisitMut,
+  },
+  semantic::ScopeFlags,
+};
+use rolldown_common::{Output, SourceMapType};
+use rolldown_plugin::{
+  HookNoopReturn, HookTransformAstArgs, HookTransformAstReturn, Plugin, PluginContext,
+};
+use rolldown_utils::xxhash::xxhash_base64_url;
+use serde::Serialize;
+use sugar_path::SugarPath;
+
+use self::utils::replace_placeholder;
+mod utils;
+
+pub const DEFAULT_PRECACHE_MANIFEST_PLACEHOLDER: &str = "self.__ROLLDOWN_PRECACHE_MANIFEST";
+
+const INLINE_SOURCEMAP_COMMENT: &str = "//# sourceMappingURL=";
+
+#[derive(Debug)]
+pub struct ServiceWorkerPlugin {
+  pub config: ServiceWorkerPluginConfig,
+}
+
+#[derive(Debug)]
+pub struct ServiceWorkerPluginConfig {
+  /// Path of the service worker entry, relative to `cwd`. It needs to be part of `input` as well.
+  pub entry: String,
+  /// Expression in the service worker that gets replaced with the precache manifest.
+  pub placeholder: String,
+}
+
+impl Default for ServiceWorkerPluginConfig {
+  fn default() -> Self {
+    Self {
+      entry: String::default(),
+      placeholder: DEFAULT_PRECACHE_MANIFEST_PLACEHOLDER.to_string(),
+    }
+  }
+}
+
+/// Same shape as the entries that `workbox-precaching` accepts.
+#[derive(Debug, Serialize)]
+struct PrecacheEntry<'a> {
+  url: &'a str,
+  revision: String,
+}
+
+impl Plugin for ServiceWorkerPlugin {
+  fn name(&self) -> Cow<'static, str> {
+    Cow::Borrowed("builtin:service-worker")
+  }
+
+  fn transform_ast(
+    &self,
+    ctx: &PluginContext,
+    mut args: HookTransformAstArgs,
+  ) -> HookTransformAstReturn {
+    if args.id != self.entry_id(ctx.cwd()) {
+      return Ok(args.ast);
+    }
+    // The service worker runs in `ServiceWorkerGlobalScope`, where top-level `this` refers to `self`.
+    args.ast.program.with_mut(|fields| {
+      let mut visitor = TopLevelThisVisit { ast_builder: AstBuilder::new(fields.allocator) };
+      visitor.visit_program(fields.program);
+    });
+    Ok(args.ast)
+  }
+
+  async fn generate_bundle(
+    &self,
+    ctx: &PluginContext,
+    bundle: &mut Vec<Output>,
+    _is_write: bool,
+  ) -> HookNoopReturn {
+    let entry_id = self.entry_id(ctx.cwd());
+    let is_service_worker = |output: &Output| match output {
+      Output::Chunk(chunk) => chunk.facade_module_id.as_deref() == Some(entry_id.as_str()),
+      Output::Asset(_) => false,
+    };
+
+    let manifest = bundle
+      .iter()
+      .filter(|output| {
+        !is_service_worker(output)
+          && !Path::new(output.filename()).extension().is_some_and(|ext| ext == "map")
+      })
+      .map(|output| PrecacheEntry {
+        url: output.filename(),
+        revision: xxhash_base64_url(output.content_as_bytes()),
+      })
+      .collect::<Vec<_>>();
+    let manifest = serde_json::to_string(&manifest)?;
+
+    // `.map` files are already emitted at this point, so they are updated along with the code.
+    let mut updated_maps = vec![];
+    for output in bundle.iter_mut() {
+      if !is_service_worker(output) {
+        continue;
+      }
+      let Output::Chunk(chunk) = output else { continue };
+      let Some(replaced) = replace_placeholder(&chunk.code, &self.config.placeholder, &manifest)
+      else {
+        continue;
+      };
+      let map = chunk.map.as_ref().map(|map| replaced.update_sourcemap(map));
+      chunk.code = replaced.code;
+      let Some(map) = map else { continue };
+      if matches!(ctx.options().sourcemap, Some(SourceMapType::Inline)) {
+        if let Some(index) = chunk.code.rfind(INLINE_SOURCEMAP_COMMENT) {
+          chunk.code.truncate(index);
+          chunk.code.push_str(INLINE_SOURCEMAP_COMMENT);
+          chunk.code.push_str(&map.to_data_url());
+        }
+      }
+      if let Some(sourcemap_filename) = &chunk.sourcemap_filename {
+        updated_maps.push((sourcemap_filename.clone(), map.to_json_string()));
+      }
+      chunk.map = Some(map);
+    }
+    for output in bundle.iter_mut() {
+      let Output::Asset(asset) = output else { continue };
+      if let Some((_, source)) =
+        updated_maps.iter().find(|(filename, _)| asset.filename.as_str() == filename)
+      {
+        asset.source = source.clone().into();
+      }
+    }
+
+    Ok(())
+  }
+}
+
+impl ServiceWorkerPlugin {
+  fn entry_id(&self, cwd: &Path) -> String {
+    cwd.join(&self.config.entry).normalize().to_string_lossy().into_owned()
+  }
+}
+
+struct TopLevelThisVisit<'ast> {
+  ast_builder: AstBuilder<'ast>,
+}
+
+impl<'ast> VisitMut<'ast> for TopLevelThisVisit<'ast> {
+  fn visit_expression(&mut self, expr: &mut Expression<'ast>) {
+    if let Expression::ThisExpression(this_expr) = expr {
+      let span = this_expr.span;
+      *expr = self.ast_builder.expression_identifier_reference(span, "self");
+      return;
+    }
+    walk_mut::walk_expression(self, expr);
+  }
+
+  // `this` is rebound inside of functions and classes, so they are left untouched.
+  fn visit_function(&mut self, _it: &mut Function<'ast>, _flags: ScopeFlags) {}
+
+  fn visit_class(&mut self, _it: &mut Class<'ast>) {}
+}
@@ -0,0 +1,183 @@
+use oxc::{
+  sourcemap::{SourceMap, Token},
+  syntax::identifier::is_identifier_part,
+};
+
+pub(crate) struct ReplacedPlaceholder {
+  pub code: String,
+  /// Line and utf-16 column of every replaced placeholder in the original code.
+  positions: Vec<(u32, u32)>,
+  placeholder_len: u32,
+  replacement_len: u32,
+}
+
+/// Replaces the occurrences of `placeholder` that aren't part of a longer identifier or member
+/// expression, e.g. `self.__ROLLDOWN_PRECACHE_MANIFEST_V2` or `a.self.__ROLLDOWN_PRECACHE_MANIFEST`.
+/// Returns `None` if there is nothing to replace.
+#[allow(clippy::cast_possible_truncation)]
+pub(crate) fn replace_placeholder(
+  code: &str,
+  placeholder: &str,
+  replacement: &str,
+) -> Option<ReplacedPlaceholder> {
+  let starts = code
+    .match_indices(placeholder)
+    .map(|(start, _)| start)
+    .filter(|&start| {
+      let before = code[..start].chars().next_back();
+      let after = code[start + placeholder.len()..].chars().next();
+      !before.is_some_and(|c| is_identifier_part(c) || c == '.')
+        && !after.is_some_and(is_identifier_part)
+    })
+    .collect::<Vec<_>>();
+  if starts.is_empty() {
+    return None;
+  }
+
+  let mut replaced = String::with_capacity(code.len() + starts.len() * replacement.len());
+  let mut positions = Vec::with_capacity(starts.len());
+  let (mut line, mut column, mut last_end) = (0, 0, 0);
+  for start in starts {
+    for c in code[last_end..start].chars() {
+      if c == '\n' {
+        line += 1;
+        column = 0;
+      } else {
+        column += c.len_utf16() as u32;
+      }
+    }
+    positions.push((line, column));
+    replaced.push_str(&code[last_end..start]);
+    replaced.push_str(replacement);
+    last_end = start + placeholder.len();
+    column += placeholder.encode_utf16().count() as u32;
+  }
+  replaced.push_str(&code[last_end..]);
+
+  Some(ReplacedPlaceholder {
+    code: replaced,
+    positions,
+    placeholder_len: placeholder.encode_utf16().count() as u32,
+    replacement_len: replacement.encode_utf16().count() as u32,
+  })
+}
+
+impl ReplacedPlaceholder {
+  /// Moves the mappings behind the replaced placeholders by the length difference. Mappings inside of
+  /// a placeholder point to the start of its replacement.
+  pub fn update_sourcemap(&self, map: &SourceMap) -> SourceMap {
+    let tokens = map
+      .get_tokens()
+      .map(|token| {
+        Token::new(
+          token.get_dst_line(),
+          self.shift_column(token.get_dst_line(), token.get_dst_col()),
+          token.get_src_line(),
+          token.get_src_col(),
+          token.get_source_id(),
+          token.get_name_id(),
+        )
+      })
+      .collect();
+    let mut updated = SourceMap::new(
+      map.get_file().map(Into::into),
+      map.get_names().map(Into::into).collect(),
+      map.get_source_root().map(ToString::to_string),
+      map.get_sources().map(Into::into).collect(),
+      map.get_source_contents().map(|contents| contents.map(Into::into).collect()),
+      tokens,
+      None,
+    );
+    if let Some(debug_id) = map.get_debug_id() {
+      updated.set_debug_id(debug_id);
+    }
+    // `SourceMap` has no getter for the ignore list, so it's taken over from the encoded map.
+    if let Some(ignore_list) = serde_json::from_str::<serde_json::Value>(&map.to_json_string())
+      .ok()
+      .and_then(|json| serde_json::from_value(json.get("x_google_ignoreList")?.clone()).ok())
+    {
+      updated.set_x_google_ignore_list(ignore_list);
+    }
+    updated
+  }
+
+  fn shift_column(&self, line: u32, column: u32) -> u32 {
+    let (mut added, mut removed) = (0, 0);
+    for &(_, start) in
+      self.positions.iter().filter(|(placeholder_line, _)| *placeholder_line == line)
+    {
+      if column >= start + self.placeholder_len {
+        added += self.replacement_len;
+        removed += self.placeholder_len;
+      } else if column > start {
+        removed += column - start;
+      }
+    }
+    column + added - removed
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use oxc::sourcemap::{SourceMap, Token};
+
+  use super::replace_placeholder;
+
+  const PLACEHOLDER: &str = "self.__MANIFEST";
+
+  #[test]
+  fn test_replace_whole_expression() {
+    let replaced = replace_placeholder("f(self.__MANIFEST);", PLACEHOLDER, "[]").unwrap();
+    assert_eq!(replaced.code, "f([]);");
+
+    let replaced =
+      replace_placeholder("a(self.__MANIFEST, self.__MANIFEST)", PLACEHOLDER, "[1]").unwrap();
+    assert_eq!(replaced.code, "a([1], [1])");
+  }
+
+  #[test]
+  fn test_skip_partial_matches() {
+    assert!(replace_placeholder("self.__MANIFEST_V2", PLACEHOLDER, "[]").is_none());
+    assert!(replace_placeholder("self.__MANIFEST$", PLACEHOLDER, "[]").is_none());
+    assert!(replace_placeholder("a.self.__MANIFEST", PLACEHOLDER, "[]").is_none());
+    assert!(replace_placeholder("myself.__MANIFEST", PLACEHOLDER, "[]").is_none());
+
+    let replaced =
+      replace_placeholder("self.__MANIFEST_V2;self.__MANIFEST", PLACEHOLDER, "[]").unwrap();
+    assert_eq!(replaced.code, "self.__MANIFEST_V2;[]");
+  }
+
+  #[test]
+  fn test_update_sourcemap() {
+    // line 0: `const a = self.__MANIFEST, b = 1;`
+    // line 1: `f(self.__MANIFEST);`
+    let code = "const a = self.__MANIFEST, b = 1;\nf(self.__MANIFEST);";
+    let map = SourceMap::new(
+      None,
+      vec![],
+      None,
+      vec!["sw.js".into()],
+      None,
+      vec![
+        Token::new(0, 6, 0, 6, Some(0), None),
+        Token::new(0, 10, 0, 10, Some(0), None),
+        Token::new(0, 15, 0, 15, Some(0), None),
+        Token::new(0, 27, 0, 27, Some(0), None),
+        Token::new(1, 0, 1, 0, Some(0), None),
+        Token::new(1, 2, 1, 2, Some(0), None),
+      ],
+      None,
+    );
+
+    let replaced = replace_placeholder(code, PLACEHOLDER, "[\"a.js\"]").unwrap();
+    assert_eq!(replaced.code, "const a = [\"a.js\"], b = 1;\nf([\"a.js\"]);");
+
+    let updated = replaced.update_sourcemap(&map);
+    let columns = updated
+      .get_tokens()
+      .map(|token| (token.get_dst_line(), token.get_dst_col()))
+      .collect::<Vec<_>>();
+    assert_eq!(columns, vec![(0, 6), (0, 10), (0, 10), (0, 20), (1, 0), (1, 2)]);
+    assert_eq!(updated.get_sources().collect::<Vec<_>>(), vec!["sw.js"]);
+  }
+}
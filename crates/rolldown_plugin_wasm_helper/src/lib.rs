@@ -45,11 +45,14 @@ impl Plugin for WasmHelperPlugin {
         source: AssetSource::Buffer(fs::read(file_path)?),
         file_name: None,
       });
-      let url = ctx.get_file_name(&reference_id);
+      let url = match &ctx.options().base {
+        Some(_) => format!("import.meta.ROLLUP_FILE_URL_{reference_id}"),
+        None => format!("\"{}\"", ctx.get_file_name(&reference_id)),
+      };
       return Ok(Some(HookLoadOutput {
         code: format!(
           r#"import initWasm from "{WASM_HELPER_ID}"; 
-          export default opts => initWasm(opts, "{url}")"#
+          export default opts => initWasm(opts, {url})"#
         ),
         ..Default::default()
       }));
@@ -3,7 +3,7 @@
 pub mod error;
 mod resolver;
 
-pub use crate::resolver::{ResolveReturn, Resolver};
+pub use crate::resolver::{ResolveAttempt, ResolveReturn, Resolver};
 
 pub use oxc_resolver::ResolveError;
 pub use rolldown_common::bundler_options::ResolveOptions;
@@ -23,9 +23,10 @@ pub struct Resolver<T: FileSystem + Default = OsFileSystem> {
   require_resolver: ResolverGeneric<T>,
   css_resolver: ResolverGeneric<T>,
   package_json_cache: DashMap<PathBuf, Arc<PackageJson>>,
+  fs: T,
 }
 
-impl<F: FileSystem + Default> Resolver<F> {
+impl<F: FileSystem + Default + Clone> Resolver<F> {
   #[allow(clippy::too_many_lines)]
   pub fn new(raw_resolve: ResolveOptions, platform: Platform, cwd: PathBuf, fs: F) -> Self {
     let mut default_conditions = vec!["default".to_string()];
@@ -125,7 +126,7 @@ impl<F: FileSystem + Default> Resolver<F> {
     };
 
     let default_resolver =
-      ResolverGeneric::new_with_file_system(fs, resolve_options_with_default_conditions);
+      ResolverGeneric::new_with_file_system(fs.clone(), resolve_options_with_default_conditions);
     let import_resolver =
       default_resolver.clone_with_options(resolve_options_with_import_conditions);
     let require_resolver =
@@ -139,6 +140,7 @@ impl<F: FileSystem + Default> Resolver<F> {
       require_resolver,
       css_resolver,
       package_json_cache: DashMap::default(),
+      fs,
     }
   }
 
@@ -147,6 +149,17 @@ impl<F: FileSystem + Default> Resolver<F> {
   }
 }
 
+/// What the resolver tried for a specifier, so a failed resolution could explain itself.
+#[derive(Debug)]
+pub struct ResolveAttempt {
+  /// Conditions that the `exports` and `imports` fields of `package.json` are matched against.
+  pub conditions: Vec<String>,
+  pub extensions: Vec<String>,
+  /// Existing `node_modules` directories from the importer up to `cwd`, innermost first. It's `None` for
+  /// relative and absolute specifiers, which don't look up packages.
+  pub node_modules_dirs: Option<Vec<PathBuf>>,
+}
+
 #[derive(Debug)]
 pub struct ResolveReturn {
   pub path: ArcStr,
@@ -154,7 +167,51 @@ pub struct ResolveReturn {
   pub package_json: Option<Arc<PackageJson>>,
 }
 
-impl<F: FileSystem + Default> Resolver<F> {
+impl<F: FileSystem + Default + Clone> Resolver<F> {
+  fn select_resolver(&self, import_kind: ImportKind) -> &ResolverGeneric<F> {
+    match import_kind {
+      ImportKind::Import | ImportKind::DynamicImport | ImportKind::NewUrl => &self.import_resolver,
+      ImportKind::Require => &self.require_resolver,
+      ImportKind::AtImport => &self.css_resolver,
+    }
+  }
+
+  fn context_dir<'a>(&'a self, importer: Option<&'a Path>) -> &'a Path {
+    importer
+      .and_then(|importer| importer.parent())
+      .filter(|dir| {
+        // Empty path `Path::new("")`
+        dir.components().next().is_some()
+      })
+      .unwrap_or(self.cwd.as_path())
+  }
+
+  /// Describes how `specifier` is resolved from `importer`, for diagnostics of failed resolutions.
+  pub fn resolve_attempt(
+    &self,
+    importer: Option<&Path>,
+    specifier: &str,
+    import_kind: ImportKind,
+  ) -> ResolveAttempt {
+    let options = self.select_resolver(import_kind).options();
+    let is_specifier_path_like = specifier.starts_with('.') || specifier.starts_with('/');
+    let node_modules_dirs = (!is_specifier_path_like).then(|| {
+      // Directories above `cwd` depend on the machine rather than the project, so they are left out.
+      self
+        .context_dir(importer)
+        .ancestors()
+        .take_while(|dir| dir.starts_with(&self.cwd))
+        .flat_map(|dir| options.modules.iter().map(|modules| dir.join(modules)))
+        .filter(|dir| self.fs.exists(dir))
+        .collect()
+    });
+    ResolveAttempt {
+      conditions: options.condition_names.clone(),
+      extensions: options.extensions.clone(),
+      node_modules_dirs,
+    }
+  }
+
   pub fn resolve(
     &self,
     importer: Option<&Path>,
@@ -162,22 +219,8 @@ impl<F: FileSystem + Default> Resolver<F> {
     import_kind: ImportKind,
     is_user_defined_entry: bool,
   ) -> anyhow::Result<Result<ResolveReturn, ResolveError>> {
-    let selected_resolver = match import_kind {
-      ImportKind::Import | ImportKind::DynamicImport => &self.import_resolver,
-      ImportKind::Require => &self.require_resolver,
-      ImportKind::AtImport => &self.css_resolver,
-    };
-
-    let importer_dir = importer.and_then(|importer| importer.parent()).and_then(|inner| {
-      if inner.components().next().is_none() {
-        // Empty path `Path::new("")`
-        None
-      } else {
-        Some(inner)
-      }
-    });
-
-    let context_dir = importer_dir.unwrap_or(self.cwd.as_path());
+    let selected_resolver = self.select_resolver(import_kind);
+    let context_dir = self.context_dir(importer);
 
     let mut resolution = selected_resolver.resolve(context_dir, specifier);
 
@@ -96,6 +96,7 @@ pub struct ConcatSource {
   sources_len: usize,
   tokens_len: usize,
   token_chunks_len: usize,
+  skip_empty_sources: bool,
 }
 
 impl ConcatSource {
@@ -114,6 +115,11 @@ impl ConcatSource {
     self.inner.push(source);
   }
 
+  /// Don't join empty sources with newlines, so they don't leave blank lines in the content.
+  pub fn set_skip_empty_sources(&mut self, skip_empty_sources: bool) {
+    self.skip_empty_sources = skip_empty_sources;
+  }
+
   pub fn add_prepend_source(&mut self, source: Box<dyn Source + Send>) {
     if let Some(sourcemap) = source.sourcemap() {
       self.add_sourcemap(sourcemap);
@@ -132,14 +138,19 @@ impl ConcatSource {
       )
     });
     let mut line_offset = 0;
-    let source_len = self.prepend_source.len() + self.inner.len();
-
-    for (index, source) in self.prepend_source.iter().chain(self.inner.iter()).enumerate() {
-      source.into_concat_source(&mut final_source, &mut sourcemap_builder, line_offset);
-      if index < source_len - 1 {
+    let sources = self
+      .prepend_source
+      .iter()
+      .chain(self.inner.iter())
+      .filter(|source| !(self.skip_empty_sources && source.content().is_empty()));
+
+    for (index, source) in sources.enumerate() {
+      if index > 0 {
         final_source.push('\n');
-        line_offset += source.lines_count() + 1; // +1 for the newline
+        line_offset += 1;
       }
+      source.into_concat_source(&mut final_source, &mut sourcemap_builder, line_offset);
+      line_offset += source.lines_count();
     }
 
     (final_source, sourcemap_builder.map(ConcatSourceMapBuilder::into_sourcemap))
@@ -18,6 +18,10 @@ impl BitSet {
     self.entries[bit as usize / 8] |= 1 << (bit & 7);
   }
 
+  pub fn clear_bit(&mut self, bit: u32) {
+    self.entries[bit as usize / 8] &= !(1 << (bit & 7));
+  }
+
   pub fn is_empty(&self) -> bool {
     self.entries.iter().all(|&e| e == 0)
   }
@@ -27,6 +31,19 @@ impl BitSet {
       self.entries[i] |= e;
     }
   }
+
+  /// Whether every bit of `other` is also set in `self`.
+  pub fn is_superset_of(&self, other: &Self) -> bool {
+    other
+      .entries
+      .iter()
+      .enumerate()
+      .all(|(i, &e)| (self.entries.get(i).copied().unwrap_or(0) & e) == e)
+  }
+
+  pub fn count_ones(&self) -> u32 {
+    self.entries.iter().map(|e| e.count_ones()).sum()
+  }
 }
 
 impl Display for BitSet {
@@ -70,6 +87,19 @@ mod tests {
     assert_eq!(bs.to_string(), "10000011_10000001");
   }
 
+  #[test]
+  fn clear_bit() {
+    let mut bs = BitSet::new(9);
+    bs.set_bit(0);
+    bs.set_bit(8);
+    bs.clear_bit(0);
+    assert_eq!(bs.to_string(), "00000000_00000001");
+    bs.clear_bit(1);
+    assert_eq!(bs.to_string(), "00000000_00000001");
+    bs.clear_bit(8);
+    assert!(bs.is_empty());
+  }
+
   #[test]
   fn union() {
     let mut bs = BitSet::new(9);
@@ -86,4 +116,17 @@ mod tests {
     bs.union(&bs2);
     assert_eq!(bs.to_string(), "10000011_10000001");
   }
+
+  #[test]
+  fn is_superset_of() {
+    let mut bs = BitSet::new(9);
+    let mut bs2 = bs.clone();
+    assert!(bs.is_superset_of(&bs2));
+    bs.set_bit(0);
+    bs.set_bit(8);
+    bs2.set_bit(8);
+    assert!(bs.is_superset_of(&bs2));
+    assert!(!bs2.is_superset_of(&bs));
+    assert_eq!(bs.count_ones(), 2);
+  }
 }
@@ -0,0 +1,39 @@
+/// Finds the candidate that is most likely a typo of `name`, such as `Button` for `Buttom`. Candidates
+/// that differ too much are not suggested.
+pub fn did_you_mean<'a>(
+  name: &str,
+  candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+  let max_distance = name.chars().count() / 3;
+  candidates
+    .into_iter()
+    .filter(|candidate| *candidate != name)
+    .map(|candidate| (edit_distance(name, candidate), candidate))
+    .filter(|(distance, _)| *distance <= max_distance)
+    .min_by(|(a_distance, a), (b_distance, b)| a_distance.cmp(b_distance).then_with(|| a.cmp(b)))
+    .map(|(_, candidate)| candidate)
+}
+
+/// Levenshtein distance between `a` and `b`, in characters.
+fn edit_distance(a: &str, b: &str) -> usize {
+  let b = b.chars().collect::<Vec<_>>();
+  let mut prev_row = (0..=b.len()).collect::<Vec<_>>();
+  let mut row = vec![0; b.len() + 1];
+  for (i, a_char) in a.chars().enumerate() {
+    row[0] = i + 1;
+    for (j, b_char) in b.iter().enumerate() {
+      let substitution = prev_row[j] + usize::from(a_char != *b_char);
+      row[j + 1] = substitution.min(prev_row[j + 1] + 1).min(row[j] + 1);
+    }
+    std::mem::swap(&mut prev_row, &mut row);
+  }
+  prev_row[b.len()]
+}
+
+#[test]
+fn test_did_you_mean() {
+  assert_eq!(did_you_mean("Buttom", ["Card", "Button"]), Some("Button"));
+  assert_eq!(did_you_mean("Buttom", ["Button", "Bottom"]), Some("Bottom"));
+  assert_eq!(did_you_mean("default", ["foo", "bar"]), None);
+  assert_eq!(did_you_mean("a", ["b"]), None);
+}
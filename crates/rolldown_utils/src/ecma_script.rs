@@ -59,6 +59,47 @@ pub fn property_access_str(obj: &str, prop: &str) -> String {
   }
 }
 
+/// Like [property_access_str], but reserved words like `default` are accessed with brackets too, since they
+/// are not allowed as property names before ES5.
+pub fn property_access_str_without_reserved_names(obj: &str, prop: &str) -> String {
+  if keyword::is_reserved_keyword(prop) {
+    format!("{obj}[\"{prop}\"]")
+  } else {
+    property_access_str(obj, prop)
+  }
+}
+
+/// Renders `name` as the key of an object literal or a destructuring pattern.
+pub fn property_key_str(name: &str, reserved_names_as_props: bool) -> Cow<str> {
+  if is_validate_identifier_name(name)
+    && (reserved_names_as_props || !keyword::is_reserved_keyword(name))
+  {
+    Cow::Borrowed(name)
+  } else {
+    Cow::Owned(format!("\"{name}\""))
+  }
+}
+
+/// Whether `name` is a property of `Object.prototype`. Under frozen intrinsics, assigning such a property to a
+/// plain object throws in strict mode, because the inherited property is read-only (the "override mistake").
+pub fn is_object_prototype_property_name(name: &str) -> bool {
+  matches!(
+    name,
+    "constructor"
+      | "hasOwnProperty"
+      | "isPrototypeOf"
+      | "propertyIsEnumerable"
+      | "toLocaleString"
+      | "toString"
+      | "valueOf"
+      | "__proto__"
+      | "__defineGetter__"
+      | "__defineSetter__"
+      | "__lookupGetter__"
+      | "__lookupSetter__"
+  )
+}
+
 #[test]
 fn test_is_validate_identifier_name() {
   assert!(is_validate_identifier_name("foo"));
@@ -73,3 +114,11 @@ fn test_legitimize_identifier_name() {
   assert_eq!(legitimize_identifier_name("react-dom"), "react_dom");
   assert_eq!(legitimize_identifier_name("111a"), "_11a");
 }
+
+#[test]
+fn test_property_key_str() {
+  assert_eq!(property_key_str("foo", false), "foo");
+  assert_eq!(property_key_str("default", true), "default");
+  assert_eq!(property_key_str("default", false), "\"default\"");
+  assert_eq!(property_key_str("a-b", true), "\"a-b\"");
+}
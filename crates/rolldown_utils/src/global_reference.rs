@@ -809,6 +809,8 @@ pub fn is_side_effect_free_member_expr_of_len_two(member_expr: &[Atom]) -> bool
     "Object" => OBJECT_SECOND_PROP.contains(member_expr[1].as_ref()),
     "Symbol" => SYMBOL_SECOND_PROP.contains(member_expr[1].as_ref()),
     "JSON" => member_expr[1] == "stringify" || member_expr[1] == "parse",
+    // Reading a known global through the global object, such as `window.matchMedia`.
+    "globalThis" | "window" | "self" => GLOBAL_IDENT.contains(member_expr[1].as_ref()),
     _ => false,
   }
 }
@@ -4,6 +4,7 @@ pub mod base64;
 mod bitset;
 pub mod dataurl;
 pub mod debug;
+pub mod did_you_mean;
 pub mod ecma_script;
 pub mod futures;
 pub mod global_reference;
@@ -22,3 +23,4 @@ pub mod extract_hash_pattern;
 pub mod js_regex;
 pub mod pattern_filter;
 pub mod unique_arc;
+pub mod virtual_module;
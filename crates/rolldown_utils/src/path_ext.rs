@@ -3,6 +3,8 @@ use std::{borrow::Cow, ffi::OsStr, path::Path};
 
 use sugar_path::SugarPath;
 
+use crate::virtual_module::VIRTUAL_MODULE_PREFIX;
+
 pub trait PathExt {
   fn expect_to_str(&self) -> &str;
 
@@ -45,6 +47,10 @@ impl PathExt for std::path::Path {
       _ => file_name,
     };
 
+    // `\0` of virtual modules isn't meaningful, and shouldn't end up in chunk names.
+    if let Some(file_name) = file_name.strip_prefix(VIRTUAL_MODULE_PREFIX) {
+      return Cow::Owned(file_name.to_string());
+    }
     file_name
   }
 }
@@ -60,6 +66,9 @@ fn test_representative_file_name() {
 
   let path = cwd.join("vue").join("mod.ts");
   assert_eq!(path.representative_file_name(), "vue_mod");
+
+  let path = Path::new("\0virtual-entry.js");
+  assert_eq!(path.representative_file_name(), "virtual-entry");
 }
 
 #[inline]
@@ -1,6 +1,5 @@
 use std::borrow::Cow;
 
-// TODO support `output.sanitizeFileName`
 // Follow from https://github.com/rollup/rollup/blob/master/src/utils/sanitizeFileName.ts
 #[allow(clippy::needless_pass_by_value)]
 pub fn sanitize_file_name(str: Cow<str>) -> String {
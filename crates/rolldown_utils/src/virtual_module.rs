@@ -0,0 +1,20 @@
+/// Ids of the modules generated by plugins start with `\0`. They are never resolved or read from the
+/// file system, so plugins have to handle both `resolveId` and `load` of them.
+/// ref: https://rollupjs.org/plugin-development/#conventions
+pub const VIRTUAL_MODULE_PREFIX: char = '\0';
+
+pub fn is_virtual_module_id(id: &str) -> bool {
+  id.starts_with(VIRTUAL_MODULE_PREFIX)
+}
+
+/// `\0` isn't printable, so it's written as `\\0` when the id is shown to users.
+pub fn display_virtual_module_id(id: &str) -> String {
+  id.replace(VIRTUAL_MODULE_PREFIX, "\\0")
+}
+
+#[test]
+fn test_virtual_module_id() {
+  assert!(is_virtual_module_id("\0virtual-entry"));
+  assert!(!is_virtual_module_id("./virtual-entry"));
+  assert_eq!(display_virtual_module_id("\0polyfill-node.util.js"), "\\0polyfill-node.util.js");
+}
@@ -8,3 +8,7 @@ pub fn xxhash_base64_url(input: &[u8]) -> String {
   let hash = xxh3_128(input).to_le_bytes();
   to_url_safe_base64(hash)
 }
+
+pub fn xxhash_128(input: &[u8]) -> u128 {
+  xxh3_128(input)
+}
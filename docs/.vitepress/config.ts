@@ -82,6 +82,7 @@ export default defineConfig({
           text: 'In Depth',
           items: [
             { text: 'Module Types', link: '/guide/in-depth/module-type.md' },
+            { text: 'Plugin Hooks', link: '/guide/in-depth/plugin-hooks.md' },
             { text: 'Use Strict', link: '/guide/in-depth/use-strict.md' },
           ],
         },
@@ -8,6 +8,12 @@ export declare class BindingLog {
   message: string
 }
 
+/** The second argument of the function form of `output.manualChunks`. */
+export declare class BindingManualChunksMeta {
+  getModuleInfo(moduleId: string): BindingModuleInfo | null
+  getModuleIds(): Array<string>
+}
+
 export declare class BindingModuleInfo {
   id: string
   importers: Array<string>
@@ -35,6 +41,7 @@ export declare class BindingOutputChunk {
   get modules(): Record<string, BindingRenderedModule>
   get imports(): Array<string>
   get dynamicImports(): Array<string>
+  get importedCss(): Array<string>
   get code(): string
   get map(): string | null
   get sourcemapFileName(): string | null
@@ -45,11 +52,16 @@ export declare class BindingOutputChunk {
 export declare class BindingOutputs {
   get chunks(): Array<BindingOutputChunk>
   get assets(): Array<BindingOutputAsset>
+  /** Only available on the result of `generate` and `write`. */
+  get stats(): BindingBuildStats | null
+  /** The SARIF log of the diagnostics, only available if `diagnosticsFormat` is `sarif`. */
+  get sarif(): string | null
 }
 
 export declare class BindingPluginContext {
   resolve(specifier: string, importer?: string | undefined | null, extraOptions?: BindingPluginContextResolveOptions | undefined | null): Promise<BindingPluginContextResolvedId | null>
   emitFile(file: BindingEmittedAsset): string
+  emitChunk(chunk: BindingEmittedChunk): string
   getFileName(referenceId: string): string
   getModuleInfo(moduleId: string): BindingModuleInfo | null
   getModuleIds(): Array<string> | null
@@ -100,12 +112,14 @@ export interface ArrowFunctionsOptions {
 export interface BindingAdvancedChunksOptions {
   minSize?: number
   minShareCount?: number
+  maxSize?: number
   groups?: Array<BindingMatchGroup>
 }
 
 export interface BindingAliasPluginAlias {
   find: BindingStringOrRegex
   replacement: string
+  customResolver?: (id: string, importer?: string) => MaybePromise<VoidNullable<string>>
 }
 
 export interface BindingAliasPluginConfig {
@@ -124,6 +138,15 @@ export interface BindingBuildImportAnalysisPluginConfig {
   isRelativeBase: boolean
 }
 
+export interface BindingBuildStats {
+  scan: BindingScanStats
+  link: BindingStageStats
+  generate: BindingStageStats
+  modules: BindingModuleStats
+  bytesRead: number
+  chunksRendered: number
+}
+
 export interface BindingBuiltinPlugin {
   __name: BindingBuiltinPluginName
   options?: unknown
@@ -141,7 +164,37 @@ export declare enum BindingBuiltinPluginName {
   AliasPlugin = 8,
   JsonPlugin = 9,
   BuildImportAnalysisPlugin = 10,
-  ReplacePlugin = 11
+  ReplacePlugin = 11,
+  ServiceWorkerPlugin = 12,
+  NodePolyfillsPlugin = 13
+}
+
+export interface BindingChecksOptions {
+  circularDependency?: boolean
+  circularDependencyIgnore?: Array<BindingStringOrRegex>
+  circularDependencyError?: Array<BindingStringOrRegex>
+  commonjsDefaultImport?: boolean
+  commonjsNamedImport?: boolean
+  requireEsm?: boolean
+  barrelSideEffects?: boolean
+}
+
+export interface BindingChunkSizeWarningLimit {
+  raw?: number
+  gzip?: number
+}
+
+export interface BindingCommonJsOptions {
+  ignoreDynamicRequires?: boolean
+  transformMixedEsModules?: boolean
+  requireReturnsDefault?: boolean | 'namespace' | 'auto' | 'preferred'
+  esmExternals?: boolean
+  strictExportsDetection?: boolean
+}
+
+export interface BindingDecoratorOptions {
+  legacy?: boolean
+  emitDecoratorMetadata?: boolean
 }
 
 export interface BindingEmittedAsset {
@@ -151,11 +204,28 @@ export interface BindingEmittedAsset {
   source: BindingAssetSource
 }
 
+export interface BindingEmittedChunk {
+  name?: string
+  fileName?: string
+  id: string
+  importer?: string
+  implicitlyLoadedAfterOneOf?: Array<string>
+}
+
 export interface BindingExperimentalOptions {
   strictExecutionOrder?: boolean
   disableLiveBindings?: boolean
 }
 
+export interface BindingGeneratedCodeOptions {
+  preset?: 'es5' | 'es2015'
+  arrowFunctions?: boolean
+  constBindings?: boolean
+  objectShorthand?: boolean
+  templateString?: boolean
+  reservedNamesAsProps?: boolean
+}
+
 export interface BindingGeneralHookFilter {
   include?: Array<BindingStringOrRegex>
   exclude?: Array<BindingStringOrRegex>
@@ -181,7 +251,8 @@ export interface BindingHookRenderChunkOutput {
 export interface BindingHookResolveIdExtraArgs {
   custom?: number
   isEntry: boolean
-  kind: 'import' | 'dynamic-import' | 'require-call'
+  kind: 'import' | 'dynamic-import' | 'require-call' | 'new-url'
+  attributes: Record<string, string>
 }
 
 export interface BindingHookResolveIdOutput {
@@ -229,16 +300,35 @@ export interface BindingInputOptions {
   shimMissingExports?: boolean
   platform?: 'node' | 'browser' | 'neutral'
   logLevel?: BindingLogLevel
-  onLog: (logLevel: 'debug' | 'warn' | 'info', log: BindingLog) => void
+  onLog?: undefined | ((logLevel: 'debug' | 'warn' | 'info', log: BindingLog) => BindingOnLogOutput)
+  /** Prints the diagnostics kept by the `onLog` option and the `onLog` hook of plugins. */
+  printLog: (logLevel: 'debug' | 'warn' | 'info', log: BindingLog) => void
+  diagnosticsFormat?: 'text' | 'sarif'
+  maxDiagnostics?: number
   cwd: string
   treeshake?: BindingTreeshake
   moduleTypes?: Record<string, string>
+  assetsInlineLimit?: number | ((id: string, size: number) => boolean)
   define?: Array<[string, string]>
   inject?: Array<BindingInjectImportNamed | BindingInjectImportNamespace>
   experimental?: BindingExperimentalOptions
   profilerNames?: boolean
   jsx?: JsxOptions
+  decorator?: BindingDecoratorOptions
+  json?: BindingJsonOptions
+  wasm?: BindingWasmOptions
+  commonjs?: BindingCommonJsOptions
   watch?: BindingWatchOption
+  checks?: BindingChecksOptions
+  cssTarget?: Array<string>
+  bail?: boolean
+  errorRecovery?: boolean
+  drop?: Array<'console' | 'debugger'>
+  dropLabels?: Array<string>
+}
+
+export interface BindingJsonOptions {
+  stringify?: boolean | 'auto'
 }
 
 export interface BindingJsonPluginConfig {
@@ -262,51 +352,121 @@ export declare enum BindingLogLevel {
   Debug = 3
 }
 
+export interface BindingMangleOptions {
+  toplevel?: boolean
+  reserved?: Array<string>
+  keepClassnames?: boolean
+  properties?: BindingStringOrRegex
+}
+
 export interface BindingManifestPluginConfig {
   root: string
   outPath: string
 }
 
+/**
+ * An entry of the object form of `output.manualChunks`. A list is used instead of an object, because the
+ * order of the entries decides which chunk a module listed more than once belongs to.
+ */
+export interface BindingManualChunksGroup {
+  name: string
+  modules: Array<string>
+}
+
 export interface BindingMatchGroup {
   name: string
-  test?: BindingStringOrRegex
+  test?: string | RegExp | ((id: string) => VoidNullable<boolean>)
   priority?: number
   minSize?: number
+  maxSize?: number
   minShareCount?: number
 }
 
+export interface BindingMinifyOptions {
+  compress?: boolean
+  mangle?: boolean | BindingMangleOptions
+  codegen?: boolean
+}
+
 export interface BindingModulePreloadPolyfillPluginConfig {
   skip?: boolean
 }
 
+export interface BindingModuleStats {
+  esm: number
+  cjs: number
+  json: number
+  css: number
+  asset: number
+  wasm: number
+  empty: number
+  external: number
+}
+
 export interface BindingNotifyOption {
   pollInterval?: number
   compareContents?: boolean
 }
 
+/** What the `onLog` option decided to do with a diagnostic. */
+export interface BindingOnLogOutput {
+  action: 'keep' | 'rewrite' | 'drop' | 'error'
+  /** The message to report the diagnostic with, if it's rewritten. */
+  message?: string
+}
+
 export interface BindingOutputOptions {
   name?: string
   entryFileNames?: string | ((chunk: PreRenderedChunk) => string)
   chunkFileNames?: string | ((chunk: PreRenderedChunk) => string)
-  assetFileNames?: string
+  cssEntryFileNames?: string | ((chunk: PreRenderedChunk) => string)
+  cssChunkFileNames?: string | ((chunk: PreRenderedChunk) => string)
+  assetFileNames?: string | ((asset: BindingPreRenderedAsset) => string)
   banner?: (chunk: RenderedChunk) => MaybePromise<VoidNullable<string>>
+  compact?: boolean
   dir?: string
+  publicDir?: string
+  dynamicImportInCjs?: boolean
   esModule?: boolean | 'if-default-prop'
   exports?: 'default' | 'named' | 'none' | 'auto'
   extend?: boolean
   externalLiveBindings?: boolean
+  externalImportAttributes?: boolean
   footer?: (chunk: RenderedChunk) => MaybePromise<VoidNullable<string>>
-  format?: 'es' | 'cjs' | 'iife' | 'umd'
+  format?: 'es' | 'cjs' | 'iife' | 'umd' | 'system'
+  freeze?: boolean
+  generatedCode?: BindingGeneratedCodeOptions
   globals?: Record<string, string>
+  hashCharacters?: 'base64' | 'base36' | 'hex'
   inlineDynamicImports?: boolean
+  interop?: 'auto' | 'esModule' | 'default' | 'defaultOnly' | ((id: string) => 'auto' | 'esModule' | 'default' | 'defaultOnly')
   intro?: (chunk: RenderedChunk) => MaybePromise<VoidNullable<string>>
+  manualChunks?: BindingManualChunksGroup[] | ((id: string, meta: BindingManualChunksMeta) => VoidNullable<string>)
+  noConflict?: boolean
   outro?: (chunk: RenderedChunk) => MaybePromise<VoidNullable<string>>
   plugins: (BindingBuiltinPlugin | BindingPluginOptions | undefined)[]
+  preserveModules?: boolean
+  preserveModulesRoot?: string
+  sanitizeFileName?: boolean | ((name: string) => string)
   sourcemap?: 'file' | 'inline' | 'hidden'
   sourcemapIgnoreList?: (source: string, sourcemapPath: string) => boolean
   sourcemapPathTransform?: (source: string, sourcemapPath: string) => string
-  minify?: boolean
+  strict?: boolean
+  minify?: BindingMinifyOptions
   advancedChunks?: BindingAdvancedChunksOptions
+  experimentalMinChunkSize?: number
+  chunkNameStrategy?: 'firstEntered' | 'dominant' | 'package'
+  chunkSizeWarningLimit?: BindingChunkSizeWarningLimit
+  runtimeChunk?: boolean
+  keepNames?: boolean
+  target?: 'es2015' | 'es2016' | 'es2017' | 'es2018' | 'es2019' | 'es2020' | 'es2021' | 'es2022' | 'esnext'
+  legalComments?: 'none' | 'inline' | 'eof' | 'linked' | 'external'
+  charset?: 'ascii' | 'utf8'
+  sesCompatible?: boolean
+  cssMode?: 'extract' | 'inline'
+  cssBanner?: (chunk: RenderedChunk) => MaybePromise<VoidNullable<string>>
+  cssFooter?: (chunk: RenderedChunk) => MaybePromise<VoidNullable<string>>
+  base?: string
 }
 
 export interface BindingPluginContextResolvedId {
@@ -339,12 +499,18 @@ export interface BindingPluginOptions {
   transform?: (ctx:  BindingTransformPluginContext, id: string, code: string, module_type: BindingTransformHookExtraArgs) => MaybePromise<VoidNullable<BindingHookTransformOutput>>
   transformMeta?: BindingPluginHookMeta
   transformFilter?: BindingTransformHookFilter
+  transformCss?: (ctx: BindingPluginContext, code: string, id: string, meta: BindingTransformHookExtraArgs) => MaybePromise<VoidNullable<BindingHookRenderChunkOutput>>
+  transformCssMeta?: BindingPluginHookMeta
   moduleParsed?: (ctx: BindingPluginContext, module: BindingModuleInfo) => MaybePromise<VoidNullable>
   moduleParsedMeta?: BindingPluginHookMeta
   buildEnd?: (ctx: BindingPluginContext, error: Nullable<string>) => MaybePromise<VoidNullable>
   buildEndMeta?: BindingPluginHookMeta
+  onLog?: (level: 'debug' | 'warn' | 'info', log: BindingLog) => VoidNullable<boolean>
+  onLogMeta?: BindingPluginHookMeta
   renderChunk?: (ctx: BindingPluginContext, code: string, chunk: RenderedChunk) => MaybePromise<VoidNullable<BindingHookRenderChunkOutput>>
   renderChunkMeta?: BindingPluginHookMeta
+  renderCssChunk?: (ctx: BindingPluginContext, code: string, chunk: RenderedChunk) => MaybePromise<VoidNullable<BindingHookRenderChunkOutput>>
+  renderCssChunkMeta?: BindingPluginHookMeta
   augmentChunkHash?: (ctx: BindingPluginContext, chunk: RenderedChunk) => MaybePromise<void | string>
   augmentChunkHashMeta?: BindingPluginHookMeta
   renderStart?: (ctx: BindingPluginContext) => void
@@ -381,6 +547,12 @@ export interface BindingPluginWithIndex {
   plugin: BindingPluginOptions
 }
 
+export interface BindingPreRenderedAsset {
+  name?: string
+  originalFileName?: string
+  source: BindingAssetSource
+}
+
 export interface BindingRenderedModule {
   code?: string
 }
@@ -390,6 +562,8 @@ export interface BindingReplacePluginConfig {
   delimiters?: [string, string]
   preventAssignment?: boolean
   objectGuards?: boolean
+  include?: Array<BindingStringOrRegex>
+  exclude?: Array<BindingStringOrRegex>
 }
 
 export interface BindingResolveOptions {
@@ -406,10 +580,29 @@ export interface BindingResolveOptions {
   tsconfigFilename?: string
 }
 
+export interface BindingScanStats {
+  /** In milliseconds. */
+  duration: number
+  /** Cache misses of the module requests. */
+  uniqueModuleRequests: number
+  /** Cache hits of the module requests. */
+  deduplicatedModuleRequests: number
+}
+
+export interface BindingServiceWorkerPluginConfig {
+  entry: string
+  placeholder?: string
+}
+
 export interface BindingSourcemap {
   inner: string | BindingJsonSourcemap
 }
 
+export interface BindingStageStats {
+  /** In milliseconds. */
+  duration: number
+}
+
 export interface BindingTransformHookExtraArgs {
   moduleType: string
 }
@@ -428,7 +621,11 @@ export interface BindingTransformPluginConfig {
 }
 
 export interface BindingTreeshake {
-  moduleSideEffects: string
+  moduleSideEffects?: string | string[] | ((id: string, external: boolean) => VoidNullable<boolean>)
+  manualPureFunctions?: Array<BindingStringOrRegex>
+  propertyReadSideEffects?: boolean | 'always'
+  annotations?: boolean
+  unknownGlobalSideEffects?: boolean
 }
 
 export declare enum BindingWatcherEvent {
@@ -438,6 +635,10 @@ export declare enum BindingWatcherEvent {
   Change = 3
 }
 
+export interface BindingWasmOptions {
+  inline?: boolean
+}
+
 export interface BindingWatchOption {
   skipWrite?: boolean
   notify?: BindingNotifyOption
@@ -501,6 +702,7 @@ export interface JsOutputChunk {
   modules: Record<string, BindingRenderedModule>
   imports: Array<string>
   dynamicImports: Array<string>
+  importedCss: Array<string>
   code: string
   map?: BindingSourcemap
   sourcemapFilename?: string
@@ -53,15 +53,27 @@ export const alias: Partial<Record<keyof CliOptions, OptionConfig>> = {
   chunkFileNames: {
     hint: 'name',
   },
+  cssEntryFileNames: {
+    hint: 'name',
+  },
+  cssChunkFileNames: {
+    hint: 'name',
+  },
   entryFileNames: {
     hint: 'name',
   },
   externalLiveBindings: {
     default: true,
   },
+  externalImportAttributes: {
+    default: true,
+  },
   treeshake: {
     default: true,
   },
+  sanitizeFileName: {
+    default: true,
+  },
   moduleTypes: {
     hint: 'types',
   },
@@ -69,11 +69,27 @@ async function bundleInner(
 ) {
   const startTime = performance.now()
 
-  const build = await rolldown({ ...options, ...cliOptions.input })
-  const bundleOutput = await build.write({
-    ...options?.output,
-    ...cliOptions.output,
-  })
+  const inputOptions = { ...options, ...cliOptions.input }
+  const build = await rolldown(inputOptions)
+  const bundleOutput = await build
+    .write({
+      ...options?.output,
+      ...cliOptions.output,
+    })
+    .catch((err) => {
+      // The SARIF log of a failed build is the message of the error.
+      if (inputOptions.diagnosticsFormat === 'sarif') {
+        logger.log(err.message)
+        process.exit(1)
+      }
+      throw err
+    })
+
+  // The SARIF log is printed alone, so that it can be piped to a file.
+  if (bundleOutput.sarif) {
+    logger.log(bundleOutput.sarif)
+    return
+  }
 
   const endTime = performance.now()
 
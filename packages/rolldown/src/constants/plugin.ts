@@ -7,6 +7,7 @@ export const ENUMERATED_PLUGIN_HOOK_NAMES = [
   'resolveId',
   'load',
   'transform',
+  'transformCss',
   'moduleParsed',
   'augmentChunkHash',
   'buildEnd',
@@ -16,6 +17,7 @@ export const ENUMERATED_PLUGIN_HOOK_NAMES = [
   'generateBundle',
   'outputOptions',
   'renderChunk',
+  'renderCssChunk',
   'renderStart',
   'renderError',
   'writeBundle',
@@ -87,4 +89,8 @@ export const DEFINED_HOOK_NAMES: DefinedHookNames = {
   [ENUMERATED_PLUGIN_HOOK_NAMES[20]]: ENUMERATED_PLUGIN_HOOK_NAMES[20],
   [ENUMERATED_PLUGIN_HOOK_NAMES[21]]: ENUMERATED_PLUGIN_HOOK_NAMES[21],
   [ENUMERATED_PLUGIN_HOOK_NAMES[22]]: ENUMERATED_PLUGIN_HOOK_NAMES[22],
+  [ENUMERATED_PLUGIN_HOOK_NAMES[23]]: ENUMERATED_PLUGIN_HOOK_NAMES[23],
+  [ENUMERATED_PLUGIN_HOOK_NAMES[24]]: ENUMERATED_PLUGIN_HOOK_NAMES[24],
+  [ENUMERATED_PLUGIN_HOOK_NAMES[25]]: ENUMERATED_PLUGIN_HOOK_NAMES[25],
+  [ENUMERATED_PLUGIN_HOOK_NAMES[26]]: ENUMERATED_PLUGIN_HOOK_NAMES[26],
 } as const
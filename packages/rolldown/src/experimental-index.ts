@@ -17,4 +17,6 @@ export {
   jsonPlugin,
   buildImportAnalysisPlugin,
   replacePlugin,
+  serviceWorkerPlugin,
+  nodePolyfillsPlugin,
 } from './plugin/builtin-plugin'
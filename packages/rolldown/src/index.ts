@@ -1,16 +1,28 @@
 import {
+  BuildStats,
+  ScanStats,
+  StageStats,
   RolldownOutput,
   RolldownOutputAsset,
   RolldownOutputChunk,
   SourceMap,
 } from './types/rolldown-output'
 import type {
+  ChecksOptions,
+  CommonJsOptions,
+  DecoratorOptions,
   ExternalOption,
   InputOption,
   InputOptions,
+  JsonOptions,
   JsxOptions,
+  WasmOptions,
 } from './options/input-options'
-import type { ModuleFormat, OutputOptions } from './options/output-options'
+import type {
+  ModuleFormat,
+  OutputOptions,
+  PreRenderedAsset,
+} from './options/output-options'
 import type { RolldownOptions } from './types/rolldown-options'
 import type {
   AsyncPluginHooks,
@@ -37,6 +49,7 @@ import { ConfigExport } from './types/config-export'
 import { RolldownBuild } from './rolldown-build'
 import {
   EmittedAsset,
+  EmittedChunk,
   EmittedFile,
   PluginContext,
 } from './plugin/plugin-context'
@@ -59,6 +72,9 @@ export { defineConfig, rolldown, watch }
 export const VERSION: string = version
 
 export type {
+  BuildStats,
+  ScanStats,
+  StageStats,
   RolldownOutputAsset,
   RolldownOutputChunk,
   RolldownOptions,
@@ -86,6 +102,7 @@ export type {
   ObjectHook,
   RenderedChunk,
   PreRenderedChunk,
+  PreRenderedAsset,
   SourceMap,
   SourceDescription,
   PartialNull,
@@ -96,6 +113,7 @@ export type {
   MinimalPluginContext,
   EmittedFile,
   EmittedAsset,
+  EmittedChunk,
   CustomPluginOptions,
   AsyncPluginHooks,
   ParallelPluginHooks,
@@ -104,6 +122,11 @@ export type {
   SourceMapInput,
   OutputBundle,
   JsxOptions,
+  DecoratorOptions,
+  JsonOptions,
+  WasmOptions,
+  CommonJsOptions,
+  ChecksOptions,
   WatchOptions,
 }
 
@@ -6,6 +6,7 @@ import type {
   WarningHandlerWithDefault,
 } from '../rollup'
 import type { Plugin } from '../plugin'
+import type { BindingLog, BindingOnLogOutput } from '../binding'
 import {
   LOG_LEVEL_DEBUG,
   LOG_LEVEL_INFO,
@@ -152,6 +153,42 @@ export const getOnLog = (
   return defaultOnLog
 }
 
+/**
+ * Returns the `onLog` option of the bundler, which tells it whether to keep, rewrite, drop or fail with a
+ * diagnostic of the build, depending on what the `onLog` option does with it.
+ */
+export const getBindingOnLog = (
+  config: InputOptions,
+): ((level: LogLevel, log: BindingLog) => BindingOnLogOutput) | undefined => {
+  const { onLog } = config
+  if (!onLog) {
+    return undefined
+  }
+  return (level, { code, message }) => {
+    // The diagnostic is dropped, unless `onLog` passes it to the default handler.
+    let output: BindingOnLogOutput = { action: 'drop' }
+    onLog(level, addLogToString({ code, message }), (level, handledLog) => {
+      if (level === LOG_LEVEL_ERROR) {
+        output = { action: 'error' }
+        return
+      }
+      const log = normalizeLog(handledLog)
+      output =
+        log.message === message
+          ? { action: 'keep' }
+          : { action: 'rewrite', message: log.message }
+    })
+    return output
+  }
+}
+
+/**
+ * Returns the handler printing diagnostics of the build, which already went through `onLog`.
+ */
+export const getPrintLog = (
+  config: Pick<InputOptions, 'onwarn'>,
+): LogHandler => getDefaultOnLog(defaultPrintLog, config.onwarn)
+
 const getDefaultOnLog = (
   printLog: LogHandler,
   onwarn?: WarningHandlerWithDefault,
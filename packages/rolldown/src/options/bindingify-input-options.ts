@@ -15,12 +15,16 @@ import {
 } from '../plugin/builtin-plugin'
 import { PluginContextData } from '../plugin/plugin-context-data'
 import { normalizedStringOrRegex } from './utils'
+import type { InputOptions } from './input-options'
+import { getBindingOnLog, getPrintLog } from '../log/logger'
 
 export function bindingifyInputOptions(
   options: NormalizedInputOptions,
   outputOptions: NormalizedOutputOptions,
+  inputOptions: InputOptions,
 ): BindingInputOptions {
   const pluginContextData = new PluginContextData()
+  const printLog = getPrintLog(options)
   return {
     input: bindingifyInput(options.input),
     plugins: options.plugins.map((plugin) => {
@@ -78,11 +82,15 @@ export function bindingifyInputOptions(
     shimMissingExports: options.shimMissingExports,
     // @ts-ignore TODO The typing should import from binding
     logLevel: bindingifyLogLevel(options.logLevel),
-    onLog: (level, log) => {
-      options.onLog(level, { code: log.code, message: log.message })
+    onLog: getBindingOnLog(inputOptions),
+    printLog: (level, log) => {
+      printLog(level, { code: log.code, message: log.message })
     },
+    diagnosticsFormat: options.diagnosticsFormat,
+    maxDiagnostics: options.maxDiagnostics,
     treeshake: options.treeshake,
     moduleTypes: options.moduleTypes,
+    assetsInlineLimit: options.assetsInlineLimit,
     define: options.define ? Object.entries(options.define) : undefined,
     inject: options.inject
       ? Object.entries(options.inject).map(
@@ -132,7 +140,17 @@ export function bindingifyInputOptions(
     },
     profilerNames: options?.profilerNames,
     jsx: bindingifyJsx(options.jsx),
+    decorator: options.decorator,
+    json: options.json,
+    wasm: options.wasm,
+    commonjs: options.commonjs,
     watch: bindingifyWatch(options.watch),
+    checks: bindingifyChecks(options.checks),
+    cssTarget: options.cssTarget ? arraify(options.cssTarget) : undefined,
+    bail: options.bail,
+    errorRecovery: options.errorRecovery,
+    drop: options.drop,
+    dropLabels: options.dropLabels,
   }
 }
 
@@ -191,6 +209,22 @@ function bindingifyJsx(
   }
 }
 
+function bindingifyChecks(
+  checks: NormalizedInputOptions['checks'],
+): BindingInputOptions['checks'] {
+  if (checks) {
+    return {
+      ...checks,
+      circularDependencyIgnore: normalizedStringOrRegex(
+        checks.circularDependencyIgnore,
+      ),
+      circularDependencyError: normalizedStringOrRegex(
+        checks.circularDependencyError,
+      ),
+    }
+  }
+}
+
 function bindingifyWatch(
   watch: NormalizedInputOptions['watch'],
 ): BindingInputOptions['watch'] {
@@ -1,5 +1,7 @@
 import type { BindingOutputOptions } from '../binding'
 import type { NormalizedOutputOptions } from './normalized-output-options'
+import { transformModuleInfo } from '../utils/transform-module-info'
+import { transformAssetSource } from '../utils/asset-source'
 
 export function bindingifyOutputOptions(
   outputOptions: NormalizedOutputOptions,
@@ -14,6 +16,8 @@ export function bindingifyOutputOptions(
     name,
     entryFileNames,
     chunkFileNames,
+    cssEntryFileNames,
+    cssChunkFileNames,
     assetFileNames,
     banner,
     footer,
@@ -34,6 +38,8 @@ export function bindingifyOutputOptions(
           return 'iife'
         case 'umd':
           return 'umd'
+        case 'system':
+          return 'system'
       }
     })(),
     exports,
@@ -46,17 +52,46 @@ export function bindingifyOutputOptions(
     outro,
     extend: outputOptions.extend,
     globals,
+    strict: outputOptions.strict,
     esModule: bindingifyEsModule(esModule),
+    interop: outputOptions.interop,
+    generatedCode: outputOptions.generatedCode,
     name,
+    noConflict: outputOptions.noConflict,
     entryFileNames,
     chunkFileNames,
-    assetFileNames,
+    cssEntryFileNames,
+    cssChunkFileNames,
+    assetFileNames: bindingifyAssetFileNames(assetFileNames),
+    hashCharacters: outputOptions.hashCharacters,
+    sanitizeFileName: outputOptions.sanitizeFileName,
     // TODO(sapphi-red): support parallel plugins
     plugins: [],
-    minify: outputOptions.minify,
+    minify: bindingifyMinify(outputOptions.minify),
+    compact: outputOptions.compact,
     externalLiveBindings: outputOptions.externalLiveBindings,
+    externalImportAttributes: outputOptions.externalImportAttributes,
+    freeze: outputOptions.freeze,
     inlineDynamicImports: outputOptions.inlineDynamicImports,
+    dynamicImportInCjs: outputOptions.dynamicImportInCjs,
+    manualChunks: bindingifyManualChunks(outputOptions.manualChunks),
     advancedChunks: outputOptions.advancedChunks,
+    experimentalMinChunkSize: outputOptions.experimentalMinChunkSize,
+    chunkNameStrategy: outputOptions.chunkNameStrategy,
+    chunkSizeWarningLimit: outputOptions.chunkSizeWarningLimit,
+    runtimeChunk: outputOptions.runtimeChunk,
+    keepNames: outputOptions.keepNames,
+    target: outputOptions.target,
+    legalComments: outputOptions.legalComments,
+    charset: outputOptions.charset,
+    preserveModules: outputOptions.preserveModules,
+    preserveModulesRoot: outputOptions.preserveModulesRoot,
+    publicDir: outputOptions.publicDir,
+    sesCompatible: outputOptions.sesCompatible,
+    cssMode: outputOptions.cssMode,
+    cssBanner: outputOptions.cssBanner,
+    cssFooter: outputOptions.cssFooter,
+    base: outputOptions.base,
   }
 }
 
@@ -82,6 +117,52 @@ function bindingifySourcemap(
   }
 }
 
+function bindingifyMinify(
+  minify: NormalizedOutputOptions['minify'],
+): BindingOutputOptions['minify'] {
+  if (minify === true) {
+    return {}
+  }
+  return minify || undefined
+}
+
+function bindingifyAssetFileNames(
+  assetFileNames: NormalizedOutputOptions['assetFileNames'],
+): BindingOutputOptions['assetFileNames'] {
+  if (typeof assetFileNames === 'function') {
+    return (asset) =>
+      assetFileNames({
+        name: asset.name,
+        originalFileName: asset.originalFileName ?? null,
+        source: transformAssetSource(asset.source),
+        type: 'asset',
+      })
+  }
+  return assetFileNames
+}
+
+function bindingifyManualChunks(
+  manualChunks: NormalizedOutputOptions['manualChunks'],
+): BindingOutputOptions['manualChunks'] {
+  if (typeof manualChunks === 'function') {
+    return (id, meta) =>
+      manualChunks(id, {
+        getModuleIds: () => meta.getModuleIds().values(),
+        getModuleInfo: (moduleId) => {
+          const info = meta.getModuleInfo(moduleId)
+          return info
+            ? transformModuleInfo(info, { moduleSideEffects: null, meta: {} })
+            : null
+        },
+      })
+  }
+  // The order of the entries decides which chunk a module listed more than once belongs to.
+  return (
+    manualChunks &&
+    Object.entries(manualChunks).map(([name, modules]) => ({ name, modules }))
+  )
+}
+
 function bindingifyEsModule(
   esModule: NormalizedOutputOptions['esModule'],
 ): BindingOutputOptions['esModule'] {
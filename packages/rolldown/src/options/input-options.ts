@@ -37,6 +37,9 @@ const moduleTypesSchema = z.record(
     .or(z.literal('base64'))
     .or(z.literal('dataurl'))
     .or(z.literal('binary'))
+    .or(z.literal('file'))
+    .or(z.literal('copy'))
+    .or(z.literal('wasm'))
     .or(z.literal('empty'))
     .or(z.literal('css')),
 )
@@ -65,6 +68,72 @@ const jsxOptionsSchema = z.strictObject({
   // The rollup preset is not supported at now
 })
 
+const decoratorOptionsSchema = z.strictObject({
+  legacy: z
+    .boolean()
+    .describe(
+      'Transform decorators like `experimentalDecorators` of TypeScript. Standard decorators are not transformed yet',
+    )
+    .optional(),
+  emitDecoratorMetadata: z
+    .boolean()
+    .describe('Emit the types of decorated declarations for `reflect-metadata`')
+    .optional(),
+})
+
+const jsonOptionsSchema = z.strictObject({
+  stringify: z
+    .boolean()
+    .or(z.literal('auto'))
+    .describe(
+      'Embed objects and arrays of JSON modules as `JSON.parse("...")`, `auto` only does it for files larger than 10kB',
+    )
+    .optional(),
+})
+
+const wasmOptionsSchema = z.strictObject({
+  inline: z
+    .boolean()
+    .describe(
+      'Embed WebAssembly modules as base64 instead of emitting them as assets',
+    )
+    .optional(),
+})
+
+const commonjsOptionsSchema = z.strictObject({
+  ignoreDynamicRequires: z
+    .boolean()
+    .describe(
+      'keep `require` calls with non-literal arguments, instead of throwing at runtime (defaults to `true`)',
+    )
+    .optional(),
+  transformMixedEsModules: z
+    .boolean()
+    .describe(
+      'treat modules using both `import` and `module`/`exports` as CommonJS (defaults to `true`)',
+    )
+    .optional(),
+  requireReturnsDefault: z
+    .boolean()
+    .or(z.literal('namespace'))
+    .or(z.literal('auto'))
+    .or(z.literal('preferred'))
+    .describe(
+      'return the default export from `require` of ES modules (namespace, auto, preferred)',
+    )
+    .optional(),
+  esmExternals: z
+    .boolean()
+    .describe('import external modules as ES modules, without `__toESM`')
+    .optional(),
+  strictExportsDetection: z
+    .boolean()
+    .describe(
+      'decide ES modules from their extension and `package.json` rather than their syntax',
+    )
+    .optional(),
+})
+
 const stringOrRegExpSchema = zodExt
   .stringOrRegExp()
   .or(zodExt.stringOrRegExp().array())
@@ -83,6 +152,43 @@ const watchOptionsSchema = z.strictObject({
   chokidar: z.any().optional(),
 })
 
+const checksOptionsSchema = z.strictObject({
+  circularDependency: z
+    .boolean()
+    .describe('warn about circular dependencies between modules')
+    .optional(),
+  circularDependencyIgnore: stringOrRegExpSchema
+    .describe(
+      'do not report cycles going through a module that matches any of these patterns',
+    )
+    .optional(),
+  circularDependencyError: stringOrRegExpSchema
+    .describe(
+      'report cycles whose modules all match any of these patterns as errors rather than warnings',
+    )
+    .optional(),
+  commonjsDefaultImport: z
+    .boolean()
+    .describe(
+      'warn about default imports of CommonJS modules that do not set `__esModule`',
+    )
+    .optional(),
+  commonjsNamedImport: z
+    .boolean()
+    .describe('warn about named imports of CommonJS modules')
+    .optional(),
+  requireEsm: z
+    .boolean()
+    .describe('warn about `require` calls of ES modules')
+    .optional(),
+  barrelSideEffects: z
+    .boolean()
+    .describe(
+      'warn about modules only included for their side effects by `export * from` in barrel modules',
+    )
+    .optional(),
+})
+
 export const inputOptionsSchema = z.strictObject({
   input: inputOptionSchema.optional(),
   plugins: zodExt.phantom<RolldownPluginRec>().array().optional(),
@@ -138,9 +244,29 @@ export const inputOptionsSchema = z.strictObject({
         ),
     )
     .optional(),
+  diagnosticsFormat: z
+    .literal('text')
+    .or(z.literal('sarif'))
+    .describe(
+      `format of diagnostics (${underline('text')}, sarif to return them as one SARIF log).`,
+    )
+    .optional(),
+  maxDiagnostics: z
+    .number()
+    .describe(
+      `maximum number of diagnostics to report, the rest are counted per code (defaults to \`100\`).`,
+    )
+    .optional(),
   moduleTypes: moduleTypesSchema
     .describe('module types for customized extensions.')
     .optional(),
+  assetsInlineLimit: z
+    .number()
+    .or(z.function().args(z.string(), z.number()).returns(z.boolean()))
+    .describe(
+      'inline `file` modules smaller than this many bytes as base64 data urls.',
+    )
+    .optional(),
   experimental: z
     .strictObject({
       enableComposingJsPlugins: z.boolean().optional(),
@@ -152,7 +278,37 @@ export const inputOptionsSchema = z.strictObject({
   inject: z.record(z.string().or(z.tuple([z.string(), z.string()]))).optional(),
   profilerNames: z.boolean().optional(),
   jsx: jsxOptionsSchema.optional(),
+  decorator: decoratorOptionsSchema.optional(),
+  json: jsonOptionsSchema.optional(),
+  wasm: wasmOptionsSchema.optional(),
+  commonjs: commonjsOptionsSchema.optional(),
   watch: watchOptionsSchema.or(z.literal(false)).optional(),
+  checks: checksOptionsSchema.optional(),
+  cssTarget: z
+    .string()
+    .or(z.array(z.string()))
+    .describe('browsers to lower css syntax for (e.g. chrome90, safari14).')
+    .optional(),
+  bail: z
+    .boolean()
+    .describe(
+      'stop loading the dependencies of a module at its first error (use `--no-bail` to report all errors at once)',
+    )
+    .optional(),
+  errorRecovery: z
+    .boolean()
+    .describe(
+      'replace modules that fail to build with modules throwing the error at import time, e.g. for dev servers',
+    )
+    .optional(),
+  drop: z
+    .array(z.literal('console').or(z.literal('debugger')))
+    .describe('remove `console` API calls or `debugger` statements from modules')
+    .optional(),
+  dropLabels: z
+    .array(z.string())
+    .describe('remove the statements labeled with these names')
+    .optional(),
 })
 
 export const inputCliOptionsSchema = inputOptionsSchema
@@ -172,6 +328,22 @@ export const inputCliOptionsSchema = inputOptionsSchema
       .describe('enable treeshaking')
       .default(true)
       .optional(),
+    assetsInlineLimit: z
+      .number()
+      .describe(
+        'inline `file` modules smaller than this many bytes as base64 data urls.',
+      )
+      .optional(),
+    json: z
+      .strictObject({
+        stringify: z
+          .boolean()
+          .describe(
+            'embed objects and arrays of JSON modules as `JSON.parse("...")`',
+          )
+          .optional(),
+      })
+      .optional(),
   })
   .omit({
     input: true,
@@ -182,6 +354,7 @@ export const inputCliOptionsSchema = inputOptionsSchema
     experimental: true,
     profilerNames: true,
     watch: true,
+    checks: true,
   })
 
 type RawInputOptions = z.infer<typeof inputOptionsSchema>
@@ -221,3 +394,13 @@ export type InputOptions = Omit<
 export type ExternalOption = z.infer<typeof externalSchema>
 
 export type JsxOptions = z.infer<typeof jsxOptionsSchema>
+
+export type DecoratorOptions = z.infer<typeof decoratorOptionsSchema>
+
+export type JsonOptions = z.infer<typeof jsonOptionsSchema>
+
+export type WasmOptions = z.infer<typeof wasmOptionsSchema>
+
+export type CommonJsOptions = z.infer<typeof commonjsOptionsSchema>
+
+export type ChecksOptions = z.infer<typeof checksOptionsSchema>
@@ -1,6 +1,16 @@
+import type { MaybePromise, NullValue } from '../types/utils'
+
 type AliasPluginAlias = {
   find: string | RegExp
   replacement: string
+  /**
+   * Resolves the id with the alias replaced. Returning nothing falls back to
+   * the other plugins and the default resolution.
+   */
+  customResolver?: (
+    id: string,
+    importer: string | undefined,
+  ) => MaybePromise<NullValue<string>>
 }
 
 // A temp config type for giving better user experience
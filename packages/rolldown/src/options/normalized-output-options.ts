@@ -2,21 +2,29 @@ import type {
   SourcemapIgnoreListOption,
   SourcemapPathTransformOption,
 } from '../rollup'
-import type { OutputOptions } from './output-options'
+import type {
+  GetManualChunk,
+  OutputOptions,
+  PreRenderedAsset,
+} from './output-options'
 import type { RolldownPlugin } from '../plugin'
 import type { PreRenderedChunk, RenderedChunk } from '../binding'
 
-export type InternalModuleFormat = 'es' | 'cjs' | 'iife' | 'umd'
+export type InternalModuleFormat = 'es' | 'cjs' | 'iife' | 'umd' | 'system'
 
+type InteropType = 'auto' | 'esModule' | 'default' | 'defaultOnly'
+type InteropOption = InteropType | ((id: string) => InteropType)
 type AddonFunction = (chunk: RenderedChunk) => string | Promise<string>
 type ChunkFileNamesOption =
   | string
   | ((chunk: PreRenderedChunk) => string)
   | undefined
+type AssetFileNamesOption = string | ((asset: PreRenderedAsset) => string)
 
 export interface NormalizedOutputOptions extends OutputOptions {
   plugins: RolldownPlugin[]
   dir: string | undefined
+  publicDir: string | undefined
   format: InternalModuleFormat
   exports: 'auto' | 'named' | 'default' | 'none'
   sourcemap: boolean | 'inline' | 'hidden'
@@ -26,10 +34,46 @@ export interface NormalizedOutputOptions extends OutputOptions {
   footer: AddonFunction
   intro: AddonFunction
   outro: AddonFunction
+  cssBanner: AddonFunction
+  cssFooter: AddonFunction
+  strict: boolean | undefined
   esModule: boolean | 'if-default-prop'
+  interop: InteropOption
+  generatedCode: NonNullable<OutputOptions['generatedCode']>
   entryFileNames: ChunkFileNamesOption
   chunkFileNames: ChunkFileNamesOption
-  assetFileNames: string
+  cssEntryFileNames: ChunkFileNamesOption
+  cssChunkFileNames: ChunkFileNamesOption
+  assetFileNames: AssetFileNamesOption
+  hashCharacters: 'base64' | 'base36' | 'hex'
+  sanitizeFileName: boolean | ((name: string) => string)
+  base: string | undefined
   name: string | undefined
+  compact: boolean
+  noConflict: boolean
+  manualChunks: Record<string, string[]> | GetManualChunk | undefined
+  experimentalMinChunkSize: number | undefined
+  chunkNameStrategy: 'firstEntered' | 'dominant' | 'package'
+  chunkSizeWarningLimit: { raw?: number; gzip?: number } | undefined
+  runtimeChunk: boolean
+  keepNames: boolean
+  target:
+    | 'es2015'
+    | 'es2016'
+    | 'es2017'
+    | 'es2018'
+    | 'es2019'
+    | 'es2020'
+    | 'es2021'
+    | 'es2022'
+    | 'esnext'
+  legalComments: 'none' | 'inline' | 'eof' | 'linked' | 'external'
+  charset: 'ascii' | 'utf8'
+  freeze: boolean
   inlineDynamicImports: boolean
+  dynamicImportInCjs: boolean
+  preserveModules: boolean
+  preserveModulesRoot: string | undefined
+  sesCompatible: boolean
+  cssMode: 'extract' | 'inline'
 }
@@ -1,7 +1,9 @@
 import type { PreRenderedChunk, RenderedChunk } from '../binding'
+import type { ModuleInfo } from '../types/module-info'
 import { z } from 'zod'
 import * as zodExt from '../utils/zod-ext'
 import { bold, underline } from '../cli/colors'
+import type { AssetSource } from '../utils/asset-source'
 
 const ModuleFormatSchema = z
   .literal('es')
@@ -11,11 +13,21 @@ const ModuleFormatSchema = z
   .or(z.literal('commonjs'))
   .or(z.literal('iife'))
   .or(z.literal('umd'))
+  .or(z.literal('system'))
+  .or(z.literal('systemjs'))
   .describe(
     `output format of the generated bundle (supports ${underline('esm')}, cjs, and iife).`,
   )
   .optional()
 
+const InteropSchema = z
+  .literal('auto')
+  .or(z.literal('esModule'))
+  .or(z.literal('default'))
+  .or(z.literal('defaultOnly'))
+
+const GeneratedCodePresetSchema = z.literal('es5').or(z.literal('es2015'))
+
 const addonFunctionSchema = z
   .function()
   .args(zodExt.phantom<RenderedChunk>())
@@ -26,8 +38,19 @@ const chunkFileNamesFunctionSchema = z
   .args(zodExt.phantom<PreRenderedChunk>())
   .returns(z.string())
 
+const assetFileNamesFunctionSchema = z
+  .function()
+  .args(zodExt.phantom<PreRenderedAsset>())
+  .returns(z.string())
+
 const outputOptionsSchema = z.strictObject({
   dir: z.string().describe('Output directory, defaults to `dist`.').optional(),
+  publicDir: z
+    .string()
+    .describe(
+      'directory whose files are copied as is into the output directory',
+    )
+    .optional(),
   exports: z
     .literal('auto')
     .or(z.literal('named'))
@@ -61,12 +84,120 @@ const outputOptionsSchema = z.strictObject({
     .boolean()
     .describe('extend global variable defined by name in IIFE / UMD formats')
     .optional(),
+  strict: z
+    .boolean()
+    .describe(
+      'emit `"use strict"` at the top of non-ESM chunks, defaults to detecting it from the modules',
+    )
+    .optional(),
   esModule: z.literal('if-default-prop').or(z.boolean()).optional(),
+  interop: InteropSchema.or(
+    z.function().args(z.string()).returns(InteropSchema),
+  ).optional(),
   entryFileNames: z.string().or(chunkFileNamesFunctionSchema).optional(),
   chunkFileNames: z.string().or(chunkFileNamesFunctionSchema).optional(),
-  assetFileNames: z.string().optional(),
-  minify: z.boolean().describe('minify the bundled file.').optional(),
+  cssEntryFileNames: z
+    .string()
+    .or(chunkFileNamesFunctionSchema)
+    .describe('name pattern for css files of entry chunks')
+    .optional(),
+  cssChunkFileNames: z
+    .string()
+    .or(chunkFileNamesFunctionSchema)
+    .describe('name pattern for css files of non-entry chunks')
+    .optional(),
+  cssBanner: z
+    .string()
+    .or(addonFunctionSchema)
+    .describe('code to insert at the top of each emitted css file')
+    .optional(),
+  cssFooter: z
+    .string()
+    .or(addonFunctionSchema)
+    .describe('code to insert at the bottom of each emitted css file')
+    .optional(),
+  cssMode: z
+    .literal('extract')
+    .or(z.literal('inline'))
+    .describe(
+      'emit css as separate files (extract) or inject it from the js chunks (inline)',
+    )
+    .optional(),
+  assetFileNames: z.string().or(assetFileNamesFunctionSchema).optional(),
+  sanitizeFileName: z
+    .boolean()
+    .or(z.function().args(z.string()).returns(z.string()))
+    .describe('sanitize the names of chunks and assets derived from module ids')
+    .optional(),
+  hashCharacters: z
+    .literal('base64')
+    .or(z.literal('base36'))
+    .or(z.literal('hex'))
+    .describe('characters of `[hash]` in file names (base64, base36, hex)')
+    .optional(),
+  base: z
+    .string()
+    .describe(
+      'public path emitted assets are served from, or `auto` to resolve it at runtime',
+    )
+    .optional(),
+  minify: z
+    .boolean()
+    .or(
+      z.strictObject({
+        compress: z.boolean().optional(),
+        mangle: z
+          .boolean()
+          .or(
+            z.strictObject({
+              toplevel: z.boolean().optional(),
+              reserved: z.string().array().optional(),
+              keepClassnames: z.boolean().optional(),
+              properties: zodExt.stringOrRegExp().optional(),
+            }),
+          )
+          .optional(),
+        codegen: z.boolean().optional(),
+      }),
+    )
+    .describe('minify the bundled file.')
+    .optional(),
+  compact: z
+    .boolean()
+    .describe('strip the comments and whitespace rolldown inserts in the output')
+    .optional(),
   name: z.string().describe('name for UMD / IIFE format outputs').optional(),
+  noConflict: z
+    .boolean()
+    .describe('add a `noConflict` method to the global of UMD outputs')
+    .optional(),
+  generatedCode: z
+    .strictObject({
+      preset: GeneratedCodePresetSchema.describe(
+        'preset of the syntax used by generated code (es5, es2015)',
+      ).optional(),
+      arrowFunctions: z
+        .boolean()
+        .describe('use arrow functions in generated code')
+        .optional(),
+      constBindings: z
+        .boolean()
+        .describe('use `const` instead of `var` in generated code')
+        .optional(),
+      objectShorthand: z
+        .boolean()
+        .describe('use shorthand properties in generated code')
+        .optional(),
+      templateString: z
+        .boolean()
+        .describe('use template literals for multi-line strings')
+        .optional(),
+      reservedNamesAsProps: z
+        .boolean()
+        .describe('use reserved words as property names without quotes')
+        .optional(),
+    })
+    .optional(),
   globals: z
     .record(z.string())
     .describe(
@@ -78,28 +209,134 @@ const outputOptionsSchema = z.strictObject({
     .describe('use external live bindings')
     .default(true)
     .optional(),
+  externalImportAttributes: z
+    .boolean()
+    .describe('keep import attributes of external modules in es chunks')
+    .default(true)
+    .optional(),
+  freeze: z
+    .boolean()
+    .describe('freeze namespace objects')
+    .default(true)
+    .optional(),
+  dynamicImportInCjs: z
+    .boolean()
+    .describe('keep `import()` as native dynamic import in cjs output')
+    .default(true)
+    .optional(),
   inlineDynamicImports: z
     .boolean()
     .describe('inline dynamic imports')
     .default(false)
     .optional(),
+  preserveModules: z
+    .boolean()
+    .describe(
+      'emit a chunk for each module instead of bundling them, e.g. for libraries',
+    )
+    .optional(),
+  preserveModulesRoot: z
+    .string()
+    .describe('directory stripped from the output paths of preserved modules')
+    .optional(),
+  sesCompatible: z
+    .boolean()
+    .describe('avoid output patterns that break under hardened JavaScript (SES)')
+    .optional(),
+  manualChunks: z
+    .record(z.array(z.string()))
+    .or(zodExt.phantom<GetManualChunk>())
+    .describe('put modules into chunks with the given names')
+    .optional(),
   advancedChunks: z
     .strictObject({
       minSize: z.number().optional(),
       minShareCount: z.number().optional(),
+      maxSize: z.number().optional(),
       groups: z
         .array(
           z.strictObject({
             name: z.string(),
-            test: z.string().or(z.instanceof(RegExp)).optional(),
+            test: z
+              .string()
+              .or(z.instanceof(RegExp))
+              .or(
+                z
+                  .function()
+                  .args(z.string())
+                  .returns(z.boolean().or(z.void()).or(z.null())),
+              )
+              .optional(),
             priority: z.number().optional(),
             minSize: z.number().optional(),
+            maxSize: z.number().optional(),
             minShareCount: z.number().optional(),
           }),
         )
         .optional(),
     })
     .optional(),
+  experimentalMinChunkSize: z
+    .number()
+    .describe(
+      'merge common chunks smaller than this size into chunks loaded by the same entries',
+    )
+    .optional(),
+  chunkNameStrategy: z
+    .literal('firstEntered')
+    .or(z.literal('dominant'))
+    .or(z.literal('package'))
+    .describe(
+      'name common chunks after the first entered module, the largest module or their package (firstEntered, dominant, package)',
+    )
+    .optional(),
+  chunkSizeWarningLimit: z
+    .number()
+    .or(
+      z.strictObject({
+        raw: z.number().optional(),
+        gzip: z.number().optional(),
+      }),
+    )
+    .describe('warn about chunks larger than this size in bytes')
+    .optional(),
+  runtimeChunk: z
+    .boolean()
+    .describe('put the runtime helpers into a chunk shared by all other chunks')
+    .optional(),
+  keepNames: z
+    .boolean()
+    .describe('keep the `name` of functions and classes through renaming')
+    .optional(),
+  target: z
+    .literal('es2015')
+    .or(z.literal('es2016'))
+    .or(z.literal('es2017'))
+    .or(z.literal('es2018'))
+    .or(z.literal('es2019'))
+    .or(z.literal('es2020'))
+    .or(z.literal('es2021'))
+    .or(z.literal('es2022'))
+    .or(z.literal('esnext'))
+    .describe('lower the syntax of chunks to this version of ECMAScript')
+    .optional(),
+  legalComments: z
+    .literal('none')
+    .or(z.literal('inline'))
+    .or(z.literal('eof'))
+    .or(z.literal('linked'))
+    .or(z.literal('external'))
+    .describe(
+      'what to do with legal comments (none, inline, eof, linked, external)',
+    )
+    .optional(),
+  charset: z
+    .literal('ascii')
+    .or(z.literal('utf8'))
+    .describe(
+      'escape non-ASCII characters in chunks (ascii) or keep them (utf8)',
+    )
+    .optional(),
 })
 
 const getAddonDescription = (
@@ -125,6 +362,29 @@ export const outputCliOptionsSchema = outputOptionsSchema
       .string()
       .describe(getAddonDescription('bottom', 'inside'))
       .optional(),
+    cssBanner: z
+      .string()
+      .describe('code to insert at the top of each emitted css file')
+      .optional(),
+    cssFooter: z
+      .string()
+      .describe('code to insert at the bottom of each emitted css file')
+      .optional(),
+    interop: InteropSchema.describe(
+      `interop of imported external CommonJS modules (${underline('auto')}, esModule, default, defaultOnly)`,
+    ).optional(),
+    sanitizeFileName: z
+      .boolean()
+      .describe(
+        'sanitize the names of chunks and assets derived from module ids',
+      )
+      .default(true)
+      .optional(),
+    chunkSizeWarningLimit: z
+      .number()
+      .describe('warn about chunks larger than this size in bytes')
+      .optional(),
+    minify: z.boolean().describe('minify the bundled file.').optional(),
     // It is hard to handle the union type in json schema, so use this first.
     esModule: z
       .boolean()
@@ -139,16 +399,27 @@ export const outputCliOptionsSchema = outputOptionsSchema
           .number()
           .describe('minimum share count of the chunk')
           .optional(),
+        maxSize: z.number().describe('maximum size of the chunk').optional(),
       })
       .optional(),
   })
   .omit({
     sourcemapPathTransform: true,
     sourcemapIgnoreList: true,
+    manualChunks: true,
+    // `--no-conflict` would be parsed as the negation of `conflict`.
+    noConflict: true,
   })
 
 export type OutputOptions = z.infer<typeof outputOptionsSchema>
 
+export interface PreRenderedAsset {
+  name: string | undefined
+  originalFileName: string | null
+  source: AssetSource
+  type: 'asset'
+}
+
 export type SourcemapIgnoreListOption = (
   relativeSourcePath: string,
   sourcemapPath: string,
@@ -159,4 +430,14 @@ export type SourcemapPathTransformOption = (
   sourcemapPath: string,
 ) => string
 
+export interface ManualChunkMeta {
+  getModuleIds: () => IterableIterator<string>
+  getModuleInfo: (id: string) => ModuleInfo | null
+}
+
+export type GetManualChunk = (
+  id: string,
+  meta: ManualChunkMeta,
+) => string | null | undefined | void
+
 export type ModuleFormat = z.infer<typeof ModuleFormatSchema>
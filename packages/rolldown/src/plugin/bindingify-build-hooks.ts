@@ -6,6 +6,7 @@ import type {
 
 import type {
   hookFilterExtension,
+  ModuleType,
   Plugin,
   PluginHooks,
   PrivateResolveIdExtraOptions,
@@ -32,6 +33,7 @@ import {
   bindingifyResolveIdFilter,
   bindingifyTransformFilter,
 } from './bindingify-hook-filter'
+import { MinimalPluginContext } from '../log/logger'
 
 export function bindingifyBuildStart(
   plugin: Plugin,
@@ -77,6 +79,27 @@ export function bindingifyBuildEnd(
   }
 }
 
+export function bindingifyOnLog(
+  plugin: Plugin,
+  options: NormalizedInputOptions,
+): PluginHookWithBindingExt<BindingPluginOptions['onLog']> {
+  const hook = plugin.onLog
+  if (!hook) {
+    return {}
+  }
+  const { handler, meta } = normalizeHook(hook)
+
+  return {
+    plugin: (level, log) => {
+      return handler.call(new MinimalPluginContext(options, plugin), level, {
+        code: log.code,
+        message: log.message,
+      })
+    },
+    meta: bindingifyPluginHookMeta(meta),
+  }
+}
+
 export function bindingifyResolveId(
   plugin: Plugin,
   normalizedOptions: NormalizedInputOptions,
@@ -248,6 +271,47 @@ export function bindingifyTransform(
   }
 }
 
+export function bindingifyTransformCss(
+  plugin: Plugin,
+  normalizedOptions: NormalizedInputOptions,
+  pluginContextData: PluginContextData,
+): PluginHookWithBindingExt<BindingPluginOptions['transformCss']> {
+  const hook = plugin.transformCss
+  if (!hook) {
+    return {}
+  }
+  const { handler, meta } = normalizeHook(hook)
+
+  return {
+    plugin: async (ctx, code, id, meta) => {
+      const ret = await handler.call(
+        new PluginContext(normalizedOptions, ctx, plugin, pluginContextData),
+        code,
+        id,
+        { moduleType: meta.moduleType as ModuleType },
+      )
+
+      if (ret == null) {
+        return undefined
+      }
+
+      if (typeof ret === 'string') {
+        return { code: ret }
+      }
+
+      if (!ret.map) {
+        return { code: ret.code }
+      }
+
+      return {
+        code: ret.code,
+        map: bindingifySourcemap(ret.map),
+      }
+    },
+    meta: bindingifyPluginHookMeta(meta),
+  }
+}
+
 export function bindingifyLoad(
   plugin: Plugin,
   normalized_options: NormalizedInputOptions,
@@ -2,18 +2,45 @@ import {
   BindingGeneralHookFilter,
   BindingTransformHookFilter,
 } from '../binding.d'
-import { hookFilterExtension, ModuleType } from '.'
+import {
+  hookFilterExtension,
+  ModuleType,
+  StringFilter,
+  StringOrRegExp,
+} from '.'
+
+function arraify(value: StringOrRegExp | StringOrRegExp[]): StringOrRegExp[] {
+  return Array.isArray(value) ? value : [value]
+}
+
+function bindingifyStringFilter(
+  filter?: StringFilter,
+): BindingGeneralHookFilter | undefined {
+  if (filter === undefined) {
+    return undefined
+  }
+  if (typeof filter === 'string' || filter instanceof RegExp) {
+    return { include: [filter] }
+  }
+  if (Array.isArray(filter)) {
+    return { include: filter }
+  }
+  return {
+    include: filter.include === undefined ? undefined : arraify(filter.include),
+    exclude: filter.exclude === undefined ? undefined : arraify(filter.exclude),
+  }
+}
 
 export function bindingifyResolveIdFilter(
   filterOption?: hookFilterExtension<'resolveId'>['filter'],
 ): BindingGeneralHookFilter | undefined {
-  return filterOption?.id
+  return bindingifyStringFilter(filterOption?.id)
 }
 
 export function bindingifyLoadFilter(
   filterOption?: hookFilterExtension<'load'>['filter'],
 ): BindingGeneralHookFilter | undefined {
-  return filterOption?.id
+  return bindingifyStringFilter(filterOption?.id)
 }
 
 export function bindingifyTransformFilter(
@@ -34,8 +61,8 @@ export function bindingifyTransformFilter(
   }
 
   return {
-    id,
-    code,
+    id: bindingifyStringFilter(id),
+    code: bindingifyStringFilter(code),
     moduleType: moduleTypeRet,
   }
 }
@@ -82,6 +82,48 @@ export function bindingifyRenderChunk(
   }
 }
 
+export function bindingifyRenderCssChunk(
+  plugin: Plugin,
+  options: NormalizedInputOptions,
+  outputOptions: NormalizedOutputOptions,
+  pluginContextData: PluginContextData,
+): PluginHookWithBindingExt<BindingPluginOptions['renderCssChunk']> {
+  const hook = plugin.renderCssChunk
+  if (!hook) {
+    return {}
+  }
+  const { handler, meta } = normalizeHook(hook)
+
+  return {
+    plugin: async (ctx, code, chunk) => {
+      const ret = await handler.call(
+        new PluginContext(options, ctx, plugin, pluginContextData),
+        code,
+        chunk,
+        outputOptions,
+      )
+
+      if (ret == null) {
+        return
+      }
+
+      if (typeof ret === 'string') {
+        return { code: ret }
+      }
+
+      if (!ret.map) {
+        return { code: ret.code }
+      }
+
+      return {
+        code: ret.code,
+        map: bindingifySourcemap(ret.map),
+      }
+    },
+    meta: bindingifyPluginHookMeta(meta),
+  }
+}
+
 export function bindingifyAugmentChunkHash(
   plugin: Plugin,
   options: NormalizedInputOptions,
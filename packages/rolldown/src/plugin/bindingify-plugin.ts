@@ -4,14 +4,17 @@ import {
   bindingifyBuildStart,
   bindingifyLoad,
   bindingifyModuleParsed,
+  bindingifyOnLog,
   bindingifyResolveDynamicImport,
   bindingifyResolveId,
   bindingifyTransform,
+  bindingifyTransformCss,
 } from './bindingify-build-hooks'
 
 import {
   bindingifyRenderStart,
   bindingifyRenderChunk,
+  bindingifyRenderCssChunk,
   bindingifyGenerateBundle,
   bindingifyWriteBundle,
   bindingifyRenderError,
@@ -60,12 +63,17 @@ export function bindingifyPlugin(
     pluginContextData,
   )
 
+  const { plugin: onLog, meta: onLogMeta } = bindingifyOnLog(plugin, options)
+
   const {
     plugin: transform,
     meta: transformMeta,
     filter: transformFilter,
   } = bindingifyTransform(plugin, options, pluginContextData)
 
+  const { plugin: transformCss, meta: transformCssMeta } =
+    bindingifyTransformCss(plugin, options, pluginContextData)
+
   const { plugin: moduleParsed, meta: moduleParsedMeta } =
     bindingifyModuleParsed(plugin, options, pluginContextData)
 
@@ -82,6 +90,9 @@ export function bindingifyPlugin(
     pluginContextData,
   )
 
+  const { plugin: renderCssChunk, meta: renderCssChunkMeta } =
+    bindingifyRenderCssChunk(plugin, options, outputOptions, pluginContextData)
+
   const { plugin: augmentChunkHash, meta: augmentChunkHashMeta } =
     bindingifyAugmentChunkHash(plugin, options, pluginContextData)
 
@@ -156,9 +167,13 @@ export function bindingifyPlugin(
     resolveDynamicImportMeta,
     buildEnd,
     buildEndMeta,
+    onLog,
+    onLogMeta,
     transform,
     transformMeta,
     transformFilter,
+    transformCss,
+    transformCssMeta,
     moduleParsed,
     moduleParsedMeta,
     load,
@@ -167,6 +182,8 @@ export function bindingifyPlugin(
     loadFilter,
     renderChunk,
     renderChunkMeta,
+    renderCssChunk,
+    renderCssChunkMeta,
     augmentChunkHash,
     augmentChunkHashMeta,
     renderStart,
@@ -4,6 +4,7 @@ import {
 } from '../options/normalized-ecma-transform-plugin-config'
 
 import { AliasPluginConfig } from '../options/normalized-alias-plugin-config'
+import { normalizedStringOrRegex } from '../options/utils'
 import {
   BindingBuiltinPluginName,
   BindingGlobImportPluginConfig,
@@ -13,8 +14,18 @@ import {
   BindingJsonPluginConfig,
   BindingBuildImportAnalysisPluginConfig,
   BindingReplacePluginConfig,
+  BindingServiceWorkerPluginConfig,
 } from '../binding'
 
+type ReplacePattern = string | RegExp | (string | RegExp)[]
+export type ReplacePluginOptions = Omit<
+  BindingReplacePluginConfig,
+  'values' | 'include' | 'exclude'
+> & {
+  include?: ReplacePattern
+  exclude?: ReplacePattern
+}
+
 export class BuiltinPlugin {
   constructor(
     public name: BindingBuiltinPluginName,
@@ -98,6 +109,18 @@ export class ReplacePlugin extends BuiltinPlugin {
   }
 }
 
+export class ServiceWorkerPlugin extends BuiltinPlugin {
+  constructor(config: BindingServiceWorkerPluginConfig) {
+    super(BindingBuiltinPluginName.ServiceWorkerPlugin, config)
+  }
+}
+
+export class NodePolyfillsPlugin extends BuiltinPlugin {
+  constructor() {
+    super(BindingBuiltinPluginName.NodePolyfillsPlugin)
+  }
+}
+
 export function modulePreloadPolyfillPlugin(
   config?: BindingModulePreloadPolyfillPluginConfig,
 ) {
@@ -146,6 +169,25 @@ export function buildImportAnalysisPlugin(
   return new BuildImportAnalysisPlugin(config)
 }
 
+/**
+ * Builds `entry` as a service worker and replaces `self.__ROLLDOWN_PRECACHE_MANIFEST` in it with the
+ * list of all other emitted files, in the `{ url, revision }` shape that `workbox-precaching` accepts.
+ *
+ * ## Usage
+ *
+ * ```js
+ * export default {
+ *   input: ['main.js', 'sw.js'],
+ *   plugins: [serviceWorkerPlugin({ entry: 'sw.js' })],
+ * }
+ * ```
+ */
+export function serviceWorkerPlugin(
+  config: BindingServiceWorkerPluginConfig,
+) {
+  return new ServiceWorkerPlugin(config)
+}
+
 /**
  * ## Usage
  *
@@ -169,12 +211,49 @@ export function buildImportAnalysisPlugin(
  * })
  * ```
  *
+ * ### With filters
+ *
+ * `include` and `exclude` take globs, which are matched against paths relative to `cwd` and the file
+ * names of chunks, or regular expressions, which are matched against module ids.
+ *
+ * ```js
+ * replacePlugin({
+ *   __DEV__: 'false',
+ * }, {
+ *   exclude: ['node_modules/**'],
+ * })
+ * ```
+ *
  */
 export function replacePlugin(
   values: BindingReplacePluginConfig['values'] = {},
-  options: Omit<BindingReplacePluginConfig, 'values'> = {},
+  options: ReplacePluginOptions = {},
 ) {
-  return new ReplacePlugin({ ...options, values })
+  const { include, exclude, ...rest } = options
+  return new ReplacePlugin({
+    ...rest,
+    values,
+    include: normalizedStringOrRegex(include),
+    exclude: normalizedStringOrRegex(exclude),
+  })
+}
+
+/**
+ * Replaces node builtins like `path`, `events` and `stream` with their browser implementations, and
+ * injects `process` and `global` into the modules that use them. Builtins that can't work in browsers,
+ * like `fs`, are replaced with empty modules.
+ *
+ * ## Usage
+ *
+ * ```js
+ * export default {
+ *   platform: 'browser',
+ *   plugins: [nodePolyfillsPlugin()],
+ * }
+ * ```
+ */
+export function nodePolyfillsPlugin() {
+  return new NodePolyfillsPlugin()
 }
 
 export function bindingifyBuiltInPlugin(
@@ -39,7 +39,11 @@ export type ModuleType =
   | 'base64'
   | 'dataurl'
   | 'binary'
+  | 'file'
+  | 'copy'
+  | 'wasm'
   | 'empty'
+  | 'css'
   | (string & {})
 
 export type ImportKind = BindingHookResolveIdExtraArgs['kind']
@@ -72,7 +76,8 @@ export interface SourceDescription extends Partial<PartialNull<ModuleOptions>> {
 interface ResolveIdExtraOptions {
   custom?: CustomPluginOptions
   isEntry: boolean
-  kind: 'import' | 'dynamic-import' | 'require-call'
+  kind: 'import' | 'dynamic-import' | 'require-call' | 'new-url'
+  attributes: Record<string, string>
 }
 
 export interface PrivateResolveIdExtraOptions extends ResolveIdExtraOptions {
@@ -139,6 +144,19 @@ export interface FunctionPluginHooks {
     meta: BindingTransformHookExtraArgs & { moduleType: ModuleType },
   ) => TransformResult
 
+  [DEFINED_HOOK_NAMES.transformCss]: (
+    this: PluginContext,
+    code: string,
+    id: string,
+    meta: { moduleType: ModuleType },
+  ) =>
+    | NullValue
+    | string
+    | {
+        code: string
+        map?: SourceMapInput
+      }
+
   [DEFINED_HOOK_NAMES.moduleParsed]: (
     this: PluginContext,
     moduleInfo: ModuleInfo,
@@ -167,6 +185,19 @@ export interface FunctionPluginHooks {
         map?: SourceMapInput
       }
 
+  [DEFINED_HOOK_NAMES.renderCssChunk]: (
+    this: PluginContext,
+    code: string,
+    chunk: RenderedChunk,
+    outputOptions: NormalizedOutputOptions,
+  ) =>
+    | NullValue
+    | string
+    | {
+        code: string
+        map?: SourceMapInput
+      }
+
   [DEFINED_HOOK_NAMES.augmentChunkHash]: (
     this: PluginContext,
     chunk: RenderedChunk,
@@ -235,7 +266,9 @@ export type SequentialPluginHooks = DefinedHookNames[
   | 'options'
   | 'outputOptions'
   | 'renderChunk'
-  | 'transform']
+  | 'renderCssChunk'
+  | 'transform'
+  | 'transformCss']
 
 export type AddonHooks = DefinedHookNames[
   | 'banner'
@@ -248,6 +281,7 @@ export type OutputPluginHooks = DefinedHookNames[
   | 'generateBundle'
   | 'outputOptions'
   | 'renderChunk'
+  | 'renderCssChunk'
   // | 'renderDynamicImport'
   | 'renderError'
   | 'renderStart'
@@ -265,20 +299,25 @@ export type hookFilterExtension<K extends keyof FunctionPluginHooks> =
     : K extends 'load' | 'resolveId'
       ? { filter?: Omit<BaseHookFilter, 'code' | 'moduleType'> }
       : {}
+export type StringOrRegExp = string | RegExp
+/**
+ * A pattern or a list of patterns is a shorthand for `{ include: patterns }`.
+ */
+export type StringFilter =
+  | StringOrRegExp
+  | StringOrRegExp[]
+  | {
+      include?: StringOrRegExp | StringOrRegExp[]
+      exclude?: StringOrRegExp | StringOrRegExp[]
+    }
 export type BaseHookFilter = {
-  id?: {
-    include?: (string | RegExp)[]
-    exclude?: (string | RegExp)[]
-  }
+  id?: StringFilter
   moduleType?:
     | ModuleType[]
     | {
         include?: ModuleType[]
       }
-  code?: {
-    include?: (string | RegExp)[]
-    exclude?: (string | RegExp)[]
-  }
+  code?: StringFilter
 }
 export type PluginHooks = {
   [K in keyof FunctionPluginHooks]: ObjectHook<
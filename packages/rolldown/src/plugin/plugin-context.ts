@@ -21,7 +21,16 @@ export interface EmittedAsset {
   source: AssetSource
 }
 
-export type EmittedFile = EmittedAsset
+export interface EmittedChunk {
+  type: 'chunk'
+  name?: string
+  fileName?: string
+  id: string
+  importer?: string
+  implicitlyLoadedAfterOneOf?: string[]
+}
+
+export type EmittedFile = EmittedAsset | EmittedChunk
 
 export interface PluginContextResolveOptions {
   skipSelf?: boolean
@@ -39,7 +48,7 @@ export class PluginContext extends MinimalPluginContext {
     importer?: string,
     options?: PluginContextResolveOptions,
   ) => Promise<ResolvedId | null>
-  readonly emitFile: (file: EmittedAsset) => string
+  readonly emitFile: (file: EmittedFile) => string
   readonly getFileName: (referenceId: string) => string
   readonly getModuleInfo: (id: string) => ModuleInfo | null
   readonly getModuleIds: () => IterableIterator<string>
@@ -73,10 +82,19 @@ export class PluginContext extends MinimalPluginContext {
       const info = data.getModuleOption(res.id) || ({} as ModuleOptions)
       return { ...res, ...info }
     }
-    this.emitFile = (file: EmittedAsset): string => {
+    this.emitFile = (file: EmittedFile): string => {
+      if (file.type === 'chunk') {
+        return context.emitChunk({
+          name: file.name,
+          fileName: file.fileName,
+          id: file.id,
+          importer: file.importer,
+          implicitlyLoadedAfterOneOf: file.implicitlyLoadedAfterOneOf,
+        })
+      }
       if (file.type !== 'asset') {
         return unimplemented(
-          'PluginContext.emitFile: only asset type is supported',
+          'PluginContext.emitFile: only asset and chunk types are supported',
         )
       }
       return context.emitFile({
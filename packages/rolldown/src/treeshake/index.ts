@@ -1,4 +1,5 @@
 import {
+  HasModuleSideEffects,
   ModuleSideEffectsOptionSchema,
   NormalizedTreeshakingOptionsSchema,
 } from './module-side-effects'
@@ -9,7 +10,16 @@ export const TreeshakingOptionsSchema =
   })
 
 export interface TreeshakingOptions {
-  moduleSideEffects?: boolean | RegExp
+  moduleSideEffects?:
+    | boolean
+    | RegExp
+    | 'no-external'
+    | string[]
+    | HasModuleSideEffects
+  manualPureFunctions?: (string | RegExp)[]
+  propertyReadSideEffects?: boolean | 'always'
+  annotations?: boolean
+  unknownGlobalSideEffects?: boolean
 }
 
 export * from './module-side-effects'
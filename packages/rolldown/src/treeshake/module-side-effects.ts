@@ -1,4 +1,5 @@
 import { z } from 'zod'
+import * as zodExt from '../utils/zod-ext'
 
 export const HasModuleSideEffectsSchema = z
   .function()
@@ -10,12 +11,40 @@ export type ModuleSideEffectsOption = z.infer<
   typeof ModuleSideEffectsOptionSchema
 >
 
-export const ModuleSideEffectsOptionSchema = z.boolean().or(z.string())
+export const ModuleSideEffectsOptionSchema = z
+  .boolean()
+  .or(z.string())
+  .or(z.string().array())
+  .or(HasModuleSideEffectsSchema)
 
 export const NormalizedTreeshakingOptionsSchema = z.strictObject({
   moduleSideEffects: ModuleSideEffectsOptionSchema,
+  manualPureFunctions: zodExt
+    .stringOrRegExp()
+    .array()
+    .describe('calls of these functions are side-effect free')
+    .optional(),
+  propertyReadSideEffects: z
+    .boolean()
+    .or(z.literal('always'))
+    .describe('whether reading a property may have side effects')
+    .optional(),
+  annotations: z
+    .boolean()
+    .describe(
+      'whether to respect `/*#__PURE__*/` and `/*#__NO_SIDE_EFFECTS__*/` annotations',
+    )
+    .optional(),
+  unknownGlobalSideEffects: z
+    .boolean()
+    .describe('whether reading unknown global variables may have side effects')
+    .optional(),
 })
 
 export type NormalizedTreeshakingOptions = {
-  moduleSideEffects: string
+  moduleSideEffects: string | string[] | HasModuleSideEffects
+  manualPureFunctions?: (string | RegExp)[]
+  propertyReadSideEffects?: boolean | 'always'
+  annotations?: boolean
+  unknownGlobalSideEffects?: boolean
 }
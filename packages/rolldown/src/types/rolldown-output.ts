@@ -43,6 +43,11 @@ export interface RolldownOutputChunk {
   }
   imports: string[]
   dynamicImports: string[]
+  /**
+   * Css files generated for this chunk. They are split along the same boundaries as the chunks,
+   * so loading a lazy chunk only needs the css listed here.
+   */
+  importedCss: string[]
   facadeModuleId: string | null
   isDynamicEntry: boolean
   moduleIds: string[]
@@ -53,15 +58,67 @@ export interface RolldownOutputChunk {
 
 function _assertRolldownOutputChunk() {
   type _ = TypeAssert<
-    IsPropertiesEqual<Omit<RolldownOutputChunk, 'modules' | 'map'>, OutputChunk>
+    IsPropertiesEqual<
+      Omit<RolldownOutputChunk, 'modules' | 'map' | 'importedCss'>,
+      OutputChunk
+    >
   >
 }
 
+export interface StageStats {
+  /**
+   * Time spent in the stage, in milliseconds.
+   */
+  duration: number
+}
+
+export interface ScanStats extends StageStats {
+  /**
+   * Modules imported by several importers are only loaded once, the other requests of them are deduplicated.
+   * The unique requests are the cache misses, the deduplicated ones are the cache hits.
+   */
+  uniqueModuleRequests: number
+  deduplicatedModuleRequests: number
+}
+
+/**
+ * The module requests are the only cache of a build, so only the scan stage reports cache hits and misses.
+ * The link and generate stages don't cache anything and only report their timing.
+ */
+export interface BuildStats {
+  scan: ScanStats
+  link: StageStats
+  generate: StageStats
+  modules: {
+    esm: number
+    cjs: number
+    json: number
+    css: number
+    asset: number
+    wasm: number
+    empty: number
+    external: number
+  }
+  /**
+   * Bytes of the sources returned by the `load` hook or read from the file system.
+   */
+  bytesRead: number
+  chunksRendered: number
+}
+
 export interface RolldownOutput {
   output: [
     RolldownOutputChunk,
     ...(RolldownOutputChunk | RolldownOutputAsset)[],
   ]
+  /**
+   * Only available on the result of `generate` and `write`.
+   */
+  stats?: BuildStats
+  /**
+   * The SARIF log of the diagnostics, only available if `diagnosticsFormat` is `sarif`.
+   */
+  sarif?: string
 }
 
 function _assertRolldownOutput() {
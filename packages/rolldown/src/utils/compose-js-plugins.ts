@@ -25,9 +25,11 @@ const unsupportedHookName = [
   'onLog',
   'options',
   'outputOptions',
+  'renderCssChunk',
   'renderError',
   'renderStart',
   'resolveDynamicImport',
+  'transformCss',
   'writeBundle',
 ] as const
 const unsupportedHooks: Set<string> = new Set(unsupportedHookName)
@@ -472,11 +474,15 @@ function isComposablePlugin(plugin: RolldownPlugin): plugin is Plugin {
     }
 
     if (plugin[hookName]) {
-      const { meta } = normalizeHook(plugin[hookName])
+      const { meta, options } = normalizeHook(plugin[hookName])
       // if `order` is specified with `pre` or `post`, it's unsafe to compose this plugin
       if (meta.order === 'pre' || meta.order === 'post') {
         return !OK_TO_COMPOSE
       }
+      // the composed hook is called for every module, which would ignore the `filter`
+      if ('filter' in options && options.filter) {
+        return !OK_TO_COMPOSE
+      }
     }
 
     return OK_TO_COMPOSE
@@ -35,6 +35,7 @@ export async function createBundler(
     const bindingInputOptions = bindingifyInputOptions(
       normalizedInputOptions,
       normalizedOutputOptions,
+      inputOptions,
     )
 
     return {
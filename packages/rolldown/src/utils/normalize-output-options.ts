@@ -15,6 +15,8 @@ export function normalizeOutputOptions(
     globals,
     entryFileNames,
     chunkFileNames,
+    cssEntryFileNames,
+    cssChunkFileNames,
     assetFileNames,
     name,
     esModule,
@@ -36,19 +38,51 @@ export function normalizeOutputOptions(
     footer: getAddon(opts, 'footer'),
     intro: getAddon(opts, 'intro'),
     outro: getAddon(opts, 'outro'),
+    cssBanner: getAddon(opts, 'cssBanner'),
+    cssFooter: getAddon(opts, 'cssFooter'),
+    strict: opts.strict,
     esModule: esModule ?? 'if-default-prop',
+    interop: opts.interop ?? 'auto',
+    generatedCode: opts.generatedCode ?? {},
     // TODO support functions
     globals: globals ?? {},
     entryFileNames: entryFileNames ?? '[name].js',
     chunkFileNames: chunkFileNames ?? '[name]-[hash].js',
+    cssEntryFileNames: cssEntryFileNames ?? '[name].css',
+    cssChunkFileNames: cssChunkFileNames ?? '[name]-[hash].css',
     assetFileNames: assetFileNames ?? 'assets/[name]-[hash][extname]',
+    hashCharacters: opts.hashCharacters ?? 'base64',
+    sanitizeFileName: opts.sanitizeFileName ?? true,
+    base: opts.base,
     plugins: [],
     minify: opts.minify,
+    compact: opts.compact ?? false,
     extend: opts.extend,
     name,
+    noConflict: opts.noConflict ?? false,
     externalLiveBindings: opts.externalLiveBindings ?? true,
+    externalImportAttributes: opts.externalImportAttributes ?? true,
+    freeze: opts.freeze ?? true,
     inlineDynamicImports: opts.inlineDynamicImports ?? false,
+    dynamicImportInCjs: opts.dynamicImportInCjs ?? true,
+    manualChunks: opts.manualChunks,
     advancedChunks: opts.advancedChunks,
+    experimentalMinChunkSize: opts.experimentalMinChunkSize,
+    chunkNameStrategy: opts.chunkNameStrategy ?? 'firstEntered',
+    chunkSizeWarningLimit:
+      typeof opts.chunkSizeWarningLimit === 'number'
+        ? { raw: opts.chunkSizeWarningLimit }
+        : opts.chunkSizeWarningLimit,
+    runtimeChunk: opts.runtimeChunk ?? false,
+    keepNames: opts.keepNames ?? false,
+    target: opts.target ?? 'esnext',
+    legalComments: opts.legalComments ?? 'inline',
+    charset: opts.charset ?? 'utf8',
+    preserveModules: opts.preserveModules ?? false,
+    preserveModulesRoot: opts.preserveModulesRoot,
+    publicDir: opts.publicDir,
+    sesCompatible: opts.sesCompatible ?? false,
+    cssMode: opts.cssMode ?? 'extract',
   }
 }
 
@@ -76,12 +110,19 @@ function getFormat(
       return 'umd'
     }
 
+    case 'system':
+    case 'systemjs': {
+      return 'system'
+    }
+
     default:
       unimplemented(`output.format: ${format}`)
   }
 }
 
-const getAddon = <T extends 'banner' | 'footer' | 'intro' | 'outro'>(
+const getAddon = <
+  T extends 'banner' | 'footer' | 'intro' | 'outro' | 'cssBanner' | 'cssFooter',
+>(
   config: OutputOptions,
   name: T,
 ): NormalizedOutputOptions[T] => {
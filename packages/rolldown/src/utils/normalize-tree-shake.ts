@@ -15,11 +15,20 @@ export function normalizeTreeshakeOptions(
   }
   let normalizedConfig: NormalizedTreeshakingOptions = {
     moduleSideEffects: '',
+    manualPureFunctions: config.manualPureFunctions,
+    propertyReadSideEffects: config.propertyReadSideEffects,
+    annotations: config.annotations,
+    unknownGlobalSideEffects: config.unknownGlobalSideEffects,
   }
   if (config.moduleSideEffects === undefined) {
     normalizedConfig.moduleSideEffects = 'true'
   } else if (isRegExp(config.moduleSideEffects)) {
     normalizedConfig.moduleSideEffects = config.moduleSideEffects.source
+  } else if (
+    Array.isArray(config.moduleSideEffects) ||
+    typeof config.moduleSideEffects === 'function'
+  ) {
+    normalizedConfig.moduleSideEffects = config.moduleSideEffects
   } else {
     normalizedConfig.moduleSideEffects = config.moduleSideEffects.toString()
   }
@@ -41,6 +41,9 @@ function transformToRollupOutputChunk(
     get dynamicImports() {
       return bindingChunk.dynamicImports
     },
+    get importedCss() {
+      return bindingChunk.importedCss
+    },
     exports: bindingChunk.exports,
     isEntry: bindingChunk.isEntry,
     facadeModuleId: bindingChunk.facadeModuleId || null,
@@ -103,12 +106,14 @@ export function transformToRollupOutput(
   output: BindingOutputs,
   changed?: ChangedOutputs,
 ): RolldownOutput {
-  const { chunks, assets } = output
+  const { chunks, assets, stats, sarif } = output
   return {
     output: [
       ...chunks.map((chunk) => transformToRollupOutputChunk(chunk, changed)),
       ...assets.map((asset) => transformToRollupOutputAsset(asset, changed)),
     ],
+    stats: stats ?? undefined,
+    sarif: sarif ?? undefined,
   } as RolldownOutput
 }
 
@@ -169,6 +174,7 @@ export function collectChangedBundle(
         ),
         imports: item.imports,
         dynamicImports: item.dynamicImports,
+        importedCss: item.importedCss,
         facadeModuleId: item.facadeModuleId || undefined,
         isDynamicEntry: item.isDynamicEntry,
         moduleIds: item.moduleIds,
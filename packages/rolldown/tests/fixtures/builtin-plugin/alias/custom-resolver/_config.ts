@@ -0,0 +1,41 @@
+import { aliasPlugin } from 'rolldown/experimental'
+import { defineTest } from '@tests'
+import { expect } from 'vitest'
+import path from 'node:path'
+
+const calls: [string, string | undefined][] = []
+
+export default defineTest({
+  config: {
+    input: './main.js',
+    plugins: [
+      aliasPlugin({
+        entries: [
+          {
+            find: '@lib',
+            replacement: './lib',
+            async customResolver(id, importer) {
+              calls.push([id, importer])
+              if (id === './lib/a') {
+                return path.join(import.meta.dirname, 'lib/a.js')
+              }
+            },
+          },
+        ],
+      }),
+    ],
+  },
+  beforeTest() {
+    calls.length = 0
+  },
+  async afterTest() {
+    const importer = path.join(import.meta.dirname, 'main.js')
+    // Imports are resolved concurrently.
+    calls.sort(([a], [b]) => a.localeCompare(b))
+    expect(calls).toStrictEqual([
+      ['./lib/a', importer],
+      ['./lib/b.js', importer],
+    ])
+    await import('./assert.mjs')
+  },
+})
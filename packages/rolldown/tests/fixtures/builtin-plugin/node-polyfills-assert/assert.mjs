@@ -0,0 +1,107 @@
+// @ts-nocheck
+import nodeAssert from 'node:assert'
+import { inspect } from 'node:util'
+import assert from './dist/main'
+
+function outcome(fn) {
+  try {
+    fn()
+    return 'passed'
+  } catch (error) {
+    return error.name
+  }
+}
+
+const values = [
+  1,
+  '1',
+  0,
+  -0,
+  NaN,
+  null,
+  undefined,
+  [1, 2],
+  [1, '2'],
+  { a: 1 },
+  { a: '1' },
+  { a: [1, { b: 2 }] },
+  { a: [1, { b: '2' }] },
+  new Date(0),
+  new Date(1),
+  /a/g,
+  /a/i,
+  new Map([[1, 2]]),
+  new Map([[1, 3]]),
+  new Set([1]),
+  new Set(['1']),
+  Object.create(null),
+  new Error('a'),
+  new Error('b'),
+]
+
+// The comparisons pass and fail for the same values as node's.
+for (const method of [
+  'equal',
+  'notEqual',
+  'strictEqual',
+  'notStrictEqual',
+  'deepEqual',
+  'notDeepEqual',
+  'deepStrictEqual',
+  'notDeepStrictEqual',
+]) {
+  for (const actual of values) {
+    for (const expected of values) {
+      nodeAssert.strictEqual(
+        outcome(() => assert[method](actual, expected)),
+        outcome(() => nodeAssert[method](actual, expected)),
+        `${method}(${inspect(actual)}, ${inspect(expected)})`,
+      )
+    }
+  }
+}
+
+nodeAssert.throws(() => assert.strictEqual(1, 2), assert.AssertionError)
+nodeAssert.throws(() => assert.strictEqual(1, 2), {
+  code: 'ERR_ASSERTION',
+  actual: 1,
+  expected: 2,
+  operator: 'strictEqual',
+  generatedMessage: true,
+})
+nodeAssert.throws(() => assert.ok(0, 'custom'), { message: 'custom' })
+nodeAssert.throws(() => assert.fail(), { name: 'AssertionError' })
+
+function throwTypeError() {
+  throw new TypeError('thrown')
+}
+assert.throws(throwTypeError, TypeError)
+assert.throws(throwTypeError, /thrown/)
+assert.throws(throwTypeError, { message: 'thrown' })
+nodeAssert.throws(() => assert.throws(() => {}), { name: 'AssertionError' })
+nodeAssert.throws(() => assert.doesNotThrow(throwTypeError), {
+  name: 'AssertionError',
+})
+await assert.rejects(Promise.reject(new Error('rejected')), /rejected/)
+await assert.doesNotReject(async () => {})
+await nodeAssert.rejects(assert.rejects(Promise.resolve()), {
+  name: 'AssertionError',
+})
+
+assert.match('abc', /b/)
+nodeAssert.throws(() => assert.match('abc', /d/), { name: 'AssertionError' })
+assert.doesNotMatch('abc', /d/)
+assert.ifError(null)
+nodeAssert.throws(() => assert.ifError(new Error('failed')), {
+  name: 'AssertionError',
+  message: 'ifError got unwanted exception: failed',
+})
+
+// `assert.strict` uses the strict comparisons.
+nodeAssert.throws(() => assert.strict.equal(1, '1'), {
+  name: 'AssertionError',
+})
+nodeAssert.throws(() => assert.strict.deepEqual([1], ['1']), {
+  name: 'AssertionError',
+})
+nodeAssert.strictEqual(assert.strict.strict, assert.strict)
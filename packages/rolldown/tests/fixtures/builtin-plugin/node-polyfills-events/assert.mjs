@@ -0,0 +1,67 @@
+// @ts-nocheck
+import assert from 'node:assert'
+import EventEmitter from './dist/main'
+
+assert.strictEqual(EventEmitter.EventEmitter, EventEmitter)
+assert.strictEqual(EventEmitter.defaultMaxListeners, 10)
+
+const emitter = new EventEmitter()
+const calls = []
+const listener = (value) => calls.push(`on ${value}`)
+emitter.on('newListener', (type) => calls.push(`newListener ${type}`))
+emitter.on('event', listener)
+emitter.prependListener('event', (value) => calls.push(`prepend ${value}`))
+emitter.once('event', (value) => calls.push(`once ${value}`))
+emitter.prependOnceListener('event', (value) =>
+  calls.push(`prependOnce ${value}`),
+)
+assert.strictEqual(emitter.emit('event', 1), true)
+emitter.emit('event', 2)
+assert.deepStrictEqual(calls, [
+  'newListener event',
+  'newListener event',
+  'newListener event',
+  'newListener event',
+  'prependOnce 1',
+  'prepend 1',
+  'on 1',
+  'once 1',
+  'prepend 2',
+  'on 2',
+])
+assert.strictEqual(emitter.listenerCount('event'), 2)
+assert.strictEqual(EventEmitter.listenerCount(emitter, 'event'), 2)
+assert.deepStrictEqual(emitter.eventNames(), ['newListener', 'event'])
+
+emitter.off('event', listener)
+assert.strictEqual(emitter.listenerCount('event'), 1)
+emitter.removeAllListeners('event')
+assert.strictEqual(emitter.emit('event', 3), false)
+
+emitter.setMaxListeners(1)
+assert.strictEqual(emitter.getMaxListeners(), 1)
+
+// Errors without listeners are thrown.
+assert.throws(() => emitter.emit('error', new Error('unhandled')), /unhandled/)
+assert.throws(() => emitter.emit('error', 'message'), /Unhandled error/)
+let handled
+emitter.on('error', (error) => (handled = error.message))
+emitter.emit('error', new Error('handled'))
+assert.strictEqual(handled, 'handled')
+
+const ready = new EventEmitter()
+setTimeout(() => ready.emit('ready', 1, 2))
+assert.deepStrictEqual(await EventEmitter.once(ready, 'ready'), [1, 2])
+setTimeout(() => ready.emit('error', new Error('failed')))
+await assert.rejects(EventEmitter.once(ready, 'ready'), /failed/)
+
+// Emitters can be extended the ES5 way.
+function Legacy() {
+  EventEmitter.call(this)
+}
+Object.setPrototypeOf(Legacy.prototype, EventEmitter.prototype)
+const legacy = new Legacy()
+let received = false
+legacy.on('event', () => (received = true))
+legacy.emit('event')
+assert.ok(received)
@@ -0,0 +1,18 @@
+// @ts-nocheck
+import assert from 'node:assert'
+import os from './dist/main'
+
+assert.strictEqual(os.EOL, '\n')
+assert.strictEqual(os.platform(), 'browser')
+assert.strictEqual(os.type(), 'Browser')
+assert.strictEqual(os.tmpdir(), '/tmp')
+assert.strictEqual(os.homedir(), '/')
+assert.ok(['LE', 'BE'].includes(os.endianness()))
+assert.ok(os.availableParallelism() >= 1)
+assert.deepStrictEqual(os.cpus(), [])
+assert.deepStrictEqual(os.loadavg(), [0, 0, 0])
+assert.deepStrictEqual(os.networkInterfaces(), {})
+assert.strictEqual(os.userInfo().homedir, '/')
+for (const method of ['totalmem', 'freemem', 'uptime']) {
+  assert.strictEqual(typeof os[method](), 'number')
+}
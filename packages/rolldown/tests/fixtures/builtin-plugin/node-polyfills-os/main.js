@@ -0,0 +1,3 @@
+import os from 'os'
+
+export default os
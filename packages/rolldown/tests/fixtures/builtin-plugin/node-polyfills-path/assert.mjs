@@ -0,0 +1,58 @@
+// @ts-nocheck
+import assert from 'node:assert'
+import { posix } from 'node:path'
+import path from './dist/main'
+
+const paths = [
+  '',
+  '.',
+  '..',
+  '/',
+  'a',
+  'a/',
+  '/a/b/',
+  'a//b/../c',
+  './a/./b',
+  '../../a',
+  '/../a',
+  '.a',
+  'a.b.c',
+  '/a/b.c.d',
+  'foo/bar.tar.gz',
+]
+
+// The polyfill behaves like the posix implementation of node.
+for (const p of paths) {
+  for (const method of [
+    'normalize',
+    'isAbsolute',
+    'dirname',
+    'basename',
+    'extname',
+    'parse',
+  ]) {
+    assert.deepStrictEqual(path[method](p), posix[method](p), `${method}(${p})`)
+  }
+  assert.strictEqual(path.basename(p, '.c'), posix.basename(p, '.c'))
+  assert.strictEqual(path.format(posix.parse(p)), posix.format(posix.parse(p)))
+  for (const q of paths) {
+    assert.strictEqual(path.join(p, q), posix.join(p, q), `join(${p}, ${q})`)
+    // `process.cwd()` is `/` in browsers, so only paths resolved from absolute ones are compared.
+    assert.strictEqual(
+      path.resolve('/root', p, q),
+      posix.resolve('/root', p, q),
+      `resolve(/root, ${p}, ${q})`,
+    )
+    assert.strictEqual(
+      path.relative(`/${p}`, `/${q}`),
+      posix.relative(`/${p}`, `/${q}`),
+      `relative(/${p}, /${q})`,
+    )
+  }
+}
+
+assert.strictEqual(path.resolve('a'), '/a')
+assert.strictEqual(path.toNamespacedPath('/a'), '/a')
+assert.strictEqual(path.sep, '/')
+assert.strictEqual(path.delimiter, ':')
+assert.strictEqual(path.posix, path)
@@ -0,0 +1,28 @@
+// @ts-nocheck
+import assert from 'node:assert'
+import process from './dist/main'
+
+assert.strictEqual(process.platform, 'browser')
+assert.strictEqual(process.browser, true)
+assert.deepStrictEqual(process.env, {})
+assert.deepStrictEqual(process.argv, [])
+assert.strictEqual(process.cwd(), '/')
+assert.throws(() => process.chdir('/a'), /not supported/)
+assert.throws(() => process.binding('fs'), /not supported/)
+assert.strictEqual(typeof process.uptime(), 'number')
+
+const [seconds, nanoseconds] = process.hrtime()
+assert.ok(seconds >= 0 && nanoseconds >= 0 && nanoseconds < 1e9)
+const [diffSeconds] = process.hrtime([seconds, nanoseconds])
+assert.strictEqual(diffSeconds, 0)
+
+// `nextTick` callbacks run after the synchronous code, with their arguments.
+const order = []
+process.nextTick((a, b) => order.push(`tick ${a} ${b}`), 1, 2)
+order.push('sync')
+await new Promise((resolve) => setTimeout(resolve))
+assert.deepStrictEqual(order, ['sync', 'tick 1 2'])
+
+// The event methods are no-ops, so code registering `process` listeners still runs.
+process.on('exit', () => {})
+assert.deepStrictEqual(process.listeners('exit'), [])
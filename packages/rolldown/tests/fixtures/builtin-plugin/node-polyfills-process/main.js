@@ -0,0 +1,3 @@
+import process from 'process'
+
+export default process
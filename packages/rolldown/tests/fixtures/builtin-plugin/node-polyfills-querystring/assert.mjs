@@ -0,0 +1,49 @@
+// @ts-nocheck
+import assert from 'node:assert'
+import nodeQuerystring from 'node:querystring'
+import querystring from './dist/main'
+
+// The polyfill behaves like node.
+for (const qs of [
+  '',
+  'a=1&b=2',
+  'a=1&a=2&a=3',
+  'a',
+  'a=',
+  '=b',
+  'a=b=c',
+  'a+b=c+d',
+  'a%20b=%E4%BD%A0',
+  'a=%zz',
+  '&&a=1&&',
+]) {
+  assert.deepStrictEqual(
+    { ...querystring.parse(qs) },
+    { ...nodeQuerystring.parse(qs) },
+    `parse(${qs})`,
+  )
+  assert.deepStrictEqual(
+    { ...querystring.parse(qs, '&', '+') },
+    { ...nodeQuerystring.parse(qs, '&', '+') },
+  )
+  assert.strictEqual(querystring.unescape(qs), nodeQuerystring.unescape(qs))
+}
+
+for (const obj of [
+  {},
+  { a: 1 },
+  { a: 'x y', b: ['1', '2'] },
+  { a: true, b: null, c: undefined, d: NaN, e: {} },
+  { ä: '你', "!*()'": '~-_.' },
+  { a: [] },
+]) {
+  assert.strictEqual(querystring.stringify(obj), nodeQuerystring.stringify(obj))
+  assert.strictEqual(
+    querystring.stringify(obj, ';', ':'),
+    nodeQuerystring.stringify(obj, ';', ':'),
+  )
+}
+
+assert.strictEqual(querystring.escape("a b!'()*~"), "a%20b!'()*~")
+assert.strictEqual(querystring.decode, querystring.parse)
+assert.strictEqual(querystring.encode, querystring.stringify)
@@ -0,0 +1,13 @@
+import { nodePolyfillsPlugin } from 'rolldown/experimental'
+import { defineTest } from '@tests'
+
+export default defineTest({
+  config: {
+    input: './main.js',
+    platform: 'browser',
+    plugins: [nodePolyfillsPlugin()],
+  },
+  async afterTest() {
+    await import('./assert.mjs')
+  },
+})
@@ -0,0 +1,157 @@
+// @ts-nocheck
+import assert from 'node:assert'
+import Stream from './dist/main'
+
+const {
+  Readable,
+  Writable,
+  Duplex,
+  Transform,
+  PassThrough,
+  pipeline,
+  finished,
+} = Stream
+
+assert.ok(new PassThrough() instanceof Transform)
+assert.ok(new Transform() instanceof Duplex)
+assert.ok(new Duplex() instanceof Readable)
+assert.ok(new Readable() instanceof Stream)
+
+// Async iteration of `Readable.from`.
+const iterated = []
+for await (const chunk of Readable.from(['a', 'b', 'c'])) {
+  iterated.push(chunk)
+}
+assert.deepStrictEqual(iterated, ['a', 'b', 'c'])
+
+// Flowing mode with an encoding.
+const flowing = new Readable({ read() {} })
+flowing.setEncoding('utf8')
+const flowed = []
+flowing.on('data', (chunk) => flowed.push(chunk))
+const flowingEnd = new Promise((resolve) => flowing.on('end', resolve))
+flowing.push('he')
+flowing.push(new TextEncoder().encode('llo'))
+flowing.push(null)
+await flowingEnd
+assert.deepStrictEqual(flowed, ['he', 'llo'])
+
+// Paused mode.
+const paused = new Readable({ read() {} })
+paused.push('x')
+paused.push('y')
+paused.push(null)
+let read = ''
+await new Promise((resolve) => {
+  paused.on('readable', () => {
+    let chunk
+    while ((chunk = paused.read()) !== null) {
+      read += chunk
+    }
+  })
+  paused.on('end', resolve)
+})
+assert.strictEqual(read, 'xy')
+
+// Backpressure of writables.
+const written = []
+const writable = new Writable({
+  highWaterMark: 2,
+  write(chunk, _encoding, callback) {
+    written.push(String(chunk))
+    setTimeout(callback)
+  },
+})
+assert.strictEqual(writable.write('abc'), false)
+const writableFinish = new Promise((resolve) => writable.on('finish', resolve))
+writable.end('d')
+await writableFinish
+assert.deepStrictEqual(written, ['abc', 'd'])
+
+// Piping through a transform with a flush.
+const upperCase = new Transform({
+  transform(chunk, _encoding, callback) {
+    callback(null, String(chunk).toUpperCase())
+  },
+  flush(callback) {
+    this.push('!')
+    callback()
+  },
+})
+const piped = []
+const sink = new Writable({
+  write(chunk, _encoding, callback) {
+    piped.push(String(chunk))
+    callback()
+  },
+})
+const sinkFinish = new Promise((resolve) => sink.on('finish', resolve))
+Readable.from(['a', 'b']).pipe(upperCase).pipe(sink)
+await sinkFinish
+assert.strictEqual(piped.join(''), 'AB!')
+
+// Object mode.
+const objects = []
+await new Promise((resolve, reject) =>
+  pipeline(
+    Readable.from([{ n: 1 }, { n: 2 }]),
+    new PassThrough({ objectMode: true }),
+    new Writable({
+      objectMode: true,
+      write(chunk, _encoding, callback) {
+        objects.push(chunk)
+        callback()
+      },
+    }),
+    (error) => (error ? reject(error) : resolve()),
+  ),
+)
+assert.deepStrictEqual(objects, [{ n: 1 }, { n: 2 }])
+
+// Errors are forwarded to the pipeline callback.
+const pipelineError = await new Promise((resolve) =>
+  pipeline(
+    Readable.from(['a']),
+    new Transform({
+      transform(_chunk, _encoding, callback) {
+        callback(new Error('bad chunk'))
+      },
+    }),
+    new PassThrough(),
+    resolve,
+  ),
+)
+assert.strictEqual(pipelineError.message, 'bad chunk')
+
+const drained = Readable.from(['z'])
+drained.resume()
+assert.strictEqual(
+  await new Promise((resolve) => finished(drained, resolve)),
+  undefined,
+)
+
+const destroyed = new Readable({ read() {} })
+const destroyError = new Promise((resolve) => destroyed.on('error', resolve))
+destroyed.destroy(new Error('gone'))
+assert.strictEqual((await destroyError).message, 'gone')
+assert.strictEqual(destroyed.destroyed, true)
+
+// Duplexes have independent readable and writable sides.
+const echoed = []
+const echo = new Duplex({
+  read() {},
+  write(chunk, _encoding, callback) {
+    this.push(`${chunk}!`)
+    callback()
+  },
+  final(callback) {
+    this.push(null)
+    callback()
+  },
+})
+echo.on('data', (chunk) => echoed.push(String(chunk)))
+const echoEnd = new Promise((resolve) => echo.on('end', resolve))
+echo.write('a')
+echo.end()
+await echoEnd
+assert.deepStrictEqual(echoed, ['a!'])
@@ -0,0 +1,3 @@
+import stream from 'stream'
+
+export default stream
@@ -0,0 +1,26 @@
+// @ts-nocheck
+import assert from 'node:assert'
+import timers from './dist/main'
+
+const calls = []
+timers.setTimeout((value) => calls.push(`timeout ${value}`), 1, 'a')
+timers.clearTimeout(timers.setTimeout(() => calls.push('cleared timeout'), 1))
+timers.setImmediate((value) => calls.push(`immediate ${value}`), 'b')
+timers.clearImmediate(
+  timers.setImmediate(() => calls.push('cleared immediate')),
+)
+let ticks = 0
+const interval = timers.setInterval(() => {
+  calls.push('interval')
+  if (++ticks === 2) {
+    timers.clearInterval(interval)
+  }
+}, 1)
+
+await new Promise((resolve) => setTimeout(resolve, 50))
+assert.deepStrictEqual(calls.sort(), [
+  'immediate b',
+  'interval',
+  'interval',
+  'timeout a',
+])
@@ -0,0 +1,3 @@
+import timers from 'timers'
+
+export default timers
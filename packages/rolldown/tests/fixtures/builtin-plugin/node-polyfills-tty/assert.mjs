@@ -0,0 +1,8 @@
+// @ts-nocheck
+import assert from 'node:assert'
+import tty from './dist/main'
+
+// Browsers have no terminals.
+assert.strictEqual(tty.isatty(1), false)
+assert.throws(() => new tty.ReadStream(), /not supported/)
+assert.throws(() => new tty.WriteStream(), /not supported/)
@@ -0,0 +1,3 @@
+import tty from 'tty'
+
+export default tty
@@ -0,0 +1,115 @@
+// @ts-nocheck
+import assert from 'node:assert'
+import nodeUtil from 'node:util'
+import util from './dist/main'
+
+// The polyfill formats like node, except for `%o` which doesn't show hidden properties.
+for (const args of [
+  ['%s-%s', 'a'],
+  ['%s %s', 'a', 'b', 'c'],
+  ['%d', '42'],
+  ['%d', 1n],
+  ['%i', 4.7],
+  ['%f', '1.5x'],
+  ['%j', { a: 1 }],
+  ['%j'],
+  ['%%', 1],
+  ['%s', { a: 1 }],
+  ['%s', [1, 'a']],
+  ['%s', null],
+  ['%s', 1n],
+  ['%s', Symbol('s')],
+  ['%s', () => {}],
+  ['%O', { a: { b: { c: { d: 1 } } } }],
+  ['a', 'b', 3],
+  [1, 2],
+  ['x', { a: 'b' }],
+]) {
+  assert.strictEqual(util.format(...args), nodeUtil.format(...args))
+}
+
+for (const value of [
+  1,
+  'a',
+  null,
+  undefined,
+  true,
+  1n,
+  Symbol('s'),
+  [],
+  [1, 2],
+  {},
+  { a: 'str', 'b-c': [1, { x: 1 }] },
+  { a: { b: { c: { d: 1 } } } },
+  new Date(0),
+  /x/g,
+  function named() {},
+]) {
+  assert.strictEqual(util.inspect(value), nodeUtil.inspect(value))
+}
+
+const circular = { a: 1 }
+circular.self = circular
+assert.strictEqual(util.inspect(circular), '{ a: 1, self: [Circular] }')
+
+for (const value of [
+  1,
+  'a',
+  null,
+  undefined,
+  [],
+  {},
+  new Date(),
+  /x/,
+  new Error(),
+  () => {},
+  Symbol(),
+  true,
+  NaN,
+]) {
+  const isObject = typeof value === 'object' && value !== null
+  assert.strictEqual(
+    util.isPrimitive(value),
+    !isObject && typeof value !== 'function',
+  )
+  assert.strictEqual(util.isObject(value), isObject)
+  assert.strictEqual(util.isFunction(value), typeof value === 'function')
+  assert.strictEqual(util.isNullOrUndefined(value), value == null)
+  assert.strictEqual(util.isError(value), value instanceof Error)
+  assert.strictEqual(util.types.isDate(value), nodeUtil.types.isDate(value))
+  assert.strictEqual(util.types.isRegExp(value), nodeUtil.types.isRegExp(value))
+}
+
+function Base() {}
+function Derived() {}
+util.inherits(Derived, Base)
+assert.ok(new Derived() instanceof Base)
+assert.strictEqual(Derived.super_, Base)
+
+const add = util.promisify((a, b, callback) => callback(null, a + b))
+assert.strictEqual(await add(1, 2), 3)
+await assert.rejects(
+  util.promisify((callback) => callback(new Error('boom')))(),
+  /boom/,
+)
+
+const double = util.callbackify(async (value) => value * 2)
+assert.deepStrictEqual(
+  await new Promise((resolve) => double(2, (...args) => resolve(args))),
+  [null, 4],
+)
+
+const warn = console.warn
+const warnings = []
+console.warn = (message) => warnings.push(message)
+const deprecated = util.deprecate(() => 'value', 'use something else')
+assert.strictEqual(deprecated(), 'value')
+assert.strictEqual(deprecated(), 'value')
+console.warn = warn
+// The deprecation is only reported once.
+assert.deepStrictEqual(warnings, ['DeprecationWarning: use something else'])
+
+assert.strictEqual(
+  new util.TextDecoder().decode(new util.TextEncoder().encode('ä')),
+  'ä',
+)
@@ -0,0 +1,16 @@
+import { nodePolyfillsPlugin } from 'rolldown/experimental'
+import { defineTest } from '@tests'
+import { expect } from 'vitest'
+
+export default defineTest({
+  config: {
+    input: './main.js',
+    platform: 'browser',
+    plugins: [nodePolyfillsPlugin()],
+  },
+  async afterTest(output) {
+    // Unused functions of the polyfills are tree-shaken.
+    expect(output.output[0].code).not.toContain('function relative')
+    await import('./assert.mjs')
+  },
+})
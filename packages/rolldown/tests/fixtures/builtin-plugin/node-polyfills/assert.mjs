@@ -0,0 +1,9 @@
+// @ts-nocheck
+import assert from 'node:assert'
+import { joined, env, platform, isGlobalThis, hasFs } from './dist/main'
+
+assert.strictEqual(joined, 'b')
+assert.deepStrictEqual(env, {})
+assert.strictEqual(platform, 'browser')
+assert.strictEqual(isGlobalThis, true)
+assert.strictEqual(hasFs, false)
@@ -0,0 +1,16 @@
+import { join } from 'node:path'
+import { EventEmitter } from 'events'
+import fs from 'fs'
+
+const emitter = new EventEmitter()
+let received
+emitter.on('message', (message) => {
+  received = message
+})
+emitter.emit('message', join('a', '..', 'b'))
+
+export const joined = received
+export const env = process.env
+export const platform = process.platform
+export const isGlobalThis = global === globalThis
+export const hasFs = Object.keys(fs).length > 0
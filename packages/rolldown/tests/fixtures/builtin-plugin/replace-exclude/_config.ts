@@ -0,0 +1,30 @@
+import { replacePlugin } from 'rolldown/experimental'
+import { defineTest } from '@tests'
+import { expect } from 'vitest'
+
+export default defineTest({
+  config: {
+    input: ['./main.js', './other.js'],
+    plugins: [
+      replacePlugin(
+        {
+          'process.env.NODE_ENV': JSON.stringify('production'),
+        },
+        {
+          exclude: /other\.js$/,
+        },
+      ),
+    ],
+  },
+  afterTest(output) {
+    const codeOf = (fileName: string) => {
+      const chunk = output.output.find((chunk) => chunk.fileName === fileName)
+      if (chunk?.type !== 'chunk') {
+        throw new Error(`${fileName} should be emitted`)
+      }
+      return chunk.code
+    }
+    expect(codeOf('main.js')).toContain('console.log("production")')
+    expect(codeOf('other.js')).toContain('console.log(process.env.NODE_ENV)')
+  },
+})
@@ -0,0 +1,21 @@
+import { serviceWorkerPlugin } from 'rolldown/experimental'
+import { defineTest } from '@tests'
+import { expect } from 'vitest'
+
+export default defineTest({
+  config: {
+    input: ['main.js', 'sw.js'],
+    plugins: [serviceWorkerPlugin({ entry: 'sw.js' })],
+  },
+  afterTest(output) {
+    const sw = output.output.find(
+      (chunk) => chunk.type === 'chunk' && chunk.fileName === 'sw.js',
+    )
+    if (sw?.type !== 'chunk') {
+      throw new Error('sw.js should be emitted')
+    }
+    expect(sw.code).not.toContain('__ROLLDOWN_PRECACHE_MANIFEST')
+    expect(sw.code).toContain('self.addEventListener')
+    expect(sw.code).toMatch(/\[\{"url":"main\.js","revision":"[\w-]+"\}\]/)
+  },
+})
@@ -0,0 +1 @@
+navigator.serviceWorker.register('./sw.js')
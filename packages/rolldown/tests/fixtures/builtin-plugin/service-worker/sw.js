@@ -0,0 +1,6 @@
+const manifest = self.__ROLLDOWN_PRECACHE_MANIFEST
+const urls = manifest.map((entry) => entry.url)
+
+this.addEventListener('install', (event) => {
+  event.waitUntil(caches.open('precache').then((cache) => cache.addAll(urls)))
+})
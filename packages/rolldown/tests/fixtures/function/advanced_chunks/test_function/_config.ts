@@ -0,0 +1,27 @@
+import type { RolldownOutputChunk } from 'rolldown'
+import { defineTest } from '@tests'
+import { expect } from 'vitest'
+
+export default defineTest({
+  config: {
+    output: {
+      advancedChunks: {
+        groups: [
+          {
+            name: 'lib',
+            test: (id) => id.endsWith('a.js'),
+          },
+        ],
+      },
+    },
+  },
+  afterTest: (output) => {
+    const chunks = output.output.filter(
+      ({ type }) => type === 'chunk',
+    ) as RolldownOutputChunk[]
+    const lib = chunks.find((chunk) => chunk.name === 'lib')!
+    expect(lib.moduleIds.map((id) => id.split(/[\\/]/).pop())).toStrictEqual([
+      'a.js',
+    ])
+  },
+})
@@ -0,0 +1,19 @@
+import { expect } from 'vitest'
+import { defineTest } from '@tests'
+import { getOutputChunk } from '@tests/utils'
+
+export default defineTest({
+  config: {
+    define: {
+      'process.env.NODE_ENV': '"production"',
+      __DEV__: 'false',
+    },
+  },
+  afterTest(output) {
+    const code = getOutputChunk(output)[0].code
+    // The dead branches are removed, so `dev-tools.js` becomes unused.
+    expect(code).not.toContain('installDevTools')
+    expect(code).not.toContain('development build')
+    expect(code).toContain('production build')
+  },
+})
@@ -0,0 +1,3 @@
+export function installDevTools() {
+  globalThis.__devToolsInstalled = true
+}
@@ -0,0 +1,11 @@
+import { installDevTools } from './dev-tools'
+
+if (__DEV__) {
+  installDevTools()
+}
+
+if (process.env.NODE_ENV !== 'production') {
+  console.log('development build')
+} else {
+  console.log('production build')
+}
@@ -0,0 +1,18 @@
+import { expect } from 'vitest'
+import { defineTest } from '@tests'
+import { getOutputChunk } from '@tests/utils'
+
+export default defineTest({
+  config: {
+    drop: ['console', 'debugger'],
+    dropLabels: ['DEV'],
+  },
+  async afterTest(output) {
+    const code = getOutputChunk(output)[0].code
+    expect(code).not.toContain('debugger')
+    expect(code).not.toContain('console.info')
+    expect(code).not.toContain('DEV:')
+    expect(code).toContain('KEEP:')
+    await import('./assert.mjs')
+  },
+})
@@ -0,0 +1,7 @@
+// @ts-nocheck
+import assert from 'node:assert'
+import { calls, logged } from './dist/main'
+
+// Only the calls under `KEEP` and of the local `console` are left.
+assert.strictEqual(calls, 2)
+assert.strictEqual(logged, undefined)
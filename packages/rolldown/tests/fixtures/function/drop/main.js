@@ -0,0 +1,17 @@
+let calls = 0
+function count() {
+  calls++
+}
+
+console.log(count())
+export const logged = console.info.call(console, count())
+debugger
+DEV: count()
+KEEP: count()
+
+// The local `console` isn't the global one.
+;(function (console) {
+  console.log(count())
+})({ log() {} })
+
+export { calls }
@@ -0,0 +1,16 @@
+import { expect } from 'vitest'
+import { defineTest } from '@tests'
+import { getOutputChunk } from '@tests/utils'
+
+export default defineTest({
+  config: {
+    inject: {
+      $: './jquery',
+    },
+    external: ['node:assert'],
+  },
+  afterTest(output) {
+    const code = getOutputChunk(output)[0].code
+    expect(code).not.toContain('jquery')
+  },
+})
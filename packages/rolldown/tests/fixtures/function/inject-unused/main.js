@@ -0,0 +1,6 @@
+import assert from 'node:assert'
+
+// `$` is a local binding here, so it's not a use of the global.
+;(function ($) {
+  assert.strictEqual($, 'local')
+})('local')
@@ -0,0 +1,18 @@
+import path from 'node:path'
+import { defineTest } from '@tests'
+import { expect } from 'vitest'
+import { getOutputChunk } from '@tests/utils'
+
+export default defineTest({
+  config: {
+    input: 'main.jsx',
+    resolve: {
+      tsconfigFilename: path.resolve(import.meta.dirname, 'tsconfig.json'),
+    },
+    external: ['preact/jsx-runtime'],
+  },
+  afterTest: (output) => {
+    const chunk = getOutputChunk(output)[0]
+    expect(chunk.code.includes('preact/jsx-runtime')).toBe(true)
+  },
+})
@@ -0,0 +1 @@
+console.log(<div>test</div>)
@@ -0,0 +1,22 @@
+import { defineTest } from '@tests'
+import { expect, vi } from 'vitest'
+
+const onLog = vi.fn()
+
+export default defineTest({
+  config: {
+    input: 'main.ts',
+    onLog(level, log) {
+      if (log.code === 'AMBIENT_CONST_ENUM') {
+        onLog(level, log.message)
+      }
+    },
+  },
+  afterTest: () => {
+    expect(onLog).toHaveBeenCalledTimes(1)
+    expect(onLog.mock.calls[0][0]).toBe('warn')
+    expect(onLog.mock.calls[0][1]).toMatch(
+      /uses the ambient const enum `Direction`, whose definition isn't part of the build/,
+    )
+  },
+})
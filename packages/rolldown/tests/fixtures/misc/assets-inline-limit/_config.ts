@@ -0,0 +1,31 @@
+import path from 'node:path'
+import { defineTest } from '@tests'
+import { getOutputChunk, getOutputFileNames } from '@tests/utils'
+import { expect } from 'vitest'
+
+const inlineArgs: [string, number][] = []
+
+export default defineTest({
+  config: {
+    moduleTypes: {
+      '.svg': 'file',
+    },
+    assetsInlineLimit: (id, size) => {
+      inlineArgs.push([path.basename(id), size])
+      return path.basename(id) === 'icon.svg'
+    },
+    output: {
+      assetFileNames: '[name][extname]',
+    },
+  },
+  afterTest: (output) => {
+    expect(inlineArgs.sort()).toStrictEqual([
+      ['icon.svg', 47],
+      ['logo.svg', 77],
+    ])
+    expect(getOutputFileNames(output)).toStrictEqual(['logo.svg', 'main.js'])
+    const code = getOutputChunk(output)[0].code
+    expect(code).toContain('"data:image/svg+xml;base64,')
+    expect(code).toContain('"logo.svg"')
+  },
+})
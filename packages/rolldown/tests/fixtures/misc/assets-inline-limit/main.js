@@ -0,0 +1,4 @@
+import icon from './icon.svg'
+import logo from './logo.svg'
+
+export { icon, logo }
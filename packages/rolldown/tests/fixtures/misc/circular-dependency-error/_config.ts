@@ -0,0 +1,18 @@
+import { defineTest } from '@tests'
+import { expect } from 'vitest'
+
+export default defineTest({
+  config: {
+    input: './main.js',
+    checks: {
+      circularDependency: true,
+      circularDependencyError: [/[ab]\.js$/],
+    },
+  },
+  afterTest() {
+    expect.unreachable()
+  },
+  catchError(err) {
+    expect(String(err)).toContain('Circular dependency')
+  },
+})
@@ -0,0 +1,26 @@
+import { defineTest } from '@tests'
+import { expect, vi } from 'vitest'
+
+const onLog = vi.fn()
+
+export default defineTest({
+  config: {
+    input: './main.js',
+    checks: {
+      circularDependency: true,
+      circularDependencyIgnore: [/vendor/],
+    },
+    onLog(level, log) {
+      if (log.code === 'CIRCULAR_DEPENDENCY') {
+        onLog(level, log.message)
+      }
+    },
+  },
+  afterTest: () => {
+    // The cycle between the vendor modules is ignored.
+    expect(onLog).toHaveBeenCalledTimes(1)
+    expect(onLog.mock.calls[0][0]).toBe('warn')
+    expect(onLog.mock.calls[0][1]).toMatch(/a\.js/)
+    expect(onLog.mock.calls[0][1]).not.toMatch(/vendor/)
+  },
+})
@@ -0,0 +1,4 @@
+import { usedB } from './a.js'
+
+export const b = 'b'
+export const usedA = () => usedB
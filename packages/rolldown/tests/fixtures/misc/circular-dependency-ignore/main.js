@@ -0,0 +1,4 @@
+import { a } from './a.js'
+import { c } from './vendor/c.js'
+
+console.log(a, c)
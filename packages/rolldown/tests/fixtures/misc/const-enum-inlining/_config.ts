@@ -0,0 +1,14 @@
+import { defineTest } from '@tests'
+import { expect } from 'vitest'
+import { getOutputChunk } from '@tests/utils'
+
+export default defineTest({
+  config: {
+    input: 'main.ts',
+  },
+  afterTest: (output) => {
+    const code = getOutputChunk(output)[0].code
+    expect(code).toMatch(/const directions = \[\s*1,\s*2,\s*4,\s*-1\s*\]/)
+    expect(code).toMatch(/const colors = \[\s*"red",\s*"red-green"\s*\]/)
+  },
+})
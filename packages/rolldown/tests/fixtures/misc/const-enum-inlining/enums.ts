@@ -0,0 +1,11 @@
+export const enum Direction {
+  Up = 1,
+  Down,
+  Left = Down * 2,
+  Right = -1,
+}
+
+export const enum Color {
+  Red = 'red',
+  Green = Color.Red + '-green',
+}
@@ -0,0 +1,10 @@
+import { Color, Direction } from './enums'
+
+export const directions = [
+  Direction.Up,
+  Direction.Down,
+  Direction['Left'],
+  Direction.Right,
+]
+
+export const colors = [Color.Red, Color.Green]
@@ -0,0 +1,38 @@
+import type { RolldownOutputAsset } from 'rolldown'
+import { defineTest } from '@tests'
+import { expect, vi } from 'vitest'
+
+const onLog = vi.fn()
+
+export default defineTest({
+  config: {
+    input: './main.js',
+    onLog(level, log) {
+      if (log.code === 'CSS_ORDERING_CONFLICT') {
+        onLog(level, log.message)
+      }
+    },
+  },
+  afterTest: (output) => {
+    const css = output.output.find((item) =>
+      item.fileName.endsWith('.css'),
+    ) as RolldownOutputAsset
+    const source = css.source.toString()
+    // A single `@charset` and a single merged `@layer` statement at the top.
+    expect(
+      source.startsWith('@charset "utf-8";\n@layer reset, base, theme;\n'),
+    ).toBe(true)
+    expect(source.match(/@charset/g)).toHaveLength(1)
+    expect(source.match(/@layer [\w, ]+;/g)).toHaveLength(1)
+
+    expect(onLog).toHaveBeenCalledTimes(2)
+    expect(onLog.mock.calls.map(([level]) => level)).toStrictEqual([
+      'warn',
+      'warn',
+    ])
+    expect(onLog.mock.calls[0][1]).toContain('@charset "iso-8859-15";')
+    expect(onLog.mock.calls[1][1]).toContain(
+      '`theme` is declared before `base`',
+    )
+  },
+})
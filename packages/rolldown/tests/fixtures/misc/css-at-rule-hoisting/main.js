@@ -0,0 +1,2 @@
+import './base.css'
+import './theme.css'
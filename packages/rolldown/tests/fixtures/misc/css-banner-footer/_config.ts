@@ -0,0 +1,26 @@
+import type { RolldownOutputAsset, RolldownOutputChunk } from 'rolldown'
+import { defineTest } from '@tests'
+import { expect } from 'vitest'
+
+export default defineTest({
+  config: {
+    input: './main.js',
+    output: {
+      banner: '/* js banner */',
+      cssBanner: '@charset "UTF-8";\n/*! license */',
+      cssFooter: (chunk) => `/* ${chunk.name} */`,
+    },
+  },
+  afterTest: (output) => {
+    const css = output.output.find((item) =>
+      item.fileName.endsWith('.css'),
+    ) as RolldownOutputAsset
+    const source = css.source.toString()
+    expect(source.startsWith('@charset "UTF-8";\n/*! license */\n')).toBe(true)
+    expect(source.endsWith('/* main */\n')).toBe(true)
+    expect(source).not.toContain('js banner')
+
+    const js = output.output[0] as RolldownOutputChunk
+    expect(js.code).not.toContain('license')
+  },
+})
@@ -0,0 +1,18 @@
+import type { RolldownOutputAsset } from 'rolldown'
+import { defineTest } from '@tests'
+import { expect } from 'vitest'
+
+export default defineTest({
+  config: {
+    input: ['main.js'],
+    cssTarget: ['chrome90', 'safari14'],
+  },
+  afterTest: (output) => {
+    const css = output.output.find((item) =>
+      item.fileName.endsWith('.css'),
+    ) as RolldownOutputAsset
+    const source = css.source.toString()
+    expect(source).toContain('.card .title')
+    expect(source).not.toContain('oklch(')
+  },
+})
@@ -0,0 +1,25 @@
+import type { RolldownOutputChunk } from 'rolldown'
+import { defineTest } from '@tests'
+import { expect, vi } from 'vitest'
+
+const onLog = vi.fn()
+
+export default defineTest({
+  config: {
+    input: './main.js',
+    errorRecovery: true,
+    onLog(level, log) {
+      onLog(level, log.code)
+    },
+  },
+  afterTest: (output) => {
+    // The parse error is reported as a warning, and thrown by the module replacing `broken.js`.
+    expect(onLog).toHaveBeenCalledWith('warn', 'PARSE_ERROR')
+    // Only the unresolved import is replaced, `main.js` itself is kept.
+    expect(onLog).toHaveBeenCalledWith('warn', 'RESOLVE_ERROR')
+    const main = output.output[0] as RolldownOutputChunk
+    expect(main.code).toContain('throw new Error(')
+    expect(main.code).toMatch(/Could not resolve .*pkg\/missing/)
+    expect(main.code).toContain('console.log(')
+  },
+})
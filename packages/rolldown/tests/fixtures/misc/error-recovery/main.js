@@ -0,0 +1,4 @@
+import { value } from './broken.js'
+import 'pkg/missing'
+
+console.log(value)
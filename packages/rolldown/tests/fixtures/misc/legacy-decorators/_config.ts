@@ -0,0 +1,19 @@
+import { defineTest } from '@tests'
+import { expect } from 'vitest'
+import { getOutputChunk } from '@tests/utils'
+
+export default defineTest({
+  config: {
+    input: 'main.ts',
+    decorator: {
+      legacy: true,
+      emitDecoratorMetadata: true,
+    },
+  },
+  async afterTest(output) {
+    const code = getOutputChunk(output)[0].code
+    expect(code).toContain('__decorateClass')
+    expect(code).not.toContain('@sealed')
+    await import('./assert.mjs')
+  },
+})
@@ -0,0 +1,21 @@
+// @ts-nocheck
+import assert from 'node:assert'
+import { Service, calls, metadata } from './dist/main'
+
+assert.deepStrictEqual(calls, [
+  'member name',
+  'param run 0',
+  'member run',
+  'member create',
+  'param undefined 0',
+  'class Service',
+])
+assert.strictEqual(Service.create().sealed, true)
+assert.deepStrictEqual(
+  metadata.filter(([key]) => key === 'design:paramtypes').map(([, value]) => value.length),
+  [1, 0, 1],
+)
+assert.deepStrictEqual(
+  metadata.find(([key]) => key === 'design:returntype')[1],
+  Boolean,
+)
@@ -0,0 +1,43 @@
+export const metadata: [string, unknown][] = []
+export const calls: string[] = []
+
+Object.assign(Reflect, {
+  metadata: (key: string, value: unknown) => () => {
+    metadata.push([key, value])
+  },
+})
+
+function sealed<T extends Function>(target: T): T {
+  calls.push(`class ${target.name}`)
+  return class extends (target as any) {
+    sealed = true
+  } as any
+}
+
+function log(target: any, key: string, descriptor?: PropertyDescriptor) {
+  calls.push(`member ${key}`)
+}
+
+function param(target: any, key: string | undefined, index: number) {
+  calls.push(`param ${key} ${index}`)
+}
+
+class Dep {}
+
+@sealed
+export class Service {
+  constructor(@param readonly dep: Dep) {}
+
+  @log
+  name: string = 'service'
+
+  @log
+  run(@param times: number): boolean {
+    return times > 0
+  }
+
+  @log
+  static create(): Service {
+    return new Service(new Dep())
+  }
+}
@@ -0,0 +1,26 @@
+import { defineTest } from '@tests'
+import { expect, vi } from 'vitest'
+
+const onwarn = vi.fn()
+
+export default defineTest({
+  config: {
+    input: './main.js',
+    maxDiagnostics: 2,
+    // Diagnostics are capped when they are printed, after going through `onLog`.
+    onwarn(warning) {
+      onwarn(warning.code, warning.message)
+    },
+  },
+  afterTest: () => {
+    // Two of the four unresolved imports are reported, and the other two are only counted.
+    expect(onwarn.mock.calls.map(([code]) => code)).toStrictEqual([
+      'UNRESOLVED_IMPORT',
+      'UNRESOLVED_IMPORT',
+      'OMITTED_DIAGNOSTICS',
+    ])
+    expect(onwarn.mock.calls[2][1]).toContain(
+      '2 more diagnostics were omitted: 2 UNRESOLVED_IMPORT.',
+    )
+  },
+})
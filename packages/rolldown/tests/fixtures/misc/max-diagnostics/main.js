@@ -0,0 +1,4 @@
+import 'missing-a'
+import 'missing-b'
+import 'missing-c'
+import 'missing-d'
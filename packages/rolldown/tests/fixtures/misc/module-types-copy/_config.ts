@@ -0,0 +1,21 @@
+import { defineTest } from '@tests'
+import { getOutputAssetNames, getOutputChunk } from '@tests/utils'
+import { expect } from 'vitest'
+
+export default defineTest({
+  config: {
+    moduleTypes: {
+      '.mjs': 'copy',
+    },
+    output: {
+      entryFileNames: 'entries/[name].js',
+      assetFileNames: 'assets/[name][extname]',
+    },
+  },
+  afterTest: (output) => {
+    expect(getOutputAssetNames(output)).toStrictEqual(['assets/helper.mjs'])
+    const code = getOutputChunk(output)[0].code
+    expect(code).toContain('from "../assets/helper.mjs"')
+    expect(code).not.toContain("'helper'")
+  },
+})
@@ -0,0 +1,3 @@
+import { value } from './helper.mjs'
+
+export { value }
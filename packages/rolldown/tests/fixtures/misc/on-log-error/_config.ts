@@ -0,0 +1,20 @@
+import { defineTest } from '@tests'
+import { expect } from 'vitest'
+
+export default defineTest({
+  config: {
+    input: './main.js',
+    onLog(level, log, defaultHandler) {
+      if (log.message.includes('missing-b')) {
+        defaultHandler('error', log)
+      }
+    },
+  },
+  afterTest() {
+    expect.unreachable()
+  },
+  catchError(err) {
+    // The diagnostic promoted to an error fails the build.
+    expect(String(err)).toContain('Build failed')
+  },
+})
@@ -0,0 +1,40 @@
+import { defineTest } from '@tests'
+import { expect, vi } from 'vitest'
+
+const pluginOnLog = vi.fn()
+const onLog = vi.fn()
+const onwarn = vi.fn()
+
+export default defineTest({
+  config: {
+    input: './main.js',
+    plugins: [
+      {
+        name: 'test-plugin',
+        onLog(level, log) {
+          pluginOnLog(level, log.code)
+          // Dropped diagnostics don't reach the `onLog` option.
+          return !log.message.includes('missing-a')
+        },
+      },
+    ],
+    onLog(level, log, defaultHandler) {
+      onLog(level, log.code)
+      defaultHandler(level, { ...log, message: `Rewritten: ${log.message}` })
+    },
+    onwarn(warning) {
+      onwarn(warning.code, warning.message)
+    },
+  },
+  afterTest: () => {
+    // Diagnostics of the scan stage go through the `onLog` hook and option as well.
+    expect(pluginOnLog.mock.calls).toStrictEqual([
+      ['warn', 'UNRESOLVED_IMPORT'],
+      ['warn', 'UNRESOLVED_IMPORT'],
+    ])
+    expect(onLog.mock.calls).toStrictEqual([['warn', 'UNRESOLVED_IMPORT']])
+    expect(onwarn).toHaveBeenCalledTimes(1)
+    expect(onwarn.mock.calls[0][1]).toContain('Rewritten: ')
+    expect(onwarn.mock.calls[0][1]).toContain('missing-b')
+  },
+})
@@ -0,0 +1,2 @@
+import 'missing-a'
+import 'missing-b'
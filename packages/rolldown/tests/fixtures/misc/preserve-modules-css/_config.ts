@@ -0,0 +1,30 @@
+import type { RolldownOutputAsset, RolldownOutputChunk } from 'rolldown'
+import { defineTest } from '@tests'
+import { expect } from 'vitest'
+
+export default defineTest({
+  config: {
+    input: './main.js',
+    output: {
+      preserveModules: true,
+    },
+  },
+  afterTest: (output) => {
+    const find = (fileName: string) =>
+      output.output.find((item) => item.fileName === fileName)
+
+    // The css of each module is emitted next to its js chunk.
+    const button = find('components/button.js') as RolldownOutputChunk
+    expect(button.code).toContain('import "./button.css";')
+    const buttonCss = find('components/button.css') as RolldownOutputAsset
+    expect(buttonCss.source.toString()).toContain('.button')
+    expect(buttonCss.source.toString()).not.toContain('body')
+
+    const main = find('main.js') as RolldownOutputChunk
+    expect(main.code).toContain('import "./main.css";')
+    expect(main.code).toContain('./components/button.js')
+    const mainCss = find('main.css') as RolldownOutputAsset
+    expect(mainCss.source.toString()).toContain('body')
+    expect(mainCss.source.toString()).not.toContain('.button')
+  },
+})
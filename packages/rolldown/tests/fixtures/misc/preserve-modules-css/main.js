@@ -0,0 +1,4 @@
+import './main.css'
+import { button } from './components/button.js'
+
+console.log(button)
@@ -0,0 +1,36 @@
+import path from 'node:path'
+import { defineTest } from '@tests'
+import { expect } from 'vitest'
+
+export default defineTest({
+  config: {
+    input: './src/main.js',
+    output: {
+      preserveModules: true,
+      preserveModulesRoot: path.resolve(import.meta.dirname, 'src'),
+    },
+    plugins: [
+      {
+        name: 'virtual',
+        resolveId(id) {
+          if (id === 'virtual:module') {
+            return '\0virtual:module'
+          }
+        },
+        load(id) {
+          if (id === '\0virtual:module') {
+            return `export const virtual = 'virtual'`
+          }
+        },
+      },
+    ],
+  },
+  afterTest: (output) => {
+    // `src/` is stripped, and the virtual module doesn't produce an illegal file name.
+    expect(output.output.map((item) => item.fileName).sort()).toStrictEqual([
+      '_virtual/virtual_module.js',
+      'lib/util.js',
+      'main.js',
+    ])
+  },
+})
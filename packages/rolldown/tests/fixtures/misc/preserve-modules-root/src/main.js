@@ -0,0 +1,4 @@
+import { util } from './lib/util.js'
+import { virtual } from 'virtual:module'
+
+console.log(util, virtual)
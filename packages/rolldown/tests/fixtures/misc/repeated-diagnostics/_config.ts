@@ -0,0 +1,24 @@
+import { defineTest } from '@tests'
+import { expect, vi } from 'vitest'
+
+const onwarn = vi.fn()
+
+export default defineTest({
+  config: {
+    input: './main.js',
+    // Repeated diagnostics are merged by the bundler after going through `onLog`.
+    onwarn(warning) {
+      if (warning.code === 'UNRESOLVED_IMPORT') {
+        onwarn(warning.message)
+      }
+    },
+  },
+  afterTest: () => {
+    // The five imports of the missing package are reported as one warning.
+    expect(onwarn).toHaveBeenCalledTimes(1)
+    expect(onwarn.mock.calls[0][0]).toContain(
+      "Could not resolve 'missing-pkg'",
+    )
+    expect(onwarn.mock.calls[0][0]).toContain('and 2 more occurrences')
+  },
+})
@@ -0,0 +1,13 @@
+import { defineTest } from '@tests'
+import { expect } from 'vitest'
+
+export default defineTest({
+  afterTest() {
+    expect.unreachable()
+  },
+  catchError(err) {
+    expect(String(err)).toContain(
+      'no plugin returned its content in the `load` hook',
+    )
+  },
+})
@@ -0,0 +1,2 @@
+import foo from '\0unloaded'
+console.log(foo)
@@ -0,0 +1,24 @@
+import { defineTest } from '@tests'
+import { getOutputChunkNames } from '@tests/utils'
+import { expect } from 'vitest'
+
+export default defineTest({
+  config: {
+    // Virtual ids don't need a `resolveId` hook, since they're never resolved in the file system.
+    input: '\0virtual-entry.js',
+    plugins: [
+      {
+        load(id) {
+          if (id === '\0virtual-entry.js') {
+            return `export default 'virtual entry'`
+          }
+        },
+      },
+    ],
+  },
+  afterTest(output) {
+    expect(getOutputChunkNames(output)).toStrictEqual(['virtual-entry.js'])
+    expect(output.output[0].facadeModuleId).toBe('\0virtual-entry.js')
+    expect(output.output[0].code).toContain('virtual entry')
+  },
+})
@@ -0,0 +1,34 @@
+import type { RenderedChunk } from 'rolldown'
+import { defineTest } from '@tests'
+import { getOutputChunk } from '@tests/utils'
+import { expect } from 'vitest'
+
+const addon = (name: string) => async (chunk: RenderedChunk) =>
+  `/* ${name} of ${chunk.fileName} */`
+
+export default defineTest({
+  config: {
+    output: {
+      format: 'iife',
+      banner: addon('banner'),
+      intro: addon('intro'),
+      outro: addon('outro'),
+      footer: addon('footer'),
+    },
+  },
+  afterTest(output) {
+    const code = getOutputChunk(output)[0].code
+    const positions = [
+      '/* banner of main.js */',
+      '(function() {',
+      '/* intro of main.js */',
+      'console.log("main")',
+      '/* outro of main.js */',
+      '})();',
+      '/* footer of main.js */',
+    ].map((text) => code.indexOf(text))
+    // The banner and footer wrap the IIFE, while the intro and outro are inside of it.
+    expect(positions.every((position) => position !== -1)).toBe(true)
+    expect(positions).toEqual([...positions].sort((a, b) => a - b))
+  },
+})
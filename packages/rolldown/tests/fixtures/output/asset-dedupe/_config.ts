@@ -0,0 +1,40 @@
+import fs from 'node:fs'
+import path from 'node:path'
+import { defineTest } from '@tests'
+import { getOutputAssetNames, getOutputChunk } from '@tests/utils'
+import { expect } from 'vitest'
+
+export default defineTest({
+  config: {
+    moduleTypes: {
+      '.svg': 'file',
+    },
+    output: {
+      assetFileNames: 'assets/[name]-[hash][extname]',
+    },
+    plugins: [
+      {
+        name: 'test-plugin',
+        buildStart() {
+          this.emitFile({
+            type: 'asset',
+            fileName: 'static/icon.svg',
+            source: fs.readFileSync(path.join(import.meta.dirname, 'c.svg')),
+          })
+        },
+      },
+    ],
+  },
+  afterTest: (output) => {
+    const assetNames = getOutputAssetNames(output)
+    expect(assetNames).toHaveLength(2)
+    const [hashedName, iconName] = assetNames
+    expect(hashedName).toMatch(/^assets\/a-[\w$-]{8}\.svg$/)
+    expect(iconName).toBe('static/icon.svg')
+
+    const code = getOutputChunk(output)[0].code
+    // `a.svg` and `b.svg` have the same content, `c.svg` has the same content as the emitted icon.
+    expect(code.split(`"${hashedName}"`)).toHaveLength(3)
+    expect(code).toContain('"static/icon.svg"')
+  },
+})
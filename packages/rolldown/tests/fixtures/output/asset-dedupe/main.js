@@ -0,0 +1,5 @@
+import a from './a.svg'
+import b from './b.svg'
+import c from './c.svg'
+
+export { a, b, c }
@@ -0,0 +1,32 @@
+import { createRequire } from 'node:module'
+import path from 'node:path'
+import { fileURLToPath } from 'node:url'
+import { defineTest } from '@tests'
+import { expect } from 'vitest'
+
+const require = createRequire(import.meta.url)
+
+export default defineTest({
+  config: {
+    moduleTypes: {
+      '.svg': 'file',
+    },
+    output: {
+      base: 'auto',
+      format: 'cjs',
+      exports: 'default',
+      entryFileNames: 'nested/[name].js',
+    },
+  },
+  afterTest: (output) => {
+    const asset = output.output.find((item) => item.type === 'asset')!
+    // The url is resolved against the chunk in `dist/nested`, not the output directory.
+    expect(output.output[0].code).toContain(
+      `new URL("../${asset.fileName}", require("url").pathToFileURL(__filename).href).href`,
+    )
+    const url = require(path.join(import.meta.dirname, 'dist/nested/main.js'))
+    expect(fileURLToPath(url)).toBe(
+      path.join(import.meta.dirname, 'dist', asset.fileName),
+    )
+  },
+})
@@ -0,0 +1,16 @@
+import { expect } from 'vitest'
+import { defineTest } from '@tests'
+import { getOutputChunk } from '@tests/utils'
+
+export default defineTest({
+  config: {
+    output: {
+      charset: 'ascii',
+    },
+  },
+  async afterTest(output) {
+    const code = getOutputChunk(output)[0].code
+    expect(/^[\x00-\x7f]*$/.test(code)).toBe(true)
+    await import('./assert.mjs')
+  },
+})
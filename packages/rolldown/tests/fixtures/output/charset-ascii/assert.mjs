@@ -0,0 +1,8 @@
+// @ts-nocheck
+import assert from 'node:assert'
+import { greeting, isAccented, 𝑥 } from './dist/main'
+
+assert.strictEqual(greeting, 'café 😀 — ünïcödé')
+assert.strictEqual(isAccented('é'), true)
+assert.strictEqual(isAccented('e'), false)
+assert.strictEqual(𝑥, 'café 😀')
@@ -0,0 +1,7 @@
+const café = 'café 😀'
+
+export const greeting = `${café} — ünïcödé`
+
+export const isAccented = (text) => /[éü]/u.test(text)
+
+export const 𝑥 = café
@@ -0,0 +1,29 @@
+import { defineTest } from '@tests'
+import { expect, vi } from 'vitest'
+
+const onLog = vi.fn()
+
+export default defineTest({
+  config: {
+    input: './main.js',
+    output: {
+      chunkSizeWarningLimit: 200,
+    },
+    onLog(level, log) {
+      if (log.code === 'CHUNK_SIZE_EXCEEDED') {
+        onLog(level, log.message)
+      }
+    },
+  },
+  afterTest: () => {
+    expect(onLog).toHaveBeenCalledTimes(1)
+    expect(onLog.mock.calls[0][0]).toBe('warn')
+    expect(onLog.mock.calls[0][1]).toMatch(
+      /Chunk 'main\.js' is \d+ bytes, larger than the limit of 200 bytes\./,
+    )
+    // The largest module is listed first.
+    expect(onLog.mock.calls[0][1]).toMatch(
+      /The largest modules are '[^']*large\.js' \(\d+ bytes\), '[^']*main\.js'/,
+    )
+  },
+})
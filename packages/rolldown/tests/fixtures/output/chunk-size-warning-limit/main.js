@@ -0,0 +1,4 @@
+import { large } from './large.js'
+import { small } from './small.js'
+
+console.log(large, small)
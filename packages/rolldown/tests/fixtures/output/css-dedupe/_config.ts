@@ -0,0 +1,23 @@
+import type { RolldownOutputChunk } from 'rolldown'
+import { defineTest } from '@tests'
+import { expect } from 'vitest'
+
+export default defineTest({
+  config: {
+    input: ['a.js', 'b.js'],
+  },
+  afterTest: (output) => {
+    const chunks = output.output.filter(
+      ({ type }) => type === 'chunk',
+    ) as RolldownOutputChunk[]
+    const cssAssets = output.output.filter(
+      (item) => item.type === 'asset' && item.fileName.endsWith('.css'),
+    )
+    // Both entries have byte-identical css, so only a single file is emitted.
+    expect(cssAssets).toHaveLength(1)
+    const a = chunks.find((chunk) => chunk.name === 'a')!
+    const b = chunks.find((chunk) => chunk.name === 'b')!
+    expect(a.importedCss).toStrictEqual([cssAssets[0].fileName])
+    expect(b.importedCss).toStrictEqual([cssAssets[0].fileName])
+  },
+})
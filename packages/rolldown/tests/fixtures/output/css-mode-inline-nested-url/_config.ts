@@ -0,0 +1,27 @@
+import type { RolldownOutputAsset, RolldownOutputChunk } from 'rolldown'
+import { defineTest } from '@tests'
+import { expect } from 'vitest'
+
+export default defineTest({
+  config: {
+    input: ['main.js'],
+    output: {
+      cssMode: 'inline',
+      entryFileNames: 'nested/[name].js',
+    },
+  },
+  afterTest: (output) => {
+    const main = output.output.find(
+      ({ type }) => type === 'chunk',
+    ) as RolldownOutputChunk
+    const logo = output.output.find((item) =>
+      item.fileName.endsWith('.svg'),
+    ) as RolldownOutputAsset
+    // The injected `<style>` element resolves urls against the document, so the url is resolved against
+    // the chunk at runtime rather than left relative to it.
+    expect(main.code).toContain(
+      `url("\${new URL("../${logo.fileName}", import.meta.url).href}")`,
+    )
+    expect(main.code).not.toContain(`url(../${logo.fileName})`)
+  },
+})
@@ -0,0 +1,32 @@
+import type { RolldownOutputChunk } from 'rolldown'
+import { defineTest } from '@tests'
+import { expect } from 'vitest'
+
+export default defineTest({
+  config: {
+    input: ['main.js'],
+    output: {
+      cssMode: 'inline',
+    },
+  },
+  afterTest: (output) => {
+    expect(
+      output.output.filter((item) => item.fileName.endsWith('.css')),
+    ).toStrictEqual([])
+
+    const chunks = output.output.filter(
+      ({ type }) => type === 'chunk',
+    ) as RolldownOutputChunk[]
+    const main = chunks.find((chunk) => chunk.isEntry)!
+    const lazy = chunks.find((chunk) => chunk.isDynamicEntry)!
+    for (const chunk of [main, lazy]) {
+      expect(chunk.code).toContain('document.createElement("style")')
+      expect(chunk.importedCss).toStrictEqual([])
+    }
+    // Each chunk only carries its own styles.
+    expect(main.code).toContain('.main')
+    expect(main.code).not.toContain('.lazy')
+    expect(lazy.code).toContain('.lazy')
+    expect(lazy.code).not.toContain('.main')
+  },
+})
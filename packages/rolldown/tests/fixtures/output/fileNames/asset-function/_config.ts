@@ -0,0 +1,55 @@
+import type { PreRenderedAsset } from 'rolldown'
+import { defineTest } from '@tests'
+import { getOutputAssetNames } from '@tests/utils'
+import { expect } from 'vitest'
+
+const preRenderedAssets: PreRenderedAsset[] = []
+
+export default defineTest({
+  config: {
+    output: {
+      assetFileNames: (asset) => {
+        preRenderedAssets.push(asset)
+        return asset.name?.endsWith('.txt')
+          ? 'text/[name][extname]'
+          : 'assets/[name][extname]'
+      },
+    },
+    plugins: [
+      {
+        name: 'test-plugin',
+        buildStart() {
+          this.emitFile({
+            type: 'asset',
+            name: 'emitted.txt',
+            originalFileName: 'src/emitted.txt',
+            source: 'emitted',
+          })
+          this.emitFile({
+            type: 'asset',
+            name: 'emitted.bin',
+            source: new Uint8Array([1, 2, 3]),
+          })
+        },
+      },
+    ],
+  },
+  afterTest: (output) => {
+    expect(getOutputAssetNames(output)).toStrictEqual([
+      'assets/emitted.bin',
+      'text/emitted.txt',
+    ])
+    expect(preRenderedAssets).toMatchObject([
+      {
+        name: 'emitted.txt',
+        originalFileName: 'src/emitted.txt',
+        source: 'emitted',
+        type: 'asset',
+      },
+      { name: 'emitted.bin', originalFileName: null, type: 'asset' },
+    ])
+    expect(
+      Array.from(preRenderedAssets[1].source as Uint8Array),
+    ).toStrictEqual([1, 2, 3])
+  },
+})
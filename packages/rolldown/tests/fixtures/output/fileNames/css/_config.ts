@@ -0,0 +1,29 @@
+import type { RolldownOutputChunk } from 'rolldown'
+import { defineTest } from '@tests'
+import { expect } from 'vitest'
+
+export default defineTest({
+  config: {
+    input: ['main.js'],
+    output: {
+      cssEntryFileNames: 'styles/[name].css',
+      cssChunkFileNames: 'styles/[name]-chunk.css',
+    },
+  },
+  afterTest: (output) => {
+    const cssFileNames = output.output
+      .map((item) => item.fileName)
+      .filter((fileName) => fileName.endsWith('.css'))
+      .sort()
+    expect(cssFileNames).toStrictEqual([
+      'styles/lazy-chunk.css',
+      'styles/main.css',
+    ])
+    // Js chunks keep using `entryFileNames` and `chunkFileNames`.
+    const entry = output.output.find(
+      (item) => item.type === 'chunk' && item.isEntry,
+    ) as RolldownOutputChunk
+    expect(entry.fileName).toBe('main.js')
+    expect(entry.importedCss).toStrictEqual(['styles/main.css'])
+  },
+})
@@ -0,0 +1,3 @@
+import './main.css'
+
+export const lazy = () => import('./lazy.js')
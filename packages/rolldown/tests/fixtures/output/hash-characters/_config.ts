@@ -0,0 +1,25 @@
+import { defineTest } from '@tests'
+import { expect } from 'vitest'
+
+export default defineTest({
+  config: {
+    output: {
+      entryFileNames: '[name]-[hash:12].js',
+      assetFileNames: '[name]-[hash:16][extname]',
+      hashCharacters: 'hex',
+    },
+    plugins: [
+      {
+        name: 'test-plugin',
+        buildStart() {
+          this.emitFile({ type: 'asset', name: 'style.css', source: 'a {}' })
+        },
+      },
+    ],
+  },
+  afterTest: (output) => {
+    const fileNames = output.output.map((item) => item.fileName).sort()
+    expect(fileNames[0]).toMatch(/^main-[0-9a-f]{12}\.js$/)
+    expect(fileNames[1]).toMatch(/^style-[0-9a-f]{16}\.css$/)
+  },
+})
@@ -0,0 +1,41 @@
+import { defineTest } from '@tests'
+import { expect } from 'vitest'
+import path from 'node:path'
+import { rolldown, type OutputOptions } from 'rolldown'
+
+const outputOptions: OutputOptions = {
+  entryFileNames: '[name]-[hash].js',
+  cssEntryFileNames: '[name]-[hash].css',
+}
+
+export default defineTest({
+  config: {
+    input: ['main.js'],
+    output: outputOptions,
+  },
+  afterTest: async (output) => {
+    const build = await rolldown({
+      input: ['main.js'],
+      cwd: import.meta.dirname,
+      plugins: [
+        {
+          name: 'change-css-only',
+          load(id) {
+            if (id === path.join(import.meta.dirname, 'style.css')) {
+              return '.main {\n  color: blue;\n}\n'
+            }
+          },
+        },
+      ],
+    })
+    const changed = await build.generate(outputOptions)
+
+    const fileNames = (items: typeof output.output) =>
+      items.map((item) => item.fileName).sort()
+    // The js is the same, but the css changes the hash of the chunk.
+    expect(changed.output[0].code).toBe(output.output[0].code)
+    for (const fileName of fileNames(changed.output)) {
+      expect(fileNames(output.output)).not.toContain(fileName)
+    }
+  },
+})
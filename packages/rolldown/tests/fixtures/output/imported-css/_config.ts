@@ -0,0 +1,30 @@
+import type { RolldownOutputAsset, RolldownOutputChunk } from 'rolldown'
+import { defineTest } from '@tests'
+import { expect } from 'vitest'
+
+export default defineTest({
+  config: {
+    input: ['main.js'],
+  },
+  afterTest: (output) => {
+    const chunks = output.output.filter(
+      ({ type }) => type === 'chunk',
+    ) as RolldownOutputChunk[]
+    const cssAssets = output.output.filter(
+      (item) => item.type === 'asset' && item.fileName.endsWith('.css'),
+    ) as RolldownOutputAsset[]
+    const main = chunks.find((chunk) => chunk.isEntry)!
+    const lazy = chunks.find((chunk) => chunk.isDynamicEntry)!
+
+    expect(main.importedCss).toHaveLength(1)
+    expect(lazy.importedCss).toHaveLength(1)
+    expect(main.importedCss[0]).not.toBe(lazy.importedCss[0])
+
+    const cssSource = (fileName: string) =>
+      cssAssets.find((asset) => asset.fileName === fileName)!.source
+    expect(cssSource(main.importedCss[0])).toContain('.main')
+    expect(cssSource(main.importedCss[0])).not.toContain('.lazy')
+    expect(cssSource(lazy.importedCss[0])).toContain('.lazy')
+    expect(cssSource(lazy.importedCss[0])).not.toContain('.main')
+  },
+})
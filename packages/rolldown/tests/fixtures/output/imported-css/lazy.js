@@ -0,0 +1,3 @@
+import './lazy.css'
+
+export const value = 'lazy'
@@ -0,0 +1,13 @@
+import { defineTest } from '@tests'
+
+export default defineTest({
+  config: {
+    output: {
+      keepNames: true,
+      minify: true,
+    },
+  },
+  async afterTest() {
+    await import('./assert.mjs')
+  },
+})
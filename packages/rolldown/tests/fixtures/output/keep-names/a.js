@@ -0,0 +1,5 @@
+export function helper() {}
+
+export class Widget {}
+
+export const arrow = () => {}
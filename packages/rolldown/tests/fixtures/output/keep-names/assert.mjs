@@ -0,0 +1,14 @@
+// @ts-nocheck
+import assert from 'node:assert'
+import { names } from './dist/main'
+
+// `helper` and `Widget` of `b.js` are renamed to avoid conflicts, and `inner`
+// is mangled.
+assert.deepStrictEqual(names, [
+  'helper',
+  'Widget',
+  'arrow',
+  'helper',
+  'Widget',
+  'inner',
+])
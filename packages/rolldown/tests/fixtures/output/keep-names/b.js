@@ -0,0 +1,3 @@
+export function helper() {}
+
+export class Widget {}
@@ -0,0 +1,16 @@
+import { helper as helperA, Widget as WidgetA, arrow } from './a.js'
+import { helper as helperB, Widget as WidgetB } from './b.js'
+
+function outer() {
+  function inner() {}
+  return inner
+}
+
+export const names = [
+  helperA.name,
+  WidgetA.name,
+  arrow.name,
+  helperB.name,
+  WidgetB.name,
+  outer().name,
+]
@@ -0,0 +1,17 @@
+import { expect } from 'vitest'
+import { defineTest } from '@tests'
+import { getOutputChunk } from '@tests/utils'
+
+export default defineTest({
+  config: {
+    output: {
+      legalComments: 'eof',
+    },
+  },
+  afterTest(output) {
+    const code = getOutputChunk(output)[0].code
+    const tail = code.slice(code.lastIndexOf('console.log'))
+    expect(tail).toContain('@license lib')
+    expect(tail).toContain('/*! main | MIT */')
+  },
+})
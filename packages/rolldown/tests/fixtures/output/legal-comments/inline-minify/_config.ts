@@ -0,0 +1,18 @@
+import { expect } from 'vitest'
+import { defineTest } from '@tests'
+import { getOutputChunk } from '@tests/utils'
+
+export default defineTest({
+  config: {
+    output: {
+      minify: true,
+    },
+  },
+  afterTest(output) {
+    const code = getOutputChunk(output)[0].code
+    expect(code.startsWith('/**')).toBe(true)
+    expect(code).toContain('/*! main | MIT */')
+    expect(code).toContain('@license lib')
+    expect(code).not.toContain('a regular comment')
+  },
+})
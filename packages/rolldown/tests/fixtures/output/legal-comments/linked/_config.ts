@@ -0,0 +1,23 @@
+import { expect } from 'vitest'
+import { defineTest } from '@tests'
+import { getOutputAsset, getOutputChunk } from '@tests/utils'
+
+export default defineTest({
+  config: {
+    output: {
+      legalComments: 'linked',
+    },
+  },
+  afterTest(output) {
+    const code = getOutputChunk(output)[0].code
+    expect(code).not.toContain('@license')
+    expect(code).toContain(
+      '/*! For license information please see main.js.LEGAL.txt */',
+    )
+    const legal = getOutputAsset(output).find(
+      (asset) => asset.fileName === 'main.js.LEGAL.txt',
+    )
+    expect(legal?.source).toContain('@license lib')
+    expect(legal?.source).toContain('/*! main | MIT */')
+  },
+})
@@ -0,0 +1,17 @@
+import { expect } from 'vitest'
+import { defineTest } from '@tests'
+import { getOutputChunk } from '@tests/utils'
+
+export default defineTest({
+  config: {
+    output: {
+      legalComments: 'none',
+    },
+  },
+  afterTest(output) {
+    const code = getOutputChunk(output)[0].code
+    expect(code).not.toContain('main | MIT')
+    expect(code).not.toContain('@license')
+    expect(code).toContain('// a regular comment')
+  },
+})
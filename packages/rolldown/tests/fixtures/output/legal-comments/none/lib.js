@@ -0,0 +1,7 @@
+/**
+ * @license lib
+ * Released under the MIT License.
+ */
+const lib = 'lib'
+
+export { lib }
@@ -0,0 +1,5 @@
+import { lib } from './lib.js'
+
+/*! main | MIT */
+// a regular comment
+console.log(lib)
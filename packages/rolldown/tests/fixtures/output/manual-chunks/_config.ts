@@ -0,0 +1,30 @@
+import type { RolldownOutputChunk } from 'rolldown'
+import { defineTest } from '@tests'
+import { expect } from 'vitest'
+
+export default defineTest({
+  config: {
+    output: {
+      manualChunks(id, { getModuleInfo }) {
+        const importers = getModuleInfo(id)?.importers ?? []
+        // `shared.js` is not claimed, so it joins the chunk of `a.js` as its dependency.
+        if (
+          id.endsWith('a.js') &&
+          importers.some((importer) => importer.endsWith('main.js'))
+        ) {
+          return 'lib'
+        }
+      },
+    },
+  },
+  afterTest: (output) => {
+    const chunks = output.output.filter(
+      ({ type }) => type === 'chunk',
+    ) as RolldownOutputChunk[]
+    const lib = chunks.find((chunk) => chunk.name === 'lib')!
+    expect(lib.moduleIds.map((id) => id.split(/[\\/]/).pop())).toStrictEqual([
+      'shared.js',
+      'a.js',
+    ])
+  },
+})
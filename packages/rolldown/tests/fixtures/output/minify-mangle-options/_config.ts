@@ -0,0 +1,27 @@
+import { defineTest } from '@tests'
+import { getOutputChunk } from '@tests/utils'
+import { expect } from 'vitest'
+
+export default defineTest({
+  config: {
+    output: {
+      minify: {
+        compress: false,
+        mangle: {
+          toplevel: true,
+          reserved: ['keptParameter'],
+          keepClassnames: true,
+          properties: /^_/,
+        },
+      },
+    },
+  },
+  afterTest: (output) => {
+    const [chunk] = getOutputChunk(output)
+    expect(chunk.code).toContain('LongClassName')
+    expect(chunk.code).toContain('keptParameter')
+    expect(chunk.code).not.toContain('mangledParameter')
+    expect(chunk.code).toContain('publicField')
+    expect(chunk.code).not.toContain('_internalField')
+  },
+})
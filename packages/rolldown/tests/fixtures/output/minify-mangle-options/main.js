@@ -0,0 +1,18 @@
+class LongClassName {
+  constructor() {
+    this._internalField = 1
+    this.publicField = 2
+  }
+}
+
+function run(keptParameter, mangledParameter) {
+  const instance = new LongClassName()
+  return [
+    instance._internalField,
+    instance.publicField,
+    keptParameter,
+    mangledParameter,
+  ]
+}
+
+console.log(run(1, 2), LongClassName.name)
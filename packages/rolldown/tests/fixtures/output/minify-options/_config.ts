@@ -0,0 +1,19 @@
+import { defineTest } from '@tests'
+import { getOutputChunk } from '@tests/utils'
+import { expect } from 'vitest'
+
+export default defineTest({
+  config: {
+    output: {
+      minify: {
+        mangle: false,
+      },
+    },
+  },
+  afterTest: (output) => {
+    const [chunk] = getOutputChunk(output)
+    // Whitespace is removed, but local names are kept.
+    expect(chunk.code).not.toContain('\n  ')
+    expect(chunk.code).toContain('longParameterName')
+  },
+})
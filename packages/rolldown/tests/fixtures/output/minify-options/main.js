@@ -0,0 +1,6 @@
+function greet(longParameterName) {
+  const longLocalName = 'Hello, ' + longParameterName
+  return longLocalName
+}
+
+console.log(greet('world'))
@@ -0,0 +1,25 @@
+import { defineTest } from '@tests'
+import { getOutputChunk } from '@tests/utils'
+import { expect } from 'vitest'
+
+export default defineTest({
+  config: {
+    input: ['./a.js', './b.js'],
+    output: {
+      runtimeChunk: true,
+    },
+  },
+  afterTest: (output) => {
+    const chunks = getOutputChunk(output)
+    // The helpers are not merged into the chunk of `shared.js`, though both
+    // are used by the same entries.
+    const runtime = chunks.filter((chunk) =>
+      chunk.code.includes('__commonJS ='),
+    )
+    expect(runtime.map((chunk) => chunk.name)).toStrictEqual([
+      'rolldown-runtime',
+    ])
+    const shared = chunks.find((chunk) => chunk.code.includes("'shared'"))!
+    expect(shared.name).not.toBe('rolldown-runtime')
+  },
+})
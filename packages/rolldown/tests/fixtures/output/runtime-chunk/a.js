@@ -0,0 +1,4 @@
+import foo from './foo.cjs'
+import { shared } from './shared.js'
+
+console.log(foo, shared)
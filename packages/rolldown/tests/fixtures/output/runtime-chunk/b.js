@@ -0,0 +1,4 @@
+import bar from './bar.cjs'
+import { shared } from './shared.js'
+
+console.log(bar, shared)
@@ -0,0 +1,34 @@
+import { defineTest } from '@tests'
+import { getOutputFileNames } from '@tests/utils'
+import { expect } from 'vitest'
+
+const sanitizedNames: string[] = []
+
+export default defineTest({
+  config: {
+    output: {
+      chunkFileNames: '[name].js',
+      assetFileNames: '[name][extname]',
+      sanitizeFileName: (name) => {
+        sanitizedNames.push(name)
+        return name.replace(/[. ]/g, '-')
+      },
+    },
+    plugins: [
+      {
+        name: 'test-plugin',
+        buildStart() {
+          this.emitFile({ type: 'asset', name: 'my style.css', source: 'a {}' })
+        },
+      },
+    ],
+  },
+  afterTest: (output) => {
+    expect(getOutputFileNames(output)).toStrictEqual([
+      'lazy-module.js',
+      'main.js',
+      'my-style.css',
+    ])
+    expect(sanitizedNames.sort()).toStrictEqual(['lazy.module', 'my style'])
+  },
+})
@@ -0,0 +1,31 @@
+import { defineTest } from '@tests'
+import { expect } from 'vitest'
+
+export default defineTest({
+  config: {
+    input: ['main.js'],
+    moduleTypes: {
+      '.txt': 'empty',
+    },
+  },
+  afterTest: (output) => {
+    const stats = output.stats!
+    expect(stats.modules).toMatchObject({
+      cjs: 1,
+      json: 1,
+      css: 1,
+      asset: 0,
+      wasm: 0,
+      empty: 1,
+      external: 0,
+    })
+    // `shared.js` is imported by both `a.js` and `b.js`, so it's only loaded once.
+    expect(stats.scan.deduplicatedModuleRequests).toBeGreaterThanOrEqual(1)
+    expect(stats.scan.uniqueModuleRequests).toBeGreaterThanOrEqual(6)
+    expect(stats.bytesRead).toBeGreaterThan(0)
+    expect(stats.chunksRendered).toBe(1)
+    for (const stage of [stats.scan, stats.link, stats.generate]) {
+      expect(stage.duration).toBeGreaterThanOrEqual(0)
+    }
+  },
+})
@@ -0,0 +1,8 @@
+import { a } from './a.js'
+import { b } from './b.js'
+import cjs from './cjs.js'
+import data from './data.json'
+import './style.css'
+import './ignored.txt'
+
+console.log(a, b, cjs, data)
@@ -0,0 +1,16 @@
+import { expect } from 'vitest'
+import { defineTest } from '@tests'
+
+export default defineTest({
+  config: {
+    output: {
+      target: 'es2021',
+    },
+  },
+  afterTest() {
+    expect.unreachable()
+  },
+  catchError(err) {
+    expect(String(err)).toContain('class fields')
+  },
+})
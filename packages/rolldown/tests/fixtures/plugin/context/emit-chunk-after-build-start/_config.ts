@@ -0,0 +1,26 @@
+import { defineTest } from '@tests'
+import { expect } from 'vitest'
+
+export default defineTest({
+  config: {
+    plugins: [
+      {
+        name: 'test-plugin-context',
+        transform(_code, id) {
+          if (id.endsWith('main.js')) {
+            // The chunk would never be loaded, since modules are already being loaded.
+            this.emitFile({ type: 'chunk', id: './foo.js' })
+          }
+        },
+      },
+    ],
+  },
+  afterTest() {
+    expect.unreachable()
+  },
+  catchError(err) {
+    expect(String(err)).toContain(
+      'Chunks can only be emitted before modules are loaded',
+    )
+  },
+})
@@ -0,0 +1,31 @@
+import { defineTest } from '@tests'
+import { getOutputChunkNames } from '@tests/utils'
+import { expect } from 'vitest'
+
+let referenceId: string
+
+export default defineTest({
+  config: {
+    output: {
+      entryFileNames: '[name]-[hash].js',
+    },
+    plugins: [
+      {
+        name: 'test-plugin-context',
+        buildStart() {
+          referenceId = this.emitFile({
+            type: 'chunk',
+            id: './page.js',
+            fileName: 'pages/page.js',
+          })
+        },
+        generateBundle() {
+          expect(this.getFileName(referenceId)).toBe('pages/page.js')
+        },
+      },
+    ],
+  },
+  afterTest: (output) => {
+    expect(getOutputChunkNames(output)).toContain('pages/page.js')
+  },
+})
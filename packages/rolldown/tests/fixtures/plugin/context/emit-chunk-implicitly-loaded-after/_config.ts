@@ -0,0 +1,37 @@
+import { defineTest } from '@tests'
+import { getOutputChunk, getOutputChunkNames } from '@tests/utils'
+import { expect } from 'vitest'
+
+let referenceId: string
+
+export default defineTest({
+  config: {
+    plugins: [
+      {
+        name: 'test-plugin-context',
+        buildStart() {
+          referenceId = this.emitFile({
+            type: 'chunk',
+            id: './page.js',
+            implicitlyLoadedAfterOneOf: ['./main.js'],
+          })
+        },
+        generateBundle() {
+          expect(this.getFileName(referenceId)).toBe('page.js')
+        },
+      },
+    ],
+  },
+  afterTest: (output) => {
+    // `shared.js` is already loaded by `main.js`, so no shared chunk is needed
+    expect(getOutputChunkNames(output).sort()).toStrictEqual([
+      'main.js',
+      'page.js',
+    ])
+    const page = getOutputChunk(output).find(
+      (chunk) => chunk.fileName === 'page.js',
+    )!
+    expect(page.isEntry).toBe(true)
+    expect(page.imports).toStrictEqual(['main.js'])
+  },
+})
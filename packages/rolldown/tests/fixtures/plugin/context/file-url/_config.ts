@@ -0,0 +1,54 @@
+import { defineTest } from '@tests'
+import { getOutputChunk } from '@tests/utils'
+import { expect } from 'vitest'
+
+let assetReferenceId: string
+let chunkReferenceId: string
+
+export default defineTest({
+  config: {
+    output: {
+      assetFileNames: '[name].[ext]',
+    },
+    plugins: [
+      {
+        name: 'test-plugin-context',
+        buildStart() {
+          assetReferenceId = this.emitFile({
+            type: 'asset',
+            name: 'hello.txt',
+            source: 'hello',
+          })
+          chunkReferenceId = this.emitFile({
+            type: 'chunk',
+            id: './worker.js',
+          })
+        },
+        transform(code, id) {
+          if (id.endsWith('main.js')) {
+            return code
+              .replace(
+                '__ASSET_URL__',
+                `import.meta.ROLLUP_FILE_URL_${assetReferenceId}`,
+              )
+              .replace(
+                '__CHUNK_URL__',
+                `import.meta.ROLLUP_FILE_URL_${chunkReferenceId}`,
+              )
+          }
+        },
+      },
+    ],
+  },
+  afterTest: (output) => {
+    const main = getOutputChunk(output).find(
+      (chunk) => chunk.fileName === 'main.js',
+    )!
+    expect(main.code).toContain(
+      'const assetUrl = new URL("./hello.txt", import.meta.url).href',
+    )
+    expect(main.code).toContain(
+      'const chunkUrl = new URL("./worker.js", import.meta.url).href',
+    )
+  },
+})
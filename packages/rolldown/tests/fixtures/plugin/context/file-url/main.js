@@ -0,0 +1,2 @@
+export const assetUrl = __ASSET_URL__
+export const chunkUrl = __CHUNK_URL__
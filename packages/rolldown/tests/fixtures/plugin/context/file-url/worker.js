@@ -0,0 +1 @@
+self.postMessage('ready')
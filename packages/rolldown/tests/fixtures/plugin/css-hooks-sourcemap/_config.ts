@@ -0,0 +1,47 @@
+// cSpell:disable
+import type { RolldownOutputAsset } from 'rolldown'
+import { defineTest } from '@tests'
+import { expect } from 'vitest'
+
+export default defineTest({
+  config: {
+    input: './main.js',
+    plugins: [
+      {
+        name: 'test-plugin',
+        transformCss(code) {
+          return {
+            code: `/* compiled */\n${code}`,
+            // Maps each line of the stylesheet to the same line of `style.scss`.
+            map: {
+              version: 3,
+              sources: ['style.scss'],
+              sourcesContent: [code],
+              names: [],
+              mappings: ';AAAA;AACA;AACA',
+            },
+          }
+        },
+      },
+    ],
+    output: {
+      sourcemap: true,
+    },
+  },
+  afterTest: (output) => {
+    const css = output.output.find(
+      (item) => item.fileName === 'main.css',
+    ) as RolldownOutputAsset
+    expect(css.source.toString()).toContain(
+      '/*# sourceMappingURL=main.css.map */',
+    )
+    const map = output.output.find(
+      (item) => item.fileName === 'main.css.map',
+    ) as RolldownOutputAsset
+    const json = JSON.parse(map.source.toString())
+    expect(json.file).toBe('main.css')
+    expect(json.sources).toHaveLength(1)
+    expect(json.sources[0]).toMatch(/style\.scss$/)
+    expect(json.mappings).toBe(';AAAA;AACA;AACA')
+  },
+})
@@ -0,0 +1,40 @@
+import type { RolldownOutputAsset } from 'rolldown'
+import { defineTest } from '@tests'
+import { expect, vi } from 'vitest'
+import path from 'node:path'
+
+const transformCssFn = vi.fn()
+const renderCssChunkFn = vi.fn()
+
+export default defineTest({
+  config: {
+    input: './main.js',
+    plugins: [
+      {
+        name: 'test-plugin',
+        transformCss(code, id, meta) {
+          transformCssFn()
+          expect(id).toBe(path.join(__dirname, 'style.css'))
+          expect(meta.moduleType).toBe('css')
+          return code.replace('red', 'blue')
+        },
+        renderCssChunk(code, chunk) {
+          renderCssChunkFn()
+          expect(chunk.name).toBe('main')
+          return { code: `/* ${chunk.name} */\n${code}` }
+        },
+      },
+    ],
+  },
+  afterTest: (output) => {
+    expect(transformCssFn).toHaveBeenCalledTimes(1)
+    expect(renderCssChunkFn).toHaveBeenCalledTimes(1)
+    const css = output.output.find((item) =>
+      item.fileName.endsWith('.css'),
+    ) as RolldownOutputAsset
+    const source = css.source.toString()
+    expect(source.startsWith('/* main */\n')).toBe(true)
+    expect(source).toContain('blue')
+    expect(source).not.toContain('red')
+  },
+})
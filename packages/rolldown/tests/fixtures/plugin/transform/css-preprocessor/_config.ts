@@ -0,0 +1,37 @@
+import type { RolldownOutputAsset } from 'rolldown'
+import { defineTest } from '@tests'
+import { expect } from 'vitest'
+
+export default defineTest({
+  config: {
+    input: './main.js',
+    plugins: [
+      {
+        name: 'fake-sass',
+        transform: {
+          filter: {
+            moduleType: ['scss'],
+          },
+          handler(code) {
+            // Inline the variables to mimic what a sass compiler would do.
+            const variables = new Map<string, string>()
+            const css = code
+              .replace(/^\$([\w-]+):\s*(.+);\n/gm, (_, name, value) => {
+                variables.set(name, value)
+                return ''
+              })
+              .replace(/\$([\w-]+)/g, (_, name) => variables.get(name)!)
+            return { code: css, moduleType: 'css' }
+          },
+        },
+      },
+    ],
+  },
+  afterTest: (output) => {
+    const css = output.output.find((item) =>
+      item.fileName.endsWith('.css'),
+    ) as RolldownOutputAsset
+    expect(css.source.toString()).toContain('color: red;')
+    expect(css.source.toString()).not.toContain('$color')
+  },
+})
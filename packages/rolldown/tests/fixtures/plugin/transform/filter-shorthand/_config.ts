@@ -0,0 +1,58 @@
+import { defineTest } from '@tests'
+import { expect, vi } from 'vitest'
+
+const transformFn = vi.fn()
+const transformFn2 = vi.fn()
+const transformFn3 = vi.fn()
+
+export default defineTest({
+  beforeTest() {
+    transformFn.mockReset()
+    transformFn2.mockReset()
+    transformFn3.mockReset()
+  },
+  config: {
+    plugins: [
+      {
+        name: 'test-plugin',
+        transform: {
+          filter: {
+            id: /foo\.js$/,
+          },
+          handler(_, id) {
+            transformFn()
+            expect(id.endsWith('foo.js')).toBe(true)
+          },
+        },
+      },
+      {
+        name: 'test-plugin-2',
+        transform: {
+          filter: {
+            code: 'export const a',
+          },
+          handler(_, id) {
+            transformFn2()
+            expect(id.endsWith('a.ts')).toBe(true)
+          },
+        },
+      },
+      {
+        name: 'test-plugin-3',
+        transform: {
+          filter: {
+            id: { exclude: /foo\.js$/ },
+          },
+          handler() {
+            transformFn3()
+          },
+        },
+      },
+    ],
+  },
+  afterTest: () => {
+    expect(transformFn).toHaveBeenCalledTimes(1)
+    expect(transformFn2).toHaveBeenCalledTimes(1)
+    expect(transformFn3).toHaveBeenCalledTimes(2)
+  },
+})
@@ -0,0 +1,16 @@
+import { defineTest } from '@tests'
+import { getOutputChunk } from '@tests/utils'
+import { expect } from 'vitest'
+
+export default defineTest({
+  config: {
+    treeshake: {
+      annotations: false,
+    },
+  },
+  afterTest: (output) => {
+    const code = getOutputChunk(output)[0].code
+    expect(code).toContain('createStore()')
+    expect(code).toContain('defineComponent()')
+  },
+})
@@ -0,0 +1,11 @@
+/*#__NO_SIDE_EFFECTS__*/
+function defineComponent() {
+  globalThis.components = (globalThis.components || 0) + 1
+}
+
+function createStore() {
+  globalThis.stores = (globalThis.stores || 0) + 1
+}
+
+const store = /*#__PURE__*/ createStore()
+const component = defineComponent()
@@ -0,0 +1,13 @@
+import { defineTest } from '@tests'
+import { getOutputChunk } from '@tests/utils'
+import { expect } from 'vitest'
+
+export default defineTest({
+  afterTest: (output) => {
+    const code = getOutputChunk(output)[0].code
+    expect(code).not.toContain('legacyInit')
+    expect(code).not.toContain('legacy mode')
+    expect(code).toContain('modern mode')
+    expect(code).toContain('"production"')
+  },
+})
@@ -0,0 +1,3 @@
+export const IS_LEGACY = false
+export const MODE = 'production'
+export const VERSION = '1.2.3'
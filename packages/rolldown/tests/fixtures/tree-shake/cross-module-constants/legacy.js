@@ -0,0 +1,3 @@
+export function legacyInit() {
+  console.log('legacy mode')
+}
@@ -0,0 +1,11 @@
+import { IS_LEGACY, MODE, VERSION } from './flags.js'
+import { legacyInit } from './legacy.js'
+
+if (IS_LEGACY) {
+  legacyInit()
+} else {
+  console.log('modern mode')
+}
+
+export const mode = MODE === 'production' ? 'production' : legacyInit()
+export { VERSION }
@@ -0,0 +1,25 @@
+import type { RolldownOutputAsset, RolldownOutputChunk } from 'rolldown'
+import { defineTest } from '@tests'
+import { expect } from 'vitest'
+
+export default defineTest({
+  config: {
+    input: './main.js',
+  },
+  afterTest: (output) => {
+    const chunk = output.output.find(
+      ({ type }) => type === 'chunk',
+    ) as RolldownOutputChunk
+    const css = output.output.find((item) =>
+      item.fileName.endsWith('.css'),
+    ) as RolldownOutputAsset
+    const source = css.source.toString()
+
+    const title = chunk.code.match(/"(title_[\w-]+)"/)![1]
+    expect(source).toContain(`.${title} {`)
+    // The rule matching both classes is kept, since one of them is used.
+    expect(source).toContain('font-weight: bold;')
+    expect(source).not.toContain('color: blue;')
+    expect(chunk.code).not.toContain('other_')
+  },
+})
@@ -0,0 +1,3 @@
+import * as styles from './style.module.css'
+
+export const className = styles.title
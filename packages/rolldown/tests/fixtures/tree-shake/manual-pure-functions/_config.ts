@@ -0,0 +1,18 @@
+import { defineTest } from '@tests'
+import { getOutputChunk } from '@tests/utils'
+import { expect } from 'vitest'
+
+export default defineTest({
+  config: {
+    treeshake: {
+      manualPureFunctions: ['styled', /^console\./],
+    },
+  },
+  afterTest: (output) => {
+    const code = getOutputChunk(output)[0].code
+    expect(code).not.toContain('color: red')
+    expect(code).not.toContain('Button')
+    expect(code).not.toContain('dropped')
+    expect(code).toContain('font-size: 2em')
+  },
+})
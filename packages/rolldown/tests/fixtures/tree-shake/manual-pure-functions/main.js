@@ -0,0 +1,8 @@
+import { styled } from './styled.js'
+
+styled.div`color: red`
+const Button = styled('button')()
+console.log('dropped')
+console.info('dropped')
+
+export const Title = styled.h1`font-size: 2em`
@@ -0,0 +1,3 @@
+export const styled = (tag) => () => tag
+styled.div = () => 'div'
+styled.h1 = () => 'h1'
@@ -0,0 +1,24 @@
+import { defineTest } from '@tests'
+import { getOutputChunk } from '@tests/utils'
+import { expect } from 'vitest'
+
+const calls: [string, boolean][] = []
+
+export default defineTest({
+  config: {
+    external: ['node:fs'],
+    treeshake: {
+      moduleSideEffects: (id, external) => {
+        calls.push([id, external])
+        return !external && !id.endsWith('b.js')
+      },
+    },
+  },
+  afterTest: (output) => {
+    const code = getOutputChunk(output)[0].code
+    expect(code).toContain('console.log("a")')
+    expect(code).not.toContain('console.log("b")')
+    expect(code).not.toContain('node:fs')
+    expect(calls).toContainEqual(['node:fs', true])
+  },
+})
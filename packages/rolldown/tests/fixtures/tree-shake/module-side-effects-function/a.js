@@ -0,0 +1,3 @@
+export const a = 100
+
+console.log('a')
@@ -0,0 +1,3 @@
+export const b = 100
+
+console.log('b')
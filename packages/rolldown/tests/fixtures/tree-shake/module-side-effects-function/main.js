@@ -0,0 +1,3 @@
+import { a } from './a.js'
+import { b } from './b.js'
+import { readFileSync } from 'node:fs'
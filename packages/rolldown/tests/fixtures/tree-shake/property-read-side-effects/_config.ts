@@ -0,0 +1,17 @@
+import { defineTest } from '@tests'
+import { getOutputChunk } from '@tests/utils'
+import { expect } from 'vitest'
+
+export default defineTest({
+  config: {
+    treeshake: {
+      propertyReadSideEffects: false,
+    },
+  },
+  afterTest: (output) => {
+    const code = getOutputChunk(output)[0].code
+    expect(code).not.toContain('config.plugins')
+    expect(code).not.toContain('config["mode"]')
+    expect(code).toContain('config.value')
+  },
+})
@@ -0,0 +1,5 @@
+export const config = {
+  mode: 'production',
+  plugins: [],
+  value: 1,
+}
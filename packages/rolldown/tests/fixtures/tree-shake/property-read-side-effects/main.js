@@ -0,0 +1,6 @@
+import { config } from './config.js'
+
+config.plugins.length
+config['mode']
+
+export const value = config.value
@@ -0,0 +1,17 @@
+import { defineTest } from '@tests'
+import { getOutputChunk } from '@tests/utils'
+import { expect } from 'vitest'
+
+export default defineTest({
+  config: {
+    treeshake: {
+      unknownGlobalSideEffects: false,
+    },
+  },
+  afterTest: (output) => {
+    const code = getOutputChunk(output)[0].code
+    expect(code).not.toContain('jQuery')
+    expect(code).not.toContain('matchMedia')
+    expect(code).toContain('export { value }')
+  },
+})
@@ -0,0 +1,3 @@
+export const hasJQuery = typeof jQuery !== 'undefined'
+export const $ = jQuery
+export const prefersDark = window.matchMedia
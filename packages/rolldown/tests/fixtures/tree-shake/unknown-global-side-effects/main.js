@@ -0,0 +1,3 @@
+import './feature.js'
+
+export const value = 1
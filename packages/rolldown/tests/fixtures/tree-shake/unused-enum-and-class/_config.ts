@@ -0,0 +1,13 @@
+import { defineTest } from '@tests'
+import { getOutputChunk } from '@tests/utils'
+import { expect } from 'vitest'
+
+export default defineTest({
+  afterTest: (output) => {
+    const code = getOutputChunk(output)[0].code
+    expect(code).not.toContain('Direction')
+    expect(code).not.toContain('Client')
+    expect(code).not.toContain('DEFAULTS')
+    expect(code).toContain('Color')
+  },
+})
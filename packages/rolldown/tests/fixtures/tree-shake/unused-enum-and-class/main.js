@@ -0,0 +1,3 @@
+import { Color } from './sdk.js'
+
+export const red = Color.Red
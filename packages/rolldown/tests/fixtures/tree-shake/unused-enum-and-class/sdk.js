@@ -0,0 +1,17 @@
+export var Direction = (function (Direction) {
+  Direction[(Direction['Up'] = 0)] = 'Up'
+  Direction[(Direction['Down'] = 1)] = 'Down'
+  return Direction
+})(Direction || {})
+
+export var Color = (function (Color) {
+  Color['Red'] = 'red'
+  return Color
+})(Color || {})
+
+export const DEFAULTS = Object.freeze({ retries: 3 })
+
+export class Client {
+  static version = '1.0.0'
+  static defaults = DEFAULTS
+}
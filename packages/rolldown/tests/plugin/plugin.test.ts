@@ -16,6 +16,75 @@ async function buildWithPlugin(plugin: Plugin) {
   }
 }
 
+test('call hooks in the same order as rollup', async () => {
+  const calls: string[] = []
+  const build = await rolldown({
+    input: './main.js',
+    cwd: import.meta.dirname,
+    plugins: [
+      {
+        options() {
+          calls.push('options')
+        },
+        outputOptions() {
+          calls.push('outputOptions')
+        },
+        buildStart() {
+          calls.push('buildStart')
+        },
+        resolveId() {
+          calls.push('resolveId')
+        },
+        load() {
+          calls.push('load')
+        },
+        transform() {
+          calls.push('transform')
+        },
+        moduleParsed() {
+          calls.push('moduleParsed')
+        },
+        buildEnd() {
+          calls.push('buildEnd')
+        },
+        renderStart() {
+          calls.push('renderStart')
+        },
+        renderChunk() {
+          calls.push('renderChunk')
+        },
+        generateBundle() {
+          calls.push('generateBundle')
+        },
+        writeBundle() {
+          calls.push('writeBundle')
+        },
+        closeBundle() {
+          calls.push('closeBundle')
+        },
+      },
+    ],
+  })
+  await build.write({})
+  await build.close()
+  expect(calls).toStrictEqual([
+    'options',
+    // The build starts when the bundle is generated or written for the first time.
+    'outputOptions',
+    'buildStart',
+    'resolveId',
+    'load',
+    'transform',
+    'moduleParsed',
+    'buildEnd',
+    'renderStart',
+    'renderChunk',
+    'generateBundle',
+    'writeBundle',
+    'closeBundle',
+  ])
+})
+
 test('Plugin renderError hook', async () => {
   const renderErrorFn = vi.fn()
   const renderChunkFn = vi.fn()